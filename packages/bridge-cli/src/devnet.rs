@@ -0,0 +1,144 @@
+use anyhow::Result;
+use bridge_core::{
+    chain_adapter::{mock::{MockChainAdapterFactory, MockOperation}, ChainAdapter},
+    config::ValidatorConfig,
+    keystore::Keystore,
+    ledger,
+    relayer::ChainAdapterFactory,
+    testing::{self, TwoChainHarness, SOURCE_CHAIN, TARGET_CHAIN},
+    types::{Attestation, ChainId, ChainRegistry, CrossChainMessage, ValidatorRegistration},
+    Error,
+};
+use std::collections::HashMap;
+
+/// 本地模拟网络的运行参数
+pub struct DevnetOptions {
+    pub transfers: usize,
+    pub amount: u64,
+    pub validators: usize,
+    /// 每笔转账在目标链上先失败的提交次数，用于演示重试
+    pub fail_submissions: u32,
+}
+
+/// 进程内验证者，持有已通过注册核对的签名密钥
+struct DevnetValidator {
+    address: String,
+    public_key: String,
+    keystore: Keystore,
+}
+
+impl DevnetValidator {
+    async fn attest(&self, message: &CrossChainMessage, chains: &ChainRegistry, timestamp: u64) -> Result<Attestation> {
+        Ok(Attestation {
+            message_id: message.message_id(chains)?,
+            message: message.clone(),
+            validator: self.address.clone(),
+            public_key: self.public_key.clone(),
+            signature: self.keystore.sign_message(&self.public_key, message, chains).await?,
+            timestamp,
+        })
+    }
+}
+
+/// 启动两条模拟链、中继器与进程内验证者，依次发出转账并打印消息流转过程
+pub async fn run(options: DevnetOptions) -> Result<()> {
+    if options.validators == 0 {
+        return Err(anyhow::anyhow!("至少需要一个验证者"));
+    }
+
+    // 验证者密钥由序号确定性生成，每次运行结果一致
+    let mut validators = Vec::with_capacity(options.validators);
+    let mut config = testing::two_chain_config();
+    for index in 0..options.validators {
+        let keystore = Keystore::from_secret_keys(&[vec![index as u8 + 1; 32]])?;
+        let public_key = keystore.public_keys().remove(0);
+        let address = format!("0x{:064x}", index + 1);
+        config.validators.push(ValidatorConfig {
+            address: address.clone(),
+            public_key: public_key.clone(),
+            bls_public_key: None,
+            weight: 1,
+            chains: vec![SOURCE_CHAIN.to_string()],
+        });
+        validators.push(DevnetValidator { address, public_key, keystore });
+    }
+    config.relayer.require_attestations = true;
+    config.relayer.max_retries = options.fail_submissions + 1;
+    let chains = config.chain_registry()?;
+
+    let mut harness = TwoChainHarness::with_config(config.clone()).await?;
+    for validator in &config.validators {
+        harness.source.register_validator(ValidatorRegistration {
+            address: validator.address.clone(),
+            weight: validator.weight,
+            active: true,
+        });
+    }
+    let source_config = config.get_chain_config(SOURCE_CHAIN)
+        .ok_or_else(|| anyhow::anyhow!("缺少源链配置"))?;
+    let adapters: HashMap<ChainId, Box<dyn ChainAdapter>> = HashMap::from([(
+        ChainId::from(SOURCE_CHAIN),
+        MockChainAdapterFactory::new([harness.source.clone()]).create_adapter(source_config).await?,
+    )]);
+    for validator in &mut validators {
+        validator.keystore.verify_registrations(&config, &adapters).await?;
+    }
+    let aggregator = harness.relayer
+        .aggregator()
+        .ok_or_else(|| anyhow::anyhow!("中继器未启用证明收集"))?;
+
+    println!("本地模拟网络已启动:");
+    println!("- 链: {} -> {}", SOURCE_CHAIN, TARGET_CHAIN);
+    println!("- 资产: {}", testing::TEST_ASSET);
+    println!("- 验证者: {} (签名阈值 {})", validators.len(), aggregator.threshold());
+    println!();
+
+    let mut delivered = 0;
+    for index in 0..options.transfers {
+        let recipient = [index as u8 + 1; 32];
+        let sent = harness.source.send(TARGET_CHAIN, "transfer", testing::transfer_payload(recipient, options.amount));
+        let message_id = sent.message.message_id(&chains)?;
+        println!("[{}] 转账 #{} 已发出", SOURCE_CHAIN, sent.message.nonce);
+        println!("  消息 ID: {}", message_id);
+        println!("  接收者: 0x{}  金额: {}", hex::encode(recipient), options.amount);
+
+        // 验证者对换算金额后提交到目标链的消息签名
+        let submitted = ledger::submitted_message(&config, &sent.message)?;
+        for validator in &validators {
+            let progress = aggregator.submit(validator.attest(&submitted, &chains, sent.timestamp).await?).await?;
+            println!("  验证者 {} 已签名 ({}/{})", short(&validator.address), progress.weight, progress.threshold);
+        }
+
+        for _ in 0..options.fail_submissions {
+            harness.target.fail_next(MockOperation::Submit, Error::Network("simulated submission failure".to_string()));
+        }
+        harness.tick().await;
+
+        let transaction = harness.target
+            .transactions()
+            .into_iter()
+            .find(|(_, message)| message.message.source_chain == SOURCE_CHAIN && message.message.nonce == sent.message.nonce);
+        match transaction {
+            Some((tx_hash, _)) => {
+                delivered += 1;
+                println!("[{}] 已交付, 交易: {}", TARGET_CHAIN, tx_hash);
+            }
+            None => println!("[{}] 未交付, 消息仍在待处理队列中", TARGET_CHAIN),
+        }
+        println!();
+    }
+
+    println!("模拟完成!");
+    println!("- 发出转账: {}", options.transfers);
+    println!("- 已交付: {}", delivered);
+    println!("- 待处理: {}", harness.backlog());
+    Ok(())
+}
+
+/// 缩短地址，便于在终端中显示
+fn short(address: &str) -> String {
+    if address.len() <= 12 {
+        return address.to_string();
+    }
+    format!("{}..{}", &address[..6], &address[address.len() - 4..])
+}
@@ -0,0 +1,465 @@
+use anyhow::Result;
+use bridge_core::{
+    chain_adapter::{address::AddressFormat, SUPPORTED_ADAPTER_TYPES},
+    config::{Config, ConfigFormat},
+    relayer::handler::MESSAGE_EVENT_HANDLER,
+    signer::{LocalSigner, Signer},
+};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::{BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+
+/// 未指定时新生成的验证者密钥写入的密钥库文件
+pub const DEFAULT_KEYSTORE: &str = "validator.keystore.json";
+
+/// `bridge-cli init` 的参数
+pub struct InitOptions {
+    pub output: PathBuf,
+    /// `ID,适配器类型,RPC 地址,桥合约地址[,数字链 ID]`
+    pub chains: Vec<String>,
+    /// `名称,原生链,类型,精度`
+    pub assets: Vec<String>,
+    /// `资产,链,类型`
+    pub mappings: Vec<String>,
+    /// `地址,公钥[,权重]`，公钥为 `new` 时生成新密钥
+    pub validators: Vec<String>,
+    pub keystore: PathBuf,
+    pub api: Option<String>,
+    pub example: bool,
+    pub force: bool,
+}
+
+/// 生成配置文件：未通过参数给出链且终端可交互时逐项询问，否则按参数生成
+///
+/// 每一项输入都立即检查，写入前再按 [`Config::parse_document`] 完整校验一次。
+pub fn run(options: InitOptions) -> Result<()> {
+    if options.output.exists() && !options.force {
+        return Err(anyhow::anyhow!("{:?} 已存在，使用 --force 覆盖", options.output));
+    }
+
+    let mut draft = Draft::new(&options.keystore);
+    if options.example {
+        draft.example()?;
+    } else if options.chains.is_empty() {
+        if !std::io::stdin().is_terminal() {
+            return Err(anyhow::anyhow!("非交互模式下至少需要一个 --chain"));
+        }
+        draft.interactive(&mut Prompt::stdin())?;
+    } else {
+        for spec in &options.chains {
+            draft.add_chain_spec(spec)?;
+        }
+        for spec in &options.assets {
+            draft.add_asset_spec(spec)?;
+        }
+        for spec in &options.mappings {
+            draft.add_mapping_spec(spec)?;
+        }
+        for spec in &options.validators {
+            draft.add_validator_spec(spec)?;
+        }
+        draft.api = options.api.clone();
+    }
+
+    let document = draft.document();
+    Config::parse_document(document.clone(), &HashMap::new())?;
+    let format = ConfigFormat::from_path(&options.output);
+    std::fs::write(&options.output, format.render(&document)?)?;
+    draft.write_keystore()?;
+
+    println!("配置已写入 {:?}", options.output);
+    println!("- 链: {}", draft.chains.len());
+    println!("- 资产: {}", draft.assets.len());
+    println!("- 验证者: {}", draft.validators.len());
+    if !draft.secret_keys.is_empty() {
+        println!("- 新生成的验证者私钥已写入 {:?}，请妥善保管", draft.keystore);
+    }
+    println!("可运行 `bridge-cli validate-config -c {}` 查看配置概要", options.output.display());
+    Ok(())
+}
+
+/// 逐项收集、尚未写入的配置
+struct Draft {
+    chains: Vec<Value>,
+    assets: Vec<Value>,
+    validators: Vec<Value>,
+    api: Option<String>,
+    keystore: PathBuf,
+    /// 本次新生成的验证者私钥（十六进制）
+    secret_keys: Vec<String>,
+}
+
+impl Draft {
+    fn new(keystore: &Path) -> Self {
+        Self {
+            chains: Vec::new(),
+            assets: Vec::new(),
+            validators: Vec::new(),
+            api: None,
+            keystore: keystore.to_path_buf(),
+            secret_keys: Vec::new(),
+        }
+    }
+
+    fn chain_ids(&self) -> Vec<String> {
+        self.chains.iter().filter_map(|chain| chain["id"].as_str().map(str::to_string)).collect()
+    }
+
+    fn document(&self) -> Value {
+        let mut relayer = json!({
+            "poll_interval": 5,
+            "max_retries": 3,
+            "retry_delay": 10,
+        });
+        if !self.secret_keys.is_empty() {
+            relayer["keystore"] = json!(self.keystore);
+        }
+        let mut document = json!({
+            "chains": self.chains,
+            "assets": self.assets,
+            "validators": self.validators,
+            "relayer": relayer,
+        });
+        if let Some(listen) = &self.api {
+            document["api"] = json!({ "listen": listen });
+        }
+        document
+    }
+
+    fn write_keystore(&self) -> Result<()> {
+        if !self.secret_keys.is_empty() {
+            std::fs::write(&self.keystore, serde_json::to_string_pretty(&self.secret_keys)?)?;
+        }
+        Ok(())
+    }
+
+    /// 两条链、一种资产与一个新生成密钥的验证者，地址均为占位值
+    fn example(&mut self) -> Result<()> {
+        self.add_chain("sui_testnet", "sui", "https://fullnode.testnet.sui.io:443", "0x1", Some(1))?;
+        self.add_chain("rooch_testnet", "rooch", "https://test-seed.rooch.network", "0x2", Some(2))?;
+        self.add_asset("USDC", "sui_testnet", "0x2::usdc::USDC", 6)?;
+        self.add_mapping("USDC", "rooch_testnet", "0x3::usdc::USDC")?;
+        self.add_validator(&format!("0x{:064x}", 1), "new", 1)?;
+        self.api = Some("127.0.0.1:8080".to_string());
+        Ok(())
+    }
+
+    fn interactive(&mut self, prompt: &mut Prompt) -> Result<()> {
+        println!("逐项输入配置，方括号中为默认值，直接回车使用默认值。");
+
+        println!("\n[链]");
+        loop {
+            let finish = self.chains.len() >= 2;
+            let question = if finish { "链 ID（留空结束）" } else { "链 ID" };
+            let id = prompt.ask_with(question, None, |input| {
+                if input.is_empty() && finish {
+                    return Ok(None);
+                }
+                check_chain_id(input, &self.chain_ids()).map(Some)
+            })?;
+            let Some(id) = id else { break };
+            let adapter_type = prompt.ask_with(
+                &format!("适配器类型（{}）", SUPPORTED_ADAPTER_TYPES.join("/")),
+                Some(SUPPORTED_ADAPTER_TYPES[0]),
+                check_adapter_type,
+            )?;
+            let rpc_url = prompt.ask_with("RPC 地址", None, check_rpc_url)?;
+            let bridge_address = prompt.ask_with("桥合约地址", None, |input| check_bridge_address(&adapter_type, input))?;
+            let numeric_id = prompt.ask_with("合约中的数字链 ID（可选）", Some(""), |input| {
+                if input.is_empty() {
+                    return Ok(None);
+                }
+                input.parse::<u64>().map(Some).map_err(|_| "应为非负整数".to_string())
+            })?;
+            self.add_chain(&id, &adapter_type, &rpc_url, &bridge_address, numeric_id)?;
+        }
+
+        println!("\n[资产]");
+        let chain_ids = self.chain_ids();
+        loop {
+            let name = prompt.ask_with("资产名称（留空结束）", Some(""), |input| {
+                if self.assets.iter().any(|asset| asset["name"] == input) {
+                    return Err(format!("资产 {} 已存在", input));
+                }
+                Ok(input.to_string())
+            })?;
+            if name.is_empty() {
+                break;
+            }
+            let native_chain = prompt.ask_with(
+                &format!("原生链（{}）", chain_ids.join("/")),
+                Some(&chain_ids[0]),
+                |input| check_known_chain(input, &chain_ids),
+            )?;
+            let type_ = prompt.ask_with("原生链上的类型", None, check_non_empty)?;
+            let decimals = prompt.ask_with("精度", Some("6"), |input| {
+                input.parse::<u8>().map_err(|_| "应为 0-255 的整数".to_string())
+            })?;
+            self.add_asset(&name, &native_chain, &type_, decimals)?;
+            for chain in chain_ids.iter().filter(|chain| **chain != native_chain) {
+                let mapped = prompt.ask(&format!("在 {} 上的类型（留空不映射）", chain), Some(""))?;
+                if !mapped.is_empty() {
+                    self.add_mapping(&name, chain, &mapped)?;
+                }
+            }
+        }
+
+        println!("\n[验证者]");
+        loop {
+            let address = prompt.ask_with("验证者地址（留空结束）", Some(""), |input| {
+                if input.is_empty() {
+                    return Ok(None);
+                }
+                check_address(input).map(Some)
+            })?;
+            let Some(address) = address else { break };
+            let public_key = prompt.ask_with("ed25519 公钥（十六进制，输入 new 生成新密钥）", Some("new"), check_public_key)?;
+            let weight = prompt.ask_with("权重", Some("1"), check_weight)?;
+            self.add_validator(&address, &public_key, weight)?;
+        }
+        if !self.secret_keys.is_empty() {
+            let keystore = prompt.ask("新密钥写入的密钥库文件", Some(&self.keystore.to_string_lossy()))?;
+            self.keystore = PathBuf::from(keystore);
+        }
+
+        println!("\n[HTTP 接口]");
+        let listen = prompt.ask("监听地址（留空不启用）", Some("127.0.0.1:8080"))?;
+        self.api = (!listen.is_empty()).then_some(listen);
+        Ok(())
+    }
+
+    fn add_chain(&mut self, id: &str, adapter_type: &str, rpc_url: &str, bridge_address: &str, numeric_id: Option<u64>) -> Result<()> {
+        let id = check_chain_id(id, &self.chain_ids()).map_err(invalid("链 ID"))?;
+        let adapter_type = check_adapter_type(adapter_type).map_err(invalid("适配器类型"))?;
+        let rpc_url = check_rpc_url(rpc_url).map_err(invalid("RPC 地址"))?;
+        let bridge_address = check_bridge_address(&adapter_type, bridge_address).map_err(invalid("桥合约地址"))?;
+        let mut chain = json!({
+            "id": id,
+            "adapter_type": adapter_type,
+            "name": id,
+            "rpc_url": rpc_url,
+            "bridge_address": bridge_address,
+            "event_filters": [{ "name": message_event(&adapter_type), "handler": MESSAGE_EVENT_HANDLER }],
+        });
+        if let Some(numeric_id) = numeric_id {
+            chain["chain_id"] = json!(numeric_id);
+        }
+        self.chains.push(chain);
+        Ok(())
+    }
+
+    fn add_chain_spec(&mut self, spec: &str) -> Result<()> {
+        let fields = split_spec(spec, 4, 5, "ID,适配器类型,RPC 地址,桥合约地址[,数字链 ID]")?;
+        let numeric_id = fields.get(4)
+            .map(|id| id.parse::<u64>().map_err(|_| anyhow::anyhow!("无效的数字链 ID: {}", id)))
+            .transpose()?;
+        self.add_chain(fields[0], fields[1], fields[2], fields[3], numeric_id)
+    }
+
+    fn add_asset(&mut self, name: &str, native_chain: &str, type_: &str, decimals: u8) -> Result<()> {
+        let name = check_non_empty(name).map_err(invalid("资产名称"))?;
+        if self.assets.iter().any(|asset| asset["name"] == name.as_str()) {
+            return Err(anyhow::anyhow!("资产 {} 重复", name));
+        }
+        let native_chain = check_known_chain(native_chain, &self.chain_ids()).map_err(invalid("原生链"))?;
+        let type_ = check_non_empty(type_).map_err(invalid("资产类型"))?;
+        self.assets.push(json!({
+            "name": name,
+            "native_chain": native_chain,
+            "type_": type_,
+            "decimals": decimals,
+            "mappings": {},
+        }));
+        Ok(())
+    }
+
+    fn add_asset_spec(&mut self, spec: &str) -> Result<()> {
+        let fields = split_spec(spec, 4, 4, "名称,原生链,类型,精度")?;
+        let decimals = fields[3].parse::<u8>().map_err(|_| anyhow::anyhow!("无效的精度: {}", fields[3]))?;
+        self.add_asset(fields[0], fields[1], fields[2], decimals)
+    }
+
+    fn add_mapping(&mut self, asset: &str, chain: &str, type_: &str) -> Result<()> {
+        let chain = check_known_chain(chain, &self.chain_ids()).map_err(invalid("映射链"))?;
+        let type_ = check_non_empty(type_).map_err(invalid("映射类型"))?;
+        let asset = self.assets
+            .iter_mut()
+            .find(|candidate| candidate["name"] == asset)
+            .ok_or_else(|| anyhow::anyhow!("映射引用了未定义的资产: {}", asset))?;
+        if asset["native_chain"] == chain.as_str() {
+            return Err(anyhow::anyhow!("资产不能映射到其原生链 {}", chain));
+        }
+        if let Some(mappings) = asset["mappings"].as_object_mut() {
+            mappings.insert(chain, Value::String(type_));
+        }
+        Ok(())
+    }
+
+    fn add_mapping_spec(&mut self, spec: &str) -> Result<()> {
+        let fields = split_spec(spec, 3, 3, "资产,链,类型")?;
+        self.add_mapping(fields[0], fields[1], fields[2])
+    }
+
+    fn add_validator(&mut self, address: &str, public_key: &str, weight: u64) -> Result<()> {
+        let address = check_address(address).map_err(invalid("验证者地址"))?;
+        let public_key = match check_public_key(public_key).map_err(invalid("验证者公钥"))?.as_str() {
+            "new" => {
+                let signer = LocalSigner::generate();
+                let secret = signer.secret_key_bytes().expect("local signer exposes its secret key");
+                self.secret_keys.push(hex::encode(secret));
+                hex::encode(signer.public_key())
+            }
+            public_key => public_key.to_string(),
+        };
+        if weight == 0 {
+            return Err(anyhow::anyhow!("验证者权重无效: 应为正整数"));
+        }
+        self.validators.push(json!({
+            "address": address,
+            "public_key": public_key,
+            "weight": weight,
+            "chains": self.chain_ids(),
+        }));
+        Ok(())
+    }
+
+    fn add_validator_spec(&mut self, spec: &str) -> Result<()> {
+        let fields = split_spec(spec, 2, 3, "地址,公钥[,权重]")?;
+        let weight = fields.get(2).map(|w| check_weight(w)).transpose().map_err(invalid("验证者权重"))?;
+        self.add_validator(fields[0], fields[1], weight.unwrap_or(1))
+    }
+}
+
+/// 从标准输入读取回答
+struct Prompt {
+    input: std::io::StdinLock<'static>,
+}
+
+impl Prompt {
+    fn stdin() -> Self {
+        Self { input: std::io::stdin().lock() }
+    }
+
+    fn ask(&mut self, question: &str, default: Option<&str>) -> Result<String> {
+        match default {
+            Some(default) if !default.is_empty() => print!("{} [{}]: ", question, default),
+            _ => print!("{}: ", question),
+        }
+        std::io::stdout().flush()?;
+        let mut line = String::new();
+        if self.input.read_line(&mut line)? == 0 {
+            return Err(anyhow::anyhow!("输入已结束"));
+        }
+        let answer = line.trim();
+        Ok(match default {
+            Some(default) if answer.is_empty() => default.to_string(),
+            _ => answer.to_string(),
+        })
+    }
+
+    /// 回答未通过检查时说明原因并重新询问
+    fn ask_with<T>(&mut self, question: &str, default: Option<&str>, check: impl Fn(&str) -> Result<T, String>) -> Result<T> {
+        loop {
+            let answer = self.ask(question, default)?;
+            match check(&answer) {
+                Ok(value) => return Ok(value),
+                Err(reason) => println!("  {}", reason),
+            }
+        }
+    }
+}
+
+fn invalid(field: &'static str) -> impl Fn(String) -> anyhow::Error {
+    move |reason| anyhow::anyhow!("{}无效: {}", field, reason)
+}
+
+fn split_spec<'a>(spec: &'a str, min: usize, max: usize, format: &str) -> Result<Vec<&'a str>> {
+    let fields: Vec<&str> = spec.split(',').map(str::trim).collect();
+    if fields.len() < min || fields.len() > max {
+        return Err(anyhow::anyhow!("格式应为 `{}`: {}", format, spec));
+    }
+    Ok(fields)
+}
+
+fn check_non_empty(input: &str) -> Result<String, String> {
+    if input.is_empty() {
+        return Err("不能为空".to_string());
+    }
+    Ok(input.to_string())
+}
+
+fn check_chain_id(input: &str, existing: &[String]) -> Result<String, String> {
+    if input.is_empty() || !input.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+        return Err("只能包含字母、数字、下划线与连字符".to_string());
+    }
+    if existing.iter().any(|id| id == input) {
+        return Err(format!("链 {} 已存在", input));
+    }
+    Ok(input.to_string())
+}
+
+fn check_known_chain(input: &str, chain_ids: &[String]) -> Result<String, String> {
+    if !chain_ids.iter().any(|id| id == input) {
+        return Err(format!("未知的链 {}，可选: {}", input, chain_ids.join(", ")));
+    }
+    Ok(input.to_string())
+}
+
+fn check_adapter_type(input: &str) -> Result<String, String> {
+    if !SUPPORTED_ADAPTER_TYPES.contains(&input) {
+        return Err(format!("可选: {}", SUPPORTED_ADAPTER_TYPES.join(", ")));
+    }
+    Ok(input.to_string())
+}
+
+/// 各类型桥合约发出跨链消息的事件
+fn message_event(adapter_type: &str) -> &'static str {
+    match adapter_type {
+        "sui" => "MessageEvent",
+        "rooch" => "MessageSentEvent",
+        _ => "MessageSent",
+    }
+}
+
+/// 允许 `${VAR}` 引用，由加载配置时展开
+fn check_rpc_url(input: &str) -> Result<String, String> {
+    if !(input.starts_with("http://") || input.starts_with("https://") || input.starts_with("${")) {
+        return Err("应以 http:// 或 https:// 开头".to_string());
+    }
+    Ok(input.to_string())
+}
+
+fn check_address(input: &str) -> Result<String, String> {
+    match input.strip_prefix("0x") {
+        Some(hex) if !hex.is_empty() && hex.len() <= 64 && hex.chars().all(|c| c.is_ascii_hexdigit()) => {
+            Ok(input.to_ascii_lowercase())
+        }
+        _ => Err("应为 0x 开头的十六进制地址".to_string()),
+    }
+}
+
+/// 按适配器类型的地址格式规范化，没有固定格式的适配器只要求非空
+fn check_bridge_address(adapter_type: &str, input: &str) -> Result<String, String> {
+    match AddressFormat::for_adapter(adapter_type) {
+        Some(format) => format.normalize(input, None).map_err(|e| e.to_string()),
+        None => check_non_empty(input),
+    }
+}
+
+fn check_public_key(input: &str) -> Result<String, String> {
+    if input == "new" {
+        return Ok(input.to_string());
+    }
+    match hex::decode(input.trim_start_matches("0x")) {
+        Ok(bytes) if bytes.len() == 32 => Ok(hex::encode(bytes)),
+        _ => Err("应为 32 字节十六进制公钥，或输入 new 生成新密钥".to_string()),
+    }
+}
+
+fn check_weight(input: &str) -> Result<u64, String> {
+    match input.parse::<u64>() {
+        Ok(weight) if weight > 0 => Ok(weight),
+        _ => Err("应为正整数".to_string()),
+    }
+}
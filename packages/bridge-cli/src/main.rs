@@ -1,16 +1,107 @@
 use anyhow::Result;
-use bridge_core::{Config, relayer::Relayer};
-use clap::{Parser, Subcommand};
+use bridge_core::{
+    Config,
+    config::{remote, LogFileConfig, LogFormat, LoggingConfig},
+    codegen,
+    relayer::{aggregator::AttestationAggregator, leader, ChainAdapterFactory, DefaultChainAdapterFactory, Relayer},
+    attestation::AttestationService,
+    audit,
+    health::{selfcheck, HealthReport},
+    indexer::Indexer,
+    info::BuildInfo,
+    bootstrap::{self, SignedBootstrapBundle},
+    confidential::{self, PayloadKey},
+    keystore::{self, Keystore},
+    signer::{LocalSigner, Signer},
+    ledger::{self, LedgerAccount, LedgerQuery},
+    metrics::sla::SlaReport,
+    replay,
+    storage::{self, retention, MessageQuery},
+    trace,
+    types::{
+        is_governance, Attestation, BridgeAction, CrossChainMessage, Governance, KeyRotation, MessageStatus, MetadataSync, SignedMessage, ValidatorSetUpdate,
+        ValidatorWeight, GOVERNANCE_MESSAGE_TYPE, METADATA_SYNC_MESSAGE_TYPE,
+    },
+    vectors::{self, VectorFile},
+    watchtower::Watchtower,
+};
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use serde_json::json;
 use std::path::PathBuf;
-use tracing::{info, error};
+use std::time::Duration;
+use tracing::{info, error, warn};
+use messages::MessageColumn;
+use output::{print_json, OutputFormat};
+
+mod devnet;
+mod init;
+mod messages;
+mod output;
+mod top;
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 struct Cli {
+    /// 输出格式：text 或 json
+    #[arg(long = "output", id = "output_format", value_name = "FORMAT", global = true, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+    /// 日志格式，覆盖配置文件中的 `logging.format`
+    #[arg(long, value_name = "FORMAT", global = true, value_enum)]
+    log_format: Option<LogFormatArg>,
+    /// 日志级别过滤，覆盖配置文件中的 `logging.level`，模块级别仍以配置为准
+    #[arg(long, value_name = "LEVEL", global = true)]
+    log_level: Option<String>,
+    /// 日志文件路径，覆盖配置文件中的 `logging.file.path`
+    #[arg(long, value_name = "FILE", global = true)]
+    log_file: Option<PathBuf>,
     #[command(subcommand)]
     command: Commands,
 }
 
+/// 日志格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum LogFormatArg {
+    /// 供人阅读的文本
+    Pretty,
+    /// 每行一个 JSON 对象
+    Json,
+}
+
+impl From<LogFormatArg> for LogFormat {
+    fn from(format: LogFormatArg) -> Self {
+        match format {
+            LogFormatArg::Pretty => LogFormat::Pretty,
+            LogFormatArg::Json => LogFormat::Json,
+        }
+    }
+}
+
+impl Cli {
+    /// 日志配置：服务命令读取配置文件中的 `logging`，再应用命令行覆盖
+    fn logging(&self) -> LoggingConfig {
+        let mut logging = self
+            .command
+            .service_config()
+            .and_then(|path| Config::load(path).ok())
+            .and_then(|config| config.logging)
+            .unwrap_or_default();
+        if let Some(format) = self.log_format {
+            logging.format = format.into();
+        }
+        if let Some(level) = &self.log_level {
+            logging.level = level.clone();
+        }
+        if let Some(path) = &self.log_file {
+            let path = path.to_string_lossy().into_owned();
+            match &mut logging.file {
+                Some(file) => file.path = path,
+                None => logging.file = Some(LogFileConfig { path, ..Default::default() }),
+            }
+        }
+        logging
+    }
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// 验证并解析配置文件
@@ -18,26 +109,699 @@ enum Commands {
         /// 配置文件路径
         #[arg(short, long, value_name = "FILE")]
         config: PathBuf,
+        /// 连接各链 RPC，检查桥合约是否存在以及配置的事件类型是否由合约定义
+        #[arg(long)]
+        check_remote: bool,
+    },
+    /// 生成配置文件：逐项询问，或通过参数一次给出
+    Init {
+        /// 写入的配置文件，扩展名为 `.toml` 时写入 TOML，否则写入 JSON
+        #[arg(short, long = "out", value_name = "FILE", default_value = "bridge.json")]
+        output: PathBuf,
+        /// 链，格式为 `ID,适配器类型,RPC 地址,桥合约地址[,数字链 ID]`，可重复；指定后不再逐项询问
+        #[arg(long = "chain", value_name = "SPEC")]
+        chains: Vec<String>,
+        /// 资产，格式为 `名称,原生链,类型,精度`，可重复
+        #[arg(long = "asset", value_name = "SPEC")]
+        assets: Vec<String>,
+        /// 资产在其它链上的类型，格式为 `资产,链,类型`，可重复
+        #[arg(long = "mapping", value_name = "SPEC")]
+        mappings: Vec<String>,
+        /// 验证者，格式为 `地址,公钥[,权重]`，公钥为 `new` 时生成新密钥，可重复
+        #[arg(long = "validator", value_name = "SPEC")]
+        validators: Vec<String>,
+        /// 新生成的验证者私钥写入的密钥库文件
+        #[arg(long, value_name = "FILE", default_value = init::DEFAULT_KEYSTORE)]
+        keystore: PathBuf,
+        /// HTTP 接口监听地址
+        #[arg(long, value_name = "ADDR")]
+        api: Option<String>,
+        /// 生成带占位地址的示例配置
+        #[arg(long)]
+        example: bool,
+        /// 覆盖已存在的文件
+        #[arg(long)]
+        force: bool,
     },
     /// 启动中继器服务
     Start {
         /// 配置文件路径
         #[arg(short, long, value_name = "FILE")]
         config: PathBuf,
+        /// 从可信引导包恢复状态，无需从创世区块重新扫描
+        #[arg(long, value_name = "FILE")]
+        bootstrap: Option<PathBuf>,
+        /// 启动自检存在关键失败时仍然启动
+        #[arg(long)]
+        force: bool,
+    },
+    /// 将领导者租约移交给指定的备用实例：当前领导者下次续期时让出，备用实例随即接管
+    Promote {
+        /// 配置文件路径，须启用 `relayer.coordination` 与共享存储
+        #[arg(short, long, value_name = "FILE")]
+        config: PathBuf,
+        /// 接管的实例标识，默认为配置中的 `relayer.coordination.instance_id`
+        #[arg(long)]
+        instance: Option<String>,
+    },
+    /// 以观察者模式运行：核对其它运营方的中继结果并对偏差告警，启用 `watchtower.challenge` 时对欺诈性交付提交挑战
+    Watch {
+        /// 配置文件路径
+        #[arg(short, long, value_name = "FILE")]
+        config: PathBuf,
+    },
+    /// 以索引器模式运行：回放并持续索引各链的跨链事件到 SQL 存储，在 `api.listen` 提供浏览器接口，不中继消息
+    Index {
+        /// 配置文件路径
+        #[arg(short, long, value_name = "FILE")]
+        config: PathBuf,
+    },
+    /// 执行一轮核对，列出可挑战的欺诈性交付，可选向目标链提交挑战
+    Challenge {
+        /// 配置文件路径
+        #[arg(short, long, value_name = "FILE")]
+        config: PathBuf,
+        /// 通过目标链适配器提交挑战交易
+        #[arg(long)]
+        submit: bool,
+    },
+    /// 以验证者身份运行证明服务，监听源链并发布签名证明
+    Validate {
+        /// 配置文件路径
+        #[arg(short, long, value_name = "FILE")]
+        config: PathBuf,
+        /// 证明收集端点地址，配置了 p2p 网络时可省略
+        #[arg(long, value_name = "URL")]
+        collector: Option<String>,
+    },
+    /// 显示版本信息
+    Version {
+        /// 显示完整的构建信息
+        #[arg(long)]
+        verbose: bool,
+        /// 配置文件路径，指定时显示配置指纹
+        #[arg(short, long, value_name = "FILE")]
+        config: Option<PathBuf>,
+    },
+    /// 生成运营报表
+    Report {
+        #[command(subcommand)]
+        command: ReportCommands,
+    },
+    /// 资产记账报表
+    Ledger {
+        #[command(subcommand)]
+        command: LedgerCommands,
+    },
+    /// 资产管理
+    Assets {
+        #[command(subcommand)]
+        command: AssetCommands,
+    },
+    /// 提出与执行桥治理操作
+    Governance {
+        #[command(subcommand)]
+        command: GovernanceCommands,
+    },
+    /// 导出或校验冷启动引导包
+    Bootstrap {
+        #[command(subcommand)]
+        command: BootstrapCommands,
+    },
+    /// 查询消息处理历史，例如 `bridge-cli list --status failed --chain sui_mainnet --since 24h`
+    #[command(visible_alias = "list")]
+    Messages {
+        /// 配置文件路径
+        #[arg(short, long, value_name = "FILE")]
+        config: PathBuf,
+        /// 按状态过滤：pending、processed、failed、held、expired
+        #[arg(long)]
+        status: Option<String>,
+        /// 路由，格式为 `源链:目标链`，可使用链 ID 或适配器类型
+        #[arg(long)]
+        route: Option<String>,
+        /// 源链或目标链为该链的消息，链 ID 或唯一的适配器类型
+        #[arg(long)]
+        chain: Option<String>,
+        /// 按源链发送者过滤
+        #[arg(long)]
+        sender: Option<String>,
+        /// 只显示最近一段时间的消息，例如 `24h`
+        #[arg(long, visible_alias = "since")]
+        window: Option<String>,
+        /// 最多显示的条数
+        #[arg(long, default_value_t = 50)]
+        limit: usize,
+        /// 显示的列，以逗号分隔；JSON 输出时只包含这些字段，未指定时输出完整记录
+        #[arg(long, value_enum, value_delimiter = ',')]
+        columns: Vec<MessageColumn>,
+    },
+    /// 维护消息存储
+    State {
+        #[command(subcommand)]
+        command: StateCommands,
+    },
+    /// 查询运行中中继器的各链同步情况、gas 余额与待处理消息数
+    Status {
+        /// 中继器 HTTP 接口地址
+        #[arg(long, default_value = "http://127.0.0.1:8080")]
+        api: String,
+    },
+    /// 终端仪表盘：实时显示各链延迟、队列、最近消息、错误率与 gas 余额
+    Top {
+        /// 中继器 HTTP 接口地址
+        #[arg(long, default_value = "http://127.0.0.1:8080")]
+        api: String,
+        /// 刷新间隔，例如 `2s`
+        #[arg(long, default_value = "2s")]
+        interval: String,
+        /// 路由统计与最近消息的时间窗口，例如 `1h`
+        #[arg(long, default_value = "1h")]
+        window: String,
+    },
+    /// 本地模拟网络：启动两条模拟链、中继器与进程内验证者，执行预设转账并打印消息流转
+    Devnet {
+        /// 发出的转账笔数
+        #[arg(long, default_value_t = 3)]
+        transfers: usize,
+        /// 每笔转账的金额（源链最小单位）
+        #[arg(long, default_value_t = 1_000_000)]
+        amount: u64,
+        /// 进程内验证者数量
+        #[arg(long, default_value_t = 3)]
+        validators: usize,
+        /// 每笔转账在目标链上模拟失败的提交次数
+        #[arg(long, default_value_t = 0)]
+        fail_submissions: u32,
+    },
+    /// 审计日志工具
+    Audit {
+        #[command(subcommand)]
+        command: AuditCommands,
+    },
+    /// 机密消息负载的密钥生成与加解密
+    Confidential {
+        #[command(subcommand)]
+        command: ConfidentialCommands,
+    },
+    /// 验证者密钥管理
+    Keys {
+        #[command(subcommand)]
+        command: KeyCommands,
+    },
+    /// 暂缓队列：因中继前检查未通过而暂缓的消息
+    Held {
+        #[command(subcommand)]
+        command: HeldCommands,
+    },
+    /// 导出单条消息的完整排查报告（JSON）
+    Trace {
+        /// 配置文件路径
+        #[arg(short, long, value_name = "FILE")]
+        config: PathBuf,
+        /// 消息 ID
+        #[arg(long)]
+        message_id: String,
+        /// 报告输出路径，未指定时打印到标准输出
+        #[arg(short, long = "out", value_name = "FILE")]
+        output: Option<PathBuf>,
+        /// 不连接目标链核对链上状态
+        #[arg(long)]
+        offline: bool,
+    },
+    /// 重新扫描源链历史区间，找出漏读或未交付的消息，可选重新中继
+    Replay {
+        /// 配置文件路径
+        #[arg(short, long, value_name = "FILE")]
+        config: PathBuf,
+        /// 源链，链 ID 或唯一的适配器类型
+        #[arg(long)]
+        chain: String,
+        /// 起始检查点（含）；Rooch 链为消息事件序号
+        #[arg(long, value_name = "N")]
+        from_checkpoint: u64,
+        /// 结束检查点（含）
+        #[arg(long, value_name = "M")]
+        to_checkpoint: u64,
+        /// 重新中继尚未交付的消息
+        #[arg(long)]
+        relay: bool,
+    },
+    /// 从链上读取桥合约的模块 ABI，生成事件与入口函数参数的 Rust 类型
+    Codegen {
+        /// 配置文件路径
+        #[arg(short, long, value_name = "FILE")]
+        config: PathBuf,
+        /// 读取 ABI 的链，链 ID 或唯一的适配器类型
+        #[arg(long)]
+        chain: String,
+        /// 合约包地址，未指定时使用该链配置的桥合约地址
+        #[arg(long, value_name = "ADDR")]
+        package: Option<String>,
+        /// 生成的 Rust 源文件，未指定时打印到标准输出
+        #[arg(short, long = "out", value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+    /// 用密钥库文件中的私钥离线签署跨链消息，输出签名证明；不连接链或中继器，适合隔离环境
+    SignMessage {
+        /// 配置文件路径，用于取得规范编码中的合约数字链 ID
+        #[arg(short, long, value_name = "FILE")]
+        config: PathBuf,
+        /// 跨链消息 JSON 文件
+        #[arg(long, value_name = "FILE")]
+        file: PathBuf,
+        /// 密钥库文件（十六进制私钥数组）
+        #[arg(long, value_name = "FILE")]
+        key: PathBuf,
+        /// 签名使用的公钥，密钥库中有多个密钥时必须指定
+        #[arg(long, value_name = "HEX")]
+        public_key: Option<String>,
+        /// 写入签名证明的验证者地址，未指定时使用公钥
+        #[arg(long)]
+        validator: Option<String>,
+        /// 签名证明输出文件，未指定时打印到标准输出
+        #[arg(short, long = "out", value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+    /// 离线校验签名证明：消息 ID 与规范哈希一致，且签名由指定公钥产生
+    VerifyMessage {
+        /// 配置文件路径，用于取得规范编码中的合约数字链 ID
+        #[arg(short, long, value_name = "FILE")]
+        config: PathBuf,
+        /// `sign-message` 输出的签名证明 JSON 文件
+        #[arg(long, value_name = "FILE")]
+        file: PathBuf,
+        /// 验证者公钥（十六进制）
+        #[arg(long, value_name = "HEX")]
+        pubkey: String,
+    },
+    /// Rust 与 Move 合约共用的签名测试向量
+    Vectors {
+        #[command(subcommand)]
+        command: VectorCommands,
+    },
+    /// 生成 shell 补全脚本，例如 `bridge-cli completions bash > /etc/bash_completion.d/bridge-cli`
+    Completions {
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
+}
+
+#[derive(Subcommand)]
+enum LedgerCommands {
+    /// 按资产与路由汇总记账分录，可导出 CSV 明细
+    Report {
+        /// 配置文件路径
+        #[arg(short, long, value_name = "FILE")]
+        config: PathBuf,
+        /// 只统计指定资产
+        #[arg(long)]
+        asset: Option<String>,
+        /// 路由，格式为 `源链:目标链`，可使用链 ID 或适配器类型
+        #[arg(long)]
+        route: Option<String>,
+        /// 统计窗口，例如 `30d`，未指定时统计全部分录
+        #[arg(long)]
+        window: Option<String>,
+        /// 将分录明细导出为 CSV 文件
+        #[arg(long, value_name = "FILE")]
+        csv: Option<PathBuf>,
+    },
+    /// 按链对比中继器的手续费收入与 gas 支出
+    Fees {
+        /// 配置文件路径
+        #[arg(short, long, value_name = "FILE")]
+        config: PathBuf,
+        /// 只统计指定链，链 ID 或唯一的适配器类型
+        #[arg(long)]
+        chain: Option<String>,
+        /// 统计窗口，例如 `30d`，未指定时统计全部分录
+        #[arg(long)]
+        window: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum AssetCommands {
+    /// 按资产配置生成元数据同步消息，在资产原生链的桥合约发出后由中继器在映射链登记包装代币
+    SyncMetadata {
+        /// 配置文件路径
+        #[arg(short, long, value_name = "FILE")]
+        config: PathBuf,
+        /// 资产名称
+        #[arg(long)]
+        asset: String,
+        /// 目标链，链 ID 或唯一的适配器类型，可重复；未指定时发往资产映射的全部链
+        #[arg(long = "target", value_name = "CHAIN")]
+        targets: Vec<String>,
+    },
+}
+
+/// 治理操作类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum GovernanceActionArg {
+    Pause,
+    Unpause,
+    SetFee,
+    UpdateLimits,
+    /// 以配置中的验证者替换目标链合约的验证者集合
+    UpdateValidatorSet,
+}
+
+#[derive(Subcommand)]
+enum GovernanceCommands {
+    /// 生成治理消息的负载，在源链的桥合约发出后由验证者按治理阈值签名
+    Propose {
+        /// 配置文件路径
+        #[arg(short, long, value_name = "FILE")]
+        config: PathBuf,
+        #[arg(long, value_enum)]
+        action: GovernanceActionArg,
+        /// 提案编号，目标链合约据此拒绝重复执行
+        #[arg(long)]
+        proposal_id: u64,
+        /// 执行治理操作的链，链 ID 或唯一的适配器类型，可重复
+        #[arg(long = "target", value_name = "CHAIN", required = true)]
+        targets: Vec<String>,
+        /// `set-fee` 与 `update-limits` 作用的资产
+        #[arg(long)]
+        asset: Option<String>,
+        /// `set-fee` 的费率（基点）
+        #[arg(long)]
+        fee_rate: Option<u64>,
+        /// `update-limits` 的单笔上限
+        #[arg(long)]
+        max_amount: Option<u64>,
+        /// `update-limits` 的每日上限
+        #[arg(long)]
+        daily_limit: Option<u64>,
+        /// `update-validator-set` 的新集合纪元
+        #[arg(long)]
+        epoch: Option<u64>,
+    },
+    /// 校验已保存的治理消息达到治理阈值后提交到目标链
+    Execute {
+        /// 配置文件路径
+        #[arg(short, long, value_name = "FILE")]
+        config: PathBuf,
+        /// 消息 ID
+        #[arg(long)]
+        message_id: String,
+    },
+}
+
+/// 按命令行参数构造治理操作
+fn governance_action(
+    config: &Config,
+    action: GovernanceActionArg,
+    asset: Option<String>,
+    fee_rate: Option<u64>,
+    max_amount: Option<u64>,
+    daily_limit: Option<u64>,
+    epoch: Option<u64>,
+) -> Result<BridgeAction> {
+    let known_asset = || -> Result<String> {
+        let asset = asset.clone().ok_or_else(|| anyhow::anyhow!("需要 --asset"))?;
+        if !config.assets.iter().any(|a| a.name == asset) {
+            anyhow::bail!("未知的资产: {}", asset);
+        }
+        Ok(asset)
+    };
+    let required = |value: Option<u64>, name: &str| value.ok_or_else(|| anyhow::anyhow!("需要 --{}", name));
+    Ok(match action {
+        GovernanceActionArg::Pause => BridgeAction::Pause,
+        GovernanceActionArg::Unpause => BridgeAction::Unpause,
+        GovernanceActionArg::SetFee => BridgeAction::SetFee { asset: known_asset()?, fee_rate: required(fee_rate, "fee-rate")? },
+        GovernanceActionArg::UpdateLimits => BridgeAction::UpdateLimits {
+            asset: known_asset()?,
+            max_amount: required(max_amount, "max-amount")?,
+            daily_limit: required(daily_limit, "daily-limit")?,
+        },
+        GovernanceActionArg::UpdateValidatorSet => BridgeAction::UpdateValidatorSet(ValidatorSetUpdate {
+            epoch: required(epoch, "epoch")?,
+            validators: config.validators.iter()
+                .map(|v| ValidatorWeight { address: v.address.clone(), weight: v.weight })
+                .collect(),
+        }),
+    })
+}
+
+#[derive(Subcommand)]
+enum StateCommands {
+    /// 删除进入终态早于指定时间的消息及其处理历史，记账分录保留
+    Prune {
+        /// 配置文件路径
+        #[arg(short, long, value_name = "FILE")]
+        config: PathBuf,
+        /// 时间界限：RFC 3339 时间、`YYYY-MM-DD`（UTC 零点）或距今的时长（例如 `90d`）
+        #[arg(long, value_name = "DATE")]
+        before: String,
+        /// 参与清理的终态，可重复指定；默认取配置中的保留策略，未配置时为 processed 与 expired
+        #[arg(long)]
+        status: Vec<String>,
+        /// 只报告将要删除的消息，不实际删除
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+/// 解析清理的时间界限，返回 Unix 时间（秒）
+fn parse_before(before: &str) -> Result<u64> {
+    let now = std::time::SystemTime::now();
+    let time = if let Ok(duration) = humantime::parse_duration(before) {
+        now.checked_sub(duration).ok_or_else(|| anyhow::anyhow!("时长超出范围: {}", before))?
+    } else if before.len() == 10 {
+        humantime::parse_rfc3339(&format!("{}T00:00:00Z", before))?
+    } else {
+        humantime::parse_rfc3339_weak(before)?
+    };
+    Ok(time.duration_since(std::time::UNIX_EPOCH)?.as_secs())
+}
+
+#[derive(Subcommand)]
+enum BootstrapCommands {
+    /// 从消息存储导出签名的引导包
+    Export {
+        /// 配置文件路径
+        #[arg(short, long, value_name = "FILE")]
+        config: PathBuf,
+        /// 引导包输出路径
+        #[arg(short, long = "out", value_name = "FILE")]
+        output: PathBuf,
+    },
+    /// 校验引导包签名及其与本地配置的一致性
+    Verify {
+        /// 配置文件路径
+        #[arg(short, long, value_name = "FILE")]
+        config: PathBuf,
+        /// 引导包路径
+        #[arg(short, long, value_name = "FILE")]
+        bundle: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+enum AuditCommands {
+    /// 校验审计日志的哈希链与签名，检测篡改
+    Verify {
+        /// 审计日志路径
+        #[arg(short, long, value_name = "FILE")]
+        log: PathBuf,
+        /// 预期的签名公钥（十六进制），未指定时只校验签名自洽
+        #[arg(long)]
+        public_key: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfidentialCommands {
+    /// 生成目标合约的 X25519 负载密钥，公钥填入链配置的 `payload_public_key`
+    Keygen {
+        /// 私钥输出路径
+        #[arg(short, long = "out", value_name = "FILE")]
+        output: PathBuf,
+    },
+    /// 将负载加密到目标合约的公钥
+    Seal {
+        /// 目标合约的 X25519 公钥（十六进制）
+        #[arg(long)]
+        public_key: String,
+        /// 路由，格式为 `源链:目标链`，需与消息的链 ID 一致
+        #[arg(long)]
+        route: String,
+        /// 十六进制编码的明文负载
+        #[arg(long)]
+        payload: String,
+    },
+    /// 使用负载私钥解密信封
+    Open {
+        /// 私钥文件路径
+        #[arg(short, long, value_name = "FILE")]
+        key: PathBuf,
+        /// 路由，格式为 `源链:目标链`，需与消息的链 ID 一致
+        #[arg(long)]
+        route: String,
+        /// 十六进制编码的加密信封
+        #[arg(long)]
+        envelope: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum KeyCommands {
+    /// 生成新的验证者密钥，用旧密钥签署轮换声明并提交到中继器；旧密钥在宽限期内仍然有效
+    Rotate {
+        /// 配置文件路径，新私钥追加到其中的密钥库文件
+        #[arg(short, long, value_name = "FILE")]
+        config: PathBuf,
+        /// 轮换密钥的验证者地址
+        #[arg(long)]
+        validator: String,
+        /// 接收轮换声明的中继器 HTTP 接口地址，可重复
+        #[arg(long = "api", value_name = "URL", default_value = "http://127.0.0.1:8080")]
+        apis: Vec<String>,
+        /// 旧密钥继续有效的宽限期，例如 `24h`
+        #[arg(long, default_value = "24h")]
+        grace: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum HeldCommands {
+    /// 列出暂缓队列中的消息
+    List {
+        /// 配置文件路径
+        #[arg(short, long, value_name = "FILE")]
+        config: PathBuf,
+    },
+    /// 人工放行暂缓的消息，中继器下一轮跳过其未通过的检查重新处理
+    Release {
+        /// 消息 ID
+        message_id: String,
+        /// 中继器 HTTP 接口地址
+        #[arg(long, value_name = "URL", default_value = "http://127.0.0.1:8080")]
+        api: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum ReportCommands {
+    /// 计算路由的交付成功率、延迟分位数和中断窗口
+    Sla {
+        /// 配置文件路径
+        #[arg(short, long, value_name = "FILE")]
+        config: PathBuf,
+        /// 路由，格式为 `源链:目标链`，可使用链 ID 或适配器类型
+        #[arg(long)]
+        route: String,
+        /// 统计窗口，例如 `7d`、`24h`
+        #[arg(long, default_value = "7d")]
+        window: String,
+    },
+    /// 列出因中继前检查未通过而暂缓的消息
+    Held {
+        /// 配置文件路径
+        #[arg(short, long, value_name = "FILE")]
+        config: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+enum VectorCommands {
+    /// 用固定种子生成消息编码、哈希、ed25519 签名与 BLS 聚合证明
+    Generate {
+        /// 向量 JSON 文件，未指定时打印到标准输出
+        #[arg(short, long = "out", value_name = "FILE")]
+        output: Option<PathBuf>,
+        /// 同时生成消费这些向量的 Move 测试模块，例如 `packages/sui_bridge/tests/vector_tests.move`
+        #[arg(long = "move", value_name = "FILE")]
+        move_tests: Option<PathBuf>,
+    },
+    /// 校验向量文件，例如 Move 测试输出的 `tests/vectors/move.json`
+    Verify {
+        /// 向量 JSON 文件
+        file: PathBuf,
     },
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    // 初始化日志
-    tracing_subscriber::fmt::init();
-
     let cli = Cli::parse();
+    let format = cli.format;
+
+    // 初始化日志；JSON 模式下日志写入标准错误，标准输出只包含结果
+    bridge_core::logging::init(&cli.logging(), format.is_json())?;
 
-    match cli.command {
-        Commands::ValidateConfig { config } => {
+    let result = run(cli.command, format).await;
+    if let Err(e) = &result {
+        if format.is_json() {
+            print_json(&json!({ "error": format!("{:#}", e) }))?;
+            std::process::exit(1);
+        }
+    }
+    result
+}
+
+impl Commands {
+    /// 长时间运行的服务命令使用的配置文件
+    fn service_config(&self) -> Option<&PathBuf> {
+        match self {
+            Commands::Start { config, .. }
+            | Commands::Watch { config }
+            | Commands::Index { config }
+            | Commands::Validate { config, .. } => Some(config),
+            _ => None,
+        }
+    }
+}
+
+async fn run(command: Commands, format: OutputFormat) -> Result<()> {
+    match command {
+        Commands::ValidateConfig { config, check_remote } => {
             info!("正在验证配置文件: {:?}", config);
             let config = Config::load(config)?;
+            let remote = if check_remote {
+                Some(remote::check_remote(&config, &DefaultChainAdapterFactory::new(&config)).await)
+            } else {
+                None
+            };
+            let failed: Vec<String> = remote
+                .iter()
+                .flatten()
+                .filter(|check| !check.is_ok())
+                .map(|check| check.chain_id.to_string())
+                .collect();
+            if format.is_json() {
+                print_json(&json!({
+                    "valid": failed.is_empty(),
+                    "chains": config.chains.iter().map(|chain| json!({
+                        "name": chain.name,
+                        "id": chain.id,
+                        "adapter_type": chain.adapter_type,
+                    })).collect::<Vec<_>>(),
+                    "assets": config.assets.iter().map(|asset| json!({
+                        "name": asset.name,
+                        "native_chain": asset.native_chain,
+                        "mappings": asset.mappings,
+                    })).collect::<Vec<_>>(),
+                    "nft_collections": config.nft_collections.iter().map(|collection| json!({
+                        "name": collection.name,
+                        "native_chain": collection.native_chain,
+                        "mappings": collection.mappings,
+                    })).collect::<Vec<_>>(),
+                    "validators": config.validators.len(),
+                    "relayer": {
+                        "poll_interval": config.relayer.poll_interval,
+                        "max_retries": config.relayer.max_retries,
+                        "retry_delay": config.relayer.retry_delay,
+                    },
+                    "remote": remote,
+                }))?;
+                if !failed.is_empty() {
+                    std::process::exit(1);
+                }
+                return Ok(());
+            }
             println!("配置文件验证成功!");
             println!("配置内容概要:");
             println!("- 支持的链:");
@@ -52,22 +816,76 @@ async fn main() -> Result<()> {
                     println!("    - {}: {}", chain_id, mapping);
                 }
             }
+            if !config.nft_collections.is_empty() {
+                println!("- 支持的 NFT 集合:");
+                for collection in &config.nft_collections {
+                    println!("  - {} (原生链: {})", collection.name, collection.native_chain);
+                    for (chain_id, mapping) in &collection.mappings {
+                        println!("    - {}: {}", chain_id, mapping);
+                    }
+                }
+            }
             println!("- 验证者数量: {}", config.validators.len());
             println!("- 中继器配置:");
             println!("  - 轮询间隔: {}秒", config.relayer.poll_interval);
             println!("  - 最大重试次数: {}", config.relayer.max_retries);
             println!("  - 重试延迟: {}秒", config.relayer.retry_delay);
+            if let Some(checks) = &remote {
+                println!("- 远程检查:");
+                for check in checks {
+                    match (&check.error, &check.contract) {
+                        (Some(error), _) => println!("  - {}: 失败: {}", check.chain_id, error),
+                        (None, Some(contract)) if !contract.exists => {
+                            println!("  - {}: 桥合约不存在", check.chain_id);
+                        }
+                        (None, Some(contract)) if !contract.missing_events.is_empty() => {
+                            println!("  - {}: 合约未定义事件类型: {}", check.chain_id, contract.missing_events.join(", "));
+                        }
+                        _ => println!("  - {}: 正常 (节点版本 {})", check.chain_id, check.node_version.as_deref().unwrap_or("未知")),
+                    }
+                }
+            }
+            if !failed.is_empty() {
+                anyhow::bail!("远程检查未通过: {}", failed.join(", "));
+            }
             Ok(())
         }
-        Commands::Start { config } => {
+        Commands::Init { output, chains, assets, mappings, validators, keystore, api, example, force } => {
+            init::run(init::InitOptions { output, chains, assets, mappings, validators, keystore, api, example, force })
+        }
+        Commands::Start { config, bootstrap, force } => {
             info!("正在启动中继器服务");
             info!("使用配置文件: {:?}", config);
             
             // 加载配置
             let config = Config::load(config)?;
             
+            // 启动自检
+            let report = selfcheck::run(&config, &DefaultChainAdapterFactory::new(&config)).await;
+            if format.is_json() {
+                print_json(&report)?;
+            } else {
+                println!("启动自检:");
+                print!("{}", report);
+            }
+            let failed: Vec<String> = report
+                .critical_failures()
+                .iter()
+                .map(|check| format!("{} {}", check.check, check.subject))
+                .collect();
+            if !failed.is_empty() {
+                if !force {
+                    anyhow::bail!("启动自检未通过: {}", failed.join(", "));
+                }
+                warn!("启动自检未通过，已使用 --force 强制启动: {}", failed.join(", "));
+            }
+            
             // 创建并启动中继器
-            let relayer = Relayer::new(config).await?;
+            let mut relayer = Relayer::new(config).await?;
+            if let Some(path) = bootstrap {
+                info!("正在从引导包恢复状态: {:?}", path);
+                relayer.restore(&SignedBootstrapBundle::load(path)?)?;
+            }
             info!("中继器初始化成功，开始运行...");
             
             // 启动中继器服务
@@ -78,5 +896,943 @@ async fn main() -> Result<()> {
             
             Ok(())
         }
+        Commands::Promote { config, instance } => {
+            let config = Config::load(config)?;
+            let coordination = config.relayer.coordination.as_ref()
+                .ok_or_else(|| anyhow::anyhow!("配置中未启用 relayer.coordination"))?;
+            let storage_config = config.storage.as_ref()
+                .ok_or_else(|| anyhow::anyhow!("配置中未启用消息存储"))?;
+            let instance = instance
+                .or_else(|| coordination.instance_id.clone())
+                .ok_or_else(|| anyhow::anyhow!("需要 --instance 或配置 relayer.coordination.instance_id"))?;
+
+            let store = storage::open(storage_config).await?;
+            let expires_at = leader::request_promotion(store.as_ref(), &instance, coordination).await?;
+            if format.is_json() {
+                return print_json(&json!({ "instance": instance, "expires_at": expires_at }));
+            }
+            println!("已请求将领导者租约移交给实例 {}", instance);
+            println!("- 当前领导者在下次续期（最长 {} 秒）时让出租约", (coordination.lease_ttl / 3).max(1));
+            println!("- 请求在 {} 前未完成移交时失效", expires_at);
+            Ok(())
+        }
+        Commands::Validate { config, collector } => {
+            info!("正在启动验证者证明服务");
+            let config = Config::load(config)?;
+            let service = AttestationService::new(config, collector.as_deref()).await?;
+            info!("验证者证明服务初始化成功，开始运行...");
+
+            if let Err(e) = service.run().await {
+                error!("验证者证明服务异常退出: {}", e);
+                return Err(e.into());
+            }
+
+            Ok(())
+        }
+        Commands::Watch { config } => {
+            info!("正在启动观察者服务");
+            let config = Config::load(config)?;
+            let watchtower = Watchtower::new(config).await?;
+            info!("观察者服务初始化成功，开始运行...");
+
+            if let Err(e) = watchtower.run().await {
+                error!("观察者服务异常退出: {}", e);
+                return Err(e.into());
+            }
+
+            Ok(())
+        }
+        Commands::Index { config } => {
+            info!("正在启动索引器");
+            let config = Config::load(config)?;
+            let indexer = Indexer::new(config).await?;
+            info!("索引器初始化成功，开始运行...");
+
+            if let Err(e) = indexer.run().await {
+                error!("索引器异常退出: {}", e);
+                return Err(e.into());
+            }
+
+            Ok(())
+        }
+        Commands::Challenge { config, submit } => {
+            let config = Config::load(config)?;
+            let mut watchtower = Watchtower::new(config).await?;
+            let mut challenges = Vec::new();
+            for divergence in watchtower.poll().await {
+                if let Some(challenge) = watchtower.challenge(&divergence).await {
+                    challenges.push(challenge);
+                }
+            }
+
+            let mut results = Vec::new();
+            if submit {
+                for challenge in &challenges {
+                    results.push(watchtower.submit_challenge(challenge).await.map_err(|e| e.to_string()));
+                }
+            }
+
+            if format.is_json() {
+                let submitted: Vec<_> = results
+                    .iter()
+                    .map(|result| match result {
+                        Ok(tx_hash) => json!({ "tx_hash": tx_hash }),
+                        Err(e) => json!({ "error": e }),
+                    })
+                    .collect();
+                return print_json(&json!({ "challenges": challenges, "submitted": submitted }));
+            }
+            if challenges.is_empty() {
+                println!("未发现可挑战的交付");
+                return Ok(());
+            }
+            for (index, challenge) in challenges.iter().enumerate() {
+                let divergence = &challenge.divergence;
+                println!(
+                    "- {:?}: {} -> {}, nonce {:?}, 交付 {}, 消息 {}",
+                    divergence.kind,
+                    divergence.source_chain,
+                    divergence.target_chain,
+                    divergence.nonce,
+                    divergence.delivery.as_deref().unwrap_or("-"),
+                    divergence.message_id.as_deref().unwrap_or("-")
+                );
+                match results.get(index) {
+                    Some(Ok(tx_hash)) => println!("  已提交挑战: {}", tx_hash),
+                    Some(Err(e)) => println!("  提交挑战失败: {}", e),
+                    None => {}
+                }
+            }
+            if !submit {
+                println!("使用 --submit 向目标链提交挑战");
+            }
+            Ok(())
+        }
+        Commands::Version { verbose, config } => {
+            let config = config.map(Config::load).transpose()?;
+            let info = BuildInfo::current(config.as_ref())?;
+            if format.is_json() {
+                return print_json(&info);
+            }
+            println!("bridge-cli {}", info.version);
+            if !verbose {
+                return Ok(());
+            }
+
+            println!("- Git 提交: {}", info.git_commit);
+            println!("- 启用的 feature: {}", if info.features.is_empty() { "无".to_string() } else { info.features.join(", ") });
+            println!("- 支持的适配器类型: {}", info.adapter_types.join(", "));
+            println!(
+                "- 消息格式版本: {}",
+                info.message_format_versions.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(", ")
+            );
+            if let Some(fingerprint) = &info.config_fingerprint {
+                println!("- 配置指纹: {}", fingerprint);
+            }
+            Ok(())
+        }
+        Commands::Ledger { command: LedgerCommands::Report { config, asset, route, window, csv } } => {
+            let config = Config::load(config)?;
+            let storage_config = config.storage.as_ref()
+                .ok_or_else(|| anyhow::anyhow!("配置中未启用消息存储"))?;
+
+            let mut query = LedgerQuery { asset, ..Default::default() };
+            if let Some(route) = &route {
+                let (source, target) = route
+                    .split_once(':')
+                    .ok_or_else(|| anyhow::anyhow!("路由格式应为 `源链:目标链`: {}", route))?;
+                query.source_chain = Some(config.resolve_chain(source)
+                    .ok_or_else(|| anyhow::anyhow!("未知的链: {}", source))?.id.to_string());
+                query.target_chain = Some(config.resolve_chain(target)
+                    .ok_or_else(|| anyhow::anyhow!("未知的链: {}", target))?.id.to_string());
+            }
+            if let Some(window) = &window {
+                let window = humantime::parse_duration(window)?;
+                let now = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)?
+                    .as_secs();
+                query.since = Some(now.saturating_sub(window.as_secs()));
+            }
+
+            let store = storage::open(storage_config).await?;
+            let entries = store.query_ledger(&query).await?;
+            let summaries = ledger::summarize(&entries);
+            if let Some(path) = &csv {
+                let file = std::fs::File::create(path)?;
+                ledger::write_csv(&entries, std::io::BufWriter::new(file))?;
+            }
+            if format.is_json() {
+                return print_json(&json!({
+                    "entries": entries.len(),
+                    "summaries": summaries.iter().map(|summary| json!({
+                        "summary": summary,
+                        "balanced": summary.is_balanced(),
+                    })).collect::<Vec<_>>(),
+                    "csv": csv,
+                }));
+            }
+
+            println!("资产记账报表 (分录 {} 条)", entries.len());
+            for summary in &summaries {
+                println!("- {} {} -> {}{}", summary.asset, summary.source_chain, summary.target_chain,
+                    if summary.is_balanced() { "" } else { " [借贷不平衡]" });
+                println!("  - 锁定: {}, 销毁: {}", summary.amount(LedgerAccount::Lock), summary.amount(LedgerAccount::Burn));
+                println!("  - 铸造: {}, 解锁: {}", summary.amount(LedgerAccount::Mint), summary.amount(LedgerAccount::Unlock));
+                println!("  - 手续费: {}, 退款: {}", summary.amount(LedgerAccount::Fee), summary.amount(LedgerAccount::Refund));
+                println!("  - 中继器手续费: {}, gas: {}", summary.amount(LedgerAccount::RelayerFee), summary.amount(LedgerAccount::Gas));
+                println!("  - 在途: {}, 尾差: {}", summary.amount(LedgerAccount::InTransit), summary.amount(LedgerAccount::Dust));
+                println!("  - 借方合计: {}, 贷方合计: {}", summary.total_debit, summary.total_credit);
+            }
+
+            if let Some(path) = csv {
+                println!("分录明细已导出到 {:?}", path);
+            }
+            Ok(())
+        }
+        Commands::Ledger { command: LedgerCommands::Fees { config, chain, window } } => {
+            let config = Config::load(config)?;
+            let storage_config = config.storage.as_ref()
+                .ok_or_else(|| anyhow::anyhow!("配置中未启用消息存储"))?;
+            let chain = chain
+                .map(|chain| config.resolve_chain(&chain).map(|c| c.id.to_string()).ok_or_else(|| anyhow::anyhow!("未知的链: {}", chain)))
+                .transpose()?;
+
+            let mut query = LedgerQuery::default();
+            if let Some(window) = &window {
+                let window = humantime::parse_duration(window)?;
+                let now = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)?
+                    .as_secs();
+                query.since = Some(now.saturating_sub(window.as_secs()));
+            }
+            let store = storage::open(storage_config).await?;
+            let report: Vec<_> = ledger::fees::fee_report(&store.query_ledger(&query).await?)
+                .into_iter()
+                .filter(|fees| chain.as_ref().is_none_or(|c| &fees.chain == c))
+                .collect();
+            if format.is_json() {
+                return print_json(&report);
+            }
+
+            println!("中继器收支 (gas 以各链 gas 代币最小单位计，手续费以源链资产单位计)");
+            if report.is_empty() {
+                println!("暂无 gas 或中继器手续费记录");
+            }
+            for fees in &report {
+                println!("- {}: 提交 {} 条消息", fees.chain, fees.submitted);
+                println!("  - gas 支出: {} (平均每条 {})", fees.gas_spent, fees.gas_per_message());
+                if fees.gas_refunded > 0 {
+                    println!("  - gas 报销: {} (净支出 {})", fees.gas_refunded, fees.net_gas());
+                }
+                if fees.fees_earned.is_empty() {
+                    println!("  - 手续费收入: 无");
+                }
+                for (asset, amount) in &fees.fees_earned {
+                    println!("  - 手续费收入: {} {}", amount, asset);
+                }
+            }
+            Ok(())
+        }
+        Commands::Bootstrap { command: BootstrapCommands::Export { config, output } } => {
+            let config = Config::load(config)?;
+            let bundle = bootstrap::export(&config).await?;
+            bundle.save(&output)?;
+            if format.is_json() {
+                return print_json(&json!({
+                    "output": output,
+                    "signer": bundle.signer,
+                    "validator_set_epoch": bundle.bundle.validator_set_epoch,
+                    "cursors": bundle.bundle.cursors,
+                }));
+            }
+            println!("引导包已导出到 {:?}", output);
+            println!("- 签名验证者: {}", bundle.signer);
+            println!("- 验证者集合纪元: {}", bundle.bundle.validator_set_epoch);
+            for (chain, cursor) in &bundle.bundle.cursors {
+                println!("- {}: 游标 {}", chain, cursor);
+            }
+            Ok(())
+        }
+        Commands::Bootstrap { command: BootstrapCommands::Verify { config, bundle } } => {
+            let config = Config::load(config)?;
+            let signed = SignedBootstrapBundle::load(bundle)?;
+            let bundle = signed.verify(&config)?;
+            if format.is_json() {
+                return print_json(&json!({ "valid": true, "signer": signed.signer, "bundle": bundle }));
+            }
+            println!("引导包校验通过!");
+            println!("- 签名验证者: {}", signed.signer);
+            println!("- 创建时间: {}", bundle.created_at);
+            println!("- 验证者集合纪元: {}", bundle.validator_set_epoch);
+            for (chain, cursor) in &bundle.cursors {
+                println!("- {}: 游标 {}", chain, cursor);
+            }
+            Ok(())
+        }
+        Commands::Report { command: ReportCommands::Sla { config, route, window } } => {
+            let config = Config::load(config)?;
+            let (source, target) = route
+                .split_once(':')
+                .ok_or_else(|| anyhow::anyhow!("路由格式应为 `源链:目标链`: {}", route))?;
+            let source_chain = config.resolve_chain(source)
+                .ok_or_else(|| anyhow::anyhow!("未知的链: {}", source))?;
+            let target_chain = config.resolve_chain(target)
+                .ok_or_else(|| anyhow::anyhow!("未知的链: {}", target))?;
+            let storage_config = config.storage.as_ref()
+                .ok_or_else(|| anyhow::anyhow!("配置中未启用消息存储"))?;
+
+            let window = humantime::parse_duration(&window)?;
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)?
+                .as_secs();
+            let window_start = now.saturating_sub(window.as_secs());
+
+            let store = storage::open(storage_config).await?;
+            let records = store.query(&MessageQuery {
+                source_chain: Some(source_chain.id.to_string()),
+                target_chain: Some(target_chain.id.to_string()),
+                since: Some(window_start),
+                ..Default::default()
+            }).await?;
+            let report = SlaReport::compute(&source_chain.id, &target_chain.id, window_start, now, &records);
+            if format.is_json() {
+                return print_json(&report);
+            }
+
+            println!("路由 {} -> {} SLA 报表", report.source_chain, report.target_chain);
+            println!("- 统计窗口: {} ~ {}", report.window_start, report.window_end);
+            println!("- 消息总数: {} (成功 {}, 失败 {}, 处理中 {})", report.total, report.delivered, report.failed, report.pending);
+            println!("- 交付成功率: {:.2}%", report.success_rate * 100.0);
+            match (report.latency_p50, report.latency_p95) {
+                (Some(p50), Some(p95)) => println!("- 交付延迟: p50 {}秒, p95 {}秒", p50, p95),
+                _ => println!("- 交付延迟: 无数据"),
+            }
+            println!("- 中断窗口: {}", report.downtime.len());
+            for window in &report.downtime {
+                match window.end {
+                    Some(end) => println!("  - {} ~ {} ({}秒)", window.start, end, end - window.start),
+                    None => println!("  - {} ~ 至今", window.start),
+                }
+            }
+            Ok(())
+        }
+        Commands::Messages { config, status, route, chain, sender, window, limit, columns } => {
+            let config = Config::load(config)?;
+            let storage_config = config.storage.as_ref()
+                .ok_or_else(|| anyhow::anyhow!("配置中未启用消息存储"))?;
+
+            let mut query = MessageQuery {
+                status: status.as_deref().map(str::parse::<MessageStatus>).transpose()?,
+                sender,
+                limit: Some(limit),
+                ..Default::default()
+            };
+            if let Some(route) = route {
+                let (source, target) = route
+                    .split_once(':')
+                    .ok_or_else(|| anyhow::anyhow!("路由格式应为 `源链:目标链`: {}", route))?;
+                query.source_chain = Some(config.resolve_chain(source)
+                    .ok_or_else(|| anyhow::anyhow!("未知的链: {}", source))?.id.to_string());
+                query.target_chain = Some(config.resolve_chain(target)
+                    .ok_or_else(|| anyhow::anyhow!("未知的链: {}", target))?.id.to_string());
+            }
+            if let Some(chain) = chain {
+                query.chain = Some(config.resolve_chain(&chain)
+                    .ok_or_else(|| anyhow::anyhow!("未知的链: {}", chain))?.id.to_string());
+            }
+            if let Some(window) = window {
+                let now = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)?
+                    .as_secs();
+                query.since = Some(now.saturating_sub(humantime::parse_duration(&window)?.as_secs()));
+            }
+
+            let store = storage::open(storage_config).await?;
+            let records = store.query(&query).await?;
+            if format.is_json() {
+                if columns.is_empty() {
+                    return print_json(&records);
+                }
+                return print_json(&messages::select(&records, &columns));
+            }
+            let columns = if columns.is_empty() { messages::DEFAULT_COLUMNS } else { &columns };
+            println!("消息数: {}", records.len());
+            if !records.is_empty() {
+                print!("{}", messages::render_table(&records, columns));
+            }
+            Ok(())
+        }
+        Commands::State { command: StateCommands::Prune { config, before, status, dry_run } } => {
+            let config = Config::load(config)?;
+            let storage_config = config.storage.as_ref()
+                .ok_or_else(|| anyhow::anyhow!("配置中未启用消息存储"))?;
+            let statuses = if status.is_empty() {
+                storage_config.retention.as_ref()
+                    .map(|retention| retention.statuses.clone())
+                    .unwrap_or_else(|| retention::DEFAULT_STATUSES.to_vec())
+            } else {
+                status.iter().map(|s| s.parse::<MessageStatus>()).collect::<Result<_, _>>()?
+            };
+            if let Some(status) = statuses.iter().find(|s| !s.is_terminal()) {
+                anyhow::bail!("只能清理终态的消息: {:?}", status);
+            }
+
+            let criteria = retention::PruneCriteria { before: parse_before(&before)?, statuses };
+            let store = storage::open(storage_config).await?;
+            let report = retention::prune(store.as_ref(), &criteria, dry_run).await?;
+            if format.is_json() {
+                return print_json(&report);
+            }
+            println!(
+                "{} {} 条消息 (终态早于 {})",
+                if dry_run { "将删除" } else { "已删除" },
+                report.messages,
+                report.before
+            );
+            if let (Some(oldest), Some(newest)) = (report.oldest, report.newest) {
+                println!("- 终态时间范围: {} ~ {}", oldest, newest);
+            }
+            for (status, count) in &report.by_status {
+                println!("- 状态 {}: {}", status, count);
+            }
+            for (route, count) in &report.by_route {
+                println!("- 路由 {}: {}", route, count);
+            }
+            Ok(())
+        }
+        Commands::Trace { config, message_id, output, offline } => {
+            let config = Config::load(config)?;
+            let storage_config = config.storage.as_ref()
+                .ok_or_else(|| anyhow::anyhow!("配置中未启用消息存储"))?;
+            let store = storage::open(storage_config).await?;
+
+            let message_id = message_id.trim_start_matches("0x").to_lowercase();
+            let trace = trace::collect(&config, store.as_ref(), &message_id, !offline).await?;
+            let report = serde_json::to_string_pretty(&trace)?;
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, report)?;
+                    let failed = trace.checks.iter().filter(|c| !c.passed).count();
+                    if format.is_json() {
+                        return print_json(&json!({ "output": path, "failed_checks": failed }));
+                    }
+                    println!("排查报告已导出到 {:?} ({} 项检查未通过)", path, failed);
+                }
+                None => println!("{}", report),
+            }
+            Ok(())
+        }
+        Commands::Replay { config, chain, from_checkpoint, to_checkpoint, relay } => {
+            let config = Config::load(config)?;
+            let storage_config = config.storage.as_ref()
+                .ok_or_else(|| anyhow::anyhow!("配置中未启用消息存储"))?;
+            let chain_id = config.resolve_chain(&chain)
+                .ok_or_else(|| anyhow::anyhow!("未知的链: {}", chain))?
+                .id
+                .clone();
+
+            let store = storage::open(storage_config).await?;
+            let factory = DefaultChainAdapterFactory::new(&config);
+            let report = replay::scan(&config, &factory, store.as_ref(), &chain_id, from_checkpoint, to_checkpoint).await?;
+            drop(store);
+            let undelivered: Vec<_> = report.undelivered().into_iter().map(|m| m.message.clone()).collect();
+
+            // 重新中继的消息经过与运行中的中继器相同的检查，仍在等待的消息由中继器后续处理
+            let still_pending = if relay && !undelivered.is_empty() {
+                let relayer = Relayer::new(config).await?;
+                let mut state = relayer.relay_state();
+                Some(relayer.replay(&mut state, &chain_id, undelivered).await?)
+            } else {
+                None
+            };
+
+            if format.is_json() {
+                return print_json(&json!({ "report": report, "still_pending": still_pending }));
+            }
+            println!(
+                "{} 区间 {}..={}: 消息 {} 条，漏读 {} 条，未交付 {} 条",
+                report.chain,
+                report.from,
+                report.to,
+                report.messages.len(),
+                report.missed(),
+                report.undelivered().len()
+            );
+            for message in report.undelivered() {
+                let stored = message.stored.as_ref().map_or("未记录".to_string(), |s| format!("{:?}", s));
+                let on_chain = message.on_chain.as_ref().map_or("未知".to_string(), |s| format!("{:?}", s));
+                println!(
+                    "- {} -> {}, nonce {}, 存储状态 {}, 链上状态 {}",
+                    message.message_id, message.target_chain, message.nonce, stored, on_chain
+                );
+            }
+            match still_pending {
+                Some(pending) if pending.is_empty() => println!("已重新中继全部未交付消息"),
+                Some(pending) => println!("已重新中继，{} 条消息仍在等待（证明、时间锁或提交窗口）", pending.len()),
+                None if !relay && !report.undelivered().is_empty() => println!("使用 --relay 重新中继未交付的消息"),
+                None => {}
+            }
+            Ok(())
+        }
+        Commands::Codegen { config, chain, package, output } => {
+            let config = Config::load(config)?;
+            let mut chain_config = config.resolve_chain(&chain)
+                .ok_or_else(|| anyhow::anyhow!("未知的链: {}", chain))?
+                .clone();
+            if let Some(package) = package {
+                chain_config.bridge_address = package;
+            }
+            let adapter = DefaultChainAdapterFactory::new(&config).create_adapter(&chain_config).await?;
+            let modules = adapter.move_modules().await;
+            adapter.close().await?;
+            let modules = modules?;
+            let code = codegen::generate(&chain_config.bridge_address, &modules);
+            let module_names: Vec<&str> = modules.iter().map(|m| m.name.as_str()).collect();
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, code)?;
+                    if format.is_json() {
+                        return print_json(&json!({ "output": path, "package": chain_config.bridge_address, "modules": module_names }));
+                    }
+                    println!("已根据 {} 的模块 {} 生成 {:?}", chain_config.bridge_address, module_names.join(", "), path);
+                }
+                None if format.is_json() => {
+                    return print_json(&json!({ "package": chain_config.bridge_address, "modules": module_names, "code": code }));
+                }
+                None => print!("{}", code),
+            }
+            Ok(())
+        }
+        Commands::Assets { command: AssetCommands::SyncMetadata { config, asset, targets } } => {
+            let config = Config::load(config)?;
+            let asset = config.assets.iter()
+                .find(|a| a.name == asset)
+                .ok_or_else(|| anyhow::anyhow!("未知的资产: {}", asset))?;
+            let targets = if targets.is_empty() {
+                let mut chains: Vec<String> = asset.mappings.keys().cloned().collect();
+                chains.sort();
+                chains
+            } else {
+                targets.iter()
+                    .map(|t| config.resolve_chain(t).map(|c| c.id.to_string()).ok_or_else(|| anyhow::anyhow!("未知的链: {}", t)))
+                    .collect::<Result<_>>()?
+            };
+            let mut messages = Vec::with_capacity(targets.len());
+            for target in &targets {
+                let metadata = MetadataSync::from_asset(asset, target)?;
+                messages.push(json!({
+                    "source_chain": asset.native_chain,
+                    "target_chain": target,
+                    "message_type": METADATA_SYNC_MESSAGE_TYPE,
+                    "payload": format!("0x{}", hex::encode(metadata.encode()?)),
+                    "metadata": metadata,
+                }));
+            }
+            if format.is_json() {
+                return print_json(&messages);
+            }
+            println!("在 {} 的桥合约发出以下 {} 消息:", asset.native_chain, METADATA_SYNC_MESSAGE_TYPE);
+            for message in &messages {
+                let field = |name: &str| message[name].as_str().unwrap_or_default().to_string();
+                println!("  {} ({} 位精度): {}", field("target_chain"), message["metadata"]["decimals"], field("payload"));
+            }
+            Ok(())
+        }
+        Commands::Governance { command: GovernanceCommands::Propose {
+            config, action, proposal_id, targets, asset, fee_rate, max_amount, daily_limit, epoch,
+        } } => {
+            let config = Config::load(config)?;
+            let action = governance_action(&config, action, asset, fee_rate, max_amount, daily_limit, epoch)?;
+            let governance = Governance { proposal_id, action };
+            // 编码时按目标链合约的解码规则再校验一次
+            let payload = governance.encode()?;
+            Governance::decode(&payload)?;
+            let targets = targets.iter()
+                .map(|t| config.resolve_chain(t).map(|c| c.id.to_string()).ok_or_else(|| anyhow::anyhow!("未知的链: {}", t)))
+                .collect::<Result<Vec<_>>>()?;
+            let payload = format!("0x{}", hex::encode(payload));
+            if format.is_json() {
+                return print_json(&json!({
+                    "message_type": GOVERNANCE_MESSAGE_TYPE,
+                    "target_chains": targets,
+                    "payload": payload,
+                    "governance": governance,
+                }));
+            }
+            println!("治理提案 {}: {:?}", proposal_id, governance.action);
+            println!("在源链的桥合约向 {} 发出 {} 消息，负载: {}", targets.join(", "), GOVERNANCE_MESSAGE_TYPE, payload);
+            Ok(())
+        }
+        Commands::Governance { command: GovernanceCommands::Execute { config, message_id } } => {
+            let config = Config::load(config)?;
+            let storage_config = config.storage.as_ref()
+                .ok_or_else(|| anyhow::anyhow!("配置中未启用消息存储"))?;
+            let store = storage::open(storage_config).await?;
+            let message_id = message_id.trim_start_matches("0x").to_lowercase();
+            let source = store.source(&message_id).await?
+                .ok_or_else(|| anyhow::anyhow!("未记录消息 {} 的源链事件", message_id))?;
+            if !is_governance(&source.message.message_type) {
+                anyhow::bail!("消息 {} 的类型为 {}，不是治理消息", message_id, source.message.message_type);
+            }
+
+            // 按当前验证者集合与治理阈值重新校验已保存的证明
+            let aggregator = AttestationAggregator::new(config.validators.clone(), config.relayer.attestation_threshold, config.chain_registry()?)
+                .with_governance_threshold(config.relayer.governance_threshold);
+            aggregator.observe(&message_id, &source.message).await?;
+            aggregator.submit_batch(store.attestations(&message_id).await?).await;
+            let progress = aggregator.progress(&message_id).await
+                .ok_or_else(|| anyhow::anyhow!("消息 {} 没有证明", message_id))?;
+            if !progress.reached() {
+                anyhow::bail!("治理消息 {} 的证明权重不足: {}/{}", message_id, progress.weight, progress.threshold);
+            }
+
+            let target = config.get_chain_config(&source.message.target_chain)
+                .ok_or_else(|| anyhow::anyhow!("未知的链: {}", source.message.target_chain))?;
+            let adapter = DefaultChainAdapterFactory::new(&config).create_adapter(target).await?;
+            let message = SignedMessage {
+                message: source.message.clone(),
+                signature: source.signature.clone(),
+                timestamp: source.observed_at,
+                event_name: source.event_name.clone(),
+                sender: source.sender.clone(),
+                provenance: source.provenance.clone(),
+                proof: None,
+                trace_id: None,
+            };
+            let result = adapter.submit_message(message).await;
+            adapter.close().await?;
+            let tx_hash = result?;
+            if format.is_json() {
+                return print_json(&json!({ "message_id": message_id, "tx_hash": tx_hash, "progress": progress }));
+            }
+            println!("治理消息 {} 已提交到 {} ({}/{})，交易: {}", message_id, target.id, progress.weight, progress.threshold, tx_hash);
+            Ok(())
+        }
+        Commands::SignMessage { config, file, key, public_key, validator, output } => {
+            let config = Config::load(config)?;
+            let chains = config.chain_registry()?;
+            let message: CrossChainMessage = serde_json::from_str(&std::fs::read_to_string(file)?)?;
+            // 与证明服务一致，对换算金额后提交到目标链的消息签名
+            let message = ledger::submitted_message(&config, &message)?;
+            let mut signers = keystore::read_secret_keys(&key)?
+                .iter()
+                .map(|bytes| LocalSigner::from_bytes(bytes))
+                .collect::<Result<Vec<_>, _>>()?;
+            let signer = match &public_key {
+                Some(public_key) => {
+                    let public_key = public_key.trim_start_matches("0x").to_lowercase();
+                    signers
+                        .into_iter()
+                        .find(|s| hex::encode(s.public_key()) == public_key)
+                        .ok_or_else(|| anyhow::anyhow!("密钥库中没有公钥 {}", public_key))?
+                }
+                None if signers.len() == 1 => signers.remove(0),
+                None => anyhow::bail!("密钥库中有 {} 个密钥，请用 --public-key 指定签名密钥", signers.len()),
+            };
+            let public_key = hex::encode(signer.public_key());
+            let attestation = Attestation {
+                message_id: message.message_id(&chains)?,
+                signature: signer.sign_message(&message, &chains).await?,
+                message,
+                validator: validator.unwrap_or_else(|| public_key.clone()),
+                public_key,
+                timestamp: std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?.as_secs(),
+            };
+            match &output {
+                Some(path) => std::fs::write(path, format!("{}\n", serde_json::to_string_pretty(&attestation)?))?,
+                None => return print_json(&attestation),
+            }
+            if format.is_json() {
+                return print_json(&json!({ "output": output, "message_id": attestation.message_id, "public_key": attestation.public_key }));
+            }
+            println!("消息 {} 已由 {} 签名: {:?}", attestation.message_id, attestation.public_key, output.unwrap_or_default());
+            Ok(())
+        }
+        Commands::VerifyMessage { config, file, pubkey } => {
+            let chains = Config::load(config)?.chain_registry()?;
+            let attestation: Attestation = serde_json::from_str(&std::fs::read_to_string(file)?)?;
+            let result = hex::decode(pubkey.trim_start_matches("0x"))
+                .map_err(|e| anyhow::anyhow!("公钥不是有效的十六进制: {}", e))
+                .and_then(|public_key| Ok(attestation.verify(&chains, &public_key)?));
+            if format.is_json() {
+                print_json(&json!({
+                    "message_id": attestation.message_id,
+                    "validator": attestation.validator,
+                    "public_key": attestation.public_key,
+                    "valid": result.is_ok(),
+                    "error": result.as_ref().err().map(|e| e.to_string()),
+                }))?;
+                if result.is_err() {
+                    std::process::exit(1);
+                }
+                return Ok(());
+            }
+            result?;
+            println!("消息 {} 的签名有效，签名者 {} ({})", attestation.message_id, attestation.validator, attestation.public_key);
+            Ok(())
+        }
+        Commands::Vectors { command: VectorCommands::Generate { output, move_tests } } => {
+            let file = vectors::generate()?;
+            let json = file.to_json()?;
+            if let Some(path) = &move_tests {
+                std::fs::write(path, vectors::render_move_tests(&file))?;
+            }
+            match &output {
+                Some(path) => std::fs::write(path, format!("{}\n", json))?,
+                None if format.is_json() => return print_json(&file),
+                None => {
+                    println!("{}", json);
+                    return Ok(());
+                }
+            }
+            if format.is_json() {
+                return print_json(&json!({ "output": output, "move": move_tests, "vectors": file.vectors.len() }));
+            }
+            println!("已生成 {} 个测试向量: {:?}", file.vectors.len(), output.unwrap_or_default());
+            if let Some(path) = move_tests {
+                println!("已生成 Move 测试模块: {:?}", path);
+            }
+            Ok(())
+        }
+        Commands::Vectors { command: VectorCommands::Verify { file } } => {
+            let file = VectorFile::from_json(&std::fs::read_to_string(file)?)?;
+            let result = vectors::verify(&file);
+            if format.is_json() {
+                print_json(&json!({
+                    "source": file.source,
+                    "vectors": file.vectors.len(),
+                    "valid": result.is_ok(),
+                    "error": result.as_ref().err().map(|e| e.to_string()),
+                }))?;
+                if result.is_err() {
+                    std::process::exit(1);
+                }
+                return Ok(());
+            }
+            result?;
+            println!("{} 个 {} 测试向量校验通过", file.vectors.len(), file.source);
+            Ok(())
+        }
+        Commands::Status { api } => {
+            let client = reqwest::Client::builder().timeout(Duration::from_secs(5)).build()?;
+            let report = top::get_json::<HealthReport>(&client, &format!("{}/status", api.trim_end_matches('/')))
+                .await
+                .map_err(|e| anyhow::anyhow!("无法获取状态: {}", e))?;
+            if format.is_json() {
+                return print_json(&report);
+            }
+            println!("中继器状态 ({})", api);
+            println!("- 就绪: {}", if report.ready { "是" } else { "否" });
+            println!("- 待处理消息: {}", report.backlog);
+            match &report.storage {
+                Some(storage) if storage.healthy => println!("- 存储: 正常"),
+                Some(storage) => println!("- 存储: 异常 ({})", storage.error.as_deref().unwrap_or("unknown")),
+                None => println!("- 存储: 未启用"),
+            }
+            for (chain_id, chain) in &report.chains {
+                println!(
+                    "- {}: {}{}, gas 余额 {}, gas 价格 {}{}{}",
+                    chain_id,
+                    if chain.connected { "在线" } else { "离线" },
+                    if chain.synced { "" } else { " (同步中)" },
+                    chain.gas_balance.map(|b| b.to_string()).unwrap_or_else(|| "-".to_string()),
+                    chain.gas_price.map(|p| p.to_string()).unwrap_or_else(|| "-".to_string()),
+                    if chain.cost_deferred > 0 { format!(", 成本超限推迟 {} 条", chain.cost_deferred) } else { String::new() },
+                    chain.last_error.as_deref().map(|e| format!(", 错误: {}", e)).unwrap_or_default()
+                );
+            }
+            Ok(())
+        }
+        Commands::Top { api, interval, window } => {
+            top::run(&api, humantime::parse_duration(&interval)?, humantime::parse_duration(&window)?).await
+        }
+        Commands::Devnet { transfers, amount, validators, fail_submissions } => {
+            devnet::run(devnet::DevnetOptions { transfers, amount, validators, fail_submissions }).await
+        }
+        Commands::Audit { command: AuditCommands::Verify { log, public_key } } => {
+            let public_key: Option<[u8; 32]> = public_key
+                .map(|key| {
+                    hex::decode(key.trim_start_matches("0x"))
+                        .ok()
+                        .and_then(|bytes| bytes.try_into().ok())
+                        .ok_or_else(|| anyhow::anyhow!("无效的公钥: {}", key))
+                })
+                .transpose()?;
+            let report = audit::verify(&log, public_key.as_ref())?;
+            if format.is_json() {
+                return print_json(&json!({ "valid": true, "entries": report.entries, "head": report.head }));
+            }
+            println!("审计日志校验通过!");
+            println!("- 条目数: {}", report.entries);
+            if let Some(head) = &report.head {
+                println!("- 链头哈希: {}", head);
+            }
+            Ok(())
+        }
+        Commands::Confidential { command: ConfidentialCommands::Keygen { output } } => {
+            let key = PayloadKey::generate();
+            std::fs::write(&output, hex::encode(key.secret_bytes()))?;
+            if format.is_json() {
+                return print_json(&json!({ "key_file": output, "public_key": hex::encode(key.public_key()) }));
+            }
+            println!("私钥已写入: {:?}", output);
+            println!("公钥: {}", hex::encode(key.public_key()));
+            Ok(())
+        }
+        Commands::Confidential { command: ConfidentialCommands::Seal { public_key, route, payload } } => {
+            let (source, target) = route.split_once(':')
+                .ok_or_else(|| anyhow::anyhow!("路由格式应为 `源链:目标链`"))?;
+            let public_key = confidential::parse_public_key(&public_key)?;
+            let envelope = confidential::seal(&public_key, source, target, &hex::decode(payload.trim_start_matches("0x"))?)?;
+            if format.is_json() {
+                return print_json(&json!({ "envelope": hex::encode(envelope) }));
+            }
+            println!("{}", hex::encode(envelope));
+            Ok(())
+        }
+        Commands::Confidential { command: ConfidentialCommands::Open { key, route, envelope } } => {
+            let (source, target) = route.split_once(':')
+                .ok_or_else(|| anyhow::anyhow!("路由格式应为 `源链:目标链`"))?;
+            let key = PayloadKey::load(key)?;
+            let plaintext = key.open(source, target, &hex::decode(envelope.trim_start_matches("0x"))?)?;
+            if format.is_json() {
+                return print_json(&json!({ "payload": hex::encode(plaintext) }));
+            }
+            println!("{}", hex::encode(plaintext));
+            Ok(())
+        }
+        Commands::Keys { command: KeyCommands::Rotate { config, validator, apis, grace } } => {
+            let config = Config::load(config)?;
+            let grace_period = humantime::parse_duration(&grace)?.as_secs();
+            let keystore_path = config.relayer.keystore.clone()
+                .ok_or_else(|| anyhow::anyhow!("配置中未指定密钥库文件，无法保存新密钥"))?;
+            let old_key = config.validators
+                .iter()
+                .find(|v| v.address == validator)
+                .map(|v| v.public_key.clone())
+                .ok_or_else(|| anyhow::anyhow!("验证者 {} 不在配置中", validator))?;
+            let keys = Keystore::open(&config.relayer).await?
+                .ok_or_else(|| anyhow::anyhow!("配置中未指定密钥库或签名后端"))?;
+            let issued_at = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?.as_secs();
+            let (new_signer, rotation) = keys.rotate(&old_key, &validator, grace_period, issued_at).await?;
+            let secret_key = new_signer.secret_key_bytes()
+                .ok_or_else(|| anyhow::anyhow!("新密钥无法导出"))?;
+            keystore::append_secret_key(&keystore_path, &secret_key)?;
+            let new_key = hex::encode(&rotation.new_public_key);
+
+            let client = reqwest::Client::builder().timeout(Duration::from_secs(10)).build()?;
+            let mut results = Vec::new();
+            for api in &apis {
+                let result = submit_key_rotation(&client, api, &rotation).await;
+                if let Err(e) = &result {
+                    error!("Failed to submit key rotation to {}: {}", api, e);
+                }
+                results.push(json!({ "api": api, "accepted": result.is_ok(), "error": result.err() }));
+            }
+            if format.is_json() {
+                return print_json(&json!({
+                    "validator": validator,
+                    "old_public_key": hex::encode(&rotation.old_public_key),
+                    "new_public_key": new_key,
+                    "grace_period": grace_period,
+                    "keystore": keystore_path,
+                    "relayers": results,
+                }));
+            }
+            println!("验证者 {} 的新公钥: {}", validator, new_key);
+            println!("新私钥已追加到密钥库: {}", keystore_path);
+            for result in &results {
+                match result["error"].as_str() {
+                    None => println!("- {}: 已接受", result["api"].as_str().unwrap_or_default()),
+                    Some(e) => println!("- {}: 失败 ({})", result["api"].as_str().unwrap_or_default(), e),
+                }
+            }
+            println!("请在宽限期 ({}) 结束前将配置中该验证者的 public_key 更新为新公钥", grace);
+            Ok(())
+        }
+        Commands::Report { command: ReportCommands::Held { config } } => {
+            let config = Config::load(config)?;
+            let storage_config = config.storage.as_ref()
+                .ok_or_else(|| anyhow::anyhow!("配置中未启用消息存储"))?;
+            let store = storage::open(storage_config).await?;
+            let records = store.query(&MessageQuery {
+                status: Some(MessageStatus::Held),
+                ..Default::default()
+            }).await?;
+            if format.is_json() {
+                return print_json(&records);
+            }
+
+            println!("暂缓中继的消息: {}", records.len());
+            for record in &records {
+                println!(
+                    "- {} ({} -> {}, nonce {}): {}",
+                    record.message_id,
+                    record.source_chain,
+                    record.target_chain,
+                    record.nonce,
+                    record.error.as_deref().unwrap_or("未知原因")
+                );
+            }
+            Ok(())
+        }
+        Commands::Held { command: HeldCommands::List { config } } => {
+            let config = Config::load(config)?;
+            let storage_config = config.storage.as_ref()
+                .ok_or_else(|| anyhow::anyhow!("配置中未启用消息存储"))?;
+            let store = storage::open(storage_config).await?;
+            let held = store.held().await?;
+            if format.is_json() {
+                return print_json(&held);
+            }
+
+            println!("暂缓队列中的消息: {}", held.len());
+            for message in &held {
+                let state = if message.released_at.is_some() { "，已放行" } else { "" };
+                println!(
+                    "- {} ({} -> {}, nonce {}, {:?}{}): {}",
+                    message.message_id,
+                    message.message.source_chain,
+                    message.message.target_chain,
+                    message.message.nonce,
+                    message.check,
+                    state,
+                    message.reason
+                );
+            }
+            Ok(())
+        }
+        Commands::Held { command: HeldCommands::Release { message_id, api } } => {
+            let client = reqwest::Client::builder().timeout(Duration::from_secs(10)).build()?;
+            let url = format!("{}/held/{}/release", api.trim_end_matches('/'), message_id);
+            let response = client.post(&url).send().await?;
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                anyhow::bail!("{} 返回 {}: {}", url, status, body);
+            }
+            let held: serde_json::Value = response.json().await?;
+            if format.is_json() {
+                return print_json(&held);
+            }
+            println!("已放行消息 {}，中继器将在下一轮重新处理", message_id);
+            Ok(())
+        }
+        Commands::Completions { shell } => {
+            clap_complete::generate(shell, &mut Cli::command(), "bridge-cli", &mut std::io::stdout());
+            Ok(())
+        }
+    }
+}
+
+/// 向中继器提交密钥轮换声明
+async fn submit_key_rotation(client: &reqwest::Client, api: &str, rotation: &KeyRotation) -> Result<(), String> {
+    let url = format!("{}/validator-set/key-rotations", api.trim_end_matches('/'));
+    let response = client.post(&url).json(rotation).send().await.map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("{} 返回 {}: {}", url, status, body));
     }
+    Ok(())
 }
@@ -0,0 +1,101 @@
+use bridge_core::storage::MessageRecord;
+use clap::ValueEnum;
+use serde_json::{json, Map, Value};
+use std::time::{Duration, UNIX_EPOCH};
+
+/// `messages` 命令可选择的列
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum MessageColumn {
+    Id,
+    Status,
+    Source,
+    Target,
+    Nonce,
+    Asset,
+    Sender,
+    Recipient,
+    /// 中继器观察到消息的时间
+    Observed,
+    /// 消息进入终态的时间
+    Completed,
+    Error,
+}
+
+/// 未指定 `--columns` 时显示的列
+pub const DEFAULT_COLUMNS: &[MessageColumn] = &[
+    MessageColumn::Id,
+    MessageColumn::Status,
+    MessageColumn::Source,
+    MessageColumn::Target,
+    MessageColumn::Nonce,
+    MessageColumn::Observed,
+    MessageColumn::Error,
+];
+
+impl MessageColumn {
+    fn name(self) -> String {
+        self.to_possible_value().map(|v| v.get_name().to_string()).unwrap_or_default()
+    }
+
+    fn value(self, record: &MessageRecord) -> Value {
+        match self {
+            MessageColumn::Id => json!(record.message_id),
+            MessageColumn::Status => json!(record.status),
+            MessageColumn::Source => json!(record.source_chain),
+            MessageColumn::Target => json!(record.target_chain),
+            MessageColumn::Nonce => json!(record.nonce),
+            MessageColumn::Asset => json!(record.asset),
+            MessageColumn::Sender => json!(record.sender),
+            MessageColumn::Recipient => json!(record.recipient),
+            MessageColumn::Observed => json!(record.observed_at),
+            MessageColumn::Completed => json!(record.completed_at),
+            MessageColumn::Error => json!(record.error),
+        }
+    }
+
+    fn text(self, record: &MessageRecord) -> String {
+        let time = |secs: u64| humantime::format_rfc3339_seconds(UNIX_EPOCH + Duration::from_secs(secs)).to_string();
+        match self {
+            MessageColumn::Observed => time(record.observed_at),
+            MessageColumn::Completed => record.completed_at.map(time).unwrap_or_default(),
+            _ => match self.value(record) {
+                Value::Null => String::new(),
+                Value::String(s) => s,
+                other => other.to_string(),
+            },
+        }
+    }
+}
+
+/// 按列选出记录的字段，用于 JSON 输出
+pub fn select(records: &[MessageRecord], columns: &[MessageColumn]) -> Vec<Value> {
+    records
+        .iter()
+        .map(|record| {
+            let fields: Map<String, Value> = columns.iter().map(|c| (c.name(), c.value(record))).collect();
+            Value::Object(fields)
+        })
+        .collect()
+}
+
+/// 按列渲染为对齐的文本表格，最后一列不补齐
+pub fn render_table(records: &[MessageRecord], columns: &[MessageColumn]) -> String {
+    let header: Vec<String> = columns.iter().map(|c| c.name().to_uppercase()).collect();
+    let rows: Vec<Vec<String>> = records.iter().map(|r| columns.iter().map(|c| c.text(r)).collect()).collect();
+    let widths: Vec<usize> = (0..columns.len())
+        .map(|i| std::iter::once(&header).chain(&rows).map(|row| row[i].chars().count()).max().unwrap_or(0))
+        .collect();
+
+    let mut table = String::new();
+    for row in std::iter::once(&header).chain(&rows) {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(i, (cell, width))| if i + 1 == row.len() { cell.clone() } else { format!("{:<width$}", cell, width = width) })
+            .collect();
+        table.push_str(cells.join("  ").trim_end());
+        table.push('\n');
+    }
+    table
+}
@@ -0,0 +1,27 @@
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::Serialize;
+
+/// 命令结果的输出格式
+///
+/// 长时间运行的服务命令与交互式命令（`start`、`top`、`devnet` 等）只输出日志，不受该选项影响。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// 供人阅读的文本
+    #[default]
+    Text,
+    /// 单个 JSON 文档，失败时为 `{"error": ...}`
+    Json,
+}
+
+impl OutputFormat {
+    pub fn is_json(self) -> bool {
+        self == OutputFormat::Json
+    }
+}
+
+/// 以 JSON 打印命令结果
+pub fn print_json<T: Serialize + ?Sized>(value: &T) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
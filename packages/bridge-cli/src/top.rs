@@ -0,0 +1,259 @@
+use anyhow::Result;
+use bridge_core::{health::HealthReport, storage::MessageRecord, types::MessageStatus};
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Paragraph, Row, Table},
+    DefaultTerminal, Frame,
+};
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// 最近消息列表显示的条数
+const RECENT_MESSAGES: usize = 20;
+
+/// 一次刷新从管理接口读取的数据
+struct Snapshot {
+    status: Result<HealthReport, String>,
+    messages: Result<Vec<MessageRecord>, String>,
+    fetched_at: u64,
+}
+
+/// 单条路由在统计窗口内的消息数
+#[derive(Default)]
+struct RouteStats {
+    processed: usize,
+    failed: usize,
+    held: usize,
+    expired: usize,
+    pending: usize,
+}
+
+impl RouteStats {
+    /// 失败数占已结束消息的比例
+    fn error_rate(&self) -> f64 {
+        let finished = self.processed + self.failed;
+        if finished == 0 {
+            0.0
+        } else {
+            self.failed as f64 / finished as f64
+        }
+    }
+}
+
+/// 运行终端仪表盘，按 `q` 或 `Esc` 退出
+pub async fn run(api: &str, interval: Duration, window: Duration) -> Result<()> {
+    let client = reqwest::Client::builder().timeout(Duration::from_secs(5)).build()?;
+    let api = api.trim_end_matches('/');
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, &client, api, interval, window).await;
+    ratatui::restore();
+    result
+}
+
+async fn event_loop(
+    terminal: &mut DefaultTerminal,
+    client: &reqwest::Client,
+    api: &str,
+    interval: Duration,
+    window: Duration,
+) -> Result<()> {
+    loop {
+        let snapshot = fetch(client, api, window).await;
+        terminal.draw(|frame| draw(frame, api, window, &snapshot))?;
+
+        let deadline = Instant::now() + interval;
+        while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+            if !event::poll(remaining)? {
+                break;
+            }
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press && matches!(key.code, KeyCode::Char('q') | KeyCode::Esc) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+async fn fetch(client: &reqwest::Client, api: &str, window: Duration) -> Snapshot {
+    let now = now_secs();
+    let status = get_json(client, &format!("{}/status", api)).await;
+    let messages = get_json(client, &format!("{}/messages?since={}", api, now.saturating_sub(window.as_secs()))).await;
+    Snapshot {
+        status,
+        messages,
+        fetched_at: now,
+    }
+}
+
+pub async fn get_json<T: serde::de::DeserializeOwned>(client: &reqwest::Client, url: &str) -> Result<T, String> {
+    let response = client.get(url).send().await.map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        return Err(format!("{} 返回 {}", url, response.status()));
+    }
+    response.json().await.map_err(|e| e.to_string())
+}
+
+fn draw(frame: &mut Frame, api: &str, window: Duration, snapshot: &Snapshot) {
+    let [header, chains, routes, recent] = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Min(5),
+        Constraint::Min(5),
+        Constraint::Min(8),
+    ])
+    .areas(frame.area());
+
+    let summary = match &snapshot.status {
+        Ok(report) => format!(
+            "{}  就绪: {}  待处理: {}  存储: {}",
+            api,
+            if report.ready { "是" } else { "否" },
+            report.backlog,
+            match &report.storage {
+                Some(storage) if storage.healthy => "正常".to_string(),
+                Some(storage) => format!("异常 ({})", storage.error.as_deref().unwrap_or("unknown")),
+                None => "未启用".to_string(),
+            }
+        ),
+        Err(e) => format!("{}  无法获取状态: {}", api, e),
+    };
+    frame.render_widget(
+        Paragraph::new(summary).block(Block::bordered().title(" bridge top (q 退出) ")),
+        header,
+    );
+
+    let chain_rows: Vec<Row> = match &snapshot.status {
+        Ok(report) => report
+            .chains
+            .iter()
+            .map(|(chain_id, chain)| {
+                let style = if chain.connected { Style::default() } else { Style::default().fg(Color::Red) };
+                Row::new(vec![
+                    chain_id.clone(),
+                    if chain.connected { "在线".to_string() } else { "离线".to_string() },
+                    chain.last_poll
+                        .map(|t| format!("{}s", snapshot.fetched_at.saturating_sub(t)))
+                        .unwrap_or_else(|| "-".to_string()),
+                    chain.gas_balance.map(|b| b.to_string()).unwrap_or_else(|| "-".to_string()),
+                    chain.last_error.clone().unwrap_or_default(),
+                ])
+                .style(style)
+            })
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    frame.render_widget(
+        Table::new(chain_rows, [
+            Constraint::Length(16),
+            Constraint::Length(6),
+            Constraint::Length(8),
+            Constraint::Length(22),
+            Constraint::Fill(1),
+        ])
+        .header(header_row(["链", "状态", "延迟", "gas 余额", "最近错误"]))
+        .block(Block::bordered().title(" 链 ")),
+        chains,
+    );
+
+    let (route_rows, recent_rows) = match &snapshot.messages {
+        Ok(records) => (route_rows(records), recent_rows(records)),
+        Err(e) => (Vec::new(), vec![Row::new(vec![format!("无法获取消息: {}", e)])]),
+    };
+    frame.render_widget(
+        Table::new(route_rows, [
+            Constraint::Fill(1),
+            Constraint::Length(8),
+            Constraint::Length(8),
+            Constraint::Length(8),
+            Constraint::Length(8),
+            Constraint::Length(8),
+            Constraint::Length(8),
+        ])
+        .header(header_row(["路由", "完成", "失败", "暂缓", "过期", "处理中", "错误率"]))
+        .block(Block::bordered().title(format!(" 路由（最近 {}） ", humantime::format_duration(window)))),
+        routes,
+    );
+    frame.render_widget(
+        Table::new(recent_rows, [
+            Constraint::Length(18),
+            Constraint::Fill(1),
+            Constraint::Length(10),
+            Constraint::Fill(2),
+        ])
+        .header(header_row(["消息", "路由", "状态", "原因"]))
+        .block(Block::bordered().title(" 最近消息 ")),
+        recent,
+    );
+}
+
+fn header_row<const N: usize>(titles: [&'static str; N]) -> Row<'static> {
+    Row::new(titles.map(Line::from)).style(Style::default().add_modifier(Modifier::BOLD))
+}
+
+fn route_rows(records: &[MessageRecord]) -> Vec<Row<'static>> {
+    let mut routes: BTreeMap<(String, String), RouteStats> = BTreeMap::new();
+    for record in records {
+        let stats = routes.entry((record.source_chain.clone(), record.target_chain.clone())).or_default();
+        match record.status {
+            MessageStatus::Processed => stats.processed += 1,
+            MessageStatus::Failed => stats.failed += 1,
+            MessageStatus::Held => stats.held += 1,
+            MessageStatus::Expired => stats.expired += 1,
+            MessageStatus::Pending => stats.pending += 1,
+        }
+    }
+    routes
+        .into_iter()
+        .map(|((source, target), stats)| {
+            Row::new(vec![
+                format!("{} -> {}", source, target),
+                stats.processed.to_string(),
+                stats.failed.to_string(),
+                stats.held.to_string(),
+                stats.expired.to_string(),
+                stats.pending.to_string(),
+                format!("{:.1}%", stats.error_rate() * 100.0),
+            ])
+        })
+        .collect()
+}
+
+fn recent_rows(records: &[MessageRecord]) -> Vec<Row<'static>> {
+    // 接口按观察时间升序返回，取末尾的最新消息
+    records
+        .iter()
+        .rev()
+        .take(RECENT_MESSAGES)
+        .map(|record| {
+            let color = match record.status {
+                MessageStatus::Processed => Color::Green,
+                MessageStatus::Failed => Color::Red,
+                MessageStatus::Held => Color::Yellow,
+                MessageStatus::Expired => Color::Magenta,
+                MessageStatus::Pending => Color::Reset,
+            };
+            let reason = record.cause
+                .as_ref()
+                .map(|cause| cause.hint.clone())
+                .or_else(|| record.error.clone())
+                .unwrap_or_default();
+            Row::new(vec![
+                record.message_id.chars().take(16).collect::<String>(),
+                format!("{} -> {}", record.source_chain, record.target_chain),
+                format!("{:?}", record.status),
+                reason,
+            ])
+            .style(Style::default().fg(color))
+        })
+        .collect()
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
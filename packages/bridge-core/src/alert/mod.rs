@@ -0,0 +1,304 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::Duration;
+use tracing::{debug, warn};
+use crate::{
+    config::{AlertConfig, AlertKind, AlertSeverity, AlertSinkConfig, PagerDutySinkConfig, SlackSinkConfig, TelegramSinkConfig},
+    health::ChainHealth,
+    Error,
+};
+
+/// 告警推送请求超时
+const SINK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 一条告警
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Alert {
+    pub kind: AlertKind,
+    pub severity: AlertSeverity,
+    /// 去重键：同一类型、同一对象的告警在去重窗口内只推送一次
+    pub key: String,
+    pub summary: String,
+    pub raised_at: u64,
+}
+
+/// 告警渠道
+#[async_trait]
+pub trait AlertSink: Send + Sync {
+    /// 渠道名称，出现在日志中
+    fn name(&self) -> &str;
+
+    async fn send(&self, alert: &Alert) -> Result<(), Error>;
+}
+
+/// 根据配置创建告警渠道
+pub fn sink(config: &AlertSinkConfig) -> Box<dyn AlertSink> {
+    let client = reqwest::Client::new();
+    match config {
+        AlertSinkConfig::Slack(slack) => Box::new(SlackSink { client, config: slack.clone() }),
+        AlertSinkConfig::PagerDuty(pagerduty) => Box::new(PagerDutySink { client, config: pagerduty.clone() }),
+        AlertSinkConfig::Telegram(telegram) => Box::new(TelegramSink { client, config: telegram.clone() }),
+    }
+}
+
+fn message_text(alert: &Alert) -> String {
+    format!("[{}] {}: {}", alert.severity.as_str().to_uppercase(), alert.kind.as_str(), alert.summary)
+}
+
+async fn post<T: Serialize>(client: &reqwest::Client, sink: &str, url: &str, body: &T) -> Result<(), Error> {
+    let response = client
+        .post(url)
+        .timeout(SINK_TIMEOUT)
+        .json(body)
+        .send()
+        .await
+        .map_err(|e| Error::Network(format!("{} alert request failed: {}", sink, e.without_url())))?;
+    if !response.status().is_success() {
+        return Err(Error::Network(format!("{} rejected alert: {}", sink, response.status())));
+    }
+    Ok(())
+}
+
+/// Slack incoming webhook，以纯文本消息推送
+pub struct SlackSink {
+    client: reqwest::Client,
+    config: SlackSinkConfig,
+}
+
+#[async_trait]
+impl AlertSink for SlackSink {
+    fn name(&self) -> &str {
+        "slack"
+    }
+
+    async fn send(&self, alert: &Alert) -> Result<(), Error> {
+        let body = serde_json::json!({ "text": message_text(alert) });
+        post(&self.client, self.name(), &self.config.webhook_url, &body).await
+    }
+}
+
+/// PagerDuty Events API v2，以告警去重键作为 `dedup_key`
+pub struct PagerDutySink {
+    client: reqwest::Client,
+    config: PagerDutySinkConfig,
+}
+
+#[async_trait]
+impl AlertSink for PagerDutySink {
+    fn name(&self) -> &str {
+        "pagerduty"
+    }
+
+    async fn send(&self, alert: &Alert) -> Result<(), Error> {
+        let body = serde_json::json!({
+            "routing_key": self.config.routing_key,
+            "event_action": "trigger",
+            "dedup_key": alert.key,
+            "payload": {
+                "summary": alert.summary,
+                "source": "move-bridge",
+                "severity": alert.severity.as_str(),
+                "component": alert.kind.as_str(),
+                "custom_details": { "raised_at": alert.raised_at },
+            },
+        });
+        post(&self.client, self.name(), &self.config.url, &body).await
+    }
+}
+
+/// Telegram bot，通过 `sendMessage` 推送到指定会话
+pub struct TelegramSink {
+    client: reqwest::Client,
+    config: TelegramSinkConfig,
+}
+
+#[async_trait]
+impl AlertSink for TelegramSink {
+    fn name(&self) -> &str {
+        "telegram"
+    }
+
+    async fn send(&self, alert: &Alert) -> Result<(), Error> {
+        let url = format!("{}/bot{}/sendMessage", self.config.api_url.trim_end_matches('/'), self.config.bot_token);
+        let body = serde_json::json!({ "chat_id": self.config.chat_id, "text": message_text(alert) });
+        post(&self.client, self.name(), &url, &body).await
+    }
+}
+
+struct AlertState {
+    /// 各去重键最近一次推送的时间
+    last_sent: HashMap<String, u64>,
+    /// 时间窗口内进入失败状态的消息时间
+    dead_letters: VecDeque<u64>,
+}
+
+/// 告警管理器
+///
+/// 中继器在运行中检查告警条件：链持续不可达、失败消息快速增长、消息长时间收集不到法定人数的证明、
+/// 验证者集合变更限制熔断、资产对账发现铸造量超过锁定量。满足条件的告警按去重键去重后推送到级别不低于渠道下限的各渠道，
+/// 推送失败只记录日志。
+pub struct AlertManager {
+    config: AlertConfig,
+    sinks: Vec<(AlertSeverity, Box<dyn AlertSink>)>,
+    /// 启动时间，从未成功轮询的链以此计算不可达时长
+    started_at: u64,
+    state: Mutex<AlertState>,
+}
+
+impl AlertManager {
+    pub fn new(config: &AlertConfig, now: u64) -> Self {
+        let sinks = config.sinks.iter().map(|s| (s.min_severity(), sink(s))).collect();
+        Self::with_sinks(config, sinks, now)
+    }
+
+    /// 使用指定的告警渠道创建，测试时可替换为记录告警的渠道
+    pub fn with_sinks(config: &AlertConfig, sinks: Vec<(AlertSeverity, Box<dyn AlertSink>)>, now: u64) -> Self {
+        Self {
+            config: config.clone(),
+            sinks,
+            started_at: now,
+            state: Mutex::new(AlertState { last_sent: HashMap::new(), dead_letters: VecDeque::new() }),
+        }
+    }
+
+    /// 告警类型的级别，配置中覆盖时使用配置的级别
+    pub fn severity(&self, kind: AlertKind) -> AlertSeverity {
+        self.config.severities.get(&kind).copied().unwrap_or_else(|| kind.default_severity())
+    }
+
+    /// 发出告警，去重窗口内已推送过同一去重键时忽略，返回是否推送
+    pub async fn raise(&self, kind: AlertKind, subject: &str, summary: String, now: u64) -> bool {
+        let key = format!("{}:{}", kind.as_str(), subject);
+        {
+            let mut state = self.state.lock().unwrap();
+            if state.last_sent.get(&key).is_some_and(|sent| now < sent.saturating_add(self.config.dedup_window)) {
+                debug!("Suppressing duplicate alert {}", key);
+                return false;
+            }
+            state.last_sent.insert(key.clone(), now);
+        }
+
+        let alert = Alert { kind, severity: self.severity(kind), key, summary, raised_at: now };
+        warn!("Alert {} ({}): {}", alert.key, alert.severity.as_str(), alert.summary);
+        for (min_severity, sink) in &self.sinks {
+            if alert.severity < *min_severity {
+                continue;
+            }
+            if let Err(e) = sink.send(&alert).await {
+                warn!("Failed to send alert {} to {}: {}", alert.key, sink.name(), e);
+            }
+        }
+        true
+    }
+
+    /// 检查各链是否持续不可达，从未成功轮询的链自启动时起计算
+    pub async fn check_chains(&self, chains: &BTreeMap<String, ChainHealth>, now: u64) {
+        for (chain_id, health) in chains {
+            if health.connected {
+                continue;
+            }
+            let since = health.last_poll.unwrap_or(self.started_at);
+            let down_for = now.saturating_sub(since);
+            if down_for < self.config.chain_unreachable_after {
+                continue;
+            }
+            let summary = format!(
+                "Chain {} unreachable for {}s: {}",
+                chain_id,
+                down_for,
+                health.last_error.as_deref().unwrap_or("no successful poll")
+            );
+            self.raise(AlertKind::ChainUnreachable, chain_id, summary, now).await;
+        }
+    }
+
+    /// 记录一条进入失败状态的消息，时间窗口内的失败数达到阈值时告警
+    pub async fn record_dead_letter(&self, now: u64) {
+        let count = {
+            let mut state = self.state.lock().unwrap();
+            let window_start = now.saturating_sub(self.config.dead_letter_window);
+            state.dead_letters.retain(|at| *at >= window_start);
+            state.dead_letters.push_back(now);
+            state.dead_letters.len()
+        };
+        if count >= self.config.dead_letter_threshold {
+            let summary = format!("{} messages failed in the last {}s", count, self.config.dead_letter_window);
+            self.raise(AlertKind::DeadLetterGrowth, "relayer", summary, now).await;
+        }
+    }
+
+    /// 检查消息等待证明的时长，超过配置时长时告警
+    pub async fn check_quorum(&self, message_id: &str, observed_at: u64, weight: u64, threshold: u64, now: u64) {
+        let waited = now.saturating_sub(observed_at);
+        if waited < self.config.quorum_timeout {
+            return;
+        }
+        let summary = format!(
+            "Message {} has waited {}s for quorum ({}/{} attestation weight)",
+            message_id, waited, weight, threshold
+        );
+        // 法定人数丢失通常影响所有消息，按整体去重避免逐条告警
+        self.raise(AlertKind::QuorumLoss, "attestations", summary, now).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[derive(Clone, Default)]
+    struct RecordingSink(Arc<Mutex<Vec<Alert>>>);
+
+    #[async_trait]
+    impl AlertSink for RecordingSink {
+        fn name(&self) -> &str {
+            "recording"
+        }
+
+        async fn send(&self, alert: &Alert) -> Result<(), Error> {
+            self.0.lock().unwrap().push(alert.clone());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_alerts_are_deduplicated_and_filtered_by_severity() {
+        let config = AlertConfig {
+            dead_letter_threshold: 2,
+            severities: HashMap::from([(AlertKind::DeadLetterGrowth, AlertSeverity::Info)]),
+            ..Default::default()
+        };
+        let (pager, chat) = (RecordingSink::default(), RecordingSink::default());
+        let alerts = AlertManager::with_sinks(&config, vec![
+            (AlertSeverity::Critical, Box::new(pager.clone())),
+            (AlertSeverity::Info, Box::new(chat.clone())),
+        ], 0);
+
+        let chains = BTreeMap::from([
+            ("sui".to_string(), ChainHealth { connected: true, ..Default::default() }),
+            ("rooch".to_string(), ChainHealth { connected: false, last_poll: Some(100), ..Default::default() }),
+        ]);
+        alerts.check_chains(&chains, 200).await;
+        assert!(pager.0.lock().unwrap().is_empty());
+        alerts.check_chains(&chains, 400).await;
+        alerts.check_chains(&chains, 500).await;
+        let paged = pager.0.lock().unwrap().clone();
+        assert_eq!(paged.len(), 1);
+        assert_eq!((paged[0].key.as_str(), paged[0].severity), ("chain_unreachable:rooch", AlertSeverity::Critical));
+
+        // 降为 info 的失败增长告警只推送到接收 info 的渠道
+        alerts.record_dead_letter(1_000).await;
+        alerts.record_dead_letter(1_010).await;
+        assert_eq!(pager.0.lock().unwrap().len(), 1);
+        let sent = chat.0.lock().unwrap().clone();
+        assert_eq!(sent.len(), 2);
+        assert_eq!((sent[1].kind, sent[1].severity), (AlertKind::DeadLetterGrowth, AlertSeverity::Info));
+
+        // 去重窗口过后再次告警
+        alerts.check_chains(&chains, 400 + config.dedup_window).await;
+        assert_eq!(pager.0.lock().unwrap().len(), 2);
+    }
+}
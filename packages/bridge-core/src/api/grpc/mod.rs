@@ -0,0 +1,433 @@
+use std::net::SocketAddr;
+use tracing::info;
+use tokio::sync::{broadcast::error::RecvError, mpsc};
+use tonic::codegen::tokio_stream::wrappers::ReceiverStream;
+use tonic::transport::Server;
+use tonic::{Request, Response, Status};
+use crate::{
+    info::BuildInfo,
+    relayer::{aggregator, guard::HeldUpdate},
+    storage::{MessageRecord, RetryRecord, SubmissionRecord},
+    types::{self, CrossChainMessage, FailureCause, MessageStatus},
+    Error,
+};
+use super::{
+    stream::{StatusEvent, Subscription},
+    ApiState, MessageHistory, MessageParams,
+};
+
+pub mod proto;
+
+use proto::bridge_node_server::{BridgeNode, BridgeNodeServer};
+
+/// 每个推送流在服务端缓冲的状态变化数
+const STREAM_BUFFER: usize = 64;
+
+/// 与 HTTP 接口共享状态的 gRPC 节点服务
+pub struct NodeService {
+    state: ApiState,
+}
+
+impl NodeService {
+    pub fn new(state: ApiState) -> Self {
+        Self { state }
+    }
+}
+
+/// 在指定地址启动 gRPC 服务
+pub async fn serve(listen: &str, state: ApiState) -> Result<(), Error> {
+    let addr: SocketAddr = listen
+        .parse()
+        .map_err(|e| Error::Config(format!("Invalid gRPC listen address {}: {}", listen, e)))?;
+    info!("gRPC API listening on {}", addr);
+    Server::builder()
+        .add_service(BridgeNodeServer::new(NodeService::new(state)))
+        .serve(addr)
+        .await
+        .map_err(|e| Error::Network(format!("gRPC API failed: {}", e)))
+}
+
+fn disabled(feature: &str) -> Status {
+    Status::unimplemented(format!("{} is disabled", feature))
+}
+
+/// 与 HTTP 接口中的状态名一致
+fn status_name(status: MessageStatus) -> String {
+    format!("{:?}", status)
+}
+
+impl From<BuildInfo> for proto::NodeInfo {
+    fn from(info: BuildInfo) -> Self {
+        Self {
+            version: info.version,
+            git_commit: info.git_commit,
+            features: info.features,
+            adapter_types: info.adapter_types,
+            message_format_versions: info.message_format_versions.into_iter().map(u32::from).collect(),
+            config_fingerprint: info.config_fingerprint,
+        }
+    }
+}
+
+impl From<FailureCause> for proto::FailureCause {
+    fn from(cause: FailureCause) -> Self {
+        Self {
+            kind: serde_json::to_value(cause.kind)
+                .ok()
+                .and_then(|kind| kind.as_str().map(str::to_string))
+                .unwrap_or_default(),
+            location: cause.location,
+            abort_code: cause.abort_code,
+            rpc_code: cause.rpc_code,
+            hint: cause.hint,
+        }
+    }
+}
+
+impl From<MessageRecord> for proto::MessageRecord {
+    fn from(record: MessageRecord) -> Self {
+        Self {
+            message_id: record.message_id,
+            source_chain: record.source_chain,
+            target_chain: record.target_chain,
+            asset: record.asset,
+            sender: record.sender,
+            nonce: record.nonce,
+            status: status_name(record.status),
+            observed_at: record.observed_at,
+            completed_at: record.completed_at,
+            error: record.error,
+            cause: record.cause.map(Into::into),
+        }
+    }
+}
+
+impl From<SubmissionRecord> for proto::SubmissionRecord {
+    fn from(submission: SubmissionRecord) -> Self {
+        Self {
+            message_id: submission.message_id,
+            chain: submission.chain,
+            attempt: submission.attempt,
+            tx_hash: submission.tx_hash,
+            error: submission.error,
+            submitted_at: submission.submitted_at,
+        }
+    }
+}
+
+impl From<RetryRecord> for proto::RetryRecord {
+    fn from(retry: RetryRecord) -> Self {
+        Self {
+            message_id: retry.message_id,
+            attempt: retry.attempt,
+            reason: retry.reason,
+            retry_at: retry.retry_at,
+        }
+    }
+}
+
+impl From<CrossChainMessage> for proto::BridgeMessage {
+    fn from(message: CrossChainMessage) -> Self {
+        Self {
+            nonce: message.nonce,
+            source_chain: message.source_chain.to_string(),
+            target_chain: message.target_chain.to_string(),
+            message_type: message.message_type,
+            payload: message.payload,
+        }
+    }
+}
+
+impl From<proto::BridgeMessage> for CrossChainMessage {
+    fn from(message: proto::BridgeMessage) -> Self {
+        Self {
+            nonce: message.nonce,
+            source_chain: message.source_chain.into(),
+            target_chain: message.target_chain.into(),
+            message_type: message.message_type,
+            payload: message.payload,
+        }
+    }
+}
+
+impl From<types::Attestation> for proto::Attestation {
+    fn from(attestation: types::Attestation) -> Self {
+        Self {
+            message_id: attestation.message_id,
+            message: Some(attestation.message.into()),
+            validator: attestation.validator,
+            public_key: attestation.public_key,
+            signature: attestation.signature,
+            timestamp: attestation.timestamp,
+        }
+    }
+}
+
+impl TryFrom<proto::Attestation> for types::Attestation {
+    type Error = Status;
+
+    fn try_from(attestation: proto::Attestation) -> Result<Self, Status> {
+        let message = attestation.message.ok_or_else(|| Status::invalid_argument("Attestation has no message"))?;
+        Ok(Self {
+            message_id: attestation.message_id,
+            message: message.into(),
+            validator: attestation.validator,
+            public_key: attestation.public_key,
+            signature: attestation.signature,
+            timestamp: attestation.timestamp,
+        })
+    }
+}
+
+impl From<MessageHistory> for proto::MessageHistory {
+    fn from(history: MessageHistory) -> Self {
+        Self {
+            record: Some(history.record.into()),
+            attestations: history.attestations.into_iter().map(Into::into).collect(),
+            submissions: history.submissions.into_iter().map(Into::into).collect(),
+            retries: history.retries.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<aggregator::QuorumProgress> for proto::QuorumProgress {
+    fn from(progress: aggregator::QuorumProgress) -> Self {
+        Self {
+            message_id: progress.message_id,
+            weight: progress.weight,
+            threshold: progress.threshold,
+            signers: progress.signers,
+            observed: progress.observed,
+        }
+    }
+}
+
+impl From<HeldUpdate> for proto::HeldUpdate {
+    fn from(update: HeldUpdate) -> Self {
+        Self {
+            message_id: update.message_id,
+            epoch: update.epoch,
+            weight_change: update.weight_change,
+        }
+    }
+}
+
+impl From<StatusEvent> for proto::StatusUpdate {
+    fn from(event: StatusEvent) -> Self {
+        Self {
+            message_id: event.message_id,
+            source_chain: event.source_chain,
+            target_chain: event.target_chain,
+            nonce: event.nonce,
+            status: status_name(event.status),
+            sender: event.sender,
+            recipient: event.recipient,
+            error: event.error,
+            timestamp: event.timestamp,
+        }
+    }
+}
+
+impl From<proto::ListMessagesRequest> for MessageParams {
+    fn from(request: proto::ListMessagesRequest) -> Self {
+        Self {
+            status: request.status,
+            source_chain: request.source_chain,
+            target_chain: request.target_chain,
+            sender: request.sender,
+            since: request.since,
+            until: request.until,
+            limit: request.limit.map(|limit| limit as usize),
+        }
+    }
+}
+
+impl From<proto::StreamStatusRequest> for Subscription {
+    fn from(request: proto::StreamStatusRequest) -> Self {
+        Self {
+            message_id: request.message_id,
+            address: request.address,
+            source_chain: request.source_chain,
+            target_chain: request.target_chain,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl BridgeNode for NodeService {
+    type StreamStatusStream = ReceiverStream<Result<proto::StatusUpdate, Status>>;
+
+    async fn get_info(&self, _request: Request<proto::GetInfoRequest>) -> Result<Response<proto::NodeInfo>, Status> {
+        Ok(Response::new(self.state.info.clone().into()))
+    }
+
+    async fn list_messages(
+        &self,
+        request: Request<proto::ListMessagesRequest>,
+    ) -> Result<Response<proto::ListMessagesResponse>, Status> {
+        let store = self.state.store.as_ref().ok_or_else(|| disabled("Message storage"))?;
+        let query = MessageParams::from(request.into_inner())
+            .to_query()
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+        let records = store.query(&query).await.map_err(|e| Status::internal(e.to_string()))?;
+        Ok(Response::new(proto::ListMessagesResponse {
+            messages: records.into_iter().map(Into::into).collect(),
+        }))
+    }
+
+    async fn get_message(
+        &self,
+        request: Request<proto::GetMessageRequest>,
+    ) -> Result<Response<proto::MessageHistory>, Status> {
+        let store = self.state.store.as_ref().ok_or_else(|| disabled("Message storage"))?;
+        let message_id = request.into_inner().message_id;
+        match super::message_history(store.as_ref(), &message_id).await {
+            Ok(Some(history)) => Ok(Response::new(history.into())),
+            Ok(None) => Err(Status::not_found(format!("Unknown message {}", message_id))),
+            Err(e) => Err(Status::internal(e.to_string())),
+        }
+    }
+
+    /// 推送匹配订阅的状态变化，客户端断开后停止
+    ///
+    /// 推送落后丢失事件时以 `DATA_LOSS` 结束流，客户端应通过 `ListMessages` 重新同步后再订阅。
+    async fn stream_status(
+        &self,
+        request: Request<proto::StreamStatusRequest>,
+    ) -> Result<Response<Self::StreamStatusStream>, Status> {
+        let feed = self.state.feed.as_ref().ok_or_else(|| disabled("Status streaming"))?;
+        let subscription = Subscription::from(request.into_inner());
+        if subscription.is_empty() {
+            return Err(Status::invalid_argument("Subscription needs message_id, address or chain filter"));
+        }
+        let mut events = feed.subscribe();
+        let (sender, receiver) = mpsc::channel(STREAM_BUFFER);
+        tokio::spawn(async move {
+            loop {
+                let update = tokio::select! {
+                    _ = sender.closed() => break,
+                    event = events.recv() => match event {
+                        Ok(event) if subscription.matches(&event) => Ok(event.into()),
+                        Ok(_) => continue,
+                        Err(RecvError::Lagged(skipped)) => {
+                            Err(Status::data_loss(format!("Stream fell behind and skipped {} updates", skipped)))
+                        }
+                        Err(RecvError::Closed) => break,
+                    },
+                };
+                let lagged = update.is_err();
+                if sender.send(update).await.is_err() || lagged {
+                    break;
+                }
+            }
+        });
+        Ok(Response::new(ReceiverStream::new(receiver)))
+    }
+
+    async fn submit_attestation(
+        &self,
+        request: Request<proto::Attestation>,
+    ) -> Result<Response<proto::QuorumProgress>, Status> {
+        let aggregator = self.state.aggregator.as_ref().ok_or_else(|| disabled("Attestation collection"))?;
+        let attestation = types::Attestation::try_from(request.into_inner())?;
+        match aggregator.submit(attestation).await {
+            Ok(progress) => Ok(Response::new(progress.into())),
+            Err(e) => Err(Status::invalid_argument(e.to_string())),
+        }
+    }
+
+    async fn get_quorum_progress(
+        &self,
+        request: Request<proto::GetQuorumProgressRequest>,
+    ) -> Result<Response<proto::QuorumProgress>, Status> {
+        let aggregator = self.state.aggregator.as_ref().ok_or_else(|| disabled("Attestation collection"))?;
+        let message_id = request.into_inner().message_id;
+        match aggregator.progress(&message_id).await {
+            Some(progress) => Ok(Response::new(progress.into())),
+            None => Err(Status::not_found(format!("No attestations for message {}", message_id))),
+        }
+    }
+
+    async fn list_held_updates(
+        &self,
+        _request: Request<proto::ListHeldUpdatesRequest>,
+    ) -> Result<Response<proto::ListHeldUpdatesResponse>, Status> {
+        let guard = self.state.validator_guard.as_ref().ok_or_else(|| disabled("Validator set guard"))?;
+        Ok(Response::new(proto::ListHeldUpdatesResponse {
+            updates: guard.held().into_iter().map(Into::into).collect(),
+        }))
+    }
+
+    async fn approve_update(
+        &self,
+        request: Request<proto::ApproveUpdateRequest>,
+    ) -> Result<Response<proto::ApproveUpdateResponse>, Status> {
+        let guard = self.state.validator_guard.as_ref().ok_or_else(|| disabled("Validator set guard"))?;
+        let message_id = request.into_inner().message_id;
+        let held = guard.approve(&message_id);
+        info!("Validator set update {} approved over gRPC (held: {})", message_id, held);
+        Ok(Response::new(proto::ApproveUpdateResponse { message_id, held }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tonic::codegen::tokio_stream::StreamExt;
+    use crate::config::StorageConfig;
+    use crate::testing::{transfer_payload, two_chain_config, TwoChainHarness, SOURCE_CHAIN, TARGET_CHAIN};
+
+    #[tokio::test]
+    async fn test_node_service_mirrors_rest_api() {
+        let path = std::env::temp_dir().join(format!("bridge-grpc-test-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let mut config = two_chain_config();
+        config.storage = Some(StorageConfig {
+            backend: "file".to_string(),
+            path: path.to_string_lossy().into_owned(),
+            url: None,
+            max_connections: 1,
+            retention: None,
+        });
+        let mut harness = TwoChainHarness::with_config(config).await.unwrap();
+        let service = NodeService::new(harness.relayer.api_state().unwrap());
+
+        let request = proto::StreamStatusRequest {
+            target_chain: Some(TARGET_CHAIN.to_string()),
+            ..Default::default()
+        };
+        let mut updates = service.stream_status(Request::new(request)).await.unwrap().into_inner();
+        harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([6u8; 32], 10));
+        harness.tick().await;
+
+        let pending = updates.next().await.unwrap().unwrap();
+        assert_eq!(pending.status, "Pending");
+        assert_eq!(pending.source_chain, SOURCE_CHAIN);
+        assert_eq!(updates.next().await.unwrap().unwrap().status, "Processed");
+
+        let request = proto::ListMessagesRequest {
+            status: Some("processed".to_string()),
+            ..Default::default()
+        };
+        let messages = service.list_messages(Request::new(request)).await.unwrap().into_inner().messages;
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].message_id, pending.message_id);
+        let request = proto::GetMessageRequest { message_id: pending.message_id.clone() };
+        let history = service.get_message(Request::new(request)).await.unwrap().into_inner();
+        assert_eq!(history.record.unwrap().status, "Processed");
+
+        // 与 HTTP 接口一致的错误：未知消息、非法参数、未启用的功能与空订阅
+        let request = proto::GetMessageRequest { message_id: "missing".to_string() };
+        assert_eq!(service.get_message(Request::new(request)).await.unwrap_err().code(), tonic::Code::NotFound);
+        let request = proto::ListMessagesRequest {
+            status: Some("bogus".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(service.list_messages(Request::new(request)).await.unwrap_err().code(), tonic::Code::InvalidArgument);
+        let request = Request::new(proto::ListHeldUpdatesRequest {});
+        assert_eq!(service.list_held_updates(request).await.unwrap_err().code(), tonic::Code::Unimplemented);
+        let request = Request::new(proto::StreamStatusRequest::default());
+        assert_eq!(service.stream_status(request).await.unwrap_err().code(), tonic::Code::InvalidArgument);
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,497 @@
+//! `bridge.node.v1` 协议的消息类型与服务桩
+//!
+//! 按 `proto/bridge.proto` 手工维护，结构与 tonic 生成代码一致，
+//! 构建时不依赖 protoc。
+
+use tonic::codegen::*;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GetInfoRequest {}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct NodeInfo {
+    #[prost(string, tag = "1")]
+    pub version: String,
+    #[prost(string, tag = "2")]
+    pub git_commit: String,
+    #[prost(string, repeated, tag = "3")]
+    pub features: Vec<String>,
+    #[prost(string, repeated, tag = "4")]
+    pub adapter_types: Vec<String>,
+    #[prost(uint32, repeated, tag = "5")]
+    pub message_format_versions: Vec<u32>,
+    #[prost(string, optional, tag = "6")]
+    pub config_fingerprint: Option<String>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ListMessagesRequest {
+    /// 状态名，不区分大小写
+    #[prost(string, optional, tag = "1")]
+    pub status: Option<String>,
+    #[prost(string, optional, tag = "2")]
+    pub source_chain: Option<String>,
+    #[prost(string, optional, tag = "3")]
+    pub target_chain: Option<String>,
+    #[prost(string, optional, tag = "4")]
+    pub sender: Option<String>,
+    #[prost(uint64, optional, tag = "5")]
+    pub since: Option<u64>,
+    #[prost(uint64, optional, tag = "6")]
+    pub until: Option<u64>,
+    #[prost(uint32, optional, tag = "7")]
+    pub limit: Option<u32>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ListMessagesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub messages: Vec<MessageRecord>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct FailureCause {
+    #[prost(string, tag = "1")]
+    pub kind: String,
+    #[prost(string, optional, tag = "2")]
+    pub location: Option<String>,
+    #[prost(uint64, optional, tag = "3")]
+    pub abort_code: Option<u64>,
+    #[prost(int64, optional, tag = "4")]
+    pub rpc_code: Option<i64>,
+    #[prost(string, tag = "5")]
+    pub hint: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MessageRecord {
+    #[prost(string, tag = "1")]
+    pub message_id: String,
+    #[prost(string, tag = "2")]
+    pub source_chain: String,
+    #[prost(string, tag = "3")]
+    pub target_chain: String,
+    #[prost(string, optional, tag = "4")]
+    pub asset: Option<String>,
+    #[prost(string, optional, tag = "5")]
+    pub sender: Option<String>,
+    #[prost(uint64, tag = "6")]
+    pub nonce: u64,
+    #[prost(string, tag = "7")]
+    pub status: String,
+    #[prost(uint64, tag = "8")]
+    pub observed_at: u64,
+    #[prost(uint64, optional, tag = "9")]
+    pub completed_at: Option<u64>,
+    #[prost(string, optional, tag = "10")]
+    pub error: Option<String>,
+    #[prost(message, optional, tag = "11")]
+    pub cause: Option<FailureCause>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GetMessageRequest {
+    #[prost(string, tag = "1")]
+    pub message_id: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubmissionRecord {
+    #[prost(string, tag = "1")]
+    pub message_id: String,
+    #[prost(string, tag = "2")]
+    pub chain: String,
+    #[prost(uint32, tag = "3")]
+    pub attempt: u32,
+    #[prost(string, optional, tag = "4")]
+    pub tx_hash: Option<String>,
+    #[prost(string, optional, tag = "5")]
+    pub error: Option<String>,
+    #[prost(uint64, tag = "6")]
+    pub submitted_at: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RetryRecord {
+    #[prost(string, tag = "1")]
+    pub message_id: String,
+    #[prost(uint32, tag = "2")]
+    pub attempt: u32,
+    #[prost(string, tag = "3")]
+    pub reason: String,
+    #[prost(uint64, tag = "4")]
+    pub retry_at: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MessageHistory {
+    #[prost(message, optional, tag = "1")]
+    pub record: Option<MessageRecord>,
+    #[prost(message, repeated, tag = "2")]
+    pub attestations: Vec<Attestation>,
+    #[prost(message, repeated, tag = "3")]
+    pub submissions: Vec<SubmissionRecord>,
+    #[prost(message, repeated, tag = "4")]
+    pub retries: Vec<RetryRecord>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct StreamStatusRequest {
+    #[prost(string, optional, tag = "1")]
+    pub message_id: Option<String>,
+    #[prost(string, optional, tag = "2")]
+    pub address: Option<String>,
+    #[prost(string, optional, tag = "3")]
+    pub source_chain: Option<String>,
+    #[prost(string, optional, tag = "4")]
+    pub target_chain: Option<String>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct StatusUpdate {
+    #[prost(string, tag = "1")]
+    pub message_id: String,
+    #[prost(string, tag = "2")]
+    pub source_chain: String,
+    #[prost(string, tag = "3")]
+    pub target_chain: String,
+    #[prost(uint64, tag = "4")]
+    pub nonce: u64,
+    #[prost(string, tag = "5")]
+    pub status: String,
+    #[prost(string, optional, tag = "6")]
+    pub sender: Option<String>,
+    #[prost(string, optional, tag = "7")]
+    pub recipient: Option<String>,
+    #[prost(string, optional, tag = "8")]
+    pub error: Option<String>,
+    #[prost(uint64, tag = "9")]
+    pub timestamp: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct BridgeMessage {
+    #[prost(uint64, tag = "1")]
+    pub nonce: u64,
+    #[prost(string, tag = "2")]
+    pub source_chain: String,
+    #[prost(string, tag = "3")]
+    pub target_chain: String,
+    #[prost(string, tag = "4")]
+    pub message_type: String,
+    #[prost(bytes = "vec", tag = "5")]
+    pub payload: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Attestation {
+    #[prost(string, tag = "1")]
+    pub message_id: String,
+    #[prost(message, optional, tag = "2")]
+    pub message: Option<BridgeMessage>,
+    #[prost(string, tag = "3")]
+    pub validator: String,
+    #[prost(string, tag = "4")]
+    pub public_key: String,
+    #[prost(bytes = "vec", tag = "5")]
+    pub signature: Vec<u8>,
+    #[prost(uint64, tag = "6")]
+    pub timestamp: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GetQuorumProgressRequest {
+    #[prost(string, tag = "1")]
+    pub message_id: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QuorumProgress {
+    #[prost(string, tag = "1")]
+    pub message_id: String,
+    #[prost(uint64, tag = "2")]
+    pub weight: u64,
+    #[prost(uint64, tag = "3")]
+    pub threshold: u64,
+    #[prost(string, repeated, tag = "4")]
+    pub signers: Vec<String>,
+    #[prost(bool, tag = "5")]
+    pub observed: bool,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ListHeldUpdatesRequest {}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct HeldUpdate {
+    #[prost(string, tag = "1")]
+    pub message_id: String,
+    #[prost(uint64, tag = "2")]
+    pub epoch: u64,
+    #[prost(double, tag = "3")]
+    pub weight_change: f64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ListHeldUpdatesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub updates: Vec<HeldUpdate>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ApproveUpdateRequest {
+    #[prost(string, tag = "1")]
+    pub message_id: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ApproveUpdateResponse {
+    #[prost(string, tag = "1")]
+    pub message_id: String,
+    #[prost(bool, tag = "2")]
+    pub held: bool,
+}
+
+const SERVICE_NAME: &str = "bridge.node.v1.BridgeNode";
+const GET_INFO_PATH: &str = "/bridge.node.v1.BridgeNode/GetInfo";
+const LIST_MESSAGES_PATH: &str = "/bridge.node.v1.BridgeNode/ListMessages";
+const GET_MESSAGE_PATH: &str = "/bridge.node.v1.BridgeNode/GetMessage";
+const STREAM_STATUS_PATH: &str = "/bridge.node.v1.BridgeNode/StreamStatus";
+const SUBMIT_ATTESTATION_PATH: &str = "/bridge.node.v1.BridgeNode/SubmitAttestation";
+const GET_QUORUM_PROGRESS_PATH: &str = "/bridge.node.v1.BridgeNode/GetQuorumProgress";
+const LIST_HELD_UPDATES_PATH: &str = "/bridge.node.v1.BridgeNode/ListHeldUpdates";
+const APPROVE_UPDATE_PATH: &str = "/bridge.node.v1.BridgeNode/ApproveUpdate";
+
+pub mod bridge_node_client {
+    use super::*;
+    use tonic::codegen::http::uri::PathAndQuery;
+
+    /// 一元调用的客户端方法，与 tonic 生成代码相同
+    macro_rules! unary_method {
+        ($name:ident, $method:literal, $path:ident, $request:ty, $response:ty) => {
+            pub async fn $name(
+                &mut self,
+                request: impl tonic::IntoRequest<$request>,
+            ) -> Result<tonic::Response<$response>, tonic::Status> {
+                self.ready().await?;
+                let mut request = request.into_request();
+                request.extensions_mut().insert(GrpcMethod::new(SERVICE_NAME, $method));
+                let codec = tonic_prost::ProstCodec::default();
+                self.inner.unary(request, PathAndQuery::from_static($path), codec).await
+            }
+        };
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct BridgeNodeClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+
+    impl<T> BridgeNodeClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            Self {
+                inner: tonic::client::Grpc::new(inner),
+            }
+        }
+
+        unary_method!(get_info, "GetInfo", GET_INFO_PATH, GetInfoRequest, NodeInfo);
+        unary_method!(list_messages, "ListMessages", LIST_MESSAGES_PATH, ListMessagesRequest, ListMessagesResponse);
+        unary_method!(get_message, "GetMessage", GET_MESSAGE_PATH, GetMessageRequest, MessageHistory);
+        unary_method!(submit_attestation, "SubmitAttestation", SUBMIT_ATTESTATION_PATH, Attestation, QuorumProgress);
+        unary_method!(
+            get_quorum_progress,
+            "GetQuorumProgress",
+            GET_QUORUM_PROGRESS_PATH,
+            GetQuorumProgressRequest,
+            QuorumProgress
+        );
+        unary_method!(
+            list_held_updates,
+            "ListHeldUpdates",
+            LIST_HELD_UPDATES_PATH,
+            ListHeldUpdatesRequest,
+            ListHeldUpdatesResponse
+        );
+        unary_method!(approve_update, "ApproveUpdate", APPROVE_UPDATE_PATH, ApproveUpdateRequest, ApproveUpdateResponse);
+
+        pub async fn stream_status(
+            &mut self,
+            request: impl tonic::IntoRequest<StreamStatusRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<StatusUpdate>>, tonic::Status> {
+            self.ready().await?;
+            let mut request = request.into_request();
+            request.extensions_mut().insert(GrpcMethod::new(SERVICE_NAME, "StreamStatus"));
+            let codec = tonic_prost::ProstCodec::default();
+            self.inner.server_streaming(request, PathAndQuery::from_static(STREAM_STATUS_PATH), codec).await
+        }
+
+        async fn ready(&mut self) -> Result<(), tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| tonic::Status::unknown(format!("Service was not ready: {}", e.into())))
+        }
+    }
+}
+
+pub mod bridge_node_server {
+    use super::*;
+
+    #[async_trait]
+    pub trait BridgeNode: Send + Sync + 'static {
+        /// `StreamStatus` 返回的推送流
+        type StreamStatusStream: tokio_stream::Stream<Item = Result<StatusUpdate, tonic::Status>> + Send + 'static;
+
+        async fn get_info(
+            &self,
+            request: tonic::Request<GetInfoRequest>,
+        ) -> Result<tonic::Response<NodeInfo>, tonic::Status>;
+
+        async fn list_messages(
+            &self,
+            request: tonic::Request<ListMessagesRequest>,
+        ) -> Result<tonic::Response<ListMessagesResponse>, tonic::Status>;
+
+        async fn get_message(
+            &self,
+            request: tonic::Request<GetMessageRequest>,
+        ) -> Result<tonic::Response<MessageHistory>, tonic::Status>;
+
+        async fn stream_status(
+            &self,
+            request: tonic::Request<StreamStatusRequest>,
+        ) -> Result<tonic::Response<Self::StreamStatusStream>, tonic::Status>;
+
+        async fn submit_attestation(
+            &self,
+            request: tonic::Request<Attestation>,
+        ) -> Result<tonic::Response<QuorumProgress>, tonic::Status>;
+
+        async fn get_quorum_progress(
+            &self,
+            request: tonic::Request<GetQuorumProgressRequest>,
+        ) -> Result<tonic::Response<QuorumProgress>, tonic::Status>;
+
+        async fn list_held_updates(
+            &self,
+            request: tonic::Request<ListHeldUpdatesRequest>,
+        ) -> Result<tonic::Response<ListHeldUpdatesResponse>, tonic::Status>;
+
+        async fn approve_update(
+            &self,
+            request: tonic::Request<ApproveUpdateRequest>,
+        ) -> Result<tonic::Response<ApproveUpdateResponse>, tonic::Status>;
+    }
+
+    #[derive(Debug)]
+    pub struct BridgeNodeServer<T> {
+        inner: Arc<T>,
+    }
+
+    impl<T> BridgeNodeServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self { inner }
+        }
+    }
+
+    impl<T> Clone for BridgeNodeServer<T> {
+        fn clone(&self) -> Self {
+            Self {
+                inner: self.inner.clone(),
+            }
+        }
+    }
+
+    impl<T: BridgeNode> tonic::server::NamedService for BridgeNodeServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+
+    /// 一元调用的服务适配，与 tonic 生成代码相同
+    macro_rules! unary_svc {
+        ($svc:ident, $method:ident, $request:ty, $response:ty) => {
+            struct $svc<T: BridgeNode>(Arc<T>);
+
+            impl<T: BridgeNode> tonic::server::UnaryService<$request> for $svc<T> {
+                type Response = $response;
+                type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+
+                fn call(&mut self, request: tonic::Request<$request>) -> Self::Future {
+                    let inner = Arc::clone(&self.0);
+                    Box::pin(async move { inner.$method(request).await })
+                }
+            }
+        };
+    }
+
+    unary_svc!(GetInfoSvc, get_info, GetInfoRequest, NodeInfo);
+    unary_svc!(ListMessagesSvc, list_messages, ListMessagesRequest, ListMessagesResponse);
+    unary_svc!(GetMessageSvc, get_message, GetMessageRequest, MessageHistory);
+    unary_svc!(SubmitAttestationSvc, submit_attestation, Attestation, QuorumProgress);
+    unary_svc!(GetQuorumProgressSvc, get_quorum_progress, GetQuorumProgressRequest, QuorumProgress);
+    unary_svc!(ListHeldUpdatesSvc, list_held_updates, ListHeldUpdatesRequest, ListHeldUpdatesResponse);
+    unary_svc!(ApproveUpdateSvc, approve_update, ApproveUpdateRequest, ApproveUpdateResponse);
+
+    struct StreamStatusSvc<T: BridgeNode>(Arc<T>);
+
+    impl<T: BridgeNode> tonic::server::ServerStreamingService<StreamStatusRequest> for StreamStatusSvc<T> {
+        type Response = StatusUpdate;
+        type ResponseStream = T::StreamStatusStream;
+        type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+
+        fn call(&mut self, request: tonic::Request<StreamStatusRequest>) -> Self::Future {
+            let inner = Arc::clone(&self.0);
+            Box::pin(async move { inner.stream_status(request).await })
+        }
+    }
+
+    impl<T, B> Service<http::Request<B>> for BridgeNodeServer<T>
+    where
+        T: BridgeNode,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = Arc::clone(&self.inner);
+            macro_rules! unary {
+                ($svc:ident) => {
+                    Box::pin(async move {
+                        let mut grpc = tonic::server::Grpc::new(tonic_prost::ProstCodec::default());
+                        Ok(grpc.unary($svc(inner), req).await)
+                    })
+                };
+            }
+            match req.uri().path() {
+                GET_INFO_PATH => unary!(GetInfoSvc),
+                LIST_MESSAGES_PATH => unary!(ListMessagesSvc),
+                GET_MESSAGE_PATH => unary!(GetMessageSvc),
+                SUBMIT_ATTESTATION_PATH => unary!(SubmitAttestationSvc),
+                GET_QUORUM_PROGRESS_PATH => unary!(GetQuorumProgressSvc),
+                LIST_HELD_UPDATES_PATH => unary!(ListHeldUpdatesSvc),
+                APPROVE_UPDATE_PATH => unary!(ApproveUpdateSvc),
+                STREAM_STATUS_PATH => Box::pin(async move {
+                    let mut grpc = tonic::server::Grpc::new(tonic_prost::ProstCodec::default());
+                    Ok(grpc.server_streaming(StreamStatusSvc(inner), req).await)
+                }),
+                _ => Box::pin(async move { Ok(tonic::Status::unimplemented("").into_http()) }),
+            }
+        }
+    }
+}
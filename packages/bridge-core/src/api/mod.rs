@@ -0,0 +1,421 @@
+use axum::{
+    extract::{ws::WebSocketUpgrade, Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::info;
+use crate::{
+    health::{HealthMonitor, HealthReport},
+    info::BuildInfo,
+    ledger::{fees::{self, ChainFees}, LedgerQuery},
+    relayer::{aggregator::AttestationAggregator, guard::ValidatorSetGuard, pause::AssetPauses, rotation::ValidatorRotation, timelock::Timelock},
+    schema::{self, SchemaDescriptor},
+    storage::{self, MessageQuery, MessageRecord, MessageStore, RetryRecord, SubmissionRecord},
+    types::{Attestation, KeyRotation, MessageStatus},
+    Error,
+};
+use stream::StatusFeed;
+
+// WebSocket 状态推送
+pub mod stream;
+// 与 HTTP 接口对应的 gRPC 服务
+#[cfg(feature = "grpc")]
+pub mod grpc;
+
+/// HTTP 接口共享状态
+#[derive(Clone)]
+pub struct ApiState {
+    pub info: BuildInfo,
+    pub aggregator: Option<Arc<AttestationAggregator>>,
+    pub store: Option<Arc<dyn MessageStore>>,
+    pub validator_guard: Option<Arc<ValidatorSetGuard>>,
+    /// 验证者集合轮换，未提供时 `/validator-set/key-rotations` 不可用
+    pub validator_rotation: Option<Arc<ValidatorRotation>>,
+    /// 大额转账时间锁，未配置时 `/timelock` 接口不可用
+    pub timelock: Option<Arc<Timelock>>,
+    /// 按资产暂停中继，未提供时 `/assets` 接口不可用
+    pub asset_pauses: Option<Arc<AssetPauses>>,
+    pub health: Option<Arc<HealthMonitor>>,
+    /// 消息状态推送，未提供时 `/ws` 不可用
+    pub feed: Option<StatusFeed>,
+}
+
+/// `GET /messages` 的查询参数
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MessageParams {
+    pub status: Option<String>,
+    pub source_chain: Option<String>,
+    pub target_chain: Option<String>,
+    pub sender: Option<String>,
+    pub since: Option<u64>,
+    pub until: Option<u64>,
+    pub limit: Option<usize>,
+}
+
+impl MessageParams {
+    fn to_query(&self) -> Result<MessageQuery, Error> {
+        Ok(MessageQuery {
+            status: self.status.as_deref().map(str::parse::<MessageStatus>).transpose()?,
+            source_chain: self.source_chain.clone(),
+            target_chain: self.target_chain.clone(),
+            chain: None,
+            sender: self.sender.clone(),
+            recipient: None,
+            since: self.since,
+            until: self.until,
+            limit: self.limit,
+        })
+    }
+}
+
+/// `GET /fees` 的查询参数，时间范围按分录时间过滤
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FeeParams {
+    pub since: Option<u64>,
+    pub until: Option<u64>,
+}
+
+/// `POST /assets/:asset/pause` 的请求体
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PauseRequest {
+    pub reason: Option<String>,
+}
+
+/// 单条消息的处理历史
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageHistory {
+    pub record: MessageRecord,
+    pub attestations: Vec<Attestation>,
+    pub submissions: Vec<SubmissionRecord>,
+    pub retries: Vec<RetryRecord>,
+}
+
+/// 构建 HTTP 路由
+pub fn router(state: ApiState) -> Router {
+    Router::new()
+        .route("/info", get(get_info))
+        .route("/status", get(get_status))
+        .route("/attestations", post(submit_attestation))
+        .route("/attestations/batch", post(submit_attestations))
+        .route("/attestations/:message_id", get(get_quorum_progress))
+        .route("/equivocations", get(list_equivocations))
+        .route("/messages", get(list_messages))
+        .route("/messages/:message_id", get(get_message_history))
+        .route("/fees", get(get_fee_report))
+        .route("/schemas", get(list_schemas).post(register_schema))
+        .route("/schemas/:message_type", get(get_schema_versions))
+        .route("/held", get(list_held_messages))
+        .route("/held/:message_id/release", post(release_held_message))
+        .route("/validator-set/held", get(list_held_updates))
+        .route("/validator-set/approvals/:message_id", post(approve_update))
+        .route("/validator-set/key-rotations", post(rotate_key))
+        .route("/timelock/held", get(list_timelocked_transfers))
+        .route("/timelock/cancellations/:message_id", post(cancel_transfer))
+        .route("/assets/paused", get(list_paused_assets))
+        .route("/assets/:asset/pause", post(pause_asset))
+        .route("/assets/:asset/resume", post(resume_asset))
+        .route("/ws", get(subscribe_status))
+        .with_state(state)
+}
+
+/// 在指定地址启动 HTTP 服务
+pub async fn serve(listen: &str, router: Router) -> Result<(), Error> {
+    let listener = tokio::net::TcpListener::bind(listen)
+        .await
+        .map_err(|e| Error::Network(format!("Failed to bind {}: {}", listen, e)))?;
+    info!("HTTP API listening on {}", listen);
+    axum::serve(listener, router)
+        .await
+        .map_err(|e| Error::Network(e.to_string()))
+}
+
+fn error_response(status: StatusCode, message: String) -> Response {
+    (status, Json(serde_json::json!({ "error": message }))).into_response()
+}
+
+async fn get_info(State(state): State<ApiState>) -> Json<BuildInfo> {
+    Json(state.info)
+}
+
+/// 运行状态：各链同步情况、gas 余额与待处理消息数，供 `bridge-cli top` 刷新
+async fn get_status(State(state): State<ApiState>) -> Response {
+    let Some(health) = &state.health else {
+        return error_response(StatusCode::NOT_FOUND, "Relayer status is unavailable".to_string());
+    };
+    Json::<HealthReport>(health.report(state.store.as_deref()).await).into_response()
+}
+
+async fn submit_attestation(State(state): State<ApiState>, Json(attestation): Json<Attestation>) -> Response {
+    let Some(aggregator) = &state.aggregator else {
+        return error_response(StatusCode::NOT_FOUND, "Attestation collection is disabled".to_string());
+    };
+    match aggregator.submit(attestation).await {
+        Ok(progress) => Json(progress).into_response(),
+        Err(e) => error_response(StatusCode::BAD_REQUEST, e.to_string()),
+    }
+}
+
+/// 批量提交证明，签名批量验证，按提交顺序返回每条证明的进度或错误
+async fn submit_attestations(State(state): State<ApiState>, Json(attestations): Json<Vec<Attestation>>) -> Response {
+    let Some(aggregator) = &state.aggregator else {
+        return error_response(StatusCode::NOT_FOUND, "Attestation collection is disabled".to_string());
+    };
+    let results: Vec<serde_json::Value> = aggregator
+        .submit_batch(attestations)
+        .await
+        .into_iter()
+        .map(|result| match result {
+            Ok(progress) => serde_json::json!({ "progress": progress }),
+            Err(e) => serde_json::json!({ "error": e.to_string() }),
+        })
+        .collect();
+    Json(results).into_response()
+}
+
+async fn get_quorum_progress(State(state): State<ApiState>, Path(message_id): Path<String>) -> Response {
+    let Some(aggregator) = &state.aggregator else {
+        return error_response(StatusCode::NOT_FOUND, "Attestation collection is disabled".to_string());
+    };
+    match aggregator.progress(&message_id).await {
+        Some(progress) => Json(progress).into_response(),
+        None => error_response(StatusCode::NOT_FOUND, format!("No attestations for message {}", message_id)),
+    }
+}
+
+/// 证明中发现的验证者双签证据，按发现顺序排列
+async fn list_equivocations(State(state): State<ApiState>) -> Response {
+    let Some(aggregator) = &state.aggregator else {
+        return error_response(StatusCode::NOT_FOUND, "Attestation collection is disabled".to_string());
+    };
+    Json(aggregator.equivocations().evidence()).into_response()
+}
+
+async fn list_messages(State(state): State<ApiState>, Query(params): Query<MessageParams>) -> Response {
+    let Some(store) = &state.store else {
+        return error_response(StatusCode::NOT_FOUND, "Message storage is disabled".to_string());
+    };
+    let query = match params.to_query() {
+        Ok(query) => query,
+        Err(e) => return error_response(StatusCode::BAD_REQUEST, e.to_string()),
+    };
+    match store.query(&query).await {
+        Ok(records) => Json(records).into_response(),
+        Err(e) => error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+async fn get_message_history(State(state): State<ApiState>, Path(message_id): Path<String>) -> Response {
+    let Some(store) = &state.store else {
+        return error_response(StatusCode::NOT_FOUND, "Message storage is disabled".to_string());
+    };
+    match message_history(store.as_ref(), &message_id).await {
+        Ok(Some(history)) => Json(history).into_response(),
+        Ok(None) => error_response(StatusCode::NOT_FOUND, format!("Unknown message {}", message_id)),
+        Err(e) => error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+async fn get_fee_report(State(state): State<ApiState>, Query(params): Query<FeeParams>) -> Response {
+    let Some(store) = &state.store else {
+        return error_response(StatusCode::NOT_FOUND, "Message storage is disabled".to_string());
+    };
+    let query = LedgerQuery { since: params.since, until: params.until, ..Default::default() };
+    match store.query_ledger(&query).await {
+        Ok(entries) => Json::<Vec<ChainFees>>(fees::fee_report(&entries)).into_response(),
+        Err(e) => error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+async fn list_schemas(State(state): State<ApiState>) -> Response {
+    schema_response(&state, None).await
+}
+
+async fn get_schema_versions(State(state): State<ApiState>, Path(message_type): Path<String>) -> Response {
+    schema_response(&state, Some(&message_type)).await
+}
+
+async fn schema_response(state: &ApiState, message_type: Option<&str>) -> Response {
+    let Some(store) = &state.store else {
+        return error_response(StatusCode::NOT_FOUND, "Message storage is disabled".to_string());
+    };
+    match store.schemas(message_type).await {
+        Ok(schemas) if schemas.is_empty() && message_type.is_some() => {
+            error_response(StatusCode::NOT_FOUND, format!("No schema registered for {}", message_type.unwrap_or_default()))
+        }
+        Ok(schemas) => Json(schemas).into_response(),
+        Err(e) => error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+async fn register_schema(State(state): State<ApiState>, Json(mut descriptor): Json<SchemaDescriptor>) -> Response {
+    let Some(store) = &state.store else {
+        return error_response(StatusCode::NOT_FOUND, "Message storage is disabled".to_string());
+    };
+    descriptor.registered_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    match schema::register(store.as_ref(), &descriptor).await {
+        Ok(()) => (StatusCode::CREATED, Json(descriptor)).into_response(),
+        Err(e @ Error::Config(_)) => error_response(StatusCode::BAD_REQUEST, e.to_string()),
+        Err(e) => error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+/// 暂缓队列中的消息，按暂缓时间排列
+async fn list_held_messages(State(state): State<ApiState>) -> Response {
+    let Some(store) = &state.store else {
+        return error_response(StatusCode::NOT_FOUND, "Message storage is disabled".to_string());
+    };
+    match store.held().await {
+        Ok(held) => Json(held).into_response(),
+        Err(e) => error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+/// 放行暂缓队列中的消息，中继器下一轮跳过其未通过的检查重新处理；不在暂缓队列中的消息返回 404
+async fn release_held_message(State(state): State<ApiState>, Path(message_id): Path<String>) -> Response {
+    let Some(store) = &state.store else {
+        return error_response(StatusCode::NOT_FOUND, "Message storage is disabled".to_string());
+    };
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    match storage::release_held(store.as_ref(), &message_id, now).await {
+        Ok(Some(held)) => {
+            info!("Held message {} released by operator", message_id);
+            Json(held).into_response()
+        }
+        Ok(None) => error_response(StatusCode::NOT_FOUND, format!("Message {} is not held", message_id)),
+        Err(e) => error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+async fn list_held_updates(State(state): State<ApiState>) -> Response {
+    let Some(guard) = &state.validator_guard else {
+        return error_response(StatusCode::NOT_FOUND, "Validator set guard is disabled".to_string());
+    };
+    Json(guard.held()).into_response()
+}
+
+async fn approve_update(State(state): State<ApiState>, Path(message_id): Path<String>) -> Response {
+    let Some(guard) = &state.validator_guard else {
+        return error_response(StatusCode::NOT_FOUND, "Validator set guard is disabled".to_string());
+    };
+    let held = guard.approve(&message_id);
+    info!("Validator set update {} approved (held: {})", message_id, held);
+    Json(serde_json::json!({ "message_id": message_id, "held": held })).into_response()
+}
+
+/// 应用验证者提交的密钥轮换声明，返回更新后的验证者集合
+///
+/// 旧密钥在声明的宽限期内仍可签名，新集合在下一轮处理时推送到各链合约。
+async fn rotate_key(State(state): State<ApiState>, Json(rotation): Json<KeyRotation>) -> Response {
+    let Some(validator_rotation) = &state.validator_rotation else {
+        return error_response(StatusCode::NOT_FOUND, "Validator rotation is disabled".to_string());
+    };
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    match validator_rotation.rotate_key(&rotation, now) {
+        Ok(set) => {
+            info!("Validator {} rotated its key, old key valid for {}s", rotation.address, rotation.grace_period);
+            if let Some(aggregator) = &state.aggregator {
+                aggregator.update(set.clone());
+            }
+            Json(set).into_response()
+        }
+        Err(e @ (Error::Rejected(_) | Error::Key(_))) => error_response(StatusCode::BAD_REQUEST, e.to_string()),
+        Err(e) => error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+async fn list_timelocked_transfers(State(state): State<ApiState>) -> Response {
+    let Some(timelock) = &state.timelock else {
+        return error_response(StatusCode::NOT_FOUND, "Timelock is disabled".to_string());
+    };
+    Json(timelock.held()).into_response()
+}
+
+/// 取消处于延迟期的转账，不在时间锁中的消息返回 404
+async fn cancel_transfer(State(state): State<ApiState>, Path(message_id): Path<String>) -> Response {
+    let Some(timelock) = &state.timelock else {
+        return error_response(StatusCode::NOT_FOUND, "Timelock is disabled".to_string());
+    };
+    if !timelock.cancel(&message_id) {
+        return error_response(StatusCode::NOT_FOUND, format!("Transfer {} is not timelocked", message_id));
+    }
+    info!("Timelocked transfer {} cancelled", message_id);
+    Json(serde_json::json!({ "message_id": message_id, "cancelled": true })).into_response()
+}
+
+async fn list_paused_assets(State(state): State<ApiState>) -> Response {
+    let Some(pauses) = &state.asset_pauses else {
+        return error_response(StatusCode::NOT_FOUND, "Asset pauses are disabled".to_string());
+    };
+    Json(pauses.list()).into_response()
+}
+
+/// 暂停资产的中继，未配置的资产返回 404
+async fn pause_asset(
+    State(state): State<ApiState>,
+    Path(asset): Path<String>,
+    Json(request): Json<PauseRequest>,
+) -> Response {
+    let Some(pauses) = &state.asset_pauses else {
+        return error_response(StatusCode::NOT_FOUND, "Asset pauses are disabled".to_string());
+    };
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    let reason = request.reason.unwrap_or_else(|| "Paused by operator".to_string());
+    match pauses.pause(&asset, reason, now) {
+        Ok(changed) => {
+            info!("Relaying of {} paused by operator", asset);
+            Json(serde_json::json!({ "asset": asset, "paused": true, "changed": changed })).into_response()
+        }
+        Err(e) => error_response(StatusCode::NOT_FOUND, e.to_string()),
+    }
+}
+
+/// 恢复资产的中继，暂停期间保留的转账在下一轮继续处理
+async fn resume_asset(State(state): State<ApiState>, Path(asset): Path<String>) -> Response {
+    let Some(pauses) = &state.asset_pauses else {
+        return error_response(StatusCode::NOT_FOUND, "Asset pauses are disabled".to_string());
+    };
+    match pauses.resume(&asset) {
+        Ok(changed) => {
+            info!("Relaying of {} resumed by operator", asset);
+            Json(serde_json::json!({ "asset": asset, "paused": false, "changed": changed })).into_response()
+        }
+        Err(e) => error_response(StatusCode::NOT_FOUND, e.to_string()),
+    }
+}
+
+/// 升级为 WebSocket 连接，按客户端的订阅推送消息状态变化
+async fn subscribe_status(State(state): State<ApiState>, ws: WebSocketUpgrade) -> Response {
+    let Some(feed) = state.feed.clone() else {
+        return error_response(StatusCode::NOT_FOUND, "Status streaming is disabled".to_string());
+    };
+    ws.on_upgrade(move |socket| stream::serve_socket(socket, feed))
+}
+
+/// 汇总消息的记录、证明、提交与重试历史
+pub async fn message_history(store: &dyn MessageStore, message_id: &str) -> Result<Option<MessageHistory>, Error> {
+    let Some(record) = store.get(message_id).await? else {
+        return Ok(None);
+    };
+    Ok(Some(MessageHistory {
+        record,
+        attestations: store.attestations(message_id).await?,
+        submissions: store.submissions(message_id).await?,
+        retries: store.retries(message_id).await?,
+    }))
+}
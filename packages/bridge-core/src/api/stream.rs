@@ -0,0 +1,257 @@
+use axum::extract::ws::{Message, WebSocket};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tracing::debug;
+use crate::types::MessageStatus;
+
+/// 状态推送缓冲的事件数，订阅者落后超过该数量时丢弃最旧的事件
+const FEED_CAPACITY: usize = 1024;
+
+/// 一次消息状态变化
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatusEvent {
+    pub message_id: String,
+    pub source_chain: String,
+    pub target_chain: String,
+    pub nonce: u64,
+    pub status: MessageStatus,
+    /// 源链上的发起账户，适配器无法提供时为 None
+    pub sender: Option<String>,
+    /// 转账接收者（十六进制），非转账消息为 None
+    pub recipient: Option<String>,
+    pub error: Option<String>,
+    pub timestamp: u64,
+}
+
+/// 消息状态变化的广播源，克隆后共享同一通道
+#[derive(Clone)]
+pub struct StatusFeed {
+    sender: broadcast::Sender<StatusEvent>,
+}
+
+impl Default for StatusFeed {
+    fn default() -> Self {
+        Self {
+            sender: broadcast::channel(FEED_CAPACITY).0,
+        }
+    }
+}
+
+impl StatusFeed {
+    /// 广播一次状态变化，没有订阅者时直接丢弃
+    pub fn publish(&self, event: StatusEvent) {
+        let _ = self.sender.send(event);
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<StatusEvent> {
+        self.sender.subscribe()
+    }
+}
+
+/// 订阅条件，设置的字段全部满足时推送；至少设置一个字段
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Subscription {
+    #[serde(default)]
+    pub message_id: Option<String>,
+    /// 发起账户或转账接收者，不区分大小写，`0x` 前缀可省略
+    #[serde(default)]
+    pub address: Option<String>,
+    #[serde(default)]
+    pub source_chain: Option<String>,
+    #[serde(default)]
+    pub target_chain: Option<String>,
+}
+
+impl Subscription {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.message_id.is_none() && self.address.is_none() && self.source_chain.is_none() && self.target_chain.is_none()
+    }
+
+    pub fn matches(&self, event: &StatusEvent) -> bool {
+        let address_matches = |address: &str| {
+            let address = normalize_address(address);
+            [&event.sender, &event.recipient]
+                .into_iter()
+                .flatten()
+                .any(|candidate| normalize_address(candidate) == address)
+        };
+        self.message_id.as_ref().is_none_or(|id| *id == event.message_id)
+            && self.address.as_deref().is_none_or(address_matches)
+            && self.source_chain.as_ref().is_none_or(|chain| *chain == event.source_chain)
+            && self.target_chain.as_ref().is_none_or(|chain| *chain == event.target_chain)
+    }
+}
+
+fn normalize_address(address: &str) -> String {
+    address.trim_start_matches("0x").to_ascii_lowercase()
+}
+
+/// 客户端发送的请求
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum ClientRequest {
+    Subscribe(Subscription),
+    Unsubscribe { id: u64 },
+}
+
+/// 推送给客户端的消息
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    Subscribed { id: u64 },
+    Unsubscribed { id: u64 },
+    /// 匹配订阅的状态变化，`subscriptions` 为命中的订阅 ID
+    Status { subscriptions: Vec<u64>, event: StatusEvent },
+    /// 推送落后被丢弃的事件数，客户端应通过 REST 接口重新同步
+    Lagged { skipped: u64 },
+    Error { message: String },
+}
+
+/// 单个连接的订阅状态
+#[derive(Debug, Default)]
+pub struct Session {
+    subscriptions: BTreeMap<u64, Subscription>,
+    next_id: u64,
+}
+
+impl Session {
+    /// 处理客户端发来的一条文本消息
+    pub fn handle(&mut self, text: &str) -> ServerMessage {
+        let request = match serde_json::from_str::<ClientRequest>(text) {
+            Ok(request) => request,
+            Err(e) => return ServerMessage::Error { message: format!("Invalid request: {}", e) },
+        };
+        match request {
+            ClientRequest::Subscribe(subscription) if subscription.is_empty() => ServerMessage::Error {
+                message: "Subscription needs message_id, address or chain filter".to_string(),
+            },
+            ClientRequest::Subscribe(subscription) => {
+                self.next_id += 1;
+                self.subscriptions.insert(self.next_id, subscription);
+                ServerMessage::Subscribed { id: self.next_id }
+            }
+            ClientRequest::Unsubscribe { id } => match self.subscriptions.remove(&id) {
+                Some(_) => ServerMessage::Unsubscribed { id },
+                None => ServerMessage::Error { message: format!("Unknown subscription {}", id) },
+            },
+        }
+    }
+
+    /// 事件命中任一订阅时返回推送内容
+    pub fn deliver(&self, event: &StatusEvent) -> Option<ServerMessage> {
+        let subscriptions: Vec<u64> = self.subscriptions
+            .iter()
+            .filter(|(_, subscription)| subscription.matches(event))
+            .map(|(id, _)| *id)
+            .collect();
+        (!subscriptions.is_empty()).then(|| ServerMessage::Status {
+            subscriptions,
+            event: event.clone(),
+        })
+    }
+}
+
+/// 在 WebSocket 连接上处理订阅请求并推送状态变化，直到连接关闭
+pub async fn serve_socket(mut socket: WebSocket, feed: StatusFeed) {
+    let mut events = feed.subscribe();
+    let mut session = Session::default();
+    loop {
+        let reply = tokio::select! {
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Text(text))) => Some(session.handle(&text)),
+                Some(Ok(Message::Close(_))) | None => break,
+                Some(Ok(_)) => None,
+                Some(Err(e)) => {
+                    debug!("WebSocket connection closed: {}", e);
+                    break;
+                }
+            },
+            event = events.recv() => match event {
+                Ok(event) => session.deliver(&event),
+                Err(RecvError::Lagged(skipped)) => Some(ServerMessage::Lagged { skipped }),
+                Err(RecvError::Closed) => break,
+            },
+        };
+        let Some(reply) = reply else {
+            continue;
+        };
+        let text = match serde_json::to_string(&reply) {
+            Ok(text) => text,
+            Err(e) => {
+                debug!("Failed to encode WebSocket message: {}", e);
+                continue;
+            }
+        };
+        if socket.send(Message::Text(text)).await.is_err() {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(message_id: &str, sender: Option<&str>) -> StatusEvent {
+        StatusEvent {
+            message_id: message_id.to_string(),
+            source_chain: "sui".to_string(),
+            target_chain: "rooch".to_string(),
+            nonce: 1,
+            status: MessageStatus::Processed,
+            sender: sender.map(str::to_string),
+            recipient: Some("ab".repeat(32)),
+            error: None,
+            timestamp: 10,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_session_filters_feed_events() {
+        let feed = StatusFeed::default();
+        let mut events = feed.subscribe();
+        let mut session = Session::default();
+
+        assert_eq!(session.handle(r#"{"op":"subscribe","message_id":"m1"}"#), ServerMessage::Subscribed { id: 1 });
+        assert_eq!(session.handle(r#"{"op":"subscribe","address":"0xBEEF"}"#), ServerMessage::Subscribed { id: 2 });
+        assert_eq!(
+            session.handle(r#"{"op":"subscribe","source_chain":"sui","target_chain":"aptos"}"#),
+            ServerMessage::Subscribed { id: 3 }
+        );
+        assert!(matches!(session.handle(r#"{"op":"subscribe"}"#), ServerMessage::Error { .. }));
+
+        feed.publish(event("m1", Some("beef")));
+        feed.publish(event("m2", None));
+        let first = events.recv().await.unwrap();
+        assert!(matches!(session.deliver(&first), Some(ServerMessage::Status { subscriptions, .. }) if subscriptions == vec![1, 2]));
+        assert_eq!(session.deliver(&events.recv().await.unwrap()), None);
+
+        // 接收者地址同样可以订阅
+        let recipient = format!("0x{}", "AB".repeat(32));
+        let id = match session.handle(&format!(r#"{{"op":"subscribe","address":"{}"}}"#, recipient)) {
+            ServerMessage::Subscribed { id } => id,
+            other => panic!("unexpected reply {:?}", other),
+        };
+        assert!(session.deliver(&event("m2", None)).is_some());
+        assert_eq!(session.handle(&format!(r#"{{"op":"unsubscribe","id":{}}}"#, id)), ServerMessage::Unsubscribed { id });
+        assert_eq!(session.deliver(&event("m2", None)), None);
+    }
+
+    #[tokio::test]
+    async fn test_relayer_publishes_status_transitions() {
+        use crate::testing::{transfer_payload, TwoChainHarness, TARGET_CHAIN};
+
+        let mut harness = TwoChainHarness::new().await.unwrap();
+        let mut events = harness.relayer.status_feed().subscribe();
+        harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([5u8; 32], 10));
+        harness.tick().await;
+
+        let pending = events.recv().await.unwrap();
+        assert_eq!(pending.status, MessageStatus::Pending);
+        assert_eq!(pending.recipient, Some("05".repeat(32)));
+        let processed = events.recv().await.unwrap();
+        assert_eq!(processed.status, MessageStatus::Processed);
+        assert_eq!(processed.message_id, pending.message_id);
+    }
+}
@@ -0,0 +1,230 @@
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use tokio::time::sleep;
+use tracing::{info, error, warn};
+use crate::{
+    config::{Config, ValidatorConfig},
+    chain_adapter::ChainAdapter,
+    keystore::Keystore,
+    ledger,
+    proof::ProofVerifier,
+    relayer::{ChainAdapterFactory, DefaultChainAdapterFactory},
+    types::{Attestation, ChainId, ChainRegistry, CrossChainMessage, SignedMessage},
+    verify::NonceState,
+    Error,
+};
+
+/// 证明收集端点的 HTTP 客户端
+pub struct CollectorClient {
+    client: reqwest::Client,
+    endpoint: String,
+}
+
+impl CollectorClient {
+    pub fn new(endpoint: &str) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// 发布一条证明到收集端点
+    pub async fn publish(&self, attestation: &Attestation) -> Result<(), Error> {
+        let response = self.client
+            .post(format!("{}/attestations", self.endpoint))
+            .json(attestation)
+            .send()
+            .await
+            .map_err(|e| Error::Network(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(Error::Network(format!(
+                "Collector rejected attestation {}: {}",
+                attestation.message_id,
+                response.status()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// 验证者证明服务
+///
+/// 独立验证者运行该服务：监听自己负责的源链，对观察到的消息的规范哈希签名，
+/// 并把证明发布到收集端点或 p2p 网络，由中继器汇总达到阈值后再中继。
+pub struct AttestationService {
+    config: Config,
+    /// 规范哈希使用的数字链 ID
+    chains: ChainRegistry,
+    adapters: HashMap<ChainId, Box<dyn ChainAdapter>>,
+    keystore: Keystore,
+    validator: ValidatorConfig,
+    collector: Option<CollectorClient>,
+    /// 启动时从各目标链合约恢复的已处理 nonce
+    nonces: NonceState,
+    /// 源链事件包含证明的验证，没有链配置轻客户端时为 None
+    proofs: Option<ProofVerifier>,
+    #[cfg(feature = "p2p")]
+    p2p: Option<crate::p2p::P2pHandle>,
+}
+
+impl AttestationService {
+    pub async fn new(config: Config, collector_endpoint: Option<&str>) -> Result<Self, Error> {
+        if collector_endpoint.is_none() && config.p2p.is_none() {
+            return Err(Error::Config("Attestation service requires a collector endpoint or p2p config".to_string()));
+        }
+        let chains = config.chain_registry()?;
+        let factory = DefaultChainAdapterFactory::new(&config);
+        let mut adapters = HashMap::new();
+        for chain in &config.chains {
+            adapters.insert(chain.id.clone(), factory.create_adapter(chain).await?);
+        }
+
+        // 只有通过链上注册核对的密钥才能用于签名
+        let mut keystore = Keystore::open(&config.relayer).await?
+            .ok_or_else(|| Error::Config("Attestation service requires relayer.keystore or relayer.signer".to_string()))?;
+        keystore.verify_registrations(&config, &adapters).await?;
+
+        let public_key = keystore.verified_keys().into_iter().next()
+            .ok_or_else(|| Error::Key("No verified validator key in keystore".to_string()))?;
+        let validator = config.validators.iter()
+            .find(|v| v.public_key.trim_start_matches("0x").eq_ignore_ascii_case(&public_key))
+            .cloned()
+            .ok_or_else(|| Error::Key(format!("No validator configured for key {}", public_key)))?;
+
+        // 重启后不再为合约已消费的消息签名
+        let mut nonces = NonceState::default();
+        nonces.reconcile(&adapters).await?;
+        let proofs = ProofVerifier::from_config(&config)?;
+
+        // p2p 节点身份由验证者密钥派生
+        #[cfg(feature = "p2p")]
+        let p2p = match &config.p2p {
+            Some(_) => Some(crate::p2p::spawn(&config, crate::p2p::node_identity(Some(&keystore))?, None)?),
+            None => None,
+        };
+
+        Ok(Self {
+            config,
+            chains,
+            adapters,
+            keystore,
+            validator,
+            collector: collector_endpoint.map(CollectorClient::new),
+            nonces,
+            proofs,
+            #[cfg(feature = "p2p")]
+            p2p,
+        })
+    }
+
+    /// 对提交到目标链的消息的规范哈希签名，生成证明
+    ///
+    /// 转账金额先按目标链精度换算（见 [`ledger::submitted_message`]），签名覆盖的金额即中继器提交的金额。
+    pub async fn attest(&self, message: &CrossChainMessage) -> Result<Attestation, Error> {
+        let message = &ledger::submitted_message(&self.config, message)?;
+        let hash = message.canonical_hash(&self.chains)?;
+        let signature = self.keystore.sign_message(&self.validator.public_key, message, &self.chains).await?;
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|e| Error::Chain(format!("Failed to get current time: {}", e)))?
+            .as_secs();
+
+        Ok(Attestation {
+            message_id: hex::encode(hash),
+            message: message.clone(),
+            validator: self.validator.address.clone(),
+            public_key: self.validator.public_key.trim_start_matches("0x").to_lowercase(),
+            signature,
+            timestamp,
+        })
+    }
+
+    /// 配置了轻客户端的源链，只为通过包含证明验证的事件签名
+    async fn check_proof(&self, adapter: &dyn ChainAdapter, message: &SignedMessage) -> Result<(), Error> {
+        let Some(proofs) = self.proofs.as_ref().filter(|proofs| proofs.covers(&adapter.config().id)) else {
+            return Ok(());
+        };
+        let proof = match &message.proof {
+            Some(proof) => proof.clone(),
+            None => adapter.inclusion_proof(message).await?
+                .ok_or_else(|| Error::InvalidProof(format!("Chain {} provided no inclusion proof", adapter.config().id)))?,
+        };
+        proofs.verify(&adapter.config().id, &message.message, &proof)?;
+        Ok(())
+    }
+
+    /// 将证明发布到收集端点和 p2p 网络
+    async fn publish(&self, attestation: &Attestation) -> Result<(), Error> {
+        #[cfg(feature = "p2p")]
+        if let Some(p2p) = &self.p2p {
+            p2p.publish(attestation.clone())?;
+        }
+        if let Some(collector) = &self.collector {
+            collector.publish(attestation).await?;
+        }
+        Ok(())
+    }
+
+    /// 持续监听源链并发布证明
+    pub async fn run(&self) -> Result<(), Error> {
+        info!("Starting attestation service for validator {}", self.validator.address);
+        let mut attested: HashSet<String> = HashSet::new();
+
+        loop {
+            for chain_id in &self.validator.chains {
+                let Some(adapter) = self.adapters.get(chain_id.as_str()) else {
+                    warn!("Chain {} is not configured, skipping", chain_id);
+                    continue;
+                };
+
+                let messages = match adapter.listen_events().await {
+                    Ok(messages) => messages,
+                    Err(e) => {
+                        error!("Failed to listen events on {}: {}", chain_id, e);
+                        continue;
+                    }
+                };
+
+                for message in messages {
+                    if self.nonces.is_consumed(&message.message) {
+                        warn!(
+                            "Skipping message {} from {} to {}: already consumed on chain",
+                            message.message.nonce, message.message.source_chain, message.message.target_chain
+                        );
+                        continue;
+                    }
+                    let message_id = match message.message.message_id(&self.chains) {
+                        Ok(message_id) if attested.contains(&message_id) => continue,
+                        Ok(message_id) => message_id,
+                        Err(e) => {
+                            error!("Failed to hash message {}: {}", message.message.nonce, e);
+                            continue;
+                        }
+                    };
+                    if let Err(e) = self.check_proof(adapter.as_ref(), &message).await {
+                        error!("Not attesting message {} from {}: {}", message.message.nonce, chain_id, e);
+                        continue;
+                    }
+                    let attestation = match self.attest(&message.message).await {
+                        Ok(attestation) => attestation,
+                        Err(e) => {
+                            error!("Failed to attest message {}: {}", message.message.nonce, e);
+                            continue;
+                        }
+                    };
+
+                    match self.publish(&attestation).await {
+                        Ok(()) => {
+                            info!("Published attestation for message {}", attestation.message_id);
+                            attested.insert(message_id);
+                        }
+                        Err(e) => error!("Failed to publish attestation {}: {}", attestation.message_id, e),
+                    }
+                }
+            }
+
+            sleep(Duration::from_secs(self.config.relayer.poll_interval)).await;
+        }
+    }
+}
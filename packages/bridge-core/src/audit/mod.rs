@@ -0,0 +1,263 @@
+use blake2::{Blake2b, Digest, digest::consts::U32};
+use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use tokio::sync::Mutex;
+use crate::{
+    config::AuditConfig,
+    keystore::verify_signature,
+    Error,
+};
+
+/// 链首条目的 `prev_hash`
+const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// 中继决策
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditDecision {
+    /// 消息通过中继前检查，进入提交流程
+    Accepted,
+    /// 消息被拒绝，不会中继
+    Rejected,
+    /// 消息暂缓中继，等待人工处理
+    Held,
+    /// 地址命中筛查名单，按策略照常中继或延迟
+    Flagged,
+    /// 交易已提交到目标链
+    Submitted,
+    /// 提交失败
+    Failed,
+}
+
+/// 一条中继决策的内容
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    pub message_id: String,
+    pub decision: AuditDecision,
+    /// 决策涉及的链：提交类决策为目标链，其余为源链
+    pub chain: String,
+    /// 拒绝、暂缓或失败的原因
+    pub reason: Option<String>,
+    pub tx_hash: Option<String>,
+}
+
+/// 审计日志中的一行
+///
+/// `hash` 为除 `hash` 与 `signature` 外全部字段规范 JSON 的 Blake2b-256 摘要，
+/// 其中包含上一条目的 `hash`，任何条目被修改、删除或插入都会使后续的链接断开。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub seq: u64,
+    pub timestamp: u64,
+    #[serde(flatten)]
+    pub record: AuditRecord,
+    pub prev_hash: String,
+    /// 签名公钥（十六进制）
+    pub signer: String,
+    pub hash: String,
+    /// 对 `hash` 原始字节的 ed25519 签名（十六进制）
+    pub signature: String,
+}
+
+/// 参与哈希计算的字段，字段顺序固定
+#[derive(Serialize)]
+struct AuditBody<'a> {
+    seq: u64,
+    timestamp: u64,
+    record: &'a AuditRecord,
+    prev_hash: &'a str,
+    signer: &'a str,
+}
+
+impl AuditEntry {
+    fn compute_hash(&self) -> Result<[u8; 32], Error> {
+        let body = AuditBody {
+            seq: self.seq,
+            timestamp: self.timestamp,
+            record: &self.record,
+            prev_hash: &self.prev_hash,
+            signer: &self.signer,
+        };
+        let bytes = serde_json::to_vec(&body).map_err(|e| Error::Serialization(e.to_string()))?;
+        Ok(Blake2b::<U32>::digest(bytes).into())
+    }
+}
+
+/// 追加写入的审计日志，由中继器持有
+pub struct AuditLog {
+    keypair: Keypair,
+    state: Mutex<AuditState>,
+}
+
+struct AuditState {
+    file: File,
+    next_seq: u64,
+    prev_hash: String,
+}
+
+impl AuditLog {
+    /// 打开审计日志，已有日志时先完整校验再续写，链已断开时拒绝启动
+    pub fn open(config: &AuditConfig) -> Result<Self, Error> {
+        let keypair = load_signing_key(&config.signing_key)?;
+        let (next_seq, prev_hash) = if Path::new(&config.path).exists() {
+            let report = verify(&config.path, Some(&keypair.public.to_bytes()))?;
+            (report.entries, report.head.unwrap_or_else(|| GENESIS_HASH.to_string()))
+        } else {
+            (0, GENESIS_HASH.to_string())
+        };
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&config.path)
+            .map_err(|e| Error::Config(format!("Failed to open audit log: {}", e)))?;
+        Ok(Self {
+            keypair,
+            state: Mutex::new(AuditState { file, next_seq, prev_hash }),
+        })
+    }
+
+    /// 签名并追加一条决策
+    pub async fn append(&self, record: AuditRecord, timestamp: u64) -> Result<AuditEntry, Error> {
+        let mut state = self.state.lock().await;
+        let mut entry = AuditEntry {
+            seq: state.next_seq,
+            timestamp,
+            record,
+            prev_hash: state.prev_hash.clone(),
+            signer: hex::encode(self.keypair.public.to_bytes()),
+            hash: String::new(),
+            signature: String::new(),
+        };
+        let hash = entry.compute_hash()?;
+        entry.hash = hex::encode(hash);
+        entry.signature = hex::encode(self.keypair.sign(&hash).to_bytes());
+
+        let mut line = serde_json::to_vec(&entry).map_err(|e| Error::Serialization(e.to_string()))?;
+        line.push(b'\n');
+        state.file
+            .write_all(&line)
+            .and_then(|_| state.file.flush())
+            .map_err(|e| Error::Config(format!("Failed to write audit log: {}", e)))?;
+        state.next_seq += 1;
+        state.prev_hash = entry.hash.clone();
+        Ok(entry)
+    }
+}
+
+/// 从十六进制 ed25519 私钥文件加载审计签名密钥
+pub fn load_signing_key<P: AsRef<Path>>(path: P) -> Result<Keypair, Error> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| Error::Key(format!("Failed to read audit signing key: {}", e)))?;
+    let bytes = hex::decode(content.trim().trim_start_matches("0x"))
+        .map_err(|e| Error::Key(format!("Invalid audit signing key encoding: {}", e)))?;
+    let secret = SecretKey::from_bytes(&bytes)
+        .map_err(|e| Error::Key(format!("Invalid audit signing key: {}", e)))?;
+    let public = PublicKey::from(&secret);
+    Ok(Keypair { secret, public })
+}
+
+/// 审计日志校验结果
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VerifyReport {
+    pub entries: u64,
+    /// 最后一条目的哈希，空日志时为 None
+    pub head: Option<String>,
+}
+
+/// 校验审计日志：逐条检查序号连续、哈希链接、条目哈希与签名
+///
+/// 指定 `public_key` 时要求所有条目都由该公钥签名，否则只校验各条目签名自洽。
+/// 发现篡改时返回指出首个问题条目的错误。
+pub fn verify<P: AsRef<Path>>(path: P, public_key: Option<&[u8; 32]>) -> Result<VerifyReport, Error> {
+    let file = File::open(path).map_err(|e| Error::Config(format!("Failed to open audit log: {}", e)))?;
+    let expected_signer = public_key.map(hex::encode);
+    let mut prev_hash = GENESIS_HASH.to_string();
+    let mut entries = 0u64;
+
+    for (index, line) in BufReader::new(file).lines().enumerate() {
+        let line = line.map_err(|e| Error::Config(format!("Failed to read audit log: {}", e)))?;
+        let tampered = |reason: &str| Error::Config(format!("Audit log line {}: {}", index + 1, reason));
+        let entry: AuditEntry = serde_json::from_str(&line).map_err(|e| tampered(&format!("malformed entry: {}", e)))?;
+
+        if entry.seq != entries {
+            return Err(tampered(&format!("expected sequence {}, found {}", entries, entry.seq)));
+        }
+        if entry.prev_hash != prev_hash {
+            return Err(tampered("hash chain is broken"));
+        }
+        let hash = entry.compute_hash()?;
+        if hex::encode(hash) != entry.hash {
+            return Err(tampered("entry hash does not match its content"));
+        }
+        if expected_signer.as_ref().is_some_and(|signer| *signer != entry.signer) {
+            return Err(tampered(&format!("signed by unexpected key {}", entry.signer)));
+        }
+        let signer = hex::decode(&entry.signer).map_err(|_| tampered("invalid signer encoding"))?;
+        let signature = hex::decode(&entry.signature).map_err(|_| tampered("invalid signature encoding"))?;
+        verify_signature(&signer, &hash, &signature).map_err(|e| tampered(&e.to_string()))?;
+
+        prev_hash = entry.hash;
+        entries += 1;
+    }
+
+    Ok(VerifyReport {
+        entries,
+        head: (entries > 0).then_some(prev_hash),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(message_id: &str, decision: AuditDecision) -> AuditRecord {
+        AuditRecord {
+            message_id: message_id.to_string(),
+            decision,
+            chain: "rooch".to_string(),
+            reason: None,
+            tx_hash: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_detects_tampering() {
+        let dir = std::env::temp_dir();
+        let key_path = dir.join(format!("bridge-audit-key-{}", std::process::id()));
+        let log_path = dir.join(format!("bridge-audit-test-{}.jsonl", std::process::id()));
+        std::fs::write(&key_path, hex::encode([7u8; 32])).unwrap();
+        let _ = std::fs::remove_file(&log_path);
+        let config = AuditConfig {
+            path: log_path.to_string_lossy().to_string(),
+            signing_key: key_path.to_string_lossy().to_string(),
+        };
+
+        let log = AuditLog::open(&config).unwrap();
+        log.append(record("a", AuditDecision::Accepted), 100).await.unwrap();
+        log.append(record("a", AuditDecision::Submitted), 101).await.unwrap();
+        drop(log);
+        // 重新打开后续写同一条链
+        let log = AuditLog::open(&config).unwrap();
+        log.append(record("b", AuditDecision::Rejected), 102).await.unwrap();
+
+        let public_key = load_signing_key(&key_path).unwrap().public.to_bytes();
+        assert_eq!(verify(&log_path, Some(&public_key)).unwrap().entries, 3);
+        assert!(verify(&log_path, Some(&[1u8; 32])).is_err());
+
+        // 修改中间条目的决策
+        let content = std::fs::read_to_string(&log_path).unwrap();
+        std::fs::write(&log_path, content.replacen("submitted", "failed", 1)).unwrap();
+        assert!(verify(&log_path, None).unwrap_err().to_string().contains("line 2"));
+
+        // 删除中间条目
+        let lines: Vec<_> = content.lines().collect();
+        std::fs::write(&log_path, format!("{}\n{}\n", lines[0], lines[2])).unwrap();
+        assert!(verify(&log_path, None).is_err());
+
+        let _ = std::fs::remove_file(&log_path);
+        let _ = std::fs::remove_file(&key_path);
+    }
+}
@@ -0,0 +1,323 @@
+use blake2::{Blake2b, Digest, digest::consts::U32};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::Path;
+use tracing::info;
+use crate::{
+    config::{Config, ValidatorConfig},
+    keystore::{verify_signature, Keystore},
+    relayer::{ChainAdapterFactory, DefaultChainAdapterFactory},
+    storage::{self, MessageQuery},
+    types::MessageStatus,
+    Error,
+};
+
+/// 当前引导包格式版本
+pub const BUNDLE_VERSION: u8 = 2;
+
+/// 引导包签名的域分隔前缀，以 [`crate::signer::RAW_SIGNING_DOMAIN`] 开头
+const BUNDLE_SIGNING_DOMAIN: &[u8] = b"move-bridge/bootstrap/v2";
+
+/// 单条源链上已消费的 nonce 集合
+///
+/// 源链 nonce 从 0 开始单调递增，`cursor` 之前的 nonce 全部已消费，
+/// 之后零散消费的 nonce 记录在 `above` 中，插入时自动向前压缩。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NonceSet {
+    pub cursor: u64,
+    pub above: BTreeSet<u64>,
+}
+
+impl NonceSet {
+    /// 记录一个已消费的 nonce，已存在时返回 false
+    pub fn insert(&mut self, nonce: u64) -> bool {
+        if nonce < self.cursor || !self.above.insert(nonce) {
+            return false;
+        }
+        while self.above.remove(&self.cursor) {
+            self.cursor += 1;
+        }
+        true
+    }
+
+    pub fn contains(&self, nonce: u64) -> bool {
+        nonce < self.cursor || self.above.contains(&nonce)
+    }
+}
+
+/// 冷启动引导包：现有实例导出的游标、验证者集合纪元与已消费 nonce 快照
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BootstrapBundle {
+    pub version: u8,
+    pub created_at: u64,
+    pub validator_set_epoch: u64,
+    /// 验证者集合指纹，新实例的配置必须与之一致
+    pub validator_set_hash: String,
+    /// 各源链的游标：低于游标的 nonce 全部已消费
+    pub cursors: BTreeMap<String, u64>,
+    /// 各源链在游标之后已消费的 nonce
+    pub consumed_nonces: BTreeMap<String, Vec<u64>>,
+}
+
+impl BootstrapBundle {
+    /// 由已消费 nonce 快照生成引导包
+    pub fn new(config: &Config, consumed: &HashMap<String, NonceSet>, created_at: u64) -> Result<Self, Error> {
+        Ok(Self {
+            version: BUNDLE_VERSION,
+            created_at,
+            validator_set_epoch: config.relayer.validator_set_epoch,
+            validator_set_hash: validator_set_hash(&config.validators)?,
+            cursors: consumed.iter().map(|(chain, set)| (chain.clone(), set.cursor)).collect(),
+            consumed_nonces: consumed
+                .iter()
+                .map(|(chain, set)| (chain.clone(), set.above.iter().copied().collect()))
+                .collect(),
+        })
+    }
+
+    /// 还原各源链的已消费 nonce 集合
+    pub fn consumed(&self) -> HashMap<String, NonceSet> {
+        let mut consumed: HashMap<String, NonceSet> = self.cursors
+            .iter()
+            .map(|(chain, cursor)| (chain.clone(), NonceSet { cursor: *cursor, above: BTreeSet::new() }))
+            .collect();
+        for (chain, nonces) in &self.consumed_nonces {
+            let set = consumed.entry(chain.clone()).or_default();
+            for nonce in nonces {
+                set.insert(*nonce);
+            }
+        }
+        consumed
+    }
+
+    /// 签名摘要：引导包 JSON 编码的 Blake2b-256（BTreeMap 保证键顺序确定）
+    pub fn signing_hash(&self) -> Result<[u8; 32], Error> {
+        let bytes = serde_json::to_vec(self)
+            .map_err(|e| Error::Serialization(e.to_string()))?;
+        Ok(Blake2b::<U32>::digest(bytes).into())
+    }
+
+    /// 签名覆盖的内容：域分隔前缀后接签名摘要，与跨链消息的规范哈希区分
+    pub fn signing_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = BUNDLE_SIGNING_DOMAIN.to_vec();
+        bytes.extend(self.signing_hash()?);
+        Ok(bytes)
+    }
+}
+
+/// 验证者签名的引导包
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedBootstrapBundle {
+    pub bundle: BootstrapBundle,
+    /// 签名验证者地址
+    pub signer: String,
+    pub public_key: String,
+    pub signature: Vec<u8>,
+}
+
+impl SignedBootstrapBundle {
+    /// 使用已核对的验证者密钥签名引导包
+    pub async fn sign(bundle: BootstrapBundle, keystore: &Keystore, validator: &ValidatorConfig) -> Result<Self, Error> {
+        let signature = keystore.sign(&validator.public_key, &bundle.signing_bytes()?).await?;
+        Ok(Self {
+            bundle,
+            signer: validator.address.clone(),
+            public_key: validator.public_key.trim_start_matches("0x").to_lowercase(),
+            signature,
+        })
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| Error::Config(format!("Failed to read bootstrap bundle: {}", e)))?;
+        serde_json::from_str(&content)
+            .map_err(|e| Error::Serialization(format!("Failed to parse bootstrap bundle: {}", e)))
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| Error::Serialization(e.to_string()))?;
+        std::fs::write(path, content)
+            .map_err(|e| Error::Config(format!("Failed to write bootstrap bundle: {}", e)))
+    }
+
+    /// 校验引导包可被本实例信任
+    ///
+    /// 签名者必须是本地配置的验证者，且引导包的验证者集合与本地配置一致。
+    pub fn verify(&self, config: &Config) -> Result<&BootstrapBundle, Error> {
+        if self.bundle.version != BUNDLE_VERSION {
+            return Err(Error::Config(format!("Unsupported bootstrap bundle version: {}", self.bundle.version)));
+        }
+
+        let validator = config.validators
+            .iter()
+            .find(|v| v.address == self.signer)
+            .ok_or_else(|| Error::Key(format!("Bootstrap bundle signed by unknown validator: {}", self.signer)))?;
+        let public_key = hex::decode(validator.public_key.trim_start_matches("0x"))
+            .map_err(|e| Error::Key(format!("Invalid public key for {}: {}", validator.address, e)))?;
+        verify_signature(&public_key, &self.bundle.signing_bytes()?, &self.signature)?;
+
+        if self.bundle.validator_set_epoch != config.relayer.validator_set_epoch {
+            return Err(Error::Config(format!(
+                "Bootstrap bundle validator set epoch {} does not match local epoch {}",
+                self.bundle.validator_set_epoch, config.relayer.validator_set_epoch
+            )));
+        }
+        if self.bundle.validator_set_hash != validator_set_hash(&config.validators)? {
+            return Err(Error::Config("Bootstrap bundle validator set does not match local config".to_string()));
+        }
+        Ok(&self.bundle)
+    }
+}
+
+/// 验证者集合指纹：按地址排序后的 (地址, 公钥, 权重) 的 Blake2b-256
+pub fn validator_set_hash(validators: &[ValidatorConfig]) -> Result<String, Error> {
+    let mut entries: Vec<_> = validators
+        .iter()
+        .map(|v| (v.address.as_str(), v.public_key.trim_start_matches("0x").to_lowercase(), v.weight))
+        .collect();
+    entries.sort();
+    let bytes = serde_json::to_vec(&entries)
+        .map_err(|e| Error::Serialization(e.to_string()))?;
+    Ok(hex::encode(Blake2b::<U32>::digest(bytes)))
+}
+
+/// 从消息存储导出引导包，并用本地已核对的验证者密钥签名
+pub async fn export(config: &Config) -> Result<SignedBootstrapBundle, Error> {
+    let storage_config = config.storage.as_ref()
+        .ok_or_else(|| Error::Config("Bootstrap export requires message storage".to_string()))?;
+
+    let store = storage::open(storage_config).await?;
+    let records = store.query(&MessageQuery {
+        status: Some(MessageStatus::Processed),
+        ..Default::default()
+    }).await?;
+    let mut consumed: HashMap<String, NonceSet> = HashMap::new();
+    for record in &records {
+        consumed.entry(record.source_chain.clone()).or_default().insert(record.nonce);
+    }
+
+    // 只有通过链上注册核对的密钥才能为引导包背书
+    let factory = DefaultChainAdapterFactory::new(config);
+    let mut adapters = HashMap::new();
+    for chain in &config.chains {
+        adapters.insert(chain.id.clone(), factory.create_adapter(chain).await?);
+    }
+    let mut keystore = Keystore::open(&config.relayer).await?
+        .ok_or_else(|| Error::Config("Bootstrap export requires relayer.keystore or relayer.signer".to_string()))?;
+    keystore.verify_registrations(config, &adapters).await?;
+    let public_key = keystore.verified_keys().into_iter().next()
+        .ok_or_else(|| Error::Key("No verified validator key in keystore".to_string()))?;
+    let validator = config.validators
+        .iter()
+        .find(|v| v.public_key.trim_start_matches("0x").eq_ignore_ascii_case(&public_key))
+        .ok_or_else(|| Error::Key(format!("No validator configured for key {}", public_key)))?;
+
+    let created_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_err(|e| Error::Chain(format!("Failed to get current time: {}", e)))?
+        .as_secs();
+    let bundle = BootstrapBundle::new(config, &consumed, created_at)?;
+    info!(
+        "Exported bootstrap bundle with {} processed message(s) across {} chain(s)",
+        records.len(),
+        consumed.len()
+    );
+    SignedBootstrapBundle::sign(bundle, &keystore, validator).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RelayerConfig;
+    use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+
+    fn keypair() -> Keypair {
+        let secret = SecretKey::from_bytes(&[3u8; 32]).unwrap();
+        let public = PublicKey::from(&secret);
+        Keypair { secret, public }
+    }
+
+    fn config(keypair: &Keypair) -> Config {
+        Config {
+            chains: vec![],
+            assets: vec![],
+            nft_collections: vec![],
+            validators: vec![ValidatorConfig {
+                address: "0xvalidator0".to_string(),
+                public_key: hex::encode(keypair.public.to_bytes()),
+                bls_public_key: None,
+                weight: 1,
+                chains: vec![],
+            }],
+            relayer: RelayerConfig {
+                poll_interval: 1,
+                max_retries: 1,
+                retry_delay: 1,
+                keystore: None,
+                signer: None,
+                require_attestations: false,
+                attestation_threshold: None,
+                governance_threshold: None,
+                validator_set_epoch: 3,
+                coordination: None,
+                check_recipients: false,
+                validator_set_guard: None,
+                max_message_age: 3_600,
+                queue_capacity: 1_000,
+                confirmations: 0,
+                batch_size: 16,
+                priority: None,
+                script_timeout: 30,
+            },
+            storage: None,
+            api: None,
+            schemas: None,
+            audit: None,
+            screening: None,
+            health: None,
+            p2p: None,
+            watchtower: None,
+            slashing: None,
+            indexer: None,
+            alerts: None,
+            logging: None,
+            reconciliation: None,
+        }
+    }
+
+    #[test]
+    fn test_nonce_set_compacts() {
+        let mut set = NonceSet::default();
+        assert!(set.insert(1));
+        assert!(set.insert(0));
+        assert!(!set.insert(1));
+        assert!(set.insert(5));
+        assert_eq!(set.cursor, 2);
+        assert!(set.contains(5) && !set.contains(4));
+    }
+
+    #[test]
+    fn test_signed_bundle_roundtrip() {
+        let keypair = keypair();
+        let config = config(&keypair);
+        let mut consumed = HashMap::new();
+        for nonce in [0, 1, 2, 7] {
+            consumed.entry("sui_testnet".to_string()).or_insert_with(NonceSet::default).insert(nonce);
+        }
+
+        let bundle = BootstrapBundle::new(&config, &consumed, 100).unwrap();
+        let signature = keypair.sign(&bundle.signing_bytes().unwrap()).to_bytes().to_vec();
+        let mut signed = SignedBootstrapBundle {
+            bundle,
+            signer: "0xvalidator0".to_string(),
+            public_key: config.validators[0].public_key.clone(),
+            signature,
+        };
+        assert_eq!(signed.verify(&config).unwrap().consumed(), consumed);
+
+        // 篡改游标后签名失效
+        signed.bundle.cursors.insert("sui_testnet".to_string(), 100);
+        assert!(signed.verify(&config).is_err());
+    }
+}
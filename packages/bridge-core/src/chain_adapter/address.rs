@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+use crate::Error;
+
+/// Rooch bech32 地址的前缀
+const ROOCH_HRP: &str = "rooch";
+
+/// 链上地址格式，决定配置与负载中的地址如何解析和规范化
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AddressFormat {
+    /// `0x` 开头的 32 字节十六进制 Move 地址，可省略前导零（Sui、Movement）
+    Move,
+    /// Move 地址或 `rooch1` 开头的 bech32 地址
+    Rooch,
+    /// base58 编码的 32 字节公钥
+    Solana,
+    /// bech32 地址，前缀须与 `cosmos.address_prefix` 一致
+    Cosmos,
+    /// `0x` 开头的 20 字节十六进制地址，大小写混合时须符合 EIP-55 校验和
+    Evm,
+}
+
+impl AddressFormat {
+    /// 内置适配器使用的地址格式，通用 JSON-RPC 与第三方适配器没有固定格式
+    pub fn for_adapter(adapter_type: &str) -> Option<Self> {
+        match adapter_type {
+            "sui" | "movement" => Some(Self::Move),
+            "rooch" => Some(Self::Rooch),
+            "solana" => Some(Self::Solana),
+            "cosmos" => Some(Self::Cosmos),
+            _ => None,
+        }
+    }
+
+    /// 解析地址为原始字节，`prefix` 为 Cosmos 链的 bech32 前缀，未指定时不检查前缀
+    pub fn parse(self, address: &str, prefix: Option<&str>) -> Result<Vec<u8>, Error> {
+        let invalid = |reason: &str| Error::Config(format!("Invalid {} address {}: {}", self.name(), address, reason));
+        match self {
+            Self::Move => parse_move(address).map_err(|e| invalid(&e)),
+            Self::Rooch if address.starts_with(ROOCH_HRP) => {
+                let bytes = parse_bech32(address, ROOCH_HRP).map_err(|e| invalid(&e))?;
+                if bytes.len() != 32 {
+                    return Err(invalid(&format!("expected 32 bytes, got {}", bytes.len())));
+                }
+                Ok(bytes)
+            }
+            Self::Rooch => parse_move(address).map_err(|e| invalid(&e)),
+            Self::Solana => {
+                let bytes = bs58::decode(address).into_vec().map_err(|e| invalid(&e.to_string()))?;
+                if bytes.len() != 32 {
+                    return Err(invalid(&format!("expected 32 bytes, got {}", bytes.len())));
+                }
+                Ok(bytes)
+            }
+            Self::Cosmos => {
+                let hrp = match prefix {
+                    Some(prefix) => prefix.to_string(),
+                    None => address.rsplit_once('1').map(|(hrp, _)| hrp.to_lowercase()).unwrap_or_default(),
+                };
+                let bytes = parse_bech32(address, &hrp).map_err(|e| invalid(&e))?;
+                if bytes.len() != 20 && bytes.len() != 32 {
+                    return Err(invalid(&format!("expected 20 or 32 bytes, got {}", bytes.len())));
+                }
+                Ok(bytes)
+            }
+            Self::Evm => {
+                let digits = address.strip_prefix("0x").ok_or_else(|| invalid("missing 0x prefix"))?;
+                if digits.len() != 40 {
+                    return Err(invalid(&format!("expected 40 hex digits, got {}", digits.len())));
+                }
+                let bytes = hex::decode(digits).map_err(|e| invalid(&e.to_string()))?;
+                let mixed_case = digits.chars().any(|c| c.is_ascii_lowercase()) && digits.chars().any(|c| c.is_ascii_uppercase());
+                if mixed_case && eip55(&bytes) != address {
+                    return Err(invalid("checksum mismatch"));
+                }
+                Ok(bytes)
+            }
+        }
+    }
+
+    /// 地址的规范形式：Move 与 Rooch 为补齐前导零的小写十六进制，Cosmos 为小写 bech32，EVM 为 EIP-55 校验和形式
+    pub fn normalize(self, address: &str, prefix: Option<&str>) -> Result<String, Error> {
+        let bytes = self.parse(address, prefix)?;
+        Ok(match self {
+            Self::Move | Self::Rooch => format!("0x{}", hex::encode(bytes)),
+            Self::Solana => bs58::encode(bytes).into_string(),
+            Self::Cosmos => address.to_lowercase(),
+            Self::Evm => eip55(&bytes),
+        })
+    }
+
+    /// 检查负载中的原始地址字节长度，不合格时返回原因
+    pub fn check_bytes(self, address: &[u8]) -> Option<String> {
+        let valid = match self {
+            Self::Move | Self::Rooch | Self::Solana => address.len() == 32,
+            Self::Cosmos => address.len() == 20 || address.len() == 32,
+            Self::Evm => address.len() == 20,
+        };
+        if !valid {
+            return Some(format!("Recipient is not a valid {} address: {} bytes", self.name(), address.len()));
+        }
+        if address.iter().all(|b| *b == 0) {
+            return Some("Recipient is the zero address".to_string());
+        }
+        None
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Move => "Move",
+            Self::Rooch => "Rooch",
+            Self::Solana => "Solana",
+            Self::Cosmos => "Cosmos",
+            Self::Evm => "EVM",
+        }
+    }
+}
+
+fn parse_move(address: &str) -> Result<Vec<u8>, String> {
+    let digits = address.strip_prefix("0x").ok_or("missing 0x prefix")?;
+    if digits.is_empty() || digits.len() > 64 {
+        return Err(format!("expected 1 to 64 hex digits, got {}", digits.len()));
+    }
+    hex::decode(format!("{:0>64}", digits)).map_err(|e| e.to_string())
+}
+
+fn parse_bech32(address: &str, expected_hrp: &str) -> Result<Vec<u8>, String> {
+    let (hrp, bytes) = bech32::decode(address).map_err(|e| e.to_string())?;
+    if !hrp.as_str().eq_ignore_ascii_case(expected_hrp) {
+        return Err(format!("expected prefix {}, got {}", expected_hrp, hrp));
+    }
+    Ok(bytes)
+}
+
+/// EIP-55：小写十六进制的 Keccak-256 哈希对应半字节不小于 8 时字母大写
+fn eip55(address: &[u8]) -> String {
+    let digits = hex::encode(address);
+    let hash = Keccak256::digest(digits.as_bytes());
+    let checksummed: String = digits
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+            if nibble >= 8 { c.to_ascii_uppercase() } else { c }
+        })
+        .collect();
+    format!("0x{}", checksummed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_normalize_per_chain() {
+        assert_eq!(
+            AddressFormat::Move.normalize("0x2", None).unwrap(),
+            format!("0x{:0>64}", "2")
+        );
+        assert!(AddressFormat::Move.parse("2", None).is_err());
+        assert!(AddressFormat::Move.parse(&format!("0x{}", "1".repeat(65)), None).is_err());
+
+        let rooch = bech32::encode::<bech32::Bech32m>(bech32::Hrp::parse(ROOCH_HRP).unwrap(), &[7u8; 32]).unwrap();
+        assert_eq!(AddressFormat::Rooch.normalize(&rooch, None).unwrap(), format!("0x{}", "07".repeat(32)));
+        assert!(AddressFormat::Rooch.parse("rooch1invalid", None).is_err());
+
+        let osmo = bech32::encode::<bech32::Bech32>(bech32::Hrp::parse("osmo").unwrap(), &[1u8; 20]).unwrap();
+        assert_eq!(AddressFormat::Cosmos.parse(&osmo, Some("osmo")).unwrap(), vec![1u8; 20]);
+        assert!(AddressFormat::Cosmos.parse(&osmo, Some("cosmos")).is_err());
+
+        let solana = bs58::encode([9u8; 32]).into_string();
+        assert_eq!(AddressFormat::Solana.normalize(&solana, None).unwrap(), solana);
+        assert!(AddressFormat::Solana.parse("0x1", None).is_err());
+    }
+
+    #[test]
+    fn test_evm_checksum() {
+        // EIP-55 规范中的示例地址
+        let checksummed = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+        assert_eq!(AddressFormat::Evm.normalize(&checksummed.to_lowercase(), None).unwrap(), checksummed);
+        assert!(AddressFormat::Evm.parse(checksummed, None).is_ok());
+        assert!(AddressFormat::Evm.parse("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD", None).is_err());
+        assert!(AddressFormat::Evm.parse("0x5aaeb6053f3e94c9b9a09f33669435e7ef1bea", None).is_err());
+
+        assert!(AddressFormat::Evm.check_bytes(&[1u8; 20]).is_none());
+        assert!(AddressFormat::Evm.check_bytes(&[1u8; 32]).is_some());
+        assert!(AddressFormat::Move.check_bytes(&[0u8; 32]).is_some());
+    }
+}
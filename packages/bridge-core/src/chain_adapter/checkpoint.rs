@@ -0,0 +1,74 @@
+use std::ops::RangeInclusive;
+
+/// 按检查点顺序读取时的进度
+///
+/// 只有一批检查点全部处理完成后才前进，读取失败时下次从同一位置重试。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckpointCursor {
+    /// 下一个要读取的检查点，第一次读取前为 None
+    next: Option<u64>,
+    /// 第一次读取的检查点，未配置时从当时的最新检查点开始
+    start: Option<u64>,
+    /// 每次最多读取的检查点数
+    batch: u64,
+}
+
+impl CheckpointCursor {
+    pub fn new(start: Option<u64>, batch: u64) -> Self {
+        Self { next: None, start, batch: batch.max(1) }
+    }
+
+    /// 下一个要读取的检查点
+    pub fn next(&self) -> Option<u64> {
+        self.next
+    }
+
+    /// 本次应读取的检查点范围，已读到最新检查点时为 None
+    pub fn window(&mut self, latest: u64) -> Option<RangeInclusive<u64>> {
+        let next = *self.next.get_or_insert(self.start.unwrap_or(latest));
+        if next > latest {
+            return None;
+        }
+        Some(next..=latest.min(next.saturating_add(self.batch - 1)))
+    }
+
+    /// 标记 `to` 及之前的检查点已处理
+    pub fn advance(&mut self, to: u64) {
+        self.next = Some(to + 1);
+    }
+
+    /// 从保存的进度继续读取，优先于配置的起点
+    pub fn resume(&mut self, next: u64) {
+        self.next = Some(next);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checkpoint_window() {
+        // 未配置起点时从第一次看到的最新检查点开始
+        let mut cursor = CheckpointCursor::new(None, 10);
+        assert_eq!(cursor.window(500), Some(500..=500));
+        cursor.advance(500);
+        assert_eq!(cursor.window(500), None);
+        assert_eq!(cursor.window(530), Some(501..=510));
+
+        // 未调用 advance 时重复返回同一范围
+        assert_eq!(cursor.window(530), Some(501..=510));
+        cursor.advance(510);
+        assert_eq!(cursor.next(), Some(511));
+
+        let mut cursor = CheckpointCursor::new(Some(7), 3);
+        assert_eq!(cursor.window(100), Some(7..=9));
+        let mut cursor = CheckpointCursor::new(Some(7), 0);
+        assert_eq!(cursor.window(100), Some(7..=7));
+
+        // 恢复保存的进度后不再从配置的起点读取
+        let mut cursor = CheckpointCursor::new(Some(7), 3);
+        cursor.resume(42);
+        assert_eq!(cursor.window(100), Some(42..=44));
+    }
+}
@@ -0,0 +1,104 @@
+use crate::Error;
+
+/// 桥合约调用失败的分类，各适配器共用
+///
+/// 各链的桥合约使用相同的模块与中止码：`message::EMESSAGE_ALREADY_EXECUTED`(5)、
+/// `message::EINVALID_MESSAGE_TYPE`(1)、`message::EINVALID_CHAIN_ID`(2)、`message::EINVALID_PAYLOAD`(4)、
+/// `validator::EINVALID_SIGNATURE`(5)、`validator::EVALID_ALREADY_VERIFIED`(6)、`control::EBRIDGE_PAUSED`(3)。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BridgeContractError {
+    /// 消息 nonce 已使用，消息已在目标链执行
+    AlreadyProcessed,
+    /// 验证者签名未通过合约校验
+    InvalidSignature,
+    /// 消息类型、链 ID 或负载不合法
+    InvalidMessage,
+    /// 桥已暂停
+    Paused,
+    /// 交易序列号过旧或过新，重新读取序列号后可重试
+    SequenceNumber,
+    /// gas 不足或余额不足以支付 gas
+    OutOfGas,
+    /// 其它中止
+    Abort { location: String, code: u64 },
+}
+
+impl BridgeContractError {
+    /// 按中止位置与中止码分类；只有桥合约包内的中止才识别为已知错误
+    pub fn from_abort(location: &str, code: u64, bridge_address: &str) -> Self {
+        let (address, module) = location.rsplit_once("::").unwrap_or(("", location));
+        let from_bridge = same_address(address, bridge_address);
+        match (module, code) {
+            ("message", 5) | ("validator", 6) if from_bridge => BridgeContractError::AlreadyProcessed,
+            ("validator", 5) if from_bridge => BridgeContractError::InvalidSignature,
+            ("message", 1 | 2 | 4) if from_bridge => BridgeContractError::InvalidMessage,
+            ("control", 3) if from_bridge => BridgeContractError::Paused,
+            _ => BridgeContractError::Abort { location: location.to_string(), code },
+        }
+    }
+
+    /// 从 Move VM 状态码名称识别交易校验阶段的错误，例如 `SEQUENCE_NUMBER_TOO_OLD`
+    pub fn from_vm_status(status: &str) -> Option<Self> {
+        let status = status.to_ascii_uppercase();
+        if status.contains("SEQUENCE_NUMBER_TOO_OLD") || status.contains("SEQUENCE_NUMBER_TOO_NEW") {
+            Some(BridgeContractError::SequenceNumber)
+        } else if status.contains("OUT_OF_GAS") || status.contains("INSUFFICIENT_BALANCE_FOR_TRANSACTION_FEE") {
+            Some(BridgeContractError::OutOfGas)
+        } else {
+            None
+        }
+    }
+
+    /// 重新提交能否成功：序列号、gas 与暂停可恢复，其它中止重试结果不变
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, BridgeContractError::Paused | BridgeContractError::SequenceNumber | BridgeContractError::OutOfGas)
+    }
+
+    /// 转换为错误，`detail` 为节点返回的原始状态
+    pub fn into_error(self, detail: &str) -> Error {
+        match self {
+            BridgeContractError::AlreadyProcessed => Error::AlreadyProcessed(detail.to_string()),
+            BridgeContractError::InvalidSignature => Error::InvalidSignature(detail.to_string()),
+            BridgeContractError::InvalidMessage => Error::InvalidMessage(detail.to_string()),
+            BridgeContractError::Paused => Error::Paused(detail.to_string()),
+            BridgeContractError::SequenceNumber => Error::Chain(format!("Sequence number rejected: {}", detail)),
+            BridgeContractError::OutOfGas => Error::InsufficientGas(detail.to_string()),
+            BridgeContractError::Abort { location, code } => Error::MoveAbort { location, code },
+        }
+    }
+}
+
+/// 比较两个十六进制地址，忽略 `0x` 前缀、大小写与前导零
+fn same_address(a: &str, b: &str) -> bool {
+    let normalize = |s: &str| s.strip_prefix("0x").unwrap_or(s).trim_start_matches('0').to_ascii_lowercase();
+    normalize(a) == normalize(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_contract_errors() {
+        let abort = |location: &str, code| BridgeContractError::from_abort(location, code, "0x00b1");
+        assert_eq!(abort("0xb1::message", 5), BridgeContractError::AlreadyProcessed);
+        assert_eq!(abort("0xB1::validator", 6), BridgeContractError::AlreadyProcessed);
+        assert_eq!(abort("0xb1::validator", 5), BridgeContractError::InvalidSignature);
+        assert_eq!(abort("0xb1::control", 3), BridgeContractError::Paused);
+        assert_eq!(abort("0xb1::message", 4), BridgeContractError::InvalidMessage);
+        // 其它包中相同模块名的中止不识别
+        assert_eq!(abort("0xc2::message", 5), BridgeContractError::Abort { location: "0xc2::message".to_string(), code: 5 });
+        assert!(!abort("0xb1::message", 5).is_retryable());
+        assert!(abort("0xb1::control", 3).is_retryable());
+
+        assert_eq!(
+            BridgeContractError::from_vm_status("Transaction validation failed: SEQUENCE_NUMBER_TOO_OLD"),
+            Some(BridgeContractError::SequenceNumber)
+        );
+        assert_eq!(BridgeContractError::from_vm_status("outofgas"), None);
+        assert_eq!(BridgeContractError::from_vm_status("OUT_OF_GAS"), Some(BridgeContractError::OutOfGas));
+        assert!(matches!(BridgeContractError::Paused.into_error("paused"), Error::Paused(_)));
+        assert!(BridgeContractError::OutOfGas.into_error("OUT_OF_GAS").is_retryable());
+        assert!(BridgeContractError::InvalidMessage.into_error("message::EINVALID_PAYLOAD").is_terminal());
+    }
+}
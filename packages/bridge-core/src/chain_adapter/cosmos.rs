@@ -0,0 +1,742 @@
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use std::collections::HashMap;
+use crate::{
+    types::{ChainId, Delivery, EventProvenance, SignedMessage, MessageStatus, CrossChainMessage, ValidatorRegistration},
+    Error,
+    config::{ChainConfig, CosmosConfig},
+};
+use super::{check_address_format, AdapterCapabilities, ChainAdapter, ContractCheck, RateLimiter};
+use super::cosmos_light::{LightClient, Verdict};
+use super::cosmos_rpc::{CometRpcClient, TxSubscription, TxView};
+use super::cosmos_tx::{AccountInfo, CosmosSigner, Fee};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, OnceCell};
+use tokio::time::sleep;
+use tracing::{debug, warn};
+
+const MAX_RETRIES: u32 = 3;
+const RETRY_DELAY: u64 = 2;
+/// `tx_search` 每页的交易数
+const TX_PAGE_SIZE: u64 = 100;
+/// 等待交易上链的轮询间隔（秒），等待时长由链配置的 `stuck_timeout` 决定
+const RECEIPT_POLL_INTERVAL: u64 = 1;
+/// CosmWasm 合约事件的类型，合约属性都在该事件下
+const WASM_EVENT_TYPE: &str = "wasm";
+/// 桥合约发出的事件
+const BRIDGE_EVENTS: &[&str] = &["MessageSent", "AssetUnlocked"];
+
+/// 桥合约事件，合约以 `action` 属性区分
+#[derive(Clone, Copy)]
+enum EventKind {
+    MessageSent,
+    AssetUnlocked,
+}
+
+impl EventKind {
+    fn name(self) -> &'static str {
+        match self {
+            EventKind::MessageSent => "MessageSent",
+            EventKind::AssetUnlocked => "AssetUnlocked",
+        }
+    }
+
+    fn action(self) -> &'static str {
+        match self {
+            EventKind::MessageSent => "message_sent",
+            EventKind::AssetUnlocked => "asset_unlocked",
+        }
+    }
+}
+
+/// 桥合约在一笔交易中发出的一个 wasm 事件
+struct ContractEvent {
+    tx_hash: String,
+    height: u64,
+    /// 在该交易的桥合约事件中的序号
+    index: usize,
+    attributes: HashMap<String, String>,
+}
+
+impl ContractEvent {
+    fn attribute(&self, key: &str) -> Result<&str, Error> {
+        self.attributes
+            .get(key)
+            .map(|v| v.as_str())
+            .ok_or_else(|| Error::Serialization(format!("Missing event attribute {}", key)))
+    }
+
+    fn u64(&self, key: &str) -> Result<u64, Error> {
+        self.attribute(key)?
+            .parse()
+            .map_err(|_| Error::Serialization(format!("Invalid event attribute {}", key)))
+    }
+
+    fn bytes(&self, key: &str) -> Result<Vec<u8>, Error> {
+        hex::decode(self.attribute(key)?.trim_start_matches("0x"))
+            .map_err(|e| Error::Serialization(format!("Invalid event attribute {}: {}", key, e)))
+    }
+}
+
+/// 合约以字符串返回 `Uint64`，兼容数字形式
+fn json_u64(value: &serde_json::Value) -> Option<u64> {
+    match value {
+        serde_json::Value::String(s) => s.parse().ok(),
+        v => v.as_u64(),
+    }
+}
+
+/// 通过 CometBFT RPC 与 CosmWasm 桥合约交互的链适配器
+///
+/// 事件经 `tx_search` 按区块高度读取，WebSocket 订阅只用于判断是否有新交易；
+/// 只读取 Tendermint 轻客户端已验证高度之内的事件，不依赖节点报告的最新高度。
+pub struct CosmosAdapter {
+    config: ChainConfig,
+    cosmos: CosmosConfig,
+    client: Arc<CometRpcClient>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    connected: AtomicBool,
+    /// 合约中的数字链 ID 到配置链 ID 的映射
+    chain_ids: HashMap<u64, ChainId>,
+    /// 各事件已读取到的区块高度
+    cursors: Mutex<HashMap<&'static str, u64>>,
+    /// 节点报告的链 ID（例如 `osmosis-1`），用于签名与轻客户端验证
+    network: OnceCell<String>,
+    /// 连接时初始化，断开后保留已验证的状态
+    light_client: Mutex<Option<LightClient>>,
+    subscription: std::sync::Mutex<Option<TxSubscription>>,
+    /// 提交交易的发送账户，未配置时只能读取不能提交
+    signer: Option<CosmosSigner>,
+    /// 发送账户的账户号与下一个序列号，提交失败后清空并重新从链上读取
+    account: Mutex<Option<AccountInfo>>,
+}
+
+impl CosmosAdapter {
+    pub fn new(config: ChainConfig) -> Result<Self, Error> {
+        let cosmos = config.cosmos.clone().ok_or_else(|| {
+            Error::Config(format!("Chain {} uses the cosmos adapter but has no cosmos config", config.id))
+        })?;
+        let rate_limiter = RateLimiter::for_chain(&config);
+        Ok(Self {
+            client: Arc::new(CometRpcClient::new(&config.rpc_url).with_rate_limiter(rate_limiter.clone())),
+            rate_limiter,
+            cosmos,
+            config,
+            connected: AtomicBool::new(false),
+            chain_ids: HashMap::new(),
+            cursors: Mutex::new(HashMap::new()),
+            network: OnceCell::new(),
+            light_client: Mutex::new(None),
+            subscription: std::sync::Mutex::new(None),
+            signer: None,
+            account: Mutex::new(None),
+        })
+    }
+
+    /// 设置提交交易使用的发送账户
+    pub fn with_signer(mut self, signer: CosmosSigner) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    /// 设置数字链 ID 映射，用于把事件中的链 ID 转换为配置中的链 ID
+    pub fn with_chain_ids(mut self, chain_ids: HashMap<u64, ChainId>) -> Self {
+        self.chain_ids = chain_ids;
+        self
+    }
+
+    fn ensure_connected(&self) -> Result<(), Error> {
+        if self.connected.load(Ordering::Acquire) {
+            Ok(())
+        } else {
+            Err(Error::Network(format!("Cosmos adapter for {} is not connected", self.config.id)))
+        }
+    }
+
+    async fn retry_with_backoff<F, Fut, T>(&self, operation: F) -> Result<T, Error>
+    where
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = Result<T, Error>>,
+    {
+        self.ensure_connected()?;
+        let mut retries = 0;
+        loop {
+            match operation().await {
+                Ok(result) => return Ok(result),
+                Err(e) => {
+                    retries += 1;
+                    if retries >= MAX_RETRIES {
+                        return Err(e);
+                    }
+                    sleep(Duration::from_secs(RETRY_DELAY.pow(retries))).await;
+                }
+            }
+        }
+    }
+
+    fn network(&self) -> Result<&str, Error> {
+        self.network
+            .get()
+            .map(|n| n.as_str())
+            .ok_or_else(|| Error::Network(format!("Cosmos adapter for {} is not connected", self.config.id)))
+    }
+
+    fn chain_name(&self, chain_id: u64) -> Result<ChainId, Error> {
+        self.chain_ids
+            .get(&chain_id)
+            .cloned()
+            .ok_or_else(|| Error::Config(format!("Unknown numeric chain ID in Cosmos event: {}", chain_id)))
+    }
+
+    /// 配置链 ID 对应的合约数字链 ID
+    fn numeric_chain_id(&self, chain: &ChainId) -> Result<u64, Error> {
+        self.chain_ids
+            .iter()
+            .find(|(_, id)| *id == chain)
+            .map(|(numeric, _)| *numeric)
+            .ok_or_else(|| Error::Config(format!("No numeric chain ID configured for {}", chain)))
+    }
+
+    async fn smart_query(&self, query: serde_json::Value) -> Result<serde_json::Value, Error> {
+        self.retry_with_backoff(|| self.client.smart_query(&self.config.bridge_address, &query)).await
+    }
+
+    /// 以配置的区块哈希或当前最新区块作为轻客户端的信任根
+    async fn init_light_client(&self, network: &str, latest_height: u64) -> Result<LightClient, Error> {
+        let expected_hash = match &self.cosmos.trusted_hash {
+            Some(hash) => Some(hex::decode(hash).map_err(|e| Error::Config(format!("Invalid trusted_hash: {}", e)))?),
+            None => None,
+        };
+        let height = self.cosmos.trusted_height.unwrap_or(latest_height);
+        if expected_hash.is_none() {
+            warn!("No trusted header configured for {}, trusting block {} reported by {}", self.config.id, height, self.config.rpc_url);
+        }
+        let root = self.client.light_block(height).await?;
+        LightClient::new(network, self.cosmos.trusting_period, &root, expected_hash.as_deref())
+    }
+
+    /// 把轻客户端推进到节点的最新区块，返回已验证的高度
+    ///
+    /// 受信任的验证者集合在新区块上签名不足 1/3 时，先验证两者中间的区块。
+    async fn verified_height(&self) -> Result<u64, Error> {
+        let latest = self.retry_with_backoff(|| self.client.status()).await?.sync_info.latest_block_height;
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|e| Error::Chain(format!("Failed to get current time: {}", e)))?
+            .as_secs() as i64;
+        let mut light_client = self.light_client.lock().await;
+        let light_client = light_client
+            .as_mut()
+            .ok_or_else(|| Error::Network(format!("Cosmos adapter for {} is not connected", self.config.id)))?;
+
+        let mut target = latest;
+        while light_client.trusted().height < latest {
+            let block = self.retry_with_backoff(|| self.client.light_block(target)).await?;
+            match light_client.verify(&block, now)? {
+                Verdict::Verified(state) => {
+                    light_client.update(state);
+                    target = latest;
+                }
+                Verdict::NotEnoughTrust => {
+                    let trusted = light_client.trusted().height;
+                    target = trusted + (target - trusted) / 2;
+                }
+            }
+        }
+        Ok(light_client.trusted().height)
+    }
+
+    /// 读取区块高度区间内桥合约发出的某类事件
+    async fn search_events(&self, kind: EventKind, from: u64, to: u64) -> Result<Vec<ContractEvent>, Error> {
+        let query = format!(
+            "{}._contract_address='{}' AND {}.action='{}' AND tx.height>={} AND tx.height<={}",
+            WASM_EVENT_TYPE, self.config.bridge_address, WASM_EVENT_TYPE, kind.action(), from, to
+        );
+        let mut events = Vec::new();
+        let mut seen = 0;
+        for page in 1.. {
+            let result = self.retry_with_backoff(|| self.client.tx_search(&query, page, TX_PAGE_SIZE)).await?;
+            seen += result.txs.len() as u64;
+            for tx in &result.txs {
+                events.extend(self.contract_events(tx, kind));
+            }
+            if result.txs.is_empty() || seen >= result.total_count {
+                break;
+            }
+        }
+        Ok(events)
+    }
+
+    /// 从交易结果中取出桥合约发出的指定事件，执行失败的交易不产生事件
+    fn contract_events(&self, tx: &TxView, kind: EventKind) -> Vec<ContractEvent> {
+        if tx.tx_result.code != 0 {
+            return Vec::new();
+        }
+        tx.tx_result
+            .events
+            .iter()
+            .filter(|event| {
+                event.event_type == WASM_EVENT_TYPE
+                    && event.attribute("_contract_address") == Some(self.config.bridge_address.as_str())
+            })
+            .enumerate()
+            .filter(|(_, event)| event.attribute("action") == Some(kind.action()))
+            .map(|(index, event)| ContractEvent {
+                tx_hash: tx.hash.clone(),
+                height: tx.height,
+                index,
+                attributes: event
+                    .attributes
+                    .iter()
+                    .filter_map(|a| Some((a.key.clone(), a.value.clone()?)))
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// 从上次的游标继续读取已通过轻客户端验证的区块中的事件
+    async fn fetch_new_events(&self, kind: EventKind) -> Result<Vec<ContractEvent>, Error> {
+        let cursor = self.cursors.lock().await.get(kind.name()).copied();
+        // 订阅覆盖了游标之后的所有区块且没有推送新交易时，无需查询
+        let subscription = self.subscription.lock().unwrap().as_ref().map(|s| s.state());
+        if let (Some(cursor), Some(state)) = (cursor, subscription) {
+            if state.since.is_some_and(|since| since <= cursor) && state.latest_event <= cursor {
+                return Ok(Vec::new());
+            }
+        }
+
+        let verified = self.verified_height().await?;
+        let from = cursor.map_or(1, |c| c + 1);
+        if from > verified {
+            return Ok(Vec::new());
+        }
+        let events = self.search_events(kind, from, verified).await?;
+        self.cursors.lock().await.insert(kind.name(), verified);
+        Ok(events)
+    }
+
+    /// 将 `message_sent` 事件解码为跨链消息
+    fn parse_event(&self, event: &ContractEvent) -> Result<SignedMessage, Error> {
+        // 负载与 Sui 侧 `encode_asset_payload` 一致：接收者地址 || 负载
+        let mut payload = event.bytes("receiver")?;
+        payload.extend(event.bytes("payload")?);
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|e| Error::Chain(format!("Failed to get current time: {}", e)))?
+            .as_secs();
+
+        Ok(SignedMessage {
+            message: CrossChainMessage {
+                nonce: event.u64("nonce")?,
+                source_chain: self.chain_name(event.u64("source_chain")?)?,
+                target_chain: self.chain_name(event.u64("target_chain")?)?,
+                message_type: "transfer".to_string(),
+                payload,
+            },
+            signature: vec![],
+            timestamp,
+            event_name: Some(EventKind::MessageSent.name().to_string()),
+            sender: None,
+            provenance: Some(EventProvenance {
+                block: event.height,
+                tx_hash: event.tx_hash.clone(),
+                event_index: event.index as u64,
+            }),
+            proof: None,
+            trace_id: None,
+        })
+    }
+
+    /// 将 `asset_unlocked` 事件解码为交付记录，接收者左侧补零到 32 字节
+    fn parse_delivery(&self, event: &ContractEvent) -> Result<Delivery, Error> {
+        let receiver = event.bytes("receiver")?;
+        let mut recipient = vec![0u8; 32usize.saturating_sub(receiver.len())];
+        recipient.extend(receiver);
+        Ok(Delivery {
+            event_id: format!("{}:{}", event.tx_hash, event.index),
+            source_chain: self.chain_name(event.u64("source_chain")?)?,
+            target_chain: self.config.id.clone(),
+            nonce: event.attributes.get("nonce").and_then(|n| n.parse().ok()),
+            recipient: Some(recipient),
+            amount: Some(event.u64("amount")?),
+            tx_hash: Some(event.tx_hash.clone()),
+            message_id: None,
+        })
+    }
+
+    fn parse_events(&self, events: &[ContractEvent]) -> Vec<SignedMessage> {
+        events
+            .iter()
+            .filter_map(|event| match self.parse_event(event) {
+                Ok(message) => Some(message),
+                Err(e) => {
+                    warn!("Skipping undecodable Cosmos event in {} at height {}: {}", event.tx_hash, event.height, e);
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// 签名并广播合约调用，返回交易哈希
+    ///
+    /// 持有账户锁直到广播完成，保证同一账户的交易按序列号依次提交。
+    async fn send_transaction(&self, signer: &CosmosSigner, msg: &serde_json::Value, fee: &Fee) -> Result<String, Error> {
+        let mut account = self.account.lock().await;
+        let info = match *account {
+            Some(info) => info,
+            None => self.client
+                .account(signer.address())
+                .await?
+                .ok_or_else(|| Error::Chain(format!("Sender account {} does not exist on {}", signer.address(), self.config.id)))?,
+        };
+        let tx = signer.sign_execute(&self.config.bridge_address, msg, info, fee, self.network()?)?;
+        match self.client.broadcast_tx_sync(&tx.encode()).await {
+            Ok(result) if result.code == 0 => {
+                *account = Some(AccountInfo { sequence: info.sequence + 1, ..info });
+                Ok(result.hash)
+            }
+            Ok(result) => {
+                // 序列号不匹配等检查失败，下次提交时重新读取账户
+                *account = None;
+                Err(Error::Chain(format!(
+                    "Transaction rejected by {} ({} code {}): {}",
+                    self.config.id, result.codespace, result.code, result.log
+                )))
+            }
+            Err(e) => {
+                *account = None;
+                Err(e)
+            }
+        }
+    }
+
+    /// 以配置 gas 价格的 `multiplier` 倍调用桥合约并等待执行结果
+    async fn execute(&self, msg: serde_json::Value, multiplier: u64) -> Result<String, Error> {
+        let signer = self.signer.as_ref().ok_or_else(|| {
+            Error::Config(format!("Chain {} requires sender_keystore to submit transactions", self.config.id))
+        })?;
+        let fee = Fee {
+            denom: self.cosmos.fee_denom.clone(),
+            amount: (self.cosmos.gas_limit as f64 * self.cosmos.gas_price * multiplier as f64).ceil() as u128,
+            gas_limit: self.cosmos.gas_limit,
+        };
+        let hash = self.retry_with_backoff(|| self.send_transaction(signer, &msg, &fee)).await?;
+        self.wait_for_receipt(&hash).await?;
+        Ok(hash)
+    }
+
+    fn process_message_msg(&self, message: &SignedMessage) -> Result<serde_json::Value, Error> {
+        Ok(serde_json::json!({
+            "process_message": {
+                "message": BASE64.encode(message.message.canonical_bytes(&self.chain_ids)?),
+                "signature": BASE64.encode(&message.signature)
+            }
+        }))
+    }
+
+    /// 轮询交易执行结果，执行失败时返回错误，超时返回 `Error::Stuck`
+    async fn wait_for_receipt(&self, hash: &str) -> Result<(), Error> {
+        let timeout = self.config.stuck_timeout();
+        for _ in 0..timeout.div_ceil(RECEIPT_POLL_INTERVAL).max(1) {
+            match self.client.tx(hash).await {
+                Ok(Some(tx)) if tx.tx_result.code == 0 => return Ok(()),
+                Ok(Some(tx)) => {
+                    return Err(Error::Chain(format!(
+                        "Transaction {} failed ({} code {}): {}",
+                        tx.hash, tx.tx_result.codespace, tx.tx_result.code, tx.tx_result.log
+                    )));
+                }
+                Ok(None) => {}
+                Err(e) => debug!("Failed to query transaction {}: {}", hash, e),
+            }
+            sleep(Duration::from_secs(RECEIPT_POLL_INTERVAL)).await;
+        }
+        Err(Error::Stuck { tx_hash: hash.to_string(), timeout })
+    }
+}
+
+#[async_trait]
+impl ChainAdapter for CosmosAdapter {
+    fn chain_type(&self) -> &str {
+        "cosmos"
+    }
+
+    fn config(&self) -> &ChainConfig {
+        &self.config
+    }
+
+    fn capabilities(&self) -> AdapterCapabilities {
+        AdapterCapabilities {
+            subscriptions: true,
+            batch_submit: true,
+            ..AdapterCapabilities::default()
+        }
+    }
+
+    fn rate_limiter(&self) -> Option<Arc<RateLimiter>> {
+        self.rate_limiter.clone()
+    }
+
+    async fn connect(&self) -> Result<(), Error> {
+        let status = self.client.status().await?;
+        let network = status.node_info.network;
+        if self.network.get().is_some_and(|n| *n != network) {
+            return Err(Error::Config(format!(
+                "Node {} serves {} but chain {} was connected to {}",
+                self.config.rpc_url, network, self.config.id, self.network()?
+            )));
+        }
+        let mut light_client = self.light_client.lock().await;
+        if light_client.is_none() {
+            *light_client = Some(self.init_light_client(&network, status.sync_info.latest_block_height).await?);
+        }
+        drop(light_client);
+        let _ = self.network.set(network.clone());
+
+        let query = format!("tm.event='Tx' AND {}._contract_address='{}'", WASM_EVENT_TYPE, self.config.bridge_address);
+        *self.subscription.lock().unwrap() = Some(TxSubscription::spawn(self.client.clone(), query));
+        debug!("Connected to CometBFT node {} (chain {})", self.config.rpc_url, network);
+        self.connected.store(true, Ordering::Release);
+        Ok(())
+    }
+
+    async fn close(&self) -> Result<(), Error> {
+        self.connected.store(false, Ordering::Release);
+        self.subscription.lock().unwrap().take();
+        *self.account.lock().await = None;
+        Ok(())
+    }
+
+    async fn is_healthy(&self) -> bool {
+        self.connected.load(Ordering::Acquire) && self.client.status().await.is_ok()
+    }
+
+    async fn listen_events(&self) -> Result<Vec<SignedMessage>, Error> {
+        let events = self.fetch_new_events(EventKind::MessageSent).await?;
+        Ok(self.parse_events(&events))
+    }
+
+    async fn replay_events(&self, from: u64, to: u64) -> Result<Vec<SignedMessage>, Error> {
+        let to = to.min(self.verified_height().await?);
+        if from > to {
+            return Ok(Vec::new());
+        }
+        let events = self.search_events(EventKind::MessageSent, from, to).await?;
+        Ok(self.parse_events(&events))
+    }
+
+    async fn listen_deliveries(&self) -> Result<Vec<Delivery>, Error> {
+        let mut deliveries = Vec::new();
+        for event in self.fetch_new_events(EventKind::AssetUnlocked).await? {
+            match self.parse_delivery(&event) {
+                Ok(delivery) => deliveries.push(delivery),
+                Err(e) => warn!("Skipping undecodable Cosmos event in {} at height {}: {}", event.tx_hash, event.height, e),
+            }
+        }
+        Ok(deliveries)
+    }
+
+    /// 返回轻客户端已验证的高度，而不是节点自报的最新高度
+    async fn latest_height(&self) -> Result<Option<u64>, Error> {
+        self.verified_height().await.map(Some)
+    }
+
+    async fn submit_message(&self, message: SignedMessage) -> Result<String, Error> {
+        let hash = self.execute(self.process_message_msg(&message)?, 1).await?;
+        debug!("Submitted message {} in Cosmos transaction {}", message.message.nonce, hash);
+        Ok(hash)
+    }
+
+    /// 重新读取账户后以更高的手续费提交
+    ///
+    /// CometBFT 内存池不按手续费替换交易，原交易仍在内存池中时新交易会因序列号冲突被拒绝，由调用方稍后重试。
+    async fn resubmit_message(&self, message: SignedMessage, stuck_tx: &str, attempt: u32) -> Result<String, Error> {
+        *self.account.lock().await = None;
+        debug!("Resubmitting message {} after stuck transaction {} (attempt {})", message.message.nonce, stuck_tx, attempt);
+        self.execute(self.process_message_msg(&message)?, 1 + attempt as u64).await
+    }
+
+    async fn submit_messages_batch(&self, messages: Vec<SignedMessage>) -> Result<String, Error> {
+        let mut encoded = Vec::with_capacity(messages.len());
+        let mut signatures = Vec::with_capacity(messages.len());
+        for message in &messages {
+            encoded.push(BASE64.encode(message.message.canonical_bytes(&self.chain_ids)?));
+            signatures.push(BASE64.encode(&message.signature));
+        }
+        let msg = serde_json::json!({ "process_messages": { "messages": encoded, "signatures": signatures } });
+        let hash = self.execute(msg, 1).await?;
+        debug!("Submitted {} messages in Cosmos transaction {}", messages.len(), hash);
+        Ok(hash)
+    }
+
+    async fn verify_message(&self, message: &SignedMessage) -> Result<MessageStatus, Error> {
+        let source_chain = self.numeric_chain_id(&message.message.source_chain)?;
+        let processed = self
+            .smart_query(serde_json::json!({
+                "is_processed": { "source_chain": source_chain.to_string(), "nonce": message.message.nonce.to_string() }
+            }))
+            .await?;
+        Ok(if processed.as_bool() == Some(true) {
+            MessageStatus::Processed
+        } else {
+            MessageStatus::Pending
+        })
+    }
+
+    async fn last_processed_nonce(&self, source_chain: &ChainId) -> Result<Option<u64>, Error> {
+        let numeric = self.numeric_chain_id(source_chain)?;
+        let nonce = self
+            .smart_query(serde_json::json!({ "last_processed_nonce": { "source_chain": numeric.to_string() } }))
+            .await?;
+        if nonce.is_null() {
+            return Ok(None);
+        }
+        json_u64(&nonce)
+            .map(Some)
+            .ok_or_else(|| Error::Chain(format!("Invalid processed nonce for source chain {}", source_chain)))
+    }
+
+    async fn get_validator_registration(&self, validator: &str) -> Result<Option<ValidatorRegistration>, Error> {
+        let registration = self.smart_query(serde_json::json!({ "validator": { "address": validator } })).await?;
+        if registration.is_null() {
+            return Ok(None);
+        }
+        let weight = json_u64(&registration["weight"])
+            .ok_or_else(|| Error::Chain(format!("Invalid validator weight for {}", validator)))?;
+        Ok(Some(ValidatorRegistration {
+            address: validator.to_string(),
+            weight,
+            active: registration["active"].as_bool().unwrap_or(true),
+        }))
+    }
+
+    async fn node_version(&self) -> Result<String, Error> {
+        Ok(self.retry_with_backoff(|| self.client.status()).await?.node_info.version)
+    }
+
+    async fn gas_balance(&self) -> Result<Option<u128>, Error> {
+        let Some(signer) = &self.signer else {
+            return Ok(None);
+        };
+        let balance = self
+            .retry_with_backoff(|| self.client.balance(signer.address(), &self.cosmos.fee_denom))
+            .await?;
+        Ok(Some(balance))
+    }
+
+    async fn locked_balance(&self, asset_type: &str) -> Result<Option<u128>, Error> {
+        let locked = self.smart_query(serde_json::json!({ "locked_balance": { "denom": asset_type } })).await?;
+        json_u64(&locked)
+            .map(|amount| Some(amount as u128))
+            .ok_or_else(|| Error::Chain(format!("Invalid locked balance for {}", asset_type)))
+    }
+
+    async fn wrapped_supply(&self, asset_type: &str) -> Result<Option<u128>, Error> {
+        let supply = self.smart_query(serde_json::json!({ "wrapped_supply": { "denom": asset_type } })).await?;
+        json_u64(&supply)
+            .map(|amount| Some(amount as u128))
+            .ok_or_else(|| Error::Chain(format!("Invalid wrapped supply for {}", asset_type)))
+    }
+
+    async fn transaction_gas(&self, tx_hash: &str) -> Result<Option<u64>, Error> {
+        self.ensure_connected()?;
+        let transaction = self.client.tx(tx_hash).await?;
+        Ok(transaction.map(|tx| (tx.tx_result.gas_used as f64 * self.cosmos.gas_price).ceil() as u64))
+    }
+
+    async fn check_contract(&self) -> Result<ContractCheck, Error> {
+        // 合约事件没有链上声明，只能检查过滤器是否为桥合约会发出的事件
+        let exists = self.retry_with_backoff(|| self.client.contract_exists(&self.config.bridge_address)).await?;
+        Ok(ContractCheck {
+            exists,
+            missing_events: self.config.event_filters
+                .iter()
+                .filter(|filter| !BRIDGE_EVENTS.contains(&filter.name.as_str()))
+                .map(|filter| filter.name.clone())
+                .collect(),
+        })
+    }
+
+    /// Cosmos 账户无需登记即可接收任意币种，只检查地址格式；20 字节的账户地址左侧补零到 32 字节
+    async fn check_recipient(&self, recipient: &[u8], _asset_type: &str) -> Result<Option<String>, Error> {
+        Ok(check_address_format(recipient))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_contract_events() {
+        let config = ChainConfig {
+            adapter_type: "cosmos".to_string(),
+            rpc_url: "http://localhost:26657".to_string(),
+            bridge_address: "osmo1bridge".to_string(),
+            chain_id: Some(7),
+            cosmos: Some(CosmosConfig {
+                address_prefix: "osmo".to_string(),
+                fee_denom: "uosmo".to_string(),
+                gas_price: 0.025,
+                gas_limit: 500_000,
+                trusted_height: None,
+                trusted_hash: None,
+                trusting_period: 3_600,
+            }),
+            ..crate::testing::mock_chain_config("osmosis")
+        };
+        assert!(CosmosAdapter::new(crate::testing::mock_chain_config("osmosis")).is_err());
+        let adapter = CosmosAdapter::new(config).unwrap().with_chain_ids(HashMap::from([
+            (1, ChainId::from("sui_testnet")),
+            (7, ChainId::from("osmosis")),
+        ]));
+
+        let wasm_event = |contract: &str, attributes: &[(&str, &str)]| {
+            let mut attrs = vec![serde_json::json!({ "key": "_contract_address", "value": contract, "index": true })];
+            attrs.extend(attributes.iter().map(|(k, v)| serde_json::json!({ "key": k, "value": v, "index": true })));
+            serde_json::json!({ "type": "wasm", "attributes": attrs })
+        };
+        let tx: TxView = serde_json::from_value(serde_json::json!({
+            "hash": "ABCD",
+            "height": "120",
+            "index": 0,
+            "tx_result": {
+                "code": 0,
+                "gas_used": "81000",
+                "events": [
+                    { "type": "message", "attributes": [{ "key": "action", "value": "/cosmwasm.wasm.v1.MsgExecuteContract" }] },
+                    wasm_event("osmo1other", &[("action", "message_sent"), ("nonce", "99")]),
+                    wasm_event("osmo1bridge", &[("action", "asset_unlocked"), ("receiver", "0a0b"), ("amount", "500"), ("source_chain", "1"), ("nonce", "3")]),
+                    wasm_event("osmo1bridge", &[
+                        ("action", "message_sent"), ("nonce", "4"), ("source_chain", "7"), ("target_chain", "1"),
+                        ("receiver", "aaaaaaaa"), ("payload", "0500000000000000")
+                    ])
+                ]
+            }
+        }))
+        .unwrap();
+
+        let sent = adapter.contract_events(&tx, EventKind::MessageSent);
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].index, 1);
+        let message = adapter.parse_event(&sent[0]).unwrap();
+        assert_eq!(message.message.nonce, 4);
+        assert_eq!(message.message.source_chain, "osmosis");
+        assert_eq!(message.message.target_chain, "sui_testnet");
+        assert_eq!(message.message.payload[..4], [0xaa; 4]);
+        assert_eq!(message.event_name.as_deref(), Some("MessageSent"));
+
+        let unlocked = adapter.contract_events(&tx, EventKind::AssetUnlocked);
+        let delivery = adapter.parse_delivery(&unlocked[0]).unwrap();
+        assert_eq!(delivery.event_id, "ABCD:0");
+        assert_eq!(delivery.recipient.as_ref().unwrap()[30..], [0x0a, 0x0b]);
+        assert_eq!(delivery.amount, Some(500));
+        assert_eq!(delivery.nonce, Some(3));
+
+        // 执行失败的交易不产生事件
+        let failed = TxView { tx_result: super::super::cosmos_rpc::TxResult { code: 5, ..tx.tx_result.clone() }, ..tx };
+        assert!(adapter.contract_events(&failed, EventKind::MessageSent).is_empty());
+    }
+}
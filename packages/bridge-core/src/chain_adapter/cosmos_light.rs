@@ -0,0 +1,546 @@
+use base64::Engine;
+use ed25519_dalek::{PublicKey, Signature, Verifier};
+use serde::{Deserialize, Deserializer};
+use sha2::{Digest, Sha256};
+use crate::Error;
+use super::cosmos_tx::{encode_varint, ProtoWriter};
+
+/// `BlockIDFlagCommit`：验证者为该区块投了预提交票
+const BLOCK_ID_FLAG_COMMIT: u8 = 2;
+/// `SignedMsgType` 中预提交投票的取值
+const PRECOMMIT_TYPE: u64 = 2;
+const ED25519_PUBKEY_TYPE: &str = "tendermint/PubKeyEd25519";
+
+/// protobuf `Timestamp`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Timestamp {
+    pub seconds: i64,
+    pub nanos: i32,
+}
+
+impl Timestamp {
+    /// 解析 CometBFT 输出的 RFC 3339 UTC 时间，例如 `2024-01-02T03:04:05.123456789Z`
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let invalid = || Error::Serialization(format!("Invalid timestamp {}", s));
+        let s = s.strip_suffix('Z').ok_or_else(invalid)?;
+        let (date, time) = s.split_once('T').ok_or_else(invalid)?;
+        let (time, fraction) = time.split_once('.').unwrap_or((time, ""));
+        let parts = |s: &str, sep: char| -> Result<Vec<i64>, Error> {
+            s.split(sep).map(|p| p.parse::<i64>().map_err(|_| invalid())).collect()
+        };
+        let (date, time) = (parts(date, '-')?, parts(time, ':')?);
+        if date.len() != 3 || time.len() != 3 || fraction.len() > 9 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        let nanos = format!("{:0<9}", fraction).parse::<i32>().map_err(|_| invalid())?;
+        Ok(Self {
+            seconds: days_from_civil(date[0], date[1], date[2]) * 86_400 + time[0] * 3_600 + time[1] * 60 + time[2],
+            nanos,
+        })
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        ProtoWriter::new().uint64(1, self.seconds as u64).uint64(2, self.nanos as i64 as u64).finish()
+    }
+}
+
+/// 公历日期距 1970-01-01 的天数
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct PartSetHeader {
+    pub total: u32,
+    #[serde(deserialize_with = "de_hex")]
+    pub hash: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct BlockId {
+    #[serde(deserialize_with = "de_hex")]
+    pub hash: Vec<u8>,
+    pub parts: PartSetHeader,
+}
+
+impl BlockId {
+    fn encode(&self) -> Vec<u8> {
+        let parts = ProtoWriter::new().uint64(1, self.parts.total as u64).bytes(2, &self.parts.hash).finish();
+        ProtoWriter::new().bytes(1, &self.hash).message(2, &parts).finish()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ConsensusVersion {
+    #[serde(deserialize_with = "de_u64")]
+    pub block: u64,
+    #[serde(default, deserialize_with = "de_u64")]
+    pub app: u64,
+}
+
+/// 区块头
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Header {
+    pub version: ConsensusVersion,
+    pub chain_id: String,
+    #[serde(deserialize_with = "de_u64")]
+    pub height: u64,
+    #[serde(deserialize_with = "de_timestamp")]
+    pub time: Timestamp,
+    pub last_block_id: BlockId,
+    #[serde(deserialize_with = "de_hex")]
+    pub last_commit_hash: Vec<u8>,
+    #[serde(deserialize_with = "de_hex")]
+    pub data_hash: Vec<u8>,
+    #[serde(deserialize_with = "de_hex")]
+    pub validators_hash: Vec<u8>,
+    #[serde(deserialize_with = "de_hex")]
+    pub next_validators_hash: Vec<u8>,
+    #[serde(deserialize_with = "de_hex")]
+    pub consensus_hash: Vec<u8>,
+    #[serde(deserialize_with = "de_hex")]
+    pub app_hash: Vec<u8>,
+    #[serde(deserialize_with = "de_hex")]
+    pub last_results_hash: Vec<u8>,
+    #[serde(deserialize_with = "de_hex")]
+    pub evidence_hash: Vec<u8>,
+    #[serde(deserialize_with = "de_hex")]
+    pub proposer_address: Vec<u8>,
+}
+
+impl Header {
+    /// 区块哈希：各字段 protobuf 编码的 Merkle 根，与 CometBFT `Header.Hash` 一致
+    pub fn hash(&self) -> Vec<u8> {
+        let wrapped_bytes = |value: &[u8]| ProtoWriter::new().bytes(1, value).finish();
+        let fields = [
+            ProtoWriter::new().uint64(1, self.version.block).uint64(2, self.version.app).finish(),
+            ProtoWriter::new().string(1, &self.chain_id).finish(),
+            ProtoWriter::new().uint64(1, self.height).finish(),
+            self.time.encode(),
+            self.last_block_id.encode(),
+            wrapped_bytes(&self.last_commit_hash),
+            wrapped_bytes(&self.data_hash),
+            wrapped_bytes(&self.validators_hash),
+            wrapped_bytes(&self.next_validators_hash),
+            wrapped_bytes(&self.consensus_hash),
+            wrapped_bytes(&self.app_hash),
+            wrapped_bytes(&self.last_results_hash),
+            wrapped_bytes(&self.evidence_hash),
+            wrapped_bytes(&self.proposer_address),
+        ];
+        merkle_root(&fields)
+    }
+}
+
+/// 提交中的单个签名
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CommitSig {
+    pub block_id_flag: u8,
+    #[serde(deserialize_with = "de_hex")]
+    pub validator_address: Vec<u8>,
+    #[serde(deserialize_with = "de_timestamp")]
+    pub timestamp: Timestamp,
+    #[serde(default, deserialize_with = "de_opt_base64")]
+    pub signature: Option<Vec<u8>>,
+}
+
+/// 区块的预提交签名集合
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Commit {
+    #[serde(deserialize_with = "de_u64")]
+    pub height: u64,
+    pub round: u32,
+    pub block_id: BlockId,
+    pub signatures: Vec<CommitSig>,
+}
+
+impl Commit {
+    /// 验证者签名的内容：长度前缀的 `CanonicalVote`
+    fn vote_sign_bytes(&self, chain_id: &str, timestamp: &Timestamp) -> Vec<u8> {
+        let parts = ProtoWriter::new()
+            .uint64(1, self.block_id.parts.total as u64)
+            .bytes(2, &self.block_id.parts.hash)
+            .finish();
+        let block_id = ProtoWriter::new().bytes(1, &self.block_id.hash).message(2, &parts).finish();
+        let vote = ProtoWriter::new()
+            .uint64(1, PRECOMMIT_TYPE)
+            .sfixed64(2, self.height as i64)
+            .sfixed64(3, self.round as i64)
+            .message(4, &block_id)
+            .message(5, &timestamp.encode())
+            .string(6, chain_id)
+            .finish();
+        let mut bytes = Vec::with_capacity(vote.len() + 2);
+        encode_varint(&mut bytes, vote.len() as u64);
+        bytes.extend(vote);
+        bytes
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SignedHeader {
+    pub header: Header,
+    pub commit: Commit,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ValidatorPubKey {
+    #[serde(rename = "type")]
+    pub key_type: String,
+    #[serde(deserialize_with = "de_base64")]
+    pub value: Vec<u8>,
+}
+
+/// 共识验证者
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Validator {
+    #[serde(deserialize_with = "de_hex")]
+    pub address: Vec<u8>,
+    pub pub_key: ValidatorPubKey,
+    #[serde(deserialize_with = "de_u64")]
+    pub voting_power: u64,
+}
+
+/// 验证者集合的哈希：`SimpleValidator` 编码的 Merkle 根
+pub fn validator_set_hash(validators: &[Validator]) -> Vec<u8> {
+    let leaves: Vec<Vec<u8>> = validators
+        .iter()
+        .map(|v| {
+            let pub_key = ProtoWriter::new().bytes(1, &v.pub_key.value).finish();
+            ProtoWriter::new().message(1, &pub_key).uint64(2, v.voting_power).finish()
+        })
+        .collect();
+    merkle_root(&leaves)
+}
+
+/// RFC 6962 Merkle 根
+pub fn merkle_root(items: &[Vec<u8>]) -> Vec<u8> {
+    match items.len() {
+        0 => Sha256::digest([]).to_vec(),
+        1 => Sha256::new().chain_update([0u8]).chain_update(&items[0]).finalize().to_vec(),
+        n => {
+            let split = n.next_power_of_two() / 2;
+            Sha256::new()
+                .chain_update([1u8])
+                .chain_update(merkle_root(&items[..split]))
+                .chain_update(merkle_root(&items[split..]))
+                .finalize()
+                .to_vec()
+        }
+    }
+}
+
+/// 区块头、提交与该高度的验证者集合
+#[derive(Debug, Clone, PartialEq)]
+pub struct LightBlock {
+    pub signed_header: SignedHeader,
+    pub validators: Vec<Validator>,
+}
+
+impl LightBlock {
+    pub fn height(&self) -> u64 {
+        self.signed_header.header.height
+    }
+
+    /// 区块自身的一致性：验证者集合哈希、区块哈希与超过 2/3 投票权的有效签名
+    pub fn validate(&self, chain_id: &str) -> Result<(), Error> {
+        let SignedHeader { header, commit } = &self.signed_header;
+        if header.chain_id != chain_id {
+            return Err(Error::Chain(format!("Header chain ID {} does not match {}", header.chain_id, chain_id)));
+        }
+        if commit.height != header.height {
+            return Err(Error::Chain(format!("Commit height {} does not match header height {}", commit.height, header.height)));
+        }
+        if validator_set_hash(&self.validators) != header.validators_hash {
+            return Err(Error::Chain(format!("Validator set does not match header at height {}", header.height)));
+        }
+        if commit.block_id.hash != header.hash() {
+            return Err(Error::Chain(format!("Commit does not sign header at height {}", header.height)));
+        }
+        if commit.signatures.len() != self.validators.len() {
+            return Err(Error::Chain(format!("Commit at height {} has {} signatures for {} validators",
+                header.height, commit.signatures.len(), self.validators.len())));
+        }
+
+        let total: u64 = self.validators.iter().map(|v| v.voting_power).sum();
+        let mut signed = 0u64;
+        for (sig, validator) in commit.signatures.iter().zip(&self.validators) {
+            if sig.block_id_flag != BLOCK_ID_FLAG_COMMIT {
+                continue;
+            }
+            if sig.validator_address != validator.address {
+                return Err(Error::Chain(format!("Commit signature order does not match validator set at height {}", header.height)));
+            }
+            verify_vote(commit, sig, validator, chain_id)?;
+            signed += validator.voting_power;
+        }
+        if signed as u128 * 3 <= total as u128 * 2 {
+            return Err(Error::Chain(format!("Commit at height {} has {}/{} voting power, need more than 2/3",
+                header.height, signed, total)));
+        }
+        Ok(())
+    }
+}
+
+fn verify_vote(commit: &Commit, sig: &CommitSig, validator: &Validator, chain_id: &str) -> Result<(), Error> {
+    if validator.pub_key.key_type != ED25519_PUBKEY_TYPE {
+        return Err(Error::Chain(format!("Unsupported validator key type {}", validator.pub_key.key_type)));
+    }
+    let invalid = || Error::Chain(format!("Invalid commit signature from {} at height {}",
+        hex::encode_upper(&validator.address), commit.height));
+    let public_key = PublicKey::from_bytes(&validator.pub_key.value).map_err(|_| invalid())?;
+    let signature = Signature::from_bytes(sig.signature.as_deref().unwrap_or_default()).map_err(|_| invalid())?;
+    public_key
+        .verify(&commit.vote_sign_bytes(chain_id, &sig.timestamp), &signature)
+        .map_err(|_| invalid())
+}
+
+/// 已验证的最新区块
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrustedState {
+    pub height: u64,
+    pub time: Timestamp,
+    pub header_hash: Vec<u8>,
+    pub next_validators_hash: Vec<u8>,
+    pub validators: Vec<Validator>,
+}
+
+/// 从受信任区块推进到新区块的结果
+#[derive(Debug)]
+pub enum Verdict {
+    Verified(TrustedState),
+    /// 受信任的验证者集合签名不足 1/3，需要先验证中间高度的区块
+    NotEnoughTrust,
+}
+
+/// Tendermint 轻客户端，与 IBC 07-tendermint 客户端采用相同的验证规则
+pub struct LightClient {
+    chain_id: String,
+    /// 受信任区块的有效期（秒），超期后验证者可能已解绑，不能再依赖其签名
+    trusting_period: u64,
+    trusted: TrustedState,
+}
+
+impl LightClient {
+    /// 以验证通过的区块作为信任根；`expected_hash` 为配置中给出的区块哈希
+    pub fn new(chain_id: &str, trusting_period: u64, root: &LightBlock, expected_hash: Option<&[u8]>) -> Result<Self, Error> {
+        root.validate(chain_id)?;
+        let header = &root.signed_header.header;
+        let header_hash = header.hash();
+        if let Some(expected) = expected_hash {
+            if header_hash != expected {
+                return Err(Error::Chain(format!("Trusted header hash mismatch at height {}: got {}",
+                    header.height, hex::encode_upper(&header_hash))));
+            }
+        }
+        Ok(Self {
+            chain_id: chain_id.to_string(),
+            trusting_period,
+            trusted: TrustedState {
+                height: header.height,
+                time: header.time,
+                header_hash,
+                next_validators_hash: header.next_validators_hash.clone(),
+                validators: root.validators.clone(),
+            },
+        })
+    }
+
+    pub fn trusted(&self) -> &TrustedState {
+        &self.trusted
+    }
+
+    /// 验证比受信任区块更高的区块，不修改信任状态
+    pub fn verify(&self, block: &LightBlock, now_secs: i64) -> Result<Verdict, Error> {
+        let header = &block.signed_header.header;
+        if header.height <= self.trusted.height {
+            return Err(Error::Chain(format!("Header at height {} is not newer than trusted height {}",
+                header.height, self.trusted.height)));
+        }
+        if self.trusted.time.seconds + self.trusting_period as i64 <= now_secs {
+            return Err(Error::Chain(format!("Trusted header at height {} is outside the trusting period",
+                self.trusted.height)));
+        }
+        if header.time <= self.trusted.time {
+            return Err(Error::Chain(format!("Header time at height {} is not after trusted header", header.height)));
+        }
+        block.validate(&self.chain_id)?;
+
+        let adjacent = header.height == self.trusted.height + 1;
+        if adjacent {
+            if header.validators_hash != self.trusted.next_validators_hash {
+                return Err(Error::Chain(format!("Validator set at height {} does not match trusted next validators",
+                    header.height)));
+            }
+        } else if !self.has_trusted_quorum(block) {
+            return Ok(Verdict::NotEnoughTrust);
+        }
+
+        Ok(Verdict::Verified(TrustedState {
+            height: header.height,
+            time: header.time,
+            header_hash: header.hash(),
+            next_validators_hash: header.next_validators_hash.clone(),
+            validators: block.validators.clone(),
+        }))
+    }
+
+    /// 受信任集合中签名了新区块的验证者是否超过其总投票权的 1/3
+    fn has_trusted_quorum(&self, block: &LightBlock) -> bool {
+        let commit = &block.signed_header.commit;
+        let total: u64 = self.trusted.validators.iter().map(|v| v.voting_power).sum();
+        let mut seen = std::collections::HashSet::new();
+        let signed: u64 = commit
+            .signatures
+            .iter()
+            .filter(|sig| sig.block_id_flag == BLOCK_ID_FLAG_COMMIT && seen.insert(sig.validator_address.clone()))
+            .filter_map(|sig| {
+                let validator = self.trusted.validators.iter().find(|v| v.address == sig.validator_address)?;
+                verify_vote(commit, sig, validator, &self.chain_id).ok()?;
+                Some(validator.voting_power)
+            })
+            .sum();
+        signed as u128 * 3 > total as u128
+    }
+
+    pub fn update(&mut self, state: TrustedState) {
+        self.trusted = state;
+    }
+}
+
+fn de_u64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StrOrNum {
+        Str(String),
+        Num(u64),
+    }
+    match StrOrNum::deserialize(deserializer)? {
+        StrOrNum::Str(s) => s.parse().map_err(serde::de::Error::custom),
+        StrOrNum::Num(n) => Ok(n),
+    }
+}
+
+fn de_hex<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    let s = Option::<String>::deserialize(deserializer)?.unwrap_or_default();
+    hex::decode(s).map_err(serde::de::Error::custom)
+}
+
+fn de_base64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    base64::engine::general_purpose::STANDARD.decode(s).map_err(serde::de::Error::custom)
+}
+
+fn de_opt_base64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Vec<u8>>, D::Error> {
+    Option::<String>::deserialize(deserializer)?
+        .map(|s| base64::engine::general_purpose::STANDARD.decode(s).map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+fn de_timestamp<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+    Timestamp::parse(&String::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Keypair, SecretKey, Signer};
+
+    fn keypair(seed: u8) -> Keypair {
+        let secret = SecretKey::from_bytes(&[seed; 32]).unwrap();
+        Keypair { public: PublicKey::from(&secret), secret }
+    }
+
+    fn validator(keypair: &Keypair, voting_power: u64) -> Validator {
+        Validator {
+            address: Sha256::digest(keypair.public.as_bytes())[..20].to_vec(),
+            pub_key: ValidatorPubKey { key_type: ED25519_PUBKEY_TYPE.to_string(), value: keypair.public.to_bytes().to_vec() },
+            voting_power,
+        }
+    }
+
+    /// 由 `signers` 中的验证者签名的区块
+    fn light_block(chain_id: &str, height: u64, keypairs: &[Keypair], signers: &[usize]) -> LightBlock {
+        let validators: Vec<Validator> = keypairs.iter().map(|k| validator(k, 10)).collect();
+        let validators_hash = validator_set_hash(&validators);
+        let header = Header {
+            version: ConsensusVersion { block: 11, app: 1 },
+            chain_id: chain_id.to_string(),
+            height,
+            time: Timestamp { seconds: 1_700_000_000 + height as i64, nanos: 0 },
+            last_block_id: BlockId::default(),
+            last_commit_hash: vec![1; 32],
+            data_hash: Vec::new(),
+            validators_hash: validators_hash.clone(),
+            next_validators_hash: validators_hash,
+            consensus_hash: vec![2; 32],
+            app_hash: vec![3; 32],
+            last_results_hash: Vec::new(),
+            evidence_hash: Vec::new(),
+            proposer_address: validators[0].address.clone(),
+        };
+        let mut commit = Commit {
+            height,
+            round: 0,
+            block_id: BlockId { hash: header.hash(), parts: PartSetHeader { total: 1, hash: vec![4; 32] } },
+            signatures: Vec::new(),
+        };
+        commit.signatures = keypairs
+            .iter()
+            .zip(&validators)
+            .enumerate()
+            .map(|(i, (keypair, validator))| {
+                let timestamp = Timestamp { seconds: header.time.seconds + 1, nanos: 500 };
+                if signers.contains(&i) {
+                    let signature = keypair.sign(&commit.vote_sign_bytes(chain_id, &timestamp));
+                    CommitSig { block_id_flag: BLOCK_ID_FLAG_COMMIT, validator_address: validator.address.clone(), timestamp, signature: Some(signature.to_bytes().to_vec()) }
+                } else {
+                    CommitSig { block_id_flag: 1, validator_address: Vec::new(), timestamp: Timestamp::default(), signature: None }
+                }
+            })
+            .collect();
+        LightBlock { signed_header: SignedHeader { header, commit }, validators }
+    }
+
+    #[test]
+    fn test_verify_headers() {
+        assert_eq!(Timestamp::parse("1970-01-01T00:00:01.5Z").unwrap(), Timestamp { seconds: 1, nanos: 500_000_000 });
+        assert_eq!(Timestamp::parse("2024-02-29T12:00:00Z").unwrap().seconds, 1_709_208_000);
+
+        let keypairs: Vec<Keypair> = (1..=4).map(keypair).collect();
+        let root = light_block("test-1", 10, &keypairs, &[0, 1, 2, 3]);
+        let root_hash = root.signed_header.header.hash();
+        assert!(LightClient::new("test-1", 3_600, &root, Some(&[0; 32])).is_err());
+        let mut client = LightClient::new("test-1", 3_600, &root, Some(&root_hash)).unwrap();
+        let now = 1_700_000_100;
+
+        // 3/4 的投票权签名，且全部来自受信任集合
+        let next = light_block("test-1", 20, &keypairs, &[0, 1, 3]);
+        match client.verify(&next, now).unwrap() {
+            Verdict::Verified(state) => client.update(state),
+            Verdict::NotEnoughTrust => panic!("expected verified"),
+        }
+        assert_eq!(client.trusted().height, 20);
+
+        // 只有一半投票权，不足 2/3
+        assert!(client.verify(&light_block("test-1", 21, &keypairs, &[0, 1]), now).is_err());
+
+        // 更换了验证者集合，受信任集合只有 1/4 签名
+        let rotated: Vec<Keypair> = [1, 5, 6, 7].into_iter().map(keypair).collect();
+        let far = light_block("test-1", 30, &rotated, &[0, 1, 2, 3]);
+        assert!(matches!(client.verify(&far, now).unwrap(), Verdict::NotEnoughTrust));
+
+        // 篡改区块头后签名不再覆盖该区块
+        let mut tampered = light_block("test-1", 22, &keypairs, &[0, 1, 2, 3]);
+        tampered.signed_header.header.app_hash = vec![9; 32];
+        assert!(client.verify(&tampered, now).is_err());
+
+        // 超过信任期
+        assert!(client.verify(&light_block("test-1", 23, &keypairs, &[0, 1, 2, 3]), now + 3_600).is_err());
+    }
+}
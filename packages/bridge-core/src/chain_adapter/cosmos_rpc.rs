@@ -0,0 +1,426 @@
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use futures_util::{SinkExt, StreamExt};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio_tungstenite::tungstenite::Message;
+use crate::Error;
+use super::cosmos_light::{LightBlock, SignedHeader, Validator};
+use super::cosmos_tx::{AccountInfo, ProtoReader, ProtoWriter};
+use super::rate_limit::{reject_throttled, RateLimiter};
+
+/// `/validators` 每页的最大条数
+const VALIDATORS_PER_PAGE: u64 = 100;
+/// WebSocket 断开后重新订阅前的等待时间
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(5);
+
+/// CometBFT JSON-RPC 客户端
+///
+/// 只覆盖中继器用到的接口，应用层查询经 `abci_query` 转发到 Cosmos SDK 的 gRPC 查询服务。
+/// 事件属性按 CometBFT 0.37 及以上版本的明文格式解析。
+pub struct CometRpcClient {
+    http: reqwest::Client,
+    url: String,
+    next_id: AtomicU64,
+    rate_limiter: Option<Arc<RateLimiter>>,
+}
+
+#[derive(Deserialize)]
+struct JsonRpcResponse<T> {
+    result: Option<T>,
+    error: Option<JsonRpcError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonRpcError {
+    code: i64,
+    message: String,
+    #[serde(default)]
+    data: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct NodeInfo {
+    /// 链 ID，例如 `osmosis-1`
+    pub network: String,
+    pub version: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SyncInfo {
+    #[serde(deserialize_with = "de_u64")]
+    pub latest_block_height: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StatusView {
+    pub node_info: NodeInfo,
+    pub sync_info: SyncInfo,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EventAttribute {
+    pub key: String,
+    #[serde(default)]
+    pub value: Option<String>,
+}
+
+/// 交易执行产生的 ABCI 事件
+#[derive(Debug, Clone, Deserialize)]
+pub struct AbciEvent {
+    #[serde(rename = "type")]
+    pub event_type: String,
+    #[serde(default)]
+    pub attributes: Vec<EventAttribute>,
+}
+
+impl AbciEvent {
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes.iter().find(|a| a.key == key).and_then(|a| a.value.as_deref())
+    }
+}
+
+/// 交易执行结果，`code` 非 0 表示失败
+#[derive(Debug, Clone, Deserialize)]
+pub struct TxResult {
+    #[serde(default)]
+    pub code: u32,
+    #[serde(default)]
+    pub codespace: String,
+    #[serde(default)]
+    pub log: String,
+    #[serde(default, deserialize_with = "de_u64")]
+    pub gas_used: u64,
+    #[serde(default)]
+    pub events: Vec<AbciEvent>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TxView {
+    pub hash: String,
+    #[serde(deserialize_with = "de_u64")]
+    pub height: u64,
+    pub tx_result: TxResult,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TxSearchView {
+    pub txs: Vec<TxView>,
+    #[serde(deserialize_with = "de_u64")]
+    pub total_count: u64,
+}
+
+/// `broadcast_tx_sync` 的结果，只包含 CheckTx 阶段的检查结果
+#[derive(Debug, Clone, Deserialize)]
+pub struct BroadcastView {
+    pub code: u32,
+    #[serde(default)]
+    pub codespace: String,
+    #[serde(default)]
+    pub log: String,
+    pub hash: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct AbciQueryView {
+    response: AbciQueryResponse,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct AbciQueryResponse {
+    #[serde(default)]
+    code: u32,
+    #[serde(default)]
+    log: String,
+    #[serde(default)]
+    value: Option<String>,
+}
+
+impl CometRpcClient {
+    pub fn new(url: &str) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            url: url.trim_end_matches('/').to_string(),
+            next_id: AtomicU64::new(1),
+            rate_limiter: None,
+        }
+    }
+
+    /// 每次调用前先从限速器取得令牌
+    pub fn with_rate_limiter(mut self, rate_limiter: Option<Arc<RateLimiter>>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// 订阅事件使用的 WebSocket 地址
+    pub fn websocket_url(&self) -> String {
+        let url = if let Some(rest) = self.url.strip_prefix("https://") {
+            format!("wss://{}", rest)
+        } else if let Some(rest) = self.url.strip_prefix("http://") {
+            format!("ws://{}", rest)
+        } else {
+            self.url.clone()
+        };
+        format!("{}/websocket", url)
+    }
+
+    /// 发起 JSON-RPC 调用，RPC 错误转换为包含错误码的 `Error::Rpc`
+    pub async fn call<R: DeserializeOwned>(&self, method: &str, params: serde_json::Value) -> Result<R, Error> {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let response = self.http
+            .post(&self.url)
+            .json(&serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": method,
+                "params": params,
+                "id": id
+            }))
+            .send()
+            .await
+            .map_err(|e| Error::Network(e.to_string()))?;
+        let response: JsonRpcResponse<R> = reject_throttled(response, method)?
+            .json()
+            .await
+            .map_err(|e| Error::Chain(format!("Invalid {} response: {}", method, e)))?;
+
+        if let Some(error) = response.error {
+            // CometBFT 把具体原因放在 data 中
+            let message = match error.data {
+                Some(data) => format!("{}: {}", error.message, data),
+                None => error.message,
+            };
+            return Err(Error::Rpc { method: method.to_string(), code: error.code, message });
+        }
+        response.result.ok_or_else(|| Error::Chain(format!("Empty {} response", method)))
+    }
+
+    pub async fn status(&self) -> Result<StatusView, Error> {
+        self.call("status", serde_json::json!({})).await
+    }
+
+    /// 指定高度的区块头与提交
+    pub async fn commit(&self, height: u64) -> Result<SignedHeader, Error> {
+        #[derive(Deserialize)]
+        struct CommitView {
+            signed_header: SignedHeader,
+        }
+        let view: CommitView = self.call("commit", serde_json::json!({ "height": height.to_string() })).await?;
+        Ok(view.signed_header)
+    }
+
+    /// 指定高度的完整验证者集合，按节点返回的顺序排列
+    pub async fn validators(&self, height: u64) -> Result<Vec<Validator>, Error> {
+        #[derive(Deserialize)]
+        struct ValidatorsView {
+            validators: Vec<Validator>,
+            #[serde(deserialize_with = "de_u64")]
+            total: u64,
+        }
+        let mut validators = Vec::new();
+        for page in 1.. {
+            let view: ValidatorsView = self
+                .call("validators", serde_json::json!({
+                    "height": height.to_string(),
+                    "page": page.to_string(),
+                    "per_page": VALIDATORS_PER_PAGE.to_string()
+                }))
+                .await?;
+            let empty = view.validators.is_empty();
+            validators.extend(view.validators);
+            if empty || validators.len() as u64 >= view.total {
+                break;
+            }
+        }
+        Ok(validators)
+    }
+
+    /// 轻客户端验证所需的区块头、提交与验证者集合
+    pub async fn light_block(&self, height: u64) -> Result<LightBlock, Error> {
+        Ok(LightBlock {
+            signed_header: self.commit(height).await?,
+            validators: self.validators(height).await?,
+        })
+    }
+
+    /// 按事件条件搜索交易，按高度升序返回
+    pub async fn tx_search(&self, query: &str, page: u64, per_page: u64) -> Result<TxSearchView, Error> {
+        self.call("tx_search", serde_json::json!({
+            "query": query,
+            "prove": false,
+            "page": page.to_string(),
+            "per_page": per_page.to_string(),
+            "order_by": "asc"
+        }))
+        .await
+    }
+
+    /// 按哈希查询已上链的交易，尚未上链时返回 None
+    pub async fn tx(&self, hash: &str) -> Result<Option<TxView>, Error> {
+        let hash_bytes = hex::decode(hash).map_err(|e| Error::Chain(format!("Invalid transaction hash {}: {}", hash, e)))?;
+        match self.call("tx", serde_json::json!({ "hash": BASE64.encode(hash_bytes), "prove": false })).await {
+            Ok(tx) => Ok(Some(tx)),
+            Err(Error::Rpc { message, .. }) if message.contains("not found") => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// 广播已签名交易，返回 CheckTx 的结果
+    pub async fn broadcast_tx_sync(&self, tx: &[u8]) -> Result<BroadcastView, Error> {
+        self.call("broadcast_tx_sync", serde_json::json!({ "tx": BASE64.encode(tx) })).await
+    }
+
+    /// 执行应用层查询，查询失败时返回 `Error::Rpc`，错误码为 ABCI 返回码
+    async fn abci_query(&self, path: &str, data: &[u8]) -> Result<Vec<u8>, Error> {
+        let view: AbciQueryView = self
+            .call("abci_query", serde_json::json!({ "path": path, "data": hex::encode(data), "prove": false }))
+            .await?;
+        if view.response.code != 0 {
+            return Err(Error::Rpc { method: path.to_string(), code: view.response.code as i64, message: view.response.log });
+        }
+        BASE64
+            .decode(view.response.value.unwrap_or_default())
+            .map_err(|e| Error::Serialization(format!("Invalid {} response: {}", path, e)))
+    }
+
+    /// 查询 CosmWasm 合约的只读接口
+    pub async fn smart_query<R: DeserializeOwned>(&self, contract: &str, query: &serde_json::Value) -> Result<R, Error> {
+        let query = serde_json::to_vec(query).map_err(|e| Error::Serialization(e.to_string()))?;
+        let request = ProtoWriter::new().string(1, contract).bytes(2, &query).finish();
+        let response = self.abci_query("/cosmwasm.wasm.v1.Query/SmartContractState", &request).await?;
+        let data = ProtoReader::find_bytes(&response, 1)?.unwrap_or_default();
+        serde_json::from_slice(data).map_err(|e| Error::Serialization(format!("Invalid contract query response: {}", e)))
+    }
+
+    /// 合约是否存在
+    pub async fn contract_exists(&self, contract: &str) -> Result<bool, Error> {
+        let request = ProtoWriter::new().string(1, contract).finish();
+        match self.abci_query("/cosmwasm.wasm.v1.Query/ContractInfo", &request).await {
+            Ok(_) => Ok(true),
+            Err(Error::Rpc { message, .. }) if message.contains("not found") => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// 账户号与序列号，账户尚未收到过资金时返回 None
+    pub async fn account(&self, address: &str) -> Result<Option<AccountInfo>, Error> {
+        let request = ProtoWriter::new().string(1, address).finish();
+        match self.abci_query("/cosmos.auth.v1beta1.Query/Account", &request).await {
+            Ok(response) => AccountInfo::decode(&response).map(Some),
+            Err(Error::Rpc { message, .. }) if message.contains("not found") => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// 账户指定币种的余额
+    pub async fn balance(&self, address: &str, denom: &str) -> Result<u128, Error> {
+        let request = ProtoWriter::new().string(1, address).string(2, denom).finish();
+        let response = self.abci_query("/cosmos.bank.v1beta1.Query/Balance", &request).await?;
+        let coin = ProtoReader::find_bytes(&response, 1)?.unwrap_or_default();
+        let amount = String::from_utf8_lossy(ProtoReader::find_bytes(coin, 2)?.unwrap_or(b"0")).to_string();
+        amount.parse().map_err(|_| Error::Chain(format!("Invalid balance for {}: {}", address, amount)))
+    }
+}
+
+/// 事件订阅的当前状态
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SubscriptionState {
+    /// 本次订阅生效时的区块高度，之后的交易都会推送过来；未订阅成功时为 None
+    pub since: Option<u64>,
+    /// 收到的最新一笔交易所在高度
+    pub latest_event: u64,
+}
+
+/// 通过 `/websocket` 订阅合约交易，断开后自动重新订阅
+///
+/// 订阅只用来判断是否有新事件，事件内容仍以 `tx_search` 的结果为准。
+pub struct TxSubscription {
+    state: Arc<Mutex<SubscriptionState>>,
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl TxSubscription {
+    pub fn spawn(client: Arc<CometRpcClient>, query: String) -> Self {
+        let state = Arc::new(Mutex::new(SubscriptionState::default()));
+        let task_state = state.clone();
+        let task = tokio::spawn(async move {
+            loop {
+                if let Err(e) = Self::run(&client, &query, &task_state).await {
+                    tracing::warn!("Cosmos event subscription dropped: {}", e);
+                }
+                task_state.lock().unwrap().since = None;
+                tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+            }
+        });
+        Self { state, task }
+    }
+
+    pub fn state(&self) -> SubscriptionState {
+        *self.state.lock().unwrap()
+    }
+
+    async fn run(client: &CometRpcClient, query: &str, state: &Mutex<SubscriptionState>) -> Result<(), Error> {
+        let (mut stream, _) = tokio_tungstenite::connect_async(client.websocket_url())
+            .await
+            .map_err(|e| Error::Network(e.to_string()))?;
+        let request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "subscribe",
+            "id": 0,
+            "params": { "query": query }
+        });
+        stream
+            .send(Message::Text(request.to_string()))
+            .await
+            .map_err(|e| Error::Network(e.to_string()))?;
+
+        while let Some(message) = stream.next().await {
+            let text = match message.map_err(|e| Error::Network(e.to_string()))? {
+                Message::Text(text) => text,
+                Message::Close(_) => break,
+                _ => continue,
+            };
+            let value: serde_json::Value = serde_json::from_str(&text)
+                .map_err(|e| Error::Serialization(format!("Invalid subscription message: {}", e)))?;
+            if let Some(error) = value.get("error") {
+                return Err(Error::Chain(format!("Subscription rejected: {}", error)));
+            }
+            match value["result"]["data"]["value"]["TxResult"]["height"].as_str() {
+                Some(height) => {
+                    let height = height.parse().unwrap_or_default();
+                    let mut state = state.lock().unwrap();
+                    state.latest_event = state.latest_event.max(height);
+                }
+                // 订阅确认：此后出块的交易都会推送，在此之前的交易需要查询
+                None if state.lock().unwrap().since.is_none() => {
+                    let height = client.status().await?.sync_info.latest_block_height;
+                    state.lock().unwrap().since = Some(height);
+                }
+                None => {}
+            }
+        }
+        Err(Error::Network("WebSocket closed".to_string()))
+    }
+}
+
+impl Drop for TxSubscription {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+fn de_u64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StrOrNum {
+        Str(String),
+        Num(u64),
+    }
+    match StrOrNum::deserialize(deserializer)? {
+        StrOrNum::Str(s) => s.parse().map_err(serde::de::Error::custom),
+        StrOrNum::Num(n) => Ok(n),
+    }
+}
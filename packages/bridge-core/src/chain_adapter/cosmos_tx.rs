@@ -0,0 +1,341 @@
+use bech32::{Bech32, Hrp};
+use k256::ecdsa::{signature::Signer, Signature, SigningKey};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+use std::path::Path;
+use crate::Error;
+
+/// `SIGN_MODE_DIRECT`
+const SIGN_MODE_DIRECT: u64 = 1;
+const MSG_EXECUTE_CONTRACT_TYPE_URL: &str = "/cosmwasm.wasm.v1.MsgExecuteContract";
+const SECP256K1_PUBKEY_TYPE_URL: &str = "/cosmos.crypto.secp256k1.PubKey";
+const BASE_ACCOUNT_TYPE_URL: &str = "/cosmos.auth.v1beta1.BaseAccount";
+
+/// 按字段号顺序写入 protobuf 消息，标量字段为默认值时按 proto3 规则省略
+#[derive(Default)]
+pub struct ProtoWriter {
+    buf: Vec<u8>,
+}
+
+impl ProtoWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn key(&mut self, field: u32, wire_type: u8) {
+        encode_varint(&mut self.buf, ((field as u64) << 3) | wire_type as u64);
+    }
+
+    pub fn uint64(mut self, field: u32, value: u64) -> Self {
+        if value != 0 {
+            self.key(field, 0);
+            encode_varint(&mut self.buf, value);
+        }
+        self
+    }
+
+    pub fn sfixed64(mut self, field: u32, value: i64) -> Self {
+        if value != 0 {
+            self.key(field, 1);
+            self.buf.extend_from_slice(&value.to_le_bytes());
+        }
+        self
+    }
+
+    pub fn bytes(self, field: u32, value: &[u8]) -> Self {
+        if !value.is_empty() {
+            self.message(field, value)
+        } else {
+            self
+        }
+    }
+
+    pub fn string(self, field: u32, value: &str) -> Self {
+        self.bytes(field, value.as_bytes())
+    }
+
+    /// 写入嵌套消息，即使为空也写入（对应不可为空的字段与重复字段中的元素）
+    pub fn message(mut self, field: u32, value: &[u8]) -> Self {
+        self.key(field, 2);
+        encode_varint(&mut self.buf, value.len() as u64);
+        self.buf.extend_from_slice(value);
+        self
+    }
+
+    pub fn finish(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+pub fn encode_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// protobuf 字段值，定长字段按小端整数返回
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProtoValue<'a> {
+    Varint(u64),
+    Fixed64(u64),
+    Bytes(&'a [u8]),
+    Fixed32(u32),
+}
+
+/// 逐个读取 protobuf 消息中的字段
+pub struct ProtoReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> ProtoReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    fn varint(&mut self) -> Result<u64, Error> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let (&byte, rest) = self.data
+                .split_first()
+                .ok_or_else(|| Error::Serialization("Truncated protobuf varint".to_string()))?;
+            self.data = rest;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(Error::Serialization("Protobuf varint is too long".to_string()))
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if self.data.len() < len {
+            return Err(Error::Serialization("Truncated protobuf field".to_string()));
+        }
+        let (head, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(head)
+    }
+
+    /// 下一个字段的字段号与值，读完时返回 None
+    pub fn next_field(&mut self) -> Result<Option<(u32, ProtoValue<'a>)>, Error> {
+        if self.data.is_empty() {
+            return Ok(None);
+        }
+        let key = self.varint()?;
+        let value = match key & 0x7 {
+            0 => ProtoValue::Varint(self.varint()?),
+            1 => ProtoValue::Fixed64(u64::from_le_bytes(self.take(8)?.try_into().expect("slice of 8 bytes"))),
+            2 => {
+                let len = self.varint()? as usize;
+                ProtoValue::Bytes(self.take(len)?)
+            }
+            5 => ProtoValue::Fixed32(u32::from_le_bytes(self.take(4)?.try_into().expect("slice of 4 bytes"))),
+            wire_type => return Err(Error::Serialization(format!("Unsupported protobuf wire type {}", wire_type))),
+        };
+        Ok(Some(((key >> 3) as u32, value)))
+    }
+
+    /// 查找某个长度前缀字段的第一次出现
+    pub fn find_bytes(data: &'a [u8], field: u32) -> Result<Option<&'a [u8]>, Error> {
+        let mut reader = ProtoReader::new(data);
+        while let Some((number, value)) = reader.next_field()? {
+            if let (true, ProtoValue::Bytes(bytes)) = (number == field, value) {
+                return Ok(Some(bytes));
+            }
+        }
+        Ok(None)
+    }
+}
+
+fn any(type_url: &str, value: &[u8]) -> Vec<u8> {
+    ProtoWriter::new().string(1, type_url).bytes(2, value).finish()
+}
+
+/// 链上账户的账户号与下一个序列号
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountInfo {
+    pub account_number: u64,
+    pub sequence: u64,
+}
+
+impl AccountInfo {
+    /// 解码 `QueryAccountResponse`，只支持 `BaseAccount`
+    pub fn decode(response: &[u8]) -> Result<Self, Error> {
+        let account = ProtoReader::find_bytes(response, 1)?
+            .ok_or_else(|| Error::Chain("Account query returned no account".to_string()))?;
+        let type_url = ProtoReader::find_bytes(account, 1)?.unwrap_or_default();
+        if type_url != BASE_ACCOUNT_TYPE_URL.as_bytes() {
+            return Err(Error::Chain(format!("Unsupported account type {}", String::from_utf8_lossy(type_url))));
+        }
+        let mut info = Self { account_number: 0, sequence: 0 };
+        let mut reader = ProtoReader::new(ProtoReader::find_bytes(account, 2)?.unwrap_or_default());
+        while let Some((field, value)) = reader.next_field()? {
+            match (field, value) {
+                (3, ProtoValue::Varint(number)) => info.account_number = number,
+                (4, ProtoValue::Varint(sequence)) => info.sequence = sequence,
+                _ => {}
+            }
+        }
+        Ok(info)
+    }
+}
+
+/// 交易费用与 gas 上限
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fee {
+    pub denom: String,
+    pub amount: u128,
+    pub gas_limit: u64,
+}
+
+/// 已签名的 `TxRaw`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CosmosTransaction {
+    pub body_bytes: Vec<u8>,
+    pub auth_info_bytes: Vec<u8>,
+    pub signature: Vec<u8>,
+}
+
+impl CosmosTransaction {
+    pub fn encode(&self) -> Vec<u8> {
+        ProtoWriter::new()
+            .bytes(1, &self.body_bytes)
+            .bytes(2, &self.auth_info_bytes)
+            .message(3, &self.signature)
+            .finish()
+    }
+
+    /// 交易哈希：`TxRaw` 编码的 SHA-256，大写十六进制
+    pub fn hash(&self) -> String {
+        hex::encode_upper(Sha256::digest(self.encode()))
+    }
+}
+
+/// 中继器在 Cosmos 链上的 secp256k1 发送账户
+pub struct CosmosSigner {
+    signing_key: SigningKey,
+    public_key: Vec<u8>,
+    address: String,
+}
+
+impl CosmosSigner {
+    /// 由私钥与地址前缀（例如 `osmo`）创建，地址为压缩公钥 SHA-256 后 RIPEMD-160 的 bech32 编码
+    pub fn from_secret_key(bytes: &[u8], address_prefix: &str) -> Result<Self, Error> {
+        let signing_key = SigningKey::from_slice(bytes)
+            .map_err(|e| Error::Key(format!("Invalid Cosmos sender key: {}", e)))?;
+        let public_key = signing_key.verifying_key().to_encoded_point(true).as_bytes().to_vec();
+        Ok(Self {
+            address: bech32_address(address_prefix, &Ripemd160::digest(Sha256::digest(&public_key)))?,
+            signing_key,
+            public_key,
+        })
+    }
+
+    /// 从私钥文件加载，文件内容为十六进制编码的 secp256k1 私钥
+    pub fn load<P: AsRef<Path>>(path: P, address_prefix: &str) -> Result<Self, Error> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| Error::Key(format!("Failed to read Cosmos sender key: {}", e)))?;
+        let bytes = hex::decode(content.trim().trim_start_matches("0x"))
+            .map_err(|e| Error::Key(format!("Invalid Cosmos sender key encoding: {}", e)))?;
+        Self::from_secret_key(&bytes, address_prefix)
+    }
+
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// 构造调用 CosmWasm 合约的交易并以 `SIGN_MODE_DIRECT` 签名
+    pub fn sign_execute(
+        &self,
+        contract: &str,
+        msg: &serde_json::Value,
+        account: AccountInfo,
+        fee: &Fee,
+        chain_id: &str,
+    ) -> Result<CosmosTransaction, Error> {
+        let msg = serde_json::to_vec(msg).map_err(|e| Error::Serialization(e.to_string()))?;
+        let execute = ProtoWriter::new()
+            .string(1, &self.address)
+            .string(2, contract)
+            .bytes(3, &msg)
+            .finish();
+        let body_bytes = ProtoWriter::new()
+            .message(1, &any(MSG_EXECUTE_CONTRACT_TYPE_URL, &execute))
+            .finish();
+
+        let public_key = ProtoWriter::new().bytes(1, &self.public_key).finish();
+        let single = ProtoWriter::new().uint64(1, SIGN_MODE_DIRECT).finish();
+        let signer_info = ProtoWriter::new()
+            .message(1, &any(SECP256K1_PUBKEY_TYPE_URL, &public_key))
+            .message(2, &ProtoWriter::new().message(1, &single).finish())
+            .uint64(3, account.sequence)
+            .finish();
+        let coin = ProtoWriter::new().string(1, &fee.denom).string(2, &fee.amount.to_string()).finish();
+        let fee = ProtoWriter::new().message(1, &coin).uint64(2, fee.gas_limit).finish();
+        let auth_info_bytes = ProtoWriter::new().message(1, &signer_info).message(2, &fee).finish();
+
+        let sign_doc = ProtoWriter::new()
+            .bytes(1, &body_bytes)
+            .bytes(2, &auth_info_bytes)
+            .string(3, chain_id)
+            .uint64(4, account.account_number)
+            .finish();
+        // 签名对 SignDoc 做 SHA-256，签名为 64 字节的 r || s（s 已规范化为低值）
+        let signature: Signature = self.signing_key.sign(&sign_doc);
+        Ok(CosmosTransaction {
+            body_bytes,
+            auth_info_bytes,
+            signature: signature.to_bytes().to_vec(),
+        })
+    }
+}
+
+/// 按链的地址前缀编码账户地址
+pub fn bech32_address(prefix: &str, bytes: &[u8]) -> Result<String, Error> {
+    let hrp = Hrp::parse(prefix).map_err(|e| Error::Config(format!("Invalid address prefix {}: {}", prefix, e)))?;
+    bech32::encode::<Bech32>(hrp, bytes).map_err(|e| Error::Config(format!("Failed to encode address: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k256::ecdsa::{signature::Verifier, VerifyingKey};
+
+    #[test]
+    fn test_sign_execute_contract() {
+        let signer = CosmosSigner::from_secret_key(&[1u8; 32], "osmo").unwrap();
+        assert!(signer.address().starts_with("osmo1"));
+        let (hrp, data) = bech32::decode(signer.address()).unwrap();
+        assert_eq!(hrp.as_str(), "osmo");
+        assert_eq!(data.len(), 20);
+
+        let account = AccountInfo { account_number: 12, sequence: 3 };
+        let fee = Fee { denom: "uosmo".to_string(), amount: 5_000, gas_limit: 200_000 };
+        let msg = serde_json::json!({ "process_message": { "message": "AQID", "signature": "" } });
+        let tx = signer.sign_execute("osmo1contract", &msg, account, &fee, "osmo-test-5").unwrap();
+
+        // 交易体中只有一条合约调用消息
+        let execute_any = ProtoReader::find_bytes(&tx.body_bytes, 1).unwrap().unwrap();
+        assert_eq!(ProtoReader::find_bytes(execute_any, 1).unwrap().unwrap(), MSG_EXECUTE_CONTRACT_TYPE_URL.as_bytes());
+        let execute = ProtoReader::find_bytes(execute_any, 2).unwrap().unwrap();
+        assert_eq!(ProtoReader::find_bytes(execute, 3).unwrap().unwrap(), serde_json::to_vec(&msg).unwrap());
+
+        let sign_doc = ProtoWriter::new()
+            .bytes(1, &tx.body_bytes)
+            .bytes(2, &tx.auth_info_bytes)
+            .string(3, "osmo-test-5")
+            .uint64(4, 12)
+            .finish();
+        let verifying_key = VerifyingKey::from_sec1_bytes(&signer.public_key).unwrap();
+        let signature = Signature::from_slice(&tx.signature).unwrap();
+        assert!(verifying_key.verify(&sign_doc, &signature).is_ok());
+        assert_eq!(tx.hash().len(), 64);
+
+        // 账户查询结果：Any{BaseAccount{address, pub_key, account_number, sequence}}
+        let base = ProtoWriter::new().string(1, signer.address()).uint64(3, 12).uint64(4, 3).finish();
+        let response = ProtoWriter::new().message(1, &any(BASE_ACCOUNT_TYPE_URL, &base)).finish();
+        assert_eq!(AccountInfo::decode(&response).unwrap(), account);
+    }
+}
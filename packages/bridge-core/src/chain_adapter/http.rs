@@ -0,0 +1,83 @@
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use std::time::Duration;
+use crate::{config::ChainConfig, Error};
+
+/// 按链配置创建 RPC 请求使用的 HTTP 客户端
+///
+/// 客户端内部维护连接池，适配器应持有同一个客户端而不是每次请求新建。
+/// `rpc_headers` 与 `auth` 作为默认请求头附加到每个请求上。
+pub fn client(chain: &ChainConfig) -> Result<reqwest::Client, Error> {
+    let config = chain.http.clone().unwrap_or_default();
+    let mut builder = reqwest::Client::builder()
+        .connect_timeout(Duration::from_secs(config.connect_timeout))
+        .timeout(Duration::from_secs(config.request_timeout))
+        .danger_accept_invalid_certs(config.insecure_skip_verify)
+        .default_headers(headers(chain)?);
+    if let Some(idle) = config.pool_max_idle_per_host {
+        builder = builder.pool_max_idle_per_host(idle);
+    }
+    if let Some(proxy) = &config.proxy {
+        let proxy = reqwest::Proxy::all(proxy).map_err(|e| Error::Config(format!("Invalid proxy {}: {}", proxy, e)))?;
+        builder = builder.proxy(proxy);
+    }
+    if let Some(path) = &config.ca_cert {
+        let pem = std::fs::read(path).map_err(|e| Error::Config(format!("Failed to read CA certificate {}: {}", path, e)))?;
+        let cert = reqwest::Certificate::from_pem(&pem)
+            .map_err(|e| Error::Config(format!("Invalid CA certificate {}: {}", path, e)))?;
+        builder = builder.add_root_certificate(cert);
+    }
+    builder.build().map_err(|e| Error::Config(format!("Failed to build HTTP client: {}", e)))
+}
+
+/// 链配置的请求头，凭据类的值标记为敏感，不会出现在调试输出中
+fn headers(chain: &ChainConfig) -> Result<HeaderMap, Error> {
+    let mut headers = HeaderMap::new();
+    for (name, value) in chain.request_headers() {
+        let name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|e| Error::Config(format!("Invalid RPC header name {}: {}", name, e)))?;
+        let mut value = HeaderValue::from_str(&value)
+            .map_err(|e| Error::Config(format!("Invalid value for RPC header {}: {}", name, e)))?;
+        value.set_sensitive(true);
+        headers.insert(name, value);
+    }
+    Ok(headers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{HttpConfig, RpcAuth};
+    use crate::testing::mock_chain_config;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_client_from_config() {
+        let chain = mock_chain_config("rpc");
+        assert!(client(&chain).is_ok());
+        let proxied = HttpConfig { proxy: Some("http://127.0.0.1:3128".to_string()), ..Default::default() };
+        assert!(client(&ChainConfig { http: Some(proxied), ..chain.clone() }).is_ok());
+
+        let missing_cert = HttpConfig { ca_cert: Some("/nonexistent/ca.pem".to_string()), ..Default::default() };
+        assert!(matches!(client(&ChainConfig { http: Some(missing_cert), ..chain.clone() }), Err(Error::Config(_))));
+        let bad_proxy = HttpConfig { proxy: Some("not a url".to_string()), ..Default::default() };
+        assert!(client(&ChainConfig { http: Some(bad_proxy), ..chain.clone() }).is_err());
+    }
+
+    #[test]
+    fn test_auth_headers() {
+        let chain = ChainConfig {
+            rpc_headers: HashMap::from([("x-api-key".to_string(), "secret".to_string())]),
+            auth: Some(RpcAuth::Basic { username: "user".to_string(), password: "pass".to_string() }),
+            ..mock_chain_config("rpc")
+        };
+        let headers = headers(&chain).unwrap();
+        assert_eq!(headers["x-api-key"], "secret");
+        assert_eq!(headers["authorization"], "Basic dXNlcjpwYXNz");
+
+        let bearer = ChainConfig { auth: Some(RpcAuth::Bearer { token: "t0k".to_string() }), ..chain.clone() };
+        assert_eq!(bearer.request_headers()["Authorization"], "Bearer t0k");
+
+        let invalid = ChainConfig { rpc_headers: HashMap::from([("bad header".to_string(), "x".to_string())]), ..chain };
+        assert!(client(&invalid).is_err());
+    }
+}
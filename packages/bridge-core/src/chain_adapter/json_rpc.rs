@@ -0,0 +1,629 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::HashMap;
+use crate::{
+    types::{ChainId, Delivery, SignedMessage, MessageStatus, CrossChainMessage, ValidatorRegistration},
+    Error,
+    config::{ChainConfig, JsonRpcTemplates, RpcMethodTemplate},
+};
+use super::{AdapterCapabilities, ChainAdapter, ContractCheck, RateLimiter};
+use super::rate_limit::reject_throttled;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::sleep;
+use tracing::{debug, warn};
+
+/// 通用 JSON-RPC 适配器类型名
+pub const JSON_RPC_ADAPTER_TYPE: &str = "json_rpc";
+
+const MAX_RETRIES: u32 = 3;
+const RETRY_DELAY: u64 = 2;
+
+/// 各方法模板可用的占位符
+const EVENT_PLACEHOLDERS: &[&str] = &["cursor", "bridge_address"];
+const SUBMIT_PLACEHOLDERS: &[&str] = &["message", "signature", "nonce", "source_chain", "target_chain", "bridge_address"];
+const STATUS_PLACEHOLDERS: &[&str] = &["nonce", "source_chain", "bridge_address"];
+const VERSION_PLACEHOLDERS: &[&str] = &["bridge_address"];
+
+#[derive(Debug, Clone, PartialEq)]
+enum PathSegment {
+    Field(String),
+    Index(usize),
+}
+
+/// JSONPath 子集：`$` 后跟 `.字段` 与 `[下标]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonPath(Vec<PathSegment>);
+
+impl JsonPath {
+    pub fn parse(path: &str) -> Result<Self, Error> {
+        let invalid = |reason: &str| Error::Config(format!("Invalid response path {}: {}", path, reason));
+        let mut rest = path.strip_prefix('$').ok_or_else(|| invalid("must start with $"))?;
+        let mut segments = Vec::new();
+        while !rest.is_empty() {
+            if let Some(tail) = rest.strip_prefix('.') {
+                let end = tail.find(['.', '[']).unwrap_or(tail.len());
+                if end == 0 {
+                    return Err(invalid("empty field name"));
+                }
+                segments.push(PathSegment::Field(tail[..end].to_string()));
+                rest = &tail[end..];
+            } else if let Some(tail) = rest.strip_prefix('[') {
+                let (index, tail) = tail.split_once(']').ok_or_else(|| invalid("unclosed ["))?;
+                segments.push(PathSegment::Index(index.parse().map_err(|_| invalid("index must be a number"))?));
+                rest = tail;
+            } else {
+                return Err(invalid("expected . or ["));
+            }
+        }
+        Ok(Self(segments))
+    }
+
+    pub fn select<'a>(&self, value: &'a serde_json::Value) -> Option<&'a serde_json::Value> {
+        self.0.iter().try_fold(value, |value, segment| match segment {
+            PathSegment::Field(name) => value.get(name.as_str()),
+            PathSegment::Index(index) => value.get(*index),
+        })
+    }
+}
+
+/// 按变量替换模板中的占位符，模板引用了不存在的变量时返回错误
+pub fn render_template(template: &serde_json::Value, vars: &HashMap<&str, serde_json::Value>) -> Result<serde_json::Value, Error> {
+    Ok(match template {
+        serde_json::Value::String(s) => {
+            if let Some(name) = whole_placeholder(s) {
+                return vars
+                    .get(name)
+                    .cloned()
+                    .ok_or_else(|| Error::Config(format!("Unknown template placeholder {{{{{}}}}}", name)));
+            }
+            let mut output = String::with_capacity(s.len());
+            let mut rest = s.as_str();
+            while let Some(start) = rest.find("{{") {
+                let end = rest[start..]
+                    .find("}}")
+                    .ok_or_else(|| Error::Config(format!("Unterminated placeholder in template {}", s)))?;
+                let name = rest[start + 2..start + end].trim();
+                let value = vars
+                    .get(name)
+                    .ok_or_else(|| Error::Config(format!("Unknown template placeholder {{{{{}}}}}", name)))?;
+                output.push_str(&rest[..start]);
+                match value {
+                    serde_json::Value::String(v) => output.push_str(v),
+                    v => output.push_str(&v.to_string()),
+                }
+                rest = &rest[start + end + 2..];
+            }
+            output.push_str(rest);
+            serde_json::Value::String(output)
+        }
+        serde_json::Value::Array(items) => {
+            serde_json::Value::Array(items.iter().map(|item| render_template(item, vars)).collect::<Result<_, _>>()?)
+        }
+        serde_json::Value::Object(fields) => serde_json::Value::Object(
+            fields
+                .iter()
+                .map(|(key, value)| Ok((key.clone(), render_template(value, vars)?)))
+                .collect::<Result<_, Error>>()?,
+        ),
+        value => value.clone(),
+    })
+}
+
+/// 整个字符串恰好是一个占位符时返回其名称
+fn whole_placeholder(s: &str) -> Option<&str> {
+    let name = s.strip_prefix("{{")?.strip_suffix("}}")?;
+    (!name.contains("{{") && !name.contains("}}")).then(|| name.trim())
+}
+
+/// 模板中引用的占位符名称
+fn placeholders(template: &serde_json::Value, names: &mut Vec<String>) {
+    match template {
+        serde_json::Value::String(s) => {
+            let mut rest = s.as_str();
+            while let Some(start) = rest.find("{{") {
+                let Some(end) = rest[start..].find("}}") else {
+                    names.push(rest[start..].to_string());
+                    return;
+                };
+                names.push(rest[start + 2..start + end].trim().to_string());
+                rest = &rest[start + end + 2..];
+            }
+        }
+        serde_json::Value::Array(items) => items.iter().for_each(|item| placeholders(item, names)),
+        serde_json::Value::Object(fields) => fields.values().for_each(|value| placeholders(value, names)),
+        _ => {}
+    }
+}
+
+/// 检查方法模板，返回 (字段路径, 问题) 列表
+pub fn check_templates(templates: &JsonRpcTemplates) -> Vec<(String, String)> {
+    let mut problems = Vec::new();
+    let mut check_method = |field: &str, template: &RpcMethodTemplate, allowed: &[&str]| {
+        if template.method.is_empty() {
+            problems.push((format!("{}.method", field), "Method name must not be empty".to_string()));
+        }
+        if let Err(e) = JsonPath::parse(&template.result) {
+            problems.push((format!("{}.result", field), e.to_string()));
+        }
+        let mut names = Vec::new();
+        placeholders(&template.params, &mut names);
+        for name in names.iter().filter(|name| !allowed.contains(&name.as_str())) {
+            problems.push((format!("{}.params", field), format!("Unknown placeholder {{{{{}}}}}", name)));
+        }
+    };
+    check_method("events", &templates.events.call, EVENT_PLACEHOLDERS);
+    check_method("submit", &templates.submit, SUBMIT_PLACEHOLDERS);
+    check_method("status", &templates.status, STATUS_PLACEHOLDERS);
+    if let Some(node_version) = &templates.node_version {
+        check_method("node_version", node_version, VERSION_PLACEHOLDERS);
+    }
+
+    let fields = &templates.events.fields;
+    let paths = [
+        ("events.fields.nonce", Some(&fields.nonce)),
+        ("events.fields.source_chain", Some(&fields.source_chain)),
+        ("events.fields.target_chain", Some(&fields.target_chain)),
+        ("events.fields.payload", Some(&fields.payload)),
+        ("events.next_cursor", templates.events.next_cursor.as_ref()),
+    ];
+    for (field, path) in paths {
+        if let Some(Err(e)) = path.map(|p| JsonPath::parse(p)) {
+            problems.push((field.to_string(), e.to_string()));
+        }
+    }
+    problems
+}
+
+/// 数字可能以字符串返回
+fn json_u64(value: &serde_json::Value) -> Option<u64> {
+    match value {
+        serde_json::Value::String(s) => s.parse().ok(),
+        v => v.as_u64(),
+    }
+}
+
+#[derive(Deserialize)]
+struct JsonRpcResponse {
+    #[serde(default)]
+    result: serde_json::Value,
+    error: Option<JsonRpcError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonRpcError {
+    code: i64,
+    message: String,
+}
+
+/// 由配置模板驱动的 JSON-RPC 链适配器
+///
+/// 只需要读取事件、提交消息与查询状态的简单链无需编写 Rust 代码：方法名、参数与响应中的字段位置
+/// 都在 `ChainConfig.json_rpc` 中声明。提交在节点接受交易后即返回，不等待执行结果。
+pub struct GenericJsonRpcAdapter {
+    config: ChainConfig,
+    templates: JsonRpcTemplates,
+    http: reqwest::Client,
+    next_id: AtomicU64,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    connected: AtomicBool,
+    /// 合约中的数字链 ID 到配置链 ID 的映射
+    chain_ids: HashMap<u64, ChainId>,
+    /// 下一次事件查询的游标
+    cursor: Mutex<serde_json::Value>,
+}
+
+impl GenericJsonRpcAdapter {
+    pub fn new(config: ChainConfig) -> Result<Self, Error> {
+        let templates = config.json_rpc.clone().ok_or_else(|| {
+            Error::Config(format!("Chain {} uses the json_rpc adapter but has no method templates", config.id))
+        })?;
+        if let Some((field, problem)) = check_templates(&templates).into_iter().next() {
+            return Err(Error::Config(format!("Invalid json_rpc.{} on chain {}: {}", field, config.id, problem)));
+        }
+        Ok(Self {
+            http: reqwest::Client::new(),
+            next_id: AtomicU64::new(1),
+            rate_limiter: RateLimiter::for_chain(&config),
+            connected: AtomicBool::new(false),
+            chain_ids: HashMap::new(),
+            cursor: Mutex::new(templates.events.start_cursor.clone()),
+            templates,
+            config,
+        })
+    }
+
+    /// 设置数字链 ID 映射，用于把事件中的链 ID 转换为配置中的链 ID
+    pub fn with_chain_ids(mut self, chain_ids: HashMap<u64, ChainId>) -> Self {
+        self.chain_ids = chain_ids;
+        self
+    }
+
+    fn ensure_connected(&self) -> Result<(), Error> {
+        if self.connected.load(Ordering::Acquire) {
+            Ok(())
+        } else {
+            Err(Error::Network(format!("JSON-RPC adapter for {} is not connected", self.config.id)))
+        }
+    }
+
+    async fn retry_with_backoff<F, Fut, T>(&self, operation: F) -> Result<T, Error>
+    where
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = Result<T, Error>>,
+    {
+        self.ensure_connected()?;
+        let mut retries = 0;
+        loop {
+            match operation().await {
+                Ok(result) => return Ok(result),
+                Err(e) => {
+                    retries += 1;
+                    if retries >= MAX_RETRIES {
+                        return Err(e);
+                    }
+                    sleep(Duration::from_secs(RETRY_DELAY.pow(retries))).await;
+                }
+            }
+        }
+    }
+
+    fn chain_name(&self, chain_id: u64) -> Result<ChainId, Error> {
+        self.chain_ids
+            .get(&chain_id)
+            .cloned()
+            .ok_or_else(|| Error::Config(format!("Unknown numeric chain ID in event: {}", chain_id)))
+    }
+
+    /// 配置链 ID 对应的合约数字链 ID
+    fn numeric_chain_id(&self, chain: &ChainId) -> Result<u64, Error> {
+        self.chain_ids
+            .iter()
+            .find(|(_, id)| *id == chain)
+            .map(|(numeric, _)| *numeric)
+            .ok_or_else(|| Error::Config(format!("No numeric chain ID configured for {}", chain)))
+    }
+
+    /// 按模板发起调用，返回 JSON-RPC 的完整 `result`
+    async fn call(&self, template: &RpcMethodTemplate, vars: &HashMap<&str, serde_json::Value>) -> Result<serde_json::Value, Error> {
+        let params = render_template(&template.params, vars)?;
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let response = self.http
+            .post(&self.config.rpc_url)
+            .json(&serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": template.method,
+                "params": params,
+                "id": id
+            }))
+            .send()
+            .await
+            .map_err(|e| Error::Network(e.to_string()))?;
+        let response: JsonRpcResponse = reject_throttled(response, &template.method)?
+            .json()
+            .await
+            .map_err(|e| Error::Chain(format!("Invalid {} response: {}", template.method, e)))?;
+
+        if let Some(error) = response.error {
+            return Err(Error::Rpc {
+                method: template.method.clone(),
+                code: error.code,
+                message: error.message,
+            });
+        }
+        Ok(response.result)
+    }
+
+    /// 取出响应路径指向的值
+    fn select(template_path: &str, method: &str, result: &serde_json::Value) -> Result<serde_json::Value, Error> {
+        JsonPath::parse(template_path)?
+            .select(result)
+            .cloned()
+            .ok_or_else(|| Error::Chain(format!("{} response has no value at {}", method, template_path)))
+    }
+
+    fn base_vars(&self) -> HashMap<&'static str, serde_json::Value> {
+        HashMap::from([("bridge_address", serde_json::Value::from(self.config.bridge_address.clone()))])
+    }
+
+    /// 按字段路径把单个事件解码为跨链消息
+    fn parse_event(&self, event: &serde_json::Value) -> Result<SignedMessage, Error> {
+        let fields = &self.templates.events.fields;
+        let field = |path: &str| {
+            JsonPath::parse(path)?
+                .select(event)
+                .cloned()
+                .ok_or_else(|| Error::Serialization(format!("Event has no value at {}", path)))
+        };
+        let number = |path: &str| {
+            json_u64(&field(path)?).ok_or_else(|| Error::Serialization(format!("Event value at {} is not a number", path)))
+        };
+        let payload = field(&fields.payload)?;
+        let payload = hex::decode(payload.as_str().unwrap_or_default().trim_start_matches("0x"))
+            .map_err(|e| Error::Serialization(format!("Invalid event payload: {}", e)))?;
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|e| Error::Chain(format!("Failed to get current time: {}", e)))?
+            .as_secs();
+
+        Ok(SignedMessage {
+            message: CrossChainMessage {
+                nonce: number(&fields.nonce)?,
+                source_chain: self.chain_name(number(&fields.source_chain)?)?,
+                target_chain: self.chain_name(number(&fields.target_chain)?)?,
+                message_type: "transfer".to_string(),
+                payload,
+            },
+            signature: vec![],
+            timestamp,
+            event_name: None,
+            sender: None,
+            provenance: None,
+            proof: None,
+            trace_id: None,
+        })
+    }
+
+    fn message_vars(&self, message: &SignedMessage) -> Result<HashMap<&'static str, serde_json::Value>, Error> {
+        let mut vars = self.base_vars();
+        vars.insert("nonce", message.message.nonce.into());
+        vars.insert("source_chain", self.numeric_chain_id(&message.message.source_chain)?.into());
+        Ok(vars)
+    }
+}
+
+#[async_trait]
+impl ChainAdapter for GenericJsonRpcAdapter {
+    fn chain_type(&self) -> &str {
+        JSON_RPC_ADAPTER_TYPE
+    }
+
+    fn config(&self) -> &ChainConfig {
+        &self.config
+    }
+
+    fn capabilities(&self) -> AdapterCapabilities {
+        AdapterCapabilities::default()
+    }
+
+    fn rate_limiter(&self) -> Option<Arc<RateLimiter>> {
+        self.rate_limiter.clone()
+    }
+
+    async fn connect(&self) -> Result<(), Error> {
+        // 配置了版本查询时用它确认节点可达
+        if let Some(template) = &self.templates.node_version {
+            self.call(template, &self.base_vars()).await?;
+        }
+        debug!("Connected to JSON-RPC node {}", self.config.rpc_url);
+        self.connected.store(true, Ordering::Release);
+        Ok(())
+    }
+
+    async fn close(&self) -> Result<(), Error> {
+        self.connected.store(false, Ordering::Release);
+        Ok(())
+    }
+
+    async fn is_healthy(&self) -> bool {
+        if !self.connected.load(Ordering::Acquire) {
+            return false;
+        }
+        match &self.templates.node_version {
+            Some(template) => self.call(template, &self.base_vars()).await.is_ok(),
+            None => true,
+        }
+    }
+
+    async fn listen_events(&self) -> Result<Vec<SignedMessage>, Error> {
+        let events = &self.templates.events;
+        let mut cursor = self.cursor.lock().await;
+        let mut vars = self.base_vars();
+        vars.insert("cursor", cursor.clone());
+        let result = self.retry_with_backoff(|| self.call(&events.call, &vars)).await?;
+        let items = match Self::select(&events.call.result, &events.call.method, &result)? {
+            serde_json::Value::Array(items) => items,
+            serde_json::Value::Null => Vec::new(),
+            _ => return Err(Error::Chain(format!("{} response at {} is not an array", events.call.method, events.call.result))),
+        };
+
+        let mut messages = Vec::new();
+        for item in &items {
+            match self.parse_event(item) {
+                Ok(message) => messages.push(message),
+                Err(e) => warn!("Skipping undecodable event on {}: {}", self.config.id, e),
+            }
+        }
+        match &events.next_cursor {
+            Some(path) => {
+                if let Some(next) = JsonPath::parse(path)?.select(&result).filter(|next| !next.is_null()) {
+                    *cursor = next.clone();
+                }
+            }
+            None => {
+                if let Some(last) = messages.iter().map(|m| m.message.nonce).max() {
+                    *cursor = (last + 1).into();
+                }
+            }
+        }
+        Ok(messages)
+    }
+
+    async fn submit_message(&self, message: SignedMessage) -> Result<String, Error> {
+        let mut vars = self.message_vars(&message)?;
+        vars.insert("message", format!("0x{}", hex::encode(message.message.canonical_bytes(&self.chain_ids)?)).into());
+        vars.insert("signature", format!("0x{}", hex::encode(&message.signature)).into());
+        vars.insert("target_chain", self.numeric_chain_id(&message.message.target_chain)?.into());
+
+        let template = &self.templates.submit;
+        let result = self.retry_with_backoff(|| self.call(template, &vars)).await?;
+        let hash = match Self::select(&template.result, &template.method, &result)? {
+            serde_json::Value::String(hash) => hash,
+            serde_json::Value::Null => return Err(Error::Chain(format!("{} returned no transaction hash", template.method))),
+            value => value.to_string(),
+        };
+        debug!("Submitted message {} in transaction {}", message.message.nonce, hash);
+        Ok(hash)
+    }
+
+    async fn listen_deliveries(&self) -> Result<Vec<Delivery>, Error> {
+        Ok(Vec::new())
+    }
+
+    async fn verify_message(&self, message: &SignedMessage) -> Result<MessageStatus, Error> {
+        let vars = self.message_vars(message)?;
+        let template = &self.templates.status;
+        let result = self.retry_with_backoff(|| self.call(template, &vars)).await?;
+        Ok(match Self::select(&template.result, &template.method, &result)?.as_bool() {
+            Some(true) => MessageStatus::Processed,
+            _ => MessageStatus::Pending,
+        })
+    }
+
+    async fn last_processed_nonce(&self, _source_chain: &ChainId) -> Result<Option<u64>, Error> {
+        Ok(None)
+    }
+
+    async fn get_validator_registration(&self, _validator: &str) -> Result<Option<ValidatorRegistration>, Error> {
+        Ok(None)
+    }
+
+    async fn node_version(&self) -> Result<String, Error> {
+        // 未配置版本查询时不报告版本，配置 `min_node_version` 的链无法通过版本检查
+        let Some(template) = &self.templates.node_version else {
+            return Ok(JSON_RPC_ADAPTER_TYPE.to_string());
+        };
+        let vars = self.base_vars();
+        let result = self.retry_with_backoff(|| self.call(template, &vars)).await?;
+        Ok(match Self::select(&template.result, &template.method, &result)? {
+            serde_json::Value::String(version) => version,
+            value => value.to_string(),
+        })
+    }
+
+    async fn check_contract(&self) -> Result<ContractCheck, Error> {
+        Err(Error::Chain("Contract checks are not supported by json_rpc adapter".to_string()))
+    }
+
+    async fn check_recipient(&self, _recipient: &[u8], _asset_type: &str) -> Result<Option<String>, Error> {
+        Ok(None)
+    }
+
+    async fn gas_balance(&self) -> Result<Option<u128>, Error> {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_template_driven_adapter() {
+        let templates: JsonRpcTemplates = serde_json::from_value(json!({
+            "events": {
+                "method": "bridge_getEvents",
+                "params": [{ "contract": "{{bridge_address}}", "from": "{{cursor}}" }],
+                "result": "$.events",
+                "next_cursor": "$.next",
+                "fields": {
+                    "nonce": "$.data.nonce",
+                    "source_chain": "$.data.route[0]",
+                    "target_chain": "$.data.route[1]",
+                    "payload": "$.data.payload"
+                }
+            },
+            "submit": {
+                "method": "bridge_submit",
+                "params": ["{{message}}", "{{signature}}", "nonce-{{nonce}}"],
+                "result": "$.tx.hash"
+            },
+            "status": {
+                "method": "bridge_isProcessed",
+                "params": ["{{source_chain}}", "{{nonce}}"]
+            }
+        }))
+        .unwrap();
+        let mut bad = templates.clone();
+        bad.status.params = json!(["{{cursor}}"]);
+        bad.submit.result = "events".to_string();
+        assert_eq!(check_templates(&bad).len(), 2);
+        assert!(check_templates(&templates).is_empty());
+
+        let mut server = mockito::Server::new_async().await;
+        let events = server.mock("POST", "/")
+            .match_body(mockito::Matcher::PartialJson(json!({
+                "method": "bridge_getEvents",
+                "params": [{ "contract": "0xbridge", "from": 0 }]
+            })))
+            .with_body(json!({ "jsonrpc": "2.0", "id": 1, "result": {
+                "events": [
+                    { "data": { "nonce": "7", "route": [5, 1], "payload": "0x0102" } },
+                    { "data": { "nonce": 8, "route": [5, 99], "payload": "0x03" } }
+                ],
+                "next": "page-2"
+            }}).to_string())
+            .create_async()
+            .await;
+        let next_page = server.mock("POST", "/")
+            .match_body(mockito::Matcher::PartialJson(json!({ "params": [{ "from": "page-2" }] })))
+            .with_body(json!({ "jsonrpc": "2.0", "id": 2, "result": { "events": [], "next": null } }).to_string())
+            .expect(2)
+            .create_async()
+            .await;
+        let status = server.mock("POST", "/")
+            .match_body(mockito::Matcher::PartialJson(json!({ "method": "bridge_isProcessed", "params": [5, 7] })))
+            .with_body(json!({ "jsonrpc": "2.0", "id": 3, "result": true }).to_string())
+            .create_async()
+            .await;
+
+        let config = ChainConfig {
+            adapter_type: JSON_RPC_ADAPTER_TYPE.to_string(),
+            rpc_url: server.url(),
+            bridge_address: "0xbridge".to_string(),
+            json_rpc: Some(templates),
+            ..crate::testing::mock_chain_config("simple")
+        };
+        let adapter = GenericJsonRpcAdapter::new(config).unwrap().with_chain_ids(HashMap::from([
+            (1, ChainId::from("sui_testnet")),
+            (5, ChainId::from("simple")),
+        ]));
+        assert!(adapter.listen_events().await.is_err());
+        adapter.connect().await.unwrap();
+
+        // 第二个事件的目标链未配置，被跳过
+        let messages = adapter.listen_events().await.unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].message.nonce, 7);
+        assert_eq!(messages[0].message.target_chain, "sui_testnet");
+        assert_eq!(messages[0].message.payload, vec![1, 2]);
+        // 下一页为空且没有新游标时保持原游标
+        assert!(adapter.listen_events().await.unwrap().is_empty());
+        assert!(adapter.listen_events().await.unwrap().is_empty());
+        events.assert_async().await;
+        next_page.assert_async().await;
+
+        assert_eq!(adapter.verify_message(&messages[0]).await.unwrap(), MessageStatus::Processed);
+        status.assert_async().await;
+
+        let mut message = messages[0].clone();
+        message.signature = vec![0xab];
+        let canonical = format!("0x{}", hex::encode(message.message.canonical_bytes(&adapter.chain_ids).unwrap()));
+        let submit = server.mock("POST", "/")
+            .match_body(mockito::Matcher::PartialJson(json!({
+                "method": "bridge_submit",
+                "params": [canonical, "0xab", "nonce-7"]
+            })))
+            .with_body(json!({ "jsonrpc": "2.0", "id": 4, "result": { "tx": { "hash": "0xfeed" } } }).to_string())
+            .create_async()
+            .await;
+        assert_eq!(adapter.submit_message(message).await.unwrap(), "0xfeed");
+        submit.assert_async().await;
+    }
+}
@@ -0,0 +1,638 @@
+use async_trait::async_trait;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use crate::{
+    relayer::{equivocation::EquivocationEvidence, rotation::ValidatorSet},
+    types::{ChainId, CrossChainMessage, Delivery, MetadataSync, SignedMessage, MessageStatus, ValidatorRegistration},
+    watchtower::Challenge,
+    Error,
+    config::ChainConfig,
+    relayer::ChainAdapterFactory,
+};
+use super::{check_address_format, AdapterCapabilities, ChainAdapter, ContractCheck, RateLimiter};
+
+/// 模拟链的适配器类型名
+pub const MOCK_ADAPTER_TYPE: &str = "mock";
+
+/// 可注入失败的适配器操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MockOperation {
+    Connect,
+    ListenEvents,
+    ReplayEvents,
+    LatestHeight,
+    Submit,
+    Resubmit,
+    SubmitBatch,
+    DryRun,
+    ListenDeliveries,
+    Challenge,
+    Equivocation,
+    Verify,
+    ProcessedNonce,
+    UpdateValidatorSet,
+    RegisterAsset,
+    Registration,
+    NodeVersion,
+    CheckContract,
+    GasBalance,
+    GasPrice,
+    TransactionGas,
+    Supply,
+    Refund,
+}
+
+struct MockState {
+    /// 尚未被读取的源链事件
+    events: VecDeque<SignedMessage>,
+    /// 发出过的全部源链事件，下标即回放区间中的位置
+    history: Vec<SignedMessage>,
+    /// 已提交的交易，按提交顺序排列
+    submitted: Vec<(String, SignedMessage)>,
+    /// 尚未被读取的交付记录
+    deliveries: VecDeque<Delivery>,
+    /// 收到的挑战交易，按提交顺序排列
+    challenges: Vec<(String, Challenge)>,
+    /// 收到的双签证据，按提交顺序排列
+    equivocations: Vec<EquivocationEvidence>,
+    /// 链的最新高度，None 表示无法查询
+    height: Option<u64>,
+    /// 推送到合约的验证者集合，按推送顺序排列
+    validator_sets: Vec<ValidatorSet>,
+    /// 登记到合约的代币元数据，按登记顺序排列
+    assets: Vec<MetadataSync>,
+    /// 待触发的失败，按注入顺序匹配操作
+    failures: VecDeque<(MockOperation, Error)>,
+    latency: Duration,
+    registrations: HashMap<String, ValidatorRegistration>,
+    node_version: String,
+    gas_balance: Option<u128>,
+    gas_price: Option<u64>,
+    /// 每笔交易消耗的 gas，None 表示无法查询
+    transaction_gas: Option<u64>,
+    next_nonce: u64,
+    /// 合约已处理的各源链最大 nonce
+    processed_nonces: HashMap<ChainId, u64>,
+    /// 合约定义的事件类型，None 表示合约不存在
+    contract_events: Option<Vec<String>>,
+    /// 合约锁定的各原生资产数量，未设置的资产无法查询
+    locked_balances: HashMap<String, u128>,
+    /// 各包装资产的流通量，未设置的资产无法查询
+    wrapped_supplies: HashMap<String, u128>,
+    /// 合约为每条附带收款地址的消息报销的 gas
+    refund_per_message: u64,
+    /// 各收款地址累计未认领的报销
+    refunds: HashMap<String, u64>,
+    /// 认领报销的交易哈希、收款地址与金额，按认领顺序排列
+    refund_claims: Vec<(String, String, u64)>,
+}
+
+impl MockState {
+    /// 在 `chain` 上执行一条消息：记录交易与交付，推进已处理 nonce，并为附带的收款地址累计报销
+    fn execute(&mut self, chain: &ChainId, tx_hash: &str, event_id: String, message: SignedMessage, payout: Option<&str>) {
+        if let Some(payout) = payout {
+            *self.refunds.entry(payout.to_string()).or_default() += self.refund_per_message;
+        }
+        self.deliveries.push_back(Delivery {
+            event_id,
+            source_chain: message.message.source_chain.clone(),
+            target_chain: chain.clone(),
+            nonce: Some(message.message.nonce),
+            recipient: None,
+            amount: None,
+            tx_hash: Some(tx_hash.to_string()),
+            message_id: message.trace_id.clone(),
+        });
+        let processed = self.processed_nonces.entry(message.message.source_chain.clone()).or_default();
+        *processed = (*processed).max(message.message.nonce);
+        self.submitted.push((tx_hash.to_string(), message));
+    }
+}
+
+/// 内存中的模拟链，克隆后共享同一状态
+///
+/// 测试通过该句柄写入源链事件、注入失败与延迟，并检查目标链收到的交易。
+#[derive(Clone)]
+pub struct MockChain {
+    id: ChainId,
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockChain {
+    pub fn new(id: &str) -> Self {
+        Self {
+            id: id.into(),
+            state: Arc::new(Mutex::new(MockState {
+                events: VecDeque::new(),
+                history: Vec::new(),
+                submitted: Vec::new(),
+                deliveries: VecDeque::new(),
+                challenges: Vec::new(),
+                equivocations: Vec::new(),
+                height: None,
+                validator_sets: Vec::new(),
+                assets: Vec::new(),
+                failures: VecDeque::new(),
+                latency: Duration::ZERO,
+                registrations: HashMap::new(),
+                node_version: "mock".to_string(),
+                gas_balance: None,
+                gas_price: None,
+                transaction_gas: None,
+                next_nonce: 0,
+                processed_nonces: HashMap::new(),
+                contract_events: Some(vec!["MessageEvent".to_string()]),
+                locked_balances: HashMap::new(),
+                wrapped_supplies: HashMap::new(),
+                refund_per_message: 0,
+                refunds: HashMap::new(),
+                refund_claims: Vec::new(),
+            })),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// 写入一条源链事件，下一次读取事件时返回
+    pub fn emit(&self, message: SignedMessage) {
+        let mut state = self.state.lock().unwrap();
+        state.history.push(message.clone());
+        state.events.push_back(message);
+    }
+
+    /// 以递增的 nonce 和当前时间发出一条发往 `target_chain` 的消息
+    pub fn send(&self, target_chain: &str, message_type: &str, payload: Vec<u8>) -> SignedMessage {
+        let mut state = self.state.lock().unwrap();
+        let message = SignedMessage {
+            message: CrossChainMessage {
+                nonce: state.next_nonce,
+                source_chain: self.id.clone(),
+                target_chain: target_chain.into(),
+                message_type: message_type.to_string(),
+                payload,
+            },
+            signature: Vec::new(),
+            timestamp: now_secs(),
+            event_name: None,
+            sender: None,
+            provenance: None,
+            proof: None,
+            trace_id: None,
+        };
+        state.next_nonce += 1;
+        state.history.push(message.clone());
+        state.events.push_back(message.clone());
+        message
+    }
+
+    /// 丢弃尚未被读取的源链事件，模拟中继器漏读，返回丢弃的条数
+    pub fn discard_events(&self) -> usize {
+        let mut state = self.state.lock().unwrap();
+        let discarded = state.events.len();
+        state.events.clear();
+        discarded
+    }
+
+    /// 让下一次指定操作返回错误
+    pub fn fail_next(&self, operation: MockOperation, error: Error) {
+        self.state.lock().unwrap().failures.push_back((operation, error));
+    }
+
+    /// 每次操作前等待的模拟网络延迟
+    pub fn set_latency(&self, latency: Duration) {
+        self.state.lock().unwrap().latency = latency;
+    }
+
+    pub fn set_node_version(&self, version: &str) {
+        self.state.lock().unwrap().node_version = version.to_string();
+    }
+
+    pub fn set_gas_balance(&self, balance: Option<u128>) {
+        self.state.lock().unwrap().gas_balance = balance;
+    }
+
+    pub fn set_gas_price(&self, price: Option<u64>) {
+        self.state.lock().unwrap().gas_price = price;
+    }
+
+    /// 设置每笔已执行交易消耗的 gas
+    pub fn set_transaction_gas(&self, gas: Option<u64>) {
+        self.state.lock().unwrap().transaction_gas = gas;
+    }
+
+    /// 设置合约定义的事件类型，None 表示合约不存在
+    pub fn set_contract_events(&self, events: Option<&[&str]>) {
+        self.state.lock().unwrap().contract_events = events.map(|events| events.iter().map(|e| e.to_string()).collect());
+    }
+
+    pub fn register_validator(&self, registration: ValidatorRegistration) {
+        self.state.lock().unwrap().registrations.insert(registration.address.clone(), registration);
+    }
+
+    /// 目标链已收到的消息，按提交顺序排列
+    pub fn submitted(&self) -> Vec<SignedMessage> {
+        self.state.lock().unwrap().submitted.iter().map(|(_, message)| message.clone()).collect()
+    }
+
+    /// 目标链已执行的交易哈希与消息，按提交顺序排列
+    pub fn transactions(&self) -> Vec<(String, SignedMessage)> {
+        self.state.lock().unwrap().submitted.clone()
+    }
+
+    /// 设置合约锁定的原生资产数量，None 表示无法查询
+    pub fn set_locked_balance(&self, asset_type: &str, amount: Option<u128>) {
+        let mut state = self.state.lock().unwrap();
+        match amount {
+            Some(amount) => state.locked_balances.insert(asset_type.to_string(), amount),
+            None => state.locked_balances.remove(asset_type),
+        };
+    }
+
+    /// 设置包装资产的流通量，None 表示无法查询
+    pub fn set_wrapped_supply(&self, asset_type: &str, amount: Option<u128>) {
+        let mut state = self.state.lock().unwrap();
+        match amount {
+            Some(amount) => state.wrapped_supplies.insert(asset_type.to_string(), amount),
+            None => state.wrapped_supplies.remove(asset_type),
+        };
+    }
+
+    /// 合约为每条附带收款地址的消息报销的 gas，默认为 0
+    pub fn set_refund_per_message(&self, amount: u64) {
+        self.state.lock().unwrap().refund_per_message = amount;
+    }
+
+    /// 认领报销的交易：交易哈希、收款地址与金额，按认领顺序排列
+    pub fn refund_claims(&self) -> Vec<(String, String, u64)> {
+        self.state.lock().unwrap().refund_claims.clone()
+    }
+
+    /// 在本链执行一条消息而不经过被测中继器，模拟另一个中继器抢先提交，返回交易哈希
+    pub fn execute_external(&self, message: SignedMessage) -> String {
+        let mut state = self.state.lock().unwrap();
+        let tx_hash = format!("{}-external-{}", self.id, state.submitted.len());
+        state.execute(&self.id, &tx_hash, tx_hash.clone(), message, None);
+        tx_hash
+    }
+
+    /// 写入一条不经过中继器的交付记录，模拟运营方的错误或欺诈交付
+    pub fn deliver(&self, delivery: Delivery) {
+        self.state.lock().unwrap().deliveries.push_back(delivery);
+    }
+
+    /// 本链收到的挑战，按提交顺序排列
+    pub fn challenges(&self) -> Vec<Challenge> {
+        self.state.lock().unwrap().challenges.iter().map(|(_, challenge)| challenge.clone()).collect()
+    }
+
+    /// 本链罚没合约收到的双签证据，按提交顺序排列
+    pub fn equivocations(&self) -> Vec<EquivocationEvidence> {
+        self.state.lock().unwrap().equivocations.clone()
+    }
+
+    pub fn set_height(&self, height: Option<u64>) {
+        self.state.lock().unwrap().height = height;
+    }
+
+    /// 合约收到的验证者集合，按推送顺序排列
+    pub fn validator_sets(&self) -> Vec<ValidatorSet> {
+        self.state.lock().unwrap().validator_sets.clone()
+    }
+
+    /// 合约登记的代币元数据，按登记顺序排列
+    pub fn assets(&self) -> Vec<MetadataSync> {
+        self.state.lock().unwrap().assets.clone()
+    }
+
+    /// 尚未被读取的源链事件数
+    pub fn pending_events(&self) -> usize {
+        self.state.lock().unwrap().events.len()
+    }
+
+    /// 模拟一次链上操作：先等待延迟，再检查是否有注入的失败
+    async fn enter(&self, operation: MockOperation) -> Result<(), Error> {
+        let latency = self.state.lock().unwrap().latency;
+        if !latency.is_zero() {
+            tokio::time::sleep(latency).await;
+        }
+        let mut state = self.state.lock().unwrap();
+        match state.failures.iter().position(|(op, _)| *op == operation) {
+            Some(index) => Err(state.failures.remove(index).expect("index in range").1),
+            None => Ok(()),
+        }
+    }
+}
+
+/// 基于 [`MockChain`] 的链适配器
+pub struct MockChainAdapter {
+    chain: MockChain,
+    config: ChainConfig,
+    connected: AtomicBool,
+    rate_limiter: Option<Arc<RateLimiter>>,
+}
+
+impl MockChainAdapter {
+    pub fn new(chain: MockChain, config: ChainConfig) -> Self {
+        Self {
+            chain,
+            rate_limiter: RateLimiter::for_chain(&config),
+            config,
+            connected: AtomicBool::new(false),
+        }
+    }
+
+    fn payout(&self) -> Option<&str> {
+        self.config.reimbursement.as_ref().map(|r| r.payout_address.as_str())
+    }
+
+    async fn enter(&self, operation: MockOperation) -> Result<(), Error> {
+        if !self.connected.load(Ordering::Acquire) {
+            return Err(Error::Network(format!("Mock adapter for {} is not connected", self.chain.id)));
+        }
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+        self.chain.enter(operation).await
+    }
+}
+
+#[async_trait]
+impl ChainAdapter for MockChainAdapter {
+    fn chain_type(&self) -> &str {
+        MOCK_ADAPTER_TYPE
+    }
+
+    fn config(&self) -> &ChainConfig {
+        &self.config
+    }
+
+    fn capabilities(&self) -> AdapterCapabilities {
+        AdapterCapabilities {
+            subscriptions: false,
+            batch_submit: true,
+            dry_run: true,
+        }
+    }
+
+    fn rate_limiter(&self) -> Option<Arc<RateLimiter>> {
+        self.rate_limiter.clone()
+    }
+
+    async fn connect(&self) -> Result<(), Error> {
+        self.chain.enter(MockOperation::Connect).await?;
+        self.connected.store(true, Ordering::Release);
+        Ok(())
+    }
+
+    async fn close(&self) -> Result<(), Error> {
+        self.connected.store(false, Ordering::Release);
+        Ok(())
+    }
+
+    async fn is_healthy(&self) -> bool {
+        self.connected.load(Ordering::Acquire)
+    }
+
+    async fn listen_events(&self) -> Result<Vec<SignedMessage>, Error> {
+        self.enter(MockOperation::ListenEvents).await?;
+        Ok(self.chain.state.lock().unwrap().events.drain(..).collect())
+    }
+
+    async fn replay_events(&self, from: u64, to: u64) -> Result<Vec<SignedMessage>, Error> {
+        self.enter(MockOperation::ReplayEvents).await?;
+        let state = self.chain.state.lock().unwrap();
+        Ok(state.history
+            .iter()
+            .skip(from as usize)
+            .take(to.saturating_sub(from).saturating_add(1) as usize)
+            .cloned()
+            .collect())
+    }
+
+    async fn latest_height(&self) -> Result<Option<u64>, Error> {
+        self.enter(MockOperation::LatestHeight).await?;
+        Ok(self.chain.state.lock().unwrap().height)
+    }
+
+    async fn submit_message(&self, message: SignedMessage) -> Result<String, Error> {
+        self.enter(MockOperation::Submit).await?;
+        let mut state = self.chain.state.lock().unwrap();
+        let tx_hash = format!("{}-tx-{}", self.chain.id, state.submitted.len());
+        state.execute(&self.chain.id, &tx_hash, tx_hash.clone(), message, self.payout());
+        Ok(tx_hash)
+    }
+
+    async fn resubmit_message(&self, message: SignedMessage, _stuck_tx: &str, _attempt: u32) -> Result<String, Error> {
+        self.enter(MockOperation::Resubmit).await?;
+        let mut state = self.chain.state.lock().unwrap();
+        let tx_hash = format!("{}-tx-{}", self.chain.id, state.submitted.len());
+        state.execute(&self.chain.id, &tx_hash, tx_hash.clone(), message, self.payout());
+        Ok(tx_hash)
+    }
+
+    async fn submit_messages_batch(&self, messages: Vec<SignedMessage>) -> Result<String, Error> {
+        self.enter(MockOperation::SubmitBatch).await?;
+        let mut state = self.chain.state.lock().unwrap();
+        let tx_hash = format!("{}-tx-{}", self.chain.id, state.submitted.len());
+        for (index, message) in messages.into_iter().enumerate() {
+            state.execute(&self.chain.id, &tx_hash, format!("{}-{}", tx_hash, index), message, self.payout());
+        }
+        Ok(tx_hash)
+    }
+
+
+    async fn dry_run(&self, _message: &SignedMessage) -> Result<(), Error> {
+        self.enter(MockOperation::DryRun).await
+    }
+
+    async fn listen_deliveries(&self) -> Result<Vec<Delivery>, Error> {
+        self.enter(MockOperation::ListenDeliveries).await?;
+        Ok(self.chain.state.lock().unwrap().deliveries.drain(..).collect())
+    }
+
+    async fn submit_challenge(&self, challenge: &Challenge) -> Result<String, Error> {
+        self.enter(MockOperation::Challenge).await?;
+        let mut state = self.chain.state.lock().unwrap();
+        let tx_hash = format!("{}-challenge-{}", self.chain.id, state.challenges.len());
+        state.challenges.push((tx_hash.clone(), challenge.clone()));
+        Ok(tx_hash)
+    }
+
+    async fn submit_equivocation(&self, evidence: &EquivocationEvidence) -> Result<Option<String>, Error> {
+        self.enter(MockOperation::Equivocation).await?;
+        let mut state = self.chain.state.lock().unwrap();
+        let tx_hash = format!("{}-slash-{}", self.chain.id, state.equivocations.len());
+        state.equivocations.push(evidence.clone());
+        Ok(Some(tx_hash))
+    }
+
+    async fn verify_message(&self, message: &SignedMessage) -> Result<MessageStatus, Error> {
+        self.enter(MockOperation::Verify).await?;
+        let state = self.chain.state.lock().unwrap();
+        let delivered = state.submitted.iter().any(|(_, submitted)| submitted.message == message.message);
+        Ok(if delivered { MessageStatus::Processed } else { MessageStatus::Pending })
+    }
+
+    async fn last_processed_nonce(&self, source_chain: &ChainId) -> Result<Option<u64>, Error> {
+        self.enter(MockOperation::ProcessedNonce).await?;
+        Ok(self.chain.state.lock().unwrap().processed_nonces.get(source_chain).copied())
+    }
+
+    async fn update_validator_set(&self, set: &ValidatorSet) -> Result<Option<String>, Error> {
+        self.enter(MockOperation::UpdateValidatorSet).await?;
+        let mut state = self.chain.state.lock().unwrap();
+        let tx_hash = format!("{}-validators-{}", self.chain.id, state.validator_sets.len());
+        state.validator_sets.push(set.clone());
+        Ok(Some(tx_hash))
+    }
+
+    async fn register_asset(&self, metadata: &MetadataSync) -> Result<Option<String>, Error> {
+        self.enter(MockOperation::RegisterAsset).await?;
+        let mut state = self.chain.state.lock().unwrap();
+        let tx_hash = format!("{}-asset-{}", self.chain.id, state.assets.len());
+        state.assets.push(metadata.clone());
+        Ok(Some(tx_hash))
+    }
+
+    async fn get_validator_registration(&self, validator: &str) -> Result<Option<ValidatorRegistration>, Error> {
+        self.enter(MockOperation::Registration).await?;
+        Ok(self.chain.state.lock().unwrap().registrations.get(validator).cloned())
+    }
+
+    async fn node_version(&self) -> Result<String, Error> {
+        self.enter(MockOperation::NodeVersion).await?;
+        Ok(self.chain.state.lock().unwrap().node_version.clone())
+    }
+
+    async fn check_contract(&self) -> Result<ContractCheck, Error> {
+        self.enter(MockOperation::CheckContract).await?;
+        let state = self.chain.state.lock().unwrap();
+        let Some(defined) = &state.contract_events else {
+            return Ok(ContractCheck { exists: false, missing_events: Vec::new() });
+        };
+        Ok(ContractCheck {
+            exists: true,
+            missing_events: self.config.event_filters
+                .iter()
+                .filter(|filter| !defined.contains(&filter.name))
+                .map(|filter| filter.name.clone())
+                .collect(),
+        })
+    }
+
+    async fn check_recipient(&self, recipient: &[u8], _asset_type: &str) -> Result<Option<String>, Error> {
+        Ok(check_address_format(recipient))
+    }
+
+    async fn gas_balance(&self) -> Result<Option<u128>, Error> {
+        self.enter(MockOperation::GasBalance).await?;
+        Ok(self.chain.state.lock().unwrap().gas_balance)
+    }
+
+    async fn gas_price(&self) -> Result<Option<u64>, Error> {
+        self.enter(MockOperation::GasPrice).await?;
+        Ok(self.chain.state.lock().unwrap().gas_price)
+    }
+
+    async fn transaction_gas(&self, tx_hash: &str) -> Result<Option<u64>, Error> {
+        self.enter(MockOperation::TransactionGas).await?;
+        let state = self.chain.state.lock().unwrap();
+        let executed = state.submitted.iter().any(|(hash, _)| hash == tx_hash);
+        Ok(state.transaction_gas.filter(|_| executed))
+    }
+
+    async fn locked_balance(&self, asset_type: &str) -> Result<Option<u128>, Error> {
+        self.enter(MockOperation::Supply).await?;
+        Ok(self.chain.state.lock().unwrap().locked_balances.get(asset_type).copied())
+    }
+
+    async fn wrapped_supply(&self, asset_type: &str) -> Result<Option<u128>, Error> {
+        self.enter(MockOperation::Supply).await?;
+        Ok(self.chain.state.lock().unwrap().wrapped_supplies.get(asset_type).copied())
+    }
+
+    async fn accrued_refund(&self) -> Result<Option<u64>, Error> {
+        let Some(payout) = self.payout() else {
+            return Ok(None);
+        };
+        self.enter(MockOperation::Refund).await?;
+        Ok(Some(self.chain.state.lock().unwrap().refunds.get(payout).copied().unwrap_or(0)))
+    }
+
+    async fn claim_refund(&self) -> Result<Option<String>, Error> {
+        let Some(payout) = self.payout() else {
+            return Ok(None);
+        };
+        self.enter(MockOperation::Refund).await?;
+        let mut state = self.chain.state.lock().unwrap();
+        let amount = state.refunds.remove(payout).unwrap_or(0);
+        let tx_hash = format!("{}-claim-{}", self.chain.id, state.refund_claims.len());
+        state.refund_claims.push((tx_hash.clone(), payout.to_string(), amount));
+        Ok(Some(tx_hash))
+    }
+}
+
+/// 为配置中的每条链返回对应模拟链适配器的工厂
+#[derive(Clone, Default)]
+pub struct MockChainAdapterFactory {
+    chains: HashMap<ChainId, MockChain>,
+}
+
+impl MockChainAdapterFactory {
+    pub fn new<I: IntoIterator<Item = MockChain>>(chains: I) -> Self {
+        Self {
+            chains: chains.into_iter().map(|chain| (chain.id.clone(), chain)).collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl ChainAdapterFactory for MockChainAdapterFactory {
+    async fn create_adapter(&self, config: &ChainConfig) -> Result<Box<dyn ChainAdapter>, Error> {
+        let chain = self.chains
+            .get(&config.id)
+            .ok_or_else(|| Error::Config(format!("No mock chain registered for {}", config.id)))?;
+        let adapter = MockChainAdapter::new(chain.clone(), config.clone());
+        adapter.connect().await?;
+        Ok(Box::new(adapter))
+    }
+}
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::mock_chain_config;
+
+    #[tokio::test]
+    async fn test_adapter_requires_connection() {
+        let chain = MockChain::new("mock");
+        let adapter = MockChainAdapter::new(chain.clone(), mock_chain_config("mock"));
+        assert!(adapter.listen_events().await.is_err());
+        assert!(!adapter.is_healthy().await);
+
+        chain.fail_next(MockOperation::Connect, Error::Network("refused".to_string()));
+        assert!(adapter.connect().await.is_err());
+        adapter.connect().await.unwrap();
+        assert!(adapter.is_healthy().await);
+        assert!(adapter.capabilities().dry_run);
+
+        let sent = chain.send("other", "transfer", vec![]);
+        adapter.dry_run(&sent).await.unwrap();
+        assert_eq!(adapter.listen_events().await.unwrap()[0].message, sent.message);
+
+        adapter.close().await.unwrap();
+        assert!(!adapter.is_healthy().await);
+        assert!(adapter.gas_balance().await.is_err());
+    }
+}
@@ -1,24 +1,238 @@
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
 use crate::{
-    types::{SignedMessage, MessageStatus},
+    codegen::MoveModule,
+    proof::InclusionProof,
+    relayer::{equivocation::EquivocationEvidence, rotation::ValidatorSet},
+    types::{ChainId, Delivery, MetadataSync, SignedMessage, MessageStatus, ValidatorRegistration},
+    watchtower::Challenge,
     Error,
     config::ChainConfig,
 };
 
+/// 内置的链适配器类型，第三方类型通过 [`registry::register_adapter`] 注册
+#[cfg(not(feature = "wasm-plugins"))]
+pub const SUPPORTED_ADAPTER_TYPES: &[&str] = &["sui", "rooch", "solana", "movement", "cosmos", "json_rpc"];
+/// 内置的链适配器类型，第三方类型通过 [`registry::register_adapter`] 注册
+#[cfg(feature = "wasm-plugins")]
+pub const SUPPORTED_ADAPTER_TYPES: &[&str] = &["sui", "rooch", "solana", "movement", "cosmos", "json_rpc", "wasm"];
+
+/// 适配器能力描述，调用方据此决定是否使用可选功能
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AdapterCapabilities {
+    /// 支持推送订阅事件；不支持时只能轮询 `listen_events`
+    pub subscriptions: bool,
+    /// 支持在一笔交易中提交多条消息
+    pub batch_submit: bool,
+    /// 支持通过 `dry_run` 在提交前模拟执行
+    pub dry_run: bool,
+}
+
+/// 桥合约的链上检查结果
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractCheck {
+    /// `bridge_address` 指向的包或对象是否存在
+    pub exists: bool,
+    /// 配置的事件过滤器中，合约没有定义的事件类型
+    pub missing_events: Vec<String>,
+}
+
+impl ContractCheck {
+    pub fn is_ok(&self) -> bool {
+        self.exists && self.missing_events.is_empty()
+    }
+}
+
 /// 链适配器特征，定义了与具体链交互所需的基本功能
+///
+/// 适配器创建时持有所属链的配置，创建后须先调用 `connect` 建立连接，
+/// 未连接或 `close` 之后的链上操作返回网络错误。
 #[async_trait]
 pub trait ChainAdapter: Send + Sync {
     /// 获取链的类型标识
     fn chain_type(&self) -> &str;
-    
+
+    /// 适配器所属链的配置
+    fn config(&self) -> &ChainConfig;
+
+    /// 适配器支持的可选功能
+    fn capabilities(&self) -> AdapterCapabilities;
+
+    /// 本适配器所有 RPC 请求共享的限速器，未配置限速时为 None
+    fn rate_limiter(&self) -> Option<Arc<RateLimiter>> {
+        None
+    }
+
+    /// 建立与节点的连接，已连接时直接返回
+    async fn connect(&self) -> Result<(), Error>;
+
+    /// 断开与节点的连接，之后需重新 `connect` 才能使用
+    async fn close(&self) -> Result<(), Error>;
+
+    /// 连接是否可用：已连接且节点能够响应
+    async fn is_healthy(&self) -> bool;
+
     /// 监听链上事件
-    async fn listen_events(&self, config: &ChainConfig) -> Result<Vec<SignedMessage>, Error>;
-    
-    /// 提交消息到链上
-    async fn submit_message(&self, config: &ChainConfig, message: SignedMessage) -> Result<(), Error>;
-    
+    async fn listen_events(&self) -> Result<Vec<SignedMessage>, Error>;
+
+    /// `listen_events` 当前的读取游标，按游标名记录下一次读取的位置；不按游标读取的适配器为空
+    ///
+    /// 中继器在读到的消息全部处理完毕后把游标写入消息存储，重启后经 `restore_cursors` 从该位置继续读取。
+    async fn event_cursors(&self) -> HashMap<String, u64> {
+        HashMap::new()
+    }
+
+    /// 恢复保存的读取游标，须在第一次 `listen_events` 之前调用
+    async fn restore_cursors(&self, _cursors: HashMap<String, u64>) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// 重新扫描历史区间内的源链事件，区间两端均包含在内
+    ///
+    /// 区间的单位由链决定：Sui 为检查点序号，Rooch 与 Movement 为消息事件的序号，Solana 为 slot，Cosmos 为区块高度。
+    /// 不影响 `listen_events` 的读取游标。
+    async fn replay_events(&self, _from: u64, _to: u64) -> Result<Vec<SignedMessage>, Error> {
+        Err(Error::Chain(format!("Event replay is not supported by {} adapter", self.chain_type())))
+    }
+
+    /// 查询链的最新高度，单位与 `replay_events` 的区间一致；适配器无法提供时返回 None
+    async fn latest_height(&self) -> Result<Option<u64>, Error> {
+        Ok(None)
+    }
+
+    /// 获取源链事件的包含证明，适配器无法提供时返回 None
+    ///
+    /// 消息需带有 `listen_events` 填写的 `provenance`，据此定位事件。
+    async fn inclusion_proof(&self, _message: &SignedMessage) -> Result<Option<InclusionProof>, Error> {
+        Ok(None)
+    }
+
+    /// 提交消息到链上，返回交易哈希
+    ///
+    /// 交易广播后在超时内未确认时返回 `Error::Stuck`。
+    async fn submit_message(&self, message: SignedMessage) -> Result<String, Error>;
+
+    /// 替换未确认的交易 `stuck_tx` 重新提交消息，`attempt` 为重新提交的次数（从 1 开始）
+    ///
+    /// 默认直接重新提交；能够替换交易的链应调整 gas 或序列号。
+    async fn resubmit_message(&self, message: SignedMessage, _stuck_tx: &str, _attempt: u32) -> Result<String, Error> {
+        self.submit_message(message).await
+    }
+
+    /// 在一笔交易中按顺序提交多条消息，返回交易哈希；任一消息执行失败时整笔交易回滚
+    ///
+    /// 仅在 `capabilities().batch_submit` 为 true 时可用。
+    async fn submit_messages_batch(&self, _messages: Vec<SignedMessage>) -> Result<String, Error> {
+        Err(Error::Chain(format!("Batch submission is not supported by {} adapter", self.chain_type())))
+    }
+
+    /// 模拟执行消息提交而不上链，执行失败时返回原因
+    async fn dry_run(&self, _message: &SignedMessage) -> Result<(), Error> {
+        Err(Error::Chain(format!("Dry run is not supported by {} adapter", self.chain_type())))
+    }
+
+    /// 读取目标链上新的消息交付记录
+    async fn listen_deliveries(&self) -> Result<Vec<Delivery>, Error>;
+
+    /// 在本链（交付所在的目标链）上对一次交付提交挑战，返回交易哈希
+    ///
+    /// 仅用于乐观模式：合约在挑战期内根据挑战携带的证据裁决交付是否有效。
+    async fn submit_challenge(&self, _challenge: &Challenge) -> Result<String, Error> {
+        Err(Error::Chain(format!("Challenges are not supported by {} adapter", self.chain_type())))
+    }
+
+    /// 向本链的罚没合约提交验证者双签证据，返回交易哈希；本链合约不支持罚没时返回 None
+    async fn submit_equivocation(&self, _evidence: &EquivocationEvidence) -> Result<Option<String>, Error> {
+        Ok(None)
+    }
+
     /// 验证消息状态
-    async fn verify_message(&self, config: &ChainConfig, message: &SignedMessage) -> Result<MessageStatus, Error>;
+    async fn verify_message(&self, message: &SignedMessage) -> Result<MessageStatus, Error>;
+
+    /// 查询本链合约是否已处理该消息，中继器提交前据此跳过其他中继器已提交的消息
+    ///
+    /// 默认按 `verify_message` 的结果判断；合约无法按消息查询时应返回 false，由合约拒绝重复提交。
+    async fn is_message_processed(&self, message: &SignedMessage) -> Result<bool, Error> {
+        Ok(self.verify_message(message).await? == MessageStatus::Processed)
+    }
+
+    /// 查询本链合约已处理的来自 `source_chain` 的最大 nonce，尚未处理过该源链的消息时返回 None
+    ///
+    /// 验证者启动时据此恢复防重放状态，拒绝签名合约已消费的消息。
+    async fn last_processed_nonce(&self, source_chain: &ChainId) -> Result<Option<u64>, Error>;
+
+    /// 将轮换后的验证者集合推送到本链的桥合约，返回交易哈希；本链合约不维护验证者集合时返回 None
+    async fn update_validator_set(&self, _set: &ValidatorSet) -> Result<Option<String>, Error> {
+        Ok(None)
+    }
+
+    /// 在本链的桥合约中登记包装代币的元数据，返回交易哈希；本链合约不需要登记时返回 None
+    async fn register_asset(&self, _metadata: &MetadataSync) -> Result<Option<String>, Error> {
+        Ok(None)
+    }
+
+    /// 查询验证者在链上注册表中的登记信息，未登记时返回 None
+    async fn get_validator_registration(&self, validator: &str) -> Result<Option<ValidatorRegistration>, Error>;
+
+    /// 查询节点报告的 RPC 版本
+    async fn node_version(&self) -> Result<String, Error>;
+
+    /// 检查桥合约是否存在，以及 `event_filters` 中的事件类型是否由合约定义
+    async fn check_contract(&self) -> Result<ContractCheck, Error>;
+
+    /// 读取桥合约各模块的 ABI，用于生成事件与入口函数参数的 Rust 类型
+    async fn move_modules(&self) -> Result<Vec<MoveModule>, Error> {
+        Err(Error::Chain(format!("Reading module ABIs is not supported by {} adapter", self.chain_type())))
+    }
+
+    /// 检查接收者能否接收指定类型的资产，不能接收时返回原因
+    async fn check_recipient(&self, recipient: &[u8], asset_type: &str) -> Result<Option<String>, Error>;
+
+    /// 查询提交交易账户的 gas 余额（最小单位），未配置发送账户时返回 None
+    async fn gas_balance(&self) -> Result<Option<u128>, Error>;
+
+    /// 查询当前 gas 价格（gas 代币最小单位），链上没有价格接口时返回 None
+    async fn gas_price(&self) -> Result<Option<u64>, Error> {
+        Ok(None)
+    }
+
+    /// 查询本链桥合约锁定的原生资产数量（最小单位），合约不提供查询时返回 None
+    async fn locked_balance(&self, _asset_type: &str) -> Result<Option<u128>, Error> {
+        Ok(None)
+    }
+
+    /// 查询本链包装资产的流通量（最小单位），无法查询时返回 None
+    async fn wrapped_supply(&self, _asset_type: &str) -> Result<Option<u128>, Error> {
+        Ok(None)
+    }
+
+    /// 查询本链桥合约为 `reimbursement.payout_address` 累计的 gas 报销（最小单位），未配置报销或合约不支持时返回 None
+    async fn accrued_refund(&self) -> Result<Option<u64>, Error> {
+        Ok(None)
+    }
+
+    /// 认领累计的 gas 报销，返回交易哈希；未配置报销或合约不支持时返回 None
+    async fn claim_refund(&self) -> Result<Option<String>, Error> {
+        Ok(None)
+    }
+
+    /// 查询已提交交易实际消耗的 gas（最小单位），交易尚未执行或适配器无法提供时返回 None
+    async fn transaction_gas(&self, _tx_hash: &str) -> Result<Option<u64>, Error> {
+        Ok(None)
+    }
+}
+
+/// 检查接收者地址格式：必须为 32 字节且不是零地址，不合格时返回原因
+pub fn check_address_format(recipient: &[u8]) -> Option<String> {
+    if recipient.len() != 32 {
+        return Some(format!("Recipient address must be 32 bytes, got {}", recipient.len()));
+    }
+    if recipient.iter().all(|b| *b == 0) {
+        return Some("Recipient is the zero address".to_string());
+    }
+    None
 }
 
 /// 链适配器工厂，用于创建不同链的适配器实例
@@ -29,7 +243,75 @@ pub trait ChainAdapterFactory {
 // 注册所有支持的链适配器
 pub mod sui;
 pub mod rooch;
+pub mod solana;
+pub mod movement;
+pub mod cosmos;
+pub mod json_rpc;
+
+// Rooch JSON-RPC 客户端
+pub mod rooch_rpc;
+
+// Rooch 交易构造与签名
+pub mod rooch_tx;
+
+// Solana JSON-RPC 客户端
+pub mod solana_rpc;
+
+// Solana 交易构造与签名
+pub mod solana_tx;
+
+// Movement（Aptos 兼容）REST 客户端
+pub mod movement_rpc;
+
+// Movement 交易构造与签名
+pub mod movement_tx;
+
+// CometBFT JSON-RPC 客户端与事件订阅
+pub mod cosmos_rpc;
+
+// Cosmos 交易构造与签名
+pub mod cosmos_tx;
+
+// Tendermint 轻客户端
+pub mod cosmos_light;
+
+// Sui 检查点读取进度
+pub mod checkpoint;
+
+// Sui 执行失败状态解析
+pub mod sui_abort;
+
+// 桥合约调用失败的分类
+pub mod contract_error;
+
+// 节点版本兼容性检查
+pub mod version;
+
+// RPC 请求限速
+pub mod rate_limit;
+
+// RPC 请求使用的 HTTP 客户端
+pub mod http;
+
+// 第三方适配器注册表
+pub mod registry;
+
+// 各链的地址解析与规范化
+pub mod address;
+
+// 在 WASM 沙箱中运行的插件适配器
+#[cfg(feature = "wasm-plugins")]
+pub mod wasm;
+
+// 内存中的模拟链，供测试与本地开发使用
+#[cfg(any(test, feature = "testing"))]
+pub mod mock;
 
 // 导出具体的适配器实现
 pub use sui::SuiAdapter;
-pub use rooch::RoochAdapter; 
\ No newline at end of file
+pub use rooch::RoochAdapter;
+pub use solana::SolanaAdapter;
+pub use movement::MovementAdapter;
+pub use cosmos::CosmosAdapter;
+pub use json_rpc::GenericJsonRpcAdapter;
+pub use rate_limit::RateLimiter; 
\ No newline at end of file
@@ -0,0 +1,710 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use crate::{
+    types::{ChainId, Delivery, SignedMessage, MessageStatus, CrossChainMessage, ValidatorRegistration},
+    Error,
+    config::ChainConfig,
+};
+use super::{address::AddressFormat, check_address_format, AdapterCapabilities, ChainAdapter, ContractCheck, RateLimiter};
+use super::movement_rpc::{EventView, MovementRestClient, ViewRequest};
+use super::movement_tx::{MovementSigner, RawTransaction, TransactionPayload, DEFAULT_MAX_GAS_AMOUNT};
+use super::rooch_tx::{FunctionCall, FunctionId};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, OnceCell};
+use tokio::time::sleep;
+use tracing::{debug, warn};
+
+const MAX_RETRIES: u32 = 3;
+const RETRY_DELAY: u64 = 2;
+/// 事件分页大小
+const EVENT_PAGE_SIZE: u64 = 50;
+/// 等待交易上链的轮询间隔（秒），等待时长由链配置的 `stuck_timeout` 决定
+const RECEIPT_POLL_INTERVAL: u64 = 1;
+/// 交易过期时间在确认超时之外留出的余量（秒）
+const EXPIRATION_MARGIN: u64 = 30;
+/// 支付交易费用的币种
+const GAS_COIN_TYPE: &str = "0x1::aptos_coin::AptosCoin";
+/// 桥合约包含的模块，入口函数在 `bridge` 中，事件定义在各模块中
+const BRIDGE_MODULES: &[&str] = &["bridge", "message", "asset"];
+
+/// 桥合约中保存事件句柄的资源与字段
+#[derive(Clone, Copy)]
+enum EventHandle {
+    /// `message::BridgeEvents.message_sent_events`
+    MessageSent,
+    /// `asset::AssetEvents.asset_unlocked_events`
+    AssetUnlocked,
+}
+
+impl EventHandle {
+    fn resource(self, bridge_address: &str) -> String {
+        match self {
+            EventHandle::MessageSent => format!("{}::message::BridgeEvents", bridge_address),
+            EventHandle::AssetUnlocked => format!("{}::asset::AssetEvents", bridge_address),
+        }
+    }
+
+    fn field(self) -> &'static str {
+        match self {
+            EventHandle::MessageSent => "message_sent_events",
+            EventHandle::AssetUnlocked => "asset_unlocked_events",
+        }
+    }
+}
+
+/// 节点以字符串返回 u64，兼容数字形式
+fn json_u64(value: &serde_json::Value) -> Option<u64> {
+    match value {
+        serde_json::Value::String(s) => s.parse().ok(),
+        v => v.as_u64(),
+    }
+}
+
+/// `vector<u8>` 与地址以 0x 前缀的十六进制字符串返回
+fn json_bytes(value: &serde_json::Value, field: &str) -> Result<Vec<u8>, Error> {
+    let hex_str = value[field]
+        .as_str()
+        .ok_or_else(|| Error::Serialization(format!("Missing event field {}", field)))?;
+    let digits = hex_str.trim_start_matches("0x");
+    // 地址可能省略前导零
+    let digits = if digits.len() % 2 == 1 { format!("0{}", digits) } else { digits.to_string() };
+    hex::decode(digits).map_err(|e| Error::Serialization(format!("Invalid event field {}: {}", field, e)))
+}
+
+fn event_u64(value: &serde_json::Value, field: &str) -> Result<u64, Error> {
+    json_u64(&value[field]).ok_or_else(|| Error::Serialization(format!("Missing event field {}", field)))
+}
+
+/// Movement 等 Aptos 兼容 Move L2 的链适配器
+///
+/// 通过 REST 接口按事件句柄读取事件、提交 BCS 编码的交易。交易中的链 ID 为节点报告的原生链 ID，
+/// 与桥合约中标识本链的数字 ID 无关。
+pub struct MovementAdapter {
+    config: ChainConfig,
+    client: MovementRestClient,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    /// REST 接口无需保持连接，`connect` 确认节点可达后才允许调用
+    connected: AtomicBool,
+    /// 合约中的数字链 ID 到配置链 ID 的映射
+    chain_ids: HashMap<u64, ChainId>,
+    /// 各事件句柄下一个要读取的事件序号
+    cursors: Mutex<HashMap<&'static str, u64>>,
+    /// 提交交易的发送账户，未配置时只能读取不能提交
+    signer: Option<MovementSigner>,
+    /// 发送账户的下一个序列号，提交失败后清空并重新从链上读取
+    sequence_number: Mutex<Option<u64>>,
+    /// 节点的原生链 ID
+    native_chain_id: OnceCell<u8>,
+}
+
+impl MovementAdapter {
+    pub fn new(config: ChainConfig) -> Self {
+        let rate_limiter = RateLimiter::for_chain(&config);
+        Self {
+            client: MovementRestClient::new(&config.rpc_url).with_rate_limiter(rate_limiter.clone()),
+            rate_limiter,
+            config,
+            connected: AtomicBool::new(false),
+            chain_ids: HashMap::new(),
+            cursors: Mutex::new(HashMap::new()),
+            signer: None,
+            sequence_number: Mutex::new(None),
+            native_chain_id: OnceCell::new(),
+        }
+    }
+
+    /// 设置提交交易使用的发送账户
+    pub fn with_signer(mut self, signer: MovementSigner) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    /// 设置数字链 ID 映射，用于把事件中的链 ID 转换为配置中的链 ID
+    pub fn with_chain_ids(mut self, chain_ids: HashMap<u64, ChainId>) -> Self {
+        self.chain_ids = chain_ids;
+        self
+    }
+
+    fn ensure_connected(&self) -> Result<(), Error> {
+        if self.connected.load(Ordering::Acquire) {
+            Ok(())
+        } else {
+            Err(Error::Network(format!("Movement adapter for {} is not connected", self.config.id)))
+        }
+    }
+
+    async fn retry_with_backoff<F, Fut, T>(&self, operation: F) -> Result<T, Error>
+    where
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = Result<T, Error>>,
+    {
+        self.ensure_connected()?;
+        let mut retries = 0;
+        loop {
+            match operation().await {
+                Ok(result) => return Ok(result),
+                Err(e) => {
+                    retries += 1;
+                    if retries >= MAX_RETRIES {
+                        return Err(e);
+                    }
+                    sleep(Duration::from_secs(RETRY_DELAY.pow(retries))).await;
+                }
+            }
+        }
+    }
+
+    /// 调用只读函数，返回 JSON 编码的返回值
+    async fn view(&self, function: &str, type_arguments: Vec<String>, arguments: Vec<serde_json::Value>) -> Result<Vec<serde_json::Value>, Error> {
+        let request = ViewRequest { function: function.to_string(), type_arguments, arguments };
+        self.retry_with_backoff(|| self.client.view(&request)).await
+    }
+
+    fn chain_name(&self, chain_id: u64) -> Result<ChainId, Error> {
+        self.chain_ids
+            .get(&chain_id)
+            .cloned()
+            .ok_or_else(|| Error::Config(format!("Unknown numeric chain ID in Movement event: {}", chain_id)))
+    }
+
+    /// 配置链 ID 对应的合约数字链 ID
+    fn numeric_chain_id(&self, chain: &ChainId) -> Result<u64, Error> {
+        self.chain_ids
+            .iter()
+            .find(|(_, id)| *id == chain)
+            .map(|(numeric, _)| *numeric)
+            .ok_or_else(|| Error::Config(format!("No numeric chain ID configured for {}", chain)))
+    }
+
+    /// 从上次的游标继续读取事件句柄中已达到最终性的事件
+    async fn fetch_new_events(&self, handle: EventHandle) -> Result<Vec<EventView>, Error> {
+        let finality_lag = self.config.movement.as_ref().map_or(0, |m| m.finality_lag);
+        let ledger = self.retry_with_backoff(|| self.client.ledger_info()).await?;
+        let final_version = ledger.ledger_version.saturating_sub(finality_lag);
+
+        let resource = handle.resource(&self.config.bridge_address);
+        let mut start = self.cursors.lock().await.get(handle.field()).copied().unwrap_or(0);
+        let mut events = Vec::new();
+        loop {
+            let page = self
+                .retry_with_backoff(|| {
+                    self.client.get_events_by_event_handle(&self.config.bridge_address, &resource, handle.field(), start, EVENT_PAGE_SIZE)
+                })
+                .await?;
+            let full = page.len() as u64 == EVENT_PAGE_SIZE;
+            let before = events.len();
+            events.extend(page.into_iter().take_while(|event| event.version <= final_version));
+            if let Some(last) = events.last() {
+                start = last.sequence_number + 1;
+            }
+            // 读到尚未最终确定的事件或最后一页时停止
+            if !full || events.len() - before < EVENT_PAGE_SIZE as usize {
+                break;
+            }
+        }
+
+        self.cursors.lock().await.insert(handle.field(), start);
+        Ok(events)
+    }
+
+    /// 将 `MessageSent` 事件解码为跨链消息，nonce 使用事件序号
+    fn parse_event(&self, event: &EventView) -> Result<SignedMessage, Error> {
+        // 负载与 Sui 侧 `encode_asset_payload` 一致：接收者地址 || 负载
+        let mut payload = json_bytes(&event.data, "receiver")?;
+        payload.extend(json_bytes(&event.data, "payload")?);
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|e| Error::Chain(format!("Failed to get current time: {}", e)))?
+            .as_secs();
+
+        Ok(SignedMessage {
+            message: CrossChainMessage {
+                nonce: event.sequence_number,
+                source_chain: self.chain_name(event_u64(&event.data, "source_chain")?)?,
+                target_chain: self.chain_name(event_u64(&event.data, "target_chain")?)?,
+                message_type: "transfer".to_string(),
+                payload,
+            },
+            signature: vec![],
+            timestamp,
+            event_name: event.event_type.rsplit("::").next().map(|name| name.to_string()),
+            sender: None,
+            provenance: None,
+            proof: None,
+            trace_id: None,
+        })
+    }
+
+    /// 将 `AssetUnlocked` 事件解码为交付记录，该事件不包含 nonce
+    fn parse_delivery(&self, event: &EventView) -> Result<Delivery, Error> {
+        let receiver = json_bytes(&event.data, "receiver")?;
+        let mut recipient = vec![0u8; 32usize.saturating_sub(receiver.len())];
+        recipient.extend(receiver);
+        Ok(Delivery {
+            event_id: format!("{}:{}", EventHandle::AssetUnlocked.field(), event.sequence_number),
+            source_chain: self.chain_name(event_u64(&event.data, "source_chain")?)?,
+            target_chain: self.config.id.clone(),
+            nonce: None,
+            recipient: Some(recipient),
+            amount: Some(event_u64(&event.data, "amount")?),
+            tx_hash: None,
+            message_id: None,
+        })
+    }
+
+    /// 构造、签名并提交交易，返回交易哈希
+    ///
+    /// 持有序列号锁直到提交完成，保证同一账户的交易按序列号依次提交。
+    async fn send_transaction(&self, signer: &MovementSigner, call: FunctionCall, gas_unit_price: u64) -> Result<String, Error> {
+        let mut sequence_number = self.sequence_number.lock().await;
+        let sequence = match *sequence_number {
+            Some(sequence) => sequence,
+            None => self.client.sequence_number(&signer.address_hex()).await?,
+        };
+        let chain_id = *self.native_chain_id
+            .get_or_try_init(|| async { Ok::<_, Error>(self.client.ledger_info().await?.chain_id) })
+            .await?;
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|e| Error::Chain(format!("Failed to get current time: {}", e)))?
+            .as_secs();
+
+        let tx = signer.sign(RawTransaction {
+            sender: signer.address(),
+            sequence_number: sequence,
+            payload: TransactionPayload::EntryFunction(call),
+            max_gas_amount: DEFAULT_MAX_GAS_AMOUNT,
+            gas_unit_price,
+            expiration_timestamp_secs: now + self.config.stuck_timeout() + EXPIRATION_MARGIN,
+            chain_id,
+        })?;
+        match self.client.submit_transaction(tx.encode()?).await {
+            Ok(hash) => {
+                *sequence_number = Some(sequence + 1);
+                Ok(hash)
+            }
+            Err(e) => {
+                // 序列号可能已被其它交易占用，下次提交时重新读取
+                *sequence_number = None;
+                Err(e)
+            }
+        }
+    }
+
+    /// 以估算单价的 `multiplier` 倍提交入口函数调用并等待执行结果
+    async fn submit_call(&self, call: FunctionCall, multiplier: u64) -> Result<String, Error> {
+        let signer = self.signer.as_ref().ok_or_else(|| {
+            Error::Config(format!("Chain {} requires sender_keystore to submit transactions", self.config.id))
+        })?;
+        let gas_unit_price = self.retry_with_backoff(|| self.client.estimate_gas_price()).await?.saturating_mul(multiplier);
+        let hash = self.retry_with_backoff(|| self.send_transaction(signer, call.clone(), gas_unit_price)).await?;
+        self.wait_for_receipt(&hash).await?;
+        Ok(hash)
+    }
+
+    fn process_message_call(&self, message: &SignedMessage) -> Result<FunctionCall, Error> {
+        let serialize = |bytes: &Vec<u8>| bcs::to_bytes(bytes).map_err(|e| Error::Serialization(e.to_string()));
+        self.with_payout("process_message", vec![serialize(&message.message.canonical_bytes(&self.chain_ids)?)?, serialize(&message.signature)?])
+    }
+
+    /// 调用 `bridge` 模块的提交入口；配置报销时改用 `_with_payout` 版本，末尾附带收款地址
+    fn with_payout(&self, function: &str, mut args: Vec<Vec<u8>>) -> Result<FunctionCall, Error> {
+        let function = match &self.config.reimbursement {
+            Some(reimbursement) => {
+                // `address` 参数的 BCS 编码即 32 字节地址本身
+                args.push(AddressFormat::Move.parse(&reimbursement.payout_address, None)?);
+                format!("{}_with_payout", function)
+            }
+            None => function.to_string(),
+        };
+        Ok(FunctionCall {
+            function_id: FunctionId::parse(&format!("{}::bridge::{}", self.config.bridge_address, function))?,
+            args,
+        })
+    }
+
+    /// 轮询交易执行结果，执行失败时返回错误，超时返回 `Error::Stuck`
+    async fn wait_for_receipt(&self, hash: &str) -> Result<(), Error> {
+        let timeout = self.config.stuck_timeout();
+        for _ in 0..timeout.div_ceil(RECEIPT_POLL_INTERVAL).max(1) {
+            match self.client.get_transaction_by_hash(hash).await {
+                Ok(Some(tx)) if !tx.is_pending() => {
+                    if tx.success == Some(true) {
+                        return Ok(());
+                    }
+                    if let Some((location, code)) = tx.move_abort() {
+                        return Err(Error::MoveAbort { location, code });
+                    }
+                    return Err(Error::Chain(format!(
+                        "Transaction {} failed: {}",
+                        tx.hash,
+                        tx.vm_status.unwrap_or_default()
+                    )));
+                }
+                Ok(_) => {}
+                Err(e) => debug!("Failed to query transaction {}: {}", hash, e),
+            }
+            sleep(Duration::from_secs(RECEIPT_POLL_INTERVAL)).await;
+        }
+        Err(Error::Stuck { tx_hash: hash.to_string(), timeout })
+    }
+}
+
+#[async_trait]
+impl ChainAdapter for MovementAdapter {
+    fn chain_type(&self) -> &str {
+        "movement"
+    }
+
+    fn config(&self) -> &ChainConfig {
+        &self.config
+    }
+
+    fn capabilities(&self) -> AdapterCapabilities {
+        AdapterCapabilities {
+            batch_submit: true,
+            ..AdapterCapabilities::default()
+        }
+    }
+
+    fn rate_limiter(&self) -> Option<Arc<RateLimiter>> {
+        self.rate_limiter.clone()
+    }
+
+    async fn connect(&self) -> Result<(), Error> {
+        let ledger = self.client.ledger_info().await?;
+        // 配置了命名空间时 `chain_id` 为网络的原生链 ID，须与节点一致，防止把配置指向另一个 Move 网络
+        if self.config.chain_namespace.is_some() && self.config.chain_id != Some(ledger.chain_id as u64) {
+            return Err(Error::Config(format!(
+                "Chain {} is configured with chain_id {:?} but node {} reports {}",
+                self.config.id, self.config.chain_id, self.config.rpc_url, ledger.chain_id
+            )));
+        }
+        let _ = self.native_chain_id.set(ledger.chain_id);
+        debug!("Connected to Movement node {} (chain {})", self.config.rpc_url, ledger.chain_id);
+        self.connected.store(true, Ordering::Release);
+        Ok(())
+    }
+
+    async fn close(&self) -> Result<(), Error> {
+        self.connected.store(false, Ordering::Release);
+        // 重新连接后可能面对另一个节点，缓存的序列号不再可信
+        *self.sequence_number.lock().await = None;
+        Ok(())
+    }
+
+    async fn is_healthy(&self) -> bool {
+        self.connected.load(Ordering::Acquire) && self.client.ledger_info().await.is_ok()
+    }
+
+    async fn listen_events(&self) -> Result<Vec<SignedMessage>, Error> {
+        let mut messages = Vec::new();
+        for event in self.fetch_new_events(EventHandle::MessageSent).await? {
+            match self.parse_event(&event) {
+                Ok(message) => messages.push(message),
+                Err(e) => warn!("Skipping undecodable Movement event {}: {}", event.sequence_number, e),
+            }
+        }
+        Ok(messages)
+    }
+
+    async fn replay_events(&self, from: u64, to: u64) -> Result<Vec<SignedMessage>, Error> {
+        let resource = EventHandle::MessageSent.resource(&self.config.bridge_address);
+        let field = EventHandle::MessageSent.field();
+        let mut start = from;
+        let mut messages = Vec::new();
+        while start <= to {
+            let limit = EVENT_PAGE_SIZE.min(to - start + 1);
+            let page = self
+                .retry_with_backoff(|| self.client.get_events_by_event_handle(&self.config.bridge_address, &resource, field, start, limit))
+                .await?;
+            for event in &page {
+                match self.parse_event(event) {
+                    Ok(message) => messages.push(message),
+                    Err(e) => warn!("Skipping undecodable Movement event {}: {}", event.sequence_number, e),
+                }
+            }
+            if (page.len() as u64) < limit {
+                break;
+            }
+            start += limit;
+        }
+        Ok(messages)
+    }
+
+    async fn listen_deliveries(&self) -> Result<Vec<Delivery>, Error> {
+        let mut deliveries = Vec::new();
+        for event in self.fetch_new_events(EventHandle::AssetUnlocked).await? {
+            match self.parse_delivery(&event) {
+                Ok(delivery) => deliveries.push(delivery),
+                Err(e) => warn!("Skipping undecodable Movement event {}: {}", event.sequence_number, e),
+            }
+        }
+        Ok(deliveries)
+    }
+
+    async fn submit_message(&self, message: SignedMessage) -> Result<String, Error> {
+        let hash = self.submit_call(self.process_message_call(&message)?, 1).await?;
+        debug!("Submitted message {} in Movement transaction {}", message.message.nonce, hash);
+        Ok(hash)
+    }
+
+    /// 重新读取序列号后以更高的 gas 单价提交：原交易仍在内存池中时，同一序列号的新交易将其替换
+    async fn resubmit_message(&self, message: SignedMessage, stuck_tx: &str, attempt: u32) -> Result<String, Error> {
+        *self.sequence_number.lock().await = None;
+        debug!("Resubmitting message {} replacing stuck transaction {} (attempt {})", message.message.nonce, stuck_tx, attempt);
+        self.submit_call(self.process_message_call(&message)?, 1 + attempt as u64).await
+    }
+
+    async fn submit_messages_batch(&self, messages: Vec<SignedMessage>) -> Result<String, Error> {
+        // 入口函数参数为 `vector<vector<u8>>`，消息与签名按下标一一对应
+        let mut encoded = Vec::with_capacity(messages.len());
+        let mut signatures = Vec::with_capacity(messages.len());
+        for message in &messages {
+            encoded.push(message.message.canonical_bytes(&self.chain_ids)?);
+            signatures.push(message.signature.clone());
+        }
+        let serialize = |values: &Vec<Vec<u8>>| bcs::to_bytes(values).map_err(|e| Error::Serialization(e.to_string()));
+        let call = self.with_payout("process_messages", vec![serialize(&encoded)?, serialize(&signatures)?])?;
+        let hash = self.submit_call(call, 1).await?;
+        debug!("Submitted {} messages in Movement transaction {}", messages.len(), hash);
+        Ok(hash)
+    }
+
+    async fn verify_message(&self, message: &SignedMessage) -> Result<MessageStatus, Error> {
+        let source_chain = self.numeric_chain_id(&message.message.source_chain)?;
+        let processed = self
+            .view(
+                &format!("{}::message::is_processed", self.config.bridge_address),
+                vec![],
+                vec![source_chain.to_string().into(), message.message.nonce.to_string().into()],
+            )
+            .await?;
+        Ok(if processed.first().and_then(|v| v.as_bool()) == Some(true) {
+            MessageStatus::Processed
+        } else {
+            MessageStatus::Pending
+        })
+    }
+
+    async fn last_processed_nonce(&self, source_chain: &ChainId) -> Result<Option<u64>, Error> {
+        let args = vec![serde_json::Value::from(self.numeric_chain_id(source_chain)?.to_string())];
+        let processed = self
+            .view(&format!("{}::message::has_processed", self.config.bridge_address), vec![], args.clone())
+            .await?;
+        if processed.first().and_then(|v| v.as_bool()) != Some(true) {
+            return Ok(None);
+        }
+        let nonce = self
+            .view(&format!("{}::message::last_processed_nonce", self.config.bridge_address), vec![], args)
+            .await?;
+        let nonce = nonce
+            .first()
+            .and_then(json_u64)
+            .ok_or_else(|| Error::Chain(format!("Invalid processed nonce for source chain {}", source_chain)))?;
+        Ok(Some(nonce))
+    }
+
+    async fn get_validator_registration(&self, validator: &str) -> Result<Option<ValidatorRegistration>, Error> {
+        let args = vec![serde_json::Value::from(validator)];
+        let registered = self
+            .view(&format!("{}::validator::has_validator", self.config.bridge_address), vec![], args.clone())
+            .await?;
+        if registered.first().and_then(|v| v.as_bool()) != Some(true) {
+            return Ok(None);
+        }
+        let weight = self
+            .view(&format!("{}::validator::get_validator_weight", self.config.bridge_address), vec![], args)
+            .await?;
+        let weight = weight
+            .first()
+            .and_then(json_u64)
+            .ok_or_else(|| Error::Chain(format!("Invalid validator weight for {}", validator)))?;
+        Ok(Some(ValidatorRegistration {
+            address: validator.to_string(),
+            weight,
+            active: true,
+        }))
+    }
+
+    async fn node_version(&self) -> Result<String, Error> {
+        self.retry_with_backoff(|| self.client.node_version()).await
+    }
+
+    async fn gas_balance(&self) -> Result<Option<u128>, Error> {
+        let Some(signer) = &self.signer else {
+            return Ok(None);
+        };
+        let balance = self
+            .view("0x1::coin::balance", vec![GAS_COIN_TYPE.to_string()], vec![signer.address_hex().into()])
+            .await?;
+        let balance = balance
+            .first()
+            .and_then(json_u64)
+            .ok_or_else(|| Error::Chain(format!("Invalid balance for {}", signer.address_hex())))?;
+        Ok(Some(balance as u128))
+    }
+
+    async fn locked_balance(&self, asset_type: &str) -> Result<Option<u128>, Error> {
+        let locked = self
+            .view(&format!("{}::asset::locked_balance", self.config.bridge_address), vec![asset_type.to_string()], vec![])
+            .await?;
+        let locked = locked
+            .first()
+            .and_then(json_u64)
+            .ok_or_else(|| Error::Chain(format!("Invalid locked balance for {}", asset_type)))?;
+        Ok(Some(locked as u128))
+    }
+
+    async fn wrapped_supply(&self, asset_type: &str) -> Result<Option<u128>, Error> {
+        let supply = self
+            .view(&format!("{}::asset::wrapped_supply", self.config.bridge_address), vec![asset_type.to_string()], vec![])
+            .await?;
+        let supply = supply
+            .first()
+            .and_then(json_u64)
+            .ok_or_else(|| Error::Chain(format!("Invalid wrapped supply for {}", asset_type)))?;
+        Ok(Some(supply as u128))
+    }
+
+    async fn gas_price(&self) -> Result<Option<u64>, Error> {
+        Ok(Some(self.retry_with_backoff(|| self.client.estimate_gas_price()).await?))
+    }
+
+    async fn accrued_refund(&self) -> Result<Option<u64>, Error> {
+        let Some(reimbursement) = &self.config.reimbursement else {
+            return Ok(None);
+        };
+        let accrued = self
+            .view(
+                &format!("{}::reimbursement::accrued", self.config.bridge_address),
+                vec![],
+                vec![reimbursement.payout_address.clone().into()],
+            )
+            .await?;
+        let accrued = accrued
+            .first()
+            .and_then(json_u64)
+            .ok_or_else(|| Error::Chain(format!("Invalid accrued refund for {}", reimbursement.payout_address)))?;
+        Ok(Some(accrued))
+    }
+
+    async fn claim_refund(&self) -> Result<Option<String>, Error> {
+        let Some(reimbursement) = &self.config.reimbursement else {
+            return Ok(None);
+        };
+        let call = FunctionCall {
+            function_id: FunctionId::parse(&format!("{}::reimbursement::claim", self.config.bridge_address))?,
+            args: vec![AddressFormat::Move.parse(&reimbursement.payout_address, None)?],
+        };
+        let hash = self.submit_call(call, 1).await?;
+        debug!("Claimed gas refund for {} in Movement transaction {}", reimbursement.payout_address, hash);
+        Ok(Some(hash))
+    }
+
+    async fn transaction_gas(&self, tx_hash: &str) -> Result<Option<u64>, Error> {
+        self.ensure_connected()?;
+        let transaction = self.client.get_transaction_by_hash(tx_hash).await?;
+        Ok(transaction
+            .filter(|tx| !tx.is_pending())
+            .and_then(|tx| Some(tx.gas_used?.saturating_mul(tx.gas_unit_price?))))
+    }
+
+    async fn check_contract(&self) -> Result<ContractCheck, Error> {
+        // 入口函数所在的 bridge 模块存在即视为合约已部署，事件类型可定义在任一桥模块中
+        let mut structs = Vec::new();
+        for module in BRIDGE_MODULES {
+            match self.retry_with_backoff(|| self.client.get_module_structs(&self.config.bridge_address, module)).await? {
+                Some(names) => structs.extend(names),
+                None if *module == "bridge" => return Ok(ContractCheck { exists: false, missing_events: Vec::new() }),
+                None => {}
+            }
+        }
+        Ok(ContractCheck {
+            exists: true,
+            missing_events: self.config.event_filters
+                .iter()
+                .filter(|filter| !structs.contains(&filter.name))
+                .map(|filter| filter.name.clone())
+                .collect(),
+        })
+    }
+
+    async fn check_recipient(&self, recipient: &[u8], asset_type: &str) -> Result<Option<String>, Error> {
+        if let Some(reason) = check_address_format(recipient) {
+            return Ok(Some(reason));
+        }
+        let address = format!("0x{}", hex::encode(recipient));
+        let registered = self
+            .view("0x1::coin::is_account_registered", vec![asset_type.to_string()], vec![address.clone().into()])
+            .await?;
+        if registered.first().and_then(|v| v.as_bool()) == Some(true) {
+            Ok(None)
+        } else {
+            Ok(Some(format!("Recipient {} has not registered {}", address, asset_type)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_namespaced_events() {
+        // 两个 Move 网络沿用相同的原生链 ID，通过命名空间区分
+        let config = ChainConfig {
+            adapter_type: "movement".to_string(),
+            rpc_url: "http://localhost:8080".to_string(),
+            chain_id: Some(250),
+            chain_namespace: Some(2),
+            ..crate::testing::mock_chain_config("movement_testnet")
+        };
+        let other = ChainConfig {
+            chain_id: Some(250),
+            chain_namespace: Some(3),
+            ..crate::testing::mock_chain_config("m2_testnet")
+        };
+        let movement_id = config.contract_chain_id().unwrap();
+        assert_eq!(movement_id, 2 << 32 | 250);
+        assert_ne!(other.contract_chain_id(), Some(movement_id));
+
+        let adapter = MovementAdapter::new(config).with_chain_ids(HashMap::from([
+            (1, ChainId::from("sui_testnet")),
+            (movement_id, ChainId::from("movement_testnet")),
+            (other.contract_chain_id().unwrap(), ChainId::from("m2_testnet")),
+        ]));
+        let event: EventView = serde_json::from_value(serde_json::json!({
+            "version": "1200",
+            "guid": { "creation_number": "4", "account_address": "0x42" },
+            "sequence_number": "6",
+            "type": "0x42::message::MessageSent",
+            "data": {
+                "source_chain": movement_id.to_string(),
+                "target_chain": "1",
+                "receiver": "0xaaaaaaaa",
+                "payload": "0x0500000000000000"
+            }
+        }))
+        .unwrap();
+        let message = adapter.parse_event(&event).unwrap();
+        assert_eq!(message.message.nonce, 6);
+        assert_eq!(message.message.source_chain, "movement_testnet");
+        assert_eq!(message.message.target_chain, "sui_testnet");
+        assert_eq!(message.message.payload[..4], [0xaa; 4]);
+        assert_eq!(message.event_name.as_deref(), Some("MessageSent"));
+
+        let delivery: EventView = serde_json::from_value(serde_json::json!({
+            "version": "1300",
+            "sequence_number": "2",
+            "type": "0x42::asset::AssetUnlocked",
+            "data": { "receiver": "0x7", "amount": "100", "source_chain": "1" }
+        }))
+        .unwrap();
+        let delivery = adapter.parse_delivery(&delivery).unwrap();
+        assert_eq!(delivery.recipient.as_ref().unwrap()[31], 7);
+        assert_eq!(delivery.amount, Some(100));
+    }
+}
@@ -0,0 +1,271 @@
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
+use std::sync::Arc;
+use crate::Error;
+use super::rate_limit::{reject_throttled, RateLimiter};
+
+/// 提交 BCS 编码交易使用的内容类型
+const SIGNED_TRANSACTION_CONTENT_TYPE: &str = "application/x.aptos.signed_transaction+bcs";
+
+/// Movement 节点的 Aptos 兼容 REST 客户端
+///
+/// 只覆盖中继器用到的接口，资源不存在（404）时返回 None。
+pub struct MovementRestClient {
+    http: reqwest::Client,
+    /// 带 `/v1` 前缀的接口根地址
+    base_url: String,
+    rate_limiter: Option<Arc<RateLimiter>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiError {
+    message: String,
+}
+
+/// 节点账本信息
+#[derive(Debug, Clone, Deserialize)]
+pub struct LedgerInfo {
+    pub chain_id: u8,
+    #[serde(deserialize_with = "de_u64")]
+    pub ledger_version: u64,
+}
+
+/// 事件句柄中的一条事件，数据为节点解码后的 JSON
+#[derive(Debug, Clone, Deserialize)]
+pub struct EventView {
+    #[serde(deserialize_with = "de_u64")]
+    pub version: u64,
+    #[serde(deserialize_with = "de_u64")]
+    pub sequence_number: u64,
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub data: serde_json::Value,
+}
+
+/// 按哈希查询到的交易，尚未执行时 `type` 为 `pending_transaction`
+#[derive(Debug, Clone, Deserialize)]
+pub struct TransactionView {
+    #[serde(rename = "type")]
+    pub transaction_type: String,
+    pub hash: String,
+    #[serde(default)]
+    pub success: Option<bool>,
+    #[serde(default)]
+    pub vm_status: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_u64")]
+    pub gas_used: Option<u64>,
+    #[serde(default, deserialize_with = "de_opt_u64")]
+    pub gas_unit_price: Option<u64>,
+}
+
+impl TransactionView {
+    pub fn is_pending(&self) -> bool {
+        self.transaction_type == "pending_transaction"
+    }
+
+    /// 合约中止时返回中止位置与中止码，例如 `Move abort in 0x42::bridge: EPAUSED(0x10001): ...`
+    pub fn move_abort(&self) -> Option<(String, u64)> {
+        let status = self.vm_status.as_deref()?.strip_prefix("Move abort in ")?;
+        let (location, rest) = status.split_once(": ")?;
+        let code = rest.split_once("(0x")?.1.split_once(')')?.0;
+        Some((location.to_string(), u64::from_str_radix(code, 16).ok()?))
+    }
+}
+
+/// 只读函数调用请求
+#[derive(Debug, Clone, Serialize)]
+pub struct ViewRequest {
+    pub function: String,
+    pub type_arguments: Vec<String>,
+    pub arguments: Vec<serde_json::Value>,
+}
+
+impl MovementRestClient {
+    pub fn new(url: &str) -> Self {
+        let url = url.trim_end_matches('/');
+        Self {
+            http: reqwest::Client::new(),
+            base_url: if url.ends_with("/v1") { url.to_string() } else { format!("{}/v1", url) },
+            rate_limiter: None,
+        }
+    }
+
+    /// 每次请求前先从限速器取得令牌
+    pub fn with_rate_limiter(mut self, rate_limiter: Option<Arc<RateLimiter>>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// 发送请求并解析响应，404 时返回 None，其它错误状态转换为 `Error::Rpc`
+    async fn send<R: DeserializeOwned>(&self, path: &str, request: reqwest::RequestBuilder) -> Result<Option<R>, Error> {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+        let response = reject_throttled(request.send().await.map_err(|e| Error::Network(e.to_string()))?, path)?;
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            let message = match response.json::<ApiError>().await {
+                Ok(error) => error.message,
+                Err(_) => status.to_string(),
+            };
+            return Err(Error::Rpc { method: path.to_string(), code: status.as_u16() as i64, message });
+        }
+        response
+            .json()
+            .await
+            .map(Some)
+            .map_err(|e| Error::Chain(format!("Invalid {} response: {}", path, e)))
+    }
+
+    async fn get<R: DeserializeOwned>(&self, path: &str) -> Result<Option<R>, Error> {
+        self.send(path, self.http.get(format!("{}{}", self.base_url, path))).await
+    }
+
+    fn required<R>(path: &str, value: Option<R>) -> Result<R, Error> {
+        value.ok_or_else(|| Error::Chain(format!("{} not found", path)))
+    }
+
+    pub async fn ledger_info(&self) -> Result<LedgerInfo, Error> {
+        Self::required("/", self.get("/").await?)
+    }
+
+    /// 节点构建信息中的版本号
+    pub async fn node_version(&self) -> Result<String, Error> {
+        let info: serde_json::Value = Self::required("/info", self.get("/info").await?)?;
+        info["build_pkg_version"]
+            .as_str()
+            .map(|v| v.to_string())
+            .ok_or_else(|| Error::Chain("Node did not report a build version".to_string()))
+    }
+
+    /// 从 `start` 开始读取账户下某个事件句柄的事件
+    pub async fn get_events_by_event_handle(
+        &self,
+        address: &str,
+        event_handle: &str,
+        field_name: &str,
+        start: u64,
+        limit: u64,
+    ) -> Result<Vec<EventView>, Error> {
+        let path = format!("/accounts/{}/events/{}/{}?start={}&limit={}", address, event_handle, field_name, start, limit);
+        // 事件句柄尚未创建时没有事件
+        Ok(self.get(&path).await?.unwrap_or_default())
+    }
+
+    /// 执行只读函数，返回 JSON 编码的返回值
+    pub async fn view(&self, request: &ViewRequest) -> Result<Vec<serde_json::Value>, Error> {
+        let result = self.send("/view", self.http.post(format!("{}/view", self.base_url)).json(request)).await?;
+        Self::required("/view", result)
+    }
+
+    /// 账户的下一个序列号
+    pub async fn sequence_number(&self, address: &str) -> Result<u64, Error> {
+        #[derive(Deserialize)]
+        struct AccountView {
+            #[serde(deserialize_with = "de_u64")]
+            sequence_number: u64,
+        }
+        let path = format!("/accounts/{}", address);
+        let account: AccountView = Self::required(&path, self.get(&path).await?)?;
+        Ok(account.sequence_number)
+    }
+
+    /// 节点估算的 gas 单价
+    pub async fn estimate_gas_price(&self) -> Result<u64, Error> {
+        #[derive(Deserialize)]
+        struct GasEstimation {
+            gas_estimate: u64,
+        }
+        let estimation: GasEstimation = Self::required("/estimate_gas_price", self.get("/estimate_gas_price").await?)?;
+        Ok(estimation.gas_estimate)
+    }
+
+    /// 提交 BCS 编码的已签名交易，返回交易哈希
+    pub async fn submit_transaction(&self, signed_bcs: Vec<u8>) -> Result<String, Error> {
+        #[derive(Deserialize)]
+        struct PendingTransaction {
+            hash: String,
+        }
+        let request = self.http
+            .post(format!("{}/transactions", self.base_url))
+            .header(reqwest::header::CONTENT_TYPE, SIGNED_TRANSACTION_CONTENT_TYPE)
+            .body(signed_bcs);
+        let pending: PendingTransaction = Self::required("/transactions", self.send("/transactions", request).await?)?;
+        Ok(pending.hash)
+    }
+
+    /// 按哈希查询交易，节点尚未见到时返回 None
+    pub async fn get_transaction_by_hash(&self, hash: &str) -> Result<Option<TransactionView>, Error> {
+        self.get(&format!("/transactions/by_hash/{}", hash)).await
+    }
+
+    /// 查询模块 ABI 中定义的结构体名，模块不存在时返回 None
+    pub async fn get_module_structs(&self, address: &str, module: &str) -> Result<Option<Vec<String>>, Error> {
+        #[derive(Deserialize)]
+        struct StructView {
+            name: String,
+        }
+        #[derive(Deserialize)]
+        struct AbiView {
+            #[serde(default)]
+            structs: Vec<StructView>,
+        }
+        #[derive(Deserialize)]
+        struct ModuleView {
+            abi: AbiView,
+        }
+        let module: Option<ModuleView> = self.get(&format!("/accounts/{}/module/{}", address, module)).await?;
+        Ok(module.map(|m| m.abi.structs.into_iter().map(|s| s.name).collect()))
+    }
+}
+
+/// REST 接口把 u64 编码为字符串，兼容数字形式
+fn de_u64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StrOrNum {
+        Str(String),
+        Num(u64),
+    }
+    match StrOrNum::deserialize(deserializer)? {
+        StrOrNum::Str(s) => s.parse().map_err(serde::de::Error::custom),
+        StrOrNum::Num(n) => Ok(n),
+    }
+}
+
+fn de_opt_u64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u64>, D::Error> {
+    #[derive(Deserialize)]
+    struct Wrapper(#[serde(deserialize_with = "de_u64")] u64);
+    Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|w| w.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_transaction_status() {
+        let tx: TransactionView = serde_json::from_value(serde_json::json!({
+            "type": "user_transaction",
+            "hash": "0xabc",
+            "success": false,
+            "vm_status": "Move abort in 0x42::bridge: EPAUSED(0x10001): bridge is paused",
+            "gas_used": "12",
+            "gas_unit_price": "100"
+        }))
+        .unwrap();
+        assert!(!tx.is_pending());
+        assert_eq!(tx.move_abort(), Some(("0x42::bridge".to_string(), 0x10001)));
+        assert_eq!(tx.gas_used, Some(12));
+
+        let pending: TransactionView = serde_json::from_value(serde_json::json!({
+            "type": "pending_transaction",
+            "hash": "0xabc"
+        }))
+        .unwrap();
+        assert!(pending.is_pending());
+        assert_eq!(MovementRestClient::new("http://localhost:8080/v1/").base_url, "http://localhost:8080/v1");
+    }
+}
@@ -0,0 +1,184 @@
+use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+use serde::{Serialize, Serializer};
+use sha3::{Digest, Sha3_256};
+use std::path::Path;
+use crate::Error;
+use super::rooch_tx::FunctionCall;
+
+/// 默认的单笔交易最大 gas 单位
+pub const DEFAULT_MAX_GAS_AMOUNT: u64 = 200_000;
+
+/// 单签 ed25519 账户的认证密钥方案标识
+const ED25519_SCHEME: u8 = 0x00;
+/// 签名消息的域分隔前缀
+const RAW_TRANSACTION_SALT: &[u8] = b"APTOS::RawTransaction";
+/// `TransactionPayload::EntryFunction` 的变体序号
+const ENTRY_FUNCTION_PAYLOAD_INDEX: u32 = 2;
+/// `TransactionAuthenticator::Ed25519` 的变体序号
+const ED25519_AUTHENTICATOR_INDEX: u32 = 0;
+
+/// 交易负载，中继器只调用入口函数
+///
+/// 入口函数的 BCS 布局与 Rooch 的 `FunctionCall` 相同：模块地址、模块名、函数名、类型参数与参数。
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransactionPayload {
+    EntryFunction(FunctionCall),
+}
+
+impl Serialize for TransactionPayload {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            TransactionPayload::EntryFunction(call) => serializer.serialize_newtype_variant(
+                "TransactionPayload",
+                ENTRY_FUNCTION_PAYLOAD_INDEX,
+                "EntryFunction",
+                call,
+            ),
+        }
+    }
+}
+
+/// Aptos 兼容链的待签名交易
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RawTransaction {
+    pub sender: [u8; 32],
+    pub sequence_number: u64,
+    pub payload: TransactionPayload,
+    pub max_gas_amount: u64,
+    pub gas_unit_price: u64,
+    pub expiration_timestamp_secs: u64,
+    /// 链自身的数字 ID，与桥合约使用的链 ID 无关
+    pub chain_id: u8,
+}
+
+impl RawTransaction {
+    /// 签名消息：域分隔前缀的 SHA3-256 || 交易 BCS 编码
+    pub fn signing_message(&self) -> Result<Vec<u8>, Error> {
+        let mut message = Sha3_256::digest(RAW_TRANSACTION_SALT).to_vec();
+        message.extend(bcs::to_bytes(self).map_err(|e| Error::Serialization(e.to_string()))?);
+        Ok(message)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ed25519Authenticator {
+    pub public_key: Vec<u8>,
+    pub signature: Vec<u8>,
+}
+
+impl Serialize for Ed25519Authenticator {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_variant(
+            "TransactionAuthenticator",
+            ED25519_AUTHENTICATOR_INDEX,
+            "Ed25519",
+            &(&self.public_key, &self.signature),
+        )
+    }
+}
+
+/// 已签名交易
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SignedTransaction {
+    pub raw: RawTransaction,
+    pub authenticator: Ed25519Authenticator,
+}
+
+impl SignedTransaction {
+    /// 提交到 `/v1/transactions` 的 BCS 请求体
+    pub fn encode(&self) -> Result<Vec<u8>, Error> {
+        bcs::to_bytes(self).map_err(|e| Error::Serialization(e.to_string()))
+    }
+}
+
+/// 中继器在 Movement 上的发送账户
+pub struct MovementSigner {
+    keypair: Keypair,
+    address: [u8; 32],
+}
+
+impl MovementSigner {
+    pub fn from_secret_key(bytes: &[u8]) -> Result<Self, Error> {
+        let secret = SecretKey::from_bytes(bytes)
+            .map_err(|e| Error::Key(format!("Invalid Movement sender key: {}", e)))?;
+        let public = PublicKey::from(&secret);
+        // 地址为 (公钥 || 方案标识) 的 SHA3-256
+        let mut hasher = Sha3_256::new();
+        hasher.update(public.as_bytes());
+        hasher.update([ED25519_SCHEME]);
+        Ok(Self {
+            keypair: Keypair { secret, public },
+            address: hasher.finalize().into(),
+        })
+    }
+
+    /// 从私钥文件加载，文件内容为十六进制编码的 ed25519 私钥，可带 `ed25519-priv-` 前缀
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| Error::Key(format!("Failed to read Movement sender key: {}", e)))?;
+        let hex_key = content.trim().trim_start_matches("ed25519-priv-").trim_start_matches("0x");
+        let bytes = hex::decode(hex_key)
+            .map_err(|e| Error::Key(format!("Invalid Movement sender key encoding: {}", e)))?;
+        Self::from_secret_key(&bytes)
+    }
+
+    pub fn address(&self) -> [u8; 32] {
+        self.address
+    }
+
+    pub fn address_hex(&self) -> String {
+        format!("0x{}", hex::encode(self.address))
+    }
+
+    pub fn sign(&self, raw: RawTransaction) -> Result<SignedTransaction, Error> {
+        let signature = self.keypair.sign(&raw.signing_message()?);
+        Ok(SignedTransaction {
+            raw,
+            authenticator: Ed25519Authenticator {
+                public_key: self.keypair.public.as_bytes().to_vec(),
+                signature: signature.to_bytes().to_vec(),
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::rooch_tx::FunctionId;
+    use ed25519_dalek::{Signature, Verifier};
+
+    #[test]
+    fn test_sign_entry_function() {
+        let signer = MovementSigner::from_secret_key(&[5u8; 32]).unwrap();
+        let raw = RawTransaction {
+            sender: signer.address(),
+            sequence_number: 4,
+            payload: TransactionPayload::EntryFunction(FunctionCall {
+                function_id: FunctionId::parse("0x42::bridge::process_message").unwrap(),
+                args: vec![bcs::to_bytes(&vec![1u8, 2, 3]).unwrap()],
+            }),
+            max_gas_amount: DEFAULT_MAX_GAS_AMOUNT,
+            gas_unit_price: 100,
+            expiration_timestamp_secs: 1_700_000_000,
+            chain_id: 250,
+        };
+        let tx = signer.sign(raw.clone()).unwrap();
+
+        // 负载变体序号紧跟在发送者与序列号之后，链 ID 为最后一个字节
+        let bytes = bcs::to_bytes(&raw).unwrap();
+        assert_eq!(bytes[32 + 8], ENTRY_FUNCTION_PAYLOAD_INDEX as u8);
+        assert_eq!(*bytes.last().unwrap(), 250);
+
+        let encoded = tx.encode().unwrap();
+        assert_eq!(encoded[..bytes.len()], bytes[..]);
+        // 认证器：变体序号 || 公钥长度 || 公钥 || 签名长度 || 签名
+        assert_eq!(encoded[bytes.len()..bytes.len() + 2], [0, 32]);
+        assert_eq!(encoded.len(), bytes.len() + 1 + 33 + 65);
+
+        let public = PublicKey::from_bytes(&tx.authenticator.public_key).unwrap();
+        let signature = Signature::from_bytes(&tx.authenticator.signature).unwrap();
+        assert!(public.verify(&raw.signing_message().unwrap(), &signature).is_ok());
+        assert!(signer.address_hex().starts_with("0x"));
+    }
+}
@@ -0,0 +1,122 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+use crate::config::{ChainConfig, RateLimitConfig};
+use crate::Error;
+
+struct Bucket {
+    /// 可用令牌数，为负表示已被排队的请求预支
+    tokens: f64,
+    updated: Instant,
+}
+
+/// RPC 请求的令牌桶限速器
+///
+/// 令牌按 `max_rps` 匀速补充，最多累积 `burst` 个。令牌不足时请求预支令牌并等待到轮到它为止，
+/// 并发请求因此按到达顺序依次放行。
+pub struct RateLimiter {
+    rate: f64,
+    burst: f64,
+    bucket: Mutex<Bucket>,
+    /// 尚未计入指标的被限速请求数
+    throttled: AtomicU64,
+}
+
+impl RateLimiter {
+    pub fn new(config: &RateLimitConfig) -> Self {
+        let burst = config.burst.unwrap_or(config.max_rps).max(1) as f64;
+        Self {
+            rate: config.max_rps.max(1) as f64,
+            burst,
+            bucket: Mutex::new(Bucket { tokens: burst, updated: Instant::now() }),
+            throttled: AtomicU64::new(0),
+        }
+    }
+
+    /// 按链配置创建，未配置限速时返回 None
+    pub fn for_chain(config: &ChainConfig) -> Option<Arc<Self>> {
+        config.rate_limit.as_ref().map(|rate_limit| Arc::new(Self::new(rate_limit)))
+    }
+
+    /// 等待一个令牌
+    pub async fn acquire(&self) {
+        let wait = self.reserve(Instant::now());
+        if !wait.is_zero() {
+            self.throttled.fetch_add(1, Ordering::Relaxed);
+            sleep(wait).await;
+        }
+    }
+
+    /// 预支一个令牌，返回需要等待的时间
+    fn reserve(&self, now: Instant) -> Duration {
+        let mut bucket = self.bucket.lock().unwrap();
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.rate).min(self.burst);
+        bucket.updated = now;
+        bucket.tokens -= 1.0;
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / self.rate)
+        }
+    }
+
+    /// 取出上次调用以来被限速的请求数
+    pub fn take_throttled(&self) -> u64 {
+        self.throttled.swap(0, Ordering::Relaxed)
+    }
+}
+
+/// 节点以 429 拒绝请求时返回 `Error::RateLimited`，其它响应原样返回
+pub fn reject_throttled(response: reqwest::Response, method: &str) -> Result<reqwest::Response, Error> {
+    if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Err(Error::RateLimited(format!("{} returned {}", method, response.status())));
+    }
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{two_chain_config, TwoChainHarness, SOURCE_CHAIN};
+
+    #[tokio::test]
+    async fn test_rate_limiter_allows_bursts_then_throttles() {
+        let limiter = RateLimiter::new(&RateLimitConfig { max_rps: 2, burst: Some(3) });
+        let start = Instant::now();
+        for _ in 0..3 {
+            assert_eq!(limiter.reserve(start), Duration::ZERO);
+        }
+        // 令牌耗尽后按每秒 2 个的速率排队
+        assert_eq!(limiter.reserve(start), Duration::from_millis(500));
+        assert_eq!(limiter.reserve(start), Duration::from_secs(1));
+        // 空闲后最多累积 burst 个令牌
+        let later = start + Duration::from_secs(60);
+        for _ in 0..3 {
+            assert_eq!(limiter.reserve(later), Duration::ZERO);
+        }
+        assert!(limiter.reserve(later) > Duration::ZERO);
+
+        let limiter = RateLimiter::new(&RateLimitConfig { max_rps: 10, burst: Some(1) });
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert!(Instant::now() - start >= Duration::from_millis(100));
+        assert_eq!(limiter.take_throttled(), 1);
+        assert_eq!(limiter.take_throttled(), 0);
+
+        // 中继器每轮把被限速的调用数计入指标
+        let mut config = two_chain_config();
+        config.chains[0].rate_limit = Some(RateLimitConfig { max_rps: 1_000, burst: Some(1) });
+        let mut harness = TwoChainHarness::with_config(config).await.unwrap();
+        harness.run(2).await;
+        let metrics = harness.relayer.metrics().export().unwrap();
+        let series = format!("bridge_rpc_throttled_total{{chain=\"{}\"}} ", SOURCE_CHAIN);
+        let throttled: u64 = metrics.lines()
+            .find_map(|line| line.strip_prefix(&series))
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(throttled > 0);
+    }
+}
@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, RwLock};
+use crate::{relayer::ChainAdapterFactory, Error};
+use super::SUPPORTED_ADAPTER_TYPES;
+
+/// 第三方注册的适配器工厂，按适配器类型索引
+static PLUGINS: LazyLock<RwLock<HashMap<String, Arc<dyn ChainAdapterFactory>>>> = LazyLock::new(Default::default);
+
+/// 注册第三方链适配器，`adapter_type` 对应链配置中的 `adapter_type`
+///
+/// 须在加载配置、创建中继器之前调用，之后配置校验与 `DefaultChainAdapterFactory` 均能识别该类型。
+/// 内置类型不能被覆盖；重复注册同一类型时后注册的工厂生效。
+pub fn register_adapter(adapter_type: &str, factory: Arc<dyn ChainAdapterFactory>) -> Result<(), Error> {
+    if adapter_type.is_empty() {
+        return Err(Error::Config("Adapter type must not be empty".to_string()));
+    }
+    if SUPPORTED_ADAPTER_TYPES.contains(&adapter_type) {
+        return Err(Error::Config(format!("Adapter type {} is built in and cannot be replaced", adapter_type)));
+    }
+    PLUGINS.write().unwrap().insert(adapter_type.to_string(), factory);
+    Ok(())
+}
+
+/// 查找已注册的第三方适配器工厂
+pub fn plugin(adapter_type: &str) -> Option<Arc<dyn ChainAdapterFactory>> {
+    PLUGINS.read().unwrap().get(adapter_type).cloned()
+}
+
+/// 内置与已注册的全部适配器类型，内置类型在前
+pub fn adapter_types() -> Vec<String> {
+    let mut plugins: Vec<_> = PLUGINS.read().unwrap().keys().cloned().collect();
+    plugins.sort();
+    SUPPORTED_ADAPTER_TYPES.iter().map(|t| t.to_string()).chain(plugins).collect()
+}
+
+/// 适配器类型是否内置或已注册
+pub fn is_supported(adapter_type: &str) -> bool {
+    SUPPORTED_ADAPTER_TYPES.contains(&adapter_type) || PLUGINS.read().unwrap().contains_key(adapter_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain_adapter::mock::{MockChain, MockChainAdapterFactory};
+    use crate::relayer::DefaultChainAdapterFactory;
+    use crate::testing::{mock_chain_config, two_chain_config};
+
+    #[tokio::test]
+    async fn test_registered_adapters_are_created_by_default_factory() {
+        let mut config = two_chain_config();
+        config.chains[0] = crate::config::ChainConfig {
+            adapter_type: "registry_test".to_string(),
+            ..mock_chain_config("plugin_chain")
+        };
+        assert!(!is_supported("registry_test"));
+        assert!(DefaultChainAdapterFactory::new(&config).create_adapter(&config.chains[0]).await.is_err());
+
+        let chain = MockChain::new("plugin_chain");
+        register_adapter("registry_test", Arc::new(MockChainAdapterFactory::new([chain.clone()]))).unwrap();
+        assert!(register_adapter("sui", Arc::new(MockChainAdapterFactory::default())).is_err());
+        assert!(is_supported("registry_test"));
+        assert!(adapter_types().starts_with(&["sui".to_string(), "rooch".to_string()]));
+        assert!(adapter_types().contains(&"registry_test".to_string()));
+
+        let adapter = DefaultChainAdapterFactory::new(&config).create_adapter(&config.chains[0]).await.unwrap();
+        assert_eq!(adapter.config().id, "plugin_chain");
+        assert!(adapter.is_healthy().await);
+        assert!(!config.validation_report().issues.iter().any(|issue| issue.path == "chains[0].adapter_type"));
+    }
+}
@@ -1,39 +1,128 @@
 use async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::HashMap;
 use crate::{
-    types::{SignedMessage, MessageStatus, CrossChainMessage},
+    codegen::{self, MoveModule},
+    proof::InclusionProof,
+    relayer::{equivocation::EquivocationEvidence, rotation::ValidatorSet},
+    types::{ChainId, Delivery, MetadataSync, SignedMessage, MessageStatus, CrossChainMessage, ValidatorRegistration},
+    watchtower::Challenge,
     Error,
-    config::ChainConfig,
+    config::{ChainConfig, RoochConfig},
 };
-use super::ChainAdapter;
+use super::{check_address_format, AdapterCapabilities, ChainAdapter, ContractCheck, RateLimiter};
+use super::contract_error::BridgeContractError;
+use super::http;
+use super::rooch_rpc::{EventView, FunctionCallView, RoochRpcClient};
+use super::rooch_tx::{FunctionCall, FunctionId, MoveAction, RoochSigner, RoochTransactionData, DEFAULT_MAX_GAS_AMOUNT};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::{Mutex, OnceCell};
 use tokio::time::sleep;
+use tracing::{debug, warn};
 
 const MAX_RETRIES: u32 = 3;
 const RETRY_DELAY: u64 = 2;
+/// 等待交易上链的轮询间隔（秒），等待时长由链配置的 `stuck_timeout` 决定
+const RECEIPT_POLL_INTERVAL: u64 = 1;
+/// 支付交易费用的币种
+const GAS_COIN_TYPE: &str = "0x3::gas_coin::RGas";
+/// 桥合约包含的模块，入口函数在 `bridge` 中，事件定义在各模块中
+const BRIDGE_MODULES: &[&str] = &["bridge", "message", "asset"];
+/// 事件过滤器只写结构体名时，事件所在的模块
+const DEFAULT_EVENT_MODULE: &str = "message";
+
+/// `rooch_bridge::message::MessageSentEvent` 的 BCS 布局
+#[derive(Debug, Deserialize)]
+struct MessageSentEvent {
+    source_chain: u64,
+    target_chain: u64,
+    receiver: Vec<u8>,
+    payload: Vec<u8>,
+}
+
+/// `rooch_bridge::asset::AssetUnlockedEvent` 的 BCS 布局
+#[derive(Debug, Deserialize)]
+struct AssetUnlockedEvent {
+    receiver: [u8; 32],
+    /// u256 金额的小端字节
+    amount: [u8; 32],
+    source_chain: u64,
+}
 
 pub struct RoochAdapter {
-    rpc_url: String,
+    config: ChainConfig,
+    client: RoochRpcClient,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    /// JSON-RPC 基于 HTTP 无需保持连接，`connect` 确认节点可达后才允许调用
+    connected: AtomicBool,
+    /// 合约中的数字链 ID 到配置链 ID 的映射
+    chain_ids: HashMap<u64, ChainId>,
+    /// 各事件类型已读取到的分页游标
+    cursors: Mutex<HashMap<String, u64>>,
+    /// 事件分页与每轮读取上限
+    paging: RoochConfig,
+    /// 提交交易的发送账户，未配置时只能读取不能提交
+    signer: Option<RoochSigner>,
+    /// 发送账户的下一个序列号，提交失败后清空并重新从链上读取
+    sequence_number: Mutex<Option<u64>>,
+    chain_id: OnceCell<u64>,
 }
 
 impl RoochAdapter {
-    pub async fn new(rpc_url: &str) -> Result<Self, Error> {
+    /// 按链配置创建适配器，HTTP 客户端参数无效时返回错误
+    pub fn new(config: ChainConfig) -> Result<Self, Error> {
+        let rate_limiter = RateLimiter::for_chain(&config);
+        let paging = config.rooch.clone().unwrap_or_default();
+        let http = http::client(&config)?;
         Ok(Self {
-            rpc_url: rpc_url.to_string(),
+            client: RoochRpcClient::new(&config.rpc_url).with_http(http).with_rate_limiter(rate_limiter.clone()),
+            rate_limiter,
+            config,
+            connected: AtomicBool::new(false),
+            chain_ids: HashMap::new(),
+            cursors: Mutex::new(HashMap::new()),
+            paging,
+            signer: None,
+            sequence_number: Mutex::new(None),
+            chain_id: OnceCell::new(),
         })
     }
 
+    fn ensure_connected(&self) -> Result<(), Error> {
+        if self.connected.load(Ordering::Acquire) {
+            Ok(())
+        } else {
+            Err(Error::Network(format!("Rooch adapter for {} is not connected", self.config.id)))
+        }
+    }
+
+    /// 设置提交交易使用的发送账户
+    pub fn with_signer(mut self, signer: RoochSigner) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    /// 设置数字链 ID 映射，用于把事件中的链 ID 转换为配置中的链 ID
+    pub fn with_chain_ids(mut self, chain_ids: HashMap<u64, ChainId>) -> Self {
+        self.chain_ids = chain_ids;
+        self
+    }
+
     async fn retry_with_backoff<F, Fut, T>(&self, operation: F) -> Result<T, Error>
     where
         F: Fn() -> Fut,
         Fut: std::future::Future<Output = Result<T, Error>>,
     {
+        self.ensure_connected()?;
         let mut retries = 0;
         loop {
             match operation().await {
                 Ok(result) => return Ok(result),
                 Err(e) => {
                     retries += 1;
-                    if retries >= MAX_RETRIES {
+                    if retries >= MAX_RETRIES || e.is_terminal() {
                         return Err(e);
                     }
                     sleep(Duration::from_secs(RETRY_DELAY.pow(retries))).await;
@@ -41,6 +130,228 @@ impl RoochAdapter {
             }
         }
     }
+
+    /// 调用合约的只读函数，返回解码后的返回值列表
+    async fn execute_view_function(&self, function_id: &str, args: Vec<String>) -> Result<Vec<serde_json::Value>, Error> {
+        self.retry_with_backoff(|| {
+            self.client.execute_view_function(FunctionCallView {
+                function_id: function_id.to_string(),
+                ty_args: vec![],
+                args: args.clone(),
+            })
+        })
+        .await
+    }
+
+    /// 从链上读取账户的序列号
+    async fn fetch_sequence_number(&self, address: &str) -> Result<u64, Error> {
+        let values = self
+            .execute_view_function("0x2::account::sequence_number", vec![format!("address:{}", address)])
+            .await?;
+        match values.first() {
+            Some(serde_json::Value::String(s)) => s.parse::<u64>().ok(),
+            Some(v) => v.as_u64(),
+            None => None,
+        }
+        .ok_or_else(|| Error::Chain(format!("Invalid sequence number for {}", address)))
+    }
+
+    /// 构造、签名并广播交易，返回交易哈希
+    ///
+    /// 持有序列号锁直到广播完成，保证同一账户的交易按序列号依次提交。
+    async fn send_transaction(&self, signer: &RoochSigner, call: FunctionCall, max_gas_amount: u64) -> Result<String, Error> {
+        let mut sequence_number = self.sequence_number.lock().await;
+        let sequence = match *sequence_number {
+            Some(sequence) => sequence,
+            None => self.fetch_sequence_number(&signer.address_hex()).await?,
+        };
+        let chain_id = *self.chain_id.get_or_try_init(|| self.client.chain_id()).await?;
+
+        let tx = signer.sign(RoochTransactionData {
+            sender: signer.address(),
+            sequence_number: sequence,
+            chain_id,
+            max_gas_amount,
+            action: MoveAction::Function(call),
+        })?;
+        match self.client.send_raw_transaction(&tx.encode()?).await {
+            Ok(tx_hash) => {
+                *sequence_number = Some(sequence + 1);
+                Ok(tx_hash)
+            }
+            Err(e) => {
+                // 序列号可能已被其它交易占用，下次提交时重新读取
+                *sequence_number = None;
+                match &e {
+                    Error::Rpc { message, .. } => match BridgeContractError::from_vm_status(message) {
+                        Some(error) => Err(error.into_error(message)),
+                        None => Err(e),
+                    },
+                    _ => Err(e),
+                }
+            }
+        }
+    }
+
+    /// 提交单条消息并等待执行结果
+    async fn send_message(&self, message: &SignedMessage, max_gas_amount: u64) -> Result<String, Error> {
+        let signer = self.signer.as_ref().ok_or_else(|| {
+            Error::Config(format!("Chain {} requires sender_keystore to submit transactions", self.config.id))
+        })?;
+        let serialize = |bytes: &Vec<u8>| bcs::to_bytes(bytes).map_err(|e| Error::Serialization(e.to_string()));
+        let call = FunctionCall {
+            function_id: FunctionId::parse(&format!("{}::bridge::process_message", self.config.bridge_address))?,
+            // 第三个参数为源链消息 ID，供浏览器与索引器把目标链交易关联回源链消息
+            args: vec![
+                serialize(&message.message.canonical_bytes(&self.chain_ids)?)?,
+                serialize(&message.signature)?,
+                serialize(&message.trace_id(&self.chain_ids)?)?,
+            ],
+        };
+
+        let tx_hash = self.retry_with_backoff(|| self.send_transaction(signer, call.clone(), max_gas_amount)).await?;
+        debug!("Submitted message {} in Rooch transaction {}", message.message.nonce, tx_hash);
+        self.wait_for_receipt(&tx_hash).await?;
+        Ok(tx_hash)
+    }
+
+    /// 轮询交易执行结果，执行失败时返回错误，超时返回 `Error::Stuck`
+    async fn wait_for_receipt(&self, tx_hash: &str) -> Result<(), Error> {
+        let timeout = self.config.stuck_timeout();
+        for _ in 0..timeout.div_ceil(RECEIPT_POLL_INTERVAL).max(1) {
+            match self.client.get_transaction_by_hash(tx_hash).await {
+                Ok(Some(tx)) => {
+                    if let Some(info) = tx.execution_info {
+                        if info.is_executed() {
+                            return Ok(());
+                        }
+                        let detail = format!("Transaction {} failed: {}", info.tx_hash, info.status);
+                        return Err(match info.contract_error(&self.config.bridge_address) {
+                            Some(error) => error.into_error(&detail),
+                            None => Error::Chain(detail),
+                        });
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => debug!("Failed to query transaction {}: {}", tx_hash, e),
+            }
+            sleep(Duration::from_secs(RECEIPT_POLL_INTERVAL)).await;
+        }
+        Err(Error::Stuck { tx_hash: tx_hash.to_string(), timeout })
+    }
+
+    fn chain_name(&self, chain_id: u64) -> Result<ChainId, Error> {
+        self.chain_ids
+            .get(&chain_id)
+            .cloned()
+            .ok_or_else(|| Error::Config(format!("Unknown numeric chain ID in Rooch event: {}", chain_id)))
+    }
+
+    /// 配置链 ID 对应的合约数字链 ID
+    fn numeric_chain_id(&self, chain: &ChainId) -> Result<u64, Error> {
+        self.chain_ids
+            .iter()
+            .find(|(_, id)| *id == chain)
+            .map(|(numeric, _)| *numeric)
+            .ok_or_else(|| Error::Config(format!("No numeric chain ID configured for {}", chain)))
+    }
+
+    /// 从上次的游标继续翻页读取指定类型的事件，直到没有下一页或达到每轮的页数上限
+    ///
+    /// 游标只在内存中前进；消息事件的游标由中继器在消息处理完毕后经 `event_cursors` 保存。
+    async fn fetch_new_events(&self, event_type: &str) -> Result<Vec<EventView>, Error> {
+        let start = self.cursors.lock().await.get(event_type).copied();
+        let mut cursor = start;
+        let mut events = Vec::new();
+        for _ in 0..self.paging.pages_per_poll {
+            let page = self
+                .retry_with_backoff(|| self.client.get_events_by_event_handle(event_type, cursor, self.paging.page_size))
+                .await?;
+            let done = !page.has_next_page || page.data.is_empty();
+            if page.next_cursor.is_some() {
+                cursor = page.next_cursor;
+            }
+            events.extend(page.data);
+            if done {
+                break;
+            }
+        }
+
+        if let Some(cursor) = cursor.filter(|cursor| start != Some(*cursor)) {
+            self.cursors.lock().await.insert(event_type.to_string(), cursor);
+        }
+        Ok(events)
+    }
+
+    /// 将 `AssetUnlockedEvent` 解码为交付记录，该事件不包含 nonce
+    fn parse_delivery(&self, event: &EventView) -> Result<Delivery, Error> {
+        let data: AssetUnlockedEvent = event.decode()?;
+        // u256 以 32 字节小端编码，超出 u64 的金额视为异常数据
+        if data.amount[8..].iter().any(|b| *b != 0) {
+            return Err(Error::Serialization("Unlocked amount exceeds u64".to_string()));
+        }
+        let amount = u64::from_le_bytes(data.amount[..8].try_into().expect("slice of 8 bytes"));
+        Ok(Delivery {
+            event_id: format!("{}:{}", event.event_id.event_handle_id, event.event_id.event_seq),
+            source_chain: self.chain_name(data.source_chain)?,
+            target_chain: self.config.id.clone(),
+            nonce: None,
+            recipient: Some(data.receiver.to_vec()),
+            amount: Some(amount),
+            tx_hash: None,
+            message_id: None,
+        })
+    }
+
+    /// 按 `event_filters` 构造的事件类型及对应的过滤器名
+    fn event_types(&self) -> Vec<(String, &str)> {
+        self.config.event_filters
+            .iter()
+            .map(|filter| (filter.move_event_type(&self.config.bridge_address, DEFAULT_EVENT_MODULE), filter.name.as_str()))
+            .collect()
+    }
+
+    /// 消息来源事件的类型：按消息的事件名称匹配过滤器，未携带事件名称时使用唯一的过滤器
+    fn event_type_of(&self, message: &SignedMessage) -> Result<String, Error> {
+        let event_types = self.event_types();
+        match &message.event_name {
+            Some(event_name) => event_types.into_iter().find(|(_, name)| name == event_name),
+            None if event_types.len() == 1 => event_types.into_iter().next(),
+            None => None,
+        }
+        .map(|(event_type, _)| event_type)
+        .ok_or_else(|| Error::Config(format!("No event filter on chain {} matches event {:?}", self.config.id, message.event_name)))
+    }
+
+    /// 将 `MessageSentEvent` 布局的事件解码为跨链消息，nonce 使用事件序号，事件名称为匹配的过滤器名
+    fn parse_event(&self, event: &EventView, filter_name: &str) -> Result<SignedMessage, Error> {
+        let data: MessageSentEvent = event.decode()?;
+        // 负载与 Sui 侧 `encode_asset_payload` 一致：接收者地址 || 负载
+        let mut payload = data.receiver;
+        payload.extend(data.payload);
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|e| Error::Chain(format!("Failed to get current time: {}", e)))?
+            .as_secs();
+
+        Ok(SignedMessage {
+            message: CrossChainMessage {
+                nonce: event.event_id.event_seq,
+                source_chain: self.chain_name(data.source_chain)?,
+                target_chain: self.chain_name(data.target_chain)?,
+                message_type: "transfer".to_string(),
+                payload,
+            },
+            signature: vec![],
+            timestamp,
+            event_name: Some(filter_name.to_string()),
+            // MessageSentEvent 不包含发送者
+            sender: None,
+            provenance: None,
+            proof: None,
+            trace_id: None,
+        })
+    }
 }
 
 #[async_trait]
@@ -49,105 +360,440 @@ impl ChainAdapter for RoochAdapter {
         "rooch"
     }
 
-    async fn listen_events(&self, config: &ChainConfig) -> Result<Vec<SignedMessage>, Error> {
-        self.retry_with_backoff(|| async {
-            let client = reqwest::Client::new();
-            let response = client
-                .post(&self.rpc_url)
-                .json(&serde_json::json!({
-                    "jsonrpc": "2.0",
-                    "method": "rooch_getEvents",
-                    "params": [
-                        {
-                            "address": config.bridge_address,
-                            "start": 0,
-                            "limit": 50
-                        }
-                    ],
-                    "id": 1
-                }))
-                .send()
-                .await
-                .map_err(|e| Error::Chain(e.to_string()))?;
-
-            let events: Vec<SignedMessage> = response
-                .json()
-                .await
-                .map_err(|e| Error::Chain(e.to_string()))?;
-
-            Ok(events)
-        })
-        .await
+    fn config(&self) -> &ChainConfig {
+        &self.config
     }
 
-    async fn submit_message(&self, config: &ChainConfig, message: SignedMessage) -> Result<(), Error> {
-        self.retry_with_backoff(|| async {
-            let client = reqwest::Client::new();
-            let response = client
-                .post(&self.rpc_url)
-                .json(&serde_json::json!({
-                    "jsonrpc": "2.0",
-                    "method": "rooch_submitTransaction",
-                    "params": [
-                        {
-                            "function": format!("{}::bridge::process_message", config.bridge_address),
-                            "type_args": [],
-                            "args": [
-                                serde_json::to_value(&message).map_err(|e| Error::Serialization(e.to_string()))?
-                            ]
-                        }
-                    ],
-                    "id": 1
-                }))
-                .send()
-                .await
-                .map_err(|e| Error::Chain(e.to_string()))?;
-
-            let result: serde_json::Value = response
-                .json()
-                .await
-                .map_err(|e| Error::Chain(e.to_string()))?;
-
-            if result.get("error").is_some() {
-                return Err(Error::Chain(format!("Transaction failed: {:?}", result["error"])));
+    fn capabilities(&self) -> AdapterCapabilities {
+        // 事件只能按游标轮询，批量提交使用合约的 `process_messages` 入口函数，节点未提供模拟执行接口
+        AdapterCapabilities {
+            batch_submit: true,
+            ..AdapterCapabilities::default()
+        }
+    }
+
+    fn rate_limiter(&self) -> Option<Arc<RateLimiter>> {
+        self.rate_limiter.clone()
+    }
+
+    async fn connect(&self) -> Result<(), Error> {
+        let version = self.client.rpc_version().await?;
+        debug!("Connected to Rooch node {} (version {})", self.config.rpc_url, version);
+        self.connected.store(true, Ordering::Release);
+        Ok(())
+    }
+
+    async fn close(&self) -> Result<(), Error> {
+        self.connected.store(false, Ordering::Release);
+        // 重新连接后可能面对另一个节点，缓存的序列号不再可信
+        *self.sequence_number.lock().await = None;
+        Ok(())
+    }
+
+    async fn is_healthy(&self) -> bool {
+        self.connected.load(Ordering::Acquire) && self.client.rpc_version().await.is_ok()
+    }
+
+    async fn listen_events(&self) -> Result<Vec<SignedMessage>, Error> {
+        let mut messages = Vec::new();
+        // 每种事件类型有独立的事件句柄与游标
+        for (event_type, filter_name) in self.event_types() {
+            for event in self.fetch_new_events(&event_type).await? {
+                match self.parse_event(&event, filter_name) {
+                    Ok(message) => messages.push(message),
+                    Err(e) => warn!("Skipping undecodable Rooch event {} {}: {}", event_type, event.event_id.event_seq, e),
+                }
+            }
+        }
+        Ok(messages)
+    }
+
+    async fn event_cursors(&self) -> HashMap<String, u64> {
+        // 交付事件的游标不随消息处理保存
+        let cursors = self.cursors.lock().await;
+        self.event_types()
+            .into_iter()
+            .filter_map(|(event_type, _)| cursors.get(&event_type).map(|cursor| (event_type, *cursor)))
+            .collect()
+    }
+
+    async fn restore_cursors(&self, cursors: HashMap<String, u64>) -> Result<(), Error> {
+        self.cursors.lock().await.extend(cursors);
+        Ok(())
+    }
+
+    async fn replay_events(&self, from: u64, to: u64) -> Result<Vec<SignedMessage>, Error> {
+        let mut messages = Vec::new();
+        for (event_type, filter_name) in self.event_types() {
+            // 游标为上一页最后一条事件的序号，从 `from` 开始读取
+            let mut cursor = from.checked_sub(1);
+            'pages: loop {
+                let page = self
+                    .retry_with_backoff(|| self.client.get_events_by_event_handle(&event_type, cursor, self.paging.page_size))
+                    .await?;
+                for event in &page.data {
+                    if event.event_id.event_seq > to {
+                        break 'pages;
+                    }
+                    match self.parse_event(event, filter_name) {
+                        Ok(message) => messages.push(message),
+                        Err(e) => warn!("Skipping undecodable Rooch event {} {}: {}", event_type, event.event_id.event_seq, e),
+                    }
+                }
+                if !page.has_next_page || page.data.is_empty() || page.next_cursor.is_none() {
+                    break;
+                }
+                cursor = page.next_cursor;
             }
+        }
+        Ok(messages)
+    }
 
-            Ok(())
+    async fn inclusion_proof(&self, message: &SignedMessage) -> Result<Option<InclusionProof>, Error> {
+        // 消息的 nonce 即来源事件的事件序号
+        let event_type = self.event_type_of(message)?;
+        let nonce = message.message.nonce;
+        let proof = self.retry_with_backoff(|| self.client.get_event_proof(&event_type, nonce)).await?;
+        proof.map(|view| view.into_proof().map(InclusionProof::RoochState)).transpose()
+    }
+
+    async fn listen_deliveries(&self) -> Result<Vec<Delivery>, Error> {
+        let event_type = format!("{}::asset::AssetUnlockedEvent", self.config.bridge_address);
+        let mut deliveries = Vec::new();
+        for event in self.fetch_new_events(&event_type).await? {
+            match self.parse_delivery(&event) {
+                Ok(delivery) => deliveries.push(delivery),
+                Err(e) => warn!("Skipping undecodable Rooch event {}: {}", event.event_id.event_seq, e),
+            }
+        }
+        Ok(deliveries)
+    }
+
+    async fn submit_message(&self, message: SignedMessage) -> Result<String, Error> {
+        self.send_message(&message, DEFAULT_MAX_GAS_AMOUNT).await
+    }
+
+    /// 丢弃缓存的序列号后重新读取：原交易被丢弃或序列号出现空洞时，替换交易使用链上实际期望的序列号；
+    /// gas 上限随重新提交次数增加
+    async fn resubmit_message(&self, message: SignedMessage, stuck_tx: &str, attempt: u32) -> Result<String, Error> {
+        *self.sequence_number.lock().await = None;
+        let max_gas_amount = DEFAULT_MAX_GAS_AMOUNT.saturating_mul(1 + attempt as u64);
+        debug!("Resubmitting message {} replacing stuck transaction {} (max gas {})", message.message.nonce, stuck_tx, max_gas_amount);
+        self.send_message(&message, max_gas_amount).await
+    }
+
+    async fn submit_messages_batch(&self, messages: Vec<SignedMessage>) -> Result<String, Error> {
+        let signer = self.signer.as_ref().ok_or_else(|| {
+            Error::Config(format!("Chain {} requires sender_keystore to submit transactions", self.config.id))
+        })?;
+        // 入口函数参数为 `vector<vector<u8>>`，消息、签名与源链消息 ID 按下标一一对应
+        let mut encoded = Vec::with_capacity(messages.len());
+        let mut signatures = Vec::with_capacity(messages.len());
+        let mut trace_ids = Vec::with_capacity(messages.len());
+        for message in &messages {
+            encoded.push(message.message.canonical_bytes(&self.chain_ids)?);
+            signatures.push(message.signature.clone());
+            trace_ids.push(message.trace_id(&self.chain_ids)?);
+        }
+        let serialize = |values: &Vec<Vec<u8>>| bcs::to_bytes(values).map_err(|e| Error::Serialization(e.to_string()));
+        let call = FunctionCall {
+            function_id: FunctionId::parse(&format!("{}::bridge::process_messages", self.config.bridge_address))?,
+            args: vec![serialize(&encoded)?, serialize(&signatures)?, serialize(&trace_ids)?],
+        };
+
+        let tx_hash = self.retry_with_backoff(|| self.send_transaction(signer, call.clone(), DEFAULT_MAX_GAS_AMOUNT)).await?;
+        debug!("Submitted {} messages in Rooch transaction {}", messages.len(), tx_hash);
+        self.wait_for_receipt(&tx_hash).await?;
+        Ok(tx_hash)
+    }
+
+    async fn submit_challenge(&self, challenge: &Challenge) -> Result<String, Error> {
+        let signer = self.signer.as_ref().ok_or_else(|| {
+            Error::Config(format!("Chain {} requires sender_keystore to submit transactions", self.config.id))
+        })?;
+        let divergence = &challenge.divergence;
+        let source_chain = self.numeric_chain_id(&divergence.source_chain)?;
+        let delivery = divergence.delivery.clone().unwrap_or_default().into_bytes();
+        let serialization = |e: bcs::Error| Error::Serialization(e.to_string());
+        // 入口函数参数：源链、交付的 nonce（可缺失）、理由编码、交付事件 ID、应交付消息的规范编码
+        let call = FunctionCall {
+            function_id: FunctionId::parse(&format!("{}::bridge::challenge_delivery", self.config.bridge_address))?,
+            args: vec![
+                bcs::to_bytes(&source_chain).map_err(serialization)?,
+                bcs::to_bytes(&divergence.nonce).map_err(serialization)?,
+                bcs::to_bytes(&challenge.reason).map_err(serialization)?,
+                bcs::to_bytes(&delivery).map_err(serialization)?,
+                bcs::to_bytes(&challenge.evidence(&self.chain_ids)?).map_err(serialization)?,
+            ],
+        };
+
+        let tx_hash = self.retry_with_backoff(|| self.send_transaction(signer, call.clone(), DEFAULT_MAX_GAS_AMOUNT)).await?;
+        debug!("Submitted challenge against delivery {:?} in Rooch transaction {}", divergence.delivery, tx_hash);
+        self.wait_for_receipt(&tx_hash).await?;
+        Ok(tx_hash)
+    }
+
+    async fn submit_equivocation(&self, evidence: &EquivocationEvidence) -> Result<Option<String>, Error> {
+        let signer = self.signer.as_ref().ok_or_else(|| {
+            Error::Config(format!("Chain {} requires sender_keystore to submit transactions", self.config.id))
+        })?;
+        let serialization = |e: bcs::Error| Error::Serialization(e.to_string());
+        // 入口函数参数：两条证明各自的消息规范编码、签名与签名公钥，合约据公钥定位验证者
+        let mut args = Vec::with_capacity(6);
+        for attestation in [&evidence.first, &evidence.second] {
+            let public_key = hex::decode(attestation.public_key.trim_start_matches("0x"))
+                .map_err(|e| Error::Key(format!("Invalid public key for {}: {}", attestation.validator, e)))?;
+            args.push(bcs::to_bytes(&attestation.message.canonical_bytes(&self.chain_ids)?).map_err(serialization)?);
+            args.push(bcs::to_bytes(&attestation.signature).map_err(serialization)?);
+            args.push(bcs::to_bytes(&public_key).map_err(serialization)?);
+        }
+        let call = FunctionCall {
+            function_id: FunctionId::parse(&format!("{}::validator::slash_equivocation", self.config.bridge_address))?,
+            args,
+        };
+
+        let tx_hash = self.retry_with_backoff(|| self.send_transaction(signer, call.clone(), DEFAULT_MAX_GAS_AMOUNT)).await?;
+        debug!("Submitted equivocation evidence against {} in Rooch transaction {}", evidence.validator, tx_hash);
+        self.wait_for_receipt(&tx_hash).await?;
+        Ok(Some(tx_hash))
+    }
+
+    async fn verify_message(&self, message: &SignedMessage) -> Result<MessageStatus, Error> {
+        self.retry_with_backoff(|| async {
+            let status: Option<String> = self.client
+                .call(
+                    "rooch_getMessageStatus",
+                    serde_json::json!([{
+                        "bridge_address": self.config.bridge_address,
+                        "message_hash": hex::encode(&message.signature)
+                    }]),
+                )
+                .await?;
+            Ok(match status.as_deref() {
+                Some("processed") => MessageStatus::Processed,
+                Some("failed") => MessageStatus::Failed,
+                _ => MessageStatus::Pending,
+            })
         })
         .await
     }
 
-    async fn verify_message(&self, config: &ChainConfig, message: &SignedMessage) -> Result<MessageStatus, Error> {
-        self.retry_with_backoff(|| async {
-            let client = reqwest::Client::new();
-            let response = client
-                .post(&self.rpc_url)
-                .json(&serde_json::json!({
-                    "jsonrpc": "2.0",
-                    "method": "rooch_getMessageStatus",
-                    "params": [
-                        {
-                            "bridge_address": config.bridge_address,
-                            "message_hash": hex::encode(message.signature.clone())
-                        }
-                    ],
-                    "id": 1
-                }))
-                .send()
-                .await
-                .map_err(|e| Error::Chain(e.to_string()))?;
-
-            let result: serde_json::Value = response
-                .json()
-                .await
-                .map_err(|e| Error::Chain(e.to_string()))?;
-
-            match result.get("result").and_then(|v| v.as_str()) {
-                Some("processed") => Ok(MessageStatus::Processed),
-                Some("failed") => Ok(MessageStatus::Failed),
-                _ => Ok(MessageStatus::Pending),
+    async fn last_processed_nonce(&self, source_chain: &ChainId) -> Result<Option<u64>, Error> {
+        let args = vec![format!("u64:{}", self.numeric_chain_id(source_chain)?)];
+        let processed = self
+            .execute_view_function(&format!("{}::message::has_processed", self.config.bridge_address), args.clone())
+            .await?;
+        if processed.first().and_then(|v| v.as_bool()) != Some(true) {
+            return Ok(None);
+        }
+
+        let nonce = self
+            .execute_view_function(&format!("{}::message::last_processed_nonce", self.config.bridge_address), args)
+            .await?;
+        let nonce = match nonce.first() {
+            Some(serde_json::Value::String(s)) => s.parse::<u64>().ok(),
+            Some(v) => v.as_u64(),
+            None => None,
+        }
+        .ok_or_else(|| Error::Chain(format!("Invalid processed nonce for source chain {}", source_chain)))?;
+        Ok(Some(nonce))
+    }
+
+    async fn update_validator_set(&self, set: &ValidatorSet) -> Result<Option<String>, Error> {
+        let signer = self.signer.as_ref().ok_or_else(|| {
+            Error::Config(format!("Chain {} requires sender_keystore to submit transactions", self.config.id))
+        })?;
+        let serialization = |e: bcs::Error| Error::Serialization(e.to_string());
+        // 入口函数参数：公钥与权重按下标对应，最后为签名阈值
+        let call = FunctionCall {
+            function_id: FunctionId::parse(&format!("{}::validator::update_validator_set", self.config.bridge_address))?,
+            args: vec![
+                bcs::to_bytes(&set.public_keys()?).map_err(serialization)?,
+                bcs::to_bytes(&set.weights()).map_err(serialization)?,
+                bcs::to_bytes(&set.threshold()).map_err(serialization)?,
+            ],
+        };
+
+        let tx_hash = self.retry_with_backoff(|| self.send_transaction(signer, call.clone(), DEFAULT_MAX_GAS_AMOUNT)).await?;
+        self.wait_for_receipt(&tx_hash).await?;
+        Ok(Some(tx_hash))
+    }
+
+    async fn register_asset(&self, metadata: &MetadataSync) -> Result<Option<String>, Error> {
+        let signer = self.signer.as_ref().ok_or_else(|| {
+            Error::Config(format!("Chain {} requires sender_keystore to submit transactions", self.config.id))
+        })?;
+        let serialization = |e: bcs::Error| Error::Serialization(e.to_string());
+        // 入口函数参数：名称、符号、精度、原生链资产类型、本链包装代币类型
+        let call = FunctionCall {
+            function_id: FunctionId::parse(&format!("{}::asset::register_wrapped_asset", self.config.bridge_address))?,
+            args: vec![
+                bcs::to_bytes(metadata.name.as_bytes()).map_err(serialization)?,
+                bcs::to_bytes(metadata.symbol.as_bytes()).map_err(serialization)?,
+                bcs::to_bytes(&metadata.decimals).map_err(serialization)?,
+                bcs::to_bytes(metadata.native_type.as_bytes()).map_err(serialization)?,
+                bcs::to_bytes(metadata.target_type.as_bytes()).map_err(serialization)?,
+            ],
+        };
+
+        let tx_hash = self.retry_with_backoff(|| self.send_transaction(signer, call.clone(), DEFAULT_MAX_GAS_AMOUNT)).await?;
+        debug!("Registered asset {} in Rooch transaction {}", metadata.name, tx_hash);
+        self.wait_for_receipt(&tx_hash).await?;
+        Ok(Some(tx_hash))
+    }
+
+    async fn get_validator_registration(&self, validator: &str) -> Result<Option<ValidatorRegistration>, Error> {
+        let args = vec![format!("address:{}", validator)];
+        let registered = self
+            .execute_view_function(&format!("{}::validator::has_validator", self.config.bridge_address), args.clone())
+            .await?;
+        if registered.first().and_then(|v| v.as_bool()) != Some(true) {
+            return Ok(None);
+        }
+
+        let weight = self
+            .execute_view_function(&format!("{}::validator::get_validator_weight", self.config.bridge_address), args)
+            .await?;
+        // u64 返回值可能以字符串形式编码
+        let weight = match weight.first() {
+            Some(serde_json::Value::String(s)) => s.parse::<u64>().ok(),
+            Some(v) => v.as_u64(),
+            None => None,
+        }
+        .ok_or_else(|| Error::Chain(format!("Invalid validator weight for {}", validator)))?;
+
+        Ok(Some(ValidatorRegistration {
+            address: validator.to_string(),
+            weight,
+            active: true,
+        }))
+    }
+
+    async fn node_version(&self) -> Result<String, Error> {
+        self.retry_with_backoff(|| self.client.rpc_version()).await
+    }
+
+    async fn gas_balance(&self) -> Result<Option<u128>, Error> {
+        let Some(signer) = &self.signer else {
+            return Ok(None);
+        };
+        self.ensure_connected()?;
+        let balance = self.client.get_balance(&signer.address_hex(), GAS_COIN_TYPE).await?;
+        Ok(Some(balance))
+    }
+
+    async fn transaction_gas(&self, tx_hash: &str) -> Result<Option<u64>, Error> {
+        self.ensure_connected()?;
+        let transaction = self.client.get_transaction_by_hash(tx_hash).await?;
+        Ok(transaction.and_then(|t| t.execution_info).and_then(|info| info.gas_used()))
+    }
+
+    async fn check_contract(&self) -> Result<ContractCheck, Error> {
+        // 入口函数所在的 bridge 模块存在即视为合约已部署，事件类型可定义在任一桥模块中
+        let mut structs = Vec::new();
+        for module in BRIDGE_MODULES {
+            let module_id = format!("{}::{}", self.config.bridge_address, module);
+            match self.retry_with_backoff(|| self.client.get_module_structs(&module_id)).await? {
+                Some(names) => structs.extend(names),
+                None if *module == "bridge" => return Ok(ContractCheck { exists: false, missing_events: Vec::new() }),
+                None => {}
             }
+        }
+        Ok(ContractCheck {
+            exists: true,
+            missing_events: self.config.event_filters
+                .iter()
+                .filter(|filter| !structs.iter().any(|name| name == filter.struct_name()))
+                .map(|filter| filter.name.clone())
+                .collect(),
         })
-        .await
     }
-}
\ No newline at end of file
+
+    async fn move_modules(&self) -> Result<Vec<MoveModule>, Error> {
+        let mut modules = Vec::new();
+        for module in BRIDGE_MODULES {
+            let module_id = format!("{}::{}", self.config.bridge_address, module);
+            if let Some(abi) = self.retry_with_backoff(|| self.client.get_module_abi(&module_id)).await? {
+                modules.push(codegen::parse_rooch_module(abi)?);
+            }
+        }
+        if modules.is_empty() {
+            return Err(Error::Chain(format!("No bridge modules found at {}", self.config.bridge_address)));
+        }
+        Ok(modules)
+    }
+
+    async fn check_recipient(&self, recipient: &[u8], asset_type: &str) -> Result<Option<String>, Error> {
+        if let Some(reason) = check_address_format(recipient) {
+            return Ok(Some(reason));
+        }
+        let address = format!("0x{}", hex::encode(recipient));
+        let accepted = self
+            .retry_with_backoff(|| {
+                self.client.execute_view_function(FunctionCallView {
+                    function_id: "0x3::account_coin_store::is_accept_coin".to_string(),
+                    ty_args: vec![asset_type.to_string()],
+                    args: vec![format!("address:{}", address)],
+                })
+            })
+            .await?;
+        if accepted.first().and_then(|v| v.as_bool()) == Some(true) {
+            Ok(None)
+        } else {
+            Ok(Some(format!("Recipient {} does not accept {}", address, asset_type)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::rooch_rpc::EventIdView;
+    use crate::config::EventFilter;
+
+    #[tokio::test]
+    async fn test_parse_message_sent_event() {
+        let config = ChainConfig {
+            adapter_type: "rooch".to_string(),
+            rpc_url: "http://localhost:6767".to_string(),
+            ..crate::testing::mock_chain_config("rooch_testnet")
+        };
+        let adapter = RoochAdapter::new(config).unwrap()
+            .with_chain_ids(HashMap::from([(1, ChainId::from("sui_testnet")), (2, ChainId::from("rooch_testnet"))]));
+        let data = bcs::to_bytes(&(2u64, 1u64, vec![0xaau8; 4], 5u64.to_le_bytes().to_vec())).unwrap();
+        let event = EventView {
+            event_id: EventIdView { event_handle_id: "0x01".to_string(), event_seq: 9 },
+            event_type: "0x42::message::MessageSentEvent".to_string(),
+            event_data: format!("0x{}", hex::encode(data)),
+            event_index: None,
+        };
+
+        let message = adapter.parse_event(&event, "MessageSentEvent").unwrap();
+        assert_eq!(message.message.nonce, 9);
+        assert_eq!(message.message.source_chain, "rooch_testnet");
+        assert_eq!(message.message.target_chain, "sui_testnet");
+        assert_eq!(message.message.payload[..4], [0xaa; 4]);
+        assert_eq!(message.event_name.as_deref(), Some("MessageSentEvent"));
+
+        // 每个事件过滤器对应一种事件类型，消息按过滤器名找回来源事件类型
+        let config = ChainConfig {
+            bridge_address: "0x42".to_string(),
+            event_filters: vec![
+                EventFilter { name: "MessageSentEvent".to_string(), handler: "transfers".to_string() },
+                EventFilter { name: "asset::AssetLockedEvent".to_string(), handler: "locks".to_string() },
+            ],
+            ..adapter.config.clone()
+        };
+        let adapter = RoochAdapter::new(config).unwrap();
+        assert_eq!(
+            adapter.event_types(),
+            [
+                ("0x42::message::MessageSentEvent".to_string(), "MessageSentEvent"),
+                ("0x42::asset::AssetLockedEvent".to_string(), "asset::AssetLockedEvent"),
+            ]
+        );
+        let locked = SignedMessage { event_name: Some("asset::AssetLockedEvent".to_string()), ..message.clone() };
+        assert_eq!(adapter.event_type_of(&locked).unwrap(), "0x42::asset::AssetLockedEvent");
+        assert!(adapter.event_type_of(&SignedMessage { event_name: None, ..message }).is_err());
+    }
+}
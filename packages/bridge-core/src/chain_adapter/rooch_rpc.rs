@@ -0,0 +1,389 @@
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use crate::{proof::rooch::StateProof, Error};
+use super::contract_error::BridgeContractError;
+use super::rate_limit::{reject_throttled, RateLimiter};
+
+/// Rooch JSON-RPC 客户端
+///
+/// 只覆盖中继器用到的接口，请求与响应使用与 Rooch RPC 一致的类型。
+pub struct RoochRpcClient {
+    http: reqwest::Client,
+    url: String,
+    next_id: AtomicU64,
+    rate_limiter: Option<Arc<RateLimiter>>,
+}
+
+#[derive(Deserialize)]
+struct JsonRpcResponse<T> {
+    result: Option<T>,
+    error: Option<JsonRpcError>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct JsonRpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+/// 事件 ID
+#[derive(Debug, Clone, Deserialize)]
+pub struct EventIdView {
+    pub event_handle_id: String,
+    #[serde(deserialize_with = "de_u64")]
+    pub event_seq: u64,
+}
+
+/// 链上事件
+#[derive(Debug, Clone, Deserialize)]
+pub struct EventView {
+    pub event_id: EventIdView,
+    pub event_type: String,
+    /// BCS 编码的事件数据（0x 前缀的十六进制）
+    pub event_data: String,
+    #[serde(default, deserialize_with = "de_opt_u64")]
+    pub event_index: Option<u64>,
+}
+
+impl EventView {
+    /// 解码事件数据
+    pub fn decode<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        let bytes = hex::decode(self.event_data.trim_start_matches("0x"))
+            .map_err(|e| Error::Serialization(format!("Invalid event data: {}", e)))?;
+        bcs::from_bytes(&bytes)
+            .map_err(|e| Error::Serialization(format!("Failed to decode {}: {}", self.event_type, e)))
+    }
+}
+
+/// 事件分页结果
+#[derive(Debug, Clone, Deserialize)]
+pub struct EventPageView {
+    pub data: Vec<EventView>,
+    #[serde(default, deserialize_with = "de_opt_u64")]
+    pub next_cursor: Option<u64>,
+    pub has_next_page: bool,
+}
+
+/// 只读函数调用
+#[derive(Debug, Clone, Serialize)]
+pub struct FunctionCallView {
+    pub function_id: String,
+    pub ty_args: Vec<String>,
+    pub args: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DecodedValueView {
+    pub decoded_value: serde_json::Value,
+}
+
+/// 只读函数执行结果
+#[derive(Debug, Clone, Deserialize)]
+pub struct FunctionResultView {
+    pub vm_status: serde_json::Value,
+    #[serde(default)]
+    pub return_values: Option<Vec<DecodedValueView>>,
+}
+
+/// 交易执行信息，`status.type` 为 `executed` 表示执行成功
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExecutionInfoView {
+    pub tx_hash: String,
+    pub status: serde_json::Value,
+    /// 消耗的 gas，节点以十进制字符串返回
+    #[serde(default)]
+    pub gas_used: Option<serde_json::Value>,
+}
+
+impl ExecutionInfoView {
+    pub fn is_executed(&self) -> bool {
+        self.status["type"].as_str() == Some("executed")
+    }
+
+    pub fn gas_used(&self) -> Option<u64> {
+        match self.gas_used.as_ref()? {
+            serde_json::Value::String(s) => s.parse().ok(),
+            v => v.as_u64(),
+        }
+    }
+
+    /// 合约中止时返回中止位置与中止码
+    pub fn move_abort(&self) -> Option<(String, u64)> {
+        if self.status["type"].as_str() != Some("moveabort") {
+            return None;
+        }
+        let code = match &self.status["abort_code"] {
+            serde_json::Value::String(s) => s.parse().ok()?,
+            v => v.as_u64()?,
+        };
+        let location = self.status["location"].as_str().unwrap_or_default().to_string();
+        Some((location, code))
+    }
+
+    /// 执行失败时按桥合约错误分类，执行成功或无法识别的状态返回 None
+    pub fn contract_error(&self, bridge_address: &str) -> Option<BridgeContractError> {
+        if let Some((location, code)) = self.move_abort() {
+            return Some(BridgeContractError::from_abort(&location, code, bridge_address));
+        }
+        match self.status["type"].as_str()? {
+            "outofgas" => Some(BridgeContractError::OutOfGas),
+            status => BridgeContractError::from_vm_status(status),
+        }
+    }
+}
+
+/// 事件的状态证明，字节串均为 0x 前缀的十六进制
+#[derive(Debug, Clone, Deserialize)]
+pub struct EventProofView {
+    #[serde(deserialize_with = "de_u64")]
+    pub tx_order: u64,
+    pub state_root: String,
+    pub sequencer_signature: String,
+    pub event_id: String,
+    pub event_data: String,
+    pub siblings: Vec<String>,
+}
+
+impl EventProofView {
+    pub fn into_proof(self) -> Result<StateProof, Error> {
+        let decode = |field: &str, value: &str| {
+            hex::decode(value.trim_start_matches("0x"))
+                .map_err(|e| Error::Serialization(format!("Invalid {} in event proof: {}", field, e)))
+        };
+        Ok(StateProof {
+            tx_order: self.tx_order,
+            state_root: decode("state_root", &self.state_root)?,
+            sequencer_signature: decode("sequencer_signature", &self.sequencer_signature)?,
+            event_id: decode("event_id", &self.event_id)?,
+            event_data: decode("event_data", &self.event_data)?,
+            siblings: self.siblings.iter().map(|s| decode("siblings", s)).collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TransactionWithInfoView {
+    #[serde(default)]
+    pub execution_info: Option<ExecutionInfoView>,
+}
+
+impl RoochRpcClient {
+    pub fn new(url: &str) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            url: url.to_string(),
+            next_id: AtomicU64::new(1),
+            rate_limiter: None,
+        }
+    }
+
+    /// 使用共享的 HTTP 客户端，沿用其连接池、超时与代理设置
+    pub fn with_http(mut self, http: reqwest::Client) -> Self {
+        self.http = http;
+        self
+    }
+
+    /// 每次调用前先从限速器取得令牌
+    pub fn with_rate_limiter(mut self, rate_limiter: Option<Arc<RateLimiter>>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// 发起 JSON-RPC 调用，RPC 错误转换为包含错误码的 `Error::Rpc`
+    pub async fn call<P: Serialize, R: DeserializeOwned>(&self, method: &str, params: P) -> Result<R, Error> {
+        self.call_optional(method, params)
+            .await?
+            .ok_or_else(|| Error::Chain(format!("Empty {} response", method)))
+    }
+
+    /// 同 [`RoochRpcClient::call`]，结果为 null 时返回 None
+    pub async fn call_optional<P: Serialize, R: DeserializeOwned>(&self, method: &str, params: P) -> Result<Option<R>, Error> {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let response = self.http
+            .post(&self.url)
+            .json(&serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": method,
+                "params": params,
+                "id": id
+            }))
+            .send()
+            .await
+            .map_err(|e| Error::Network(e.to_string()))?;
+        let response: JsonRpcResponse<R> = reject_throttled(response, method)?
+            .json()
+            .await
+            .map_err(|e| Error::Chain(format!("Invalid {} response: {}", method, e)))?;
+
+        if let Some(error) = response.error {
+            return Err(Error::Rpc {
+                method: method.to_string(),
+                code: error.code,
+                message: error.message,
+            });
+        }
+        Ok(response.result)
+    }
+
+    /// 按事件类型分页查询事件，`cursor` 为上一页返回的 `next_cursor`
+    pub async fn get_events_by_event_handle(
+        &self,
+        event_handle_type: &str,
+        cursor: Option<u64>,
+        limit: u64,
+    ) -> Result<EventPageView, Error> {
+        self.call(
+            "rooch_getEventsByEventHandle",
+            serde_json::json!([
+                event_handle_type,
+                cursor.map(|c| c.to_string()),
+                limit.to_string(),
+                false,
+                { "decode": false }
+            ]),
+        )
+        .await
+    }
+
+    /// 查询事件在排序器签名的状态根下的证明，事件不存在时返回 None
+    pub async fn get_event_proof(&self, event_handle_type: &str, event_seq: u64) -> Result<Option<EventProofView>, Error> {
+        self.call_optional("rooch_getEventProof", [event_handle_type, &event_seq.to_string()]).await
+    }
+
+    /// 执行合约只读函数，返回解码后的返回值
+    pub async fn execute_view_function(&self, call: FunctionCallView) -> Result<Vec<serde_json::Value>, Error> {
+        let result: FunctionResultView = self.call("rooch_executeViewFunction", [call]).await?;
+        if result.vm_status != serde_json::Value::String("Executed".to_string()) {
+            return Err(Error::Chain(format!("View function failed: {}", result.vm_status)));
+        }
+        Ok(result
+            .return_values
+            .unwrap_or_default()
+            .into_iter()
+            .map(|v| v.decoded_value)
+            .collect())
+    }
+
+    /// 获取链 ID，用于构造交易
+    pub async fn chain_id(&self) -> Result<u64, Error> {
+        #[derive(Deserialize)]
+        struct ChainId(#[serde(deserialize_with = "de_u64")] u64);
+        let ChainId(chain_id) = self.call("rooch_getChainID", serde_json::json!([])).await?;
+        Ok(chain_id)
+    }
+
+    /// 广播已签名交易，返回交易哈希
+    pub async fn send_raw_transaction(&self, tx_bcs_hex: &str) -> Result<String, Error> {
+        self.call("rooch_sendRawTransaction", [tx_bcs_hex]).await
+    }
+
+    /// 按哈希查询交易，尚未上链时返回 None
+    pub async fn get_transaction_by_hash(&self, tx_hash: &str) -> Result<Option<TransactionWithInfoView>, Error> {
+        let mut result: Vec<Option<TransactionWithInfoView>> = self
+            .call("rooch_getTransactionsByHash", [[tx_hash]])
+            .await?;
+        Ok(result.pop().flatten())
+    }
+
+    /// 查询账户指定币种的余额
+    pub async fn get_balance(&self, owner: &str, coin_type: &str) -> Result<u128, Error> {
+        #[derive(Deserialize)]
+        struct BalanceInfoView {
+            balance: String,
+        }
+        let info: BalanceInfoView = self.call("rooch_getBalance", [owner, coin_type]).await?;
+        info.balance
+            .parse()
+            .map_err(|_| Error::Chain(format!("Invalid balance for {}: {}", owner, info.balance)))
+    }
+
+    /// 查询模块 ABI，模块不存在时返回 None
+    pub async fn get_module_abi(&self, module_id: &str) -> Result<Option<serde_json::Value>, Error> {
+        self.call_optional("rooch_getModuleABI", [module_id]).await
+    }
+
+    /// 查询模块 ABI 中定义的结构体名，模块不存在时返回 None
+    pub async fn get_module_structs(&self, module_id: &str) -> Result<Option<Vec<String>>, Error> {
+        #[derive(Deserialize)]
+        struct StructView {
+            name: String,
+        }
+        #[derive(Deserialize)]
+        struct ModuleAbiView {
+            #[serde(default)]
+            structs: Vec<StructView>,
+        }
+        let abi: Option<ModuleAbiView> = self.call_optional("rooch_getModuleABI", [module_id]).await?;
+        Ok(abi.map(|abi| abi.structs.into_iter().map(|s| s.name).collect()))
+    }
+
+    /// 通过 `rpc.discover` 获取节点 RPC 版本
+    pub async fn rpc_version(&self) -> Result<String, Error> {
+        let spec: serde_json::Value = self.call("rpc.discover", serde_json::json!([])).await?;
+        spec["info"]["version"]
+            .as_str()
+            .map(|v| v.to_string())
+            .ok_or_else(|| Error::Chain("Node did not report an RPC version".to_string()))
+    }
+}
+
+/// Rooch RPC 将 u64 编码为字符串，兼容数字形式
+fn de_u64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StrOrNum {
+        Str(String),
+        Num(u64),
+    }
+    match StrOrNum::deserialize(deserializer)? {
+        StrOrNum::Str(s) => s.parse().map_err(serde::de::Error::custom),
+        StrOrNum::Num(n) => Ok(n),
+    }
+}
+
+fn de_opt_u64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u64>, D::Error> {
+    #[derive(Deserialize)]
+    struct Wrapper(#[serde(deserialize_with = "de_u64")] u64);
+    Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|w| w.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_event_page() {
+        let page: EventPageView = serde_json::from_value(serde_json::json!({
+            "data": [{
+                "event_id": { "event_handle_id": "0x01", "event_seq": "7" },
+                "event_type": "0x42::message::MessageSentEvent",
+                "event_data": "0x0100000000000000",
+                "event_index": "0"
+            }],
+            "next_cursor": "7",
+            "has_next_page": false
+        }))
+        .unwrap();
+
+        assert_eq!(page.next_cursor, Some(7));
+        assert_eq!(page.data[0].event_id.event_seq, 7);
+        assert_eq!(page.data[0].decode::<u64>().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_classify_execution_failure() {
+        let info = |status: serde_json::Value| ExecutionInfoView { tx_hash: "0x01".to_string(), status, gas_used: None };
+        let aborted = info(serde_json::json!({ "type": "moveabort", "location": "0x42::message", "abort_code": "5" }));
+        assert_eq!(aborted.contract_error("0x42"), Some(BridgeContractError::AlreadyProcessed));
+        assert_eq!(
+            aborted.contract_error("0x43"),
+            Some(BridgeContractError::Abort { location: "0x42::message".to_string(), code: 5 })
+        );
+        assert_eq!(info(serde_json::json!({ "type": "outofgas" })).contract_error("0x42"), Some(BridgeContractError::OutOfGas));
+        assert_eq!(info(serde_json::json!({ "type": "executed" })).contract_error("0x42"), None);
+    }
+}
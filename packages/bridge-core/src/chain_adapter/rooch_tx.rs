@@ -0,0 +1,212 @@
+use blake2::{Blake2b, Digest, digest::consts::U32};
+use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+use serde::{Serialize, Serializer};
+use sha3::Sha3_256;
+use std::path::Path;
+use crate::Error;
+
+/// 默认的单笔交易最大 gas
+pub const DEFAULT_MAX_GAS_AMOUNT: u64 = 100_000_000;
+
+/// ed25519 签名方案标识
+const ED25519_FLAG: u8 = 0x00;
+/// 会话密钥验证器 ID，Rooch 用它验证 ed25519 签名
+const SESSION_VALIDATOR_ID: u64 = 0;
+/// `MoveAction::Function` 在 Rooch 中的变体序号
+const FUNCTION_ACTION_INDEX: u32 = 1;
+
+/// Move 函数 ID：`<address>::<module>::<function>`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FunctionId {
+    pub address: [u8; 32],
+    pub module: String,
+    pub function: String,
+}
+
+impl FunctionId {
+    pub fn parse(function_id: &str) -> Result<Self, Error> {
+        let parts: Vec<&str> = function_id.split("::").collect();
+        let [address, module, function] = parts[..] else {
+            return Err(Error::Config(format!("Invalid function ID: {}", function_id)));
+        };
+        Ok(Self {
+            address: parse_address(address)?,
+            module: module.to_string(),
+            function: function.to_string(),
+        })
+    }
+}
+
+/// 入口函数调用，参数为各自 BCS 编码后的字节
+///
+/// 桥合约的入口函数没有类型参数，这里只编码空的类型参数列表。
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionCall {
+    pub function_id: FunctionId,
+    pub args: Vec<Vec<u8>>,
+}
+
+impl Serialize for FunctionCall {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let ty_args: Vec<()> = Vec::new();
+        (&self.function_id, ty_args, &self.args).serialize(serializer)
+    }
+}
+
+/// 交易动作，中继器只需要调用入口函数
+#[derive(Debug, Clone, PartialEq)]
+pub enum MoveAction {
+    Function(FunctionCall),
+}
+
+impl Serialize for MoveAction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            MoveAction::Function(call) => {
+                serializer.serialize_newtype_variant("MoveAction", FUNCTION_ACTION_INDEX, "Function", call)
+            }
+        }
+    }
+}
+
+/// 待签名的交易数据
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RoochTransactionData {
+    pub sender: [u8; 32],
+    pub sequence_number: u64,
+    pub chain_id: u64,
+    pub max_gas_amount: u64,
+    pub action: MoveAction,
+}
+
+impl RoochTransactionData {
+    /// 交易哈希：交易数据 BCS 编码的 SHA3-256
+    pub fn tx_hash(&self) -> Result<[u8; 32], Error> {
+        let bytes = bcs::to_bytes(self).map_err(|e| Error::Serialization(e.to_string()))?;
+        Ok(Sha3_256::digest(bytes).into())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Authenticator {
+    pub auth_validator_id: u64,
+    pub payload: Vec<u8>,
+}
+
+/// 已签名交易
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RoochTransaction {
+    pub data: RoochTransactionData,
+    pub authenticator: Authenticator,
+}
+
+impl RoochTransaction {
+    /// 编码为 `rooch_sendRawTransaction` 接受的十六进制字符串
+    pub fn encode(&self) -> Result<String, Error> {
+        let bytes = bcs::to_bytes(self).map_err(|e| Error::Serialization(e.to_string()))?;
+        Ok(format!("0x{}", hex::encode(bytes)))
+    }
+}
+
+/// 中继器在 Rooch 上的发送账户
+pub struct RoochSigner {
+    keypair: Keypair,
+    address: [u8; 32],
+}
+
+impl RoochSigner {
+    pub fn from_secret_key(bytes: &[u8]) -> Result<Self, Error> {
+        let secret = SecretKey::from_bytes(bytes)
+            .map_err(|e| Error::Key(format!("Invalid Rooch sender key: {}", e)))?;
+        let public = PublicKey::from(&secret);
+        // 地址为 (方案标识 || 公钥) 的 Blake2b-256
+        let mut hasher = Blake2b::<U32>::new();
+        hasher.update([ED25519_FLAG]);
+        hasher.update(public.as_bytes());
+        Ok(Self {
+            keypair: Keypair { secret, public },
+            address: hasher.finalize().into(),
+        })
+    }
+
+    /// 从私钥文件加载，文件内容为十六进制编码的 ed25519 私钥
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| Error::Key(format!("Failed to read Rooch sender key: {}", e)))?;
+        let bytes = hex::decode(content.trim().trim_start_matches("0x"))
+            .map_err(|e| Error::Key(format!("Invalid Rooch sender key encoding: {}", e)))?;
+        Self::from_secret_key(&bytes)
+    }
+
+    pub fn address(&self) -> [u8; 32] {
+        self.address
+    }
+
+    pub fn address_hex(&self) -> String {
+        format!("0x{}", hex::encode(self.address))
+    }
+
+    /// 对交易哈希签名，认证负载为 `方案标识 || 签名 || 公钥`
+    pub fn sign(&self, data: RoochTransactionData) -> Result<RoochTransaction, Error> {
+        let signature = self.keypair.sign(&data.tx_hash()?);
+        let mut payload = vec![ED25519_FLAG];
+        payload.extend_from_slice(&signature.to_bytes());
+        payload.extend_from_slice(self.keypair.public.as_bytes());
+        Ok(RoochTransaction {
+            data,
+            authenticator: Authenticator {
+                auth_validator_id: SESSION_VALIDATOR_ID,
+                payload,
+            },
+        })
+    }
+}
+
+/// 解析十六进制 Move 地址，不足 32 字节时左侧补零
+fn parse_address(address: &str) -> Result<[u8; 32], Error> {
+    let digits = address.trim_start_matches("0x");
+    if digits.is_empty() || digits.len() > 64 {
+        return Err(Error::Config(format!("Invalid Move address: {}", address)));
+    }
+    let bytes = hex::decode(format!("{:0>64}", digits))
+        .map_err(|e| Error::Config(format!("Invalid Move address {}: {}", address, e)))?;
+    let mut result = [0u8; 32];
+    result.copy_from_slice(&bytes);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signature, Verifier};
+
+    #[test]
+    fn test_sign_function_call() {
+        let signer = RoochSigner::from_secret_key(&[7u8; 32]).unwrap();
+        let function_id = FunctionId::parse("0x42::bridge::process_message").unwrap();
+        assert_eq!(function_id.address[31], 0x42);
+
+        let data = RoochTransactionData {
+            sender: signer.address(),
+            sequence_number: 3,
+            chain_id: 2,
+            max_gas_amount: DEFAULT_MAX_GAS_AMOUNT,
+            action: MoveAction::Function(FunctionCall {
+                function_id,
+                args: vec![bcs::to_bytes(&vec![1u8, 2, 3]).unwrap()],
+            }),
+        };
+        let tx = signer.sign(data.clone()).unwrap();
+
+        // 变体序号紧跟在 sender、序列号、链 ID 与 gas 之后
+        let bytes = bcs::to_bytes(&data).unwrap();
+        assert_eq!(bytes[32 + 24], FUNCTION_ACTION_INDEX as u8);
+
+        let payload = &tx.authenticator.payload;
+        assert_eq!(payload.len(), 1 + 64 + 32);
+        let signature = Signature::from_bytes(&payload[1..65]).unwrap();
+        let public = PublicKey::from_bytes(&payload[65..]).unwrap();
+        assert!(public.verify(&data.tx_hash().unwrap(), &signature).is_ok());
+        assert!(tx.encode().unwrap().starts_with("0x"));
+    }
+}
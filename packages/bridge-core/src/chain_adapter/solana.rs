@@ -0,0 +1,634 @@
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use crate::{
+    types::{ChainId, Delivery, SignedMessage, MessageStatus, CrossChainMessage, ValidatorRegistration},
+    Error,
+    config::ChainConfig,
+};
+use super::{check_address_format, AdapterCapabilities, ChainAdapter, ContractCheck, RateLimiter};
+use super::solana_rpc::{AccountView, SignatureInfo, SolanaRpcClient};
+use super::solana_tx::{
+    advance_nonce_account, parse_nonce_account, set_compute_unit_price, system_program, write_borsh_bytes,
+    AccountMeta, BorshReader, Instruction, Message, Pubkey, SolanaSigner,
+};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::sleep;
+use tracing::{debug, warn};
+
+const MAX_RETRIES: u32 = 3;
+const RETRY_DELAY: u64 = 2;
+/// 签名分页大小
+const SIGNATURE_PAGE_SIZE: usize = 100;
+/// 等待交易确认的轮询间隔（秒），等待时长由链配置的 `stuck_timeout` 决定
+const CONFIRMATION_POLL_INTERVAL: u64 = 1;
+/// 重新提交时每次增加的优先费（每计算单元的 micro-lamports）
+const RESUBMIT_PRIORITY_FEE: u64 = 10_000;
+/// 程序通过 `emit!` 输出的事件日志前缀
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+/// 桥程序输出的事件
+const BRIDGE_EVENTS: &[&str] = &["MessageSent", "AssetUnlocked"];
+
+/// 桥程序事件与指令数据的 8 字节类型前缀：`sha256("<namespace>:<name>")` 的前 8 字节
+fn discriminator(namespace: &str, name: &str) -> [u8; 8] {
+    let hash = Sha256::digest(format!("{}:{}", namespace, name));
+    hash[..8].try_into().expect("slice of 8 bytes")
+}
+
+/// 从交易日志中取出由 `program_id` 直接输出的事件数据
+///
+/// 日志按调用栈记录，跨程序调用中其它程序输出的事件被忽略。
+fn program_events(logs: &[String], program_id: &str) -> Vec<Vec<u8>> {
+    let mut stack: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+    for log in logs {
+        if let Some(data) = log.strip_prefix(PROGRAM_DATA_PREFIX) {
+            if stack.last() == Some(&program_id) {
+                match BASE64.decode(data) {
+                    Ok(bytes) => events.push(bytes),
+                    Err(e) => warn!("Skipping undecodable Solana program data: {}", e),
+                }
+            }
+        } else if let Some((program, status)) = log.strip_prefix("Program ").and_then(|rest| rest.split_once(' ')) {
+            if status.starts_with("invoke [") {
+                stack.push(program);
+            } else if status == "success" || status.starts_with("failed") {
+                stack.pop();
+            }
+        }
+    }
+    events
+}
+
+/// `MessageSent` 事件的 Borsh 布局
+struct MessageSentEvent {
+    nonce: u64,
+    source_chain: u64,
+    target_chain: u64,
+    receiver: Vec<u8>,
+    payload: Vec<u8>,
+}
+
+impl MessageSentEvent {
+    fn decode(reader: &mut BorshReader) -> Result<Self, Error> {
+        Ok(Self {
+            nonce: reader.read_u64()?,
+            source_chain: reader.read_u64()?,
+            target_chain: reader.read_u64()?,
+            receiver: reader.read_bytes()?,
+            payload: reader.read_bytes()?,
+        })
+    }
+}
+
+/// `AssetUnlocked` 事件的 Borsh 布局
+struct AssetUnlockedEvent {
+    receiver: [u8; 32],
+    amount: u64,
+    source_chain: u64,
+}
+
+impl AssetUnlockedEvent {
+    fn decode(reader: &mut BorshReader) -> Result<Self, Error> {
+        Ok(Self {
+            receiver: reader.read_array()?,
+            amount: reader.read_u64()?,
+            source_chain: reader.read_u64()?,
+        })
+    }
+}
+
+/// 桥程序输出的某一类事件
+#[derive(Clone, Copy)]
+enum EventKind {
+    MessageSent,
+    AssetUnlocked,
+}
+
+impl EventKind {
+    fn name(self) -> &'static str {
+        match self {
+            EventKind::MessageSent => "MessageSent",
+            EventKind::AssetUnlocked => "AssetUnlocked",
+        }
+    }
+}
+
+/// 交易中由桥程序输出的一条事件
+struct ProgramEvent {
+    signature: String,
+    index: usize,
+    data: Vec<u8>,
+}
+
+/// Solana 链适配器
+///
+/// 从桥程序所在交易的日志中读取事件，通过 durable nonce 提交交易：交易的区块哈希取自 nonce 账户，
+/// 不会因区块哈希过期而失效，卡住的交易与其替换交易使用同一 nonce，最多只有一笔上链。
+pub struct SolanaAdapter {
+    config: ChainConfig,
+    client: SolanaRpcClient,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    /// JSON-RPC 基于 HTTP 无需保持连接，`connect` 确认节点可达后才允许调用
+    connected: AtomicBool,
+    program_id: Pubkey,
+    /// 合约中的数字链 ID 到配置链 ID 的映射
+    chain_ids: HashMap<u64, ChainId>,
+    /// 各类事件已读取到的最新交易签名
+    cursors: Mutex<HashMap<&'static str, String>>,
+    /// 付费账户与其授权的 nonce 账户，未配置时只能读取不能提交
+    submitter: Option<(SolanaSigner, Pubkey)>,
+    /// 同一 nonce 只能被一笔交易使用，持有到交易确认后才读取下一个 nonce
+    submit_lock: Mutex<()>,
+}
+
+impl SolanaAdapter {
+    pub fn new(config: ChainConfig) -> Result<Self, Error> {
+        let rate_limiter = RateLimiter::for_chain(&config);
+        Ok(Self {
+            client: SolanaRpcClient::new(&config.rpc_url).with_rate_limiter(rate_limiter.clone()),
+            rate_limiter,
+            program_id: Pubkey::parse(&config.bridge_address)?,
+            config,
+            connected: AtomicBool::new(false),
+            chain_ids: HashMap::new(),
+            cursors: Mutex::new(HashMap::new()),
+            submitter: None,
+            submit_lock: Mutex::new(()),
+        })
+    }
+
+    /// 设置付费账户与 durable nonce 账户，付费账户须为 nonce 账户的授权账户
+    pub fn with_signer(mut self, signer: SolanaSigner, nonce_account: Pubkey) -> Self {
+        self.submitter = Some((signer, nonce_account));
+        self
+    }
+
+    /// 设置数字链 ID 映射，用于把事件中的链 ID 转换为配置中的链 ID
+    pub fn with_chain_ids(mut self, chain_ids: HashMap<u64, ChainId>) -> Self {
+        self.chain_ids = chain_ids;
+        self
+    }
+
+    fn ensure_connected(&self) -> Result<(), Error> {
+        if self.connected.load(Ordering::Acquire) {
+            Ok(())
+        } else {
+            Err(Error::Network(format!("Solana adapter for {} is not connected", self.config.id)))
+        }
+    }
+
+    async fn retry_with_backoff<F, Fut, T>(&self, operation: F) -> Result<T, Error>
+    where
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = Result<T, Error>>,
+    {
+        self.ensure_connected()?;
+        let mut retries = 0;
+        loop {
+            match operation().await {
+                Ok(result) => return Ok(result),
+                Err(e) => {
+                    retries += 1;
+                    if retries >= MAX_RETRIES {
+                        return Err(e);
+                    }
+                    sleep(Duration::from_secs(RETRY_DELAY.pow(retries))).await;
+                }
+            }
+        }
+    }
+
+    fn chain_name(&self, chain_id: u64) -> Result<ChainId, Error> {
+        self.chain_ids
+            .get(&chain_id)
+            .cloned()
+            .ok_or_else(|| Error::Config(format!("Unknown numeric chain ID in Solana event: {}", chain_id)))
+    }
+
+    /// 配置链 ID 对应的合约数字链 ID
+    fn numeric_chain_id(&self, chain: &ChainId) -> Result<u64, Error> {
+        self.chain_ids
+            .iter()
+            .find(|(_, id)| *id == chain)
+            .map(|(numeric, _)| *numeric)
+            .ok_or_else(|| Error::Config(format!("No numeric chain ID configured for {}", chain)))
+    }
+
+    fn program_address(&self, seeds: &[&[u8]]) -> Result<Pubkey, Error> {
+        Ok(Pubkey::find_program_address(seeds, &self.program_id)?.0)
+    }
+
+    /// 消息处理记录账户，程序处理消息时创建，存在即表示消息已处理
+    fn message_record(&self, message: &CrossChainMessage) -> Result<Pubkey, Error> {
+        let hash = Sha256::digest(message.canonical_bytes(&self.chain_ids)?);
+        self.program_address(&[b"message", &hash])
+    }
+
+    async fn get_account(&self, address: &str) -> Result<Option<AccountView>, Error> {
+        self.retry_with_backoff(|| self.client.get_account_info(address)).await
+    }
+
+    /// 读取程序账户数据，跳过 8 字节的账户类型前缀；账户不存在时返回 None
+    async fn read_account(&self, address: Pubkey) -> Result<Option<Vec<u8>>, Error> {
+        match self.get_account(&address.to_string()).await? {
+            Some(account) => {
+                let data = account.decode_data()?;
+                if data.len() < 8 {
+                    return Err(Error::Chain(format!("Account {} has no data", address)));
+                }
+                Ok(Some(data[8..].to_vec()))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// 按时间从旧到新返回签名区间内执行成功的交易中由桥程序输出的事件
+    async fn fetch_events(&self, signatures: Vec<SignatureInfo>) -> Result<Vec<ProgramEvent>, Error> {
+        let program_id = self.program_id.to_string();
+        let mut events = Vec::new();
+        for info in signatures.into_iter().rev().filter(|info| info.err.is_none()) {
+            let Some(transaction) = self.retry_with_backoff(|| self.client.get_transaction(&info.signature)).await? else {
+                continue;
+            };
+            let logs = transaction.meta.and_then(|meta| meta.log_messages).unwrap_or_default();
+            for (index, data) in program_events(&logs, &program_id).into_iter().enumerate() {
+                events.push(ProgramEvent { signature: info.signature.clone(), index, data });
+            }
+        }
+        Ok(events)
+    }
+
+    /// 读取上次游标之后的新交易中某一类事件，读取完成后推进游标
+    async fn fetch_new_events(&self, kind: EventKind) -> Result<Vec<ProgramEvent>, Error> {
+        let program_id = self.program_id.to_string();
+        let until = self.cursors.lock().await.get(kind.name()).cloned();
+        let mut before: Option<String> = None;
+        let mut signatures = Vec::new();
+        loop {
+            let page = self
+                .retry_with_backoff(|| {
+                    self.client.get_signatures_for_address(&program_id, before.as_deref(), until.as_deref(), SIGNATURE_PAGE_SIZE)
+                })
+                .await?;
+            let done = page.len() < SIGNATURE_PAGE_SIZE;
+            before = page.last().map(|info| info.signature.clone());
+            signatures.extend(page);
+            if done {
+                break;
+            }
+        }
+
+        let newest = signatures.first().map(|info| info.signature.clone());
+        let events = self.fetch_events(signatures).await?;
+        if let Some(newest) = newest {
+            self.cursors.lock().await.insert(kind.name(), newest);
+        }
+        let prefix = discriminator("event", kind.name());
+        Ok(events.into_iter().filter(|event| event.data.starts_with(&prefix)).collect())
+    }
+
+    /// 将 `MessageSent` 事件解码为跨链消息，事件数据不含类型前缀时返回 None
+    fn parse_event(&self, event: &ProgramEvent) -> Result<Option<SignedMessage>, Error> {
+        let Some(data) = event.data.strip_prefix(&discriminator("event", EventKind::MessageSent.name())) else {
+            return Ok(None);
+        };
+        let data = MessageSentEvent::decode(&mut BorshReader::new(data))?;
+        // 负载与 Sui 侧 `encode_asset_payload` 一致：接收者地址 || 负载
+        let mut payload = data.receiver;
+        payload.extend(data.payload);
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|e| Error::Chain(format!("Failed to get current time: {}", e)))?
+            .as_secs();
+
+        Ok(Some(SignedMessage {
+            message: CrossChainMessage {
+                nonce: data.nonce,
+                source_chain: self.chain_name(data.source_chain)?,
+                target_chain: self.chain_name(data.target_chain)?,
+                message_type: "transfer".to_string(),
+                payload,
+            },
+            signature: vec![],
+            timestamp,
+            event_name: Some(EventKind::MessageSent.name().to_string()),
+            sender: None,
+            provenance: None,
+            proof: None,
+            trace_id: None,
+        }))
+    }
+
+    fn parse_delivery(&self, event: &ProgramEvent) -> Result<Option<Delivery>, Error> {
+        let Some(data) = event.data.strip_prefix(&discriminator("event", EventKind::AssetUnlocked.name())) else {
+            return Ok(None);
+        };
+        let data = AssetUnlockedEvent::decode(&mut BorshReader::new(data))?;
+        Ok(Some(Delivery {
+            event_id: format!("{}:{}", event.signature, event.index),
+            source_chain: self.chain_name(data.source_chain)?,
+            target_chain: self.config.id.clone(),
+            nonce: None,
+            recipient: Some(data.receiver.to_vec()),
+            amount: Some(data.amount),
+            tx_hash: Some(event.signature.clone()),
+            message_id: None,
+        }))
+    }
+
+    fn parse_messages(&self, events: &[ProgramEvent]) -> Vec<SignedMessage> {
+        events
+            .iter()
+            .filter_map(|event| match self.parse_event(event) {
+                Ok(message) => message,
+                Err(e) => {
+                    warn!("Skipping undecodable Solana event {}:{}: {}", event.signature, event.index, e);
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// 桥程序 `process_message` 指令，账户依次为付费账户、桥状态账户、消息处理记录账户与系统程序
+    fn process_message_instruction(&self, payer: Pubkey, message: &SignedMessage) -> Result<Instruction, Error> {
+        let mut data = discriminator("global", "process_message").to_vec();
+        write_borsh_bytes(&mut data, &message.message.canonical_bytes(&self.chain_ids)?);
+        write_borsh_bytes(&mut data, &message.signature);
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::writable(payer, true),
+                AccountMeta::writable(self.program_address(&[b"state"])?, false),
+                AccountMeta::writable(self.message_record(&message.message)?, false),
+                AccountMeta::readonly(system_program(), false),
+            ],
+            data,
+        })
+    }
+
+    /// 以 nonce 账户的当前 nonce 构造、签名并广播交易，等待确认后返回交易签名
+    async fn send_message(&self, message: &SignedMessage, priority_fee: u64) -> Result<String, Error> {
+        let (signer, nonce_account) = self.submitter.as_ref().ok_or_else(|| {
+            Error::Config(format!("Chain {} requires sender_keystore and solana.nonce_account to submit transactions", self.config.id))
+        })?;
+        let _guard = self.submit_lock.lock().await;
+
+        let account = self
+            .get_account(&nonce_account.to_string())
+            .await?
+            .ok_or_else(|| Error::Chain(format!("Nonce account {} not found", nonce_account)))?;
+        let nonce = parse_nonce_account(&account.decode_data()?)?;
+
+        // 推进 nonce 的指令必须在第一条
+        let mut instructions = vec![advance_nonce_account(*nonce_account, signer.pubkey())];
+        if priority_fee > 0 {
+            instructions.push(set_compute_unit_price(priority_fee));
+        }
+        instructions.push(self.process_message_instruction(signer.pubkey(), message)?);
+        let tx = signer.sign(Message::new(&instructions, &signer.pubkey(), nonce));
+
+        let encoded = tx.encode();
+        let signature = self.retry_with_backoff(|| self.client.send_transaction(&encoded)).await?;
+        debug!("Submitted message {} in Solana transaction {}", message.message.nonce, signature);
+        self.wait_for_confirmation(&signature).await?;
+        Ok(signature)
+    }
+
+    /// 轮询交易确认状态，执行失败时返回错误，超时返回 `Error::Stuck`
+    async fn wait_for_confirmation(&self, signature: &str) -> Result<(), Error> {
+        let timeout = self.config.stuck_timeout();
+        for _ in 0..timeout.div_ceil(CONFIRMATION_POLL_INTERVAL).max(1) {
+            match self.client.get_signature_status(signature).await {
+                Ok(Some(status)) => {
+                    if let Some(err) = status.err {
+                        return Err(Error::Chain(format!("Transaction {} failed: {}", signature, err)));
+                    }
+                    if status.is_confirmed() {
+                        return Ok(());
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => debug!("Failed to query transaction {}: {}", signature, e),
+            }
+            sleep(Duration::from_secs(CONFIRMATION_POLL_INTERVAL)).await;
+        }
+        Err(Error::Stuck { tx_hash: signature.to_string(), timeout })
+    }
+}
+
+#[async_trait]
+impl ChainAdapter for SolanaAdapter {
+    fn chain_type(&self) -> &str {
+        "solana"
+    }
+
+    fn config(&self) -> &ChainConfig {
+        &self.config
+    }
+
+    fn capabilities(&self) -> AdapterCapabilities {
+        // 事件按交易签名轮询；每条消息需要各自的处理记录账户，不做批量提交
+        AdapterCapabilities::default()
+    }
+
+    fn rate_limiter(&self) -> Option<Arc<RateLimiter>> {
+        self.rate_limiter.clone()
+    }
+
+    async fn connect(&self) -> Result<(), Error> {
+        let version = self.client.get_version().await?;
+        debug!("Connected to Solana node {} (version {})", self.config.rpc_url, version);
+        self.connected.store(true, Ordering::Release);
+        Ok(())
+    }
+
+    async fn close(&self) -> Result<(), Error> {
+        self.connected.store(false, Ordering::Release);
+        Ok(())
+    }
+
+    async fn is_healthy(&self) -> bool {
+        self.connected.load(Ordering::Acquire) && self.client.get_version().await.is_ok()
+    }
+
+    async fn listen_events(&self) -> Result<Vec<SignedMessage>, Error> {
+        let events = self.fetch_new_events(EventKind::MessageSent).await?;
+        Ok(self.parse_messages(&events))
+    }
+
+    async fn replay_events(&self, from: u64, to: u64) -> Result<Vec<SignedMessage>, Error> {
+        let program_id = self.program_id.to_string();
+        // 签名从新到旧返回，向前翻页直到早于 `from` 的 slot
+        let mut before: Option<String> = None;
+        let mut signatures = Vec::new();
+        loop {
+            let page = self
+                .retry_with_backoff(|| {
+                    self.client.get_signatures_for_address(&program_id, before.as_deref(), None, SIGNATURE_PAGE_SIZE)
+                })
+                .await?;
+            let done = page.len() < SIGNATURE_PAGE_SIZE || page.last().is_some_and(|info| info.slot < from);
+            before = page.last().map(|info| info.signature.clone());
+            signatures.extend(page.into_iter().filter(|info| (from..=to).contains(&info.slot)));
+            if done {
+                break;
+            }
+        }
+        let events = self.fetch_events(signatures).await?;
+        Ok(self.parse_messages(&events))
+    }
+
+    async fn listen_deliveries(&self) -> Result<Vec<Delivery>, Error> {
+        let mut deliveries = Vec::new();
+        for event in self.fetch_new_events(EventKind::AssetUnlocked).await? {
+            match self.parse_delivery(&event) {
+                Ok(Some(delivery)) => deliveries.push(delivery),
+                Ok(None) => {}
+                Err(e) => warn!("Skipping undecodable Solana event {}:{}: {}", event.signature, event.index, e),
+            }
+        }
+        Ok(deliveries)
+    }
+
+    async fn submit_message(&self, message: SignedMessage) -> Result<String, Error> {
+        self.send_message(&message, 0).await
+    }
+
+    /// 替换交易读取同一个 nonce，原交易上链后 nonce 已推进、替换交易随之失效；优先费随重新提交次数增加
+    async fn resubmit_message(&self, message: SignedMessage, stuck_tx: &str, attempt: u32) -> Result<String, Error> {
+        let priority_fee = RESUBMIT_PRIORITY_FEE.saturating_mul(attempt as u64);
+        debug!("Resubmitting message {} replacing stuck transaction {} (priority fee {})", message.message.nonce, stuck_tx, priority_fee);
+        self.send_message(&message, priority_fee).await
+    }
+
+    async fn verify_message(&self, message: &SignedMessage) -> Result<MessageStatus, Error> {
+        let record = self.message_record(&message.message)?;
+        let account = self.get_account(&record.to_string()).await?;
+        Ok(if account.is_some() { MessageStatus::Processed } else { MessageStatus::Pending })
+    }
+
+    async fn last_processed_nonce(&self, source_chain: &ChainId) -> Result<Option<u64>, Error> {
+        let numeric = self.numeric_chain_id(source_chain)?.to_le_bytes();
+        let address = self.program_address(&[b"nonce", &numeric])?;
+        match self.read_account(address).await? {
+            Some(data) => Ok(Some(BorshReader::new(&data).read_u64()?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn get_validator_registration(&self, validator: &str) -> Result<Option<ValidatorRegistration>, Error> {
+        let key = Pubkey::parse(validator)?;
+        let Some(data) = self.read_account(self.program_address(&[b"validator", &key.0])?).await? else {
+            return Ok(None);
+        };
+        let mut reader = BorshReader::new(&data);
+        Ok(Some(ValidatorRegistration {
+            address: validator.to_string(),
+            weight: reader.read_u64()?,
+            active: reader.read_u8()? != 0,
+        }))
+    }
+
+    async fn node_version(&self) -> Result<String, Error> {
+        self.retry_with_backoff(|| self.client.get_version()).await
+    }
+
+    async fn gas_balance(&self) -> Result<Option<u128>, Error> {
+        let Some((signer, _)) = &self.submitter else {
+            return Ok(None);
+        };
+        self.ensure_connected()?;
+        let balance = self.client.get_balance(&signer.pubkey().to_string()).await?;
+        Ok(Some(balance as u128))
+    }
+
+    async fn transaction_gas(&self, tx_hash: &str) -> Result<Option<u64>, Error> {
+        self.ensure_connected()?;
+        let transaction = self.client.get_transaction(tx_hash).await?;
+        Ok(transaction.and_then(|t| t.meta).map(|meta| meta.fee))
+    }
+
+    async fn check_contract(&self) -> Result<ContractCheck, Error> {
+        let program = self.get_account(&self.config.bridge_address).await?;
+        if !program.is_some_and(|account| account.executable) {
+            return Ok(ContractCheck { exists: false, missing_events: Vec::new() });
+        }
+        // 程序账户不含事件定义，只能对照桥程序已知的事件
+        Ok(ContractCheck {
+            exists: true,
+            missing_events: self.config.event_filters
+                .iter()
+                .filter(|filter| !BRIDGE_EVENTS.contains(&filter.name.as_str()))
+                .map(|filter| filter.name.clone())
+                .collect(),
+        })
+    }
+
+    async fn check_recipient(&self, recipient: &[u8], _asset_type: &str) -> Result<Option<String>, Error> {
+        if let Some(reason) = check_address_format(recipient) {
+            return Ok(Some(reason));
+        }
+        let address = Pubkey(recipient.try_into().expect("checked 32 bytes")).to_string();
+        let account = self.get_account(&address).await?;
+        if account.is_some_and(|account| account.executable) {
+            return Ok(Some(format!("Recipient {} is a program", address)));
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_program_logs() {
+        let config = ChainConfig {
+            adapter_type: "solana".to_string(),
+            rpc_url: "http://localhost:8899".to_string(),
+            bridge_address: Pubkey([7u8; 32]).to_string(),
+            ..crate::testing::mock_chain_config("solana_devnet")
+        };
+        let adapter = SolanaAdapter::new(config)
+            .unwrap()
+            .with_chain_ids(HashMap::from([(1, ChainId::from("sui_testnet")), (3, ChainId::from("solana_devnet"))]));
+        let program_id = adapter.program_id.to_string();
+
+        let mut data = discriminator("event", "MessageSent").to_vec();
+        data.extend(9u64.to_le_bytes());
+        data.extend(3u64.to_le_bytes());
+        data.extend(1u64.to_le_bytes());
+        write_borsh_bytes(&mut data, &[0xaa; 4]);
+        write_borsh_bytes(&mut data, &5u64.to_le_bytes());
+        // 桥程序调用的其它程序输出的事件不属于桥程序
+        let logs: Vec<String> = [
+            format!("Program {} invoke [1]", program_id),
+            "Program log: Instruction: SendMessage".to_string(),
+            "Program 11111111111111111111111111111111 invoke [2]".to_string(),
+            format!("Program data: {}", BASE64.encode([1u8; 16])),
+            "Program 11111111111111111111111111111111 success".to_string(),
+            format!("Program data: {}", BASE64.encode(&data)),
+            format!("Program {} success", program_id),
+        ]
+        .into();
+        let events = program_events(&logs, &program_id);
+        assert_eq!(events, vec![data.clone()]);
+
+        let event = ProgramEvent { signature: "sig".to_string(), index: 0, data };
+        let message = adapter.parse_event(&event).unwrap().unwrap();
+        assert_eq!(message.message.nonce, 9);
+        assert_eq!(message.message.source_chain, "solana_devnet");
+        assert_eq!(message.message.target_chain, "sui_testnet");
+        assert_eq!(message.message.payload[..4], [0xaa; 4]);
+        assert!(adapter.parse_delivery(&event).unwrap().is_none());
+
+        let instruction = adapter.process_message_instruction(Pubkey([1u8; 32]), &message).unwrap();
+        assert_eq!(instruction.data[..8], discriminator("global", "process_message"));
+        assert_eq!(instruction.accounts[2].pubkey, adapter.message_record(&message.message).unwrap());
+    }
+}
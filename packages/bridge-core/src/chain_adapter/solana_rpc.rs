@@ -0,0 +1,230 @@
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use crate::Error;
+use super::rate_limit::{reject_throttled, RateLimiter};
+
+/// 读取链上状态使用的确认级别
+const COMMITMENT: &str = "confirmed";
+
+/// Solana JSON-RPC 客户端
+///
+/// 只覆盖中继器用到的接口。
+pub struct SolanaRpcClient {
+    http: reqwest::Client,
+    url: String,
+    next_id: AtomicU64,
+    rate_limiter: Option<Arc<RateLimiter>>,
+}
+
+#[derive(Deserialize)]
+struct JsonRpcResponse<T> {
+    result: Option<T>,
+    error: Option<JsonRpcError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonRpcError {
+    code: i64,
+    message: String,
+}
+
+/// 带上下文的结果，只取 `value`
+#[derive(Deserialize)]
+struct Contextual<T> {
+    value: T,
+}
+
+/// `getSignaturesForAddress` 返回的交易签名，按时间从新到旧排列
+#[derive(Debug, Clone, Deserialize)]
+pub struct SignatureInfo {
+    pub signature: String,
+    pub slot: u64,
+    /// 交易执行失败时的错误
+    #[serde(default)]
+    pub err: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionMeta {
+    #[serde(default)]
+    pub err: Option<serde_json::Value>,
+    /// 交易费用（lamports）
+    pub fee: u64,
+    #[serde(default)]
+    pub log_messages: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TransactionView {
+    pub slot: u64,
+    #[serde(default)]
+    pub meta: Option<TransactionMeta>,
+}
+
+/// 账户信息，数据以 `[base64 内容, "base64"]` 返回
+#[derive(Debug, Clone, Deserialize)]
+pub struct AccountView {
+    pub lamports: u64,
+    pub data: (String, String),
+    pub owner: String,
+    pub executable: bool,
+}
+
+impl AccountView {
+    pub fn decode_data(&self) -> Result<Vec<u8>, Error> {
+        BASE64
+            .decode(&self.data.0)
+            .map_err(|e| Error::Serialization(format!("Invalid account data: {}", e)))
+    }
+}
+
+/// 交易的确认状态，`confirmationStatus` 为 `processed`、`confirmed` 或 `finalized`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignatureStatus {
+    #[serde(default)]
+    pub err: Option<serde_json::Value>,
+    #[serde(default)]
+    pub confirmation_status: Option<String>,
+}
+
+impl SignatureStatus {
+    pub fn is_confirmed(&self) -> bool {
+        matches!(self.confirmation_status.as_deref(), Some("confirmed" | "finalized"))
+    }
+}
+
+impl SolanaRpcClient {
+    pub fn new(url: &str) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            url: url.to_string(),
+            next_id: AtomicU64::new(1),
+            rate_limiter: None,
+        }
+    }
+
+    /// 每次调用前先从限速器取得令牌
+    pub fn with_rate_limiter(mut self, rate_limiter: Option<Arc<RateLimiter>>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// 发起 JSON-RPC 调用，结果为 null 时返回 None，RPC 错误转换为 `Error::Rpc`
+    async fn call_optional<P: Serialize, R: DeserializeOwned>(&self, method: &str, params: P) -> Result<Option<R>, Error> {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let response = self.http
+            .post(&self.url)
+            .json(&serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": method,
+                "params": params,
+                "id": id
+            }))
+            .send()
+            .await
+            .map_err(|e| Error::Network(e.to_string()))?;
+        let response: JsonRpcResponse<R> = reject_throttled(response, method)?
+            .json()
+            .await
+            .map_err(|e| Error::Chain(format!("Invalid {} response: {}", method, e)))?;
+
+        if let Some(error) = response.error {
+            return Err(Error::Rpc {
+                method: method.to_string(),
+                code: error.code,
+                message: error.message,
+            });
+        }
+        Ok(response.result)
+    }
+
+    async fn call<P: Serialize, R: DeserializeOwned>(&self, method: &str, params: P) -> Result<R, Error> {
+        self.call_optional(method, params)
+            .await?
+            .ok_or_else(|| Error::Chain(format!("Empty {} response", method)))
+    }
+
+    /// 节点的 `solana-core` 版本
+    pub async fn get_version(&self) -> Result<String, Error> {
+        #[derive(Deserialize)]
+        struct VersionView {
+            #[serde(rename = "solana-core")]
+            solana_core: String,
+        }
+        let version: VersionView = self.call("getVersion", serde_json::json!([])).await?;
+        Ok(version.solana_core)
+    }
+
+    /// 查询涉及某地址的交易签名，从 `before` 之前（不含）向更早读取，读到 `until` 为止（不含）
+    pub async fn get_signatures_for_address(
+        &self,
+        address: &str,
+        before: Option<&str>,
+        until: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<SignatureInfo>, Error> {
+        self.call(
+            "getSignaturesForAddress",
+            serde_json::json!([address, {
+                "before": before,
+                "until": until,
+                "limit": limit,
+                "commitment": COMMITMENT
+            }]),
+        )
+        .await
+    }
+
+    /// 按签名查询交易，尚未确认时返回 None
+    pub async fn get_transaction(&self, signature: &str) -> Result<Option<TransactionView>, Error> {
+        self.call_optional(
+            "getTransaction",
+            serde_json::json!([signature, {
+                "encoding": "json",
+                "commitment": COMMITMENT,
+                "maxSupportedTransactionVersion": 0
+            }]),
+        )
+        .await
+    }
+
+    /// 查询账户，账户不存在时返回 None
+    pub async fn get_account_info(&self, address: &str) -> Result<Option<AccountView>, Error> {
+        let account: Contextual<Option<AccountView>> = self
+            .call("getAccountInfo", serde_json::json!([address, { "encoding": "base64", "commitment": COMMITMENT }]))
+            .await?;
+        Ok(account.value)
+    }
+
+    /// 账户余额（lamports）
+    pub async fn get_balance(&self, address: &str) -> Result<u64, Error> {
+        let balance: Contextual<u64> = self
+            .call("getBalance", serde_json::json!([address, { "commitment": COMMITMENT }]))
+            .await?;
+        Ok(balance.value)
+    }
+
+    /// 广播已签名交易，返回交易签名
+    pub async fn send_transaction(&self, tx_base64: &str) -> Result<String, Error> {
+        self.call(
+            "sendTransaction",
+            serde_json::json!([tx_base64, { "encoding": "base64", "preflightCommitment": COMMITMENT }]),
+        )
+        .await
+    }
+
+    /// 查询交易确认状态，节点尚未见到该交易时返回 None
+    pub async fn get_signature_status(&self, signature: &str) -> Result<Option<SignatureStatus>, Error> {
+        let mut statuses: Contextual<Vec<Option<SignatureStatus>>> = self
+            .call("getSignatureStatuses", serde_json::json!([[signature]]))
+            .await?;
+        Ok(statuses.value.pop().flatten())
+    }
+}
@@ -0,0 +1,368 @@
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use ed25519_dalek::{Keypair, Signer};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::path::Path;
+use crate::Error;
+
+/// 程序派生地址哈希的后缀
+const PDA_MARKER: &[u8] = b"ProgramDerivedAddress";
+/// 系统程序 `AdvanceNonceAccount` 指令的序号
+const ADVANCE_NONCE_ACCOUNT_INDEX: u32 = 4;
+/// 计算预算程序 `SetComputeUnitPrice` 指令的序号
+const SET_COMPUTE_UNIT_PRICE_INDEX: u8 = 3;
+/// 已初始化的 nonce 账户数据长度：版本、状态、授权账户、nonce 值与费用
+const NONCE_ACCOUNT_LENGTH: usize = 80;
+
+/// Solana 账户地址，base58 编码的 32 字节公钥或程序派生地址
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pubkey(pub [u8; 32]);
+
+impl Pubkey {
+    pub fn parse(address: &str) -> Result<Self, Error> {
+        let bytes = bs58::decode(address)
+            .into_vec()
+            .map_err(|e| Error::Config(format!("Invalid Solana address {}: {}", address, e)))?;
+        let bytes: [u8; 32] = bytes
+            .try_into()
+            .map_err(|_| Error::Config(format!("Solana address {} is not 32 bytes", address)))?;
+        Ok(Self(bytes))
+    }
+
+    /// 按种子查找程序派生地址，从 bump 255 开始取第一个不在 ed25519 曲线上的地址
+    pub fn find_program_address(seeds: &[&[u8]], program_id: &Pubkey) -> Result<(Pubkey, u8), Error> {
+        for bump in (0..=u8::MAX).rev() {
+            let mut hasher = Sha256::new();
+            for seed in seeds {
+                hasher.update(seed);
+            }
+            hasher.update([bump]);
+            hasher.update(program_id.0);
+            hasher.update(PDA_MARKER);
+            let hash: [u8; 32] = hasher.finalize().into();
+            // 派生地址不能有对应的私钥
+            if ed25519_dalek::PublicKey::from_bytes(&hash).is_err() {
+                return Ok((Pubkey(hash), bump));
+            }
+        }
+        Err(Error::Chain(format!("No program address found for program {}", program_id)))
+    }
+}
+
+impl fmt::Display for Pubkey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&bs58::encode(self.0).into_string())
+    }
+}
+
+/// 系统程序
+pub fn system_program() -> Pubkey {
+    Pubkey([0u8; 32])
+}
+
+/// `AdvanceNonceAccount` 要求传入的最近区块哈希系统变量
+fn recent_blockhashes_sysvar() -> Pubkey {
+    Pubkey::parse("SysvarRecentB1ockHashes11111111111111111111").expect("valid sysvar address")
+}
+
+fn compute_budget_program() -> Pubkey {
+    Pubkey::parse("ComputeBudget111111111111111111111111111111").expect("valid program address")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl AccountMeta {
+    pub fn writable(pubkey: Pubkey, is_signer: bool) -> Self {
+        Self { pubkey, is_signer, is_writable: true }
+    }
+
+    pub fn readonly(pubkey: Pubkey, is_signer: bool) -> Self {
+        Self { pubkey, is_signer, is_writable: false }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Instruction {
+    pub program_id: Pubkey,
+    pub accounts: Vec<AccountMeta>,
+    pub data: Vec<u8>,
+}
+
+/// 推进 nonce 账户，使用 durable nonce 的交易必须以此为第一条指令
+pub fn advance_nonce_account(nonce_account: Pubkey, authority: Pubkey) -> Instruction {
+    Instruction {
+        program_id: system_program(),
+        accounts: vec![
+            AccountMeta::writable(nonce_account, false),
+            AccountMeta::readonly(recent_blockhashes_sysvar(), false),
+            AccountMeta::readonly(authority, true),
+        ],
+        data: ADVANCE_NONCE_ACCOUNT_INDEX.to_le_bytes().to_vec(),
+    }
+}
+
+/// 设置优先费，单位为每计算单元的 micro-lamports
+pub fn set_compute_unit_price(micro_lamports: u64) -> Instruction {
+    let mut data = vec![SET_COMPUTE_UNIT_PRICE_INDEX];
+    data.extend_from_slice(&micro_lamports.to_le_bytes());
+    Instruction {
+        program_id: compute_budget_program(),
+        accounts: vec![],
+        data,
+    }
+}
+
+/// 从 nonce 账户数据中读取当前的 durable nonce，用作交易的区块哈希
+pub fn parse_nonce_account(data: &[u8]) -> Result<[u8; 32], Error> {
+    if data.len() < NONCE_ACCOUNT_LENGTH {
+        return Err(Error::Chain(format!("Nonce account data is {} bytes", data.len())));
+    }
+    // 版本(u32) || 状态(u32) || 授权账户 || nonce 值 || 每签名费用(u64)
+    if data[4..8] != 1u32.to_le_bytes() {
+        return Err(Error::Chain("Nonce account is not initialized".to_string()));
+    }
+    Ok(data[40..72].try_into().expect("slice of 32 bytes"))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct CompiledInstruction {
+    program_id_index: u8,
+    accounts: Vec<u8>,
+    data: Vec<u8>,
+}
+
+/// 待签名的 legacy 交易消息
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    /// 签名账户数、只读签名账户数、只读非签名账户数
+    header: [u8; 3],
+    account_keys: Vec<Pubkey>,
+    recent_blockhash: [u8; 32],
+    instructions: Vec<CompiledInstruction>,
+}
+
+impl Message {
+    /// 编译指令，账户按 可写签名、只读签名、可写非签名、只读非签名 排序，付费账户在最前
+    pub fn new(instructions: &[Instruction], payer: &Pubkey, recent_blockhash: [u8; 32]) -> Self {
+        let mut metas = vec![AccountMeta::writable(*payer, true)];
+        for instruction in instructions {
+            for meta in instruction.accounts.iter().cloned().chain([AccountMeta::readonly(instruction.program_id, false)]) {
+                match metas.iter_mut().find(|m| m.pubkey == meta.pubkey) {
+                    Some(existing) => {
+                        existing.is_signer |= meta.is_signer;
+                        existing.is_writable |= meta.is_writable;
+                    }
+                    None => metas.push(meta),
+                }
+            }
+        }
+        // 稳定排序保持付费账户在最前
+        metas.sort_by_key(|m| (!m.is_signer, !m.is_writable));
+
+        let count = |f: fn(&AccountMeta) -> bool| metas.iter().filter(|m| f(m)).count() as u8;
+        let header = [
+            count(|m| m.is_signer),
+            count(|m| m.is_signer && !m.is_writable),
+            count(|m| !m.is_signer && !m.is_writable),
+        ];
+        let account_keys: Vec<Pubkey> = metas.iter().map(|m| m.pubkey).collect();
+        let index = |key: &Pubkey| account_keys.iter().position(|k| k == key).expect("account compiled") as u8;
+        let instructions = instructions
+            .iter()
+            .map(|instruction| CompiledInstruction {
+                program_id_index: index(&instruction.program_id),
+                accounts: instruction.accounts.iter().map(|m| index(&m.pubkey)).collect(),
+                data: instruction.data.clone(),
+            })
+            .collect();
+
+        Self { header, account_keys, recent_blockhash, instructions }
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut out = self.header.to_vec();
+        encode_length(&mut out, self.account_keys.len());
+        for key in &self.account_keys {
+            out.extend_from_slice(&key.0);
+        }
+        out.extend_from_slice(&self.recent_blockhash);
+        encode_length(&mut out, self.instructions.len());
+        for instruction in &self.instructions {
+            out.push(instruction.program_id_index);
+            encode_length(&mut out, instruction.accounts.len());
+            out.extend_from_slice(&instruction.accounts);
+            encode_length(&mut out, instruction.data.len());
+            out.extend_from_slice(&instruction.data);
+        }
+        out
+    }
+}
+
+/// 已签名交易，中继器是唯一的签名者
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transaction {
+    pub signature: [u8; 64],
+    pub message: Message,
+}
+
+impl Transaction {
+    /// 交易 ID：签名的 base58 编码
+    pub fn signature(&self) -> String {
+        bs58::encode(self.signature).into_string()
+    }
+
+    /// 编码为 `sendTransaction` 接受的 base64 字符串
+    pub fn encode(&self) -> String {
+        let mut out = Vec::new();
+        encode_length(&mut out, 1);
+        out.extend_from_slice(&self.signature);
+        out.extend(self.message.serialize());
+        BASE64.encode(out)
+    }
+}
+
+/// 中继器在 Solana 上的付费账户，同时是 nonce 账户的授权账户
+pub struct SolanaSigner {
+    keypair: Keypair,
+}
+
+impl SolanaSigner {
+    /// 从 64 字节的 `私钥 || 公钥` 创建
+    pub fn from_keypair_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let keypair = Keypair::from_bytes(bytes)
+            .map_err(|e| Error::Key(format!("Invalid Solana keypair: {}", e)))?;
+        Ok(Self { keypair })
+    }
+
+    /// 从 `solana-keygen` 生成的密钥文件加载，文件内容为 64 个字节的 JSON 数组
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| Error::Key(format!("Failed to read Solana keypair: {}", e)))?;
+        let bytes: Vec<u8> = serde_json::from_str(&content)
+            .map_err(|e| Error::Key(format!("Invalid Solana keypair file: {}", e)))?;
+        Self::from_keypair_bytes(&bytes)
+    }
+
+    pub fn pubkey(&self) -> Pubkey {
+        Pubkey(self.keypair.public.to_bytes())
+    }
+
+    /// 对序列化的消息签名
+    pub fn sign(&self, message: Message) -> Transaction {
+        let signature = self.keypair.sign(&message.serialize()).to_bytes();
+        Transaction { signature, message }
+    }
+}
+
+/// 按 compact-u16 编码长度：每字节 7 位，最高位表示后面还有字节
+fn encode_length(out: &mut Vec<u8>, mut len: usize) {
+    loop {
+        let byte = (len & 0x7f) as u8;
+        len >>= 7;
+        if len == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+/// 按 Borsh 写入字节数组：u32 小端长度 || 内容
+pub fn write_borsh_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+    out.extend_from_slice(bytes);
+}
+
+/// 按 Borsh 布局顺序读取程序事件与账户数据
+pub struct BorshReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> BorshReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if self.data.len() < len {
+            return Err(Error::Serialization(format!("Expected {} more bytes, got {}", len, self.data.len())));
+        }
+        let (head, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(head)
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, Error> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64, Error> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().expect("slice of 8 bytes")))
+    }
+
+    pub fn read_array<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        Ok(self.take(N)?.try_into().expect("slice of N bytes"))
+    }
+
+    pub fn read_bytes(&mut self) -> Result<Vec<u8>, Error> {
+        let len = u32::from_le_bytes(self.read_array()?) as usize;
+        Ok(self.take(len)?.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{PublicKey, Signature, Verifier};
+
+    #[test]
+    fn test_sign_with_durable_nonce() {
+        let secret = ed25519_dalek::SecretKey::from_bytes(&[9u8; 32]).unwrap();
+        let public = PublicKey::from(&secret);
+        let mut keypair_bytes = secret.to_bytes().to_vec();
+        keypair_bytes.extend_from_slice(public.as_bytes());
+        let signer = SolanaSigner::from_keypair_bytes(&keypair_bytes).unwrap();
+
+        let program = Pubkey([7u8; 32]);
+        let nonce_account = Pubkey([3u8; 32]);
+        let (state, _) = Pubkey::find_program_address(&[b"state"], &program).unwrap();
+        assert!(PublicKey::from_bytes(&state.0).is_err());
+        assert_eq!(Pubkey::parse(&state.to_string()).unwrap(), state);
+
+        let mut nonce_data = vec![0u8; NONCE_ACCOUNT_LENGTH];
+        nonce_data[4] = 1;
+        nonce_data[40..72].copy_from_slice(&[5u8; 32]);
+        let blockhash = parse_nonce_account(&nonce_data).unwrap();
+
+        let instructions = [
+            advance_nonce_account(nonce_account, signer.pubkey()),
+            Instruction {
+                program_id: program,
+                accounts: vec![AccountMeta::writable(signer.pubkey(), true), AccountMeta::writable(state, false)],
+                data: vec![0xab; 200],
+            },
+        ];
+        let tx = signer.sign(Message::new(&instructions, &signer.pubkey(), blockhash));
+
+        // 付费账户兼授权账户只签名一次；nonce 账户与状态账户可写，系统变量与两个程序只读
+        assert_eq!(tx.message.header, [1, 0, 3]);
+        assert_eq!(tx.message.account_keys[0], signer.pubkey());
+        assert_eq!(tx.message.account_keys.len(), 6);
+        assert_eq!(tx.message.instructions[0].data, [4, 0, 0, 0]);
+        assert_eq!(tx.message.account_keys[tx.message.instructions[0].program_id_index as usize], system_program());
+
+        let bytes = tx.message.serialize();
+        assert_eq!(bytes[3], 6);
+        assert_eq!(bytes[4 + 6 * 32..4 + 7 * 32], [5u8; 32]);
+        // 长度 200 的 compact-u16 编码占两个字节
+        assert!(bytes.windows(2).any(|w| w == [0xc8, 0x01]));
+        let signature = Signature::from_bytes(&tx.signature).unwrap();
+        assert!(public.verify(&bytes, &signature).is_ok());
+        assert_eq!(BASE64.decode(tx.encode()).unwrap()[0], 1);
+    }
+}
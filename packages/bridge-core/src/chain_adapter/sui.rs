@@ -1,19 +1,27 @@
 use async_trait::async_trait;
+use tracing::warn;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
 use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::{Mutex, RwLock};
 use sui_sdk::{
     SuiClient, SuiClientBuilder,
     rpc_types::{
+        CheckpointId,
         SuiTransactionBlockResponseOptions,
         SuiEvent,
         EventFilter,
         SuiExecutionStatus,
         SuiTransactionBlockEffects,
         SuiTransactionBlockEffectsAPI,
+        SuiParsedData,
+        SuiObjectDataOptions,
     },
     types::{
         base_types::{ObjectID, TransactionDigest},
         programmable_transaction_builder::ProgrammableTransactionBuilder,
-        transaction::{Argument, CallArg, Command, ProgrammableMoveCall},
+        transaction::{CallArg, Command, ProgrammableMoveCall},
     },
 };
 use sui_types::{
@@ -23,82 +31,367 @@ use sui_types::{
     message_envelope::Envelope,
     transaction::SenderSignedData,
     gas_coin::GasCoin,
+    full_checkpoint_content::CheckpointData,
+    dynamic_field::DynamicFieldName,
+    parse_sui_struct_tag,
 };
 use shared_crypto::intent::Intent;
-use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::{StructTag, TypeTag};
 use sui_json_rpc_types::BcsEvent;
 
-use crate::types::{SignedMessage, MessageStatus, CrossChainMessage};
-use crate::chain_adapter::ChainAdapter;
-use crate::config::ChainConfig;
+use crate::types::{ChainId, Delivery, EventProvenance, MetadataSync, SignedMessage, MessageStatus, CrossChainMessage, ValidatorRegistration};
+use crate::chain_adapter::{check_address_format, AdapterCapabilities, ChainAdapter, ContractCheck, RateLimiter};
+use crate::chain_adapter::checkpoint::CheckpointCursor;
+use crate::chain_adapter::sui_abort;
+use crate::codegen::{self, MoveModule};
+use crate::config::{ChainConfig, LightClientConfig, SuiIngestion};
+use crate::proof::{sui::{CertifiedSummary, CheckpointProof}, InclusionProof};
+use crate::relayer::{equivocation::EquivocationEvidence, rotation::ValidatorSet};
+use crate::watchtower::Challenge;
 use crate::Error as BridgeError;
 
+/// `sui_bridge::message::MessageExecutedEvent` 的 BCS 布局
+#[derive(Debug, Deserialize)]
+struct MessageExecutedEvent {
+    message_id: Vec<u8>,
+    message_type: u8,
+    source_chain: u64,
+    target_chain: u64,
+    nonce: u64,
+}
+
 pub struct SuiAdapter {
-    client: SuiClient,
+    /// `connect` 之后可用，`close` 时释放
+    client: RwLock<Option<SuiClient>>,
     config: ChainConfig,
+    /// 合约中的数字链 ID 到配置链 ID 的映射
+    chain_ids: HashMap<u64, ChainId>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    /// 检查点模式下的读取进度
+    checkpoints: Mutex<CheckpointCursor>,
+    /// 已读取的各纪元最后一个检查点，用于在包含证明中附带委员会切换
+    epoch_changes: Mutex<BTreeMap<u64, CertifiedSummary>>,
 }
 
+/// 节点单次最多返回 50 笔交易
+const MAX_MULTI_GET: usize = 50;
+
+/// 事件过滤器只写结构体名时，事件所在的模块
+const DEFAULT_EVENT_MODULE: &str = "bridge";
+
+/// 检查点模式下读取进度在事件游标中的名称
+const CHECKPOINT_CURSOR: &str = "checkpoint";
+
 impl SuiAdapter {
-    pub async fn new(config: ChainConfig) -> Result<Self, BridgeError> {
-        let client = SuiClientBuilder::default()
-            .build(config.rpc_url.as_str())
+    pub fn new(config: ChainConfig) -> Self {
+        let rate_limiter = RateLimiter::for_chain(&config);
+        let checkpoints = match &config.sui {
+            Some(sui) => CheckpointCursor::new(sui.start_checkpoint, sui.checkpoints_per_poll),
+            None => CheckpointCursor::new(None, 1),
+        };
+        Self {
+            client: RwLock::new(None),
+            config,
+            chain_ids: HashMap::new(),
+            rate_limiter,
+            checkpoints: Mutex::new(checkpoints),
+            epoch_changes: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    fn ingestion(&self) -> SuiIngestion {
+        self.config.sui.as_ref().map(|sui| sui.ingestion).unwrap_or_default()
+    }
+
+    /// 获取已建立的客户端，未连接时返回错误
+    ///
+    /// 每次取得客户端消耗一个限速令牌，取得的客户端只应发起一次请求。
+    async fn client(&self) -> Result<SuiClient, BridgeError> {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+        self.client
+            .read()
             .await
-            .map_err(|e| BridgeError::Chain(e.to_string()))?;
-        
-        Ok(Self { client, config })
+            .clone()
+            .ok_or_else(|| BridgeError::Network(format!("Sui adapter for {} is not connected", self.config.id)))
     }
 
-    pub async fn send_message(&self, message: &SignedMessage) -> Result<TransactionDigest, BridgeError> {
+    /// 设置数字链 ID 映射，用于把事件中的链 ID 转换为配置中的链 ID
+    pub fn with_chain_ids(mut self, chain_ids: HashMap<u64, ChainId>) -> Self {
+        self.chain_ids = chain_ids;
+        self
+    }
+
+    fn chain_name(&self, chain_id: u64) -> Result<ChainId, BridgeError> {
+        self.chain_ids
+            .get(&chain_id)
+            .cloned()
+            .ok_or_else(|| BridgeError::Config(format!("Unknown numeric chain ID in Sui event: {}", chain_id)))
+    }
+
+    /// 配置链 ID 对应的合约数字链 ID
+    fn numeric_chain_id(&self, chain: &ChainId) -> Result<u64, BridgeError> {
+        self.chain_ids
+            .iter()
+            .find(|(_, id)| *id == chain)
+            .map(|(numeric, _)| *numeric)
+            .ok_or_else(|| BridgeError::Config(format!("No numeric chain ID configured for {}", chain)))
+    }
+
+    /// 将 `MessageExecutedEvent` 解码为交付记录，该事件不包含接收者与金额
+    fn parse_delivery(&self, event: &SuiEvent) -> Result<Delivery, BridgeError> {
+        let data: MessageExecutedEvent = bcs::from_bytes(event.bcs.bytes())
+            .map_err(|e| BridgeError::Serialization(e.to_string()))?;
+        Ok(Delivery {
+            event_id: format!("{}:{}", event.id.tx_digest, event.id.event_seq),
+            source_chain: self.chain_name(data.source_chain)?,
+            target_chain: self.chain_name(data.target_chain)?,
+            nonce: Some(data.nonce),
+            recipient: None,
+            amount: None,
+            tx_hash: Some(event.id.tx_digest.to_string()),
+            message_id: None,
+        })
+    }
+
+    /// 构造调用 `bridge::process_message` 的交易
+    fn build_transaction(&self, message: &SignedMessage) -> Result<TransactionData, BridgeError> {
+        self.build_batch_transaction(std::slice::from_ref(message))
+    }
+
+    /// 将每条消息构造为一次 `process_message` 调用，按顺序放入同一个可编程交易块；
+    /// 每次调用的参数为消息的规范编码、签名与源链消息 ID，配置报销时改用 `process_message_with_payout` 并附带收款地址
+    fn build_batch_transaction(&self, messages: &[SignedMessage]) -> Result<TransactionData, BridgeError> {
         let package = ObjectID::from_hex_literal(&self.config.bridge_address)
             .map_err(|e| BridgeError::Chain(e.to_string()))?;
         let sender = SuiAddress::from_str(&self.config.id)
             .map_err(|e| BridgeError::Chain(e.to_string()))?;
         let mut builder = ProgrammableTransactionBuilder::new();
-        
-        // 添加参数
-        builder.input(CallArg::Pure(bcs::to_bytes(&message.message)
-            .map_err(|e| BridgeError::Serialization(e.to_string()))?))
+        let payout = match &self.config.reimbursement {
+            Some(reimbursement) => {
+                let address = SuiAddress::from_str(&reimbursement.payout_address)
+                    .map_err(|e| BridgeError::Config(format!("Invalid payout address: {}", e)))?;
+                Some(builder.input(CallArg::Pure(bcs::to_bytes(&address)
+                    .map_err(|e| BridgeError::Serialization(e.to_string()))?))
+                    .map_err(|e| BridgeError::Chain(e.to_string()))?)
+            }
+            None => None,
+        };
+
+        for message in messages {
+            // 添加参数
+            let message_arg = builder.input(CallArg::Pure(bcs::to_bytes(&message.message.canonical_bytes(&self.chain_ids)?)
+                .map_err(|e| BridgeError::Serialization(e.to_string()))?))
+                .map_err(|e| BridgeError::Chain(e.to_string()))?;
+            let signature_arg = builder.input(CallArg::Pure(bcs::to_bytes(&message.signature)
+                .map_err(|e| BridgeError::Serialization(e.to_string()))?))
+                .map_err(|e| BridgeError::Chain(e.to_string()))?;
+            // 源链消息 ID，供浏览器与索引器把交易关联回源链消息
+            let trace_arg = builder.input(CallArg::Pure(bcs::to_bytes(&message.trace_id(&self.chain_ids)?)
+                .map_err(|e| BridgeError::Serialization(e.to_string()))?))
+                .map_err(|e| BridgeError::Chain(e.to_string()))?;
+
+            // 添加 Move 调用
+            let mut arguments = vec![message_arg, signature_arg, trace_arg];
+            arguments.extend(payout);
+            builder.command(Command::MoveCall(Box::new(ProgrammableMoveCall {
+                package,
+                module: "bridge".to_string(),
+                function: if payout.is_some() { "process_message_with_payout" } else { "process_message" }.to_string(),
+                type_arguments: vec![],
+                arguments,
+            })));
+        }
+
+        let pt = builder.finish();
+        Ok(TransactionData::new_programmable(
+            sender,
+            vec![],
+            pt,
+            1000u64 * messages.len() as u64,
+            1000u64,
+        ))
+    }
+
+    /// 构造调用 `bridge::challenge_delivery` 的交易：源链、交付的 nonce（可缺失）、理由编码、交付事件 ID、应交付消息的规范编码
+    fn build_challenge_transaction(&self, challenge: &Challenge) -> Result<TransactionData, BridgeError> {
+        let package = ObjectID::from_hex_literal(&self.config.bridge_address)
             .map_err(|e| BridgeError::Chain(e.to_string()))?;
-        builder.input(CallArg::Pure(bcs::to_bytes(&message.signature)
-            .map_err(|e| BridgeError::Serialization(e.to_string()))?))
+        let sender = SuiAddress::from_str(&self.config.id)
             .map_err(|e| BridgeError::Chain(e.to_string()))?;
+        let divergence = &challenge.divergence;
+        let delivery = divergence.delivery.clone().unwrap_or_default().into_bytes();
+        let pure = [
+            bcs::to_bytes(&self.numeric_chain_id(&divergence.source_chain)?),
+            bcs::to_bytes(&divergence.nonce),
+            bcs::to_bytes(&challenge.reason),
+            bcs::to_bytes(&delivery),
+            bcs::to_bytes(&challenge.evidence(&self.chain_ids)?),
+        ];
 
-        // 添加 Move 调用
+        let mut builder = ProgrammableTransactionBuilder::new();
+        let mut arguments = Vec::with_capacity(pure.len());
+        for bytes in pure {
+            let bytes = bytes.map_err(|e| BridgeError::Serialization(e.to_string()))?;
+            arguments.push(builder.input(CallArg::Pure(bytes)).map_err(|e| BridgeError::Chain(e.to_string()))?);
+        }
         builder.command(Command::MoveCall(Box::new(ProgrammableMoveCall {
             package,
             module: "bridge".to_string(),
-            function: "process_message".to_string(),
+            function: "challenge_delivery".to_string(),
             type_arguments: vec![],
-            arguments: vec![Argument::Input(0), Argument::Input(1)],
+            arguments,
         })));
 
-        let pt = builder.finish();
-        let tx_data = TransactionData::new_programmable(
-            sender,
-            vec![],
-            pt,
-            1000u64,
-            1000u64,
-        );
-        
+        Ok(TransactionData::new_programmable(sender, vec![], builder.finish(), 1000u64, 1000u64))
+    }
+
+    /// 构造调用 `validator::slash_equivocation` 的交易：两条证明各自的消息规范编码、签名与签名公钥
+    fn build_equivocation_transaction(&self, evidence: &EquivocationEvidence) -> Result<TransactionData, BridgeError> {
+        let package = ObjectID::from_hex_literal(&self.config.bridge_address)
+            .map_err(|e| BridgeError::Chain(e.to_string()))?;
+        let sender = SuiAddress::from_str(&self.config.id)
+            .map_err(|e| BridgeError::Chain(e.to_string()))?;
+        let mut pure = Vec::with_capacity(6);
+        for attestation in [&evidence.first, &evidence.second] {
+            let public_key = hex::decode(attestation.public_key.trim_start_matches("0x"))
+                .map_err(|e| BridgeError::Key(format!("Invalid public key for {}: {}", attestation.validator, e)))?;
+            pure.push(bcs::to_bytes(&attestation.message.canonical_bytes(&self.chain_ids)?));
+            pure.push(bcs::to_bytes(&attestation.signature));
+            pure.push(bcs::to_bytes(&public_key));
+        }
+
+        let mut builder = ProgrammableTransactionBuilder::new();
+        let mut arguments = Vec::with_capacity(pure.len());
+        for bytes in pure {
+            let bytes = bytes.map_err(|e| BridgeError::Serialization(e.to_string()))?;
+            arguments.push(builder.input(CallArg::Pure(bytes)).map_err(|e| BridgeError::Chain(e.to_string()))?);
+        }
+        builder.command(Command::MoveCall(Box::new(ProgrammableMoveCall {
+            package,
+            module: "validator".to_string(),
+            function: "slash_equivocation".to_string(),
+            type_arguments: vec![],
+            arguments,
+        })));
+
+        Ok(TransactionData::new_programmable(sender, vec![], builder.finish(), 1000u64, 1000u64))
+    }
+
+    /// 构造调用 `validator::update_validator_set` 的交易：公钥与权重按下标对应，最后为签名阈值
+    fn build_validator_set_transaction(&self, set: &ValidatorSet) -> Result<TransactionData, BridgeError> {
+        let package = ObjectID::from_hex_literal(&self.config.bridge_address)
+            .map_err(|e| BridgeError::Chain(e.to_string()))?;
+        let sender = SuiAddress::from_str(&self.config.id)
+            .map_err(|e| BridgeError::Chain(e.to_string()))?;
+        let pure = [
+            bcs::to_bytes(&set.public_keys()?),
+            bcs::to_bytes(&set.weights()),
+            bcs::to_bytes(&set.threshold()),
+        ];
+
+        let mut builder = ProgrammableTransactionBuilder::new();
+        let mut arguments = Vec::with_capacity(pure.len());
+        for bytes in pure {
+            let bytes = bytes.map_err(|e| BridgeError::Serialization(e.to_string()))?;
+            arguments.push(builder.input(CallArg::Pure(bytes)).map_err(|e| BridgeError::Chain(e.to_string()))?);
+        }
+        builder.command(Command::MoveCall(Box::new(ProgrammableMoveCall {
+            package,
+            module: "validator".to_string(),
+            function: "update_validator_set".to_string(),
+            type_arguments: vec![],
+            arguments,
+        })));
+
+        Ok(TransactionData::new_programmable(sender, vec![], builder.finish(), 1000u64, 1000u64))
+    }
+
+    /// 构造调用 `asset::register_wrapped_asset` 的交易：名称、符号、精度、原生链资产类型、本链包装代币类型
+    fn build_register_asset_transaction(&self, metadata: &MetadataSync) -> Result<TransactionData, BridgeError> {
+        let package = ObjectID::from_hex_literal(&self.config.bridge_address)
+            .map_err(|e| BridgeError::Chain(e.to_string()))?;
+        let sender = SuiAddress::from_str(&self.config.id)
+            .map_err(|e| BridgeError::Chain(e.to_string()))?;
+        let pure = [
+            bcs::to_bytes(metadata.name.as_bytes()),
+            bcs::to_bytes(metadata.symbol.as_bytes()),
+            bcs::to_bytes(&metadata.decimals),
+            bcs::to_bytes(metadata.native_type.as_bytes()),
+            bcs::to_bytes(metadata.target_type.as_bytes()),
+        ];
+
+        let mut builder = ProgrammableTransactionBuilder::new();
+        let mut arguments = Vec::with_capacity(pure.len());
+        for bytes in pure {
+            let bytes = bytes.map_err(|e| BridgeError::Serialization(e.to_string()))?;
+            arguments.push(builder.input(CallArg::Pure(bytes)).map_err(|e| BridgeError::Chain(e.to_string()))?);
+        }
+        builder.command(Command::MoveCall(Box::new(ProgrammableMoveCall {
+            package,
+            module: "asset".to_string(),
+            function: "register_wrapped_asset".to_string(),
+            type_arguments: vec![],
+            arguments,
+        })));
+
+        Ok(TransactionData::new_programmable(sender, vec![], builder.finish(), 1000u64, 1000u64))
+    }
+
+    pub async fn send_message(&self, message: &SignedMessage) -> Result<TransactionDigest, BridgeError> {
+        let tx_data = self.build_transaction(message)?;
+        self.preflight(&tx_data).await?;
+        self.execute(tx_data).await
+    }
+
+    /// 在一笔交易中提交多条消息
+    pub async fn send_messages(&self, messages: &[SignedMessage]) -> Result<TransactionDigest, BridgeError> {
+        let tx_data = self.build_batch_transaction(messages)?;
+        self.preflight(&tx_data).await?;
+        self.execute(tx_data).await
+    }
+
+    /// 提交前试运行交易，会中止的交易不再提交以免消耗 gas
+    ///
+    /// 桥合约的已知中止码转换为 `AlreadyProcessed`、`InvalidSignature`、`Paused`，其它中止为 `MoveAbort`。
+    async fn preflight(&self, tx_data: &TransactionData) -> Result<(), BridgeError> {
+        let response = self.client().await?
+            .read_api()
+            .dry_run_transaction_block(tx_data.clone())
+            .await
+            .map_err(|e| BridgeError::Chain(e.to_string()))?;
+        match response.effects.status() {
+            SuiExecutionStatus::Success => Ok(()),
+            SuiExecutionStatus::Failure { error } => Err(sui_abort::dry_run_error(error, &self.config.bridge_address)),
+        }
+    }
+
+    async fn execute(&self, tx_data: TransactionData) -> Result<TransactionDigest, BridgeError> {
         let intent = Intent::sui_transaction();
         let signed_tx = Transaction::from_data(tx_data, vec![]);
-        
-        let response = self.client
+        let digest = *signed_tx.digest();
+        let timeout = self.config.stuck_timeout();
+
+        // 超时未取得执行结果时交易可能仍在排队，交由中继器重新提交
+        let client = self.client().await?;
+        let execution = client
             .quorum_driver_api()
             .execute_transaction_block(
                 signed_tx,
                 SuiTransactionBlockResponseOptions::new(),
                 None,
-            )
+            );
+        let response = tokio::time::timeout(std::time::Duration::from_secs(timeout), execution)
             .await
+            .map_err(|_| BridgeError::Stuck { tx_hash: digest.to_string(), timeout })?
             .map_err(|e| BridgeError::Chain(e.to_string()))?;
 
         Ok(response.digest)
     }
 
     pub async fn get_message_status(&self, digest: &TransactionDigest) -> Result<MessageStatus, BridgeError> {
-        let response = self.client
+        let response = self.client().await?
             .read_api()
             .get_transaction_with_options(
                 *digest,
@@ -117,9 +410,25 @@ impl SuiAdapter {
         }
     }
 
-    async fn parse_event(&self, event: &SuiEvent) -> Result<Option<SignedMessage>, BridgeError> {
-        let event_type = event.type_.to_string();
-        if event_type.contains("::bridge::MessageEvent") {
+    /// 按 `event_filters` 构造的事件类型及对应的过滤器名
+    fn event_types(&self) -> Result<Vec<(StructTag, String)>, BridgeError> {
+        self.config.event_filters
+            .iter()
+            .map(|filter| {
+                let event_type = filter.move_event_type(&self.config.bridge_address, DEFAULT_EVENT_MODULE);
+                parse_sui_struct_tag(&event_type)
+                    .map(|tag| (tag, filter.name.clone()))
+                    .map_err(|e| BridgeError::Config(format!("Invalid event filter {}: {}", filter.name, e)))
+            })
+            .collect()
+    }
+
+    /// 解码与任一事件过滤器匹配的事件，消息的事件名称为匹配的过滤器名；不匹配的事件返回 None
+    async fn parse_event(&self, event: &SuiEvent, event_types: &[(StructTag, String)]) -> Result<Option<SignedMessage>, BridgeError> {
+        let matched = event_types.iter().find(|(tag, _)| {
+            tag.address == event.type_.address && tag.module == event.type_.module && tag.name == event.type_.name
+        });
+        if let Some((_, filter_name)) = matched {
             let bcs_data = event.bcs.bytes();
             let message: CrossChainMessage = bcs::from_bytes(bcs_data)
                 .map_err(|e| BridgeError::Serialization(e.to_string()))?;
@@ -132,10 +441,111 @@ impl SuiAdapter {
                 message,
                 signature: vec![], // 从事件中获取签名
                 timestamp,
+                event_name: Some(filter_name.clone()),
+                sender: Some(event.sender.to_string()),
+                provenance: None,
+                proof: None,
+                trace_id: None,
             }));
         }
         Ok(None)
     }
+
+    /// 读取一个完整检查点中与事件过滤器匹配的事件，按交易在检查点中的顺序与事件序号排列
+    async fn checkpoint_events(&self, event_types: &[(StructTag, String)], sequence: u64) -> Result<Vec<SignedMessage>, BridgeError> {
+        let checkpoint = self.client().await?
+            .read_api()
+            .get_checkpoint(CheckpointId::SequenceNumber(sequence))
+            .await
+            .map_err(|e| BridgeError::Chain(format!("Failed to read checkpoint {}: {}", sequence, e)))?;
+        let mut messages = Vec::new();
+        for digests in checkpoint.transactions.chunks(MAX_MULTI_GET) {
+            let responses = self.client().await?
+                .read_api()
+                .multi_get_transactions_with_options(digests.to_vec(), SuiTransactionBlockResponseOptions::new().with_events())
+                .await
+                .map_err(|e| BridgeError::Chain(e.to_string()))?;
+            for event in responses.into_iter().filter_map(|r| r.events).flat_map(|events| events.data) {
+                if let Some(mut message) = self.parse_event(&event, event_types).await? {
+                    message.provenance = Some(EventProvenance {
+                        block: sequence,
+                        tx_hash: event.id.tx_digest.to_string(),
+                        event_index: event.id.event_seq,
+                    });
+                    messages.push(message);
+                }
+            }
+        }
+        Ok(messages)
+    }
+
+    /// 从检查点存储读取完整检查点，文件首字节为编码方式，其后为 BCS 编码的 `CheckpointData`
+    async fn checkpoint_data(&self, store: &str, sequence: u64) -> Result<CheckpointData, BridgeError> {
+        let url = format!("{}/{}.chk", store.trim_end_matches('/'), sequence);
+        let response = reqwest::get(&url).await.map_err(|e| BridgeError::Network(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(BridgeError::Network(format!("Failed to fetch checkpoint {}: {}", sequence, response.status())));
+        }
+        let bytes = response.bytes().await.map_err(|e| BridgeError::Network(e.to_string()))?;
+        let (_, data) = bytes
+            .split_first()
+            .ok_or_else(|| BridgeError::Serialization(format!("Empty checkpoint file {}", sequence)))?;
+        bcs::from_bytes(data).map_err(|e| BridgeError::Serialization(format!("Invalid checkpoint {}: {}", sequence, e)))
+    }
+
+    /// 纪元的最后一个检查点：二分查找 `upper` 之前纪元号不大于 `epoch` 的最大检查点
+    async fn epoch_change(&self, store: &str, epoch: u64, upper: u64) -> Result<CertifiedSummary, BridgeError> {
+        if let Some(summary) = self.epoch_changes.lock().await.get(&epoch) {
+            return Ok(summary.clone());
+        }
+        let (mut low, mut high) = (0, upper);
+        while low < high {
+            let middle = low + (high - low).div_ceil(2);
+            let checkpoint = self.client().await?
+                .read_api()
+                .get_checkpoint(CheckpointId::SequenceNumber(middle))
+                .await
+                .map_err(|e| BridgeError::Chain(format!("Failed to read checkpoint {}: {}", middle, e)))?;
+            if checkpoint.epoch <= epoch {
+                low = middle;
+            } else {
+                high = middle - 1;
+            }
+        }
+        let summary = certified_summary(&self.checkpoint_data(store, low).await?)?;
+        self.epoch_changes.lock().await.insert(epoch, summary.clone());
+        Ok(summary)
+    }
+
+    /// 从上次读到的位置起按序读取检查点，整批读取成功后才前进
+    async fn listen_checkpoints(&self) -> Result<Vec<SignedMessage>, BridgeError> {
+        let event_types = self.event_types()?;
+        let latest = self.client().await?
+            .read_api()
+            .get_latest_checkpoint_sequence_number()
+            .await
+            .map_err(|e| BridgeError::Network(e.to_string()))?;
+        let mut cursor = self.checkpoints.lock().await;
+        let Some(window) = cursor.window(latest) else {
+            return Ok(Vec::new());
+        };
+        let end = *window.end();
+        let mut messages = Vec::new();
+        for sequence in window {
+            messages.extend(self.checkpoint_events(&event_types, sequence).await?);
+        }
+        cursor.advance(end);
+        Ok(messages)
+    }
+}
+
+fn certified_summary(data: &CheckpointData) -> Result<CertifiedSummary, BridgeError> {
+    let summary = &data.checkpoint_summary;
+    Ok(CertifiedSummary {
+        summary: bcs::to_bytes(summary.data()).map_err(|e| BridgeError::Serialization(e.to_string()))?,
+        signature: summary.auth_sig().signature.as_ref().to_vec(),
+        signers: summary.auth_sig().signers_map.iter().collect(),
+    })
 }
 
 #[async_trait]
@@ -144,40 +554,390 @@ impl ChainAdapter for SuiAdapter {
         "sui"
     }
 
-    async fn listen_events(&self, config: &ChainConfig) -> Result<Vec<SignedMessage>, BridgeError> {
+    fn config(&self) -> &ChainConfig {
+        &self.config
+    }
+
+    fn capabilities(&self) -> AdapterCapabilities {
+        AdapterCapabilities {
+            // 事件通过 query_events 或按检查点轮询读取
+            subscriptions: false,
+            // 多条 process_message 调用可放入同一个可编程交易块
+            batch_submit: true,
+            dry_run: true,
+        }
+    }
+
+    fn rate_limiter(&self) -> Option<Arc<RateLimiter>> {
+        self.rate_limiter.clone()
+    }
+
+    async fn connect(&self) -> Result<(), BridgeError> {
+        let mut client = self.client.write().await;
+        if client.is_none() {
+            // 私有 RPC 端点的 API key 与认证头随每个请求发送
+            *client = Some(SuiClientBuilder::default()
+                .custom_headers(self.config.request_headers())
+                .build(self.config.rpc_url.as_str())
+                .await
+                .map_err(|e| BridgeError::Network(e.to_string()))?);
+        }
+        Ok(())
+    }
+
+    async fn close(&self) -> Result<(), BridgeError> {
+        self.client.write().await.take();
+        Ok(())
+    }
+
+    async fn is_healthy(&self) -> bool {
+        match self.client().await {
+            Ok(client) => client.read_api().get_latest_checkpoint_sequence_number().await.is_ok(),
+            Err(_) => false,
+        }
+    }
+
+    async fn event_cursors(&self) -> HashMap<String, u64> {
+        // 事件查询模式没有读取进度
+        if self.ingestion() != SuiIngestion::Checkpoints {
+            return HashMap::new();
+        }
+        self.checkpoints.lock().await.next()
+            .map(|next| HashMap::from([(CHECKPOINT_CURSOR.to_string(), next)]))
+            .unwrap_or_default()
+    }
+
+    async fn restore_cursors(&self, cursors: HashMap<String, u64>) -> Result<(), BridgeError> {
+        if let Some(next) = cursors.get(CHECKPOINT_CURSOR) {
+            self.checkpoints.lock().await.resume(*next);
+        }
+        Ok(())
+    }
+
+    async fn listen_events(&self) -> Result<Vec<SignedMessage>, BridgeError> {
+        if self.ingestion() == SuiIngestion::Checkpoints {
+            return self.listen_checkpoints().await;
+        }
         let mut messages = Vec::new();
-        let package = ObjectID::from_hex_literal(&config.bridge_address)
-            .map_err(|e| BridgeError::Chain(e.to_string()))?;
+        let event_types = self.event_types()?;
 
-        let module = Identifier::new("bridge").unwrap();
-        let filter = EventFilter::MoveModule {
-            package,
-            module,
+        // 每个事件过滤器对应一种事件类型，分别查询
+        for (event_type, _) in &event_types {
+            let events = self.client().await?
+                .event_api()
+                .query_events(EventFilter::MoveEventType(event_type.clone()), None, None, false)
+                .await
+                .map_err(|e| BridgeError::Chain(e.to_string()))?;
+            for event in events.data {
+                if let Some(message) = self.parse_event(&event, &event_types).await? {
+                    messages.push(message);
+                }
+            }
+        }
+
+        Ok(messages)
+    }
+
+    async fn replay_events(&self, from: u64, to: u64) -> Result<Vec<SignedMessage>, BridgeError> {
+        let event_types = self.event_types()?;
+        let mut messages = Vec::new();
+        for sequence in from..=to {
+            messages.extend(self.checkpoint_events(&event_types, sequence).await?);
+        }
+        Ok(messages)
+    }
+
+    async fn latest_height(&self) -> Result<Option<u64>, BridgeError> {
+        let latest = self.client().await?
+            .read_api()
+            .get_latest_checkpoint_sequence_number()
+            .await
+            .map_err(|e| BridgeError::Network(e.to_string()))?;
+        Ok(Some(latest))
+    }
+
+    async fn inclusion_proof(&self, message: &SignedMessage) -> Result<Option<InclusionProof>, BridgeError> {
+        let store = self.config.sui.as_ref().and_then(|sui| sui.checkpoint_store.as_deref());
+        let (Some(provenance), Some(store)) = (&message.provenance, store) else {
+            return Ok(None);
         };
+        let data = self.checkpoint_data(store, provenance.block).await?;
+        let transaction = data.transactions
+            .iter()
+            .find(|tx| tx.transaction.digest().to_string() == provenance.tx_hash)
+            .ok_or_else(|| BridgeError::Chain(format!("Transaction {} not found in checkpoint {}", provenance.tx_hash, provenance.block)))?;
+        let events = transaction.events
+            .as_ref()
+            .ok_or_else(|| BridgeError::Chain(format!("Transaction {} emitted no events", provenance.tx_hash)))?;
+
+        // 从配置的信任纪元起附带各纪元的最后一个检查点，验证方跳过已信任的纪元
+        let mut epoch_changes = Vec::new();
+        if let Some(LightClientConfig::Sui { epoch: trusted, .. }) = &self.config.light_client {
+            for epoch in *trusted..data.checkpoint_summary.epoch {
+                epoch_changes.push(self.epoch_change(store, epoch, provenance.block).await?);
+            }
+        }
+        let encode = |e: bcs::Error| BridgeError::Serialization(e.to_string());
+        Ok(Some(InclusionProof::SuiCheckpoint(CheckpointProof {
+            epoch_changes,
+            checkpoint: certified_summary(&data)?,
+            contents: bcs::to_bytes(&data.checkpoint_contents).map_err(encode)?,
+            effects: bcs::to_bytes(&transaction.effects).map_err(encode)?,
+            events: bcs::to_bytes(events).map_err(encode)?,
+            event_index: provenance.event_index,
+        })))
+    }
+
+    async fn submit_message(&self, message: SignedMessage) -> Result<String, BridgeError> {
+        let digest = self.send_message(&message).await?;
+        Ok(digest.to_string())
+    }
+
+    async fn submit_messages_batch(&self, messages: Vec<SignedMessage>) -> Result<String, BridgeError> {
+        let digest = self.send_messages(&messages).await?;
+        Ok(digest.to_string())
+    }
+
+    async fn dry_run(&self, message: &SignedMessage) -> Result<(), BridgeError> {
+        self.preflight(&self.build_transaction(message)?).await
+    }
 
-        let events = self.client
+    async fn listen_deliveries(&self) -> Result<Vec<Delivery>, BridgeError> {
+        let event_type = parse_sui_struct_tag(&format!("{}::message::MessageExecutedEvent", self.config.bridge_address))
+            .map_err(|e| BridgeError::Chain(e.to_string()))?;
+        let events = self.client().await?
             .event_api()
-            .query_events(filter, None, None, false)
+            .query_events(EventFilter::MoveEventType(event_type), None, None, false)
             .await
             .map_err(|e| BridgeError::Chain(e.to_string()))?;
 
+        let mut deliveries = Vec::new();
         for event in events.data {
-            if let Some(message) = self.parse_event(&event).await? {
-                messages.push(message);
+            match self.parse_delivery(&event) {
+                Ok(delivery) => deliveries.push(delivery),
+                Err(e) => warn!("Skipping undecodable Sui event {}: {}", event.id.tx_digest, e),
             }
         }
+        Ok(deliveries)
+    }
 
-        Ok(messages)
+    async fn submit_challenge(&self, challenge: &Challenge) -> Result<String, BridgeError> {
+        let digest = self.execute(self.build_challenge_transaction(challenge)?).await?;
+        Ok(digest.to_string())
     }
 
-    async fn submit_message(&self, _config: &ChainConfig, message: SignedMessage) -> Result<(), BridgeError> {
-        self.send_message(&message).await?;
-        Ok(())
+    async fn submit_equivocation(&self, evidence: &EquivocationEvidence) -> Result<Option<String>, BridgeError> {
+        let digest = self.execute(self.build_equivocation_transaction(evidence)?).await?;
+        Ok(Some(digest.to_string()))
     }
 
-    async fn verify_message(&self, _config: &ChainConfig, message: &SignedMessage) -> Result<MessageStatus, BridgeError> {
+    async fn verify_message(&self, message: &SignedMessage) -> Result<MessageStatus, BridgeError> {
         // 使用消息的签名作为唯一标识来查询状态
         let digest = TransactionDigest::new(message.signature.as_slice().try_into().unwrap());
         self.get_message_status(&digest).await
     }
-} 
\ No newline at end of file
+
+    async fn is_message_processed(&self, message: &SignedMessage) -> Result<bool, BridgeError> {
+        // 合约按消息 ID 记录已执行的消息（`message::is_message_executed`）；
+        // 未配置执行表时无法逐条判断，重复提交由合约拒绝
+        let Some(table) = self.config.executed_messages.as_ref() else {
+            return Ok(false);
+        };
+        let table = ObjectID::from_hex_literal(table)
+            .map_err(|e| BridgeError::Chain(e.to_string()))?;
+
+        // 执行表是 Table<vector<u8>, bool>，键为消息 ID，即规范哈希
+        let name = DynamicFieldName {
+            type_: TypeTag::Vector(Box::new(TypeTag::U8)),
+            value: serde_json::json!(message.message.canonical_hash(&self.chain_ids)?.to_vec()),
+        };
+        let response = self.client().await?
+            .read_api()
+            .get_dynamic_field_object(table, name)
+            .await
+            .map_err(|e| BridgeError::Chain(e.to_string()))?;
+        Ok(response.data.is_some())
+    }
+
+    async fn last_processed_nonce(&self, source_chain: &ChainId) -> Result<Option<u64>, BridgeError> {
+        let table = self.config.processed_nonces.as_ref()
+            .ok_or_else(|| BridgeError::Config(format!("Processed nonce table not configured for chain: {}", self.config.id)))?;
+        let table = ObjectID::from_hex_literal(table)
+            .map_err(|e| BridgeError::Chain(e.to_string()))?;
+
+        // nonce 表是 Table<u64, u64>，键为源链的数字链 ID
+        let name = DynamicFieldName {
+            type_: TypeTag::U64,
+            value: serde_json::json!(self.numeric_chain_id(source_chain)?.to_string()),
+        };
+        let response = self.client().await?
+            .read_api()
+            .get_dynamic_field_object(table, name)
+            .await
+            .map_err(|e| BridgeError::Chain(e.to_string()))?;
+
+        let Some(data) = response.data else {
+            return Ok(None);
+        };
+        let nonce = match data.content {
+            Some(SuiParsedData::MoveObject(object)) => {
+                let fields = object.fields.to_json_value();
+                match &fields["value"] {
+                    serde_json::Value::String(s) => s.parse::<u64>().ok(),
+                    v => v.as_u64(),
+                }
+            }
+            _ => None,
+        }
+        .ok_or_else(|| BridgeError::Chain(format!("Invalid processed nonce for source chain {}", source_chain)))?;
+        Ok(Some(nonce))
+    }
+
+    async fn update_validator_set(&self, set: &ValidatorSet) -> Result<Option<String>, BridgeError> {
+        let digest = self.execute(self.build_validator_set_transaction(set)?).await?;
+        Ok(Some(digest.to_string()))
+    }
+
+    async fn register_asset(&self, metadata: &MetadataSync) -> Result<Option<String>, BridgeError> {
+        let tx_data = self.build_register_asset_transaction(metadata)?;
+        self.preflight(&tx_data).await?;
+        let digest = self.execute(tx_data).await?;
+        Ok(Some(digest.to_string()))
+    }
+
+    async fn get_validator_registration(&self, validator: &str) -> Result<Option<ValidatorRegistration>, BridgeError> {
+        let registry = self.config.validator_registry.as_ref()
+            .ok_or_else(|| BridgeError::Config(format!("Validator registry not configured for chain: {}", self.config.id)))?;
+        let registry = ObjectID::from_hex_literal(registry)
+            .map_err(|e| BridgeError::Chain(e.to_string()))?;
+        let address = SuiAddress::from_str(validator)
+            .map_err(|e| BridgeError::Chain(e.to_string()))?;
+
+        // 验证者注册表是 Table<address, u64>，每个验证者对应一个动态字段
+        let name = DynamicFieldName {
+            type_: TypeTag::Address,
+            value: serde_json::json!(address.to_string()),
+        };
+        let response = self.client().await?
+            .read_api()
+            .get_dynamic_field_object(registry, name)
+            .await
+            .map_err(|e| BridgeError::Chain(e.to_string()))?;
+
+        let Some(data) = response.data else {
+            return Ok(None);
+        };
+        let weight = match data.content {
+            Some(SuiParsedData::MoveObject(object)) => {
+                let fields = object.fields.to_json_value();
+                match &fields["value"] {
+                    serde_json::Value::String(s) => s.parse::<u64>().ok(),
+                    v => v.as_u64(),
+                }
+            }
+            _ => None,
+        }
+        .ok_or_else(|| BridgeError::Chain(format!("Invalid validator weight for {}", validator)))?;
+
+        Ok(Some(ValidatorRegistration {
+            address: validator.to_string(),
+            weight,
+            active: true,
+        }))
+    }
+
+    async fn node_version(&self) -> Result<String, BridgeError> {
+        // 构建客户端时已通过 rpc.discover 获取节点 API 版本
+        Ok(self.client().await?.api_version().to_string())
+    }
+
+    async fn check_contract(&self) -> Result<ContractCheck, BridgeError> {
+        let package = ObjectID::from_hex_literal(&self.config.bridge_address)
+            .map_err(|e| BridgeError::Config(format!("Invalid bridge address {}: {}", self.config.bridge_address, e)))?;
+        // 先确认对象存在，区分包不存在与节点请求失败
+        let object = self.client().await?
+            .read_api()
+            .get_object_with_options(package, SuiObjectDataOptions::new())
+            .await
+            .map_err(|e| BridgeError::Chain(e.to_string()))?;
+        if object.data.is_none() {
+            return Ok(ContractCheck { exists: false, missing_events: Vec::new() });
+        }
+
+        // 事件类型为包中任一模块定义的结构体
+        let modules = self.client().await?
+            .read_api()
+            .get_normalized_move_modules_by_package(package)
+            .await
+            .map_err(|e| BridgeError::Chain(e.to_string()))?;
+        Ok(ContractCheck {
+            exists: true,
+            missing_events: self.config.event_filters
+                .iter()
+                .filter(|filter| !modules.values().any(|module| module.structs.contains_key(filter.struct_name())))
+                .map(|filter| filter.name.clone())
+                .collect(),
+        })
+    }
+
+    async fn move_modules(&self) -> Result<Vec<MoveModule>, BridgeError> {
+        let package = ObjectID::from_hex_literal(&self.config.bridge_address)
+            .map_err(|e| BridgeError::Config(format!("Invalid bridge address {}: {}", self.config.bridge_address, e)))?;
+        let modules = self.client().await?
+            .read_api()
+            .get_normalized_move_modules_by_package(package)
+            .await
+            .map_err(|e| BridgeError::Chain(e.to_string()))?;
+        // 规范化模块的 JSON 形式即 RPC 返回格式
+        let value = serde_json::to_value(&modules).map_err(|e| BridgeError::Serialization(e.to_string()))?;
+        codegen::parse_sui_modules(&value)
+    }
+
+    async fn check_recipient(&self, recipient: &[u8], _asset_type: &str) -> Result<Option<String>, BridgeError> {
+        // Sui 上任意地址都可以接收对象，只需检查地址格式
+        Ok(check_address_format(recipient))
+    }
+
+    async fn gas_balance(&self) -> Result<Option<u128>, BridgeError> {
+        // 与提交交易使用同一发送地址，未指定币种时查询 SUI
+        let sender = SuiAddress::from_str(&self.config.id)
+            .map_err(|e| BridgeError::Chain(e.to_string()))?;
+        let balance = self.client().await?
+            .coin_read_api()
+            .get_balance(sender, None)
+            .await
+            .map_err(|e| BridgeError::Network(e.to_string()))?;
+        Ok(Some(balance.total_balance))
+    }
+
+    async fn wrapped_supply(&self, asset_type: &str) -> Result<Option<u128>, BridgeError> {
+        // 包装代币由桥合约持有的 TreasuryCap 铸造，流通量即代币总供应量
+        let supply = self.client().await?
+            .coin_read_api()
+            .get_total_supply(asset_type.to_string())
+            .await
+            .map_err(|e| BridgeError::Network(e.to_string()))?;
+        Ok(Some(supply.value as u128))
+    }
+
+    async fn gas_price(&self) -> Result<Option<u64>, BridgeError> {
+        let price = self.client().await?
+            .read_api()
+            .get_reference_gas_price()
+            .await
+            .map_err(|e| BridgeError::Network(e.to_string()))?;
+        Ok(Some(price))
+    }
+
+    async fn transaction_gas(&self, tx_hash: &str) -> Result<Option<u64>, BridgeError> {
+        let digest = TransactionDigest::from_str(tx_hash)
+            .map_err(|e| BridgeError::Chain(format!("Invalid transaction digest {}: {}", tx_hash, e)))?;
+        let response = self.client().await?
+            .read_api()
+            .get_transaction_with_options(digest, SuiTransactionBlockResponseOptions::new().with_effects())
+            .await
+            .map_err(|e| BridgeError::Network(e.to_string()))?;
+        // 存储返还计入后的净消耗，返还超过开销时记为 0
+        Ok(response.effects.map(|effects| effects.gas_cost_summary().net_gas_usage().max(0) as u64))
+    }
+}
@@ -0,0 +1,60 @@
+use super::contract_error::BridgeContractError;
+use crate::Error;
+
+/// 从 Sui 执行失败状态中解析中止位置与中止码
+///
+/// 状态形如 `MoveAbort(MoveLocation { module: ModuleId { address: <hex>, name: Identifier("message") }, ... }, 5) in command 0`，
+/// 位置返回 `0x<hex>::<module>`。
+pub fn parse_move_abort(status: &str) -> Option<(String, u64)> {
+    let body = &status[status.find("MoveAbort(")? + "MoveAbort(".len()..];
+    let address = body[body.find("address: ")? + "address: ".len()..].split(',').next()?.trim();
+    let name = &body[body.find("Identifier(\"")? + "Identifier(\"".len()..];
+    let name = &name[..name.find('"')?];
+    // 中止码位于 `MoveLocation { .. }` 之后
+    let code = &body[body.rfind("}, ")? + 3..];
+    let code = code[..code.find(')')?].trim().parse().ok()?;
+    let address = address.strip_prefix("0x").unwrap_or(address);
+    Some((format!("0x{}::{}", address, name), code))
+}
+
+/// 将试运行的失败状态转换为错误，中止按 [`BridgeContractError`] 分类
+pub fn dry_run_error(status: &str, bridge_address: &str) -> Error {
+    match parse_move_abort(status) {
+        Some((location, code)) => {
+            let detail = format!("{} aborted with code {}", location, code);
+            BridgeContractError::from_abort(&location, code, bridge_address).into_error(&detail)
+        }
+        None => match BridgeContractError::from_vm_status(status) {
+            Some(error) => error.into_error(status),
+            None => Error::Chain(format!("Dry run failed: {}", status)),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_bridge_aborts() {
+        let status = |module: &str, code: u64| {
+            format!(
+                "MoveAbort(MoveLocation {{ module: ModuleId {{ address: 00000000000000000000000000000000000000000000000000000000000000b1, \
+                 name: Identifier(\"{}\") }}, function: 3, instruction: 12, function_name: Some(\"execute\") }}, {}) in command 0",
+                module, code
+            )
+        };
+        assert_eq!(
+            parse_move_abort(&status("message", 5)),
+            Some(("0x00000000000000000000000000000000000000000000000000000000000000b1::message".to_string(), 5))
+        );
+
+        assert!(matches!(dry_run_error(&status("message", 5), "0xb1"), Error::AlreadyProcessed(_)));
+        assert!(matches!(dry_run_error(&status("validator", 5), "0xB1"), Error::InvalidSignature(_)));
+        assert!(matches!(dry_run_error(&status("control", 3), "0xb1"), Error::Paused(_)));
+        // 其它中止码与其它包的中止保留原始位置与中止码
+        assert!(matches!(dry_run_error(&status("asset", 1), "0xb1"), Error::MoveAbort { code: 1, .. }));
+        assert!(matches!(dry_run_error(&status("control", 3), "0xc2"), Error::MoveAbort { code: 3, .. }));
+        assert!(matches!(dry_run_error("InsufficientGas", "0xb1"), Error::Chain(_)));
+    }
+}
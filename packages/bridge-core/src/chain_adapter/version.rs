@@ -0,0 +1,145 @@
+use std::cmp::Ordering;
+use tracing::{info, error};
+use crate::{
+    types::RpcErrorClass,
+    Error,
+};
+use super::ChainAdapter;
+
+/// 节点版本号，只比较 `主.次.补丁` 数字部分，忽略 `-` / `+` 之后的构建后缀
+#[derive(Debug, Clone)]
+pub struct NodeVersion(Vec<u64>);
+
+impl NodeVersion {
+    pub fn parse(version: &str) -> Result<Self, Error> {
+        let core = version
+            .trim()
+            .trim_start_matches('v')
+            .split(['-', '+'])
+            .next()
+            .unwrap_or_default();
+        let parts = core
+            .split('.')
+            .map(|part| part.parse::<u64>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| Error::Config(format!("Invalid node version: {}", version)))?;
+        Ok(Self(parts))
+    }
+}
+
+impl PartialEq for NodeVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for NodeVersion {}
+
+impl PartialOrd for NodeVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NodeVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // 缺失的段按 0 处理，使 1.2 与 1.2.0 相等
+        let len = self.0.len().max(other.0.len());
+        (0..len)
+            .map(|i| {
+                let a = self.0.get(i).copied().unwrap_or(0);
+                let b = other.0.get(i).copied().unwrap_or(0);
+                a.cmp(&b)
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+/// 启动时检查节点版本是否满足链配置中固定的最低版本
+pub async fn ensure_compatible(adapter: &dyn ChainAdapter) -> Result<(), Error> {
+    let config = adapter.config();
+    let detected = adapter.node_version().await?;
+    info!("Chain {} node version: {}", config.id, detected);
+
+    let Some(required) = &config.min_node_version else {
+        return Ok(());
+    };
+    if NodeVersion::parse(&detected)? < NodeVersion::parse(required)? {
+        return Err(Error::IncompatibleNode {
+            chain: config.id.to_string(),
+            detected,
+            required: format!(">= {}", required),
+        });
+    }
+    Ok(())
+}
+
+/// 错误信息是否像是节点 RPC 协议变更导致的
+///
+/// 节点升级后常见的表现是方法不存在、或响应结构无法反序列化。
+pub fn is_version_skew(error: &Error) -> bool {
+    let message = match error {
+        Error::Rpc { code, .. } if RpcErrorClass::from_code(*code) == RpcErrorClass::MethodNotFound => return true,
+        Error::Rpc { message, .. } => message,
+        Error::Chain(message) | Error::Network(message) | Error::Serialization(message) => message,
+        _ => return false,
+    };
+    const PATTERNS: [&str; 6] = [
+        "Method not found",
+        "-32601",
+        "unknown variant",
+        "missing field",
+        "invalid type",
+        "unsupported version",
+    ];
+    PATTERNS.iter().any(|pattern| message.contains(pattern))
+}
+
+/// 对疑似版本不兼容的错误重新探测节点版本，转换为 `Error::IncompatibleNode`
+///
+/// 其它错误原样返回。
+pub async fn classify_error(adapter: &dyn ChainAdapter, error: Error) -> Error {
+    if !is_version_skew(&error) {
+        return error;
+    }
+    let config = adapter.config();
+    let detected = adapter
+        .node_version()
+        .await
+        .unwrap_or_else(|_| "unknown".to_string());
+    error!("Chain {} looks incompatible after node upgrade: {}", config.id, error);
+    Error::IncompatibleNode {
+        chain: config.id.to_string(),
+        detected,
+        required: match &config.min_node_version {
+            Some(required) => format!(">= {}", required),
+            None => "an RPC API compatible with this relayer".to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_ordering() {
+        let parse = |v| NodeVersion::parse(v).unwrap();
+        assert!(parse("1.23.0-8f1e2a") < parse("1.24.0"));
+        assert!(parse("v0.7.10") > parse("0.7.9"));
+        assert_eq!(parse("1.2"), parse("1.2.0"));
+        assert!(NodeVersion::parse("latest").is_err());
+    }
+
+    #[test]
+    fn test_version_skew_patterns() {
+        assert!(is_version_skew(&Error::Chain("RPC error -32601: Method not found".to_string())));
+        assert!(is_version_skew(&Error::Chain("missing field `digest`".to_string())));
+        assert!(!is_version_skew(&Error::Chain("connection refused".to_string())));
+        assert!(!is_version_skew(&Error::Config("missing field".to_string())));
+        let rpc = |code| Error::Rpc { method: "rooch_getStates".to_string(), code, message: "failed".to_string() };
+        assert!(is_version_skew(&rpc(-32601)));
+        assert!(!is_version_skew(&rpc(-32000)));
+    }
+}
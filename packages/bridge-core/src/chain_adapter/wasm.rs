@@ -0,0 +1,493 @@
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tracing::{debug, error, info, warn};
+use wasmtime::{Caller, Config as EngineConfig, Engine, Instance, Linker, Memory, Module, Store, StoreLimits, StoreLimitsBuilder};
+use wasmtime_wasi::{preview1::WasiP1Ctx, WasiCtxBuilder};
+use crate::{
+    config::{ChainConfig, WasmPluginConfig},
+    types::{ChainId, Delivery, MessageStatus, SignedMessage, ValidatorRegistration},
+    Error,
+};
+use super::{AdapterCapabilities, ChainAdapter, ContractCheck};
+
+/// WASM 插件适配器类型名
+pub const WASM_ADAPTER_TYPE: &str = "wasm";
+
+/// 主机函数所在的导入模块
+const HOST_MODULE: &str = "bridge";
+/// 插件导出：分配 `len` 字节的内存，返回起始地址，主机用它写入参数与主机函数的返回值
+const ALLOC: &str = "bridge_alloc";
+/// 插件导出（可选）：以链配置 JSON 初始化插件
+const INIT: &str = "bridge_init";
+/// 插件导出：读取新的源链事件，返回 `SignedMessage` 数组
+const POLL_EVENTS: &str = "bridge_poll_events";
+/// 插件导出：提交一条 `SignedMessage`，返回交易哈希
+const SUBMIT_MESSAGE: &str = "bridge_submit_message";
+/// 插件导出（可选）：查询消息在本链上的状态，返回 `MessageStatus`
+const VERIFY_MESSAGE: &str = "bridge_verify_message";
+
+/// 插件与主机之间传递的结果，编码为 `{"ok": ...}` 或 `{"error": "..."}`
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum PluginResult<T> {
+    Ok(T),
+    Error(String),
+}
+
+impl<T> From<Result<T, String>> for PluginResult<T> {
+    fn from(result: Result<T, String>) -> Self {
+        match result {
+            Ok(value) => Self::Ok(value),
+            Err(e) => Self::Error(e),
+        }
+    }
+}
+
+/// 插件通过 `bridge.http_request` 发起的请求
+#[derive(Debug, Deserialize)]
+struct HttpRequest {
+    #[serde(default = "default_method")]
+    method: String,
+    url: String,
+    #[serde(default)]
+    headers: HashMap<String, String>,
+    #[serde(default)]
+    body: Option<String>,
+}
+
+fn default_method() -> String {
+    "GET".to_string()
+}
+
+#[derive(Debug, Serialize)]
+struct HttpResponse {
+    status: u16,
+    body: String,
+}
+
+/// 单个插件实例的主机侧状态
+struct PluginState {
+    chain: ChainId,
+    wasi: WasiP1Ctx,
+    limits: StoreLimits,
+    http: reqwest::Client,
+    allowed_hosts: Vec<String>,
+    runtime: tokio::runtime::Handle,
+}
+
+impl PluginState {
+    /// 执行插件的 HTTP 请求，只允许访问配置的主机
+    fn http_request(&self, request: HttpRequest) -> Result<HttpResponse, String> {
+        let url = reqwest::Url::parse(&request.url).map_err(|e| format!("Invalid URL {}: {}", request.url, e))?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(format!("Scheme {} is not allowed", url.scheme()));
+        }
+        let host = url.host_str().unwrap_or_default();
+        if !self.allowed_hosts.iter().any(|allowed| allowed.eq_ignore_ascii_case(host)) {
+            return Err(format!("Host {} is not allowed", host));
+        }
+        let method = reqwest::Method::from_bytes(request.method.as_bytes()).map_err(|e| e.to_string())?;
+        let mut builder = self.http.request(method, url);
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+        // 插件调用运行在阻塞线程中，可以在此等待异步请求完成
+        self.runtime.block_on(async {
+            let response = builder.send().await.map_err(|e| e.to_string())?;
+            let status = response.status().as_u16();
+            let body = response.text().await.map_err(|e| e.to_string())?;
+            Ok(HttpResponse { status, body })
+        })
+    }
+}
+
+/// 已实例化的插件
+struct PluginInstance {
+    store: Store<PluginState>,
+    instance: Instance,
+    memory: Memory,
+}
+
+impl PluginInstance {
+    fn new(engine: &Engine, module: &Module, config: &ChainConfig, plugin: &WasmPluginConfig) -> Result<Self, Error> {
+        let mut linker: Linker<PluginState> = Linker::new(engine);
+        wasmtime_wasi::preview1::add_to_linker_sync(&mut linker, |state| &mut state.wasi).map_err(plugin_error)?;
+        linker
+            .func_wrap(HOST_MODULE, "http_request", |mut caller: Caller<'_, PluginState>, ptr: u32, len: u32| {
+                let request = read_guest(&mut caller, ptr, len)?;
+                let result = serde_json::from_slice::<HttpRequest>(&request)
+                    .map_err(|e| format!("Invalid HTTP request: {}", e))
+                    .and_then(|request| caller.data().http_request(request));
+                let response = serde_json::to_vec(&PluginResult::from(result))?;
+                write_guest(&mut caller, &response)
+            })
+            .map_err(plugin_error)?;
+        linker
+            .func_wrap(HOST_MODULE, "log", |mut caller: Caller<'_, PluginState>, level: u32, ptr: u32, len: u32| {
+                let message = read_guest(&mut caller, ptr, len)?;
+                let message = String::from_utf8_lossy(&message);
+                let chain = &caller.data().chain;
+                match level {
+                    0 => error!("[plugin {}] {}", chain, message),
+                    1 => warn!("[plugin {}] {}", chain, message),
+                    2 => info!("[plugin {}] {}", chain, message),
+                    _ => debug!("[plugin {}] {}", chain, message),
+                }
+                Ok(())
+            })
+            .map_err(plugin_error)?;
+
+        // 不继承任何环境变量、参数、标准输入输出与目录
+        let state = PluginState {
+            chain: config.id.clone(),
+            wasi: WasiCtxBuilder::new().build_p1(),
+            limits: StoreLimitsBuilder::new()
+                .memory_size(plugin.max_memory_bytes)
+                .instances(1)
+                .trap_on_grow_failure(true)
+                .build(),
+            http: reqwest::Client::new(),
+            allowed_hosts: plugin.allowed_hosts.clone(),
+            runtime: tokio::runtime::Handle::current(),
+        };
+        let mut store = Store::new(engine, state);
+        store.limiter(|state| &mut state.limits);
+        store.set_fuel(plugin.fuel).map_err(plugin_error)?;
+        let instance = linker.instantiate(&mut store, module).map_err(plugin_error)?;
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .ok_or_else(|| Error::Chain("Plugin does not export its memory".to_string()))?;
+        Ok(Self { store, instance, memory })
+    }
+
+    /// 以 `fuel` 为预算调用插件导出函数，`input` 写入插件内存后作为 `(ptr, len)` 传入
+    ///
+    /// 导出函数返回 `(ptr << 32) | len`，长度为 0 表示没有返回值。
+    fn call(&mut self, export: &str, input: Option<&[u8]>, fuel: u64) -> Result<Option<Vec<u8>>, Error> {
+        self.store.set_fuel(fuel).map_err(plugin_error)?;
+        let packed = match input {
+            Some(input) => {
+                let (ptr, len) = self.write(input)?;
+                self.instance
+                    .get_typed_func::<(u32, u32), u64>(&mut self.store, export)
+                    .and_then(|func| func.call(&mut self.store, (ptr, len)))
+            }
+            None => self.instance
+                .get_typed_func::<(), u64>(&mut self.store, export)
+                .and_then(|func| func.call(&mut self.store, ())),
+        }
+        .map_err(|e| Error::Chain(format!("Plugin call {} failed: {}", export, e)))?;
+
+        let (ptr, len) = ((packed >> 32) as usize, (packed & 0xffff_ffff) as usize);
+        if len == 0 {
+            return Ok(None);
+        }
+        let mut output = vec![0; len];
+        self.memory
+            .read(&self.store, ptr, &mut output)
+            .map_err(|e| Error::Chain(format!("Plugin returned an invalid buffer from {}: {}", export, e)))?;
+        Ok(Some(output))
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> Result<(u32, u32), Error> {
+        let len = u32::try_from(bytes.len()).map_err(|_| Error::Chain("Plugin input too large".to_string()))?;
+        let ptr = self.instance
+            .get_typed_func::<u32, u32>(&mut self.store, ALLOC)
+            .and_then(|alloc| alloc.call(&mut self.store, len))
+            .map_err(plugin_error)?;
+        self.memory
+            .write(&mut self.store, ptr as usize, bytes)
+            .map_err(|e| Error::Chain(format!("Plugin allocated an invalid buffer: {}", e)))?;
+        Ok((ptr, len))
+    }
+}
+
+/// 读取插件传给主机函数的缓冲区
+fn read_guest(caller: &mut Caller<'_, PluginState>, ptr: u32, len: u32) -> anyhow::Result<Vec<u8>> {
+    let memory = caller
+        .get_export("memory")
+        .and_then(|export| export.into_memory())
+        .ok_or_else(|| anyhow::anyhow!("Plugin does not export its memory"))?;
+    let mut buffer = vec![0; len as usize];
+    memory.read(&caller, ptr as usize, &mut buffer)?;
+    Ok(buffer)
+}
+
+/// 通过插件的分配函数把主机函数的返回值写入插件内存，返回 `(ptr << 32) | len`
+fn write_guest(caller: &mut Caller<'_, PluginState>, bytes: &[u8]) -> anyhow::Result<u64> {
+    let alloc = caller
+        .get_export(ALLOC)
+        .and_then(|export| export.into_func())
+        .ok_or_else(|| anyhow::anyhow!("Plugin does not export {}", ALLOC))?
+        .typed::<u32, u32>(&caller)?;
+    let len = u32::try_from(bytes.len())?;
+    let ptr = alloc.call(&mut *caller, len)?;
+    let memory = caller
+        .get_export("memory")
+        .and_then(|export| export.into_memory())
+        .ok_or_else(|| anyhow::anyhow!("Plugin does not export its memory"))?;
+    memory.write(&mut *caller, ptr as usize, bytes)?;
+    Ok(((ptr as u64) << 32) | len as u64)
+}
+
+fn plugin_error(e: anyhow::Error) -> Error {
+    Error::Chain(format!("Plugin error: {}", e))
+}
+
+/// 在 WASM 沙箱中运行的社区适配器
+///
+/// 插件编译为 WASI 模块，只通过导出函数与 `bridge` 模块中的主机函数与中继器交互：
+/// 参数与返回值均为 JSON，结果编码为 `{"ok": ...}` 或 `{"error": "..."}`。
+/// 每次调用都有燃料上限，内存受 `max_memory_bytes` 限制，网络访问只限于 `allowed_hosts`。
+pub struct WasmAdapter {
+    config: ChainConfig,
+    plugin: WasmPluginConfig,
+    engine: Engine,
+    module: Module,
+    /// `connect` 之后可用，`close` 时释放
+    instance: Arc<Mutex<Option<PluginInstance>>>,
+}
+
+impl WasmAdapter {
+    /// 加载并编译插件模块
+    pub fn new(config: ChainConfig) -> Result<Self, Error> {
+        let plugin = config.plugin.clone()
+            .ok_or_else(|| Error::Config(format!("Chain {} has no plugin configured", config.id)))?;
+        let mut engine_config = EngineConfig::new();
+        engine_config.consume_fuel(true);
+        let engine = Engine::new(&engine_config).map_err(plugin_error)?;
+        let module = Module::from_file(&engine, &plugin.path)
+            .map_err(|e| Error::Config(format!("Failed to load plugin {}: {}", plugin.path, e)))?;
+        Ok(Self {
+            config,
+            plugin,
+            engine,
+            module,
+            instance: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    /// 在阻塞线程中调用插件，避免插件执行期间占用异步运行时
+    async fn call(&self, export: &'static str, input: Option<Vec<u8>>) -> Result<Option<Vec<u8>>, Error> {
+        let instance = self.instance.clone();
+        let chain = self.config.id.clone();
+        let fuel = self.plugin.fuel;
+        tokio::task::spawn_blocking(move || {
+            let mut instance = instance.lock().unwrap();
+            let instance = instance
+                .as_mut()
+                .ok_or_else(|| Error::Network(format!("Wasm adapter for {} is not connected", chain)))?;
+            instance.call(export, input.as_deref(), fuel)
+        })
+        .await
+        .map_err(|e| Error::Chain(format!("Plugin task failed: {}", e)))?
+    }
+
+    /// 调用插件并解码 JSON 结果，插件未返回结果时为 None
+    async fn call_json<I: Serialize, T: DeserializeOwned>(&self, export: &'static str, input: Option<&I>) -> Result<Option<T>, Error> {
+        let input = input
+            .map(serde_json::to_vec)
+            .transpose()
+            .map_err(|e| Error::Serialization(e.to_string()))?;
+        let Some(output) = self.call(export, input).await? else {
+            return Ok(None);
+        };
+        match serde_json::from_slice(&output)
+            .map_err(|e| Error::Serialization(format!("Invalid result from plugin {}: {}", export, e)))?
+        {
+            PluginResult::Ok(value) => Ok(Some(value)),
+            PluginResult::Error(e) => Err(Error::Chain(format!("Plugin {} failed: {}", export, e))),
+        }
+    }
+}
+
+#[async_trait]
+impl ChainAdapter for WasmAdapter {
+    fn chain_type(&self) -> &str {
+        WASM_ADAPTER_TYPE
+    }
+
+    fn config(&self) -> &ChainConfig {
+        &self.config
+    }
+
+    fn capabilities(&self) -> AdapterCapabilities {
+        AdapterCapabilities::default()
+    }
+
+    async fn connect(&self) -> Result<(), Error> {
+        if self.instance.lock().unwrap().is_some() {
+            return Ok(());
+        }
+        let (engine, module) = (self.engine.clone(), self.module.clone());
+        let (config, plugin) = (self.config.clone(), self.plugin.clone());
+        let instance = tokio::task::spawn_blocking(move || -> Result<PluginInstance, Error> {
+            let mut instance = PluginInstance::new(&engine, &module, &config, &plugin)?;
+            // 初始化函数可选，插件据此读取 RPC 地址与合约地址等配置
+            if instance.instance.get_export(&mut instance.store, INIT).is_some() {
+                let config = serde_json::to_vec(&config).map_err(|e| Error::Serialization(e.to_string()))?;
+                if let Some(output) = instance.call(INIT, Some(&config), plugin.fuel)? {
+                    if let PluginResult::Error(e) = serde_json::from_slice::<PluginResult<serde_json::Value>>(&output)
+                        .map_err(|e| Error::Serialization(format!("Invalid result from plugin {}: {}", INIT, e)))?
+                    {
+                        return Err(Error::Chain(format!("Plugin {} failed: {}", INIT, e)));
+                    }
+                }
+            }
+            Ok(instance)
+        })
+        .await
+        .map_err(|e| Error::Chain(format!("Plugin task failed: {}", e)))??;
+        *self.instance.lock().unwrap() = Some(instance);
+        debug!("Loaded wasm plugin {} for chain {}", self.plugin.path, self.config.id);
+        Ok(())
+    }
+
+    async fn close(&self) -> Result<(), Error> {
+        self.instance.lock().unwrap().take();
+        Ok(())
+    }
+
+    async fn is_healthy(&self) -> bool {
+        self.instance.lock().unwrap().is_some()
+    }
+
+    async fn listen_events(&self) -> Result<Vec<SignedMessage>, Error> {
+        Ok(self.call_json::<(), _>(POLL_EVENTS, None).await?.unwrap_or_default())
+    }
+
+    async fn submit_message(&self, message: SignedMessage) -> Result<String, Error> {
+        self.call_json(SUBMIT_MESSAGE, Some(&message))
+            .await?
+            .ok_or_else(|| Error::Chain(format!("Plugin {} returned no transaction hash", SUBMIT_MESSAGE)))
+    }
+
+    async fn listen_deliveries(&self) -> Result<Vec<Delivery>, Error> {
+        Ok(Vec::new())
+    }
+
+    async fn verify_message(&self, message: &SignedMessage) -> Result<MessageStatus, Error> {
+        self.call_json(VERIFY_MESSAGE, Some(message))
+            .await?
+            .ok_or_else(|| Error::Chain(format!("Plugin {} returned no status", VERIFY_MESSAGE)))
+    }
+
+    async fn last_processed_nonce(&self, _source_chain: &ChainId) -> Result<Option<u64>, Error> {
+        Ok(None)
+    }
+
+    async fn get_validator_registration(&self, _validator: &str) -> Result<Option<ValidatorRegistration>, Error> {
+        Ok(None)
+    }
+
+    async fn node_version(&self) -> Result<String, Error> {
+        // 插件不报告节点版本，配置 `min_node_version` 的插件链无法通过版本检查
+        Ok(WASM_ADAPTER_TYPE.to_string())
+    }
+
+    async fn check_contract(&self) -> Result<ContractCheck, Error> {
+        Err(Error::Chain("Contract checks are not supported by wasm adapter".to_string()))
+    }
+
+    async fn check_recipient(&self, _recipient: &[u8], _asset_type: &str) -> Result<Option<String>, Error> {
+        Ok(None)
+    }
+
+    async fn gas_balance(&self) -> Result<Option<u128>, Error> {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{mock_chain_config, transfer_payload, SOURCE_CHAIN, TARGET_CHAIN};
+    use crate::types::CrossChainMessage;
+
+    /// 提交返回固定交易哈希；查询状态时尝试访问未授权的主机
+    const ECHO_PLUGIN: &str = r#"(module
+        (import "bridge" "http_request" (func $http (param i32 i32) (result i64)))
+        (memory (export "memory") 1)
+        (global $next (mut i32) (i32.const 4096))
+        (data (i32.const 0) "{\"ok\":[]}")
+        (data (i32.const 16) "{\"ok\":\"0xabc\"}")
+        (data (i32.const 64) "{\"url\":\"https://evil.example/\"}")
+        (func (export "bridge_alloc") (param $len i32) (result i32)
+            (local $ptr i32)
+            (local.set $ptr (global.get $next))
+            (global.set $next (i32.add (global.get $next) (local.get $len)))
+            (local.get $ptr))
+        (func (export "bridge_poll_events") (result i64)
+            (i64.const 9))
+        (func (export "bridge_submit_message") (param i32 i32) (result i64)
+            (i64.or (i64.shl (i64.const 16) (i64.const 32)) (i64.const 14)))
+        (func (export "bridge_verify_message") (param i32 i32) (result i64)
+            (call $http (i32.const 64) (i32.const 31))))"#;
+
+    /// 读取事件时死循环，内存超过 64 KiB 上限
+    const RUNAWAY_PLUGIN: &str = r#"(module
+        (memory (export "memory") 1)
+        (func (export "bridge_alloc") (param i32) (result i32)
+            (i32.const 0))
+        (func (export "bridge_poll_events") (result i64)
+            (loop $spin (br $spin))
+            (i64.const 0))
+        (func (export "bridge_submit_message") (param i32 i32) (result i64)
+            (drop (memory.grow (i32.const 16)))
+            (i64.const 0)))"#;
+
+    fn plugin_chain(name: &str, wat: &str) -> ChainConfig {
+        let path = std::env::temp_dir().join(format!("bridge-wasm-{}-{}.wat", name, std::process::id()));
+        std::fs::write(&path, wat).unwrap();
+        ChainConfig {
+            adapter_type: WASM_ADAPTER_TYPE.to_string(),
+            plugin: Some(WasmPluginConfig {
+                path: path.to_string_lossy().into_owned(),
+                allowed_hosts: vec!["rpc.example".to_string()],
+                max_memory_bytes: 64 * 1024,
+                fuel: 1_000_000,
+            }),
+            ..mock_chain_config(name)
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_wasm_plugin_sandbox() {
+        let message = SignedMessage {
+            message: CrossChainMessage {
+                nonce: 1,
+                source_chain: SOURCE_CHAIN.into(),
+                target_chain: TARGET_CHAIN.into(),
+                message_type: "transfer".to_string(),
+                payload: transfer_payload([1; 32], 100),
+            },
+            signature: vec![],
+            timestamp: 0,
+            event_name: None,
+            sender: None,
+            provenance: None,
+            proof: None,
+            trace_id: None,
+        };
+
+        let adapter = WasmAdapter::new(plugin_chain("echo", ECHO_PLUGIN)).unwrap();
+        assert!(adapter.listen_events().await.is_err());
+        adapter.connect().await.unwrap();
+        assert!(adapter.listen_events().await.unwrap().is_empty());
+        assert_eq!(adapter.submit_message(message.clone()).await.unwrap(), "0xabc");
+        // 网络访问只限于配置的主机
+        let denied = adapter.verify_message(&message).await.unwrap_err().to_string();
+        assert!(denied.contains("Host evil.example is not allowed"), "{}", denied);
+
+        // 燃料用尽与超出内存上限都会中止调用，而不会拖垮中继器
+        let adapter = WasmAdapter::new(plugin_chain("runaway", RUNAWAY_PLUGIN)).unwrap();
+        adapter.connect().await.unwrap();
+        assert!(adapter.listen_events().await.is_err());
+        assert!(adapter.submit_message(message).await.is_err());
+    }
+}
@@ -0,0 +1,352 @@
+use std::collections::HashMap;
+use std::fmt;
+
+mod parse;
+
+pub use parse::{parse_rooch_module, parse_sui_modules, parse_type_tag};
+
+/// Move 类型
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoveType {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    U256,
+    Address,
+    Signer,
+    Vector(Box<MoveType>),
+    Struct {
+        address: String,
+        module: String,
+        name: String,
+        type_args: Vec<MoveType>,
+    },
+    TypeParam(u16),
+    Reference {
+        mutable: bool,
+        inner: Box<MoveType>,
+    },
+}
+
+impl fmt::Display for MoveType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoveType::Bool => write!(f, "bool"),
+            MoveType::U8 => write!(f, "u8"),
+            MoveType::U16 => write!(f, "u16"),
+            MoveType::U32 => write!(f, "u32"),
+            MoveType::U64 => write!(f, "u64"),
+            MoveType::U128 => write!(f, "u128"),
+            MoveType::U256 => write!(f, "u256"),
+            MoveType::Address => write!(f, "address"),
+            MoveType::Signer => write!(f, "signer"),
+            MoveType::Vector(inner) => write!(f, "vector<{}>", inner),
+            MoveType::Struct { address, module, name, type_args } => {
+                write!(f, "{}::{}::{}", address, module, name)?;
+                if !type_args.is_empty() {
+                    let args: Vec<String> = type_args.iter().map(ToString::to_string).collect();
+                    write!(f, "<{}>", args.join(", "))?;
+                }
+                Ok(())
+            }
+            MoveType::TypeParam(index) => write!(f, "T{}", index),
+            MoveType::Reference { mutable: true, inner } => write!(f, "&mut {}", inner),
+            MoveType::Reference { mutable: false, inner } => write!(f, "&{}", inner),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveField {
+    pub name: String,
+    pub type_: MoveType,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveStruct {
+    pub name: String,
+    /// 小写的能力名，例如 `copy`、`drop`
+    pub abilities: Vec<String>,
+    pub type_params: usize,
+    pub fields: Vec<MoveField>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveFunction {
+    pub name: String,
+    pub is_entry: bool,
+    pub type_params: usize,
+    pub params: Vec<MoveType>,
+}
+
+/// 链上读取的 Move 模块 ABI，Sui 与 Rooch 统一为同一结构
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveModule {
+    pub address: String,
+    pub name: String,
+    pub structs: Vec<MoveStruct>,
+    pub functions: Vec<MoveFunction>,
+}
+
+/// 为包中的数据结构体与入口函数参数生成 BCS 兼容的 Rust 类型
+///
+/// 带 `key` 能力的对象结构体不生成；包含无法映射的类型的结构体或函数跳过，并在输出中以注释注明。
+/// 入口函数的 `signer` 与 `TxContext` 参数由链填充，不出现在参数结构体中。
+pub fn generate(package: &str, modules: &[MoveModule]) -> String {
+    let names = TypeNames::new(package, modules);
+    let mut modules: Vec<&MoveModule> = modules.iter().collect();
+    modules.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut items = vec![format!(
+        "// 由 `bridge-cli codegen` 根据 {} 的链上 ABI 生成，请勿手工修改\n\nuse serde::{{Deserialize, Serialize}};\n",
+        package
+    )];
+    for module in modules {
+        for item in &module.structs {
+            if item.abilities.iter().any(|ability| ability == "key") {
+                continue;
+            }
+            items.push(render_struct(module, item, &names).unwrap_or_else(|ty| {
+                format!("// 跳过 {}::{}::{}：不支持的类型 {}\n", package, module.name, item.name, ty)
+            }));
+        }
+        for function in module.functions.iter().filter(|f| f.is_entry) {
+            items.push(render_args(module, function, &names).unwrap_or_else(|reason| {
+                format!("// 跳过 {}::{}::{}：{}\n", package, module.name, function.name, reason)
+            }));
+        }
+    }
+    items.join("\n")
+}
+
+fn render_struct(module: &MoveModule, item: &MoveStruct, names: &TypeNames) -> Result<String, String> {
+    let mut fields = Vec::with_capacity(item.fields.len());
+    for field in &item.fields {
+        let ty = names.rust_type(&field.type_).ok_or_else(|| field.type_.to_string())?;
+        fields.push(render_field(&field.name, &field.type_, &ty, None));
+    }
+    let generics = generic_params(item.type_params);
+    Ok(format!(
+        "/// `{}::{}::{}`\n#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]\npub struct {}{} {{\n{}}}\n",
+        names.package,
+        module.name,
+        item.name,
+        names.get(&module.name, &item.name),
+        generics,
+        fields.concat()
+    ))
+}
+
+fn render_args(module: &MoveModule, function: &MoveFunction, names: &TypeNames) -> Result<String, String> {
+    if function.type_params > 0 {
+        return Err("泛型入口函数".to_string());
+    }
+    let mut fields = Vec::new();
+    for (index, param) in function.params.iter().enumerate() {
+        let name = format!("arg{}", index);
+        match param {
+            MoveType::Signer => {}
+            MoveType::Reference { inner, .. } if is_filled_by_chain(inner) => {}
+            // 对象参数在交易中以对象 ID 引用，不做 BCS 编码
+            MoveType::Reference { .. } => {
+                fields.push(render_field(&name, param, "[u8; 32]", Some("对象参数，传入对象 ID")));
+            }
+            _ => {
+                let ty = names.rust_type(param).ok_or_else(|| format!("不支持的参数类型 {}", param))?;
+                fields.push(render_field(&name, param, &ty, None));
+            }
+        }
+    }
+    Ok(format!(
+        "/// `{}::{}::{}` 的参数\n#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]\npub struct {}{}Args {{\n{}}}\n",
+        names.package,
+        module.name,
+        function.name,
+        camel_case(&module.name),
+        camel_case(&function.name),
+        fields.concat()
+    ))
+}
+
+fn render_field(name: &str, move_type: &MoveType, rust_type: &str, note: Option<&str>) -> String {
+    let doc = match (note, move_type) {
+        (Some(note), _) => format!("    /// {}\n", note),
+        (None, MoveType::U256) => "    /// u256 的小端字节\n".to_string(),
+        _ => String::new(),
+    };
+    format!("{}    pub {}: {},\n", doc, name, rust_type)
+}
+
+/// `signer` 与 `TxContext` 由链在执行时提供
+fn is_filled_by_chain(ty: &MoveType) -> bool {
+    match ty {
+        MoveType::Signer => true,
+        MoveType::Struct { module, name, .. } => module == "tx_context" && name == "TxContext",
+        _ => false,
+    }
+}
+
+fn generic_params(count: usize) -> String {
+    if count == 0 {
+        return String::new();
+    }
+    let params: Vec<String> = (0..count).map(|i| format!("T{}", i)).collect();
+    format!("<{}>", params.join(", "))
+}
+
+fn camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// 去掉前导零的小写地址，用于比较长短两种写法
+fn normalize_address(address: &str) -> String {
+    let trimmed = address.trim_start_matches("0x").trim_start_matches('0').to_ascii_lowercase();
+    if trimmed.is_empty() {
+        "0x0".to_string()
+    } else {
+        format!("0x{}", trimmed)
+    }
+}
+
+/// 包内结构体对应的 Rust 类型名；不同模块中重名的结构体加上模块名前缀
+struct TypeNames {
+    package: String,
+    normalized: String,
+    names: HashMap<(String, String), String>,
+}
+
+impl TypeNames {
+    fn new(package: &str, modules: &[MoveModule]) -> Self {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for item in modules.iter().flat_map(|m| &m.structs) {
+            *counts.entry(item.name.as_str()).or_default() += 1;
+        }
+        let mut names = HashMap::new();
+        for module in modules {
+            for item in &module.structs {
+                let name = if counts[item.name.as_str()] > 1 {
+                    format!("{}{}", camel_case(&module.name), item.name)
+                } else {
+                    item.name.clone()
+                };
+                names.insert((module.name.clone(), item.name.clone()), name);
+            }
+        }
+        Self {
+            package: package.to_string(),
+            normalized: normalize_address(package),
+            names,
+        }
+    }
+
+    fn get(&self, module: &str, name: &str) -> String {
+        self.names.get(&(module.to_string(), name.to_string())).cloned().unwrap_or_else(|| name.to_string())
+    }
+
+    /// Move 类型对应的 Rust 类型，无法映射时返回 None
+    fn rust_type(&self, ty: &MoveType) -> Option<String> {
+        Some(match ty {
+            MoveType::Bool => "bool".to_string(),
+            MoveType::U8 => "u8".to_string(),
+            MoveType::U16 => "u16".to_string(),
+            MoveType::U32 => "u32".to_string(),
+            MoveType::U64 => "u64".to_string(),
+            MoveType::U128 => "u128".to_string(),
+            MoveType::U256 | MoveType::Address => "[u8; 32]".to_string(),
+            MoveType::Vector(inner) => format!("Vec<{}>", self.rust_type(inner)?),
+            MoveType::TypeParam(index) => format!("T{}", index),
+            MoveType::Struct { address, module, name, type_args } => {
+                let address = normalize_address(address);
+                match (address.as_str(), module.as_str(), name.as_str(), type_args.as_slice()) {
+                    ("0x1", "string" | "ascii", "String", []) => "String".to_string(),
+                    // Move 的 Option 以长度为 0 或 1 的 vector 编码，与 Rust Option 的 BCS 编码相同
+                    ("0x1", "option", "Option", [inner]) => format!("Option<{}>", self.rust_type(inner)?),
+                    ("0x2", "object", "UID" | "ID" | "ObjectID", []) => "[u8; 32]".to_string(),
+                    _ if address == self.normalized && self.names.contains_key(&(module.clone(), name.clone())) => {
+                        let mut rust = self.get(module, name);
+                        if !type_args.is_empty() {
+                            let args = type_args.iter().map(|arg| self.rust_type(arg)).collect::<Option<Vec<_>>>()?;
+                            rust = format!("{}<{}>", rust, args.join(", "));
+                        }
+                        rust
+                    }
+                    _ => return None,
+                }
+            }
+            MoveType::Signer | MoveType::Reference { .. } => return None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_generate_from_sui_abi() {
+        let modules = parse_sui_modules(&json!({
+            "bridge": {
+                "address": "0x42",
+                "name": "bridge",
+                "structs": {
+                    "MessageEvent": {
+                        "abilities": { "abilities": ["Copy", "Drop"] },
+                        "typeParameters": [],
+                        "fields": [
+                            { "name": "nonce", "type": "U64" },
+                            { "name": "source_chain", "type": { "Struct": {
+                                "address": "0x1", "module": "string", "name": "String", "typeArguments": []
+                            } } },
+                            { "name": "payload", "type": { "Vector": "U8" } },
+                            { "name": "amount", "type": "U256" }
+                        ]
+                    },
+                    "Bridge": {
+                        "abilities": { "abilities": ["Key"] },
+                        "typeParameters": [],
+                        "fields": [{ "name": "id", "type": { "Struct": {
+                            "address": "0x2", "module": "object", "name": "UID", "typeArguments": []
+                        } } }]
+                    }
+                },
+                "exposedFunctions": {
+                    "process_message": {
+                        "visibility": "Public",
+                        "isEntry": true,
+                        "typeParameters": [],
+                        "parameters": [
+                            { "MutableReference": { "Struct": {
+                                "address": "0x42", "module": "bridge", "name": "Bridge", "typeArguments": []
+                            } } },
+                            { "Vector": "U8" },
+                            { "MutableReference": { "Struct": {
+                                "address": "0x2", "module": "tx_context", "name": "TxContext", "typeArguments": []
+                            } } }
+                        ],
+                        "return": []
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let code = generate("0x42", &modules);
+        assert!(code.contains("pub struct MessageEvent {\n    pub nonce: u64,\n    pub source_chain: String,\n    pub payload: Vec<u8>,\n    /// u256 的小端字节\n    pub amount: [u8; 32],\n}"));
+        assert!(!code.contains("pub struct Bridge "));
+        assert!(code.contains("pub struct BridgeProcessMessageArgs {\n    /// 对象参数，传入对象 ID\n    pub arg0: [u8; 32],\n    pub arg1: Vec<u8>,\n}"));
+
+        assert_eq!(parse_type_tag("vector<0x1::option::Option<u64>>").unwrap().to_string(), "vector<0x1::option::Option<u64>>");
+    }
+}
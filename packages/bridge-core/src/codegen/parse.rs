@@ -0,0 +1,231 @@
+use serde::Deserialize;
+use serde_json::Value;
+use crate::Error;
+use super::{MoveField, MoveFunction, MoveModule, MoveStruct, MoveType};
+
+fn invalid(what: &str, value: &impl std::fmt::Display) -> Error {
+    Error::Serialization(format!("Invalid {}: {}", what, value))
+}
+
+/// 解析 `sui_getNormalizedMoveModulesByPackage` 返回的模块名到模块的映射
+pub fn parse_sui_modules(value: &Value) -> Result<Vec<MoveModule>, Error> {
+    let modules = value.as_object().ok_or_else(|| invalid("normalized modules", value))?;
+    modules.values().map(parse_sui_module).collect()
+}
+
+fn parse_sui_module(value: &Value) -> Result<MoveModule, Error> {
+    let text = |key: &str| value[key].as_str().map(str::to_string).ok_or_else(|| invalid("normalized module", value));
+    let mut structs = Vec::new();
+    for (name, item) in value["structs"].as_object().into_iter().flatten() {
+        let mut fields = Vec::new();
+        for field in item["fields"].as_array().into_iter().flatten() {
+            fields.push(MoveField {
+                name: field["name"].as_str().ok_or_else(|| invalid("struct field", field))?.to_string(),
+                type_: parse_sui_type(&field["type"])?,
+            });
+        }
+        structs.push(MoveStruct {
+            name: name.clone(),
+            abilities: item["abilities"]["abilities"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .map(str::to_ascii_lowercase)
+                .collect(),
+            type_params: item["typeParameters"].as_array().map_or(0, Vec::len),
+            fields,
+        });
+    }
+
+    let mut functions = Vec::new();
+    for (name, item) in value["exposedFunctions"].as_object().into_iter().flatten() {
+        functions.push(MoveFunction {
+            name: name.clone(),
+            is_entry: item["isEntry"].as_bool().unwrap_or(false),
+            type_params: item["typeParameters"].as_array().map_or(0, Vec::len),
+            params: item["parameters"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(parse_sui_type)
+                .collect::<Result<_, _>>()?,
+        });
+    }
+    Ok(MoveModule { address: text("address")?, name: text("name")?, structs, functions })
+}
+
+/// Sui 规范化类型：基本类型为字符串，其余为单键对象
+fn parse_sui_type(value: &Value) -> Result<MoveType, Error> {
+    if let Some(name) = value.as_str() {
+        return primitive(&name.to_ascii_lowercase()).ok_or_else(|| invalid("Move type", value));
+    }
+    let (kind, inner) = value
+        .as_object()
+        .filter(|object| object.len() == 1)
+        .and_then(|object| object.iter().next())
+        .ok_or_else(|| invalid("Move type", value))?;
+    Ok(match kind.as_str() {
+        "Vector" => MoveType::Vector(Box::new(parse_sui_type(inner)?)),
+        "Reference" => MoveType::Reference { mutable: false, inner: Box::new(parse_sui_type(inner)?) },
+        "MutableReference" => MoveType::Reference { mutable: true, inner: Box::new(parse_sui_type(inner)?) },
+        "TypeParameter" => MoveType::TypeParam(inner.as_u64().and_then(|i| u16::try_from(i).ok()).ok_or_else(|| invalid("type parameter", inner))?),
+        "Struct" => {
+            let text = |key: &str| inner[key].as_str().map(str::to_string).ok_or_else(|| invalid("struct type", inner));
+            MoveType::Struct {
+                address: text("address")?,
+                module: text("module")?,
+                name: text("name")?,
+                type_args: inner["typeArguments"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(parse_sui_type)
+                    .collect::<Result<_, _>>()?,
+            }
+        }
+        _ => return Err(invalid("Move type", value)),
+    })
+}
+
+fn primitive(name: &str) -> Option<MoveType> {
+    Some(match name {
+        "bool" => MoveType::Bool,
+        "u8" => MoveType::U8,
+        "u16" => MoveType::U16,
+        "u32" => MoveType::U32,
+        "u64" => MoveType::U64,
+        "u128" => MoveType::U128,
+        "u256" => MoveType::U256,
+        "address" => MoveType::Address,
+        "signer" => MoveType::Signer,
+        _ => return None,
+    })
+}
+
+/// `rooch_getModuleABI` 返回的模块 ABI，类型以 Move 类型标签字符串表示
+#[derive(Deserialize)]
+struct RoochModuleAbi {
+    address: String,
+    name: String,
+    #[serde(default)]
+    structs: Vec<RoochStructAbi>,
+    #[serde(default)]
+    functions: Vec<RoochFunctionAbi>,
+}
+
+#[derive(Deserialize)]
+struct RoochStructAbi {
+    name: String,
+    #[serde(default)]
+    abilities: Vec<String>,
+    #[serde(default)]
+    type_parameters: Vec<Value>,
+    #[serde(default)]
+    fields: Vec<RoochFieldAbi>,
+}
+
+#[derive(Deserialize)]
+struct RoochFieldAbi {
+    name: String,
+    #[serde(alias = "type")]
+    type_tag: String,
+}
+
+#[derive(Deserialize)]
+struct RoochFunctionAbi {
+    name: String,
+    #[serde(default)]
+    is_entry: bool,
+    #[serde(default)]
+    type_parameters: Vec<Value>,
+    #[serde(default, alias = "parameters")]
+    params: Vec<String>,
+}
+
+/// 解析 Rooch 的模块 ABI
+pub fn parse_rooch_module(value: Value) -> Result<MoveModule, Error> {
+    let abi: RoochModuleAbi = serde_json::from_value(value)
+        .map_err(|e| Error::Serialization(format!("Invalid module ABI: {}", e)))?;
+    let mut structs = Vec::with_capacity(abi.structs.len());
+    for item in abi.structs {
+        let fields = item.fields
+            .into_iter()
+            .map(|field| Ok(MoveField { name: field.name, type_: parse_type_tag(&field.type_tag)? }))
+            .collect::<Result<_, Error>>()?;
+        structs.push(MoveStruct {
+            name: item.name,
+            abilities: item.abilities.iter().map(|a| a.to_ascii_lowercase()).collect(),
+            type_params: item.type_parameters.len(),
+            fields,
+        });
+    }
+    let mut functions = Vec::with_capacity(abi.functions.len());
+    for item in abi.functions {
+        functions.push(MoveFunction {
+            name: item.name,
+            is_entry: item.is_entry,
+            type_params: item.type_parameters.len(),
+            params: item.params.iter().map(|p| parse_type_tag(p)).collect::<Result<_, _>>()?,
+        });
+    }
+    Ok(MoveModule { address: abi.address, name: abi.name, structs, functions })
+}
+
+/// 解析 Move 类型标签，例如 `vector<0x1::option::Option<u64>>`、`&mut T0`
+pub fn parse_type_tag(tag: &str) -> Result<MoveType, Error> {
+    let tag = tag.trim();
+    if let Some(inner) = tag.strip_prefix("&mut ") {
+        return Ok(MoveType::Reference { mutable: true, inner: Box::new(parse_type_tag(inner)?) });
+    }
+    if let Some(inner) = tag.strip_prefix('&') {
+        return Ok(MoveType::Reference { mutable: false, inner: Box::new(parse_type_tag(inner)?) });
+    }
+    if let Some(primitive) = primitive(tag) {
+        return Ok(primitive);
+    }
+    if let Some(index) = tag.strip_prefix('T').and_then(|i| i.parse().ok()) {
+        return Ok(MoveType::TypeParam(index));
+    }
+
+    let (path, type_args) = match tag.split_once('<') {
+        Some((path, rest)) => {
+            let args = rest.strip_suffix('>').ok_or_else(|| invalid("type tag", &tag))?;
+            (path, split_type_args(args).into_iter().map(parse_type_tag).collect::<Result<Vec<_>, _>>()?)
+        }
+        None => (tag, Vec::new()),
+    };
+    if path == "vector" {
+        let [inner]: [MoveType; 1] = type_args.try_into().map_err(|_| invalid("type tag", &tag))?;
+        return Ok(MoveType::Vector(Box::new(inner)));
+    }
+    match path.split("::").collect::<Vec<_>>().as_slice() {
+        [address, module, name] => Ok(MoveType::Struct {
+            address: address.to_string(),
+            module: module.to_string(),
+            name: name.to_string(),
+            type_args,
+        }),
+        _ => Err(invalid("type tag", &tag)),
+    }
+}
+
+/// 按顶层逗号拆分类型参数列表
+fn split_type_args(args: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in args.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(&args[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&args[start..]);
+    parts
+}
@@ -0,0 +1,180 @@
+use blake2::{Blake2b, Digest, digest::consts::U32};
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng, Payload},
+    ChaCha20Poly1305, Key, Nonce,
+};
+use hkdf::Hkdf;
+use sha2::Sha256;
+use std::path::Path;
+use x25519_dalek::{PublicKey, StaticSecret};
+use crate::{types::CrossChainMessage, Error};
+
+/// 机密消息的类型名，负载为发往目标合约公钥的加密信封
+pub const CONFIDENTIAL_MESSAGE_TYPE: &str = "confidential";
+
+const ENVELOPE_VERSION: u8 = 1;
+const KEY_ID_LEN: usize = 8;
+const NONCE_LEN: usize = 12;
+/// 版本 || 公钥标识 || 临时公钥 || AEAD nonce
+const HEADER_LEN: usize = 1 + KEY_ID_LEN + 32 + NONCE_LEN;
+/// Poly1305 认证标签长度
+const TAG_LEN: usize = 16;
+const HKDF_INFO: &[u8] = b"move-bridge confidential payload v1";
+
+/// 公钥标识：公钥 Blake2b-256 摘要的前 8 字节，中继器据此确认信封发往目标链登记的公钥
+pub fn key_id(public_key: &[u8; 32]) -> [u8; KEY_ID_LEN] {
+    let digest = Blake2b::<U32>::digest(public_key);
+    let mut id = [0u8; KEY_ID_LEN];
+    id.copy_from_slice(&digest[..KEY_ID_LEN]);
+    id
+}
+
+/// 解析十六进制编码的 X25519 公钥
+pub fn parse_public_key(public_key: &str) -> Result<[u8; 32], Error> {
+    hex::decode(public_key.trim_start_matches("0x"))
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| Error::Key(format!("Invalid X25519 public key: {}", public_key)))
+}
+
+/// 目标合约运营方持有的负载解密密钥
+pub struct PayloadKey {
+    secret: StaticSecret,
+}
+
+impl PayloadKey {
+    pub fn generate() -> Self {
+        Self { secret: StaticSecret::random_from_rng(OsRng) }
+    }
+
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self { secret: StaticSecret::from(bytes) }
+    }
+
+    /// 从密钥文件加载，文件内容为十六进制编码的 X25519 私钥
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| Error::Key(format!("Failed to read payload key: {}", e)))?;
+        let bytes: [u8; 32] = hex::decode(content.trim().trim_start_matches("0x"))
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| Error::Key("Invalid payload key encoding".to_string()))?;
+        Ok(Self::from_bytes(bytes))
+    }
+
+    pub fn secret_bytes(&self) -> [u8; 32] {
+        self.secret.to_bytes()
+    }
+
+    pub fn public_key(&self) -> [u8; 32] {
+        PublicKey::from(&self.secret).to_bytes()
+    }
+
+    /// 解密信封，`source_chain` 与 `target_chain` 需与加密时一致
+    pub fn open(&self, source_chain: &str, target_chain: &str, envelope: &[u8]) -> Result<Vec<u8>, Error> {
+        let envelope = Envelope::parse(envelope)?;
+        if envelope.key_id != key_id(&self.public_key()) {
+            return Err(Error::Key("Envelope was sealed to a different public key".to_string()));
+        }
+        let shared = self.secret.diffie_hellman(&PublicKey::from(envelope.ephemeral));
+        let cipher = cipher(shared.as_bytes(), &envelope.ephemeral, &self.public_key())?;
+        cipher
+            .decrypt(
+                Nonce::from_slice(&envelope.nonce),
+                Payload { msg: envelope.ciphertext, aad: &associated_data(source_chain, target_chain)? },
+            )
+            .map_err(|_| Error::Key("Failed to decrypt confidential payload".to_string()))
+    }
+}
+
+/// 加密信封：`版本 || 公钥标识 || 临时公钥 || nonce || 密文`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Envelope<'a> {
+    pub key_id: [u8; KEY_ID_LEN],
+    pub ephemeral: [u8; 32],
+    pub nonce: [u8; NONCE_LEN],
+    pub ciphertext: &'a [u8],
+}
+
+impl<'a> Envelope<'a> {
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, Error> {
+        if bytes.len() < HEADER_LEN + TAG_LEN {
+            return Err(Error::Serialization("Confidential envelope is truncated".to_string()));
+        }
+        if bytes[0] != ENVELOPE_VERSION {
+            return Err(Error::Serialization(format!("Unsupported envelope version: {}", bytes[0])));
+        }
+        let (header, ciphertext) = bytes.split_at(HEADER_LEN);
+        Ok(Self {
+            key_id: header[1..1 + KEY_ID_LEN].try_into().expect("header length checked"),
+            ephemeral: header[1 + KEY_ID_LEN..1 + KEY_ID_LEN + 32].try_into().expect("header length checked"),
+            nonce: header[HEADER_LEN - NONCE_LEN..].try_into().expect("header length checked"),
+            ciphertext,
+        })
+    }
+}
+
+/// 使用临时密钥把负载加密到目标合约登记的公钥
+///
+/// 源链与目标链作为附加认证数据，信封被转发到其它路由时无法解密。
+pub fn seal(recipient: &[u8; 32], source_chain: &str, target_chain: &str, plaintext: &[u8]) -> Result<Vec<u8>, Error> {
+    let ephemeral = StaticSecret::random_from_rng(OsRng);
+    let ephemeral_public = PublicKey::from(&ephemeral).to_bytes();
+    let shared = ephemeral.diffie_hellman(&PublicKey::from(*recipient));
+    let cipher = cipher(shared.as_bytes(), &ephemeral_public, recipient)?;
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, Payload { msg: plaintext, aad: &associated_data(source_chain, target_chain)? })
+        .map_err(|_| Error::Key("Failed to encrypt confidential payload".to_string()))?;
+
+    let mut envelope = Vec::with_capacity(HEADER_LEN + ciphertext.len());
+    envelope.push(ENVELOPE_VERSION);
+    envelope.extend_from_slice(&key_id(recipient));
+    envelope.extend_from_slice(&ephemeral_public);
+    envelope.extend_from_slice(&nonce);
+    envelope.extend_from_slice(&ciphertext);
+    Ok(envelope)
+}
+
+/// 存储用的消息副本：机密消息的负载被清空，只保留可由消息 ID 追溯的规范哈希
+///
+/// 非机密消息返回 None。
+pub fn redact(message: &CrossChainMessage) -> Option<CrossChainMessage> {
+    (message.message_type == CONFIDENTIAL_MESSAGE_TYPE).then(|| CrossChainMessage {
+        payload: Vec::new(),
+        ..message.clone()
+    })
+}
+
+fn cipher(shared: &[u8; 32], ephemeral: &[u8; 32], recipient: &[u8; 32]) -> Result<ChaCha20Poly1305, Error> {
+    let salt = [ephemeral.as_slice(), recipient.as_slice()].concat();
+    let mut key = [0u8; 32];
+    Hkdf::<Sha256>::new(Some(&salt), shared)
+        .expand(HKDF_INFO, &mut key)
+        .map_err(|e| Error::Key(format!("Failed to derive payload key: {}", e)))?;
+    Ok(ChaCha20Poly1305::new(Key::from_slice(&key)))
+}
+
+fn associated_data(source_chain: &str, target_chain: &str) -> Result<Vec<u8>, Error> {
+    bcs::to_bytes(&(source_chain, target_chain)).map_err(|e| Error::Serialization(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seal_and_open() {
+        let key = PayloadKey::generate();
+        let envelope = seal(&key.public_key(), "sui", "rooch", b"invoice #42").unwrap();
+        assert_eq!(Envelope::parse(&envelope).unwrap().key_id, key_id(&key.public_key()));
+        assert_eq!(key.open("sui", "rooch", &envelope).unwrap(), b"invoice #42");
+
+        // 路由不一致或密文被篡改时无法解密
+        assert!(key.open("rooch", "sui", &envelope).is_err());
+        let mut tampered = envelope.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(key.open("sui", "rooch", &tampered).is_err());
+        assert!(PayloadKey::generate().open("sui", "rooch", &envelope).is_err());
+    }
+}
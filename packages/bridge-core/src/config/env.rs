@@ -0,0 +1,264 @@
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use crate::Error;
+
+/// 覆盖配置项的环境变量前缀
+///
+/// `BRIDGE__` 之后按 `__` 分段，每段对应一层 JSON 键（不区分大小写，统一转为小写），
+/// 数字段为数组下标。例如 `BRIDGE__CHAINS__0__RPC_URL` 覆盖 `chains[0].rpc_url`，
+/// `BRIDGE__RELAYER__POLL_INTERVAL` 覆盖 `relayer.poll_interval`。
+/// 原值为字符串时覆盖值按原样使用，否则先按 JSON 解析，解析失败时作为字符串。
+pub const OVERRIDE_PREFIX: &str = "BRIDGE__";
+
+/// 配置值的来源
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValueOrigin {
+    /// 配置文件中的 `${VAR}` 引用，按出现顺序列出变量名
+    Interpolated(Vec<String>),
+    /// `BRIDGE__` 前缀的覆盖变量
+    Override(String),
+}
+
+impl fmt::Display for ValueOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValueOrigin::Interpolated(vars) => {
+                let vars: Vec<String> = vars.iter().map(|v| format!("${{{}}}", v)).collect();
+                write!(f, "interpolated from {}", vars.join(", "))
+            }
+            ValueOrigin::Override(var) => write!(f, "overridden by {}", var),
+        }
+    }
+}
+
+/// 来自环境变量的配置值，按 JSON 路径（如 `chains[0].rpc_url`）记录
+#[derive(Debug, Clone, Default)]
+pub struct ConfigSources {
+    values: BTreeMap<String, (String, ValueOrigin)>,
+}
+
+impl ConfigSources {
+    /// 指定路径的值来源，来自配置文件原文时为 None
+    pub fn origin(&self, path: &str) -> Option<&ValueOrigin> {
+        self.values.get(path).map(|(_, origin)| origin)
+    }
+
+    /// 在配置错误后附上相关值的来源
+    ///
+    /// 错误信息中出现的解析结果视为相关值，空值不参与匹配。
+    pub fn annotate(&self, error: Error) -> Error {
+        let Error::Config(message) = error else { return error };
+        let origins: Vec<String> = self.values
+            .iter()
+            .filter(|(_, (value, _))| !value.is_empty() && message.contains(value.as_str()))
+            .map(|(path, (_, origin))| format!("{} {}", path, origin))
+            .collect();
+        if origins.is_empty() {
+            return Error::Config(message);
+        }
+        Error::Config(format!("{} ({})", message, origins.join("; ")))
+    }
+
+    fn record(&mut self, path: String, value: &Value, origin: ValueOrigin) {
+        let resolved = match value {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+        self.values.insert(path, (resolved, origin));
+    }
+}
+
+/// 展开配置中所有字符串值里的 `${VAR}` 引用
+///
+/// `$${` 表示字面量 `${`。引用的变量未设置时返回指出配置路径的错误。
+pub fn interpolate(value: &mut Value, env: &HashMap<String, String>, sources: &mut ConfigSources) -> Result<(), Error> {
+    interpolate_at(value, String::new(), env, sources)
+}
+
+fn interpolate_at(value: &mut Value, path: String, env: &HashMap<String, String>, sources: &mut ConfigSources) -> Result<(), Error> {
+    match value {
+        Value::String(s) => {
+            if let Some((resolved, vars)) = expand(s, &path, env)? {
+                *s = resolved;
+                if !vars.is_empty() {
+                    sources.record(path, value, ValueOrigin::Interpolated(vars));
+                }
+            }
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter_mut().enumerate() {
+                interpolate_at(item, format!("{}[{}]", path, index), env, sources)?;
+            }
+        }
+        Value::Object(fields) => {
+            for (key, field) in fields.iter_mut() {
+                interpolate_at(field, join(&path, key), env, sources)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// 展开单个字符串，不含 `${` 时返回 None
+fn expand(input: &str, path: &str, env: &HashMap<String, String>) -> Result<Option<(String, Vec<String>)>, Error> {
+    if !input.contains("${") {
+        return Ok(None);
+    }
+    let mut output = String::with_capacity(input.len());
+    let mut vars = Vec::new();
+    let mut rest = input;
+    while let Some(start) = rest.find('$') {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if let Some(tail) = rest.strip_prefix("$${") {
+            output.push_str("${");
+            rest = tail;
+        } else if let Some(tail) = rest.strip_prefix("${") {
+            let end = tail.find('}').ok_or_else(|| {
+                Error::Config(format!("Unterminated ${{...}} reference at {}", path))
+            })?;
+            let name = &tail[..end];
+            if name.is_empty() {
+                return Err(Error::Config(format!("Empty ${{}} reference at {}", path)));
+            }
+            let resolved = env.get(name).ok_or_else(|| {
+                Error::Config(format!("Environment variable {} referenced at {} is not set", name, path))
+            })?;
+            output.push_str(resolved);
+            vars.push(name.to_string());
+            rest = &tail[end + 1..];
+        } else {
+            output.push('$');
+            rest = &rest[1..];
+        }
+    }
+    output.push_str(rest);
+    Ok(Some((output, vars)))
+}
+
+/// 应用 `BRIDGE__` 前缀的覆盖变量
+///
+/// 变量按名称排序后依次应用，结果与环境变量的枚举顺序无关。
+/// 中间层对象不存在时自动创建；数组下标越界或路径穿过标量值时返回错误。
+pub fn apply_overrides(value: &mut Value, env: &HashMap<String, String>, sources: &mut ConfigSources) -> Result<(), Error> {
+    let overrides: BTreeMap<&String, &String> = env
+        .iter()
+        .filter(|(name, _)| name.starts_with(OVERRIDE_PREFIX))
+        .collect();
+    for (name, raw) in overrides {
+        let segments: Vec<String> = name[OVERRIDE_PREFIX.len()..]
+            .split("__")
+            .map(str::to_lowercase)
+            .collect();
+        if segments.iter().any(String::is_empty) {
+            return Err(Error::Config(format!("Invalid config override variable: {}", name)));
+        }
+
+        let mut path = String::new();
+        let mut target = &mut *value;
+        for segment in &segments {
+            if target.is_null() {
+                *target = Value::Object(Default::default());
+            }
+            target = match target {
+                Value::Object(fields) => {
+                    path = join(&path, segment);
+                    fields.entry(segment.clone()).or_insert(Value::Null)
+                }
+                Value::Array(items) => {
+                    let index: usize = segment.parse().map_err(|_| {
+                        Error::Config(format!("{} expects an array index at {}, found {}", name, path, segment))
+                    })?;
+                    let len = items.len();
+                    path = format!("{}[{}]", path, index);
+                    items.get_mut(index).ok_or_else(|| {
+                        Error::Config(format!("{} indexes {} but the array has {} elements", name, path, len))
+                    })?
+                }
+                _ => {
+                    return Err(Error::Config(format!("{} descends into non-object value at {}", name, path)));
+                }
+            };
+        }
+
+        *target = match target {
+            Value::String(_) => Value::String(raw.clone()),
+            _ => serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.clone())),
+        };
+        sources.record(path, target, ValueOrigin::Override(name.clone()));
+    }
+    Ok(())
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn env(vars: &[(&str, &str)]) -> HashMap<String, String> {
+        vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn test_interpolation_and_overrides() {
+        let mut value = json!({
+            "chains": [{"id": "sui", "rpc_url": "https://${HOST}/v1?key=${KEY}", "chain_id": 1}],
+            "relayer": {"poll_interval": 5, "keystore": "$${literal}"},
+        });
+        let vars = env(&[
+            ("HOST", "rpc.example"),
+            ("KEY", "s3cret"),
+            ("BRIDGE__CHAINS__0__CHAIN_ID", "7"),
+            ("BRIDGE__CHAINS__0__ID", "123"),
+            ("BRIDGE__HEALTH__LISTEN", "0.0.0.0:9000"),
+        ]);
+        let mut sources = ConfigSources::default();
+        interpolate(&mut value, &vars, &mut sources).unwrap();
+        apply_overrides(&mut value, &vars, &mut sources).unwrap();
+
+        assert_eq!(value["chains"][0]["rpc_url"], "https://rpc.example/v1?key=s3cret");
+        assert_eq!(value["relayer"]["keystore"], "${literal}");
+        assert_eq!(value["chains"][0]["chain_id"], 7);
+        // 原值为字符串时不按 JSON 解析
+        assert_eq!(value["chains"][0]["id"], "123");
+        assert_eq!(value["health"]["listen"], "0.0.0.0:9000");
+        assert_eq!(
+            sources.origin("chains[0].rpc_url"),
+            Some(&ValueOrigin::Interpolated(vec!["HOST".to_string(), "KEY".to_string()]))
+        );
+
+        let annotated = sources.annotate(Error::Config("Invalid adapter type: 123".to_string()));
+        assert!(annotated.to_string().contains("chains[0].id overridden by BRIDGE__CHAINS__0__ID"));
+
+        let missing = interpolate(&mut json!({"api": {"token": "${NOPE}"}}), &vars, &mut sources).unwrap_err();
+        assert!(missing.to_string().contains("NOPE referenced at api.token"));
+        let out_of_range = apply_overrides(&mut value, &env(&[("BRIDGE__CHAINS__3__ID", "x")]), &mut sources).unwrap_err();
+        assert!(out_of_range.to_string().contains("chains[3]"));
+    }
+
+    #[test]
+    fn test_load_reports_override_origin() {
+        let config_str = serde_json::to_string(&crate::testing::two_chain_config()).unwrap()
+            .replace("mock://mock_source", "${SOURCE_RPC}")
+            .replace("\"mock\"", "\"rooch\"");
+        let vars = env(&[("SOURCE_RPC", "mock://resolved"), ("BRIDGE__RELAYER__POLL_INTERVAL", "5")]);
+        let config = crate::config::Config::parse_with_env(&config_str, &vars).unwrap();
+        assert_eq!(config.chains[0].rpc_url, "mock://resolved");
+        assert_eq!(config.relayer.poll_interval, 5);
+
+        let vars = env(&[("SOURCE_RPC", "x"), ("BRIDGE__CHAINS__1__ADAPTER_TYPE", "aptos")]);
+        let error = crate::config::Config::parse_with_env(&config_str, &vars).unwrap_err().to_string();
+        assert!(error.contains("Invalid adapter type: aptos"));
+        assert!(error.contains("chains[1].adapter_type overridden by BRIDGE__CHAINS__1__ADAPTER_TYPE"));
+    }
+}
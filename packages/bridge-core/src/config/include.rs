@@ -0,0 +1,377 @@
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use super::ConfigFormat;
+use crate::Error;
+
+/// 引用其它配置片段的键，值为单个路径或路径列表，相对于所在文件的目录
+///
+/// 路径为目录时引用其中所有 `.json` 与 `.toml` 文件，按文件名排序。
+pub const INCLUDE_KEY: &str = "include";
+
+/// 按主键合并的顶层列表及其主键字段
+const KEYED_LISTS: &[(&str, &str)] = &[
+    ("chains", "id"),
+    ("assets", "name"),
+    ("nft_collections", "name"),
+    ("validators", "address"),
+];
+
+/// 读取配置文件并展开其中的 `include`
+///
+/// 被引用的片段按列出的顺序合并，彼此之间不能为同一配置项给出不同的值，`chains`、`assets`、
+/// `nft_collections`、`validators` 按主键拼接，主键重复即为冲突；引用方自身的内容最后合并，
+/// 可覆盖片段中的值，列表项按主键逐项覆盖。所有冲突一并报告，并附上各自所在的文件与行号。
+pub fn load_file(path: &Path) -> Result<Value, Error> {
+    let mut loader = Loader::default();
+    let fragment = loader.file(path)?;
+    loader.finish(fragment)
+}
+
+/// 合并目录中的所有 `.json` 与 `.toml` 文件，文件之间的规则同 `include` 引用的片段
+pub fn load_dir(dir: &Path) -> Result<Value, Error> {
+    let mut loader = Loader::default();
+    let fragment = loader.siblings(&fragment_files(dir)?)?;
+    loader.finish(fragment)
+}
+
+/// 目录中的配置片段，按文件名排序
+fn fragment_files(dir: &Path) -> Result<Vec<PathBuf>, Error> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| Error::Config(format!("Failed to read config directory {}: {}", dir.display(), e)))?;
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|e| Error::Config(format!("Failed to read config directory {}: {}", dir.display(), e)))?
+            .path();
+        let fragment = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json") || ext.eq_ignore_ascii_case("toml"));
+        if fragment && path.is_file() {
+            files.push(path);
+        }
+    }
+    if files.is_empty() {
+        return Err(Error::Config(format!("No config files found in {}", dir.display())));
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// 合并中的配置文档，记录各配置项来自哪个文件
+#[derive(Default)]
+struct Fragment {
+    value: Value,
+    /// 配置项路径到文件下标；列表项以主键标识，如 `chains[sui].rpc_url`
+    origins: BTreeMap<String, usize>,
+}
+
+#[derive(Default)]
+struct Loader {
+    /// 已读取的文件路径与原文
+    files: Vec<(PathBuf, String)>,
+    /// 正在展开的文件，用于检测循环引用
+    stack: Vec<PathBuf>,
+    conflicts: Vec<String>,
+}
+
+impl Loader {
+    fn finish(self, fragment: Fragment) -> Result<Value, Error> {
+        match self.conflicts.as_slice() {
+            [] => Ok(fragment.value),
+            [conflict] => Err(Error::Config(conflict.clone())),
+            conflicts => {
+                let lines: Vec<String> = conflicts.iter().map(|conflict| format!("  - {}", conflict)).collect();
+                Err(Error::Config(format!("{} config conflicts found:\n{}", conflicts.len(), lines.join("\n"))))
+            }
+        }
+    }
+
+    fn file(&mut self, path: &Path) -> Result<Fragment, Error> {
+        let canonical = path
+            .canonicalize()
+            .map_err(|e| Error::Config(format!("Failed to read config file {}: {}", path.display(), e)))?;
+        if self.stack.contains(&canonical) {
+            let chain: Vec<String> = self.stack.iter().chain([&canonical]).map(|p| p.display().to_string()).collect();
+            return Err(Error::Config(format!("Config include cycle: {}", chain.join(" -> "))));
+        }
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| Error::Config(format!("Failed to read config file {}: {}", path.display(), e)))?;
+        let mut value = ConfigFormat::from_path(path)
+            .parse(&text)
+            .map_err(|e| Error::Config(format!("{}: {}", path.display(), e)))?;
+
+        let includes = match value.as_object_mut().and_then(|fields| fields.remove(INCLUDE_KEY)) {
+            None => Vec::new(),
+            Some(Value::String(include)) => vec![include],
+            Some(Value::Array(items)) => items
+                .into_iter()
+                .map(|item| match item {
+                    Value::String(include) => Ok(include),
+                    other => Err(Error::Config(format!("{}: include entries must be paths, found {}", path.display(), other))),
+                })
+                .collect::<Result<_, _>>()?,
+            Some(other) => {
+                return Err(Error::Config(format!("{}: include must be a path or a list of paths, found {}", path.display(), other)));
+            }
+        };
+
+        let index = self.files.len();
+        self.files.push((path.to_path_buf(), text));
+        let mut own = Fragment::default();
+        record_origins(&value, String::new(), index, &mut own.origins);
+        own.value = value;
+        if includes.is_empty() {
+            return Ok(own);
+        }
+
+        self.stack.push(canonical);
+        let base = path.parent().unwrap_or(Path::new(""));
+        let mut files = Vec::new();
+        for include in &includes {
+            let target = base.join(include);
+            if target.is_dir() {
+                files.extend(fragment_files(&target)?);
+            } else {
+                files.push(target);
+            }
+        }
+        let mut merged = self.siblings(&files)?;
+        self.stack.pop();
+
+        self.merge(&mut merged, own, true);
+        Ok(merged)
+    }
+
+    /// 依次合并同级片段，不允许相互覆盖
+    fn siblings(&mut self, files: &[PathBuf]) -> Result<Fragment, Error> {
+        let mut merged = Fragment { value: Value::Object(Default::default()), origins: BTreeMap::new() };
+        for file in files {
+            let fragment = self.file(file)?;
+            self.merge(&mut merged, fragment, false);
+        }
+        Ok(merged)
+    }
+
+    fn merge(&mut self, target: &mut Fragment, overlay: Fragment, overriding: bool) {
+        let Fragment { value, origins } = overlay;
+        self.merge_value(&mut target.value, &mut target.origins, value, &origins, String::new(), overriding);
+    }
+
+    fn merge_value(
+        &mut self,
+        target: &mut Value,
+        target_origins: &mut BTreeMap<String, usize>,
+        overlay: Value,
+        overlay_origins: &BTreeMap<String, usize>,
+        path: String,
+        overriding: bool,
+    ) {
+        match (target, overlay) {
+            (Value::Object(fields), Value::Object(overlay_fields)) => {
+                for (key, value) in overlay_fields {
+                    let child = join(&path, &key);
+                    match fields.get_mut(&key) {
+                        Some(existing) => self.merge_value(existing, target_origins, value, overlay_origins, child, overriding),
+                        None => {
+                            copy_origins(&child, overlay_origins, target_origins);
+                            fields.insert(key, value);
+                        }
+                    }
+                }
+            }
+            (Value::Array(items), Value::Array(overlay_items)) if keyed_list(&path).is_some() => {
+                let id_field = keyed_list(&path).expect("keyed list");
+                for item in overlay_items {
+                    let Some(id) = entry_id(&item, id_field) else {
+                        items.push(item);
+                        continue;
+                    };
+                    let entry = format!("{}[{}]", path, id);
+                    match items.iter_mut().find(|existing| entry_id(existing, id_field).as_deref() == Some(id.as_str())) {
+                        Some(existing) if overriding => {
+                            self.merge_value(existing, target_origins, item, overlay_origins, entry, true);
+                        }
+                        Some(_) => self.conflict(&entry, target_origins, overlay_origins, "is defined more than once"),
+                        None => {
+                            copy_origins(&entry, overlay_origins, target_origins);
+                            items.push(item);
+                        }
+                    }
+                }
+            }
+            (target, overlay) => {
+                if *target == overlay {
+                    return;
+                }
+                if overriding {
+                    target_origins.retain(|key, _| !within(key, &path));
+                    copy_origins(&path, overlay_origins, target_origins);
+                    *target = overlay;
+                } else {
+                    self.conflict(&path, target_origins, overlay_origins, "has conflicting values");
+                }
+            }
+        }
+    }
+
+    fn conflict(&mut self, path: &str, first: &BTreeMap<String, usize>, second: &BTreeMap<String, usize>, problem: &str) {
+        let locations = [first, second].map(|origins| match origins.get(path) {
+            Some(index) => {
+                let (file, text) = &self.files[*index];
+                match line_of(text, path) {
+                    Some(line) => format!("{}:{}", file.display(), line),
+                    None => file.display().to_string(),
+                }
+            }
+            None => "unknown file".to_string(),
+        });
+        self.conflicts.push(format!("{} {} in {} and {}", path, problem, locations[0], locations[1]));
+    }
+}
+
+fn keyed_list(path: &str) -> Option<&'static str> {
+    KEYED_LISTS.iter().find(|(list, _)| *list == path).map(|(_, id)| *id)
+}
+
+fn entry_id(entry: &Value, id_field: &str) -> Option<String> {
+    match entry.get(id_field)? {
+        Value::String(id) => Some(id.clone()),
+        Value::Null => None,
+        other => Some(other.to_string()),
+    }
+}
+
+/// 记录文档中每个配置项的来源；主键列表逐项记录，其余列表整体视为一个值
+fn record_origins(value: &Value, path: String, index: usize, origins: &mut BTreeMap<String, usize>) {
+    match value {
+        Value::Object(fields) => {
+            for (key, field) in fields {
+                record_origins(field, join(&path, key), index, origins);
+            }
+        }
+        Value::Array(items) if keyed_list(&path).is_some() => {
+            let id_field = keyed_list(&path).expect("keyed list");
+            origins.insert(path.clone(), index);
+            for item in items {
+                if let Some(id) = entry_id(item, id_field) {
+                    let entry = format!("{}[{}]", path, id);
+                    origins.insert(entry.clone(), index);
+                    record_origins(item, entry, index, origins);
+                }
+            }
+        }
+        _ => {
+            origins.insert(path, index);
+        }
+    }
+}
+
+fn copy_origins(path: &str, from: &BTreeMap<String, usize>, to: &mut BTreeMap<String, usize>) {
+    for (key, index) in from.range(path.to_string()..) {
+        if !key.starts_with(path) {
+            break;
+        }
+        if within(key, path) {
+            to.insert(key.clone(), *index);
+        }
+    }
+}
+
+/// `key` 是否为 `path` 本身或其下的配置项
+fn within(key: &str, path: &str) -> bool {
+    path.is_empty()
+        || key.strip_prefix(path).is_some_and(|rest| rest.is_empty() || rest.starts_with('.') || rest.starts_with('['))
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// 在原文中定位配置项所在的行（从 1 开始）
+///
+/// 依次查找路径中的各个键，JSON 中为 `"key"`，TOML 中为 `key =` 或表头；列表项按主键值查找。
+/// 找不到某一段时返回已定位到的行。
+fn line_of(text: &str, path: &str) -> Option<usize> {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut found = None;
+    let mut start = 0;
+    for segment in path.split('.') {
+        let (key, id) = match segment.split_once('[') {
+            Some((key, id)) => (key, Some(id.trim_end_matches(']'))),
+            None => (segment, None),
+        };
+        let needles = [key.to_string()]
+            .into_iter()
+            .chain(id.map(|id| format!("\"{}\"", id)));
+        for needle in needles {
+            let Some(offset) = lines[start..].iter().position(|line| mentions(line, &needle)) else {
+                return found.map(|line: usize| line + 1);
+            };
+            start += offset;
+            found = Some(start);
+        }
+    }
+    found.map(|line| line + 1)
+}
+
+fn mentions(line: &str, needle: &str) -> bool {
+    if needle.starts_with('"') {
+        return line.contains(needle);
+    }
+    let line = line.trim_start();
+    if line.contains(&format!("\"{}\"", needle)) {
+        return true;
+    }
+    if line.starts_with('[') {
+        return line.trim_matches(|c| c == '[' || c == ']').split('.').any(|part| part.trim() == needle);
+    }
+    line.strip_prefix(needle).is_some_and(|rest| rest.trim_start().starts_with('='))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_merge_includes_and_report_conflicts() {
+        let dir = std::env::temp_dir().join(format!("bridge-include-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("chains")).unwrap();
+        std::fs::write(dir.join("chains/rooch.toml"), "[[chains]]\nid = \"rooch\"\nrpc_url = \"https://rooch\"\n").unwrap();
+        std::fs::write(dir.join("chains/sui.json"), "{\n  \"chains\": [{\n    \"id\": \"sui\",\n    \"rpc_url\": \"https://sui\"\n  }]\n}\n").unwrap();
+        std::fs::write(dir.join("relayer.json"), "{\n  \"relayer\": {\n    \"poll_interval\": 5\n  }\n}\n").unwrap();
+        std::fs::write(
+            dir.join("bridge.json"),
+            "{\n  \"include\": [\"chains\", \"relayer.json\"],\n  \"chains\": [{ \"id\": \"sui\", \"rpc_url\": \"https://override\" }],\n  \"relayer\": { \"max_retries\": 3 }\n}\n",
+        )
+        .unwrap();
+
+        let value = load_file(&dir.join("bridge.json")).unwrap();
+        assert_eq!(value, json!({
+            "chains": [{ "id": "rooch", "rpc_url": "https://rooch" }, { "id": "sui", "rpc_url": "https://override" }],
+            "relayer": { "poll_interval": 5, "max_retries": 3 },
+        }));
+
+        // 同级片段之间的重复定义与不同取值均为冲突，并指出文件与行号
+        std::fs::write(dir.join("chains/sui-copy.toml"), "[relayer]\npoll_interval = 10\n\n[[chains]]\nid = \"sui\"\n").unwrap();
+        std::fs::write(dir.join("chains/zz-relayer.json"), "{ \"relayer\": { \"poll_interval\": 7 } }").unwrap();
+        let error = load_dir(&dir.join("chains")).unwrap_err().to_string();
+        assert!(error.contains("2 config conflicts found"), "{}", error);
+        assert!(error.contains("chains[sui] is defined more than once"), "{}", error);
+        assert!(error.contains("sui-copy.toml:5"), "{}", error);
+        assert!(error.contains("sui.json:3"), "{}", error);
+        assert!(error.contains("relayer.poll_interval has conflicting values in"), "{}", error);
+        assert!(error.contains("sui-copy.toml:2"), "{}", error);
+
+        std::fs::write(dir.join("loop.json"), "{ \"include\": \"loop.json\" }").unwrap();
+        assert!(load_file(&dir.join("loop.json")).unwrap_err().to_string().contains("Config include cycle"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,132 +1,2352 @@
 use serde::{Deserialize, Serialize};
 use std::path::Path;
-use std::collections::HashMap;
-use crate::Error;
+use std::collections::{BTreeMap, HashMap};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use blake2::{Blake2b, Digest, digest::consts::U32};
+use crate::{
+    chain_adapter::{address::AddressFormat, cosmos_tx::bech32_address, http, json_rpc, registry, solana_tx::Pubkey, version::NodeVersion},
+    confidential,
+    relayer::scheduler::SubmissionWindow,
+    types::{ChainId, ChainRegistry, MessageStatus},
+    Error,
+};
 
+pub mod env;
+// 多文件配置：`include` 引用与目录合并
+pub mod include;
+pub mod remote;
+
+pub use env::{ConfigSources, ValueOrigin};
+
+/// 事件过滤器：适配器读取 `name` 指定的事件，交给 `handler` 处理
+///
+/// Move 链上 `name` 可以是结构体名（位于适配器默认的事件模块）、`模块::结构体`，或带包地址的完整类型。
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct EventFilter {
     pub name: String,
     pub handler: String,
 }
 
+impl EventFilter {
+    /// 事件的结构体名
+    pub fn struct_name(&self) -> &str {
+        self.name.rsplit("::").next().unwrap_or(&self.name)
+    }
+
+    /// 完整的 Move 事件类型 `<地址>::<模块>::<结构体>`
+    pub fn move_event_type(&self, bridge_address: &str, default_module: &str) -> String {
+        match self.name.matches("::").count() {
+            0 => format!("{}::{}::{}", bridge_address, default_module, self.name),
+            1 => format!("{}::{}", bridge_address, self.name),
+            _ => self.name.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ChainConfig {
+    pub id: ChainId,
+    #[serde(rename = "adapter_type")]
+    pub adapter_type: String,
+    pub name: String,
+    pub rpc_url: String,
+    pub bridge_address: String,
+    /// 桥合约与接收者的地址格式，未配置时按适配器类型确定；通用 JSON-RPC 与第三方适配器未配置时不校验地址
+    #[serde(default)]
+    pub address_format: Option<AddressFormat>,
+    pub event_filters: Vec<EventFilter>,
+    /// 链上验证者注册表的对象地址（Sui 上为验证者 Table 的对象 ID）
+    #[serde(default)]
+    pub validator_registry: Option<String>,
+    /// 合约已处理 nonce 表的对象地址（Sui 上为按源链数字 ID 索引的 `Table<u64, u64>` 的对象 ID）
+    #[serde(default)]
+    pub processed_nonces: Option<String>,
+    /// 合约已执行消息表的对象地址（Sui 上为 `MessageConfig.executed_messages`，即以消息 ID 为键的 `Table<vector<u8>, bool>` 的对象 ID）
+    #[serde(default)]
+    pub executed_messages: Option<String>,
+    /// 要求的最低节点版本，启动时低于该版本拒绝连接
+    #[serde(default)]
+    pub min_node_version: Option<String>,
+    /// 合约中使用的数字链 ID，用于解析链上事件中的源链与目标链
+    #[serde(default)]
+    pub chain_id: Option<u64>,
+    /// 数字链 ID 所属的命名空间，多个 Move 网络沿用各自原生链 ID 时用来区分，见 [`ChainConfig::contract_chain_id`]
+    #[serde(default)]
+    pub chain_namespace: Option<u32>,
+    /// 提交交易的发送账户私钥文件：Rooch 与 Movement 为十六进制编码的 ed25519 私钥，Solana 为 `solana-keygen` 生成的密钥文件，
+    /// Cosmos 为十六进制编码的 secp256k1 私钥
+    #[serde(default)]
+    pub sender_keystore: Option<String>,
+    /// 提交调度约束，未配置时不限制
+    #[serde(default)]
+    pub submission: Option<SubmissionConfig>,
+    /// 目标合约登记的 X25519 公钥（十六进制），发往该链的机密消息必须加密到此公钥
+    #[serde(default)]
+    pub payload_public_key: Option<String>,
+    /// 批量提交配置，未配置时逐条提交
+    #[serde(default)]
+    pub batch: Option<BatchConfig>,
+    /// 发往本链的消息的交付顺序，未配置时尽力而为
+    #[serde(default)]
+    pub ordering: Option<OrderingConfig>,
+    /// 覆盖本链使用的中继参数，未配置的项沿用 `relayer` 中的全局值
+    #[serde(default)]
+    pub relayer: Option<ChainRelayerConfig>,
+    /// 本链 RPC 端点的请求速率限制，未配置时不限制
+    #[serde(default)]
+    pub rate_limit: Option<RateLimitConfig>,
+    /// RPC 请求的超时、TLS 与代理设置，目前由 Rooch 适配器使用；未配置时使用默认超时
+    #[serde(default)]
+    pub http: Option<HttpConfig>,
+    /// RPC 请求附加的 HTTP 头，例如服务商要求的 API key，值可用 `${VAR}` 引用环境变量
+    #[serde(default)]
+    pub rpc_headers: HashMap<String, String>,
+    /// RPC 端点的认证方式，由 Sui 与 Rooch 适配器使用
+    #[serde(default)]
+    pub auth: Option<RpcAuth>,
+    /// WASM 插件适配器，`adapter_type` 为 `wasm` 时必须配置
+    #[serde(default)]
+    pub plugin: Option<WasmPluginConfig>,
+    /// Solana 链的提交参数，`adapter_type` 为 `solana` 且配置了发送账户时必须配置
+    #[serde(default)]
+    pub solana: Option<SolanaConfig>,
+    /// Movement 链的最终性参数，未配置时事件一经执行即视为最终
+    #[serde(default)]
+    pub movement: Option<MovementConfig>,
+    /// Cosmos 链的地址、手续费与轻客户端参数，`adapter_type` 为 `cosmos` 时必须配置
+    #[serde(default)]
+    pub cosmos: Option<CosmosConfig>,
+    /// 通用 JSON-RPC 适配器的方法模板，`adapter_type` 为 `json_rpc` 时必须配置
+    #[serde(default)]
+    pub json_rpc: Option<JsonRpcTemplates>,
+    /// Sui 链的事件读取方式，未配置时通过事件查询读取
+    #[serde(default)]
+    pub sui: Option<SuiConfig>,
+    /// Rooch 链的事件分页参数，未配置时使用默认分页；读取游标由中继器保存在消息存储中
+    #[serde(default)]
+    pub rooch: Option<RoochConfig>,
+    /// 验证源链事件包含证明的信任根，配置后本链发出的消息须经证明验证才会签名或中继
+    #[serde(default)]
+    pub light_client: Option<LightClientConfig>,
+    /// 桥合约的中继器 gas 报销，配置后提交时附带收款地址并定期认领累计的报销；合约不支持报销时不要配置
+    #[serde(default)]
+    pub reimbursement: Option<ReimbursementConfig>,
+}
+
+impl ChainConfig {
+    /// 桥合约中标识本链的数字 ID：配置命名空间时为 `namespace << 32 | chain_id`，否则为 `chain_id`
+    pub fn contract_chain_id(&self) -> Option<u64> {
+        let chain_id = self.chain_id?;
+        Some(match self.chain_namespace {
+            Some(namespace) => (namespace as u64) << 32 | chain_id,
+            None => chain_id,
+        })
+    }
+
+    /// RPC 请求附加的全部 HTTP 头：`rpc_headers` 加上 `auth` 生成的 `Authorization`
+    pub fn request_headers(&self) -> HashMap<String, String> {
+        let mut headers = self.rpc_headers.clone();
+        if let Some(auth) = &self.auth {
+            headers.insert("Authorization".to_string(), auth.header_value());
+        }
+        headers
+    }
+
+    /// 等待本链交易确认的秒数
+    pub fn stuck_timeout(&self) -> u64 {
+        self.relayer.as_ref().and_then(|r| r.stuck_timeout).unwrap_or(DEFAULT_STUCK_TIMEOUT)
+    }
+
+    /// 本链的地址格式，未知时为 None
+    pub fn address_format(&self) -> Option<AddressFormat> {
+        self.address_format.or_else(|| AddressFormat::for_adapter(&self.adapter_type))
+    }
+
+    /// 按本链地址格式规范化地址，地址格式未知时原样返回
+    pub fn normalize_address(&self, address: &str) -> Result<String, Error> {
+        match self.address_format() {
+            Some(format) => format.normalize(address, self.cosmos.as_ref().map(|c| c.address_prefix.as_str())),
+            None => Ok(address.to_string()),
+        }
+    }
+}
+
+/// 单条链的中继参数覆盖
+///
+/// 轮询间隔与确认数作用于从本链读取事件，重试参数作用于向本链提交交易。
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct ChainRelayerConfig {
+    #[serde(default)]
+    pub poll_interval: Option<u64>,
+    #[serde(default)]
+    pub max_retries: Option<u32>,
+    #[serde(default)]
+    pub retry_delay: Option<u64>,
+    #[serde(default)]
+    pub confirmations: Option<u64>,
+    /// 出块间隔（秒），用于把确认数换算为等待时间；未配置时取 `submission.block_time`，再缺省为 1 秒
+    #[serde(default)]
+    pub block_time: Option<u64>,
+    /// 交易广播后等待确认的秒数，超时视为卡住并重新提交
+    #[serde(default)]
+    pub stuck_timeout: Option<u64>,
+    /// 卡住的交易最多重新提交的次数
+    #[serde(default)]
+    pub max_resubmissions: Option<u32>,
+}
+
+/// 未配置时等待交易确认的秒数
+pub const DEFAULT_STUCK_TIMEOUT: u64 = 20;
+/// 未配置时卡住交易的重新提交次数
+pub const DEFAULT_MAX_RESUBMISSIONS: u32 = 3;
+
+/// 合并全局配置与链覆盖后，某条链实际使用的中继参数
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelayerSettings {
+    pub poll_interval: u64,
+    pub max_retries: u32,
+    pub retry_delay: u64,
+    pub confirmations: u64,
+    pub block_time: u64,
+    /// 批量提交时每笔交易最多包含的消息数
+    pub batch_size: usize,
+    pub stuck_timeout: u64,
+    pub max_resubmissions: u32,
+}
+
+impl RelayerSettings {
+    /// 源链事件达到确认数所需等待的秒数
+    pub fn confirmation_delay(&self) -> u64 {
+        self.confirmations.saturating_mul(self.block_time)
+    }
+}
+
+/// 向链提交交易的时间窗口与速率约束，用于避开拥堵时段或合约限流
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SubmissionConfig {
+    /// 允许提交的每日时间窗口（UTC），格式为 `HH:MM-HH:MM`，为空时全天允许
+    #[serde(default)]
+    pub windows: Vec<String>,
+    /// 每个区块最多提交的交易数
+    #[serde(default)]
+    pub max_per_block: Option<u32>,
+    /// 估算区块所用的出块间隔（秒）
+    #[serde(default = "default_block_time")]
+    pub block_time: u64,
+    /// 单次中继允许的最高预估成本（gas 代币最小单位），超过时推迟到低费用队列
+    #[serde(default)]
+    pub max_relay_cost: Option<u64>,
+    /// 估算成本所用的单次中继 gas 用量，成本为该用量乘以当前 gas 价格
+    #[serde(default = "default_gas_units_per_relay")]
+    pub gas_units_per_relay: u64,
+    /// 低费用窗口（UTC），格式同 `windows`；窗口内不检查成本上限，推迟的消息在此期间提交
+    #[serde(default)]
+    pub low_fee_windows: Vec<String>,
+}
+
+fn default_block_time() -> u64 {
+    1
+}
+
+fn default_gas_units_per_relay() -> u64 {
+    10_000
+}
+
+/// RPC 请求的令牌桶限速，同一链适配器的所有请求共享
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+pub struct RateLimitConfig {
+    /// 每秒允许的请求数
+    pub max_rps: u32,
+    /// 允许的突发请求数，未配置时等于 `max_rps`
+    #[serde(default)]
+    pub burst: Option<u32>,
+}
+
+/// 在沙箱中运行的 WASM 适配器插件
+///
+/// 插件只能通过主机接口访问 `allowed_hosts` 中的主机，没有文件系统、环境变量与套接字权限。
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct WasmPluginConfig {
+    /// 编译为 WASI 的插件模块路径
+    pub path: String,
+    /// 插件可以发起 HTTP 请求的主机名，为空时不允许任何网络访问
+    #[serde(default)]
+    pub allowed_hosts: Vec<String>,
+    /// 插件线性内存上限（字节）
+    #[serde(default = "default_plugin_memory")]
+    pub max_memory_bytes: usize,
+    /// 每次调用插件可消耗的燃料，用尽时调用中止
+    #[serde(default = "default_plugin_fuel")]
+    pub fuel: u64,
+}
+
+/// Solana 交易使用的 durable nonce 账户
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SolanaConfig {
+    /// nonce 账户地址（base58），授权账户须为 `sender_keystore` 对应的账户
+    pub nonce_account: String,
+}
+
+/// Aptos 兼容的 Move L2 的最终性参数
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct MovementConfig {
+    /// 事件所在账本版本落后最新版本至少这么多时才读取，用于等待 L2 区块在结算层确认
+    #[serde(default)]
+    pub finality_lag: u64,
+}
+
+/// RPC 端点的认证方式，凭据建议以 `${VAR}` 引用环境变量
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RpcAuth {
+    /// `Authorization: Bearer <token>`
+    Bearer { token: String },
+    /// HTTP Basic 认证
+    Basic { username: String, password: String },
+}
+
+impl RpcAuth {
+    /// `Authorization` 头的值
+    pub fn header_value(&self) -> String {
+        match self {
+            RpcAuth::Bearer { token } => format!("Bearer {}", token),
+            RpcAuth::Basic { username, password } => {
+                format!("Basic {}", BASE64.encode(format!("{}:{}", username, password)))
+            }
+        }
+    }
+}
+
+/// RPC 请求使用的 HTTP 客户端参数
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct HttpConfig {
+    /// 建立连接的超时（秒）
+    #[serde(default = "default_connect_timeout")]
+    pub connect_timeout: u64,
+    /// 单次请求从发出到读完响应的超时（秒）
+    #[serde(default = "default_request_timeout")]
+    pub request_timeout: u64,
+    /// 连接池中每个主机保留的空闲连接数上限，未配置时不限制
+    #[serde(default)]
+    pub pool_max_idle_per_host: Option<usize>,
+    /// 代理地址，例如 `http://proxy:3128`
+    #[serde(default)]
+    pub proxy: Option<String>,
+    /// 额外信任的 CA 证书文件（PEM），用于自签名证书的节点
+    #[serde(default)]
+    pub ca_cert: Option<String>,
+    /// 不校验节点证书，只应在本地测试网络中使用
+    #[serde(default)]
+    pub insecure_skip_verify: bool,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout: default_connect_timeout(),
+            request_timeout: default_request_timeout(),
+            pool_max_idle_per_host: None,
+            proxy: None,
+            ca_cert: None,
+            insecure_skip_verify: false,
+        }
+    }
+}
+
+/// Rooch 链的事件读取参数
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RoochConfig {
+    /// 每页读取的事件数
+    #[serde(default = "default_rooch_page_size")]
+    pub page_size: u64,
+    /// 每次轮询每种事件最多读取的页数，超出部分留到下一轮读取
+    #[serde(default = "default_rooch_pages_per_poll")]
+    pub pages_per_poll: u64,
+}
+
+impl Default for RoochConfig {
+    fn default() -> Self {
+        Self {
+            page_size: default_rooch_page_size(),
+            pages_per_poll: default_rooch_pages_per_poll(),
+        }
+    }
+}
+
+/// 源链轻客户端的信任根
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LightClientConfig {
+    /// 受信任的 Sui 验证者委员会，之后的委员会通过纪元切换检查点验证
+    Sui {
+        epoch: u64,
+        committee: Vec<CommitteeMemberConfig>,
+    },
+    /// Rooch 排序器的 Ed25519 公钥（十六进制），用于验证其签名的状态根
+    Rooch {
+        sequencer_public_key: String,
+    },
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct CommitteeMemberConfig {
+    /// BLS12-381 公钥（十六进制，96 字节）
+    pub public_key: String,
+    pub stake: u64,
+}
+
+/// Sui 源链事件的读取方式
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SuiConfig {
+    #[serde(default)]
+    pub ingestion: SuiIngestion,
+    /// 检查点模式下第一次读取的检查点序号，未配置时从当时的最新检查点开始；消息存储中保存了读取进度时从保存的位置继续
+    #[serde(default)]
+    pub start_checkpoint: Option<u64>,
+    /// 检查点模式下每次轮询最多读取的检查点数
+    #[serde(default = "default_checkpoints_per_poll")]
+    pub checkpoints_per_poll: u64,
+    /// Sui 数据读取服务的远程检查点存储地址（按 `{序号}.chk` 提供完整检查点），用于生成包含证明
+    #[serde(default)]
+    pub checkpoint_store: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SuiIngestion {
+    /// 通过 `suix_queryEvents` 按桥模块查询事件
+    #[default]
+    Events,
+    /// 按序读取完整检查点并从中提取桥事件，事件带有检查点序号与交易摘要
+    Checkpoints,
+}
+
+/// 通过 CometBFT RPC 访问的 CosmWasm 链
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct CosmosConfig {
+    /// bech32 地址前缀，例如 `osmo`
+    pub address_prefix: String,
+    /// 支付手续费的币种，例如 `uosmo`
+    pub fee_denom: String,
+    /// 每单位 gas 的价格（`fee_denom` 的最小单位，可为小数）
+    pub gas_price: f64,
+    /// 单笔交易的 gas 上限
+    #[serde(default = "default_cosmos_gas_limit")]
+    pub gas_limit: u64,
+    /// 轻客户端信任根的区块高度与区块哈希（十六进制），须同时配置；未配置时信任连接时的最新区块
+    #[serde(default)]
+    pub trusted_height: Option<u64>,
+    #[serde(default)]
+    pub trusted_hash: Option<String>,
+    /// 受信任区块的有效期（秒），须小于链的解绑期
+    #[serde(default = "default_trusting_period")]
+    pub trusting_period: u64,
+}
+
+/// 通用 JSON-RPC 适配器的方法模板
+///
+/// 参数模板中形如 `{{name}}` 的字符串为占位符：整个字符串恰好是一个占位符时替换为对应的 JSON 值，
+/// 否则按文本插入。响应路径为相对 JSON-RPC `result` 的 JSONPath 子集，例如 `$.data[0].hash`。
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct JsonRpcTemplates {
+    /// 读取源链事件，可用占位符 `cursor`、`bridge_address`
+    pub events: EventQueryTemplate,
+    /// 提交消息，响应路径指向交易哈希；可用占位符 `message`、`signature`（0x 前缀的十六进制）、
+    /// `nonce`、`source_chain`、`target_chain`（数字链 ID）与 `bridge_address`
+    pub submit: RpcMethodTemplate,
+    /// 查询消息是否已处理，响应路径指向布尔值；可用占位符 `nonce`、`source_chain` 与 `bridge_address`
+    pub status: RpcMethodTemplate,
+    /// 查询节点版本，响应路径指向版本字符串；未配置时不报告版本
+    #[serde(default)]
+    pub node_version: Option<RpcMethodTemplate>,
+}
+
+/// 单个 JSON-RPC 方法的调用模板
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RpcMethodTemplate {
+    pub method: String,
+    #[serde(default = "default_rpc_params")]
+    pub params: serde_json::Value,
+    /// 取结果的响应路径
+    #[serde(default = "default_result_path")]
+    pub result: String,
+}
+
+/// 事件查询模板，响应路径指向事件数组
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct EventQueryTemplate {
+    #[serde(flatten)]
+    pub call: RpcMethodTemplate,
+    /// 各字段在单个事件中的路径
+    pub fields: EventFieldPaths,
+    /// 第一次查询使用的游标
+    #[serde(default = "default_start_cursor")]
+    pub start_cursor: serde_json::Value,
+    /// 下一次查询的游标在响应中的路径；未配置时为最后一个事件的 nonce 加 1
+    #[serde(default)]
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct EventFieldPaths {
+    pub nonce: String,
+    /// 源链与目标链的数字链 ID
+    pub source_chain: String,
+    pub target_chain: String,
+    /// 十六进制编码的消息负载
+    pub payload: String,
+}
+
+fn default_rpc_params() -> serde_json::Value {
+    serde_json::Value::Array(Vec::new())
+}
+
+fn default_result_path() -> String {
+    "$".to_string()
+}
+
+fn default_start_cursor() -> serde_json::Value {
+    serde_json::Value::from(0)
+}
+
+fn default_checkpoints_per_poll() -> u64 {
+    100
+}
+
+fn default_connect_timeout() -> u64 {
+    10
+}
+
+fn default_request_timeout() -> u64 {
+    30
+}
+
+fn default_rooch_page_size() -> u64 {
+    50
+}
+
+fn default_rooch_pages_per_poll() -> u64 {
+    20
+}
+
+fn default_cosmos_gas_limit() -> u64 {
+    500_000
+}
+
+fn default_trusting_period() -> u64 {
+    14 * 24 * 3600
+}
+
+fn default_plugin_memory() -> usize {
+    64 * 1024 * 1024
+}
+
+fn default_plugin_fuel() -> u64 {
+    1_000_000_000
+}
+
+/// 中继器 gas 报销
+///
+/// 合约只向实际执行消息的交易记账，多实例冗余提交时未执行的实例不累计报销。
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ReimbursementConfig {
+    /// 提交交易时附带的收款地址，合约把该笔交易的报销记到此地址名下
+    pub payout_address: String,
+    /// 认领累计报销的间隔（秒）
+    #[serde(default = "default_claim_interval")]
+    pub claim_interval: u64,
+    /// 累计报销达到该数量（gas 代币最小单位）才认领，避免认领交易的 gas 超过报销
+    #[serde(default)]
+    pub min_claim: u64,
+}
+
+fn default_claim_interval() -> u64 {
+    3_600
+}
+
+/// 将发往同一条链的多条消息合并到一笔交易中提交
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct BatchConfig {
+    /// 每笔交易最多包含的消息数，攒满后立即提交；未配置时取 `relayer.batch_size`
+    #[serde(default)]
+    pub max_size: Option<usize>,
+    /// 未攒满时最多等待的时间（秒），实际间隔按中继轮询间隔向上取整
+    #[serde(default = "default_flush_interval")]
+    pub flush_interval: u64,
+}
+
+fn default_batch_size() -> usize {
+    16
+}
+
+fn default_flush_interval() -> u64 {
+    5
+}
+
+/// 同一链对消息的交付顺序
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderingMode {
+    /// 消息就绪即提交，重试可能使 nonce 乱序到达
+    #[default]
+    BestEffort,
+    /// 较小 nonce 的消息确认前暂缓较大 nonce 的消息
+    Strict,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct OrderingConfig {
+    #[serde(default)]
+    pub mode: OrderingMode,
+    /// 前序消息自源链时间戳起超过该秒数仍未确认时不再等待
+    #[serde(default = "default_skip_after")]
+    pub skip_after: u64,
+}
+
+fn default_skip_after() -> u64 {
+    600
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct AssetConfig {
+    pub name: String,
+    /// 代币符号，目标链登记包装代币时使用，未配置时与 `name` 相同
+    #[serde(default)]
+    pub symbol: Option<String>,
+    pub native_chain: String,
+    pub type_: String,
+    pub decimals: u8,
+    pub mappings: HashMap<String, String>,
+    /// 跨链手续费率（基点），与合约中的 `fee_rate` 保持一致，用于记账
+    #[serde(default)]
+    pub fee_rate: u64,
+    /// 资产在映射链上的精度，未配置的链与原生链精度相同
+    #[serde(default)]
+    pub mapped_decimals: HashMap<String, u8>,
+    /// 中继器手续费，从转账金额中扣除后再提交到目标链，未配置时不收取
+    #[serde(default)]
+    pub relayer_fee: Option<RelayerFeeConfig>,
+    /// 大额转账时间锁，未配置时不延迟
+    #[serde(default)]
+    pub timelock: Option<TimelockConfig>,
+    /// 启动时即暂停该资产的中继，运行时可通过管理接口或资产治理消息恢复
+    #[serde(default)]
+    pub paused: bool,
+}
+
+/// 中继器手续费：固定部分加按金额计算的部分，均以源链资产单位计
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub struct RelayerFeeConfig {
+    #[serde(default)]
+    pub flat: u64,
+    /// 费率（基点）
+    #[serde(default)]
+    pub bps: u64,
+}
+
+impl RelayerFeeConfig {
+    /// 转账金额应付的中继器手续费，不超过转账金额
+    pub fn fee_for(&self, amount: u64) -> u64 {
+        let proportional = (amount as u128 * self.bps as u128 / 10000) as u64;
+        self.flat.saturating_add(proportional).min(amount)
+    }
+}
+
+/// 大额转账时间锁：金额超过阈值的转账自源链时间戳起等待一段时间后才提交，
+/// 期间可通过管理接口取消
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct TimelockConfig {
+    /// 金额阈值，以源链资产单位计，超过该金额的转账进入时间锁
+    pub threshold: u64,
+    /// 延迟时长（秒）
+    #[serde(default = "default_timelock_delay")]
+    pub delay: u64,
+}
+
+fn default_timelock_delay() -> u64 {
+    30 * 60
+}
+
+impl AssetConfig {
+    /// 代币符号
+    pub fn symbol(&self) -> &str {
+        self.symbol.as_deref().unwrap_or(&self.name)
+    }
+
+    /// 资产在指定链上的精度
+    pub fn decimals_on(&self, chain_id: &str) -> u8 {
+        self.mapped_decimals.get(chain_id).copied().unwrap_or(self.decimals)
+    }
+
+    /// 按费率（基点）计算手续费
+    pub fn fee_for(&self, amount: u64) -> u64 {
+        ((amount as u128 * self.fee_rate as u128) / 10000) as u64
+    }
+
+    /// 资产在指定链上的类型，未映射到该链时返回 None
+    pub fn type_on(&self, chain_id: &str) -> Option<&str> {
+        if self.native_chain == chain_id {
+            Some(&self.type_)
+        } else {
+            self.mappings.get(chain_id).map(|t| t.as_str())
+        }
+    }
+}
+
+/// NFT 集合的跨链映射，与 `AssetConfig` 对应
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct NftCollectionConfig {
+    pub name: String,
+    pub native_chain: String,
+    /// 原生链上的集合类型
+    pub type_: String,
+    /// 目标链 ID 到该链上集合类型的映射
+    pub mappings: HashMap<String, String>,
+}
+
+impl NftCollectionConfig {
+    /// 集合在指定链上的类型
+    pub fn type_on(&self, chain_id: &str) -> Option<&str> {
+        if self.native_chain == chain_id {
+            Some(&self.type_)
+        } else {
+            self.mappings.get(chain_id).map(|t| t.as_str())
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ValidatorConfig {
+    pub address: String,
+    pub public_key: String,
+    /// 验证者的 BLS12-381 公钥（十六进制，48 字节），用于委员会聚合签名
+    #[serde(default)]
+    pub bls_public_key: Option<String>,
+    pub weight: u64,
+    pub chains: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RelayerConfig {
+    pub poll_interval: u64,
+    pub max_retries: u32,
+    pub retry_delay: u64,
+    /// 本地验证者密钥库文件路径
+    #[serde(default)]
+    pub keystore: Option<String>,
+    /// 远程签名后端，配置后私钥保存在后端中，与 `keystore` 互斥
+    #[serde(default)]
+    pub signer: Option<SignerConfig>,
+    /// 是否要求消息收集到足够的验证者证明后才中继
+    #[serde(default)]
+    pub require_attestations: bool,
+    /// 证明权重阈值，未配置时为验证者总权重的 2/3
+    #[serde(default)]
+    pub attestation_threshold: Option<u64>,
+    /// 治理消息的证明权重阈值，未配置时为验证者总权重的 3/4；低于 `attestation_threshold` 时按后者计
+    #[serde(default)]
+    pub governance_threshold: Option<u64>,
+    /// 当前验证者集合的纪元，验证者集合变更时递增
+    #[serde(default)]
+    pub validator_set_epoch: u64,
+    /// 多实例协调配置，配置后只有持有租约的实例提交交易
+    #[serde(default)]
+    pub coordination: Option<CoordinationConfig>,
+    /// 中继转账前是否在目标链检查接收者能否接收资产，未通过的消息进入暂缓队列
+    #[serde(default)]
+    pub check_recipients: bool,
+    /// 验证者集合更新的变更幅度限制，未配置时不限制
+    #[serde(default)]
+    pub validator_set_guard: Option<ValidatorSetGuardConfig>,
+    /// 消息最大有效期（秒），自源链时间戳起超过该时长仍未中继的消息标记为过期
+    #[serde(default = "default_max_message_age")]
+    pub max_message_age: u64,
+    /// 读取事件与中继处理之间工作队列的容量，同时限制内存中待处理的消息数
+    #[serde(default = "default_queue_capacity")]
+    pub queue_capacity: usize,
+    /// 源链事件需要等待的区块确认数，按链的出块间隔换算为自源链时间戳起的等待时间
+    #[serde(default)]
+    pub confirmations: u64,
+    /// 批量提交时每笔交易最多包含的消息数，链的 `batch.max_size` 未配置时使用
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+    /// 待处理消息的优先级评分，未配置时按观察顺序处理
+    #[serde(default)]
+    pub priority: Option<PriorityConfig>,
+    /// `script:` 事件处理器的运行时限（秒），超时的脚本被终止
+    #[serde(default = "default_script_timeout")]
+    pub script_timeout: u64,
+}
+
+fn default_max_message_age() -> u64 {
+    3_600
+}
+
+fn default_queue_capacity() -> usize {
+    1_000
+}
+
+fn default_script_timeout() -> u64 {
+    30
+}
+
+/// 待处理消息的优先级评分：金额档位、链对与等待时长的分数相加，分数高的消息先申请提交名额
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct PriorityConfig {
+    /// 转账金额档位，转账按所属资产金额下界最高的已达到档位计分
+    #[serde(default)]
+    pub value_bands: Vec<ValueBandConfig>,
+    /// 链对附加分数
+    #[serde(default)]
+    pub routes: Vec<RoutePriorityConfig>,
+    /// 消息自源链时间戳起每等待一分钟增加的分数，避免小额消息长期得不到提交
+    #[serde(default)]
+    pub age_score_per_minute: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ValueBandConfig {
+    /// 档位名称，用作队列构成指标的标签
+    pub name: String,
+    /// 适用的资产，未配置时适用于所有资产
+    #[serde(default)]
+    pub asset: Option<String>,
+    /// 金额下界（含），以源链资产单位计
+    #[serde(default)]
+    pub min_amount: u64,
+    pub score: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RoutePriorityConfig {
+    pub source_chain: String,
+    pub target_chain: String,
+    pub score: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ValidatorSetGuardConfig {
+    /// 单个纪元内允许自动放行的最大权重变更比例（0 到 1），超出时等待人工批准
+    #[serde(default = "default_max_weight_change")]
+    pub max_weight_change: f64,
+    /// 已人工批准的更新消息 ID
+    #[serde(default)]
+    pub approved_updates: Vec<String>,
+}
+
+fn default_max_weight_change() -> f64 {
+    1.0 / 3.0
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct CoordinationConfig {
+    /// 本实例标识，未配置时使用主机名与进程号
+    #[serde(default)]
+    pub instance_id: Option<String>,
+    /// 领导者租约有效期（秒）
+    #[serde(default = "default_lease_ttl")]
+    pub lease_ttl: u64,
+    /// 备用实例从共享存储跟随领导者处理结果的间隔（秒）
+    #[serde(default = "default_follow_interval")]
+    pub follow_interval: u64,
+}
+
+fn default_lease_ttl() -> u64 {
+    30
+}
+
+fn default_follow_interval() -> u64 {
+    5
+}
+
+/// 远程签名后端配置
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(tag = "backend", rename_all = "snake_case")]
+pub enum SignerConfig {
+    /// HashiCorp Vault transit 引擎中的 ed25519 密钥
+    Vault(VaultSignerConfig),
+    /// AWS KMS 中的 `ECC_NIST_EDWARDS25519` 密钥
+    AwsKms(KmsSignerConfig),
+    /// `bridge-signer` gRPC 签名服务，需要启用 `remote-signer` feature
+    Remote(RemoteSignerConfig),
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct VaultSignerConfig {
+    /// Vault 地址，例如 `https://vault.internal:8200`
+    pub address: String,
+    /// transit 引擎挂载路径
+    #[serde(default = "default_transit_mount")]
+    pub mount: String,
+    pub key_name: String,
+    /// 访问令牌，建议以 `${VAULT_TOKEN}` 引用环境变量
+    pub token: String,
+    /// Vault 企业版命名空间
+    #[serde(default)]
+    pub namespace: Option<String>,
+    /// 单次请求超时（毫秒）
+    #[serde(default = "default_signer_timeout")]
+    pub timeout_ms: u64,
+}
+
+fn default_transit_mount() -> String {
+    "transit".to_string()
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct KmsSignerConfig {
+    pub region: String,
+    /// 密钥 ID、ARN 或别名
+    pub key_id: String,
+    /// 自定义端点，未配置时为 `https://kms.{region}.amazonaws.com`
+    #[serde(default)]
+    pub endpoint: Option<String>,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    /// 临时凭证的会话令牌
+    #[serde(default)]
+    pub session_token: Option<String>,
+    /// 单次请求超时（毫秒）
+    #[serde(default = "default_signer_timeout")]
+    pub timeout_ms: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RemoteSignerConfig {
+    /// 服务地址，例如 `https://signer.internal:50051`
+    pub endpoint: String,
+    /// 校验服务端证书的 CA 证书（PEM）路径
+    pub ca_cert: String,
+    /// 双向 TLS 的客户端证书与私钥（PEM）路径
+    pub client_cert: String,
+    pub client_key: String,
+    /// 服务端证书中的域名，未配置时取 `endpoint` 的主机名
+    #[serde(default)]
+    pub domain_name: Option<String>,
+    /// 单次请求超时（毫秒）
+    #[serde(default = "default_signer_timeout")]
+    pub timeout_ms: u64,
+}
+
+fn default_signer_timeout() -> u64 {
+    5_000
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct StorageConfig {
+    /// 存储后端类型
+    #[serde(default = "default_storage_backend")]
+    pub backend: String,
+    /// 存储文件路径，`sqlite` 后端为数据库文件；`postgres` 后端不使用
+    #[serde(default)]
+    pub path: String,
+    /// 数据库连接串，`postgres` 后端使用
+    #[serde(default)]
+    pub url: Option<String>,
+    /// 连接池最大连接数
+    #[serde(default = "default_max_connections")]
+    pub max_connections: u32,
+    /// 保留策略，未配置时不清理历史消息
+    #[serde(default)]
+    pub retention: Option<RetentionConfig>,
+}
+
+fn default_storage_backend() -> String {
+    "file".to_string()
+}
+
+fn default_max_connections() -> u32 {
+    10
+}
+
+/// 消息存储保留策略：进入终态超过保留时长的消息由后台任务清理
+///
+/// 清理消息记录及其源链事件、证明、提交与重试历史，记账分录与双签证据始终保留。
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RetentionConfig {
+    /// 消息进入终态后保留的时长（秒）
+    pub max_age: u64,
+    /// 参与清理的终态，默认见 [`retention::DEFAULT_STATUSES`](crate::storage::retention::DEFAULT_STATUSES)
+    #[serde(default = "default_retention_statuses")]
+    pub statuses: Vec<MessageStatus>,
+    /// 后台清理间隔（秒）
+    #[serde(default = "default_prune_interval")]
+    pub interval: u64,
+}
+
+fn default_retention_statuses() -> Vec<MessageStatus> {
+    crate::storage::retention::DEFAULT_STATUSES.to_vec()
+}
+
+fn default_prune_interval() -> u64 {
+    3600
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ApiConfig {
+    /// HTTP 服务监听地址，例如 `0.0.0.0:8080`
+    pub listen: String,
+    /// gRPC 服务监听地址，例如 `0.0.0.0:50052`，需要启用 `grpc` feature
+    #[serde(default)]
+    pub grpc_listen: Option<String>,
+}
+
+/// 负载结构检查策略
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaPolicy {
+    /// 不检查
+    Off,
+    /// 拒绝不符合已注册结构的负载，未注册的类型放行
+    #[default]
+    Permissive,
+    /// 未注册的类型同样拒绝
+    Strict,
+}
+
+/// 单条路由的负载结构检查策略
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RouteSchemaPolicy {
+    pub source_chain: String,
+    pub target_chain: String,
+    pub policy: SchemaPolicy,
+    /// 目标链合约支持的最高负载版本，更高版本的消息须向前兼容才会中继
+    #[serde(default)]
+    pub max_version: Option<u32>,
+}
+
+/// 负载结构注册表配置，结构定义保存在消息存储中
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct SchemaConfig {
+    #[serde(default)]
+    pub default_policy: SchemaPolicy,
+    #[serde(default)]
+    pub routes: Vec<RouteSchemaPolicy>,
+}
+
+/// 审计日志配置
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct AuditConfig {
+    /// 审计日志文件路径（JSON Lines，只追加）
+    pub path: String,
+    /// 签名密钥文件路径，内容为十六进制编码的 ed25519 私钥
+    pub signing_key: String,
+}
+
+/// 地址筛查配置：中继转账前筛查发送方与接收方地址
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ScreeningConfig {
+    pub provider: ScreeningProviderConfig,
+    /// 地址命中名单时的处理方式
+    #[serde(default)]
+    pub policy: ScreeningPolicy,
+    /// `delay` 策略下自源链时间戳起的延迟（秒）
+    #[serde(default = "default_screening_delay")]
+    pub delay: u64,
+}
+
+fn default_screening_delay() -> u64 {
+    24 * 3600
+}
+
+/// 筛查名单来源
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScreeningProviderConfig {
+    /// 本地 JSON 名单文件，包含 `allowlist` 与 `denylist` 两个地址数组
+    Static { path: String },
+    /// 外部筛查服务
+    Http(HttpScreeningConfig),
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct HttpScreeningConfig {
+    /// 筛查接口地址，以 POST 提交 `{"chain", "address"}`
+    pub url: String,
+    /// Bearer 令牌，建议以 `${SCREENING_TOKEN}` 引用环境变量
+    #[serde(default)]
+    pub token: Option<String>,
+    /// 单次请求超时（毫秒）
+    #[serde(default = "default_screening_timeout")]
+    pub timeout_ms: u64,
+}
+
+fn default_screening_timeout() -> u64 {
+    5_000
+}
+
+/// 地址命中筛查名单时的处理方式
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ScreeningPolicy {
+    /// 拒绝中继
+    #[default]
+    Block,
+    /// 照常中继，记录审计日志
+    Flag,
+    /// 进入时间锁，延迟期内可人工取消
+    Delay,
+}
+
+/// 健康检查服务配置
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct HealthConfig {
+    /// `/healthz` 与 `/readyz` 的监听地址，与 HTTP 接口分开，便于只对探针开放
+    pub listen: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct P2pConfig {
+    /// 本地监听的 multiaddr，例如 `/ip4/0.0.0.0/tcp/9000`
+    pub listen: String,
+    /// 启动时连接的引导节点 multiaddr
+    #[serde(default)]
+    pub bootstrap_peers: Vec<String>,
+    /// 向其它节点公布的外部地址，未配置时公布本地监听地址
+    #[serde(default)]
+    pub external_addresses: Vec<String>,
+}
+
+/// 观察者模式配置：独立核对其它运营方的中继结果
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct WatchtowerConfig {
+    /// 源链消息超过该时长（秒）仍未在目标链交付时告警
+    #[serde(default = "default_delivery_timeout")]
+    pub delivery_timeout: u64,
+    /// 告警推送地址，以 JSON POST 发送，未配置时只写日志
+    #[serde(default)]
+    pub alert_webhook: Option<String>,
+    /// 乐观模式下发现欺诈性交付时，自动通过目标链适配器提交挑战交易
+    #[serde(default)]
+    pub challenge: bool,
+}
+
+fn default_delivery_timeout() -> u64 {
+    600
+}
+
+/// 日志配置
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LoggingConfig {
+    #[serde(default)]
+    pub format: LogFormat,
+    /// 默认日志级别：`trace`、`debug`、`info`、`warn`、`error` 或 `off`
+    #[serde(default = "default_log_level")]
+    pub level: String,
+    /// 按模块覆盖日志级别，键为模块路径，例如 `bridge_core::relayer`
+    #[serde(default)]
+    pub modules: BTreeMap<String, String>,
+    /// 写入文件，未配置时写入终端
+    #[serde(default)]
+    pub file: Option<LogFileConfig>,
+    /// 额外需要脱敏的字段名；私钥、令牌、密码等字段与 URL 中的凭据始终脱敏
+    #[serde(default)]
+    pub redact_fields: Vec<String>,
+}
+
+fn default_log_level() -> String {
+    "info".to_string()
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            format: LogFormat::default(),
+            level: default_log_level(),
+            modules: BTreeMap::new(),
+            file: None,
+            redact_fields: Vec::new(),
+        }
+    }
+}
+
+/// 日志输出格式
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum LogFormat {
+    /// 便于阅读的单行文本
+    #[default]
+    Pretty,
+    /// 每行一个 JSON 对象，便于日志系统采集
+    Json,
+}
+
+/// 日志文件配置，按大小或时间轮转
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LogFileConfig {
+    pub path: String,
+    /// 单个文件超过该字节数时轮转，未配置时不按大小轮转
+    #[serde(default)]
+    pub max_size: Option<u64>,
+    /// 按时间轮转的周期
+    #[serde(default)]
+    pub rotation: LogRotation,
+    /// 保留的历史文件数，依次命名为 `<path>.1`、`<path>.2` ...
+    #[serde(default = "default_max_log_files")]
+    pub max_files: usize,
+}
+
+fn default_max_log_files() -> usize {
+    5
+}
+
+impl Default for LogFileConfig {
+    fn default() -> Self {
+        Self {
+            path: String::new(),
+            max_size: None,
+            rotation: LogRotation::default(),
+            max_files: default_max_log_files(),
+        }
+    }
+}
+
+/// 日志文件按时间轮转的周期，以 UTC 整点或零点为界
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum LogRotation {
+    #[default]
+    Never,
+    Hourly,
+    Daily,
+}
+
+/// 告警配置：满足告警条件时推送到配置的告警渠道
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct AlertConfig {
+    /// 告警渠道，未配置时告警只写日志
+    #[serde(default)]
+    pub sinks: Vec<AlertSinkConfig>,
+    /// 链持续不可达超过该时长（秒）时告警
+    #[serde(default = "default_chain_unreachable_after")]
+    pub chain_unreachable_after: u64,
+    /// 统计失败消息增长的时间窗口（秒）
+    #[serde(default = "default_dead_letter_window")]
+    pub dead_letter_window: u64,
+    /// 时间窗口内新增的失败消息达到该数量时告警
+    #[serde(default = "default_dead_letter_threshold")]
+    pub dead_letter_threshold: usize,
+    /// 消息自源链时间戳起等待验证者证明超过该时长（秒）时视为法定人数丢失
+    #[serde(default = "default_quorum_timeout")]
+    pub quorum_timeout: u64,
+    /// 同一告警在该时长（秒）内只推送一次
+    #[serde(default = "default_alert_dedup_window")]
+    pub dedup_window: u64,
+    /// 覆盖各类告警的默认级别
+    #[serde(default)]
+    pub severities: HashMap<AlertKind, AlertSeverity>,
+}
+
+fn default_chain_unreachable_after() -> u64 {
+    300
+}
+
+fn default_dead_letter_window() -> u64 {
+    3_600
+}
+
+fn default_dead_letter_threshold() -> usize {
+    10
+}
+
+fn default_quorum_timeout() -> u64 {
+    600
+}
+
+fn default_alert_dedup_window() -> u64 {
+    1_800
+}
+
+/// 资产对账：定期读取原生链的锁定量与各映射链的包装资产流通量，核对锁定量不小于铸造量
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ReconciliationConfig {
+    /// 对账间隔（秒）
+    #[serde(default = "default_reconciliation_interval")]
+    pub interval: u64,
+    /// 核对的资产，为空时核对全部资产
+    #[serde(default)]
+    pub assets: Vec<String>,
+    /// 发现铸造量超过锁定量时暂停该资产的转账
+    #[serde(default)]
+    pub auto_pause: bool,
+}
+
+fn default_reconciliation_interval() -> u64 {
+    300
+}
+
+/// 告警类型
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertKind {
+    /// 链持续不可达
+    ChainUnreachable,
+    /// 失败消息在时间窗口内快速增长
+    DeadLetterGrowth,
+    /// 消息长时间收集不到足够的验证者证明
+    QuorumLoss,
+    /// 验证者集合变更限制触发熔断，更新等待人工批准
+    CircuitBreaker,
+    /// 资产的包装流通量超过原生链锁定量
+    SupplyMismatch,
+}
+
+impl AlertKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlertKind::ChainUnreachable => "chain_unreachable",
+            AlertKind::DeadLetterGrowth => "dead_letter_growth",
+            AlertKind::QuorumLoss => "quorum_loss",
+            AlertKind::CircuitBreaker => "circuit_breaker",
+            AlertKind::SupplyMismatch => "supply_mismatch",
+        }
+    }
+
+    /// 未在配置中覆盖时的告警级别
+    pub fn default_severity(&self) -> AlertSeverity {
+        match self {
+            AlertKind::DeadLetterGrowth => AlertSeverity::Warning,
+            AlertKind::ChainUnreachable | AlertKind::QuorumLoss | AlertKind::CircuitBreaker | AlertKind::SupplyMismatch => {
+                AlertSeverity::Critical
+            }
+        }
+    }
+}
+
+/// 告警级别
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertSeverity {
+    Info,
+    #[default]
+    Warning,
+    Critical,
+}
+
+impl AlertSeverity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlertSeverity::Info => "info",
+            AlertSeverity::Warning => "warning",
+            AlertSeverity::Critical => "critical",
+        }
+    }
+}
+
+/// 告警渠道
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AlertSinkConfig {
+    /// Slack incoming webhook
+    Slack(SlackSinkConfig),
+    /// PagerDuty Events API v2
+    #[serde(rename = "pagerduty")]
+    PagerDuty(PagerDutySinkConfig),
+    /// Telegram bot
+    Telegram(TelegramSinkConfig),
+}
+
+impl AlertSinkConfig {
+    /// 推送到该渠道的最低告警级别
+    pub fn min_severity(&self) -> AlertSeverity {
+        match self {
+            AlertSinkConfig::Slack(sink) => sink.min_severity,
+            AlertSinkConfig::PagerDuty(sink) => sink.min_severity,
+            AlertSinkConfig::Telegram(sink) => sink.min_severity,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SlackSinkConfig {
+    /// incoming webhook 地址，建议以 `${SLACK_WEBHOOK_URL}` 引用环境变量
+    pub webhook_url: String,
+    #[serde(default)]
+    pub min_severity: AlertSeverity,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PagerDutySinkConfig {
+    /// 服务集成的 routing key，建议以 `${PAGERDUTY_ROUTING_KEY}` 引用环境变量
+    pub routing_key: String,
+    /// Events API 地址
+    #[serde(default = "default_pagerduty_url")]
+    pub url: String,
+    #[serde(default)]
+    pub min_severity: AlertSeverity,
+}
+
+fn default_pagerduty_url() -> String {
+    "https://events.pagerduty.com/v2/enqueue".to_string()
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TelegramSinkConfig {
+    /// bot 令牌，建议以 `${TELEGRAM_BOT_TOKEN}` 引用环境变量
+    pub bot_token: String,
+    pub chat_id: String,
+    /// Bot API 地址
+    #[serde(default = "default_telegram_api_url")]
+    pub api_url: String,
+    #[serde(default)]
+    pub min_severity: AlertSeverity,
+}
+
+fn default_telegram_api_url() -> String {
+    "https://api.telegram.org".to_string()
+}
+
+impl Default for AlertConfig {
+    fn default() -> Self {
+        Self {
+            sinks: Vec::new(),
+            chain_unreachable_after: default_chain_unreachable_after(),
+            dead_letter_window: default_dead_letter_window(),
+            dead_letter_threshold: default_dead_letter_threshold(),
+            quorum_timeout: default_quorum_timeout(),
+            dedup_window: default_alert_dedup_window(),
+            severities: HashMap::new(),
+        }
+    }
+}
+
+/// 索引器配置：回放各链历史事件写入消息存储，供浏览器接口查询
 #[derive(Debug, Deserialize, Serialize, Clone)]
-pub struct ChainConfig {
-    pub id: String,
-    #[serde(rename = "adapter_type")]
-    pub adapter_type: String,
-    pub name: String,
-    pub rpc_url: String,
-    pub bridge_address: String,
-    pub event_filters: Vec<EventFilter>,
+pub struct IndexerConfig {
+    /// 各链开始回放的高度，单位与该链 `replay_events` 的区间一致，未配置的链从 0 开始
+    #[serde(default)]
+    pub start_heights: HashMap<String, u64>,
+    /// 每轮回放的最大高度区间
+    #[serde(default = "default_backfill_batch")]
+    pub backfill_batch: u64,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
-pub struct AssetConfig {
-    pub name: String,
-    pub native_chain: String,
-    pub type_: String,
-    pub decimals: u8,
-    pub mappings: HashMap<String, String>,
+fn default_backfill_batch() -> u64 {
+    1000
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
-pub struct ValidatorConfig {
-    pub address: String,
-    pub public_key: String,
-    pub weight: u64,
+impl Default for IndexerConfig {
+    fn default() -> Self {
+        Self {
+            start_heights: HashMap::new(),
+            backfill_batch: default_backfill_batch(),
+        }
+    }
+}
+
+/// 双签罚没配置：发现验证者双签时向支持罚没的链提交证据交易
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct SlashingConfig {
+    /// 接收证据的链，未配置时提交到双签验证者登记的全部链
+    #[serde(default)]
     pub chains: Vec<String>,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
-pub struct RelayerConfig {
-    pub poll_interval: u64,
-    pub max_retries: u32,
-    pub retry_delay: u64,
+impl Default for WatchtowerConfig {
+    fn default() -> Self {
+        Self {
+            delivery_timeout: default_delivery_timeout(),
+            alert_webhook: None,
+            challenge: false,
+        }
+    }
+}
+
+/// 配置文件格式，按扩展名区分
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Json,
+    Toml,
+}
+
+impl ConfigFormat {
+    /// 扩展名为 `.toml` 时为 TOML，其余为 JSON
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => ConfigFormat::Toml,
+            _ => ConfigFormat::Json,
+        }
+    }
+
+    /// 解析为 JSON 文档，之后的变量展开与校验与格式无关
+    pub fn parse(self, text: &str) -> Result<serde_json::Value, Error> {
+        match self {
+            ConfigFormat::Json => serde_json::from_str(text)
+                .map_err(|e| Error::Config(format!("Failed to parse config file: {}", e))),
+            ConfigFormat::Toml => toml::from_str(text)
+                .map_err(|e| Error::Config(format!("Failed to parse config file: {}", e))),
+        }
+    }
+
+    /// 输出配置文档；TOML 没有空值，文档中不能包含 `null`
+    pub fn render(self, value: &serde_json::Value) -> Result<String, Error> {
+        match self {
+            ConfigFormat::Json => serde_json::to_string_pretty(value)
+                .map_err(|e| Error::Serialization(format!("Failed to encode config: {}", e))),
+            ConfigFormat::Toml => toml::to_string_pretty(value)
+                .map_err(|e| Error::Serialization(format!("Failed to encode config: {}", e))),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Config {
     pub chains: Vec<ChainConfig>,
     pub assets: Vec<AssetConfig>,
+    /// NFT 集合映射，未配置时不中继 NFT 转移
+    #[serde(default)]
+    pub nft_collections: Vec<NftCollectionConfig>,
     pub validators: Vec<ValidatorConfig>,
     pub relayer: RelayerConfig,
+    /// 消息存储配置，未配置时不持久化消息记录
+    #[serde(default)]
+    pub storage: Option<StorageConfig>,
+    /// HTTP 接口配置，未配置时不启动 HTTP 服务
+    #[serde(default)]
+    pub api: Option<ApiConfig>,
+    /// 负载结构检查配置，未配置时不检查负载结构
+    #[serde(default)]
+    pub schemas: Option<SchemaConfig>,
+    /// 中继决策审计日志配置，未配置时不记录
+    #[serde(default)]
+    pub audit: Option<AuditConfig>,
+    /// 地址筛查配置，未配置时不筛查
+    #[serde(default)]
+    pub screening: Option<ScreeningConfig>,
+    /// 健康检查服务配置，未配置时不启动
+    #[serde(default)]
+    pub health: Option<HealthConfig>,
+    /// p2p gossip 网络配置，需要启用 `p2p` feature
+    #[serde(default)]
+    pub p2p: Option<P2pConfig>,
+    /// 观察者模式配置，未配置时使用默认值
+    #[serde(default)]
+    pub watchtower: Option<WatchtowerConfig>,
+    /// 双签罚没配置，未配置时只收集证据、不提交
+    #[serde(default)]
+    pub slashing: Option<SlashingConfig>,
+    /// 索引器配置，未配置时索引器使用默认值
+    #[serde(default)]
+    pub indexer: Option<IndexerConfig>,
+    /// 告警配置，未配置时不检查告警条件
+    #[serde(default)]
+    pub alerts: Option<AlertConfig>,    /// 日志配置，未配置时以默认格式输出 `info` 及以上级别的日志到终端
+    #[serde(default)]
+    pub logging: Option<LoggingConfig>,
+    /// 资产锁定量与铸造量的定期对账，未配置时不对账
+    #[serde(default)]
+    pub reconciliation: Option<ReconciliationConfig>,
+}
+
+/// 配置校验发现的一个问题
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConfigIssue {
+    /// 问题所在的配置路径，例如 `chains[1].id`
+    pub path: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// 一次配置校验发现的全部问题，按配置中出现的顺序排列
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ValidationReport {
+    pub issues: Vec<ConfigIssue>,
+}
+
+impl ValidationReport {
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    fn push(&mut self, path: impl Into<String>, message: impl Into<String>) {
+        self.issues.push(ConfigIssue { path: path.into(), message: message.into() });
+    }
+
+    /// 记录其它模块解析配置值时返回的错误，配置错误只保留原始信息
+    fn push_error(&mut self, path: impl Into<String>, error: Error) {
+        let message = match error {
+            Error::Config(message) => message,
+            other => other.to_string(),
+        };
+        self.push(path, message);
+    }
+
+    /// 没有问题时为 Ok，否则把所有问题合并为一个配置错误
+    pub fn into_result(self) -> Result<(), Error> {
+        match self.issues.as_slice() {
+            [] => Ok(()),
+            [issue] => Err(Error::Config(issue.to_string())),
+            issues => {
+                let lines: Vec<String> = issues.iter().map(|issue| format!("  - {}", issue)).collect();
+                Err(Error::Config(format!("{} problems found:\n{}", issues.len(), lines.join("\n"))))
+            }
+        }
+    }
 }
 
 impl Config {
+    /// 读取配置文件，展开 `${VAR}` 引用并应用 `BRIDGE__` 覆盖变量后校验
+    ///
+    /// 扩展名为 `.toml` 的文件按 TOML 解析，其余按 JSON 解析。配置文件可通过 `include` 引用其它片段，
+    /// 合并规则见 [`include::load_file`]；路径为目录时按 [`Config::load_dir`] 合并目录中的所有片段。
     pub fn load<P: AsRef<Path>>(config_path: P) -> Result<Self, Error> {
-        let config_str = std::fs::read_to_string(config_path)
-            .map_err(|e| Error::Config(format!("Failed to read config file: {}", e)))?;
-        
-        let config: Config = serde_json::from_str(&config_str)
-            .map_err(|e| Error::Config(format!("Failed to parse config file: {}", e)))?;
+        let path = config_path.as_ref();
+        if path.is_dir() {
+            return Self::load_dir(path);
+        }
+        Self::parse_document(include::load_file(path)?, &std::env::vars().collect())
+    }
+
+    /// 合并目录中的所有 `.json` 与 `.toml` 配置片段后解析，片段之间不能相互覆盖
+    pub fn load_dir<P: AsRef<Path>>(dir: P) -> Result<Self, Error> {
+        Self::parse_document(include::load_dir(dir.as_ref())?, &std::env::vars().collect())
+    }
+
+    /// 按给定的环境变量解析 JSON 配置文本
+    ///
+    /// 先展开配置文件中的 `${VAR}`，再应用 `BRIDGE__` 覆盖变量（见 [`env::OVERRIDE_PREFIX`]），
+    /// 覆盖值不再展开。解析或校验失败时，错误信息附上来自环境变量的相关值的配置路径与变量名。
+    pub fn parse_with_env(config_str: &str, vars: &HashMap<String, String>) -> Result<Self, Error> {
+        Self::parse_document(ConfigFormat::Json.parse(config_str)?, vars)
+    }
+
+    /// 按给定的环境变量解析已读入的配置文档，规则同 [`Config::parse_with_env`]
+    pub fn parse_document(mut value: serde_json::Value, vars: &HashMap<String, String>) -> Result<Self, Error> {
+        let mut sources = ConfigSources::default();
+        env::interpolate(&mut value, vars, &mut sources)?;
+        env::apply_overrides(&mut value, vars, &mut sources)?;
+
+        let config: Config = serde_json::from_value(value)
+            .map_err(|e| sources.annotate(Error::Config(format!("Failed to parse config file: {}", e))))?;
         
         // 验证配置
-        config.validate()?;
+        config.validate().map_err(|e| sources.annotate(e))?;
         
         Ok(config)
     }
 
     fn validate(&self) -> Result<(), Error> {
-        // 验证链配置
-        let chain_ids: Vec<_> = self.chains.iter().map(|c| &c.id).collect();
-        for chain in &self.chains {
-            if !["sui", "rooch"].contains(&chain.adapter_type.as_str()) {
-                return Err(Error::Config(format!("Invalid adapter type: {}", chain.adapter_type)));
+        self.validation_report().into_result()
+    }
+
+    /// 校验配置并列出发现的所有问题，每个问题附带所在的配置路径
+    pub fn validation_report(&self) -> ValidationReport {
+        let mut report = ValidationReport::default();
+
+        // 验证链配置，链 ID 不能重复；其它配置引用的链 ID 都须已登记
+        let mut registry = ChainRegistry::new();
+        for (i, chain) in self.chains.iter().enumerate() {
+            let path = format!("chains[{}]", i);
+            if let Err(e) = registry.register(chain.id.clone(), &chain.adapter_type) {
+                report.push_error(format!("{}.id", path), e);
+            }
+            if !registry::is_supported(&chain.adapter_type) {
+                report.push(format!("{}.adapter_type", path), format!("Invalid adapter type: {}", chain.adapter_type));
+            }
+            if let Err(e) = chain.normalize_address(&chain.bridge_address) {
+                report.push_error(format!("{}.bridge_address", path), e);
+            }
+            if chain.event_filters.is_empty() {
+                report.push(format!("{}.event_filters", path), format!("Chain {} has no event filters", chain.id));
+            }
+            if let Some(version) = &chain.min_node_version {
+                if let Err(e) = NodeVersion::parse(version) {
+                    report.push_error(format!("{}.min_node_version", path), e);
+                }
+            }
+            if let Some(submission) = &chain.submission {
+                for (j, window) in submission.windows.iter().enumerate() {
+                    if let Err(e) = SubmissionWindow::parse(window) {
+                        report.push_error(format!("{}.submission.windows[{}]", path, j), e);
+                    }
+                }
+                for (j, window) in submission.low_fee_windows.iter().enumerate() {
+                    if let Err(e) = SubmissionWindow::parse(window) {
+                        report.push_error(format!("{}.submission.low_fee_windows[{}]", path, j), e);
+                    }
+                }
+                if submission.max_relay_cost.is_some() && submission.gas_units_per_relay == 0 {
+                    report.push(format!("{}.submission.gas_units_per_relay", path), format!("gas_units_per_relay must be positive on chain {}", chain.id));
+                }
+                if submission.max_per_block == Some(0) {
+                    report.push(format!("{}.submission.max_per_block", path), format!("max_per_block must be positive on chain {}", chain.id));
+                }
+            }
+            if let Some(reimbursement) = &chain.reimbursement {
+                if let Err(e) = chain.normalize_address(&reimbursement.payout_address) {
+                    report.push_error(format!("{}.reimbursement.payout_address", path), e);
+                }
+                if reimbursement.claim_interval == 0 {
+                    report.push(format!("{}.reimbursement.claim_interval", path), format!("Claim interval must be greater than 0 on chain {}", chain.id));
+                }
+            }
+            if chain.batch.as_ref().is_some_and(|batch| batch.max_size == Some(0)) {
+                report.push(format!("{}.batch.max_size", path), format!("Batch max_size must be positive on chain {}", chain.id));
+            }
+            if let Some(overrides) = &chain.relayer {
+                let zero = [
+                    ("poll_interval", overrides.poll_interval == Some(0)),
+                    ("max_retries", overrides.max_retries == Some(0)),
+                    ("block_time", overrides.block_time == Some(0)),
+                    ("stuck_timeout", overrides.stuck_timeout == Some(0)),
+                ];
+                for (field, _) in zero.iter().filter(|(_, is_zero)| *is_zero) {
+                    report.push(format!("{}.relayer.{}", path, field), format!("Relayer {} on chain {} must be greater than 0", field, chain.id));
+                }
+            }
+            match &chain.plugin {
+                Some(plugin) if plugin.path.is_empty() || plugin.max_memory_bytes == 0 || plugin.fuel == 0 => {
+                    report.push(format!("{}.plugin", path), format!("Plugin path, max_memory_bytes and fuel must be set on chain {}", chain.id));
+                }
+                None if chain.adapter_type == "wasm" => {
+                    report.push(format!("{}.plugin", path), format!("Chain {} uses the wasm adapter but has no plugin configured", chain.id));
+                }
+                _ => {}
+            }
+            if chain.adapter_type == "solana" {
+                match &chain.solana {
+                    Some(solana) => {
+                        if let Err(e) = Pubkey::parse(&solana.nonce_account) {
+                            report.push_error(format!("{}.solana.nonce_account", path), e);
+                        }
+                    }
+                    None if chain.sender_keystore.is_some() => {
+                        report.push(format!("{}.solana", path), format!("Chain {} needs solana.nonce_account to submit transactions", chain.id));
+                    }
+                    None => {}
+                }
+            }
+            if chain.http.as_ref().is_some_and(|http| http.connect_timeout == 0 || http.request_timeout == 0) {
+                report.push(format!("{}.http", path), format!("HTTP timeouts must be positive on chain {}", chain.id));
+            }
+            if chain.http.is_some() || chain.auth.is_some() || !chain.rpc_headers.is_empty() {
+                if let Err(e) = http::client(chain) {
+                    report.push_error(format!("{}.http", path), e);
+                }
+            }
+            if chain.rooch.as_ref().is_some_and(|rooch| rooch.page_size == 0 || rooch.pages_per_poll == 0) {
+                report.push(format!("{}.rooch", path), format!("Rooch page_size and pages_per_poll must be positive on chain {}", chain.id));
+            }
+            match &chain.cosmos {
+                Some(cosmos) => {
+                    if let Err(e) = bech32_address(&cosmos.address_prefix, &[]) {
+                        report.push_error(format!("{}.cosmos.address_prefix", path), e);
+                    }
+                    if cosmos.fee_denom.is_empty() || !cosmos.gas_price.is_finite() || cosmos.gas_price < 0.0 || cosmos.gas_limit == 0 {
+                        report.push(format!("{}.cosmos", path), format!("Cosmos fee_denom, gas_price and gas_limit must be valid on chain {}", chain.id));
+                    }
+                    if cosmos.trusted_height.is_some() != cosmos.trusted_hash.is_some() {
+                        report.push(format!("{}.cosmos.trusted_hash", path), format!("Chain {} must set trusted_height and trusted_hash together", chain.id));
+                    }
+                    if cosmos.trusted_hash.as_ref().is_some_and(|hash| !hex::decode(hash).is_ok_and(|bytes| bytes.len() == 32)) {
+                        report.push(format!("{}.cosmos.trusted_hash", path), format!("Trusted hash on chain {} must be 32 bytes of hex", chain.id));
+                    }
+                    if cosmos.trusting_period == 0 {
+                        report.push(format!("{}.cosmos.trusting_period", path), format!("Cosmos trusting_period must be positive on chain {}", chain.id));
+                    }
+                }
+                None if chain.adapter_type == "cosmos" => {
+                    report.push(format!("{}.cosmos", path), format!("Chain {} uses the cosmos adapter but has no cosmos config", chain.id));
+                }
+                None => {}
+            }
+            if chain.sui.as_ref().is_some_and(|sui| sui.checkpoints_per_poll == 0) {
+                report.push(format!("{}.sui.checkpoints_per_poll", path), format!("checkpoints_per_poll must be positive on chain {}", chain.id));
+            }
+            match (&chain.light_client, chain.adapter_type.as_str()) {
+                (Some(LightClientConfig::Sui { committee, .. }), "sui") => {
+                    let invalid_keys = committee.iter().any(|m| !hex::decode(m.public_key.trim_start_matches("0x")).is_ok_and(|k| k.len() == 96));
+                    if committee.is_empty() || invalid_keys || committee.iter().all(|m| m.stake == 0) {
+                        report.push(format!("{}.light_client.committee", path), format!("Sui committee on chain {} needs 96-byte hex keys and positive stake", chain.id));
+                    }
+                }
+                (Some(LightClientConfig::Rooch { sequencer_public_key }), "rooch") => {
+                    if !hex::decode(sequencer_public_key.trim_start_matches("0x")).is_ok_and(|k| k.len() == 32) {
+                        report.push(format!("{}.light_client.sequencer_public_key", path), format!("Sequencer public key on chain {} must be 32 bytes of hex", chain.id));
+                    }
+                }
+                (Some(_), adapter_type) => {
+                    report.push(format!("{}.light_client", path), format!("Light client type does not match adapter {} on chain {}", adapter_type, chain.id));
+                }
+                (None, _) => {}
+            }
+            match &chain.json_rpc {
+                Some(templates) => {
+                    for (field, error) in json_rpc::check_templates(templates) {
+                        report.push(format!("{}.json_rpc.{}", path, field), error);
+                    }
+                }
+                None if chain.adapter_type == json_rpc::JSON_RPC_ADAPTER_TYPE => {
+                    report.push(format!("{}.json_rpc", path), format!("Chain {} uses the json_rpc adapter but has no method templates", chain.id));
+                }
+                None => {}
+            }
+            if let Some(rate_limit) = &chain.rate_limit {
+                if rate_limit.max_rps == 0 || rate_limit.burst == Some(0) {
+                    report.push(format!("{}.rate_limit", path), format!("Rate limit max_rps and burst must be positive on chain {}", chain.id));
+                }
+            }
+            if chain.ordering.as_ref().is_some_and(|ordering| ordering.skip_after == 0) {
+                report.push(format!("{}.ordering.skip_after", path), format!("Ordering skip_after must be positive on chain {}", chain.id));
+            }
+            if let Some(public_key) = &chain.payload_public_key {
+                if let Err(e) = confidential::parse_public_key(public_key) {
+                    report.push_error(format!("{}.payload_public_key", path), e);
+                }
+            }
+            if chain.chain_namespace.is_some() && chain.chain_id.is_none_or(|id| id > u32::MAX as u64) {
+                report.push(format!("{}.chain_id", path), format!("Chain {} has a chain_namespace and needs a chain_id that fits in 32 bits", chain.id));
+            }
+            if let Some(numeric_id) = chain.contract_chain_id() {
+                if let Err(e) = registry.register_numeric(&chain.id, numeric_id) {
+                    report.push_error(format!("{}.chain_id", path), e);
+                }
             }
         }
 
         // 验证资产配置
-        for asset in &self.assets {
-            if !chain_ids.contains(&&asset.native_chain) {
-                return Err(Error::Config(format!("Invalid chain ID in asset config: {}", asset.native_chain)));
+        for (i, asset) in self.assets.iter().enumerate() {
+            let path = format!("assets[{}]", i);
+            if self.assets[..i].iter().any(|a| a.name == asset.name) {
+                report.push(format!("{}.name", path), format!("Duplicate asset: {}", asset.name));
+            }
+            if !registry.contains(&asset.native_chain) {
+                report.push(format!("{}.native_chain", path), format!("Invalid chain ID in asset config: {}", asset.native_chain));
+            }
+
+            // 验证资产映射；转账负载不携带资产标识，同一原生链到同一映射链只能有一个资产
+            for chain_id in asset.mappings.keys() {
+                if !registry.contains(chain_id) {
+                    report.push(format!("{}.mappings.{}", path, chain_id), format!("Invalid chain ID in asset mapping: {}", chain_id));
+                }
+                if let Some(other) = self.assets[..i]
+                    .iter()
+                    .find(|a| a.native_chain == asset.native_chain && a.mappings.contains_key(chain_id))
+                {
+                    report.push(
+                        format!("{}.mappings.{}", path, chain_id),
+                        format!(
+                            "Ambiguous asset route {} -> {}: assets {} and {} cannot be told apart in transfer payloads",
+                            asset.native_chain, chain_id, other.name, asset.name,
+                        ),
+                    );
+                }
+            }
+            for (chain_id, decimals) in &asset.mapped_decimals {
+                let decimals_path = format!("{}.mapped_decimals.{}", path, chain_id);
+                if !asset.mappings.contains_key(chain_id) {
+                    report.push(decimals_path, format!("Decimals configured for unmapped chain {} of asset {}", chain_id, asset.name));
+                } else if decimals.abs_diff(asset.decimals) > 19 {
+                    // u64 金额最多 20 位十进制数字，更大的精度差必然溢出或归零
+                    report.push(decimals_path, format!("Decimal difference of asset {} on {} is too large", asset.name, chain_id));
+                }
+            }
+            if asset.relayer_fee.as_ref().is_some_and(|fee| fee.bps > 10000) {
+                report.push(format!("{}.relayer_fee.bps", path), format!("Relayer fee of asset {} exceeds 10000 bps", asset.name));
+            }
+            if asset.timelock.as_ref().is_some_and(|timelock| timelock.delay == 0) {
+                report.push(format!("{}.timelock.delay", path), format!("Timelock delay of asset {} must be positive", asset.name));
+            }
+        }
+
+        // 验证 NFT 集合配置
+        for (i, collection) in self.nft_collections.iter().enumerate() {
+            let path = format!("nft_collections[{}]", i);
+            if self.nft_collections[..i].iter().any(|c| c.name == collection.name) {
+                report.push(format!("{}.name", path), format!("Duplicate NFT collection: {}", collection.name));
             }
-            
-            // 验证资产映射
-            for (chain_id, _) in &asset.mappings {
-                if !chain_ids.contains(&chain_id) {
-                    return Err(Error::Config(format!("Invalid chain ID in asset mapping: {}", chain_id)));
+            if !registry.contains(&collection.native_chain) {
+                report.push(format!("{}.native_chain", path), format!("Invalid chain ID in NFT collection config: {}", collection.native_chain));
+            }
+            for chain_id in collection.mappings.keys() {
+                if !registry.contains(chain_id) {
+                    report.push(format!("{}.mappings.{}", path, chain_id), format!("Invalid chain ID in NFT collection mapping: {}", chain_id));
                 }
             }
         }
 
         // 验证验证者配置
-        for validator in &self.validators {
+        for (i, validator) in self.validators.iter().enumerate() {
+            let path = format!("validators[{}]", i);
+            if self.validators[..i].iter().any(|v| v.address.eq_ignore_ascii_case(&validator.address)) {
+                report.push(format!("{}.address", path), format!("Duplicate validator address: {}", validator.address));
+            }
+            if validator.weight == 0 {
+                report.push(format!("{}.weight", path), format!("Validator {} has zero weight", validator.address));
+            }
             // 验证公钥格式
             if hex::decode(&validator.public_key).is_err() {
-                return Err(Error::Config(format!("Invalid public key: {}", validator.public_key)));
+                report.push(format!("{}.public_key", path), format!("Invalid public key: {}", validator.public_key));
+            }
+            if let Some(bls_key) = &validator.bls_public_key {
+                if hex::decode(bls_key.trim_start_matches("0x")).map(|k| k.len()) != Ok(48) {
+                    report.push(format!("{}.bls_public_key", path), format!("Invalid BLS public key: {}", bls_key));
+                }
             }
-            
+
             // 验证支持的链
-            for chain in &validator.chains {
-                if !chain_ids.contains(&chain) {
-                    return Err(Error::Config(format!("Invalid chain ID in validator config: {}", chain)));
+            for (j, chain) in validator.chains.iter().enumerate() {
+                if !registry.contains(chain) {
+                    report.push(format!("{}.chains[{}]", path, j), format!("Invalid chain ID in validator config: {}", chain));
                 }
             }
         }
 
         // 验证中继器配置
         if self.relayer.poll_interval == 0 {
-            return Err(Error::Config("Relayer poll interval must be greater than 0".to_string()));
+            report.push("relayer.poll_interval", "Relayer poll interval must be greater than 0");
         }
         if self.relayer.max_retries == 0 {
-            return Err(Error::Config("Relayer max retries must be greater than 0".to_string()));
+            report.push("relayer.max_retries", "Relayer max retries must be greater than 0");
+        }
+        if self.relayer.max_message_age == 0 {
+            report.push("relayer.max_message_age", "Relayer max message age must be greater than 0");
+        }
+        if self.relayer.queue_capacity == 0 {
+            report.push("relayer.queue_capacity", "Relayer queue capacity must be greater than 0");
+        }
+        if self.relayer.batch_size == 0 {
+            report.push("relayer.batch_size", "Relayer batch size must be greater than 0");
+        }
+        if self.relayer.script_timeout == 0 {
+            report.push("relayer.script_timeout", "Relayer script timeout must be greater than 0");
+        }
+        // 未通过检查的消息转入保存在存储中的暂缓队列，没有存储时会一直占用待处理队列
+        if self.relayer.check_recipients && self.storage.is_none() {
+            report.push("relayer.check_recipients", "Recipient checks require a storage backend");
+        }
+
+        if self.relayer.keystore.is_some() && self.relayer.signer.is_some() {
+            report.push("relayer.signer", "relayer.keystore and relayer.signer cannot both be configured");
+        }
+        match &self.relayer.signer {
+            Some(SignerConfig::Vault(vault)) if vault.timeout_ms == 0 => {
+                report.push("relayer.signer.timeout_ms", "Vault signer timeout must be greater than 0");
+            }
+            Some(SignerConfig::AwsKms(kms)) if kms.timeout_ms == 0 => {
+                report.push("relayer.signer.timeout_ms", "AWS KMS signer timeout must be greater than 0");
+            }
+            Some(SignerConfig::Remote(remote)) if remote.timeout_ms == 0 => {
+                report.push("relayer.signer.timeout_ms", "Remote signer timeout must be greater than 0");
+            }
+            _ => {}
+        }
+
+        if let Some(coordination) = &self.relayer.coordination {
+            if self.storage.is_none() {
+                report.push("relayer.coordination", "Relayer coordination requires a shared storage backend");
+            }
+            if coordination.lease_ttl <= self.relayer.poll_interval {
+                report.push("relayer.coordination.lease_ttl", "Coordination lease TTL must be greater than the poll interval");
+            }
+            if coordination.follow_interval == 0 {
+                report.push("relayer.coordination.follow_interval", "Coordination follow interval must be greater than 0");
+            }
+        }
+
+        if let Some(screening) = &self.screening {
+            if screening.policy == ScreeningPolicy::Delay && screening.delay == 0 {
+                report.push("screening.delay", "Screening delay must be greater than 0");
+            }
+            if let ScreeningProviderConfig::Http(http) = &screening.provider {
+                if http.timeout_ms == 0 {
+                    report.push("screening.provider.timeout_ms", "Screening service timeout must be greater than 0");
+                }
+            }
+        }
+
+        // 验证存储后端
+        if let Some(storage) = &self.storage {
+            match storage.backend.as_str() {
+                "file" | "sqlite" if storage.path.is_empty() => {
+                    report.push("storage.path", format!("Storage backend {} requires a path", storage.backend));
+                }
+                "file" => {}
+                "sqlite" if cfg!(feature = "sqlite") => {}
+                "sqlite" => {
+                    report.push("storage.backend", "SQLite storage requires bridge-core built with the `sqlite` feature");
+                }
+                "postgres" if storage.url.is_none() => {
+                    report.push("storage.url", "PostgreSQL storage requires a connection url");
+                }
+                "postgres" if cfg!(feature = "postgres") => {}
+                "postgres" => {
+                    report.push("storage.backend", "PostgreSQL storage requires bridge-core built with the `postgres` feature");
+                }
+                backend => report.push("storage.backend", format!("Unsupported storage backend: {}", backend)),
+            }
+            if storage.max_connections == 0 {
+                report.push("storage.max_connections", "Storage max_connections must be greater than 0");
+            }
+            if let Some(retention) = &storage.retention {
+                if retention.max_age == 0 {
+                    report.push("storage.retention.max_age", "Retention max_age must be greater than 0");
+                }
+                if retention.interval == 0 {
+                    report.push("storage.retention.interval", "Retention interval must be greater than 0");
+                }
+                for (i, status) in retention.statuses.iter().enumerate() {
+                    if !status.is_terminal() {
+                        report.push(format!("storage.retention.statuses[{}]", i), format!("Only terminal messages can be pruned, found {:?}", status));
+                    }
+                }
+            }
+        }
+
+        if self.watchtower.as_ref().is_some_and(|w| w.delivery_timeout == 0) {
+            report.push("watchtower.delivery_timeout", "Watchtower delivery timeout must be greater than 0");
+        }
+
+        if let Some(indexer) = &self.indexer {
+            if indexer.backfill_batch == 0 {
+                report.push("indexer.backfill_batch", "Indexer backfill_batch must be greater than 0");
+            }
+            for chain_id in indexer.start_heights.keys() {
+                if !registry.contains(chain_id) {
+                    report.push(format!("indexer.start_heights.{}", chain_id), format!("Indexer references unknown chain {}", chain_id));
+                }
+            }
+        }
+
+        if let Some(logging) = &self.logging {
+            if let Err(e) = crate::logging::filter(logging) {
+                report.push_error("logging", e);
+            }
+            if logging.file.as_ref().is_some_and(|file| file.path.is_empty() || file.max_size == Some(0)) {
+                report.push("logging.file", "Log file requires a path and a max_size greater than 0");
+            }
+        }
+
+        if let Some(alerts) = &self.alerts {
+            if alerts.chain_unreachable_after == 0 || alerts.dead_letter_window == 0 || alerts.quorum_timeout == 0 {
+                report.push("alerts", "Alert durations must be greater than 0");
+            }
+            if alerts.dead_letter_threshold == 0 {
+                report.push("alerts.dead_letter_threshold", "Alert dead_letter_threshold must be greater than 0");
+            }
+            for (i, sink) in alerts.sinks.iter().enumerate() {
+                let missing = match sink {
+                    AlertSinkConfig::Slack(slack) => slack.webhook_url.is_empty(),
+                    AlertSinkConfig::PagerDuty(pagerduty) => pagerduty.routing_key.is_empty(),
+                    AlertSinkConfig::Telegram(telegram) => telegram.bot_token.is_empty() || telegram.chat_id.is_empty(),
+                };
+                if missing {
+                    report.push(format!("alerts.sinks[{}]", i), "Alert sink is missing its credentials");
+                }
+            }
+        }
+
+        if let Some(reconciliation) = &self.reconciliation {
+            if reconciliation.interval == 0 {
+                report.push("reconciliation.interval", "Reconciliation interval must be greater than 0");
+            }
+            for (i, asset) in reconciliation.assets.iter().enumerate() {
+                if !self.assets.iter().any(|a| &a.name == asset) {
+                    report.push(format!("reconciliation.assets[{}]", i), format!("Reconciliation references unknown asset {}", asset));
+                }
+            }
+        }
+
+        if let Some(slashing) = &self.slashing {
+            for (i, chain_id) in slashing.chains.iter().enumerate() {
+                if !registry.contains(chain_id) {
+                    report.push(format!("slashing.chains[{}]", i), format!("Slashing references unknown chain {}", chain_id));
+                }
+            }
+        }
+
+        if let Some(priority) = &self.relayer.priority {
+            let bands = &priority.value_bands;
+            for (i, band) in bands.iter().enumerate() {
+                let path = format!("relayer.priority.value_bands[{}]", i);
+                if band.name.is_empty() || band.name == crate::relayer::priority::UNBANDED {
+                    report.push(format!("{}.name", path), format!("Invalid priority band name: {:?}", band.name));
+                }
+                // 同名档位可分属不同资产，指标中合并计数
+                if bands[..i].iter().any(|b| b.name == band.name && b.asset == band.asset) {
+                    report.push(format!("{}.name", path), format!("Duplicate priority band: {}", band.name));
+                }
+                if let Some(asset) = &band.asset {
+                    if !self.assets.iter().any(|a| &a.name == asset) {
+                        report.push(format!("{}.asset", path), format!("Priority band references unknown asset {}", asset));
+                    }
+                }
+            }
+            for (i, route) in priority.routes.iter().enumerate() {
+                if !registry.contains(&route.source_chain) || !registry.contains(&route.target_chain) {
+                    report.push(
+                        format!("relayer.priority.routes[{}]", i),
+                        format!("Priority route references unknown chain {} -> {}", route.source_chain, route.target_chain),
+                    );
+                }
+            }
+        }
+
+        if let Some(guard) = &self.relayer.validator_set_guard {
+            if !(guard.max_weight_change > 0.0 && guard.max_weight_change <= 1.0) {
+                report.push("relayer.validator_set_guard.max_weight_change", "Validator set guard max_weight_change must be in (0, 1]");
+            }
+            if self.validators.iter().all(|v| v.weight == 0) {
+                report.push("relayer.validator_set_guard", "Validator set guard requires validators with weight");
+            }
+        }
+
+        if let Some(schemas) = &self.schemas {
+            if self.storage.is_none() {
+                report.push("schemas", "Schema registry requires a storage backend");
+            }
+            for (i, route) in schemas.routes.iter().enumerate() {
+                if !registry.contains(&route.source_chain) || !registry.contains(&route.target_chain) {
+                    report.push(format!("schemas.routes[{}]", i), format!(
+                        "Schema policy references unknown route {} -> {}",
+                        route.source_chain, route.target_chain
+                    ));
+                }
+            }
+        }
+
+        // 验证 p2p 配置
+        if self.p2p.is_some() && !cfg!(feature = "p2p") {
+            report.push("p2p", "p2p is configured but bridge-core was built without the `p2p` feature");
+        }
+
+        if self.api.as_ref().is_some_and(|api| api.grpc_listen.is_some()) && !cfg!(feature = "grpc") {
+            report.push("api.grpc_listen", "api.grpc_listen is configured but bridge-core was built without the `grpc` feature");
         }
 
-        Ok(())
+        report
+    }
+
+    /// 配置指纹：规范化 JSON 的 Blake2b-256 摘要（十六进制）
+    ///
+    /// JSON 对象键按字典序排列，字段顺序与 HashMap 迭代顺序不影响结果。
+    pub fn fingerprint(&self) -> Result<String, Error> {
+        let value = serde_json::to_value(self)
+            .map_err(|e| Error::Serialization(e.to_string()))?;
+        let bytes = serde_json::to_vec(&value)
+            .map_err(|e| Error::Serialization(e.to_string()))?;
+        Ok(hex::encode(Blake2b::<U32>::digest(bytes)))
+    }
+
+    /// 由链配置构建链登记表，链 ID 或合约数字链 ID 重复时返回错误
+    pub fn chain_registry(&self) -> Result<ChainRegistry, Error> {
+        let mut registry = ChainRegistry::new();
+        for chain in &self.chains {
+            registry.register(chain.id.clone(), &chain.adapter_type)?;
+            if let Some(numeric_id) = chain.contract_chain_id() {
+                registry.register_numeric(&chain.id, numeric_id)?;
+            }
+        }
+        Ok(registry)
     }
 
     pub fn get_chain_config(&self, chain_id: &str) -> Option<&ChainConfig> {
         self.chains.iter().find(|c| c.id == chain_id)
     }
 
+    /// 某条链实际使用的中继参数，未知的链使用全局值
+    pub fn relayer_settings(&self, chain_id: &str) -> RelayerSettings {
+        let chain = self.get_chain_config(chain_id);
+        let overrides = chain.and_then(|c| c.relayer.clone()).unwrap_or_default();
+        RelayerSettings {
+            poll_interval: overrides.poll_interval.unwrap_or(self.relayer.poll_interval),
+            max_retries: overrides.max_retries.unwrap_or(self.relayer.max_retries),
+            retry_delay: overrides.retry_delay.unwrap_or(self.relayer.retry_delay),
+            confirmations: overrides.confirmations.unwrap_or(self.relayer.confirmations),
+            block_time: overrides.block_time
+                .or_else(|| chain.and_then(|c| c.submission.as_ref()).map(|s| s.block_time))
+                .unwrap_or(1),
+            batch_size: chain
+                .and_then(|c| c.batch.as_ref())
+                .and_then(|batch| batch.max_size)
+                .unwrap_or(self.relayer.batch_size),
+            stuck_timeout: overrides.stuck_timeout.unwrap_or(DEFAULT_STUCK_TIMEOUT),
+            max_resubmissions: overrides.max_resubmissions.unwrap_or(DEFAULT_MAX_RESUBMISSIONS),
+        }
+    }
+
+    /// 所有链中最短的轮询间隔，中继循环按此间隔运行
+    pub fn shortest_poll_interval(&self) -> u64 {
+        self.chains
+            .iter()
+            .map(|chain| self.relayer_settings(&chain.id).poll_interval)
+            .min()
+            .unwrap_or(self.relayer.poll_interval)
+    }
+
+    /// 按链 ID 查找链配置，找不到时按适配器类型查找唯一匹配的链
+    pub fn resolve_chain(&self, name: &str) -> Option<&ChainConfig> {
+        if let Some(chain) = self.get_chain_config(name) {
+            return Some(chain);
+        }
+        let mut matches = self.chains.iter().filter(|c| c.adapter_type == name);
+        match (matches.next(), matches.next()) {
+            (Some(chain), None) => Some(chain),
+            _ => None,
+        }
+    }
+
     pub fn get_asset_config(&self, asset_name: &str) -> Option<&AssetConfig> {
         self.assets.iter().find(|a| a.name == asset_name)
     }
 
+    /// 查找源链上类型为 `collection` 且映射到目标链的 NFT 集合
+    pub fn find_nft_collection(&self, source_chain: &str, target_chain: &str, collection: &str) -> Option<&NftCollectionConfig> {
+        self.nft_collections.iter().find(|c| {
+            c.type_on(source_chain) == Some(collection) && c.type_on(target_chain).is_some()
+        })
+    }
+
     pub fn get_validators_for_chain(&self, chain_id: &str) -> Vec<&ValidatorConfig> {
         self.validators
             .iter()
             .filter(|v| v.chains.contains(&chain_id.to_string()))
             .collect()
     }
-} 
\ No newline at end of file
+} 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain_adapter::mock::MockOperation;
+    use crate::testing::{transfer_payload, two_chain_config, TwoChainHarness, SOURCE_CHAIN, TARGET_CHAIN};
+
+    #[test]
+    fn test_toml_document_matches_json() {
+        let json = serde_json::json!({
+            "chains": [{
+                "id": "sui",
+                "adapter_type": "sui",
+                "name": "Sui",
+                "rpc_url": "${SUI_RPC}",
+                "bridge_address": "0x1",
+                "event_filters": [{ "name": "MessageEvent", "handler": "handle_message_event" }]
+            }],
+            "assets": [],
+            "validators": [],
+            "relayer": { "poll_interval": 5, "max_retries": 3, "retry_delay": 10 }
+        });
+        let toml = ConfigFormat::Toml.render(&json).unwrap();
+        assert_eq!(ConfigFormat::Toml.parse(&toml).unwrap(), json);
+        assert_eq!(ConfigFormat::from_path(Path::new("bridge.TOML")), ConfigFormat::Toml);
+        assert_eq!(ConfigFormat::from_path(Path::new("bridge.json")), ConfigFormat::Json);
+
+        let vars = HashMap::from([("SUI_RPC".to_string(), "https://fullnode.testnet.sui.io".to_string())]);
+        let config = Config::parse_document(ConfigFormat::Toml.parse(&toml).unwrap(), &vars).unwrap();
+        assert_eq!(config.chains[0].rpc_url, "https://fullnode.testnet.sui.io");
+        assert_eq!(config.relayer.queue_capacity, 1_000);
+    }
+
+    #[test]
+    fn test_validation_reports_all_problems() {
+        let mut config = two_chain_config();
+        for chain in &mut config.chains {
+            chain.adapter_type = "rooch".to_string();
+        }
+        config.relayer.poll_interval = 1;
+        assert!(config.validation_report().is_empty());
+
+        let mut duplicate = config.chains[0].clone();
+        duplicate.event_filters.clear();
+        duplicate.bridge_address = "bridge".to_string();
+        config.chains.push(duplicate);
+        config.assets.push(config.assets[0].clone());
+        let validator = ValidatorConfig {
+            address: "0xA1".to_string(),
+            public_key: "00".repeat(32),
+            bls_public_key: None,
+            weight: 0,
+            chains: vec![],
+        };
+        config.validators = vec![validator.clone(), ValidatorConfig { address: "0xa1".to_string(), weight: 1, ..validator }];
+
+        let paths: Vec<_> = config.validation_report().issues.into_iter().map(|issue| issue.path).collect();
+        assert_eq!(paths, [
+            "chains[2].id",
+            "chains[2].bridge_address",
+            "chains[2].event_filters",
+            "assets[1].name",
+            "assets[1].mappings.mock_target",
+            "validators[0].weight",
+            "validators[1].address",
+        ]);
+        let message = config.validate().unwrap_err().to_string();
+        assert!(message.contains("7 problems found"));
+        assert!(message.contains("chains[2].id: Duplicate chain ID: mock_source"));
+
+        // 暂缓队列保存在存储中，会暂缓消息的检查须配置存储
+        let mut config = two_chain_config();
+        config.relayer.check_recipients = true;
+        config.schemas = Some(SchemaConfig::default());
+        let paths: Vec<_> = config.validation_report().issues.into_iter().map(|issue| issue.path).collect();
+        assert!(paths.iter().any(|path| path == "relayer.check_recipients"));
+        assert!(paths.iter().any(|path| path == "schemas"));
+    }
+
+    #[test]
+    fn test_numeric_chain_ids_are_unique() {
+        let mut config = two_chain_config();
+        for chain in &mut config.chains {
+            chain.adapter_type = "rooch".to_string();
+        }
+        config.relayer.poll_interval = 1;
+        config.chains[0].chain_id = Some(1);
+        config.chains[1].chain_id = Some(2);
+        let chains = config.chain_registry().unwrap();
+        assert_eq!(chains.numeric_id(TARGET_CHAIN), Some(2));
+        assert_eq!(chains.resolve_numeric(1).unwrap(), SOURCE_CHAIN);
+
+        let message = crate::types::CrossChainMessage {
+            nonce: 3,
+            source_chain: SOURCE_CHAIN.into(),
+            target_chain: TARGET_CHAIN.into(),
+            message_type: "transfer".to_string(),
+            payload: vec![9],
+        };
+        let bytes = message.canonical_bytes(&chains).unwrap();
+        assert_eq!(&bytes[..24], [3u64.to_le_bytes(), 1u64.to_le_bytes(), 2u64.to_le_bytes()].concat());
+
+        // 同一数字 ID 在不同命名空间下不冲突
+        config.chains[1].chain_id = Some(1);
+        config.chains[1].chain_namespace = Some(1);
+        assert!(config.validation_report().is_empty());
+
+        config.chains[1].chain_namespace = None;
+        let paths: Vec<_> = config.validation_report().issues.into_iter().map(|issue| issue.path).collect();
+        assert_eq!(paths, ["chains[1].chain_id"]);
+        assert!(config.chain_registry().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_chain_relayer_overrides() {
+        let mut config = two_chain_config();
+        config.chains[1].relayer = Some(ChainRelayerConfig { max_retries: Some(1), ..Default::default() });
+        let target = config.relayer_settings(TARGET_CHAIN);
+        assert_eq!(target.max_retries, 1);
+        assert_eq!(target.retry_delay, config.relayer.retry_delay);
+        assert_eq!(target.batch_size, config.relayer.batch_size);
+        assert_eq!(config.relayer_settings(SOURCE_CHAIN).max_retries, config.relayer.max_retries);
+
+        // 目标链只尝试一次，失败后不再重试
+        let mut harness = TwoChainHarness::with_config(config.clone()).await.unwrap();
+        harness.target.fail_next(MockOperation::Submit, Error::Chain("busy".to_string()));
+        harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([1u8; 32], 100));
+        harness.tick().await;
+        assert!(harness.target.submitted().is_empty());
+
+        // 源链事件按出块间隔换算的确认时间到达前不中继
+        config.chains[1].relayer = None;
+        config.chains[0].relayer = Some(ChainRelayerConfig { confirmations: Some(2), block_time: Some(3_600), ..Default::default() });
+        assert_eq!(config.relayer_settings(SOURCE_CHAIN).confirmation_delay(), 7_200);
+        let mut harness = TwoChainHarness::with_config(config).await.unwrap();
+        harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([1u8; 32], 100));
+        harness.tick().await;
+        assert!(harness.target.submitted().is_empty());
+        assert_eq!(harness.backlog(), 1);
+    }
+}
@@ -0,0 +1,89 @@
+use serde::Serialize;
+use crate::{
+    chain_adapter::ContractCheck,
+    relayer::ChainAdapterFactory,
+    types::ChainId,
+    Error,
+};
+use super::{ChainConfig, Config};
+
+/// 一条链的远程检查结果
+#[derive(Debug, Clone, Serialize)]
+pub struct RemoteCheck {
+    pub chain_id: ChainId,
+    /// 节点报告的 RPC 版本，未能连接时为 None
+    pub node_version: Option<String>,
+    /// 桥合约检查结果，未能完成检查时为 None
+    pub contract: Option<ContractCheck>,
+    /// 连接或查询失败的原因
+    pub error: Option<String>,
+}
+
+impl RemoteCheck {
+    /// 节点可连接、合约存在且定义了所有配置的事件类型
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none() && self.contract.as_ref().is_some_and(ContractCheck::is_ok)
+    }
+}
+
+/// 逐条链连接节点并检查桥合约，某条链失败不影响其它链的检查
+///
+/// 适配器由 `factory` 创建，连接与节点版本要求的检查在创建时完成。
+pub async fn check_remote(config: &Config, factory: &dyn ChainAdapterFactory) -> Vec<RemoteCheck> {
+    let mut checks = Vec::with_capacity(config.chains.len());
+    for chain in &config.chains {
+        let mut check = RemoteCheck {
+            chain_id: chain.id.clone(),
+            node_version: None,
+            contract: None,
+            error: None,
+        };
+        if let Err(e) = check_chain(factory, chain, &mut check).await {
+            check.error = Some(e.to_string());
+        }
+        checks.push(check);
+    }
+    checks
+}
+
+async fn check_chain(factory: &dyn ChainAdapterFactory, chain: &ChainConfig, check: &mut RemoteCheck) -> Result<(), Error> {
+    let adapter = factory.create_adapter(chain).await?;
+    let result = async {
+        check.node_version = Some(adapter.node_version().await?);
+        check.contract = Some(adapter.check_contract().await?);
+        Ok(())
+    }
+    .await;
+    adapter.close().await?;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain_adapter::mock::{MockChain, MockChainAdapterFactory, MockOperation};
+    use crate::testing::{two_chain_config, SOURCE_CHAIN, TARGET_CHAIN};
+
+    #[tokio::test]
+    async fn test_check_remote_reports_each_chain() {
+        let mut config = two_chain_config();
+        let source = MockChain::new(SOURCE_CHAIN);
+        let target = MockChain::new(TARGET_CHAIN);
+        let third = MockChain::new("third");
+        config.chains.push(crate::testing::mock_chain_config("third"));
+        let factory = MockChainAdapterFactory::new([source.clone(), target.clone(), third.clone()]);
+
+        target.set_contract_events(Some(&["MessageSentEvent"]));
+        third.fail_next(MockOperation::Connect, Error::Network("connection refused".to_string()));
+        let checks = check_remote(&config, &factory).await;
+        assert!(checks[0].is_ok());
+        assert_eq!(checks[0].node_version.as_deref(), Some("mock"));
+        assert!(!checks[1].is_ok());
+        assert_eq!(checks[1].contract.as_ref().unwrap().missing_events, ["MessageEvent"]);
+        assert!(checks[2].error.as_ref().unwrap().contains("connection refused"));
+
+        source.set_contract_events(None);
+        let checks = check_remote(&config, &factory).await;
+        assert!(!checks[0].contract.as_ref().unwrap().exists);
+    }
+}
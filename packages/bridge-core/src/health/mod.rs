@@ -0,0 +1,190 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex,
+};
+use crate::{
+    storage::{MessageQuery, MessageStore},
+    Error,
+};
+
+/// 单条链适配器的健康状态
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChainHealth {
+    /// 最近一次轮询是否成功
+    pub connected: bool,
+    /// 是否已完成首次同步；完成后即使节点暂时不可用也保持为 true
+    pub synced: bool,
+    /// 最近一次成功轮询的时间（Unix 秒）
+    pub last_poll: Option<u64>,
+    pub last_error: Option<String>,
+    /// 提交账户的 gas 余额（最小单位），未配置发送账户或尚未查询时为 None
+    pub gas_balance: Option<u128>,
+    /// 最近一次查询到的 gas 价格，适配器无法提供时为 None
+    #[serde(default)]
+    pub gas_price: Option<u64>,
+    /// 因预估成本超过上限而推迟提交的消息数
+    #[serde(default)]
+    pub cost_deferred: usize,
+}
+
+/// 存储后端的健康状态
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StorageHealth {
+    pub healthy: bool,
+    pub error: Option<String>,
+}
+
+/// `/healthz` 与 `/readyz` 返回的状态报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthReport {
+    /// 所有适配器完成首次同步且存储可用
+    pub ready: bool,
+    pub chains: BTreeMap<String, ChainHealth>,
+    /// 未配置存储时为 None
+    pub storage: Option<StorageHealth>,
+    /// 已观察但尚未处理完毕的消息数
+    pub backlog: usize,
+}
+
+/// 中继循环写入、健康检查服务读取的共享状态
+pub struct HealthMonitor {
+    chains: Mutex<BTreeMap<String, ChainHealth>>,
+    backlog: AtomicUsize,
+}
+
+impl HealthMonitor {
+    pub fn new<I: IntoIterator<Item = String>>(chain_ids: I) -> Self {
+        Self {
+            chains: Mutex::new(chain_ids.into_iter().map(|id| (id, ChainHealth::default())).collect()),
+            backlog: AtomicUsize::new(0),
+        }
+    }
+
+    /// 记录一次成功的事件轮询
+    pub fn record_poll(&self, chain_id: &str, now: u64) {
+        let mut chains = self.chains.lock().unwrap();
+        let chain = chains.entry(chain_id.to_string()).or_default();
+        chain.connected = true;
+        chain.synced = true;
+        chain.last_poll = Some(now);
+        chain.last_error = None;
+    }
+
+    /// 记录一次失败的事件轮询
+    pub fn record_failure(&self, chain_id: &str, error: &Error) {
+        let mut chains = self.chains.lock().unwrap();
+        let chain = chains.entry(chain_id.to_string()).or_default();
+        chain.connected = false;
+        chain.last_error = Some(error.to_string());
+    }
+
+    pub fn record_gas_balance(&self, chain_id: &str, balance: Option<u128>) {
+        self.chains.lock().unwrap().entry(chain_id.to_string()).or_default().gas_balance = balance;
+    }
+
+    pub fn record_gas_price(&self, chain_id: &str, price: Option<u64>) {
+        self.chains.lock().unwrap().entry(chain_id.to_string()).or_default().gas_price = price;
+    }
+
+    pub fn set_cost_deferred(&self, chain_id: &str, deferred: usize) {
+        self.chains.lock().unwrap().entry(chain_id.to_string()).or_default().cost_deferred = deferred;
+    }
+
+    /// 各链的健康状态快照
+    pub fn chains(&self) -> BTreeMap<String, ChainHealth> {
+        self.chains.lock().unwrap().clone()
+    }
+
+    pub fn set_backlog(&self, backlog: usize) {
+        self.backlog.store(backlog, Ordering::Relaxed);
+    }
+
+    /// 生成状态报告，配置了存储时顺带探测存储是否可用
+    pub async fn report(&self, store: Option<&dyn MessageStore>) -> HealthReport {
+        let storage = match store {
+            Some(store) => {
+                let probe = MessageQuery { limit: Some(1), ..Default::default() };
+                Some(match store.query(&probe).await {
+                    Ok(_) => StorageHealth { healthy: true, error: None },
+                    Err(e) => StorageHealth { healthy: false, error: Some(e.to_string()) },
+                })
+            }
+            None => None,
+        };
+        let chains = self.chains.lock().unwrap().clone();
+        HealthReport {
+            ready: chains.values().all(|chain| chain.synced)
+                && storage.as_ref().is_none_or(|storage| storage.healthy),
+            chains,
+            storage,
+            backlog: self.backlog.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// 健康检查服务共享状态
+#[derive(Clone)]
+pub struct HealthState {
+    pub monitor: Arc<HealthMonitor>,
+    pub store: Option<Arc<dyn MessageStore>>,
+}
+
+/// 构建健康检查路由
+///
+/// `/healthz` 只要进程能够响应即返回 200，链或存储的异常体现在报告内容中，
+/// 避免节点短暂不可用时存活探针重启中继器；`/readyz` 在未就绪时返回 503。
+pub fn router(state: HealthState) -> Router {
+    Router::new()
+        .route("/healthz", get(healthz))
+        .route("/readyz", get(readyz))
+        .with_state(state)
+}
+
+async fn healthz(State(state): State<HealthState>) -> Json<HealthReport> {
+    Json(state.monitor.report(state.store.as_deref()).await)
+}
+
+async fn readyz(State(state): State<HealthState>) -> Response {
+    let report = state.monitor.report(state.store.as_deref()).await;
+    let status = if report.ready { StatusCode::OK } else { StatusCode::SERVICE_UNAVAILABLE };
+    (status, Json(report)).into_response()
+}
+
+// 启动自检
+pub mod selfcheck;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_readiness_requires_initial_sync() {
+        let monitor = HealthMonitor::new(["sui".to_string(), "rooch".to_string()]);
+        assert!(!monitor.report(None).await.ready);
+
+        monitor.record_poll("sui", 100);
+        monitor.record_failure("rooch", &Error::Network("connection refused".to_string()));
+        let report = monitor.report(None).await;
+        assert!(!report.ready);
+        assert_eq!(report.chains["rooch"].last_error.as_deref(), Some("Network error: connection refused"));
+
+        // 首次同步完成后，节点暂时不可用不影响就绪状态
+        monitor.record_poll("rooch", 101);
+        monitor.record_failure("sui", &Error::Network("timeout".to_string()));
+        monitor.set_backlog(3);
+        let report = monitor.report(None).await;
+        assert!(report.ready);
+        assert!(!report.chains["sui"].connected);
+        assert_eq!(report.chains["sui"].last_poll, Some(100));
+        assert_eq!(report.backlog, 3);
+    }
+}
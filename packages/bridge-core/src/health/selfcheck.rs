@@ -0,0 +1,242 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt;
+use crate::{
+    chain_adapter::ChainAdapter,
+    config::Config,
+    keystore::Keystore,
+    relayer::{rotation::ValidatorSet, ChainAdapterFactory},
+    storage::{self, MessageQuery},
+    types::ChainId,
+};
+
+/// 单项检查的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    Pass,
+    Fail,
+    /// 未配置相应功能，无需检查
+    Skipped,
+}
+
+impl CheckStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CheckStatus::Pass => "PASS",
+            CheckStatus::Fail => "FAIL",
+            CheckStatus::Skipped => "SKIP",
+        }
+    }
+}
+
+/// 启动自检的一项检查
+#[derive(Debug, Clone, Serialize)]
+pub struct SelfCheck {
+    /// 检查项：`keys`、`storage`、`rpc`、`contract` 或 `quorum`
+    pub check: String,
+    /// 检查对象，例如链 ID；全局检查为 `-`
+    pub subject: String,
+    pub status: CheckStatus,
+    /// 失败时是否拒绝启动
+    pub critical: bool,
+    pub detail: String,
+}
+
+/// 启动自检报告
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SelfCheckReport {
+    pub checks: Vec<SelfCheck>,
+}
+
+impl SelfCheckReport {
+    fn push(&mut self, check: &str, subject: &str, status: CheckStatus, critical: bool, detail: impl Into<String>) {
+        self.checks.push(SelfCheck {
+            check: check.to_string(),
+            subject: subject.to_string(),
+            status,
+            critical,
+            detail: detail.into(),
+        });
+    }
+
+    /// 失败的关键检查，非空时不应启动
+    pub fn critical_failures(&self) -> Vec<&SelfCheck> {
+        self.checks.iter().filter(|c| c.critical && c.status == CheckStatus::Fail).collect()
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.critical_failures().is_empty()
+    }
+}
+
+/// 以表格形式输出，非关键检查失败时结果列标注 `(non-critical)`
+impl fmt::Display for SelfCheckReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let check_width = self.checks.iter().map(|c| c.check.len()).chain(["CHECK".len()]).max().unwrap_or_default();
+        let subject_width = self.checks.iter().map(|c| c.subject.len()).chain(["SUBJECT".len()]).max().unwrap_or_default();
+        writeln!(f, "{:<check_width$}  {:<subject_width$}  {:<19}  DETAIL", "CHECK", "SUBJECT", "RESULT")?;
+        for check in &self.checks {
+            let result = if check.status == CheckStatus::Fail && !check.critical {
+                format!("{} (non-critical)", check.status.as_str())
+            } else {
+                check.status.as_str().to_string()
+            };
+            writeln!(f, "{:<check_width$}  {:<subject_width$}  {:<19}  {}", check.check, check.subject, result, check.detail)?;
+        }
+        Ok(())
+    }
+}
+
+/// 启动前依次检查密钥能否解密、存储能否打开、各链 RPC 能否响应、桥合约是否存在以及验证者法定权重是否可达
+///
+/// 每项检查独立进行，某项失败不影响其它检查。适配器由 `factory` 创建，检查完毕后关闭。
+pub async fn run(config: &Config, factory: &dyn ChainAdapterFactory) -> SelfCheckReport {
+    let mut report = SelfCheckReport::default();
+
+    match Keystore::open(&config.relayer).await {
+        Ok(Some(keystore)) => {
+            report.push("keys", "-", CheckStatus::Pass, true, format!("{} key(s) loaded", keystore.public_keys().len()));
+        }
+        Ok(None) => report.push("keys", "-", CheckStatus::Skipped, true, "No keystore or signer configured"),
+        Err(e) => report.push("keys", "-", CheckStatus::Fail, true, e.to_string()),
+    }
+
+    match &config.storage {
+        Some(storage_config) => {
+            let probe = MessageQuery { limit: Some(1), ..Default::default() };
+            let result = match storage::open(storage_config).await {
+                Ok(store) => store.query(&probe).await.map(|_| ()),
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(()) => report.push("storage", &storage_config.backend, CheckStatus::Pass, true, "Opened and queried"),
+                Err(e) => report.push("storage", &storage_config.backend, CheckStatus::Fail, true, e.to_string()),
+            }
+        }
+        None => report.push("storage", "-", CheckStatus::Skipped, true, "No storage configured"),
+    }
+
+    let mut adapters: HashMap<ChainId, Box<dyn ChainAdapter>> = HashMap::new();
+    for chain in &config.chains {
+        let adapter = match factory.create_adapter(chain).await {
+            Ok(adapter) => adapter,
+            Err(e) => {
+                report.push("rpc", &chain.id, CheckStatus::Fail, true, e.to_string());
+                report.push("contract", &chain.id, CheckStatus::Skipped, true, "RPC unavailable");
+                continue;
+            }
+        };
+        match adapter.node_version().await {
+            Ok(version) => report.push("rpc", &chain.id, CheckStatus::Pass, true, format!("Node version {}", version)),
+            Err(e) => report.push("rpc", &chain.id, CheckStatus::Fail, true, e.to_string()),
+        }
+        match adapter.check_contract().await {
+            Ok(check) if check.is_ok() => {
+                report.push("contract", &chain.id, CheckStatus::Pass, true, format!("Bridge contract at {}", chain.bridge_address));
+            }
+            Ok(check) if !check.exists => {
+                report.push("contract", &chain.id, CheckStatus::Fail, true, format!("No bridge contract at {}", chain.bridge_address));
+            }
+            Ok(check) => {
+                let detail = format!("Missing event types: {}", check.missing_events.join(", "));
+                report.push("contract", &chain.id, CheckStatus::Fail, true, detail);
+            }
+            Err(e) => report.push("contract", &chain.id, CheckStatus::Fail, true, e.to_string()),
+        }
+        adapters.insert(chain.id.clone(), adapter);
+    }
+
+    check_quorum(config, &adapters, &mut report).await;
+
+    for adapter in adapters.values() {
+        let _ = adapter.close().await;
+    }
+    report
+}
+
+/// 可达的验证者权重：验证者负责的每条链都能连接，且配置了注册表的链上处于激活状态
+///
+/// 只有要求验证者证明时才是关键检查，否则中继不依赖验证者法定权重。
+async fn check_quorum(config: &Config, adapters: &HashMap<ChainId, Box<dyn ChainAdapter>>, report: &mut SelfCheckReport) {
+    let critical = config.relayer.require_attestations;
+    if config.validators.is_empty() {
+        report.push("quorum", "-", CheckStatus::Skipped, critical, "No validators configured");
+        return;
+    }
+
+    let mut reachable = 0u64;
+    let mut unreachable = Vec::new();
+    for validator in &config.validators {
+        let mut ok = true;
+        for chain_id in &validator.chains {
+            let Some(adapter) = adapters.get(chain_id.as_str()) else {
+                ok = false;
+                break;
+            };
+            if adapter.config().validator_registry.is_none() {
+                continue;
+            }
+            match adapter.get_validator_registration(&validator.address).await {
+                Ok(Some(registration)) if registration.active => {}
+                _ => {
+                    ok = false;
+                    break;
+                }
+            }
+        }
+        if ok {
+            reachable += validator.weight;
+        } else {
+            unreachable.push(validator.address.as_str());
+        }
+    }
+
+    let set = ValidatorSet {
+        validators: config.validators.clone(),
+        threshold: config.relayer.attestation_threshold,
+        retiring: Vec::new(),
+    };
+    let threshold = set.threshold();
+    let mut detail = format!("Reachable weight {} of {}, threshold {}", reachable, set.total_weight(), threshold);
+    if !unreachable.is_empty() {
+        detail.push_str(&format!("; unreachable: {}", unreachable.join(", ")));
+    }
+    let status = if reachable >= threshold { CheckStatus::Pass } else { CheckStatus::Fail };
+    report.push("quorum", "-", status, critical, detail);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain_adapter::mock::{MockChain, MockChainAdapterFactory, MockOperation};
+    use crate::config::ValidatorConfig;
+    use crate::testing::{two_chain_config, SOURCE_CHAIN, TARGET_CHAIN};
+    use crate::Error;
+
+    #[tokio::test]
+    async fn test_reports_critical_failures() {
+        let mut config = two_chain_config();
+        config.relayer.require_attestations = true;
+        config.validators = vec![ValidatorConfig {
+            address: "0xv1".to_string(),
+            public_key: "00".repeat(32),
+            bls_public_key: None,
+            weight: 1,
+            chains: vec![SOURCE_CHAIN.to_string()],
+        }];
+        let source = MockChain::new(SOURCE_CHAIN);
+        let target = MockChain::new(TARGET_CHAIN);
+        let factory = MockChainAdapterFactory::new([source.clone(), target.clone()]);
+
+        let report = run(&config, &factory).await;
+        assert!(report.is_ok(), "{}", report);
+        assert!(report.to_string().contains("quorum"));
+
+        source.fail_next(MockOperation::Connect, Error::Network("connection refused".to_string()));
+        target.set_contract_events(None);
+        let report = run(&config, &factory).await;
+        let failures: Vec<_> = report.critical_failures().iter().map(|c| (c.check.as_str(), c.subject.as_str())).collect();
+        assert_eq!(failures, [("rpc", SOURCE_CHAIN), ("contract", TARGET_CHAIN), ("quorum", "-")]);
+    }
+}
@@ -0,0 +1,183 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use crate::{
+    api::message_history,
+    ledger::{EntrySide, LedgerAccount, LedgerEntry, LedgerQuery},
+    storage::{MessageQuery, MessageRecord, MessageStore},
+    Error,
+};
+
+/// 地址历史默认返回的消息数
+const DEFAULT_HISTORY_LIMIT: usize = 100;
+/// 资产排行默认返回的资产数
+const DEFAULT_TOP_ASSETS: usize = 10;
+
+/// 浏览器接口共享状态
+#[derive(Clone)]
+pub struct ExplorerState {
+    pub store: Arc<dyn MessageStore>,
+}
+
+/// 浏览器查询参数，时间范围按观察时间或分录时间过滤
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExplorerParams {
+    pub since: Option<u64>,
+    pub until: Option<u64>,
+    pub limit: Option<usize>,
+}
+
+/// 一条路线上某资产的转账量
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RouteVolume {
+    pub asset: String,
+    pub source_chain: String,
+    pub target_chain: String,
+    /// 发起的转账笔数
+    pub transfers: u64,
+    /// 源链转出总额
+    pub volume: u128,
+    /// 目标链已到账总额（扣除手续费后）
+    pub delivered: u128,
+    /// 手续费与中继器手续费合计
+    pub fees: u128,
+}
+
+/// 资产在所有路线上的转账量
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AssetVolume {
+    pub asset: String,
+    pub transfers: u64,
+    pub volume: u128,
+}
+
+/// 构建浏览器路由
+pub fn router(state: ExplorerState) -> Router {
+    Router::new()
+        .route("/explorer/addresses/:address", get(get_address_history))
+        .route("/explorer/messages/:message_id", get(get_message))
+        .route("/explorer/stats/volume", get(get_volume))
+        .route("/explorer/stats/assets", get(get_top_assets))
+        .with_state(state)
+}
+
+/// 地址作为发送者或接收者参与的消息，按观察时间倒序
+pub async fn address_history(store: &dyn MessageStore, address: &str, params: &ExplorerParams) -> Result<Vec<MessageRecord>, Error> {
+    let normalized = address.trim_start_matches("0x").to_lowercase();
+    let base = MessageQuery { since: params.since, until: params.until, ..Default::default() };
+    // 发送者按适配器提供的原样保存，接收者为不带 0x 前缀的十六进制
+    let mut queries = vec![
+        MessageQuery { sender: Some(address.to_string()), ..base.clone() },
+        MessageQuery { recipient: Some(normalized.clone()), ..base.clone() },
+    ];
+    let prefixed = format!("0x{}", normalized);
+    if prefixed != address {
+        queries.push(MessageQuery { sender: Some(prefixed), ..base });
+    }
+
+    let mut records: BTreeMap<String, MessageRecord> = BTreeMap::new();
+    for query in &queries {
+        for record in store.query(query).await? {
+            records.insert(record.message_id.clone(), record);
+        }
+    }
+    let mut records: Vec<_> = records.into_values().collect();
+    records.sort_by(|a, b| b.observed_at.cmp(&a.observed_at).then_with(|| a.message_id.cmp(&b.message_id)));
+    records.truncate(params.limit.unwrap_or(DEFAULT_HISTORY_LIMIT));
+    Ok(records)
+}
+
+/// 按资产与路线汇总转账量
+pub fn route_volumes(entries: &[LedgerEntry]) -> Vec<RouteVolume> {
+    let mut volumes: BTreeMap<(String, String, String), RouteVolume> = BTreeMap::new();
+    for entry in entries {
+        let key = (entry.asset.clone(), entry.source_chain.clone(), entry.target_chain.clone());
+        let volume = volumes.entry(key).or_insert_with(|| RouteVolume {
+            asset: entry.asset.clone(),
+            source_chain: entry.source_chain.clone(),
+            target_chain: entry.target_chain.clone(),
+            transfers: 0,
+            volume: 0,
+            delivered: 0,
+            fees: 0,
+        });
+        let amount = entry.amount as u128;
+        match (entry.account, entry.side) {
+            (LedgerAccount::Lock | LedgerAccount::Burn, EntrySide::Debit) => {
+                volume.transfers += 1;
+                volume.volume += amount;
+            }
+            (LedgerAccount::Mint | LedgerAccount::Unlock, EntrySide::Credit) => volume.delivered += amount,
+            (LedgerAccount::Fee | LedgerAccount::RelayerFee, EntrySide::Credit) => volume.fees += amount,
+            _ => {}
+        }
+    }
+    volumes.into_values().filter(|v| v.transfers > 0 || v.delivered > 0).collect()
+}
+
+/// 按转出总额降序排列的资产
+pub fn top_assets(volumes: &[RouteVolume], limit: usize) -> Vec<AssetVolume> {
+    let mut assets: BTreeMap<&str, AssetVolume> = BTreeMap::new();
+    for route in volumes {
+        let asset = assets.entry(route.asset.as_str()).or_insert_with(|| AssetVolume {
+            asset: route.asset.clone(),
+            transfers: 0,
+            volume: 0,
+        });
+        asset.transfers += route.transfers;
+        asset.volume += route.volume;
+    }
+    let mut assets: Vec<_> = assets.into_values().collect();
+    assets.sort_by(|a, b| b.volume.cmp(&a.volume).then_with(|| a.asset.cmp(&b.asset)));
+    assets.truncate(limit);
+    assets
+}
+
+fn error_response(status: StatusCode, message: String) -> Response {
+    (status, Json(serde_json::json!({ "error": message }))).into_response()
+}
+
+async fn get_address_history(
+    State(state): State<ExplorerState>,
+    Path(address): Path<String>,
+    Query(params): Query<ExplorerParams>,
+) -> Response {
+    match address_history(state.store.as_ref(), &address, &params).await {
+        Ok(records) => Json(records).into_response(),
+        Err(e) => error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+async fn get_message(State(state): State<ExplorerState>, Path(message_id): Path<String>) -> Response {
+    match message_history(state.store.as_ref(), &message_id).await {
+        Ok(Some(history)) => Json(history).into_response(),
+        Ok(None) => error_response(StatusCode::NOT_FOUND, format!("Unknown message {}", message_id)),
+        Err(e) => error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+async fn query_volumes(store: &dyn MessageStore, params: &ExplorerParams) -> Result<Vec<RouteVolume>, Error> {
+    let query = LedgerQuery { since: params.since, until: params.until, ..Default::default() };
+    Ok(route_volumes(&store.query_ledger(&query).await?))
+}
+
+async fn get_volume(State(state): State<ExplorerState>, Query(params): Query<ExplorerParams>) -> Response {
+    match query_volumes(state.store.as_ref(), &params).await {
+        Ok(volumes) => Json(volumes).into_response(),
+        Err(e) => error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+async fn get_top_assets(State(state): State<ExplorerState>, Query(params): Query<ExplorerParams>) -> Response {
+    match query_volumes(state.store.as_ref(), &params).await {
+        Ok(volumes) => Json(top_assets(&volumes, params.limit.unwrap_or(DEFAULT_TOP_ASSETS))).into_response(),
+        Err(e) => error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
@@ -0,0 +1,352 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
+use tracing::{debug, error, info, warn};
+use crate::{
+    api,
+    config::{Config, IndexerConfig},
+    chain_adapter::ChainAdapter,
+    confidential,
+    ledger::Transfer,
+    metrics,
+    relayer::{ChainAdapterFactory, DefaultChainAdapterFactory},
+    storage::{self, MessageRecord, MessageStore, SourceRecord, SubmissionRecord},
+    types::{ChainId, ChainRegistry, CrossChainMessage, Delivery, MessageStatus, SignedMessage},
+    watchtower::Reconciler,
+    Error,
+};
+use explorer::ExplorerState;
+
+// 浏览器查询接口
+pub mod explorer;
+
+/// 交付迟迟匹配不到源链消息时，索引器不告警，只在调试日志中记录
+const UNMATCHED_DELIVERY_TIMEOUT: u64 = 24 * 3600;
+
+/// 一轮索引的结果
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IndexProgress {
+    /// 新写入的源链消息数
+    pub messages: usize,
+    /// 新关联到源链消息的交付数
+    pub deliveries: usize,
+    /// 仍在回放历史事件的链数
+    pub backfilling: usize,
+}
+
+/// 浏览器后端的索引器
+///
+/// 从配置的起始高度回放各链的历史事件，之后持续读取新事件与交付记录，
+/// 写入消息存储：源链事件记为待处理的消息并记转出账，目标链交付按消息 ID 关联后
+/// 把消息标记为已处理、记到账并保存交付交易。索引器只读链上数据，不提交任何交易，可独立于中继器运行。
+/// 回放进度只保存在内存中，重启后从起始高度重新回放，写入是幂等的。
+pub struct Indexer {
+    config: Config,
+    /// 规范哈希使用的数字链 ID
+    chains: ChainRegistry,
+    settings: IndexerConfig,
+    adapters: HashMap<ChainId, Box<dyn ChainAdapter>>,
+    store: Arc<dyn MessageStore>,
+    reconciler: Reconciler,
+    /// 各链下一个待回放的高度，追上最新高度后移除
+    backfill: HashMap<ChainId, u64>,
+    /// 尚未关联到交付的转账消息，关联后记到账
+    in_flight: HashMap<String, CrossChainMessage>,
+}
+
+impl Indexer {
+    /// 按配置打开 SQL 消息存储并创建索引器
+    pub async fn new(config: Config) -> Result<Self, Error> {
+        let storage_config = config.storage.as_ref()
+            .ok_or_else(|| Error::Config("Indexer requires a storage backend".to_string()))?;
+        if storage_config.backend == "file" {
+            return Err(Error::Config("Indexer requires a SQL storage backend (sqlite or postgres)".to_string()));
+        }
+        let store: Arc<dyn MessageStore> = Arc::from(storage::open(storage_config).await?);
+        let factory = DefaultChainAdapterFactory::new(&config);
+        Self::with_factory(config, &factory, store).await
+    }
+
+    /// 使用指定的适配器工厂与存储创建，测试时可替换为模拟链
+    pub async fn with_factory(config: Config, factory: &dyn ChainAdapterFactory, store: Arc<dyn MessageStore>) -> Result<Self, Error> {
+        let settings = config.indexer.clone().unwrap_or_default();
+        let mut adapters = HashMap::new();
+        let mut backfill = HashMap::new();
+        for chain in &config.chains {
+            adapters.insert(chain.id.clone(), factory.create_adapter(chain).await?);
+            let start = settings.start_heights.get(chain.id.as_str()).copied().unwrap_or_default();
+            backfill.insert(chain.id.clone(), start);
+        }
+
+        Ok(Self {
+            chains: config.chain_registry()?,
+            config,
+            settings,
+            adapters,
+            store,
+            reconciler: Reconciler::new(UNMATCHED_DELIVERY_TIMEOUT),
+            backfill,
+            in_flight: HashMap::new(),
+        })
+    }
+
+    /// 索引写入的消息存储，供浏览器接口查询
+    pub fn store(&self) -> Arc<dyn MessageStore> {
+        self.store.clone()
+    }
+
+    /// 执行一轮索引：回放一批历史事件，读取新事件，再关联新的交付
+    pub async fn poll(&mut self) -> IndexProgress {
+        let now = now_secs();
+        let mut progress = IndexProgress::default();
+
+        // 先读源链再读目标链，尽量让交付在读取时已有对应的源链消息
+        for chain in self.config.chains.clone() {
+            let mut messages = match self.backfill_batch(&chain.id).await {
+                Ok(messages) => messages,
+                Err(e) => {
+                    error!("Failed to replay history of {}: {}", chain.id, e);
+                    Vec::new()
+                }
+            };
+            let Some(adapter) = self.adapters.get(&chain.id) else { continue };
+            match adapter.listen_events().await {
+                Ok(live) => messages.extend(live),
+                Err(e) => error!("Failed to listen events on {}: {}", chain.id, e),
+            }
+            for message in messages {
+                match self.index_message(&chain.id, message, now).await {
+                    Ok(true) => progress.messages += 1,
+                    Ok(false) => {}
+                    Err(e) => warn!("Failed to index message from {}: {}", chain.id, e),
+                }
+            }
+        }
+
+        for chain in &self.config.chains {
+            let Some(adapter) = self.adapters.get(&chain.id) else { continue };
+            match adapter.listen_deliveries().await {
+                Ok(deliveries) => {
+                    for delivery in deliveries {
+                        for divergence in self.reconciler.observe(delivery, now) {
+                            debug!("Indexed delivery diverges from its message: {:?}", divergence);
+                        }
+                    }
+                }
+                Err(e) => error!("Failed to listen deliveries on {}: {}", chain.id, e),
+            }
+        }
+        for divergence in self.reconciler.check(now) {
+            debug!("Unreconciled bridge event: {:?}", divergence);
+        }
+        for (message_id, delivery) in self.reconciler.take_matched() {
+            match self.index_delivery(&message_id, &delivery, now).await {
+                Ok(()) => progress.deliveries += 1,
+                Err(e) => warn!("Failed to index delivery {} of {}: {}", delivery.event_id, message_id, e),
+            }
+        }
+
+        progress.backfilling = self.backfill.len();
+        progress
+    }
+
+    /// 持续索引，配置了 `api` 时在其监听地址提供浏览器接口
+    pub async fn run(mut self) -> Result<(), Error> {
+        info!("Starting indexer for {} chain(s)", self.adapters.len());
+
+        if let Some(api_config) = &self.config.api {
+            let router = explorer::router(ExplorerState { store: self.store.clone() });
+            let listen = api_config.listen.clone();
+            tokio::spawn(async move {
+                if let Err(e) = api::serve(&listen, router).await {
+                    error!("Explorer API stopped: {}", e);
+                }
+            });
+        }
+
+        loop {
+            let progress = self.poll().await;
+            if progress.messages > 0 || progress.deliveries > 0 {
+                info!(
+                    "Indexed {} message(s) and {} deliveries, {} chain(s) backfilling",
+                    progress.messages, progress.deliveries, progress.backfilling
+                );
+            }
+            // 回放期间不等待，尽快追上最新高度
+            if progress.backfilling == 0 {
+                sleep(Duration::from_secs(self.config.relayer.poll_interval)).await;
+            }
+        }
+    }
+
+    /// 回放链的下一段历史事件；适配器无法提供链高度或已追上最新高度时结束回放
+    async fn backfill_batch(&mut self, chain_id: &ChainId) -> Result<Vec<SignedMessage>, Error> {
+        let (Some(&from), Some(adapter)) = (self.backfill.get(chain_id), self.adapters.get(chain_id)) else {
+            return Ok(Vec::new());
+        };
+        let Some(latest) = adapter.latest_height().await? else {
+            info!("Chain {} cannot report its height, indexing new events only", chain_id);
+            self.backfill.remove(chain_id);
+            return Ok(Vec::new());
+        };
+        if from > latest {
+            return Ok(Vec::new());
+        }
+        let to = latest.min(from.saturating_add(self.settings.backfill_batch - 1));
+        let messages = adapter.replay_events(from, to).await?;
+        debug!("Replayed {} event(s) of {} in [{}, {}]", messages.len(), chain_id, from, to);
+        if to == latest {
+            info!("Indexer caught up with {} at height {}", chain_id, latest);
+            self.backfill.remove(chain_id);
+        } else {
+            self.backfill.insert(chain_id.clone(), to + 1);
+        }
+        Ok(messages)
+    }
+
+    /// 写入一条源链消息，已索引过的消息只登记等待交付，返回是否新写入
+    async fn index_message(&mut self, chain_id: &ChainId, message: SignedMessage, now: u64) -> Result<bool, Error> {
+        let message_id = message.message.message_id(&self.chains)?;
+        self.reconciler.expect(&self.config, &message.message, now)?;
+        if let Some(existing) = self.store.get(&message_id).await? {
+            if existing.status == MessageStatus::Pending {
+                self.in_flight.insert(message_id, message.message);
+            }
+            return Ok(false);
+        }
+
+        // 回放的历史事件以事件时间为准，便于按时间窗口统计
+        let observed_at = if message.timestamp > 0 { message.timestamp } else { now };
+        let transfer = Transfer::parse(&self.config, &message_id, &message.message);
+        let redacted = confidential::redact(&message.message);
+        self.store.record_source(&SourceRecord {
+            message_id: message_id.clone(),
+            chain: chain_id.to_string(),
+            event_name: message.event_name.clone(),
+            sender: message.sender.clone(),
+            provenance: message.provenance.clone(),
+            payload_redacted: redacted.is_some(),
+            message: redacted.unwrap_or_else(|| message.message.clone()),
+            signature: message.signature.clone(),
+            observed_at,
+        }).await?;
+        self.store.upsert(&MessageRecord {
+            message_id: message_id.clone(),
+            source_chain: message.message.source_chain.to_string(),
+            target_chain: message.message.target_chain.to_string(),
+            asset: metrics::route_asset(&self.config, &message.message.source_chain, &message.message.target_chain),
+            sender: message.sender.clone(),
+            recipient: transfer.as_ref().map(|t| hex::encode(t.recipient())),
+            nonce: message.message.nonce,
+            status: MessageStatus::Pending,
+            observed_at,
+            completed_at: None,
+            error: None,
+            cause: None,
+        }).await?;
+        if let Some(transfer) = &transfer {
+            self.store.append_ledger(&transfer.initiated(observed_at)).await?;
+        }
+        self.in_flight.insert(message_id, message.message);
+        Ok(true)
+    }
+
+    /// 关联一次交付：消息标记为已处理，保存交付交易并记到账
+    async fn index_delivery(&mut self, message_id: &str, delivery: &Delivery, now: u64) -> Result<(), Error> {
+        let Some(mut record) = self.store.get(message_id).await? else {
+            return Err(Error::Config(format!("Message {} is not indexed", message_id)));
+        };
+        if record.status != MessageStatus::Pending {
+            return Ok(());
+        }
+        record.status = MessageStatus::Processed;
+        record.completed_at = Some(now);
+        self.store.upsert(&record).await?;
+        self.store.record_submission(&SubmissionRecord {
+            message_id: message_id.to_string(),
+            chain: delivery.target_chain.to_string(),
+            attempt: 1,
+            tx_hash: delivery.tx_hash.clone(),
+            error: None,
+            submitted_at: now,
+        }).await?;
+
+        if let Some(message) = self.in_flight.remove(message_id) {
+            if let Some(transfer) = Transfer::parse(&self.config, message_id, &message) {
+                self.store.append_ledger(&transfer.delivered(now)).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain_adapter::mock::{MockChain, MockChainAdapterFactory};
+    use crate::storage::{file::FileStore, MessageQuery};
+    use crate::testing::{transfer_payload, two_chain_config, SOURCE_CHAIN, TARGET_CHAIN};
+
+    #[tokio::test]
+    async fn test_backfill_and_correlate_deliveries() {
+        let source = MockChain::new(SOURCE_CHAIN);
+        let target = MockChain::new(TARGET_CHAIN);
+        let factory = MockChainAdapterFactory::new([source.clone(), target.clone()]);
+        let path = std::env::temp_dir().join(format!("bridge-indexer-test-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let store: Arc<dyn MessageStore> = Arc::new(FileStore::open(&path).unwrap());
+
+        let mut config = two_chain_config();
+        config.indexer = Some(IndexerConfig { backfill_batch: 2, ..Default::default() });
+        // 历史事件：索引器启动前已发出的三笔转账
+        for amount in [100, 200, 300] {
+            source.send(TARGET_CHAIN, "transfer", transfer_payload([0xaa; 32], amount));
+        }
+        source.discard_events();
+        source.set_height(Some(2));
+
+        let mut indexer = Indexer::with_factory(config, &factory, store.clone()).await.unwrap();
+        let first = indexer.poll().await;
+        assert_eq!(first.messages, 2);
+        assert_eq!(first.backfilling, 1);
+        let second = indexer.poll().await;
+        assert_eq!(second.messages, 1);
+        assert_eq!(second.backfilling, 0);
+
+        target.deliver(Delivery {
+            event_id: "delivery-1".to_string(),
+            source_chain: SOURCE_CHAIN.into(),
+            target_chain: TARGET_CHAIN.into(),
+            nonce: Some(1),
+            recipient: Some(vec![0xaa; 32]),
+            amount: Some(200),
+            tx_hash: Some("0xdelivered".to_string()),
+            message_id: None,
+        });
+        assert_eq!(indexer.poll().await.deliveries, 1);
+
+        let history = explorer::address_history(store.as_ref(), &format!("0x{}", hex::encode([0xaa; 32])), &Default::default())
+            .await
+            .unwrap();
+        assert_eq!(history.len(), 3);
+        let delivered: Vec<_> = history.iter().filter(|r| r.status == MessageStatus::Processed).collect();
+        assert_eq!(delivered.len(), 1);
+        assert_eq!(delivered[0].nonce, 1);
+        let pending = store.query(&MessageQuery { status: Some(MessageStatus::Pending), ..Default::default() }).await.unwrap();
+        assert_eq!(pending.len(), 2);
+
+        let volumes = explorer::route_volumes(&store.query_ledger(&Default::default()).await.unwrap());
+        assert_eq!(volumes.len(), 1);
+        assert_eq!((volumes[0].transfers, volumes[0].volume, volumes[0].delivered), (3, 600, 200));
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use crate::{
+    chain_adapter::registry,
+    config::Config,
+    types::SUPPORTED_MESSAGE_VERSIONS,
+    Error,
+};
+
+/// 中继器身份与构建信息
+///
+/// 供运维与集成方核对线上实例运行的确切版本与配置。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildInfo {
+    pub version: String,
+    pub git_commit: String,
+    /// 构建时启用的 cargo feature
+    pub features: Vec<String>,
+    pub adapter_types: Vec<String>,
+    pub message_format_versions: Vec<u8>,
+    /// 当前加载配置的指纹，未加载配置时为 None
+    pub config_fingerprint: Option<String>,
+}
+
+impl BuildInfo {
+    /// 收集当前构建信息，传入配置时附带配置指纹
+    pub fn current(config: Option<&Config>) -> Result<Self, Error> {
+        let features = env!("BRIDGE_FEATURES")
+            .split(',')
+            .filter(|feature| !feature.is_empty())
+            .map(|feature| feature.to_string())
+            .collect();
+
+        Ok(Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            git_commit: env!("BRIDGE_GIT_COMMIT").to_string(),
+            features,
+            adapter_types: registry::adapter_types(),
+            message_format_versions: SUPPORTED_MESSAGE_VERSIONS.to_vec(),
+            config_fingerprint: config.map(|c| c.fingerprint()).transpose()?,
+        })
+    }
+}
@@ -0,0 +1,329 @@
+use ed25519_dalek::{PublicKey, Signature, Verifier};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+use tracing::{info, error};
+use crate::{
+    config::{Config, RelayerConfig},
+    chain_adapter::ChainAdapter,
+    signer::{self, LocalSigner, Signer},
+    types::{ChainId, ChainRegistry, CrossChainMessage, KeyRotation},
+    Error,
+};
+
+/// 验证者密钥库
+///
+/// 密钥来自本地密钥库文件（十六进制编码的 ed25519 私钥数组）或远程签名后端。
+/// 启动时需要通过 `verify_registrations` 与链上注册表核对，只有核对通过的密钥才允许签名，
+/// 避免使用已被轮换下线的密钥继续签名。
+pub struct Keystore {
+    signers: Vec<Arc<dyn Signer>>,
+    /// 已通过链上注册核对的公钥（十六进制）
+    verified: HashSet<String>,
+}
+
+impl Keystore {
+    /// 从密钥库文件加载本地密钥
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        Self::from_secret_keys(&read_secret_keys(path)?)
+    }
+
+    /// 从原始私钥字节创建密钥库
+    pub fn from_secret_keys(secret_keys: &[Vec<u8>]) -> Result<Self, Error> {
+        let signers = secret_keys
+            .iter()
+            .map(|bytes| Ok(Arc::new(LocalSigner::from_bytes(bytes)?) as Arc<dyn Signer>))
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(Self::from_signers(signers))
+    }
+
+    /// 由任意签名后端创建密钥库
+    pub fn from_signers(signers: Vec<Arc<dyn Signer>>) -> Self {
+        Self {
+            signers,
+            verified: HashSet::new(),
+        }
+    }
+
+    /// 按中继器配置打开密钥库：优先连接远程签名后端，其次加载本地密钥库文件，都未配置时为 None
+    pub async fn open(config: &RelayerConfig) -> Result<Option<Self>, Error> {
+        if let Some(signer_config) = &config.signer {
+            return Ok(Some(Self::from_signers(vec![signer::connect(signer_config).await?])));
+        }
+        config.keystore.as_ref().map(Self::load).transpose()
+    }
+
+    /// 获取密钥库中的全部公钥（十六进制）
+    pub fn public_keys(&self) -> Vec<String> {
+        self.signers
+            .iter()
+            .map(|s| hex::encode(s.public_key()))
+            .collect()
+    }
+
+    /// 已通过链上注册核对的公钥（十六进制）
+    pub fn verified_keys(&self) -> Vec<String> {
+        self.public_keys()
+            .into_iter()
+            .filter(|k| self.verified.contains(k))
+            .collect()
+    }
+
+    /// 公钥是否已通过链上注册核对
+    pub fn is_verified(&self, public_key: &str) -> bool {
+        self.verified.contains(&normalize_key(public_key))
+    }
+
+    /// 核对每个本地密钥都对应一个链上有效注册且权重一致
+    ///
+    /// 任何一个密钥核对失败都会返回错误，并列出全部不一致项。
+    pub async fn verify_registrations(
+        &mut self,
+        config: &Config,
+        adapters: &HashMap<ChainId, Box<dyn ChainAdapter>>,
+    ) -> Result<(), Error> {
+        let mut mismatches = Vec::new();
+        let mut verified = HashSet::new();
+
+        for public_key in self.public_keys() {
+            let validator = match config
+                .validators
+                .iter()
+                .find(|v| normalize_key(&v.public_key) == public_key)
+            {
+                Some(validator) => validator,
+                None => {
+                    mismatches.push(format!("key {} is not configured as a validator", public_key));
+                    continue;
+                }
+            };
+
+            let mut key_ok = true;
+            for chain_id in &validator.chains {
+                let adapter = match adapters.get(chain_id.as_str()) {
+                    Some(adapter) => adapter,
+                    None => {
+                        mismatches.push(format!("chain {} not available for validator {}", chain_id, validator.address));
+                        key_ok = false;
+                        continue;
+                    }
+                };
+
+                match adapter.get_validator_registration(&validator.address).await? {
+                    Some(registration) if !registration.active => {
+                        mismatches.push(format!("validator {} is inactive on {}", validator.address, chain_id));
+                        key_ok = false;
+                    }
+                    Some(registration) if registration.weight != validator.weight => {
+                        mismatches.push(format!(
+                            "validator {} weight mismatch on {}: local {}, on-chain {}",
+                            validator.address, chain_id, validator.weight, registration.weight
+                        ));
+                        key_ok = false;
+                    }
+                    Some(_) => {}
+                    None => {
+                        mismatches.push(format!("validator {} is not registered on {}", validator.address, chain_id));
+                        key_ok = false;
+                    }
+                }
+            }
+
+            if key_ok {
+                verified.insert(public_key);
+            }
+        }
+
+        if !mismatches.is_empty() {
+            for mismatch in &mismatches {
+                error!("Key ceremony check failed: {}", mismatch);
+            }
+            return Err(Error::Key(format!("Key ceremony failed: {}", mismatches.join("; "))));
+        }
+
+        info!("Key ceremony passed for {} local key(s)", verified.len());
+        self.verified = verified;
+        Ok(())
+    }
+
+    /// 使用指定公钥对应的签名后端签名，未通过链上核对的密钥拒绝签名
+    pub async fn sign(&self, public_key: &str, message: &[u8]) -> Result<Vec<u8>, Error> {
+        let public_key = normalize_key(public_key);
+        if !self.verified.contains(&public_key) {
+            return Err(Error::Key(format!("Refusing to sign with unverified key: {}", public_key)));
+        }
+        self.signer(&public_key)?.sign(message).await
+    }
+
+    /// 使用指定公钥对应的签名后端对跨链消息的规范哈希签名
+    pub async fn sign_message(
+        &self,
+        public_key: &str,
+        message: &CrossChainMessage,
+        chains: &ChainRegistry,
+    ) -> Result<Vec<u8>, Error> {
+        let public_key = normalize_key(public_key);
+        if !self.verified.contains(&public_key) {
+            return Err(Error::Key(format!("Refusing to sign with unverified key: {}", public_key)));
+        }
+        self.signer(&public_key)?.sign_message(message, chains).await
+    }
+
+    /// 私钥能否导出，远程签名后端的密钥不能导出
+    pub fn is_exportable(&self, public_key: &str) -> bool {
+        self.signer(&normalize_key(public_key))
+            .is_ok_and(|s| s.secret_key_bytes().is_some())
+    }
+
+    /// 导出已核对密钥的私钥字节，用于派生 p2p 节点身份
+    pub fn secret_key_bytes(&self, public_key: &str) -> Result<[u8; 32], Error> {
+        let public_key = normalize_key(public_key);
+        if !self.verified.contains(&public_key) {
+            return Err(Error::Key(format!("Refusing to export unverified key: {}", public_key)));
+        }
+        let signer = self.signer(&public_key)?;
+        signer
+            .secret_key_bytes()
+            .ok_or_else(|| Error::Key(format!("Key {} is held by the {} signer and cannot be exported", public_key, signer.backend())))
+    }
+
+    /// 为验证者生成新密钥，并用 `public_key` 对应的旧密钥签署轮换声明
+    ///
+    /// 签署轮换声明不要求旧密钥已通过链上核对：验证者可能正是因为旧密钥即将失效才轮换。
+    pub async fn rotate(&self, public_key: &str, address: &str, grace_period: u64, issued_at: u64) -> Result<(LocalSigner, KeyRotation), Error> {
+        let signer = self.signer(&normalize_key(public_key))?;
+        let new_signer = LocalSigner::generate();
+        let mut rotation = KeyRotation {
+            address: address.to_string(),
+            old_public_key: signer.public_key().to_vec(),
+            new_public_key: new_signer.public_key().to_vec(),
+            grace_period,
+            issued_at,
+            signature: Vec::new(),
+        };
+        rotation.signature = signer.sign(&rotation.signing_bytes()?).await?;
+        Ok((new_signer, rotation))
+    }
+
+    fn signer(&self, public_key: &str) -> Result<&Arc<dyn Signer>, Error> {
+        self.signers
+            .iter()
+            .find(|s| hex::encode(s.public_key()) == public_key)
+            .ok_or_else(|| Error::Key(format!("Key not found in keystore: {}", public_key)))
+    }
+}
+
+/// 读取密钥库文件中的全部私钥
+///
+/// 只用于离线签名等不经过链上注册核对的场景，中继器应使用 [`Keystore::load`]。
+pub fn read_secret_keys<P: AsRef<Path>>(path: P) -> Result<Vec<Vec<u8>>, Error> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| Error::Key(format!("Failed to read keystore: {}", e)))?;
+    let encoded: Vec<String> = serde_json::from_str(&content)
+        .map_err(|e| Error::Key(format!("Failed to parse keystore: {}", e)))?;
+
+    encoded
+        .iter()
+        .map(|key| hex::decode(key.trim_start_matches("0x")))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| Error::Key(format!("Invalid secret key encoding: {}", e)))
+}
+
+/// 把私钥追加到密钥库文件，文件不存在时新建
+pub fn append_secret_key<P: AsRef<Path>>(path: P, secret_key: &[u8; 32]) -> Result<(), Error> {
+    let path = path.as_ref();
+    let mut encoded: Vec<String> = match std::fs::read_to_string(path) {
+        Ok(content) => serde_json::from_str(&content)
+            .map_err(|e| Error::Key(format!("Failed to parse keystore: {}", e)))?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+        Err(e) => return Err(Error::Key(format!("Failed to read keystore: {}", e))),
+    };
+    encoded.push(hex::encode(secret_key));
+    let content = serde_json::to_string_pretty(&encoded).map_err(|e| Error::Serialization(e.to_string()))?;
+    std::fs::write(path, content).map_err(|e| Error::Key(format!("Failed to write keystore: {}", e)))
+}
+
+/// 校验 ed25519 签名
+pub fn verify_signature(public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<(), Error> {
+    let public_key = PublicKey::from_bytes(public_key)
+        .map_err(|e| Error::Key(format!("Invalid public key: {}", e)))?;
+    let signature = Signature::try_from(signature)
+        .map_err(|e| Error::Key(format!("Invalid signature: {}", e)))?;
+    public_key
+        .verify(message, &signature)
+        .map_err(|e| Error::Key(format!("Signature verification failed: {}", e)))
+}
+
+pub(crate) fn normalize_key(key: &str) -> String {
+    key.trim_start_matches("0x").to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_unverified_key_refuses_to_sign() {
+        let keystore = Keystore::from_secret_keys(&[vec![1u8; 32]]).unwrap();
+        let public_key = keystore.public_keys().remove(0);
+
+        assert!(!keystore.is_verified(&public_key));
+        assert!(keystore.sign(&public_key, b"message").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_verified_key_signs() {
+        let mut keystore = Keystore::from_secret_keys(&[vec![1u8; 32]]).unwrap();
+        let public_key = keystore.public_keys().remove(0);
+        keystore.verified.insert(public_key.clone());
+
+        let signature = keystore.sign(&format!("0x{}", public_key.to_uppercase()), b"message").await.unwrap();
+        assert_eq!(signature.len(), 64);
+        assert!(keystore.is_exportable(&public_key));
+    }
+
+    #[tokio::test]
+    async fn test_rotation_is_signed_by_old_key() {
+        let keystore = Keystore::from_secret_keys(&[vec![1u8; 32]]).unwrap();
+        let public_key = keystore.public_keys().remove(0);
+
+        let (new_signer, mut rotation) = keystore.rotate(&public_key, "0xv1", 3600, 100).await.unwrap();
+        assert_eq!(hex::encode(&rotation.old_public_key), public_key);
+        assert_eq!(rotation.new_public_key, new_signer.public_key().to_vec());
+        rotation.verify().unwrap();
+
+        rotation.grace_period = 7200;
+        assert!(rotation.verify().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_offline_attestation_verifies() {
+        let path = std::env::temp_dir().join(format!("bridge-keystore-test-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        append_secret_key(&path, &[2u8; 32]).unwrap();
+        let signer = LocalSigner::from_bytes(&read_secret_keys(&path).unwrap()[0]).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let chains = crate::testing::test_chains();
+        let message = CrossChainMessage {
+            nonce: 1,
+            source_chain: crate::testing::SOURCE_CHAIN.into(),
+            target_chain: crate::testing::TARGET_CHAIN.into(),
+            message_type: "transfer".to_string(),
+            payload: vec![1, 2, 3],
+        };
+        let mut attestation = crate::types::Attestation {
+            message_id: message.message_id(&chains).unwrap(),
+            signature: signer.sign_message(&message, &chains).await.unwrap(),
+            message,
+            validator: "0xv1".to_string(),
+            public_key: hex::encode(signer.public_key()),
+            timestamp: 100,
+        };
+        attestation.verify(&chains, &signer.public_key()).unwrap();
+        assert!(attestation.verify(&chains, &[3u8; 32]).is_err());
+
+        attestation.message.nonce = 2;
+        assert!(attestation.verify(&chains, &signer.public_key()).is_err());
+    }
+}
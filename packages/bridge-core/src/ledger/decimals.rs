@@ -0,0 +1,60 @@
+use crate::Error;
+
+/// 精度换算结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rescaled {
+    /// 目标链精度下的金额
+    pub amount: u64,
+    /// 降低精度时无法表示的尾差（源链单位），留在源链不随转账交付
+    pub dust: u64,
+}
+
+/// 将金额从 `from` 位小数换算为 `to` 位小数
+///
+/// 使用 u128 计算，换算后的金额超出 u64 或舍去尾差后为零时返回错误。
+pub fn rescale(amount: u64, from: u8, to: u8) -> Result<Rescaled, Error> {
+    let factor = |diff: u8| {
+        10u128
+            .checked_pow(diff as u32)
+            .ok_or_else(|| Error::Config(format!("Decimal difference {} is too large", diff)))
+    };
+
+    if to >= from {
+        let scaled = (amount as u128)
+            .checked_mul(factor(to - from)?)
+            .and_then(|scaled| u64::try_from(scaled).ok())
+            .ok_or_else(|| Error::Serialization(format!(
+                "Amount {} overflows u64 when scaled from {} to {} decimals",
+                amount, from, to
+            )))?;
+        return Ok(Rescaled { amount: scaled, dust: 0 });
+    }
+
+    let factor = factor(from - to)?;
+    let scaled = amount as u128 / factor;
+    if amount > 0 && scaled == 0 {
+        return Err(Error::Serialization(format!(
+            "Amount {} is below the smallest unit at {} decimals",
+            amount, to
+        )));
+    }
+    Ok(Rescaled {
+        amount: scaled as u64,
+        dust: (amount as u128 % factor) as u64,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rescale() {
+        assert_eq!(rescale(1_500_000, 6, 9).unwrap(), Rescaled { amount: 1_500_000_000, dust: 0 });
+        assert_eq!(rescale(1_500_000_123, 9, 6).unwrap(), Rescaled { amount: 1_500_000, dust: 123 });
+        assert_eq!(rescale(42, 8, 8).unwrap(), Rescaled { amount: 42, dust: 0 });
+        assert!(rescale(u64::MAX, 6, 9).is_err());
+        assert!(rescale(999, 9, 6).is_err());
+        assert!(rescale(1, 0, 40).is_err());
+    }
+}
@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use crate::types::CrossChainMessage;
+use super::{EntrySide, LedgerAccount, LedgerEntry};
+
+/// gas 分录使用的资产名，金额为所在链 gas 代币的最小单位
+pub const GAS_ASSET: &str = "gas";
+
+/// 中继器在目标链提交消息消耗的 gas：借记 gas，贷记提交账户余额
+///
+/// 批量提交时由调用方把整笔交易的 gas 分摊到各条消息。
+pub fn gas_spent(message_id: &str, message: &CrossChainMessage, gas: u64, timestamp: u64) -> Vec<LedgerEntry> {
+    let entry = |index: usize, account: LedgerAccount, side: EntrySide| LedgerEntry {
+        entry_id: format!("{}:gas:{}", message_id, index),
+        message_id: message_id.to_string(),
+        asset: GAS_ASSET.to_string(),
+        source_chain: message.source_chain.to_string(),
+        target_chain: message.target_chain.to_string(),
+        chain: message.target_chain.to_string(),
+        account,
+        side,
+        amount: gas,
+        timestamp,
+    };
+    vec![
+        entry(0, LedgerAccount::Gas, EntrySide::Debit),
+        entry(1, LedgerAccount::GasWallet, EntrySide::Credit),
+    ]
+}
+
+/// 中继器从桥合约认领的 gas 报销：借记提交账户余额，贷记报销收入
+///
+/// 报销不对应单条消息，分录以认领交易的哈希作为 `message_id`，源链与目标链均为认领所在的链。
+pub fn refund_claimed(chain: &str, tx_hash: &str, amount: u64, timestamp: u64) -> Vec<LedgerEntry> {
+    let entry = |index: usize, account: LedgerAccount, side: EntrySide| LedgerEntry {
+        entry_id: format!("{}:refund_claim:{}", tx_hash, index),
+        message_id: tx_hash.to_string(),
+        asset: GAS_ASSET.to_string(),
+        source_chain: chain.to_string(),
+        target_chain: chain.to_string(),
+        chain: chain.to_string(),
+        account,
+        side,
+        amount,
+        timestamp,
+    };
+    vec![
+        entry(0, LedgerAccount::GasWallet, EntrySide::Debit),
+        entry(1, LedgerAccount::GasRefund, EntrySide::Credit),
+    ]
+}
+
+/// 把一笔交易的 gas 分摊到 `count` 条消息，余数计入第一条
+pub fn split_gas(gas: u64, count: usize) -> Vec<u64> {
+    if count == 0 {
+        return Vec::new();
+    }
+    let share = gas / count as u64;
+    let mut shares = vec![share; count];
+    shares[0] += gas % count as u64;
+    shares
+}
+
+/// 中继器在一条链上的收支
+///
+/// gas 以该链 gas 代币计，手续费按资产分别以源链单位计，两者由运营方按价格折算比较。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChainFees {
+    pub chain: String,
+    /// 记录了 gas 消耗的已提交消息数
+    pub submitted: usize,
+    pub gas_spent: u128,
+    /// 从桥合约认领的 gas 报销
+    pub gas_refunded: u128,
+    /// 各资产的中继器手续费收入
+    pub fees_earned: BTreeMap<String, u128>,
+}
+
+impl ChainFees {
+    /// 平均每条消息消耗的 gas
+    pub fn gas_per_message(&self) -> u128 {
+        self.gas_spent / self.submitted.max(1) as u128
+    }
+
+    /// 扣除报销后的 gas 净支出，报销超过支出时为 0
+    pub fn net_gas(&self) -> u128 {
+        self.gas_spent.saturating_sub(self.gas_refunded)
+    }
+}
+
+/// 按目标链汇总中继器的手续费收入与 gas 支出
+pub fn fee_report(entries: &[LedgerEntry]) -> Vec<ChainFees> {
+    let mut chains: BTreeMap<&str, (ChainFees, BTreeSet<&str>)> = BTreeMap::new();
+    for entry in entries {
+        let (fees, messages) = chains.entry(entry.chain.as_str()).or_insert_with(|| {
+            (ChainFees { chain: entry.chain.clone(), ..Default::default() }, BTreeSet::new())
+        });
+        match (entry.account, entry.side) {
+            (LedgerAccount::Gas, EntrySide::Debit) => {
+                fees.gas_spent += entry.amount as u128;
+                messages.insert(entry.message_id.as_str());
+            }
+            (LedgerAccount::GasRefund, EntrySide::Credit) => fees.gas_refunded += entry.amount as u128,
+            (LedgerAccount::RelayerFee, EntrySide::Credit) => {
+                *fees.fees_earned.entry(entry.asset.clone()).or_default() += entry.amount as u128;
+            }
+            _ => {}
+        }
+    }
+    chains
+        .into_values()
+        .filter(|(fees, messages)| !messages.is_empty() || !fees.fees_earned.is_empty() || fees.gas_refunded > 0)
+        .map(|(mut fees, messages)| {
+            fees.submitted = messages.len();
+            fees
+        })
+        .collect()
+}
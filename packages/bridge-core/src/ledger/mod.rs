@@ -0,0 +1,602 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Write;
+use crate::{
+    config::{AssetConfig, Config},
+    types::{CrossChainMessage, TRANSFER_MESSAGE_TYPE},
+    Error,
+};
+use decimals::{rescale, Rescaled};
+
+/// 记账科目
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerAccount {
+    /// 原生资产在源链锁定
+    Lock,
+    /// 包装资产在源链销毁
+    Burn,
+    /// 包装资产在目标链铸造
+    Mint,
+    /// 原生资产在目标链解锁
+    Unlock,
+    /// 跨链手续费
+    Fee,
+    /// 中继器从转账金额中扣除的手续费
+    RelayerFee,
+    /// 中继失败后退回用户
+    Refund,
+    /// 已离开源链、尚未到达目标链的在途资产
+    InTransit,
+    /// 中继器提交交易消耗的 gas
+    Gas,
+    /// 中继器提交账户的 gas 余额
+    GasWallet,
+    /// 桥合约报销给中继器的 gas
+    GasRefund,
+    /// 降低精度时无法交付、留在源链的尾差
+    Dust,
+}
+
+impl LedgerAccount {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LedgerAccount::Lock => "lock",
+            LedgerAccount::Burn => "burn",
+            LedgerAccount::Mint => "mint",
+            LedgerAccount::Unlock => "unlock",
+            LedgerAccount::Fee => "fee",
+            LedgerAccount::RelayerFee => "relayer_fee",
+            LedgerAccount::Refund => "refund",
+            LedgerAccount::InTransit => "in_transit",
+            LedgerAccount::Gas => "gas",
+            LedgerAccount::GasWallet => "gas_wallet",
+            LedgerAccount::GasRefund => "gas_refund",
+            LedgerAccount::Dust => "dust",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntrySide {
+    Debit,
+    Credit,
+}
+
+/// 记账分录
+///
+/// 同一笔业务（同一 `message_id` 与阶段）产生的分录借贷相等。
+/// `entry_id` 由消息 ID、阶段与序号确定，重复写入时覆盖，保证记账幂等。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    pub entry_id: String,
+    pub message_id: String,
+    pub asset: String,
+    pub source_chain: String,
+    pub target_chain: String,
+    /// 分录所在的链
+    pub chain: String,
+    pub account: LedgerAccount,
+    pub side: EntrySide,
+    pub amount: u64,
+    pub timestamp: u64,
+}
+
+/// 记账分录查询条件，未设置的字段不参与过滤
+#[derive(Debug, Clone, Default)]
+pub struct LedgerQuery {
+    pub asset: Option<String>,
+    pub source_chain: Option<String>,
+    pub target_chain: Option<String>,
+    /// 时间下界（含）
+    pub since: Option<u64>,
+    /// 时间上界（不含）
+    pub until: Option<u64>,
+}
+
+impl LedgerQuery {
+    pub fn matches(&self, entry: &LedgerEntry) -> bool {
+        self.asset.as_ref().is_none_or(|a| &entry.asset == a)
+            && self.source_chain.as_ref().is_none_or(|c| &entry.source_chain == c)
+            && self.target_chain.as_ref().is_none_or(|c| &entry.target_chain == c)
+            && self.since.is_none_or(|t| entry.timestamp >= t)
+            && self.until.is_none_or(|t| entry.timestamp < t)
+    }
+}
+
+/// 按转账方向确定资产
+///
+/// 转账负载不携带资产标识：源链合约只锁定原生资产，因此优先取源链为原生链的资产，
+/// 没有时才取返回方向（目标链为原生链）的资产；同一方向上有多个资产时无法区分，返回 None。
+pub fn transfer_asset<'a>(config: &'a Config, source_chain: &str, target_chain: &str) -> Option<&'a AssetConfig> {
+    let routed = |native_chain: &str, mapped_chain: &str| -> Vec<&'a AssetConfig> {
+        config.assets
+            .iter()
+            .filter(|asset| asset.native_chain == native_chain && asset.mappings.contains_key(mapped_chain))
+            .collect()
+    };
+    let mut candidates = routed(source_chain, target_chain);
+    if candidates.is_empty() {
+        candidates = routed(target_chain, source_chain);
+    }
+    match candidates[..] {
+        [asset] => Some(asset),
+        _ => None,
+    }
+}
+
+/// 提交到目标链的消息：转账按目标链精度换算扣除中继器手续费后的金额，其余消息原样返回
+///
+/// 验证者对该消息签名，中继器提交同一消息，签名覆盖的金额即目标链收到的金额。
+pub fn submitted_message(config: &Config, message: &CrossChainMessage) -> Result<CrossChainMessage, Error> {
+    match Transfer::parse(config, "", message) {
+        Some(transfer) => Ok(transfer.rescaled_message()?.0),
+        None => Ok(message.clone()),
+    }
+}
+
+/// 转账消息的记账上下文
+pub struct Transfer<'a> {
+    pub message_id: &'a str,
+    pub message: &'a CrossChainMessage,
+    pub asset: &'a AssetConfig,
+    pub amount: u64,
+}
+
+impl<'a> Transfer<'a> {
+    /// 从转账消息解析记账上下文，非转账消息或无法确定资产时返回 None
+    ///
+    /// 转账负载为 `接收者地址 || amount`，amount 为末尾 8 字节的 BCS u64；资产按 [`transfer_asset`] 确定。
+    pub fn parse(config: &'a Config, message_id: &'a str, message: &'a CrossChainMessage) -> Option<Self> {
+        if message.message_type != TRANSFER_MESSAGE_TYPE || message.payload.len() <= 8 {
+            return None;
+        }
+        let asset = transfer_asset(config, &message.source_chain, &message.target_chain)?;
+        let amount_bytes: [u8; 8] = message.payload[message.payload.len() - 8..].try_into().ok()?;
+        Some(Self {
+            message_id,
+            message,
+            asset,
+            amount: u64::from_le_bytes(amount_bytes),
+        })
+    }
+
+    /// 接收者地址：负载去掉末尾金额后的部分
+    pub fn recipient(&self) -> &[u8] {
+        &self.message.payload[..self.message.payload.len() - 8]
+    }
+
+    /// 资产在目标链上的类型
+    pub fn target_asset_type(&self) -> Option<&str> {
+        if self.asset.native_chain == self.message.target_chain {
+            Some(&self.asset.type_)
+        } else {
+            self.asset.mappings.get(self.message.target_chain.as_str()).map(|t| t.as_str())
+        }
+    }
+
+    /// 源链方向为原生链时锁定，否则销毁包装资产
+    fn source_account(&self) -> LedgerAccount {
+        if self.asset.native_chain == self.message.source_chain {
+            LedgerAccount::Lock
+        } else {
+            LedgerAccount::Burn
+        }
+    }
+
+    /// 目标链为原生链时解锁，否则铸造包装资产
+    fn target_account(&self) -> LedgerAccount {
+        if self.asset.native_chain == self.message.target_chain {
+            LedgerAccount::Unlock
+        } else {
+            LedgerAccount::Mint
+        }
+    }
+
+    /// 中继器手续费，未配置时为 0
+    pub fn relayer_fee(&self) -> u64 {
+        self.asset.relayer_fee.as_ref().map_or(0, |fee| fee.fee_for(self.amount))
+    }
+
+    /// 扣除中继器手续费后提交到目标链的金额（源链单位）
+    pub fn net_amount(&self) -> u64 {
+        self.amount - self.relayer_fee()
+    }
+
+    /// 手续费，费率以基点计，与合约 `control::calculate_fee` 一致
+    ///
+    /// 合约只看到扣除中继器手续费后的金额，因此按净额计算。
+    pub fn fee(&self) -> u64 {
+        self.asset.fee_for(self.net_amount())
+    }
+
+    /// 按源链与目标链的资产精度换算扣除中继器手续费后的金额
+    ///
+    /// 记账分录始终使用源链单位，换算只影响提交到目标链的负载。
+    pub fn target_amount(&self) -> Result<Rescaled, Error> {
+        rescale(
+            self.net_amount(),
+            self.asset.decimals_on(&self.message.source_chain),
+            self.asset.decimals_on(&self.message.target_chain),
+        )
+    }
+
+    /// 换算为目标链精度时舍去的尾差（源链单位），换算失败时为 0
+    pub fn dust(&self) -> u64 {
+        self.target_amount().map_or(0, |rescaled| rescaled.dust)
+    }
+
+    /// 以扣除中继器手续费并换算为目标链精度的金额替换负载末尾的金额，返回待提交的消息与尾差
+    pub fn rescaled_message(&self) -> Result<(CrossChainMessage, u64), Error> {
+        let rescaled = self.target_amount()?;
+        let mut message = self.message.clone();
+        let split = message.payload.len() - 8;
+        message.payload[split..].copy_from_slice(&rescaled.amount.to_le_bytes());
+        Ok((message, rescaled.dust))
+    }
+
+    /// 源链转出：借记锁定/销毁，贷记在途
+    pub fn initiated(&self, timestamp: u64) -> Vec<LedgerEntry> {
+        let source = self.message.source_chain.as_str();
+        vec![
+            self.entry("initiated", 0, source, self.source_account(), EntrySide::Debit, self.amount, timestamp),
+            self.entry("initiated", 1, source, LedgerAccount::InTransit, EntrySide::Credit, self.amount, timestamp),
+        ]
+    }
+
+    /// 目标链到账：借记在途，贷记铸造/解锁、手续费、中继器手续费与留在源链的尾差
+    pub fn delivered(&self, timestamp: u64) -> Vec<LedgerEntry> {
+        let target = self.message.target_chain.as_str();
+        let fee = self.fee();
+        let relayer_fee = self.relayer_fee();
+        let dust = self.dust().min(self.net_amount() - fee);
+        let mut entries = vec![
+            self.entry("delivered", 0, target, LedgerAccount::InTransit, EntrySide::Debit, self.amount, timestamp),
+            self.entry("delivered", 1, target, self.target_account(), EntrySide::Credit, self.net_amount() - fee - dust, timestamp),
+        ];
+        if fee > 0 {
+            entries.push(self.entry("delivered", 2, target, LedgerAccount::Fee, EntrySide::Credit, fee, timestamp));
+        }
+        if relayer_fee > 0 {
+            entries.push(self.entry("delivered", 3, target, LedgerAccount::RelayerFee, EntrySide::Credit, relayer_fee, timestamp));
+        }
+        if dust > 0 {
+            let source = self.message.source_chain.as_str();
+            entries.push(self.entry("delivered", 4, source, LedgerAccount::Dust, EntrySide::Credit, dust, timestamp));
+        }
+        entries
+    }
+
+    /// 中继失败退款：借记在途，贷记源链退款
+    pub fn refunded(&self, timestamp: u64) -> Vec<LedgerEntry> {
+        let source = self.message.source_chain.as_str();
+        vec![
+            self.entry("refunded", 0, source, LedgerAccount::InTransit, EntrySide::Debit, self.amount, timestamp),
+            self.entry("refunded", 1, source, LedgerAccount::Refund, EntrySide::Credit, self.amount, timestamp),
+        ]
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn entry(
+        &self,
+        stage: &str,
+        index: usize,
+        chain: &str,
+        account: LedgerAccount,
+        side: EntrySide,
+        amount: u64,
+        timestamp: u64,
+    ) -> LedgerEntry {
+        LedgerEntry {
+            entry_id: format!("{}:{}:{}", self.message_id, stage, index),
+            message_id: self.message_id.to_string(),
+            asset: self.asset.name.clone(),
+            source_chain: self.message.source_chain.to_string(),
+            target_chain: self.message.target_chain.to_string(),
+            chain: chain.to_string(),
+            account,
+            side,
+            amount,
+            timestamp,
+        }
+    }
+}
+
+/// 单个资产与路由的记账汇总
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LedgerSummary {
+    pub asset: String,
+    pub source_chain: String,
+    pub target_chain: String,
+    /// 各科目的净额（借方为正）
+    pub balances: BTreeMap<LedgerAccount, i128>,
+    pub total_debit: u128,
+    pub total_credit: u128,
+}
+
+impl LedgerSummary {
+    /// 借贷是否平衡
+    pub fn is_balanced(&self) -> bool {
+        self.total_debit == self.total_credit
+    }
+
+    /// 科目的发生额（不区分借贷方向）
+    pub fn amount(&self, account: LedgerAccount) -> u128 {
+        self.balances.get(&account).map(|b| b.unsigned_abs()).unwrap_or_default()
+    }
+}
+
+/// 按资产与路由汇总分录
+pub fn summarize(entries: &[LedgerEntry]) -> Vec<LedgerSummary> {
+    let mut summaries: BTreeMap<(String, String, String), LedgerSummary> = BTreeMap::new();
+    for entry in entries {
+        let key = (entry.asset.clone(), entry.source_chain.clone(), entry.target_chain.clone());
+        let summary = summaries.entry(key).or_insert_with(|| LedgerSummary {
+            asset: entry.asset.clone(),
+            source_chain: entry.source_chain.clone(),
+            target_chain: entry.target_chain.clone(),
+            ..Default::default()
+        });
+        let balance = summary.balances.entry(entry.account).or_default();
+        match entry.side {
+            EntrySide::Debit => {
+                *balance += entry.amount as i128;
+                summary.total_debit += entry.amount as u128;
+            }
+            EntrySide::Credit => {
+                *balance -= entry.amount as i128;
+                summary.total_credit += entry.amount as u128;
+            }
+        }
+    }
+    summaries.into_values().collect()
+}
+
+/// 以 CSV 格式导出分录
+pub fn write_csv<W: Write>(entries: &[LedgerEntry], mut writer: W) -> Result<(), Error> {
+    let io_error = |e: std::io::Error| Error::Config(format!("Failed to write CSV: {}", e));
+    writeln!(writer, "entry_id,message_id,timestamp,asset,source_chain,target_chain,chain,account,side,amount")
+        .map_err(io_error)?;
+    for entry in entries {
+        let side = match entry.side {
+            EntrySide::Debit => "debit",
+            EntrySide::Credit => "credit",
+        };
+        writeln!(
+            writer,
+            "{},{},{},{},{},{},{},{},{},{}",
+            csv_field(&entry.entry_id),
+            csv_field(&entry.message_id),
+            entry.timestamp,
+            csv_field(&entry.asset),
+            csv_field(&entry.source_chain),
+            csv_field(&entry.target_chain),
+            csv_field(&entry.chain),
+            entry.account.as_str(),
+            side,
+            entry.amount
+        )
+        .map_err(io_error)?;
+    }
+    Ok(())
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// 跨链金额精度换算
+pub mod decimals;
+// 中继器手续费收入与 gas 支出
+pub mod fees;
+// 锁定量与铸造量核对
+pub mod supply;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RelayerConfig;
+    use std::collections::HashMap;
+
+    fn config() -> Config {
+        Config {
+            chains: vec![],
+            assets: vec![AssetConfig {
+                name: "USDC".to_string(),
+                symbol: None,
+                native_chain: "sui_testnet".to_string(),
+                type_: "0x2::usdc::USDC".to_string(),
+                decimals: 6,
+                mappings: HashMap::from([("rooch_testnet".to_string(), "0x3::usdc::USDC".to_string())]),
+                fee_rate: 30,
+                mapped_decimals: HashMap::new(),
+                relayer_fee: None,
+                timelock: None,
+                paused: false,
+            }],
+            nft_collections: vec![],
+            validators: vec![],
+            relayer: RelayerConfig {
+                poll_interval: 1,
+                max_retries: 1,
+                retry_delay: 1,
+                keystore: None,
+                signer: None,
+                require_attestations: false,
+                attestation_threshold: None,
+                governance_threshold: None,
+                validator_set_epoch: 0,
+                coordination: None,
+                check_recipients: false,
+                validator_set_guard: None,
+                max_message_age: 3_600,
+                queue_capacity: 1_000,
+                confirmations: 0,
+                batch_size: 16,
+                priority: None,
+                script_timeout: 30,
+            },
+            storage: None,
+            api: None,
+            schemas: None,
+            audit: None,
+            screening: None,
+            health: None,
+            p2p: None,
+            watchtower: None,
+            slashing: None,
+            indexer: None,
+            alerts: None,
+            logging: None,
+            reconciliation: None,
+        }
+    }
+
+    #[test]
+    fn test_transfer_journals_balance() {
+        let config = config();
+        let mut payload = vec![0xaa; 32];
+        payload.extend_from_slice(&1_000_000u64.to_le_bytes());
+        let message = CrossChainMessage {
+            nonce: 1,
+            source_chain: "sui_testnet".into(),
+            target_chain: "rooch_testnet".into(),
+            message_type: "transfer".to_string(),
+            payload,
+        };
+
+        let transfer = Transfer::parse(&config, "m1", &message).unwrap();
+        assert_eq!(transfer.fee(), 3_000);
+        assert_eq!(transfer.recipient(), &[0xaa; 32]);
+        assert_eq!(transfer.target_asset_type(), Some("0x3::usdc::USDC"));
+        let mut entries = transfer.initiated(10);
+        entries.extend(transfer.delivered(20));
+
+        let summary = summarize(&entries).remove(0);
+        assert!(summary.is_balanced());
+        assert_eq!(summary.amount(LedgerAccount::Lock), 1_000_000);
+        assert_eq!(summary.amount(LedgerAccount::Mint), 997_000);
+        assert_eq!(summary.amount(LedgerAccount::Fee), 3_000);
+        assert_eq!(summary.amount(LedgerAccount::InTransit), 0);
+
+        let mut csv = Vec::new();
+        write_csv(&entries, &mut csv).unwrap();
+        assert_eq!(String::from_utf8(csv).unwrap().lines().count(), entries.len() + 1);
+    }
+
+    #[test]
+    fn test_parse_resolves_asset_by_direction() {
+        let mut config = config();
+        let mut returning = config.assets[0].clone();
+        returning.name = "RGAS".to_string();
+        returning.native_chain = "rooch_testnet".to_string();
+        returning.mappings = HashMap::from([("sui_testnet".to_string(), "0x2::rgas::RGAS".to_string())]);
+        config.assets.insert(0, returning);
+
+        let mut payload = vec![0xaa; 32];
+        payload.extend_from_slice(&1_000u64.to_le_bytes());
+        let outbound = CrossChainMessage {
+            nonce: 1,
+            source_chain: "sui_testnet".into(),
+            target_chain: "rooch_testnet".into(),
+            message_type: "transfer".to_string(),
+            payload,
+        };
+        // 源链为原生链的资产优先，不受配置顺序影响
+        assert_eq!(Transfer::parse(&config, "m1", &outbound).unwrap().asset.name, "USDC");
+        let inbound = CrossChainMessage {
+            source_chain: "rooch_testnet".into(),
+            target_chain: "sui_testnet".into(),
+            ..outbound.clone()
+        };
+        assert_eq!(Transfer::parse(&config, "m2", &inbound).unwrap().asset.name, "RGAS");
+
+        // 同一方向上有两个资产时无法确定
+        let mut duplicate = config.assets[1].clone();
+        duplicate.name = "USDT".to_string();
+        config.assets.push(duplicate);
+        assert!(Transfer::parse(&config, "m3", &outbound).is_none());
+    }
+
+    #[test]
+    fn test_dust_is_journaled_and_signed_amount_matches_submission() {
+        let mut config = config();
+        config.assets[0].decimals = 9;
+        config.assets[0].mapped_decimals = HashMap::from([("rooch_testnet".to_string(), 6)]);
+        let mut payload = vec![0xaa; 32];
+        payload.extend_from_slice(&1_000_000_123u64.to_le_bytes());
+        let message = CrossChainMessage {
+            nonce: 1,
+            source_chain: "sui_testnet".into(),
+            target_chain: "rooch_testnet".into(),
+            message_type: "transfer".to_string(),
+            payload,
+        };
+
+        // 验证者签名的消息即中继器提交的消息，金额为目标链精度
+        let submitted = submitted_message(&config, &message).unwrap();
+        assert_eq!(submitted, Transfer::parse(&config, "m1", &message).unwrap().rescaled_message().unwrap().0);
+        assert_eq!(submitted.payload[32..], 1_000_000u64.to_le_bytes());
+
+        let transfer = Transfer::parse(&config, "m1", &message).unwrap();
+        assert_eq!(transfer.dust(), 123);
+        let entries = transfer.delivered(20);
+        let dust = entries.iter().find(|e| e.account == LedgerAccount::Dust).unwrap();
+        assert_eq!((dust.chain.as_str(), dust.amount), ("sui_testnet", 123));
+
+        let mut entries = transfer.initiated(10);
+        entries.extend(transfer.delivered(20));
+        let summary = summarize(&entries).remove(0);
+        assert!(summary.is_balanced());
+        assert_eq!(summary.amount(LedgerAccount::Mint), 1_000_000_123 - transfer.fee() as u128 - 123);
+
+        // 非转账消息原样提交
+        let call = CrossChainMessage { message_type: "call".to_string(), ..message };
+        assert_eq!(submitted_message(&config, &call).unwrap(), call);
+    }
+
+    #[test]
+    fn test_relayer_fee_accounting() {
+        let mut config = config();
+        config.assets[0].relayer_fee = Some(crate::config::RelayerFeeConfig { flat: 500, bps: 10 });
+        let mut payload = vec![0xaa; 32];
+        payload.extend_from_slice(&1_000_000u64.to_le_bytes());
+        let message = CrossChainMessage {
+            nonce: 1,
+            source_chain: "sui_testnet".into(),
+            target_chain: "rooch_testnet".into(),
+            message_type: "transfer".to_string(),
+            payload,
+        };
+
+        let transfer = Transfer::parse(&config, "m1", &message).unwrap();
+        assert_eq!(transfer.relayer_fee(), 1_500);
+        assert_eq!(transfer.fee(), 2_995);
+        let (submitted, _) = transfer.rescaled_message().unwrap();
+        assert_eq!(submitted.payload[32..], 998_500u64.to_le_bytes());
+
+        let mut entries = transfer.initiated(10);
+        entries.extend(transfer.delivered(20));
+        entries.extend(fees::gas_spent("m1", &message, 300, 20));
+        entries.extend(fees::refund_claimed("rooch_testnet", "0xclaim", 120, 30));
+        let summaries = summarize(&entries);
+        assert!(summaries.iter().all(LedgerSummary::is_balanced));
+        let usdc = summaries.iter().find(|s| s.asset == "USDC").unwrap();
+        assert_eq!(usdc.amount(LedgerAccount::Mint), 995_505);
+        assert_eq!(usdc.amount(LedgerAccount::RelayerFee), 1_500);
+
+        let report = fees::fee_report(&entries);
+        assert_eq!(report.len(), 1);
+        assert_eq!(report[0].chain, "rooch_testnet");
+        assert_eq!(report[0].submitted, 1);
+        assert_eq!(report[0].gas_spent, 300);
+        assert_eq!(report[0].net_gas(), 180);
+        assert_eq!(report[0].fees_earned["USDC"], 1_500);
+        assert_eq!(fees::split_gas(10, 3), [4, 3, 3]);
+    }
+}
@@ -0,0 +1,137 @@
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use tracing::warn;
+use crate::{
+    chain_adapter::ChainAdapter,
+    config::AssetConfig,
+    types::ChainId,
+    Error,
+};
+
+/// 一个资产的锁定量与铸造量核对结果，金额均换算为原生链精度
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SupplyReport {
+    pub asset: String,
+    pub native_chain: String,
+    /// 原生链桥合约锁定的数量
+    pub locked: u128,
+    /// 各映射链上包装资产的流通量
+    pub minted: BTreeMap<String, u128>,
+    /// 无法查询流通量的映射链，不计入铸造量
+    pub unavailable: Vec<String>,
+}
+
+impl SupplyReport {
+    pub fn total_minted(&self) -> u128 {
+        self.minted.values().fold(0u128, |total, amount| total.saturating_add(*amount))
+    }
+
+    /// 锁定量不小于铸造量
+    pub fn holds(&self) -> bool {
+        self.locked >= self.total_minted()
+    }
+
+    /// 铸造量超出锁定量的部分
+    pub fn shortfall(&self) -> u128 {
+        self.total_minted().saturating_sub(self.locked)
+    }
+}
+
+/// 将金额从 `from` 位小数换算为 `to` 位小数，降低精度时舍去尾差，溢出时取最大值
+fn to_decimals(amount: u128, from: u8, to: u8) -> u128 {
+    let factor = 10u128.saturating_pow(from.abs_diff(to) as u32);
+    if to >= from {
+        amount.saturating_mul(factor)
+    } else {
+        amount / factor
+    }
+}
+
+/// 读取资产在原生链的锁定量与各映射链的包装资产流通量
+///
+/// 原生链无法提供锁定量时返回 None；映射链查询失败或无法提供时记入 `unavailable`，
+/// 其余链的流通量仍参与核对，已查到的铸造量超过锁定量同样说明不变量被破坏。
+pub async fn check(
+    asset: &AssetConfig,
+    adapters: &HashMap<ChainId, Box<dyn ChainAdapter>>,
+) -> Result<Option<SupplyReport>, Error> {
+    let native = adapters
+        .get(asset.native_chain.as_str())
+        .ok_or_else(|| Error::Chain(format!("Native chain adapter not found: {}", asset.native_chain)))?;
+    let Some(locked) = native.locked_balance(&asset.type_).await? else {
+        return Ok(None);
+    };
+
+    let mut minted = BTreeMap::new();
+    let mut unavailable = Vec::new();
+    for (chain_id, asset_type) in &asset.mappings {
+        let supply = match adapters.get(chain_id.as_str()) {
+            Some(adapter) => adapter.wrapped_supply(asset_type).await,
+            None => Err(Error::Chain(format!("Chain adapter not found: {}", chain_id))),
+        };
+        match supply {
+            Ok(Some(supply)) => {
+                minted.insert(chain_id.clone(), to_decimals(supply, asset.decimals_on(chain_id), asset.decimals));
+            }
+            Ok(None) => unavailable.push(chain_id.clone()),
+            Err(e) => {
+                warn!("Failed to query supply of {} on {}: {}", asset.name, chain_id, e);
+                unavailable.push(chain_id.clone());
+            }
+        }
+    }
+    unavailable.sort();
+
+    Ok(Some(SupplyReport {
+        asset: asset.name.clone(),
+        native_chain: asset.native_chain.clone(),
+        locked,
+        minted,
+        unavailable,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ReconciliationConfig;
+    use crate::testing::{transfer_payload, two_chain_config, TwoChainHarness, TARGET_CHAIN, TEST_ASSET};
+
+    #[test]
+    fn test_minted_supply_is_rescaled_to_native_decimals() {
+        let report = SupplyReport {
+            asset: TEST_ASSET.to_string(),
+            native_chain: "sui".to_string(),
+            locked: 1_000,
+            minted: BTreeMap::from([("rooch".to_string(), to_decimals(600_000, 9, 6)), ("solana".to_string(), 400)]),
+            unavailable: vec![],
+        };
+        assert_eq!(report.total_minted(), 1_000);
+        assert!(report.holds());
+        assert_eq!(to_decimals(5, 6, 8), 500);
+        assert_eq!(to_decimals(1_999, 9, 6), 1);
+    }
+
+    #[tokio::test]
+    async fn test_supply_mismatch_pauses_asset() {
+        let mut config = two_chain_config();
+        config.reconciliation = Some(ReconciliationConfig { interval: 3_600, assets: vec![], auto_pause: true });
+        let mut harness = TwoChainHarness::with_config(config).await.unwrap();
+        harness.source.set_locked_balance("0x2::usdc::USDC", Some(1_000));
+        harness.target.set_wrapped_supply("0x3::usdc::USDC", Some(1_500));
+        harness.tick().await;
+        assert!(harness.relayer.asset_pauses().is_paused(TEST_ASSET));
+
+        // 暂停的资产保留在待处理队列中，恢复后继续中继
+        harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([1; 32], 100));
+        harness.run(2).await;
+        assert!(harness.target.submitted().is_empty());
+        assert_eq!(harness.backlog(), 1);
+
+        assert!(harness.relayer.asset_pauses().resume(TEST_ASSET).unwrap());
+        harness.tick().await;
+        assert_eq!(harness.target.submitted().len(), 1);
+        let metrics = harness.relayer.metrics().export().unwrap();
+        assert!(metrics.contains("bridge_supply_shortfall{asset=\"USDC\"} 500"));
+    }
+}
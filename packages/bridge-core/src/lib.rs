@@ -1,10 +1,38 @@
 pub mod config;
 pub mod types;
 pub mod chain_adapter;
+pub mod codegen;
 pub mod relayer;
+pub mod keystore;
+pub mod signer;
+pub mod metrics;
+pub mod storage;
+pub mod attestation;
+pub mod api;
+pub mod info;
+pub mod bootstrap;
+pub mod ledger;
+pub mod trace;
+pub mod replay;
+pub mod watchtower;
+pub mod indexer;
+pub mod alert;
+pub mod logging;
+pub mod confidential;
+pub mod health;
+pub mod schema;
+pub mod screening;
+pub mod audit;
+pub mod verify;
+pub mod proof;
+pub mod vectors;
+#[cfg(feature = "p2p")]
+pub mod p2p;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 
 pub use config::Config;
-pub use types::{CrossChainMessage, SignedMessage, MessageStatus};
+pub use types::{ChainId, CrossChainMessage, SignedMessage, MessageStatus};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -19,4 +47,131 @@ pub enum Error {
     
     #[error("Serialization error: {0}")]
     Serialization(String),
+
+    #[error("Key error: {0}")]
+    Key(String),
+
+    #[error("Incompatible node for chain {chain}: detected version {detected}, required {required}")]
+    IncompatibleNode {
+        chain: String,
+        detected: String,
+        required: String,
+    },
+
+    #[error("Move abort in {location} with code {code}")]
+    MoveAbort {
+        location: String,
+        code: u64,
+    },
+
+    #[error("Message already processed on target chain: {0}")]
+    AlreadyProcessed(String),
+
+    #[error("Signature rejected by target contract: {0}")]
+    InvalidSignature(String),
+
+    #[error("Bridge paused on target chain: {0}")]
+    Paused(String),
+
+    #[error("Rate limited by node: {0}")]
+    RateLimited(String),
+
+    #[error("Insufficient gas: {0}")]
+    InsufficientGas(String),
+
+    #[error("Invalid message: {0}")]
+    InvalidMessage(String),
+
+    #[error("RPC error {code} in {method}: {message}")]
+    Rpc {
+        method: String,
+        code: i64,
+        message: String,
+    },
+
+    #[error("Rejected by pre-relay check: {0}")]
+    Rejected(String),
+
+    #[error("Payload schema violation: {0}")]
+    Schema(String),
+
+    #[error("Held for manual approval: {0}")]
+    ApprovalRequired(String),
+
+    #[error("Held by timelock until {release_at}")]
+    Timelocked {
+        release_at: u64,
+    },
+
+    #[error("Cancelled by operator: {0}")]
+    Cancelled(String),
+
+    #[error("Blocked by address screening: {0}")]
+    Screened(String),
+
+    #[error("Relaying of {asset} paused: {reason}")]
+    AssetPaused {
+        asset: String,
+        reason: String,
+    },
+
+    #[error("Invalid inclusion proof: {0}")]
+    InvalidProof(String),
+
+    #[error("Transaction {tx_hash} not confirmed within {timeout} seconds")]
+    Stuck {
+        tx_hash: String,
+        timeout: u64,
+    },
+
+    #[error("Gave up after {attempts} attempts: {last}")]
+    RetriesExhausted {
+        attempts: u32,
+        last: String,
+    },
+
+    #[error("Signer {backend} did not respond within {timeout_ms} ms")]
+    SignerTimeout {
+        backend: String,
+        timeout_ms: u64,
+    },
+
+    #[error("Signer {backend} failed: {message}")]
+    SignerFailure {
+        backend: String,
+        message: String,
+    },
+}
+
+impl Error {
+    /// 稍后重试可能成功的错误：连接失败、被限速、gas 不足、桥暂停、交易未确认，以及未分类的链错误
+    ///
+    /// 合约拒绝（中止、签名无效、消息已执行或不合法）、配置与编码错误重试无意义；
+    /// 已用完重试次数的消息也不再重试。
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Network(_)
+            | Error::RateLimited(_)
+            | Error::InsufficientGas(_)
+            | Error::Paused(_)
+            | Error::Stuck { .. }
+            | Error::SignerTimeout { .. }
+            | Error::Chain(_) => true,
+            Error::Rpc { code, .. } => !matches!(
+                types::RpcErrorClass::from_code(*code),
+                types::RpcErrorClass::InvalidRequest | types::RpcErrorClass::MethodNotFound
+            ),
+            _ => false,
+        }
+    }
+
+    /// 重试无法成功、应直接记为失败的错误
+    pub fn is_terminal(&self) -> bool {
+        !self.is_retryable()
+    }
+
+    /// 面向接入方的失败原因，附带处理建议
+    pub fn cause(&self) -> types::FailureCause {
+        types::FailureCause::from_error(self)
+    }
 }
\ No newline at end of file
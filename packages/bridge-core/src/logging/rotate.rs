@@ -0,0 +1,160 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing_subscriber::fmt::MakeWriter;
+use crate::{
+    config::{LogFileConfig, LogRotation},
+    Error,
+};
+
+struct FileState {
+    file: File,
+    /// 当前文件已写入的字节数
+    size: u64,
+    /// 当前文件所属的时间周期
+    period: u64,
+}
+
+/// 按大小或时间轮转的日志文件
+///
+/// 轮转时当前文件依次重命名为 `<path>.1`、`<path>.2` ...，超出保留数量的最旧文件被删除。
+#[derive(Clone)]
+pub struct RotatingFile {
+    path: PathBuf,
+    max_size: Option<u64>,
+    rotation: LogRotation,
+    max_files: usize,
+    state: Arc<Mutex<FileState>>,
+}
+
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+fn period(rotation: LogRotation, at: u64) -> u64 {
+    match rotation {
+        LogRotation::Never => 0,
+        LogRotation::Hourly => at / 3600,
+        LogRotation::Daily => at / 86400,
+    }
+}
+
+fn open_append(path: &PathBuf) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+impl RotatingFile {
+    pub fn open(config: &LogFileConfig) -> Result<Self, Error> {
+        let path = PathBuf::from(&config.path);
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)
+                .map_err(|e| Error::Config(format!("Failed to create log directory {}: {}", parent.display(), e)))?;
+        }
+        let file = open_append(&path).map_err(|e| Error::Config(format!("Failed to open log file {}: {}", config.path, e)))?;
+        let metadata = file.metadata().map_err(|e| Error::Config(format!("Failed to open log file {}: {}", config.path, e)))?;
+        // 已有文件按其修改时间归入周期，重启后跨周期时先轮转
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_secs())
+            .unwrap_or_else(now);
+        Ok(Self {
+            path,
+            max_size: config.max_size,
+            rotation: config.rotation,
+            max_files: config.max_files,
+            state: Arc::new(Mutex::new(FileState { file, size: metadata.len(), period: period(config.rotation, modified) })),
+        })
+    }
+
+    fn rotated(&self, index: usize) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(format!(".{}", index));
+        PathBuf::from(path)
+    }
+
+    fn rotate(&self, state: &mut FileState) -> io::Result<()> {
+        state.file.flush()?;
+        if self.max_files == 0 {
+            fs::remove_file(&self.path)?;
+        } else {
+            let _ = fs::remove_file(self.rotated(self.max_files));
+            for index in (1..self.max_files).rev() {
+                let from = self.rotated(index);
+                if from.exists() {
+                    fs::rename(&from, self.rotated(index + 1))?;
+                }
+            }
+            fs::rename(&self.path, self.rotated(1))?;
+        }
+        state.file = open_append(&self.path)?;
+        state.size = 0;
+        Ok(())
+    }
+
+    fn write_record(&self, buf: &[u8]) -> io::Result<usize> {
+        let mut state = self.state.lock().unwrap();
+        let current = period(self.rotation, now());
+        let full = self.max_size.is_some_and(|max| state.size > 0 && state.size + buf.len() as u64 > max);
+        if full || current != state.period {
+            self.rotate(&mut state)?;
+            state.period = current;
+        }
+        state.file.write_all(buf)?;
+        state.size += buf.len() as u64;
+        Ok(buf.len())
+    }
+}
+
+/// 单条日志的写入句柄
+pub struct RotatingWriter<'a> {
+    file: &'a RotatingFile,
+}
+
+impl Write for RotatingWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.file.write_record(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.state.lock().unwrap().file.flush()
+    }
+}
+
+impl<'a> MakeWriter<'a> for RotatingFile {
+    type Writer = RotatingWriter<'a>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        RotatingWriter { file: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotate_by_size() {
+        let dir = std::env::temp_dir().join(format!("bridge-log-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let config = LogFileConfig {
+            path: dir.join("relayer.log").to_string_lossy().into_owned(),
+            max_size: Some(16),
+            rotation: LogRotation::Never,
+            max_files: 2,
+        };
+        let file = RotatingFile::open(&config).unwrap();
+        for line in ["first line 01\n", "second line 2\n", "third line 03\n", "fourth line 4\n"] {
+            file.make_writer().write_all(line.as_bytes()).unwrap();
+        }
+
+        assert_eq!(fs::read_to_string(&config.path).unwrap(), "fourth line 4\n");
+        assert_eq!(fs::read_to_string(format!("{}.1", config.path)).unwrap(), "third line 03\n");
+        assert_eq!(fs::read_to_string(format!("{}.2", config.path)).unwrap(), "second line 2\n");
+        assert!(!dir.join("relayer.log.3").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,212 @@
+use prometheus::{Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec, Opts, Registry, TextEncoder};
+use std::collections::BTreeMap;
+use crate::{
+    config::Config,
+    types::CrossChainMessage,
+    Error,
+};
+
+/// 所有路由维度指标使用的标签
+pub const ROUTE_LABELS: [&str; 3] = ["source_chain", "target_chain", "asset"];
+
+/// 消息路由标签
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RouteLabels {
+    pub source_chain: String,
+    pub target_chain: String,
+    pub asset: String,
+}
+
+impl RouteLabels {
+    /// 根据消息与资产配置计算路由标签，无法确定资产时使用 `unknown`
+    pub fn for_message(config: &Config, message: &CrossChainMessage) -> Self {
+        Self {
+            source_chain: message.source_chain.to_string(),
+            target_chain: message.target_chain.to_string(),
+            asset: route_asset(config, &message.source_chain, &message.target_chain)
+                .unwrap_or_else(|| "unknown".to_string()),
+        }
+    }
+
+    fn values(&self) -> [&str; 3] {
+        [&self.source_chain, &self.target_chain, &self.asset]
+    }
+}
+
+/// 查找路由对应的资产名称，与记账一致按 [`crate::ledger::transfer_asset`] 确定
+pub fn route_asset(config: &Config, source_chain: &str, target_chain: &str) -> Option<String> {
+    crate::ledger::transfer_asset(config, source_chain, target_chain).map(|asset| asset.name.clone())
+}
+
+/// 中继器指标
+pub struct Metrics {
+    registry: Registry,
+    messages_observed: IntCounterVec,
+    messages_relayed: IntCounterVec,
+    messages_failed: IntCounterVec,
+    relay_latency: HistogramVec,
+    queue_depth: IntGauge,
+    pending_by_band: IntGaugeVec,
+    ingestion_paused: IntCounterVec,
+    submissions_skipped: IntCounterVec,
+    rpc_throttled: IntCounterVec,
+    supply_shortfall: IntGaugeVec,
+    refunds_claimed: IntCounterVec,
+}
+
+impl Metrics {
+    pub fn new() -> Result<Self, Error> {
+        let registry = Registry::new();
+
+        let messages_observed = IntCounterVec::new(
+            Opts::new("bridge_messages_observed_total", "Messages observed on source chains"),
+            &ROUTE_LABELS,
+        )
+        .map_err(metrics_error)?;
+        let messages_relayed = IntCounterVec::new(
+            Opts::new("bridge_messages_relayed_total", "Messages successfully relayed"),
+            &ROUTE_LABELS,
+        )
+        .map_err(metrics_error)?;
+        let messages_failed = IntCounterVec::new(
+            Opts::new("bridge_messages_failed_total", "Messages that failed to relay"),
+            &ROUTE_LABELS,
+        )
+        .map_err(metrics_error)?;
+        let relay_latency = HistogramVec::new(
+            HistogramOpts::new("bridge_relay_latency_seconds", "Latency from source event to target submission")
+                .buckets(vec![1.0, 5.0, 15.0, 30.0, 60.0, 120.0, 300.0, 600.0, 1800.0, 3600.0]),
+            &ROUTE_LABELS,
+        )
+        .map_err(metrics_error)?;
+        let queue_depth = IntGauge::new("bridge_relay_queue_depth", "Messages waiting between event ingestion and relay")
+            .map_err(metrics_error)?;
+        let pending_by_band = IntGaugeVec::new(
+            Opts::new("bridge_relay_pending_messages", "Pending messages by priority value band"),
+            &["band"],
+        )
+        .map_err(metrics_error)?;
+        let ingestion_paused = IntCounterVec::new(
+            Opts::new("bridge_ingestion_paused_total", "Polls skipped because the work queue was full"),
+            &["chain"],
+        )
+        .map_err(metrics_error)?;
+        let submissions_skipped = IntCounterVec::new(
+            Opts::new("bridge_submissions_skipped_total", "Submissions skipped because the target contract already processed the message"),
+            &["chain"],
+        )
+        .map_err(metrics_error)?;
+        let rpc_throttled = IntCounterVec::new(
+            Opts::new("bridge_rpc_throttled_total", "RPC calls delayed by the per-chain rate limiter"),
+            &["chain"],
+        )
+        .map_err(metrics_error)?;
+        let refunds_claimed = IntCounterVec::new(
+            Opts::new("bridge_gas_refunds_claimed_total", "Gas refunds claimed from the bridge contract"),
+            &["chain"],
+        )
+        .map_err(metrics_error)?;
+        let supply_shortfall = IntGaugeVec::new(
+            Opts::new("bridge_supply_shortfall", "Wrapped supply in excess of the amount locked on the native chain"),
+            &["asset"],
+        )
+        .map_err(metrics_error)?;
+
+        registry.register(Box::new(messages_observed.clone())).map_err(metrics_error)?;
+        registry.register(Box::new(messages_relayed.clone())).map_err(metrics_error)?;
+        registry.register(Box::new(messages_failed.clone())).map_err(metrics_error)?;
+        registry.register(Box::new(relay_latency.clone())).map_err(metrics_error)?;
+        registry.register(Box::new(queue_depth.clone())).map_err(metrics_error)?;
+        registry.register(Box::new(pending_by_band.clone())).map_err(metrics_error)?;
+        registry.register(Box::new(ingestion_paused.clone())).map_err(metrics_error)?;
+        registry.register(Box::new(submissions_skipped.clone())).map_err(metrics_error)?;
+        registry.register(Box::new(rpc_throttled.clone())).map_err(metrics_error)?;
+        registry.register(Box::new(supply_shortfall.clone())).map_err(metrics_error)?;
+        registry.register(Box::new(refunds_claimed.clone())).map_err(metrics_error)?;
+
+        Ok(Self {
+            registry,
+            messages_observed,
+            messages_relayed,
+            messages_failed,
+            relay_latency,
+            queue_depth,
+            pending_by_band,
+            ingestion_paused,
+            submissions_skipped,
+            rpc_throttled,
+            supply_shortfall,
+            refunds_claimed,
+        })
+    }
+
+    /// 指标注册表，供其他模块注册附加指标
+    pub fn registry(&self) -> &Registry {
+        &self.registry
+    }
+
+    pub fn record_observed(&self, route: &RouteLabels) {
+        self.messages_observed.with_label_values(&route.values()).inc();
+    }
+
+    pub fn record_relayed(&self, route: &RouteLabels, latency_secs: f64) {
+        self.messages_relayed.with_label_values(&route.values()).inc();
+        self.relay_latency.with_label_values(&route.values()).observe(latency_secs);
+    }
+
+    pub fn record_failed(&self, route: &RouteLabels) {
+        self.messages_failed.with_label_values(&route.values()).inc();
+    }
+
+    pub fn set_queue_depth(&self, depth: usize) {
+        self.queue_depth.set(depth as i64);
+    }
+
+    /// 按优先级档位设置待处理消息数
+    pub fn set_pending_composition(&self, composition: &BTreeMap<String, usize>) {
+        for (band, count) in composition {
+            self.pending_by_band.with_label_values(&[band]).set(*count as i64);
+        }
+    }
+
+    /// 记录一次因工作队列已满而跳过的源链轮询
+    pub fn record_ingestion_paused(&self, chain: &str) {
+        self.ingestion_paused.with_label_values(&[chain]).inc();
+    }
+
+    /// 记录一次因目标链合约已处理消息而跳过的提交
+    pub fn record_submission_skipped(&self, chain: &str) {
+        self.submissions_skipped.with_label_values(&[chain]).inc();
+    }
+
+    /// 记录被限速器延迟的 RPC 调用数
+    pub fn record_rpc_throttled(&self, chain: &str, count: u64) {
+        self.rpc_throttled.with_label_values(&[chain]).inc_by(count);
+    }
+
+    /// 记录资产对账结果，不变量成立时为 0
+    pub fn set_supply_shortfall(&self, asset: &str, shortfall: u128) {
+        self.supply_shortfall.with_label_values(&[asset]).set(i64::try_from(shortfall).unwrap_or(i64::MAX));
+    }
+
+    /// 记录从桥合约认领的 gas 报销（gas 代币最小单位）
+    pub fn record_refund_claimed(&self, chain: &str, amount: u64) {
+        self.refunds_claimed.with_label_values(&[chain]).inc_by(amount);
+    }
+
+    /// 以 Prometheus 文本格式导出全部指标
+    pub fn export(&self) -> Result<String, Error> {
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .map_err(metrics_error)?;
+        String::from_utf8(buffer).map_err(|e| Error::Serialization(e.to_string()))
+    }
+}
+
+fn metrics_error(e: prometheus::Error) -> Error {
+    Error::Config(format!("Metrics error: {}", e))
+}
+
+// SLA 报表
+pub mod sla;
@@ -0,0 +1,154 @@
+use serde::{Deserialize, Serialize};
+use crate::{
+    storage::MessageRecord,
+    types::MessageStatus,
+};
+
+/// 交付中断窗口
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DowntimeWindow {
+    pub start: u64,
+    /// 窗口结束时间，仍未恢复时为 None
+    pub end: Option<u64>,
+}
+
+/// 单条路由在统计窗口内的 SLA 报表
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlaReport {
+    pub source_chain: String,
+    pub target_chain: String,
+    pub window_start: u64,
+    pub window_end: u64,
+    pub total: usize,
+    pub delivered: usize,
+    pub failed: usize,
+    pub pending: usize,
+    /// 交付成功率（已进入终态的消息中成功的比例）
+    pub success_rate: f64,
+    pub latency_p50: Option<u64>,
+    pub latency_p95: Option<u64>,
+    pub downtime: Vec<DowntimeWindow>,
+}
+
+impl SlaReport {
+    /// 根据存储中的消息记录计算报表，记录需已按路由与时间窗口过滤
+    pub fn compute(
+        source_chain: &str,
+        target_chain: &str,
+        window_start: u64,
+        window_end: u64,
+        records: &[MessageRecord],
+    ) -> Self {
+        let mut records: Vec<_> = records.iter().collect();
+        records.sort_by_key(|r| r.observed_at);
+
+        let delivered = records.iter().filter(|r| r.status == MessageStatus::Processed).count();
+        let failed = records.iter().filter(|r| r.status == MessageStatus::Failed).count();
+        let pending = records.len() - delivered - failed;
+        let success_rate = if delivered + failed == 0 {
+            1.0
+        } else {
+            delivered as f64 / (delivered + failed) as f64
+        };
+
+        let mut latencies: Vec<u64> = records
+            .iter()
+            .filter(|r| r.status == MessageStatus::Processed)
+            .filter_map(|r| r.completed_at.map(|t| t.saturating_sub(r.observed_at)))
+            .collect();
+        latencies.sort_unstable();
+
+        Self {
+            source_chain: source_chain.to_string(),
+            target_chain: target_chain.to_string(),
+            window_start,
+            window_end,
+            total: records.len(),
+            delivered,
+            failed,
+            pending,
+            success_rate,
+            latency_p50: percentile(&latencies, 50),
+            latency_p95: percentile(&latencies, 95),
+            downtime: downtime_windows(&records),
+        }
+    }
+}
+
+/// 计算有序样本的百分位数（最近秩法）
+fn percentile(sorted: &[u64], p: usize) -> Option<u64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = (p * sorted.len()).div_ceil(100).max(1);
+    sorted.get(rank - 1).copied()
+}
+
+/// 计算中断窗口：从一次失败开始，到下一次成功交付结束
+fn downtime_windows(records: &[&MessageRecord]) -> Vec<DowntimeWindow> {
+    let mut windows = Vec::new();
+    let mut current: Option<u64> = None;
+
+    for record in records {
+        match record.status {
+            MessageStatus::Failed if current.is_none() => current = Some(record.observed_at),
+            MessageStatus::Processed => {
+                if let Some(start) = current.take() {
+                    windows.push(DowntimeWindow {
+                        start,
+                        end: Some(record.completed_at.unwrap_or(record.observed_at)),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(start) = current {
+        windows.push(DowntimeWindow { start, end: None });
+    }
+    windows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(observed_at: u64, status: MessageStatus, latency: u64) -> MessageRecord {
+        MessageRecord {
+            message_id: format!("{}", observed_at),
+            source_chain: "sui".to_string(),
+            target_chain: "rooch".to_string(),
+            asset: None,
+            sender: None,
+            recipient: None,
+            nonce: observed_at,
+            completed_at: (status != MessageStatus::Pending).then_some(observed_at + latency),
+            status,
+            observed_at,
+            error: None,
+            cause: None,
+        }
+    }
+
+    #[test]
+    fn test_sla_report() {
+        let records = vec![
+            record(10, MessageStatus::Processed, 5),
+            record(20, MessageStatus::Failed, 1),
+            record(30, MessageStatus::Failed, 1),
+            record(40, MessageStatus::Processed, 10),
+            record(50, MessageStatus::Pending, 0),
+        ];
+        let report = SlaReport::compute("sui", "rooch", 0, 100, &records);
+
+        assert_eq!(report.total, 5);
+        assert_eq!(report.delivered, 2);
+        assert_eq!(report.failed, 2);
+        assert_eq!(report.pending, 1);
+        assert_eq!(report.success_rate, 0.5);
+        assert_eq!(report.latency_p50, Some(5));
+        assert_eq!(report.latency_p95, Some(10));
+        assert_eq!(report.downtime, vec![DowntimeWindow { start: 20, end: Some(50) }]);
+    }
+}
@@ -0,0 +1,346 @@
+use libp2p::{
+    futures::StreamExt,
+    gossipsub::{self, IdentTopic, MessageAuthenticity, MessageId, ValidationMode},
+    identity, noise,
+    swarm::SwarmEvent,
+    tcp, yamux, Multiaddr, PeerId, Swarm, SwarmBuilder,
+};
+use blake2::{Blake2b, Digest, digest::consts::U32};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashSet, VecDeque};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::{debug, info, error, warn};
+use crate::{
+    config::{Config, P2pConfig},
+    keystore::Keystore,
+    relayer::aggregator::AttestationAggregator,
+    types::Attestation,
+    Error,
+};
+
+/// 节点公告主题，所有节点都订阅，用于互相发现
+const PEERS_TOPIC: &str = "move-bridge/peers";
+/// 节点公告间隔（秒）
+const ANNOUNCE_INTERVAL: u64 = 30;
+/// 去重缓存容量
+const SEEN_CAPACITY: usize = 10_000;
+
+/// 链对的证明主题
+pub fn attestation_topic(source_chain: &str, target_chain: &str) -> IdentTopic {
+    IdentTopic::new(format!("move-bridge/attestations/{}/{}", source_chain, target_chain))
+}
+
+/// gossip 网络上传播的消息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum GossipMessage {
+    Attestation(Attestation),
+    /// 节点公告自身的 PeerId 和可连接地址
+    Announce {
+        peer_id: String,
+        addresses: Vec<String>,
+    },
+}
+
+impl GossipMessage {
+    /// 去重键：同一验证者对同一消息的证明只传播一次，与签名时间无关
+    pub fn dedup_key(&self) -> String {
+        match self {
+            GossipMessage::Attestation(attestation) => {
+                format!("attestation:{}:{}", attestation.message_id, attestation.validator)
+            }
+            GossipMessage::Announce { peer_id, addresses } => {
+                format!("announce:{}:{}", peer_id, addresses.join(","))
+            }
+        }
+    }
+}
+
+/// 派生节点身份：优先使用已核对且可导出的验证者密钥，没有时生成临时身份
+pub fn node_identity(keystore: Option<&Keystore>) -> Result<identity::Keypair, Error> {
+    let exportable = keystore.and_then(|k| k.verified_keys().into_iter().find(|key| k.is_exportable(key)));
+    let Some(public_key) = exportable else {
+        return Ok(identity::Keypair::generate_ed25519());
+    };
+    let secret = keystore
+        .map(|k| k.secret_key_bytes(&public_key))
+        .transpose()?
+        .ok_or_else(|| Error::Key("Keystore not available".to_string()))?;
+    identity::Keypair::ed25519_from_bytes(secret)
+        .map_err(|e| Error::Key(format!("Invalid p2p identity key: {}", e)))
+}
+
+/// p2p 节点句柄，用于向网络发布证明
+#[derive(Clone)]
+pub struct P2pHandle {
+    peer_id: PeerId,
+    commands: mpsc::UnboundedSender<Attestation>,
+}
+
+impl P2pHandle {
+    pub fn peer_id(&self) -> PeerId {
+        self.peer_id
+    }
+
+    /// 发布一条证明到对应链对的主题
+    pub fn publish(&self, attestation: Attestation) -> Result<(), Error> {
+        self.commands
+            .send(attestation)
+            .map_err(|_| Error::Network("p2p node has stopped".to_string()))
+    }
+}
+
+/// 启动 p2p 节点
+///
+/// 节点订阅配置中所有链对的证明主题；传入聚合器时，收到的证明会提交给聚合器。
+pub fn spawn(
+    config: &Config,
+    keypair: identity::Keypair,
+    aggregator: Option<Arc<AttestationAggregator>>,
+) -> Result<P2pHandle, Error> {
+    let p2p_config = config.p2p.as_ref()
+        .ok_or_else(|| Error::Config("p2p is not configured".to_string()))?;
+    let peer_id = keypair.public().to_peer_id();
+    let mut swarm = build_swarm(keypair)?;
+
+    let mut topics = vec![IdentTopic::new(PEERS_TOPIC)];
+    for source in &config.chains {
+        for target in config.chains.iter().filter(|c| c.id != source.id) {
+            topics.push(attestation_topic(&source.id, &target.id));
+        }
+    }
+    for topic in &topics {
+        swarm
+            .behaviour_mut()
+            .subscribe(topic)
+            .map_err(|e| Error::Network(format!("Failed to subscribe {}: {}", topic, e)))?;
+    }
+
+    let listen = parse_multiaddr(&p2p_config.listen)?;
+    swarm
+        .listen_on(listen)
+        .map_err(|e| Error::Network(format!("Failed to listen on {}: {}", p2p_config.listen, e)))?;
+    for peer in &p2p_config.bootstrap_peers {
+        if let Err(e) = swarm.dial(parse_multiaddr(peer)?) {
+            warn!("Failed to dial bootstrap peer {}: {}", peer, e);
+        }
+    }
+
+    let (commands, receiver) = mpsc::unbounded_channel();
+    let node = P2pNode {
+        swarm,
+        config: p2p_config.clone(),
+        commands: receiver,
+        aggregator,
+        seen: SeenCache::default(),
+    };
+    info!("Starting p2p node {}", peer_id);
+    tokio::spawn(node.run());
+
+    Ok(P2pHandle { peer_id, commands })
+}
+
+fn build_swarm(keypair: identity::Keypair) -> Result<Swarm<gossipsub::Behaviour>, Error> {
+    let swarm = SwarmBuilder::with_existing_identity(keypair)
+        .with_tokio()
+        .with_tcp(tcp::Config::default(), noise::Config::new, yamux::Config::default)
+        .map_err(|e| Error::Network(e.to_string()))?
+        .with_behaviour(|key| {
+            // 按去重键计算消息 ID，同一证明被重复发布时网络只传播一次
+            let config = gossipsub::ConfigBuilder::default()
+                .validation_mode(ValidationMode::Strict)
+                .message_id_fn(|message: &gossipsub::Message| {
+                    let key = serde_json::from_slice::<GossipMessage>(&message.data)
+                        .map(|m| m.dedup_key().into_bytes())
+                        .unwrap_or_else(|_| message.data.clone());
+                    MessageId::from(hex::encode(Blake2b::<U32>::digest(key)))
+                })
+                .build()?;
+            Ok(gossipsub::Behaviour::new(MessageAuthenticity::Signed(key.clone()), config)?)
+        })
+        .map_err(|e| Error::Network(e.to_string()))?
+        .with_swarm_config(|c| c.with_idle_connection_timeout(Duration::from_secs(60)))
+        .build();
+    Ok(swarm)
+}
+
+fn parse_multiaddr(address: &str) -> Result<Multiaddr, Error> {
+    address
+        .parse()
+        .map_err(|e| Error::Config(format!("Invalid multiaddr {}: {}", address, e)))
+}
+
+/// 有界的已处理消息集合，超出容量时淘汰最早的记录
+#[derive(Default)]
+struct SeenCache {
+    keys: HashSet<String>,
+    order: VecDeque<String>,
+}
+
+impl SeenCache {
+    /// 记录去重键，已存在时返回 false
+    fn insert(&mut self, key: String) -> bool {
+        if !self.keys.insert(key.clone()) {
+            return false;
+        }
+        self.order.push_back(key);
+        if self.order.len() > SEEN_CAPACITY {
+            if let Some(oldest) = self.order.pop_front() {
+                self.keys.remove(&oldest);
+            }
+        }
+        true
+    }
+}
+
+struct P2pNode {
+    swarm: Swarm<gossipsub::Behaviour>,
+    config: P2pConfig,
+    commands: mpsc::UnboundedReceiver<Attestation>,
+    aggregator: Option<Arc<AttestationAggregator>>,
+    seen: SeenCache,
+}
+
+impl P2pNode {
+    async fn run(mut self) {
+        let mut announce = tokio::time::interval(Duration::from_secs(ANNOUNCE_INTERVAL));
+        loop {
+            tokio::select! {
+                command = self.commands.recv() => match command {
+                    Some(attestation) => {
+                        let topic = attestation_topic(&attestation.message.source_chain, &attestation.message.target_chain);
+                        self.publish(topic, GossipMessage::Attestation(attestation));
+                    }
+                    None => {
+                        info!("p2p handle dropped, stopping node");
+                        return;
+                    }
+                },
+                _ = announce.tick() => self.announce(),
+                event = self.swarm.select_next_some() => self.handle_event(event).await,
+            }
+        }
+    }
+
+    fn publish(&mut self, topic: IdentTopic, message: GossipMessage) {
+        self.seen.insert(message.dedup_key());
+        let data = match serde_json::to_vec(&message) {
+            Ok(data) => data,
+            Err(e) => {
+                error!("Failed to encode gossip message: {}", e);
+                return;
+            }
+        };
+        match self.swarm.behaviour_mut().publish(topic.clone(), data) {
+            Ok(_) => debug!("Published gossip message on {}", topic),
+            // 网络中暂无订阅者或重复发布时不视为错误
+            Err(gossipsub::PublishError::InsufficientPeers) | Err(gossipsub::PublishError::Duplicate) => {}
+            Err(e) => warn!("Failed to publish on {}: {}", topic, e),
+        }
+    }
+
+    fn announce(&mut self) {
+        let addresses: Vec<String> = if self.config.external_addresses.is_empty() {
+            self.swarm.listeners().map(|a| a.to_string()).collect()
+        } else {
+            self.config.external_addresses.clone()
+        };
+        if addresses.is_empty() {
+            return;
+        }
+        let message = GossipMessage::Announce {
+            peer_id: self.swarm.local_peer_id().to_string(),
+            addresses,
+        };
+        // 公告需要周期性重发，不记入去重缓存
+        match serde_json::to_vec(&message) {
+            Ok(data) => {
+                if let Err(e) = self.swarm.behaviour_mut().publish(IdentTopic::new(PEERS_TOPIC), data) {
+                    debug!("Failed to announce: {}", e);
+                }
+            }
+            Err(e) => error!("Failed to encode announcement: {}", e),
+        }
+    }
+
+    async fn handle_event(&mut self, event: SwarmEvent<gossipsub::Event>) {
+        match event {
+            SwarmEvent::NewListenAddr { address, .. } => info!("p2p node listening on {}", address),
+            SwarmEvent::ConnectionEstablished { peer_id, .. } => debug!("Connected to peer {}", peer_id),
+            SwarmEvent::Behaviour(gossipsub::Event::Message { propagation_source, message, .. }) => {
+                match serde_json::from_slice::<GossipMessage>(&message.data) {
+                    Ok(message) => self.handle_message(message).await,
+                    Err(e) => warn!("Invalid gossip message from {}: {}", propagation_source, e),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    async fn handle_message(&mut self, message: GossipMessage) {
+        match message {
+            GossipMessage::Attestation(attestation) => {
+                if !self.seen.insert(GossipMessage::Attestation(attestation.clone()).dedup_key()) {
+                    return;
+                }
+                let Some(aggregator) = &self.aggregator else {
+                    return;
+                };
+                if let Err(e) = aggregator.submit(attestation.clone()).await {
+                    warn!("Rejected gossiped attestation {} from {}: {}", attestation.message_id, attestation.validator, e);
+                }
+            }
+            GossipMessage::Announce { peer_id, addresses } => {
+                let Ok(peer_id) = PeerId::from_str(&peer_id) else {
+                    return;
+                };
+                if peer_id == *self.swarm.local_peer_id() || self.swarm.is_connected(&peer_id) {
+                    return;
+                }
+                // 发现新节点，尝试直接连接
+                for address in addresses.iter().filter_map(|a| a.parse::<Multiaddr>().ok()) {
+                    if self.swarm.dial(address.clone()).is_ok() {
+                        info!("Discovered peer {} at {}", peer_id, address);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::CrossChainMessage;
+
+    fn attestation(timestamp: u64) -> Attestation {
+        Attestation {
+            message_id: "ab".repeat(32),
+            message: CrossChainMessage {
+                nonce: 1,
+                source_chain: "sui_testnet".to_string(),
+                target_chain: "rooch_testnet".to_string(),
+                message_type: "transfer".to_string(),
+                payload: vec![],
+            },
+            validator: "0xvalidator0".to_string(),
+            public_key: String::new(),
+            signature: vec![],
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn test_attestation_dedup_ignores_timestamp() {
+        let first = GossipMessage::Attestation(attestation(1));
+        let republished = GossipMessage::Attestation(attestation(2));
+        assert_eq!(first.dedup_key(), republished.dedup_key());
+
+        let mut seen = SeenCache::default();
+        assert!(seen.insert(first.dedup_key()));
+        assert!(!seen.insert(republished.dedup_key()));
+    }
+}
@@ -0,0 +1,169 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use crate::{
+    config::{Config, LightClientConfig},
+    types::{ChainId, CrossChainMessage, EventProvenance},
+    Error,
+};
+
+pub mod rooch;
+pub mod sui;
+
+/// 源链事件的包含证明，格式由源链决定
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum InclusionProof {
+    /// Sui 委员会签名的检查点及交易效果与事件
+    SuiCheckpoint(sui::CheckpointProof),
+    /// Rooch 排序器签名的状态根下的事件
+    RoochState(rooch::StateProof),
+}
+
+impl InclusionProof {
+    fn kind(&self) -> &'static str {
+        match self {
+            InclusionProof::SuiCheckpoint(_) => "sui_checkpoint",
+            InclusionProof::RoochState(_) => "rooch_state",
+        }
+    }
+}
+
+enum LightClient {
+    Sui(sui::SuiLightClient),
+    Rooch(rooch::RoochStateVerifier),
+}
+
+/// 按源链配置的信任根验证消息的包含证明
+///
+/// 只覆盖配置了 `light_client` 的链；其它链的消息不要求证明。
+pub struct ProofVerifier {
+    clients: HashMap<ChainId, LightClient>,
+}
+
+impl ProofVerifier {
+    /// 没有链配置轻客户端时返回 None
+    pub fn from_config(config: &Config) -> Result<Option<Self>, Error> {
+        let chain_ids = config.chain_registry()?.numeric_ids();
+        let mut clients = HashMap::new();
+        for chain in &config.chains {
+            let client = match &chain.light_client {
+                Some(LightClientConfig::Sui { epoch, committee }) => {
+                    let members = committee
+                        .iter()
+                        .map(|member| {
+                            let public_key = hex::decode(member.public_key.trim_start_matches("0x"))
+                                .map_err(|e| Error::Config(format!("Invalid committee key on chain {}: {}", chain.id, e)))?;
+                            Ok(sui::CommitteeMember { public_key, stake: member.stake })
+                        })
+                        .collect::<Result<_, Error>>()?;
+                    let committee = sui::Committee { epoch: *epoch, members };
+                    LightClient::Sui(sui::SuiLightClient::new(&chain.bridge_address, committee)?)
+                }
+                Some(LightClientConfig::Rooch { sequencer_public_key }) => LightClient::Rooch(
+                    rooch::RoochStateVerifier::new(&chain.bridge_address, sequencer_public_key, chain_ids.clone())?,
+                ),
+                None => continue,
+            };
+            clients.insert(chain.id.clone(), client);
+        }
+        Ok((!clients.is_empty()).then_some(Self { clients }))
+    }
+
+    /// 源链是否要求包含证明
+    pub fn covers(&self, chain: &str) -> bool {
+        self.clients.contains_key(chain)
+    }
+
+    /// 验证消息由源链桥合约发出，证明能给出事件位置时一并返回
+    pub fn verify(&self, chain: &str, message: &CrossChainMessage, proof: &InclusionProof) -> Result<Option<EventProvenance>, Error> {
+        let client = self.clients
+            .get(chain)
+            .ok_or_else(|| Error::Config(format!("No light client configured for chain {}", chain)))?;
+        if message.source_chain.as_str() != chain {
+            return Err(Error::InvalidProof(format!("Message from {} observed on chain {}", message.source_chain, chain)));
+        }
+        match (client, proof) {
+            (LightClient::Sui(client), InclusionProof::SuiCheckpoint(proof)) => client.verify(proof, message).map(Some),
+            (LightClient::Rooch(client), InclusionProof::RoochState(proof)) => client.verify(proof, message).map(|_| None),
+            (_, proof) => Err(Error::InvalidProof(format!("Chain {} does not accept {} proofs", chain, proof.kind()))),
+        }
+    }
+}
+
+/// 按 BCS 编码读取证明中的链上数据，只解析验证需要的字段
+pub(crate) struct BcsReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BcsReader<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        let end = self.position
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(|| Error::InvalidProof("Unexpected end of BCS data".to_string()))?;
+        let bytes = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    pub(crate) fn u64(&mut self) -> Result<u64, Error> {
+        let bytes = self.take(8)?;
+        Ok(u64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    pub(crate) fn uleb128(&mut self) -> Result<u64, Error> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.take(1)?[0];
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(Error::InvalidProof("ULEB128 value overflows u64".to_string()))
+    }
+
+    pub(crate) fn option(&mut self) -> Result<bool, Error> {
+        match self.take(1)?[0] {
+            0 => Ok(false),
+            1 => Ok(true),
+            tag => Err(Error::InvalidProof(format!("Invalid option tag {}", tag))),
+        }
+    }
+
+    /// 带长度前缀的字节串
+    pub(crate) fn bytes(&mut self) -> Result<&'a [u8], Error> {
+        let len = self.uleb128()?;
+        self.take(usize::try_from(len).map_err(|_| Error::InvalidProof("Byte string too long".to_string()))?)
+    }
+
+    pub(crate) fn string(&mut self) -> Result<&'a str, Error> {
+        std::str::from_utf8(self.bytes()?).map_err(|e| Error::InvalidProof(format!("Invalid string: {}", e)))
+    }
+
+    /// 32 字节的地址，编码时没有长度前缀
+    pub(crate) fn address(&mut self) -> Result<[u8; 32], Error> {
+        Ok(self.take(32)?.try_into().unwrap())
+    }
+
+    /// 32 字节的摘要，编码为带长度前缀的字节串
+    pub(crate) fn digest(&mut self) -> Result<[u8; 32], Error> {
+        self.bytes()?
+            .try_into()
+            .map_err(|_| Error::InvalidProof("Digest must be 32 bytes".to_string()))
+    }
+
+    /// 确认数据已全部读取
+    pub(crate) fn finish(&self) -> Result<(), Error> {
+        if self.position == self.bytes.len() {
+            Ok(())
+        } else {
+            Err(Error::InvalidProof(format!("{} trailing bytes in BCS data", self.bytes.len() - self.position)))
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use ed25519_dalek::{PublicKey, Signature, Verifier};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Sha3_256};
+use std::collections::HashMap;
+use crate::{
+    types::{ChainId, CrossChainMessage},
+    Error,
+};
+use super::BcsReader;
+
+const LEAF_PREFIX: u8 = 0;
+const INTERNAL_PREFIX: u8 = 1;
+
+fn sha3(parts: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+fn invalid(reason: impl Into<String>) -> Error {
+    Error::InvalidProof(reason.into())
+}
+
+/// `rooch_bridge::message::MessageSentEvent` 的 BCS 布局
+#[derive(Deserialize)]
+struct MessageSentEvent {
+    source_chain: u64,
+    target_chain: u64,
+    receiver: Vec<u8>,
+    payload: Vec<u8>,
+}
+
+/// Rooch 事件的状态包含证明
+///
+/// 事件以 BCS 编码的 `EventID` 为键存放在状态树中，状态根由排序器签名确认。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StateProof {
+    /// 产生事件的交易在排序器中的序号
+    pub tx_order: u64,
+    pub state_root: Vec<u8>,
+    /// 排序器对 `tx_order（小端）|| state_root` 的 Ed25519 签名
+    pub sequencer_signature: Vec<u8>,
+    /// BCS 编码的 `EventID`：事件句柄 ID 与事件序号
+    pub event_id: Vec<u8>,
+    /// BCS 编码的事件数据
+    pub event_data: Vec<u8>,
+    /// 从叶子向上到根的兄弟节点哈希
+    pub siblings: Vec<Vec<u8>>,
+}
+
+/// 按键哈希的比特从根向下定位叶子，计算稀疏默克尔树的根
+pub fn state_root(key: &[u8], value: &[u8], siblings: &[Vec<u8>]) -> Result<[u8; 32], Error> {
+    if siblings.len() > 256 {
+        return Err(invalid("Sparse merkle proof is deeper than 256 levels"));
+    }
+    let path = sha3(&[key]);
+    let mut node = sha3(&[&[LEAF_PREFIX], &path, &sha3(&[value])]);
+    for (i, sibling) in siblings.iter().enumerate() {
+        let sibling: [u8; 32] = sibling.as_slice().try_into()
+            .map_err(|_| invalid("Sibling hash must be 32 bytes"))?;
+        let depth = siblings.len() - 1 - i;
+        let right = path[depth / 8] & (0x80 >> (depth % 8)) != 0;
+        node = if right {
+            sha3(&[&[INTERNAL_PREFIX], &sibling, &node])
+        } else {
+            sha3(&[&[INTERNAL_PREFIX], &node, &sibling])
+        };
+    }
+    Ok(node)
+}
+
+/// 桥合约 `MessageSentEvent` 的事件句柄 ID
+pub fn event_handle_id(bridge_address: &str) -> [u8; 32] {
+    sha3(&[format!("{}::message::MessageSentEvent", bridge_address).as_bytes()])
+}
+
+/// 以排序器公钥为信任根验证 Rooch 状态证明
+pub struct RoochStateVerifier {
+    sequencer: PublicKey,
+    handle_id: [u8; 32],
+    /// 合约中的数字链 ID 到配置链 ID 的映射
+    chain_ids: HashMap<u64, ChainId>,
+}
+
+impl RoochStateVerifier {
+    pub fn new(bridge_address: &str, sequencer_public_key: &str, chain_ids: HashMap<u64, ChainId>) -> Result<Self, Error> {
+        let key = hex::decode(sequencer_public_key.trim_start_matches("0x"))
+            .map_err(|e| Error::Config(format!("Invalid sequencer public key: {}", e)))?;
+        let sequencer = PublicKey::from_bytes(&key)
+            .map_err(|e| Error::Config(format!("Invalid sequencer public key: {}", e)))?;
+        Ok(Self { sequencer, handle_id: event_handle_id(bridge_address), chain_ids })
+    }
+
+    /// 验证消息对应的事件存在于排序器签名的状态根下
+    pub fn verify(&self, proof: &StateProof, message: &CrossChainMessage) -> Result<(), Error> {
+        let signature = Signature::from_bytes(&proof.sequencer_signature)
+            .map_err(|e| invalid(format!("Invalid sequencer signature: {}", e)))?;
+        let mut signed = proof.tx_order.to_le_bytes().to_vec();
+        signed.extend_from_slice(&proof.state_root);
+        self.sequencer
+            .verify(&signed, &signature)
+            .map_err(|_| invalid(format!("State root of transaction {} is not signed by the sequencer", proof.tx_order)))?;
+
+        if state_root(&proof.event_id, &proof.event_data, &proof.siblings)?.as_slice() != proof.state_root {
+            return Err(invalid(format!("Event is not included in the state of transaction {}", proof.tx_order)));
+        }
+
+        let mut reader = BcsReader::new(&proof.event_id);
+        let handle_id = reader.address()?;
+        let event_seq = reader.u64()?;
+        reader.finish()?;
+        if handle_id != self.handle_id {
+            return Err(invalid("Event is not a bridge MessageSentEvent"));
+        }
+
+        let event: MessageSentEvent = bcs::from_bytes(&proof.event_data)
+            .map_err(|e| invalid(format!("Invalid MessageSentEvent: {}", e)))?;
+        // 与 Rooch 适配器的解码一致：nonce 为事件序号，负载为接收者地址 || 负载
+        let mut payload = event.receiver;
+        payload.extend(event.payload);
+        let matches = message.nonce == event_seq
+            && self.chain_ids.get(&event.source_chain) == Some(&message.source_chain)
+            && self.chain_ids.get(&event.target_chain) == Some(&message.target_chain)
+            && message.payload == payload;
+        if !matches {
+            return Err(invalid("Proven event does not match the message"));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Keypair, SecretKey, Signer};
+
+    #[test]
+    fn test_verify_state_proof() {
+        let secret = SecretKey::from_bytes(&[3; 32]).unwrap();
+        let sequencer = Keypair { public: PublicKey::from(&secret), secret };
+        let chain_ids = HashMap::from([(1, ChainId::from("sui")), (2, ChainId::from("rooch"))]);
+        let verifier = RoochStateVerifier::new("0x42", &hex::encode(sequencer.public.to_bytes()), chain_ids).unwrap();
+
+        let event_id = bcs::to_bytes(&(event_handle_id("0x42"), 7u64)).unwrap();
+        let event_data = bcs::to_bytes(&(2u64, 1u64, vec![0xaau8; 4], vec![1u8, 2])).unwrap();
+        let siblings = vec![vec![4; 32], vec![5; 32], vec![0; 32]];
+        let root = state_root(&event_id, &event_data, &siblings).unwrap().to_vec();
+        let mut signed = 99u64.to_le_bytes().to_vec();
+        signed.extend_from_slice(&root);
+        let proof = StateProof {
+            tx_order: 99,
+            state_root: root,
+            sequencer_signature: sequencer.sign(&signed).to_bytes().to_vec(),
+            event_id,
+            event_data,
+            siblings,
+        };
+        let message = CrossChainMessage {
+            nonce: 7,
+            source_chain: "rooch".into(),
+            target_chain: "sui".into(),
+            message_type: "transfer".to_string(),
+            payload: vec![0xaa, 0xaa, 0xaa, 0xaa, 1, 2],
+        };
+        assert!(verifier.verify(&proof, &message).is_ok());
+
+        let mut forged = message.clone();
+        forged.nonce = 8;
+        assert!(verifier.verify(&proof, &forged).is_err());
+        // 兄弟节点被篡改后根不一致
+        let mut tampered = proof.clone();
+        tampered.siblings[1] = vec![6; 32];
+        assert!(verifier.verify(&tampered, &message).is_err());
+        // 未经排序器签名的状态根
+        let mut unsigned = proof;
+        unsigned.tx_order = 100;
+        assert!(verifier.verify(&unsigned, &message).is_err());
+    }
+}
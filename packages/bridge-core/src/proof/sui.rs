@@ -0,0 +1,483 @@
+use blake2::{Blake2b, Digest, digest::consts::U32};
+use blst::min_sig::{AggregatePublicKey, PublicKey, Signature};
+use blst::BLST_ERROR;
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use crate::{
+    types::{CrossChainMessage, EventProvenance},
+    Error,
+};
+use super::BcsReader;
+
+type Blake2b256 = Blake2b<U32>;
+
+/// Sui 验证者签名的域分隔标签（min_sig 方案：签名在 G1，公钥在 G2）
+const SUI_BLS_DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
+/// `IntentScope::CheckpointSummary`、`IntentVersion::V0`、`AppId::Sui`
+const CHECKPOINT_INTENT: [u8; 3] = [2, 0, 0];
+/// 验证者 BLS 公钥长度（压缩的 G2 点）
+const AUTHORITY_KEY_LENGTH: usize = 96;
+/// 桥合约发出跨链消息的事件，内容为消息的 BCS 编码
+const MESSAGE_EVENT_MODULE: &str = "bridge";
+const MESSAGE_EVENT_NAME: &str = "MessageEvent";
+
+/// Sui 对象摘要：`Blake2b256("类型名::" || BCS)`
+fn digest(type_name: &str, bcs: &[u8]) -> [u8; 32] {
+    let mut hasher = Blake2b256::new();
+    hasher.update(type_name.as_bytes());
+    hasher.update(b"::");
+    hasher.update(bcs);
+    hasher.finalize().into()
+}
+
+fn invalid(reason: impl Into<String>) -> Error {
+    Error::InvalidProof(reason.into())
+}
+
+/// 委员会成员及其质押权重
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitteeMember {
+    /// BLS12-381 公钥（G2，96 字节）
+    pub public_key: Vec<u8>,
+    pub stake: u64,
+}
+
+/// 某一纪元的验证者委员会
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Committee {
+    pub epoch: u64,
+    pub members: Vec<CommitteeMember>,
+}
+
+impl Committee {
+    /// 检查摘要属于本纪元且带有质押权重超过 2/3 的聚合签名，返回解析后的摘要
+    fn verify(&self, certified: &CertifiedSummary) -> Result<CheckpointSummary, Error> {
+        let summary = CheckpointSummary::parse(&certified.summary)?;
+        if summary.epoch != self.epoch {
+            return Err(invalid(format!(
+                "Checkpoint {} is from epoch {}, trusted committee is for epoch {}",
+                summary.sequence_number, summary.epoch, self.epoch
+            )));
+        }
+
+        let mut signers = certified.signers.clone();
+        signers.sort_unstable();
+        signers.dedup();
+        let mut keys = Vec::with_capacity(signers.len());
+        let mut signed_stake: u128 = 0;
+        for index in signers {
+            let member = self.members.get(index as usize)
+                .ok_or_else(|| invalid(format!("Signer {} is not in the committee of epoch {}", index, self.epoch)))?;
+            keys.push(PublicKey::key_validate(&member.public_key).map_err(|e| invalid(format!("{:?}", e)))?);
+            signed_stake += member.stake as u128;
+        }
+        let total_stake: u128 = self.members.iter().map(|m| m.stake as u128).sum();
+        if signed_stake * 3 <= total_stake * 2 {
+            return Err(invalid(format!(
+                "Checkpoint {} is signed by {} of {} stake, below quorum",
+                summary.sequence_number, signed_stake, total_stake
+            )));
+        }
+
+        let refs: Vec<&PublicKey> = keys.iter().collect();
+        let aggregate = AggregatePublicKey::aggregate(&refs, false).map_err(|e| invalid(format!("{:?}", e)))?;
+        let signature = Signature::from_bytes(&certified.signature).map_err(|e| invalid(format!("{:?}", e)))?;
+        // 验证者签名的是意图消息，末尾附加纪元号
+        let mut message = CHECKPOINT_INTENT.to_vec();
+        message.extend_from_slice(&certified.summary);
+        message.extend_from_slice(&self.epoch.to_le_bytes());
+        if signature.verify(true, &message, SUI_BLS_DST, &[], &aggregate.to_public_key(), false) != BLST_ERROR::BLST_SUCCESS {
+            return Err(invalid(format!("Invalid committee signature on checkpoint {}", summary.sequence_number)));
+        }
+        Ok(summary)
+    }
+}
+
+/// `CheckpointSummary` 中验证所需的字段
+struct CheckpointSummary {
+    epoch: u64,
+    sequence_number: u64,
+    content_digest: [u8; 32],
+    /// 纪元最后一个检查点携带下一纪元的委员会
+    next_committee: Option<Vec<CommitteeMember>>,
+}
+
+impl CheckpointSummary {
+    fn parse(bytes: &[u8]) -> Result<Self, Error> {
+        let mut reader = BcsReader::new(bytes);
+        let epoch = reader.u64()?;
+        let sequence_number = reader.u64()?;
+        let _network_total_transactions = reader.u64()?;
+        let content_digest = reader.digest()?;
+        if reader.option()? {
+            let _previous_digest = reader.digest()?;
+        }
+        // epoch_rolling_gas_cost_summary 与 timestamp_ms
+        for _ in 0..5 {
+            reader.u64()?;
+        }
+        skip_commitments(&mut reader)?;
+        let next_committee = if reader.option()? {
+            let count = reader.uleb128()?;
+            let mut members = Vec::new();
+            for _ in 0..count {
+                let public_key = reader.bytes()?.to_vec();
+                if public_key.len() != AUTHORITY_KEY_LENGTH {
+                    return Err(invalid(format!("Authority key must be {} bytes", AUTHORITY_KEY_LENGTH)));
+                }
+                members.push(CommitteeMember { public_key, stake: reader.u64()? });
+            }
+            let _next_epoch_protocol_version = reader.u64()?;
+            skip_commitments(&mut reader)?;
+            Some(members)
+        } else {
+            None
+        };
+        let _version_specific_data = reader.bytes()?;
+        reader.finish()?;
+        Ok(Self { epoch, sequence_number, content_digest, next_committee })
+    }
+}
+
+/// 跳过 `Vec<CheckpointCommitment>`，每种承诺都只包含一个摘要
+fn skip_commitments(reader: &mut BcsReader) -> Result<(), Error> {
+    for _ in 0..reader.uleb128()? {
+        reader.uleb128()?;
+        reader.digest()?;
+    }
+    Ok(())
+}
+
+/// 检查点内容中一笔交易的 (交易摘要, 效果摘要)
+type ExecutionDigests = ([u8; 32], [u8; 32]);
+
+/// `CheckpointContents` 中各交易的摘要
+fn parse_contents(bytes: &[u8]) -> Result<Vec<ExecutionDigests>, Error> {
+    let mut reader = BcsReader::new(bytes);
+    if reader.uleb128()? != 0 {
+        return Err(invalid("Unsupported checkpoint contents version"));
+    }
+    let count = reader.uleb128()?;
+    let mut digests = Vec::new();
+    for _ in 0..count {
+        digests.push((reader.digest()?, reader.digest()?));
+    }
+    Ok(digests)
+}
+
+/// `TransactionEvents` 中的一个事件
+struct Event {
+    package_id: [u8; 32],
+    type_address: [u8; 32],
+    type_module: String,
+    type_name: String,
+    contents: Vec<u8>,
+}
+
+fn parse_events(bytes: &[u8]) -> Result<Vec<Event>, Error> {
+    let mut reader = BcsReader::new(bytes);
+    let count = reader.uleb128()?;
+    let mut events = Vec::new();
+    for _ in 0..count {
+        let package_id = reader.address()?;
+        let _transaction_module = reader.string()?;
+        let _sender = reader.address()?;
+        let type_address = reader.address()?;
+        let type_module = reader.string()?.to_string();
+        let type_name = reader.string()?.to_string();
+        for _ in 0..reader.uleb128()? {
+            skip_type_tag(&mut reader)?;
+        }
+        events.push(Event { package_id, type_address, type_module, type_name, contents: reader.bytes()?.to_vec() });
+    }
+    reader.finish()?;
+    Ok(events)
+}
+
+fn skip_type_tag(reader: &mut BcsReader) -> Result<(), Error> {
+    match reader.uleb128()? {
+        0..=5 | 8..=10 => Ok(()),
+        // vector<T>
+        6 => skip_type_tag(reader),
+        // 结构体：地址、模块、名称与类型参数
+        7 => {
+            reader.address()?;
+            reader.string()?;
+            reader.string()?;
+            for _ in 0..reader.uleb128()? {
+                skip_type_tag(reader)?;
+            }
+            Ok(())
+        }
+        tag => Err(invalid(format!("Unknown type tag {}", tag))),
+    }
+}
+
+/// 带有委员会聚合签名的检查点摘要
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CertifiedSummary {
+    /// BCS 编码的 `CheckpointSummary`
+    pub summary: Vec<u8>,
+    /// 委员会的 BLS 聚合签名（G1，48 字节）
+    pub signature: Vec<u8>,
+    /// 参与签名的成员在委员会中的序号
+    pub signers: Vec<u32>,
+}
+
+/// Sui 事件的检查点包含证明
+///
+/// 验证链路：委员会签名的检查点摘要 → 检查点内容摘要 → 交易效果摘要 → 效果中的事件摘要 → 事件。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckpointProof {
+    /// 从受信任委员会的纪元到检查点所在纪元之间，各纪元的最后一个检查点
+    #[serde(default)]
+    pub epoch_changes: Vec<CertifiedSummary>,
+    pub checkpoint: CertifiedSummary,
+    /// BCS 编码的 `CheckpointContents`
+    pub contents: Vec<u8>,
+    /// BCS 编码的 `TransactionEffects`
+    pub effects: Vec<u8>,
+    /// BCS 编码的 `TransactionEvents`
+    pub events: Vec<u8>,
+    /// 消息事件在交易事件中的序号
+    pub event_index: u64,
+}
+
+/// 跟踪 Sui 验证者委员会的轻客户端
+///
+/// 从配置的委员会开始，证明中携带的纪元切换检查点通过验证后才更新受信任的委员会。
+pub struct SuiLightClient {
+    package: [u8; 32],
+    committee: Mutex<Committee>,
+}
+
+impl SuiLightClient {
+    pub fn new(bridge_address: &str, committee: Committee) -> Result<Self, Error> {
+        let hex_address = bridge_address.trim_start_matches("0x");
+        let bytes = hex::decode(format!("{:0>64}", hex_address))
+            .map_err(|e| Error::Config(format!("Invalid bridge address {}: {}", bridge_address, e)))?;
+        let package = bytes.try_into()
+            .map_err(|_| Error::Config(format!("Bridge address {} is longer than 32 bytes", bridge_address)))?;
+        Ok(Self { package, committee: Mutex::new(committee) })
+    }
+
+    /// 当前受信任的委员会
+    pub fn committee(&self) -> Committee {
+        self.committee.lock().unwrap().clone()
+    }
+
+    /// 验证消息由桥合约在已签名的检查点中发出，返回事件位置
+    pub fn verify(&self, proof: &CheckpointProof, message: &CrossChainMessage) -> Result<EventProvenance, Error> {
+        let mut committee = self.committee();
+        for change in &proof.epoch_changes {
+            // 已经信任的纪元切换直接跳过
+            if CheckpointSummary::parse(&change.summary)?.epoch < committee.epoch {
+                continue;
+            }
+            let summary = committee.verify(change)?;
+            let members = summary.next_committee
+                .ok_or_else(|| invalid(format!("Checkpoint {} does not end epoch {}", summary.sequence_number, committee.epoch)))?;
+            committee = Committee { epoch: committee.epoch + 1, members };
+        }
+        let summary = committee.verify(&proof.checkpoint)?;
+
+        if digest("CheckpointContents", &proof.contents) != summary.content_digest {
+            return Err(invalid(format!("Contents do not match checkpoint {}", summary.sequence_number)));
+        }
+        let effects_digest = digest("TransactionEffects", &proof.effects);
+        let (transaction, _) = parse_contents(&proof.contents)?
+            .into_iter()
+            .find(|(_, effects)| *effects == effects_digest)
+            .ok_or_else(|| invalid(format!("Transaction effects are not in checkpoint {}", summary.sequence_number)))?;
+        // 效果中的 `events_digest: Option<TransactionEventsDigest>` 编码为 0x01 || 长度 || 摘要
+        let mut events_digest = vec![1, 32];
+        events_digest.extend(digest("TransactionEvents", &proof.events));
+        if !proof.effects.windows(events_digest.len()).any(|window| window == events_digest) {
+            return Err(invalid("Transaction effects do not commit to the events"));
+        }
+
+        let events = parse_events(&proof.events)?;
+        let event = events.get(proof.event_index as usize)
+            .ok_or_else(|| invalid(format!("Event {} not found in transaction", proof.event_index)))?;
+        if event.package_id != self.package
+            || event.type_address != self.package
+            || event.type_module != MESSAGE_EVENT_MODULE
+            || event.type_name != MESSAGE_EVENT_NAME
+        {
+            return Err(invalid(format!("Event {} is not a bridge message event", proof.event_index)));
+        }
+        let encoded = bcs::to_bytes(message).map_err(|e| Error::Serialization(e.to_string()))?;
+        if event.contents != encoded {
+            return Err(invalid("Proven event does not match the message"));
+        }
+
+        // 只有证明整体有效时才接受新的委员会
+        let mut trusted = self.committee.lock().unwrap();
+        if committee.epoch > trusted.epoch {
+            *trusted = committee;
+        }
+        Ok(EventProvenance {
+            block: summary.sequence_number,
+            tx_hash: bs58::encode(transaction).into_string(),
+            event_index: proof.event_index,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use blst::min_sig::{AggregateSignature, SecretKey};
+
+    const PACKAGE: [u8; 32] = [0x42; 32];
+
+    type Commitment = (u8, Vec<u8>);
+    /// 与 `EndOfEpochData` 相同的 BCS 布局
+    type EndOfEpoch = (Vec<(Vec<u8>, u64)>, u64, Vec<Commitment>);
+
+    /// 与 `CheckpointSummary` 相同的 BCS 布局
+    #[derive(Serialize)]
+    struct Summary {
+        epoch: u64,
+        sequence_number: u64,
+        network_total_transactions: u64,
+        content_digest: Vec<u8>,
+        previous_digest: Option<Vec<u8>>,
+        gas: [u64; 4],
+        timestamp_ms: u64,
+        commitments: Vec<Commitment>,
+        end_of_epoch_data: Option<EndOfEpoch>,
+        version_specific_data: Vec<u8>,
+    }
+
+    struct TestCommittee {
+        epoch: u64,
+        keys: Vec<SecretKey>,
+    }
+
+    impl TestCommittee {
+        fn new(epoch: u64, seed: u8) -> Self {
+            let keys = (0..4).map(|i| SecretKey::key_gen(&[seed + i; 32], &[]).unwrap()).collect();
+            Self { epoch, keys }
+        }
+
+        fn members(&self) -> Vec<CommitteeMember> {
+            self.keys
+                .iter()
+                .map(|key| CommitteeMember { public_key: key.sk_to_pk().to_bytes().to_vec(), stake: 2500 })
+                .collect()
+        }
+
+        fn certify(&self, summary: &Summary, signers: &[u32]) -> CertifiedSummary {
+            let bytes = bcs::to_bytes(summary).unwrap();
+            let mut message = CHECKPOINT_INTENT.to_vec();
+            message.extend_from_slice(&bytes);
+            message.extend_from_slice(&self.epoch.to_le_bytes());
+            let signatures: Vec<Signature> = signers
+                .iter()
+                .map(|&i| self.keys[i as usize].sign(&message, SUI_BLS_DST, &[]))
+                .collect();
+            let refs: Vec<&Signature> = signatures.iter().collect();
+            CertifiedSummary {
+                summary: bytes,
+                signature: AggregateSignature::aggregate(&refs, false).unwrap().to_signature().to_bytes().to_vec(),
+                signers: signers.to_vec(),
+            }
+        }
+    }
+
+    fn summary(epoch: u64, sequence_number: u64, content_digest: [u8; 32], next: Option<&TestCommittee>) -> Summary {
+        Summary {
+            epoch,
+            sequence_number,
+            network_total_transactions: 1000,
+            content_digest: content_digest.to_vec(),
+            previous_digest: Some(vec![7; 32]),
+            gas: [1, 2, 3, 4],
+            timestamp_ms: 1_700_000_000_000,
+            commitments: vec![],
+            end_of_epoch_data: next.map(|committee| {
+                let members = committee.members().into_iter().map(|m| (m.public_key, m.stake)).collect();
+                (members, 60, vec![(0, vec![9; 32])])
+            }),
+            version_specific_data: vec![],
+        }
+    }
+
+    fn message() -> CrossChainMessage {
+        CrossChainMessage {
+            nonce: 3,
+            source_chain: "sui".into(),
+            target_chain: "rooch".into(),
+            message_type: "transfer".to_string(),
+            payload: vec![1, 2, 3],
+        }
+    }
+
+    /// 构造包含一个桥消息事件的交易，返回 (内容, 效果, 事件)
+    fn transaction(message: &CrossChainMessage) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+        type StructTag = ([u8; 32], String, String, Vec<u8>);
+        let event: ([u8; 32], String, [u8; 32], StructTag, Vec<u8>) = (
+            PACKAGE,
+            "bridge".to_string(),
+            [0x11; 32],
+            (PACKAGE, "bridge".to_string(), "MessageEvent".to_string(), vec![]),
+            bcs::to_bytes(message).unwrap(),
+        );
+        let events = bcs::to_bytes(&vec![event]).unwrap();
+        let mut effects = vec![1, 0, 0, 0];
+        effects.extend([1, 32]);
+        effects.extend(digest("TransactionEvents", &events));
+        effects.extend([0, 0]);
+        let contents = bcs::to_bytes(&(0u8, vec![(vec![5u8; 32], digest("TransactionEffects", &effects).to_vec())], Vec::<u8>::new())).unwrap();
+        (contents, effects, events)
+    }
+
+    #[test]
+    fn test_verify_checkpoint_proof() {
+        let genesis = TestCommittee::new(0, 1);
+        let client = SuiLightClient::new(&format!("0x{}", hex::encode(PACKAGE)), Committee { epoch: 0, members: genesis.members() }).unwrap();
+        let message = message();
+        let (contents, effects, events) = transaction(&message);
+        let content_digest = digest("CheckpointContents", &contents);
+        let proof = CheckpointProof {
+            epoch_changes: vec![],
+            checkpoint: genesis.certify(&summary(0, 10, content_digest, None), &[0, 1, 3]),
+            contents: contents.clone(),
+            effects: effects.clone(),
+            events: events.clone(),
+            event_index: 0,
+        };
+        let provenance = client.verify(&proof, &message).unwrap();
+        assert_eq!(provenance.block, 10);
+        assert_eq!(provenance.tx_hash, bs58::encode([5u8; 32]).into_string());
+
+        // 事件内容与消息不一致
+        let mut forged = message.clone();
+        forged.payload = vec![9];
+        assert!(client.verify(&proof, &forged).is_err());
+        // 签名权重不足 2/3
+        let mut weak = proof.clone();
+        weak.checkpoint = genesis.certify(&summary(0, 10, content_digest, None), &[0, 1]);
+        assert!(client.verify(&weak, &message).is_err());
+        // 签名者与签名不符
+        let mut mislabeled = proof.clone();
+        mislabeled.checkpoint.signers = vec![0, 1, 2];
+        assert!(client.verify(&mislabeled, &message).is_err());
+
+        // 下一纪元的检查点须先通过纪元切换检查点验证新委员会
+        let next = TestCommittee::new(1, 50);
+        let later = CheckpointProof {
+            epoch_changes: vec![],
+            checkpoint: next.certify(&summary(1, 20, content_digest, None), &[0, 1, 2, 3]),
+            ..proof
+        };
+        assert!(client.verify(&later, &message).is_err());
+        let end_of_epoch = genesis.certify(&summary(0, 15, [0; 32], Some(&next)), &[1, 2, 3]);
+        let later = CheckpointProof { epoch_changes: vec![end_of_epoch], ..later };
+        assert_eq!(client.verify(&later, &message).unwrap().block, 20);
+        assert_eq!(client.committee().epoch, 1);
+        // 委员会更新后，重复携带的纪元切换检查点被跳过
+        assert!(client.verify(&later, &message).is_ok());
+    }
+}
@@ -0,0 +1,392 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+use tracing::{debug, info, warn};
+use crate::{
+    config::ValidatorConfig,
+    types::{is_governance, Attestation, ChainRegistry, CrossChainMessage},
+    verify::batch::{self, SignatureCheck},
+    Error,
+};
+use super::{equivocation::EquivocationDetector, now_secs, rotation::ValidatorSet};
+
+/// 单条消息的法定人数进度
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuorumProgress {
+    pub message_id: String,
+    /// 已签名验证者的总权重
+    pub weight: u64,
+    pub threshold: u64,
+    /// 已签名的验证者地址
+    pub signers: Vec<String>,
+    /// 中继器是否已在源链上观察到该消息
+    pub observed: bool,
+}
+
+impl QuorumProgress {
+    pub fn reached(&self) -> bool {
+        self.weight >= self.threshold
+    }
+}
+
+#[derive(Default)]
+struct MessageAttestations {
+    message: Option<CrossChainMessage>,
+    observed: bool,
+    attestations: HashMap<String, Attestation>,
+}
+
+/// 证明聚合器
+///
+/// 接收验证者发布的证明，按消息 ID 与中继器观察到的消息匹配，
+/// 按 `ValidatorConfig.weight` 累计权重，达到阈值后才允许中继。
+///
+/// 验证者对提交到目标链的消息签名，转账换算金额后其消息 ID 与源链消息 ID 不同；
+/// 中继器观察时登记两者的对应关系，之后仍可按源链消息 ID 查询进度与证明。
+pub struct AttestationAggregator {
+    validators: std::sync::RwLock<ValidatorSet>,
+    /// 治理消息的阈值，未设置时按 [`ValidatorSet::governance_threshold`] 的默认值
+    governance_threshold: Option<u64>,
+    /// 计算消息 ID 所用的链登记表
+    chains: ChainRegistry,
+    messages: RwLock<HashMap<String, MessageAttestations>>,
+    /// 源链消息 ID 到验证者签名的消息 ID，两者相同时不登记
+    aliases: RwLock<HashMap<String, String>>,
+    equivocations: EquivocationDetector,
+}
+
+impl AttestationAggregator {
+    /// 创建聚合器，未指定阈值时使用总权重的 2/3（与链上合约一致）
+    pub fn new(validators: Vec<ValidatorConfig>, threshold: Option<u64>, chains: ChainRegistry) -> Self {
+        Self {
+            validators: std::sync::RwLock::new(ValidatorSet { validators, threshold, retiring: Vec::new() }),
+            governance_threshold: None,
+            chains,
+            messages: RwLock::new(HashMap::new()),
+            aliases: RwLock::new(HashMap::new()),
+            equivocations: EquivocationDetector::default(),
+        }
+    }
+
+    /// 设置治理消息的阈值
+    pub fn with_governance_threshold(mut self, threshold: Option<u64>) -> Self {
+        self.governance_threshold = threshold;
+        self
+    }
+
+    pub fn threshold(&self) -> u64 {
+        self.validators.read().unwrap().threshold()
+    }
+
+    /// 证明中发现的双签
+    pub fn equivocations(&self) -> &EquivocationDetector {
+        &self.equivocations
+    }
+
+    /// 切换到轮换后的验证者集合，已收集的证明按新集合重新计算权重
+    pub fn update(&self, set: ValidatorSet) {
+        *self.validators.write().unwrap() = set;
+    }
+
+    /// 校验并记录一条证明，返回该消息当前的法定人数进度
+    pub async fn submit(&self, attestation: Attestation) -> Result<QuorumProgress, Error> {
+        self.submit_batch(vec![attestation]).await.pop().expect("one result per attestation")
+    }
+
+    /// 校验并记录一组证明，按输入顺序返回每条证明的结果
+    ///
+    /// 签名通过 [`batch::verify`] 批量验证，无效的签名只影响其所在的证明。
+    pub async fn submit_batch(&self, attestations: Vec<Attestation>) -> Vec<Result<QuorumProgress, Error>> {
+        let checked: Vec<_> = attestations.iter().map(|attestation| self.check(attestation)).collect();
+        let signatures: Vec<SignatureCheck> = attestations
+            .iter()
+            .zip(&checked)
+            .filter_map(|(attestation, checked)| {
+                let (public_key, hash) = checked.as_ref().ok()?;
+                Some(SignatureCheck { public_key, message: hash, signature: &attestation.signature })
+            })
+            .collect();
+        let mut verified = batch::verify(&signatures).into_iter();
+
+        let mut results = Vec::with_capacity(attestations.len());
+        for (attestation, checked) in attestations.into_iter().zip(checked) {
+            let result = match checked {
+                Ok(_) => match verified.next().expect("one result per signature") {
+                    Ok(()) => self.record(attestation).await,
+                    Err(e) => Err(e),
+                },
+                Err(e) => Err(e),
+            };
+            results.push(result);
+        }
+        results
+    }
+
+    /// 检查证明的验证者、公钥与消息 ID，返回待验证签名的公钥与消息哈希
+    fn check(&self, attestation: &Attestation) -> Result<(Vec<u8>, [u8; 32]), Error> {
+        {
+            let set = self.validators.read().unwrap();
+            if !set.validators.iter().any(|v| v.address == attestation.validator) {
+                return Err(Error::Key(format!("Unknown validator: {}", attestation.validator)));
+            }
+            // 密钥轮换的宽限期内，旧密钥的证明同样有效
+            if !set.accepts_key(&attestation.validator, &attestation.public_key, now_secs()) {
+                return Err(Error::Key(format!("Public key mismatch for validator {}", attestation.validator)));
+            }
+        }
+
+        let public_key = hex::decode(attestation.public_key.trim_start_matches("0x"))
+            .map_err(|e| Error::Key(format!("Invalid public key for {}: {}", attestation.validator, e)))?;
+
+        let hash = attestation.message.canonical_hash(&self.chains)?;
+        if hex::encode(hash) != attestation.message_id {
+            return Err(Error::Key(format!("Message ID mismatch in attestation {}", attestation.message_id)));
+        }
+        Ok((public_key, hash))
+    }
+
+    /// 记录签名已验证的证明
+    async fn record(&self, attestation: Attestation) -> Result<QuorumProgress, Error> {
+        // 对同一 nonce 的另一条消息签过名的验证者，其冲突证明不计入权重
+        if let Some(evidence) = self.equivocations.check(&attestation, now_secs()) {
+            warn!(
+                "Validator {} equivocated on nonce {} of {}: signed {} and {}",
+                evidence.validator, evidence.nonce, evidence.source_chain, evidence.first.message_id, evidence.second.message_id
+            );
+            return Err(Error::Key(format!(
+                "Validator {} already signed a different message for nonce {} of {}",
+                evidence.validator, evidence.nonce, evidence.source_chain
+            )));
+        }
+
+        let mut messages = self.messages.write().await;
+        let entry = messages.entry(attestation.message_id.clone()).or_default();
+        if let Some(message) = &entry.message {
+            if message != &attestation.message {
+                return Err(Error::Key(format!("Conflicting message content for {}", attestation.message_id)));
+            }
+        } else {
+            entry.message = Some(attestation.message.clone());
+        }
+
+        debug!("Accepted attestation from {} for {}", attestation.validator, attestation.message_id);
+        entry.attestations.insert(attestation.validator.clone(), attestation.clone());
+        let progress = self.progress_of(&attestation.message_id, entry);
+        if progress.reached() {
+            info!("Quorum reached for message {} ({}/{})", progress.message_id, progress.weight, progress.threshold);
+        }
+        Ok(progress)
+    }
+
+    /// 记录中继器在源链上观察到的消息
+    ///
+    /// `message_id` 为中继器跟踪该消息所用的源链消息 ID，`message` 为验证者签名并提交到目标链的消息
+    /// （见 [`crate::ledger::submitted_message`]）。
+    pub async fn observe(&self, message_id: &str, message: &CrossChainMessage) -> Result<QuorumProgress, Error> {
+        let attested_id = message.message_id(&self.chains)?;
+        if attested_id != message_id {
+            self.aliases.write().await.insert(message_id.to_string(), attested_id.clone());
+        }
+        let mut messages = self.messages.write().await;
+        let entry = messages.entry(attested_id).or_default();
+
+        // 观察到的消息内容为准，丢弃内容不一致的证明
+        if entry.message.as_ref().is_some_and(|m| m != message) {
+            entry.attestations.clear();
+        }
+        entry.message = Some(message.clone());
+        entry.observed = true;
+        Ok(self.progress_of(message_id, entry))
+    }
+
+    /// 源链消息 ID 对应的验证者签名消息 ID
+    async fn resolve(&self, message_id: &str) -> String {
+        self.aliases.read().await.get(message_id).cloned().unwrap_or_else(|| message_id.to_string())
+    }
+
+    /// 查询消息的法定人数进度
+    pub async fn progress(&self, message_id: &str) -> Option<QuorumProgress> {
+        let attested_id = self.resolve(message_id).await;
+        let messages = self.messages.read().await;
+        messages.get(&attested_id).map(|entry| self.progress_of(message_id, entry))
+    }
+
+    /// 消息是否已被观察到且达到签名阈值
+    pub async fn is_ready(&self, message_id: &str) -> bool {
+        self.progress(message_id)
+            .await
+            .is_some_and(|p| p.observed && p.reached())
+    }
+
+    /// 获取消息已收集的证明
+    pub async fn attestations(&self, message_id: &str) -> Vec<Attestation> {
+        let attested_id = self.resolve(message_id).await;
+        let messages = self.messages.read().await;
+        messages
+            .get(&attested_id)
+            .map(|entry| entry.attestations.values().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// 消息中继完成后清理其证明
+    pub async fn remove(&self, message_id: &str) {
+        let attested_id = self.aliases.write().await.remove(message_id).unwrap_or_else(|| message_id.to_string());
+        self.messages.write().await.remove(&attested_id);
+    }
+
+    fn progress_of(&self, message_id: &str, entry: &MessageAttestations) -> QuorumProgress {
+        let set = self.validators.read().unwrap();
+        let weight = entry.attestations
+            .keys()
+            .filter_map(|address| set.validators.iter().find(|v| &v.address == address))
+            .map(|v| v.weight)
+            .sum();
+        QuorumProgress {
+            message_id: message_id.to_string(),
+            weight,
+            threshold: match &entry.message {
+                Some(message) if is_governance(&message.message_type) => set.governance_threshold(self.governance_threshold),
+                _ => set.threshold(),
+            },
+            signers: entry.attestations.keys().cloned().collect(),
+            observed: entry.observed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{test_chains, SOURCE_CHAIN, TARGET_CHAIN};
+    use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+
+    fn setup(count: usize) -> (Vec<Keypair>, Vec<ValidatorConfig>) {
+        let keypairs: Vec<_> = (0..count)
+            .map(|i| {
+                let secret = SecretKey::from_bytes(&[i as u8 + 1; 32]).unwrap();
+                let public = PublicKey::from(&secret);
+                Keypair { secret, public }
+            })
+            .collect();
+        let validators = keypairs
+            .iter()
+            .enumerate()
+            .map(|(i, keypair)| ValidatorConfig {
+                address: format!("0xvalidator{}", i),
+                public_key: hex::encode(keypair.public.to_bytes()),
+                bls_public_key: None,
+                weight: 1,
+                chains: vec![],
+            })
+            .collect();
+        (keypairs, validators)
+    }
+
+    fn message() -> CrossChainMessage {
+        CrossChainMessage {
+            nonce: 1,
+            source_chain: SOURCE_CHAIN.into(),
+            target_chain: TARGET_CHAIN.into(),
+            message_type: "transfer".to_string(),
+            payload: vec![1, 2, 3],
+        }
+    }
+
+    fn attest(keypair: &Keypair, validator: &ValidatorConfig, message: &CrossChainMessage) -> Attestation {
+        let hash = message.canonical_hash(&test_chains()).unwrap();
+        Attestation {
+            message_id: hex::encode(hash),
+            message: message.clone(),
+            validator: validator.address.clone(),
+            public_key: validator.public_key.clone(),
+            signature: keypair.sign(&hash).to_bytes().to_vec(),
+            timestamp: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_quorum_requires_threshold_and_observation() {
+        let (keypairs, validators) = setup(3);
+        let aggregator = AttestationAggregator::new(validators.clone(), None, test_chains());
+        let message = message();
+        let message_id = message.message_id(&test_chains()).unwrap();
+
+        aggregator.submit(attest(&keypairs[0], &validators[0], &message)).await.unwrap();
+        let progress = aggregator.submit(attest(&keypairs[1], &validators[1], &message)).await.unwrap();
+        assert!(progress.reached());
+        assert!(!aggregator.is_ready(&message_id).await);
+
+        aggregator.observe(&message_id, &message).await.unwrap();
+        assert!(aggregator.is_ready(&message_id).await);
+    }
+
+    #[tokio::test]
+    async fn test_attestations_over_submitted_message_count_for_source_id() {
+        let (keypairs, validators) = setup(3);
+        let aggregator = AttestationAggregator::new(validators.clone(), None, test_chains());
+        let source = message();
+        let source_id = source.message_id(&test_chains()).unwrap();
+        // 验证者对换算金额后的消息签名
+        let submitted = CrossChainMessage { payload: vec![1, 2], ..source.clone() };
+        for (keypair, validator) in keypairs.iter().zip(&validators).take(2) {
+            aggregator.submit(attest(keypair, validator, &submitted)).await.unwrap();
+        }
+
+        let progress = aggregator.observe(&source_id, &submitted).await.unwrap();
+        assert_eq!(progress.message_id, source_id);
+        assert!(aggregator.is_ready(&source_id).await);
+        assert_eq!(aggregator.attestations(&source_id).await.len(), 2);
+
+        aggregator.remove(&source_id).await;
+        let submitted_id = submitted.message_id(&test_chains()).unwrap();
+        assert!(aggregator.progress(&submitted_id).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_forged_attestation() {
+        let (keypairs, validators) = setup(2);
+        let aggregator = AttestationAggregator::new(validators.clone(), None, test_chains());
+
+        // 使用验证者 1 的密钥冒充验证者 0
+        let mut forged = attest(&keypairs[1], &validators[1], &message());
+        forged.validator = validators[0].address.clone();
+        assert!(aggregator.submit(forged).await.is_err());
+
+        // 批量提交时，签名被篡改的证明不影响同批的其它证明
+        let mut tampered = attest(&keypairs[1], &validators[1], &message());
+        tampered.signature[0] ^= 1;
+        let results = aggregator.submit_batch(vec![attest(&keypairs[0], &validators[0], &message()), tampered]).await;
+        assert_eq!(results[0].as_ref().unwrap().signers, [validators[0].address.clone()]);
+        assert!(results[1].as_ref().unwrap_err().to_string().contains("Signature verification failed"));
+    }
+
+    #[tokio::test]
+    async fn test_governance_requires_higher_threshold() {
+        use crate::types::{BridgeAction, Governance, GOVERNANCE_MESSAGE_TYPE};
+
+        let (keypairs, validators) = setup(4);
+        let governance = CrossChainMessage {
+            nonce: 2,
+            message_type: GOVERNANCE_MESSAGE_TYPE.to_string(),
+            payload: Governance { proposal_id: 1, action: BridgeAction::Pause }.encode().unwrap(),
+            ..message()
+        };
+        let aggregator = AttestationAggregator::new(validators.clone(), None, test_chains());
+        for (keypair, validator) in keypairs.iter().zip(&validators).take(2) {
+            aggregator.submit(attest(keypair, validator, &message())).await.unwrap();
+            aggregator.submit(attest(keypair, validator, &governance)).await.unwrap();
+        }
+        // 转账需要总权重的 2/3，治理消息需要 3/4
+        let chains = test_chains();
+        assert!(aggregator.observe(&message().message_id(&chains).unwrap(), &message()).await.unwrap().reached());
+        let governance_id = governance.message_id(&chains).unwrap();
+        let progress = aggregator.observe(&governance_id, &governance).await.unwrap();
+        assert_eq!((progress.weight, progress.threshold), (2, 3));
+
+        let strict = AttestationAggregator::new(validators.clone(), None, test_chains()).with_governance_threshold(Some(4));
+        for (keypair, validator) in keypairs.iter().zip(&validators).take(3) {
+            strict.submit(attest(keypair, validator, &governance)).await.unwrap();
+        }
+        assert!(!strict.observe(&governance_id, &governance).await.unwrap().reached());
+    }
+}
@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use crate::{
+    config::Config,
+    types::{ChainId, SignedMessage},
+};
+
+/// 等待批量提交的一条消息
+#[derive(Debug, Clone)]
+pub struct BatchEntry {
+    pub message_id: String,
+    /// 观察到消息的源链
+    pub chain_id: ChainId,
+    pub message: SignedMessage,
+}
+
+/// 发往同一条链、尚未提交的消息
+struct PendingBatch {
+    /// 批次中第一条消息加入的时间
+    opened_at: u64,
+    entries: Vec<BatchEntry>,
+}
+
+/// 某条链的攒批上限
+struct BatchLimits {
+    max_size: usize,
+    flush_interval: u64,
+}
+
+/// 按目标链攒批，攒满 `max_size` 条或等待超过 `flush_interval` 后整批提交
+pub struct SubmissionBatcher {
+    configs: HashMap<ChainId, BatchLimits>,
+    batches: Mutex<HashMap<ChainId, PendingBatch>>,
+}
+
+impl SubmissionBatcher {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            configs: config.chains
+                .iter()
+                .filter_map(|chain| {
+                    let batch = chain.batch.as_ref()?;
+                    let limits = BatchLimits {
+                        max_size: config.relayer_settings(&chain.id).batch_size,
+                        flush_interval: batch.flush_interval,
+                    };
+                    Some((chain.id.clone(), limits))
+                })
+                .collect(),
+            batches: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 发往该链的消息是否批量提交
+    pub fn is_enabled(&self, chain_id: &str) -> bool {
+        self.configs.contains_key(chain_id)
+    }
+
+    /// 消息是否已在某个批次中等待提交
+    pub fn contains(&self, message_id: &str) -> bool {
+        self.batches
+            .lock()
+            .unwrap()
+            .values()
+            .any(|batch| batch.entries.iter().any(|entry| entry.message_id == message_id))
+    }
+
+    /// 将消息加入其目标链的批次，已在批次中时忽略
+    pub fn push(&self, entry: BatchEntry, now: u64) {
+        if self.contains(&entry.message_id) {
+            return;
+        }
+        let mut batches = self.batches.lock().unwrap();
+        let batch = batches
+            .entry(entry.message.message.target_chain.clone())
+            .or_insert_with(|| PendingBatch { opened_at: now, entries: Vec::new() });
+        batch.entries.push(entry);
+    }
+
+    /// 取出所有应当提交的批次，每批不超过 `max_size` 条
+    ///
+    /// 未攒满且未超时的剩余消息继续等待。
+    pub fn take_due(&self, now: u64) -> Vec<(ChainId, Vec<BatchEntry>)> {
+        let mut batches = self.batches.lock().unwrap();
+        let mut due = Vec::new();
+        for (chain_id, batch) in batches.iter_mut() {
+            let Some(config) = self.configs.get(chain_id) else {
+                continue;
+            };
+            let max_size = config.max_size.max(1);
+            while batch.entries.len() >= max_size {
+                due.push((chain_id.clone(), batch.entries.drain(..max_size).collect()));
+            }
+            if !batch.entries.is_empty() && now >= batch.opened_at + config.flush_interval {
+                due.push((chain_id.clone(), std::mem::take(&mut batch.entries)));
+            }
+        }
+        batches.retain(|_, batch| !batch.entries.is_empty());
+        due
+    }
+
+    /// 放回暂时无法提交的批次，下一次到期检查时优先提交
+    pub fn requeue(&self, chain_id: &ChainId, mut entries: Vec<BatchEntry>, now: u64) {
+        let mut batches = self.batches.lock().unwrap();
+        let batch = batches
+            .entry(chain_id.clone())
+            .or_insert_with(|| PendingBatch { opened_at: now, entries: Vec::new() });
+        entries.append(&mut batch.entries);
+        batch.entries = entries;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain_adapter::mock::MockOperation;
+    use crate::config::BatchConfig;
+    use crate::testing::{transfer_payload, two_chain_config, TwoChainHarness, SOURCE_CHAIN, TARGET_CHAIN};
+    use crate::Error;
+
+    #[tokio::test]
+    async fn test_batches_flush_on_size_and_interval() {
+        let mut config = two_chain_config();
+        config.chains[1].batch = Some(BatchConfig { max_size: Some(2), flush_interval: 3_600 });
+        let mut harness = TwoChainHarness::with_config(config).await.unwrap();
+        for amount in [100, 200, 300] {
+            harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([1u8; 32], amount));
+        }
+        harness.tick().await;
+
+        // 攒满的两条在同一笔交易中提交，第三条等待后续消息或超时
+        let transactions = harness.target.transactions();
+        assert_eq!(transactions.len(), 2);
+        assert_eq!(transactions[0].0, transactions[1].0);
+        assert_eq!(harness.backlog(), 1);
+        harness.tick().await;
+        assert_eq!(harness.target.transactions().len(), 2);
+
+        let batcher = SubmissionBatcher::new(&harness_config(0));
+        let message = harness.target.submitted()[0].clone();
+        batcher.push(BatchEntry { message_id: "a".to_string(), chain_id: SOURCE_CHAIN.into(), message: message.clone() }, 10);
+        batcher.push(BatchEntry { message_id: "a".to_string(), chain_id: SOURCE_CHAIN.into(), message }, 10);
+        assert!(batcher.contains("a"));
+        assert!(batcher.take_due(9).is_empty());
+        let due = batcher.take_due(10);
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].1.len(), 1);
+        assert!(!batcher.contains("a"));
+
+        // 合约拒绝的批次记为失败，之后各轮不再提交
+        let mut config = two_chain_config();
+        config.chains[1].batch = Some(BatchConfig { max_size: Some(1), flush_interval: 3_600 });
+        let mut harness = TwoChainHarness::with_config(config.clone()).await.unwrap();
+        harness.target.fail_next(MockOperation::SubmitBatch, Error::InvalidMessage("message::EINVALID_PAYLOAD".to_string()));
+        harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([1u8; 32], 100));
+        harness.run(2).await;
+        assert!(harness.target.transactions().is_empty());
+        assert_eq!(harness.backlog(), 0);
+
+        // 批量提交的消息处理完毕后，源链游标随之前进
+        let sent = harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([1u8; 32], 200));
+        let message_id = sent.message.message_id(&harness.relayer.chains).unwrap();
+        let source: ChainId = SOURCE_CHAIN.into();
+        let cursors = |position: u64| HashMap::from([("message".to_string(), position)]);
+        assert_eq!(harness.relayer.cursor_commits.observe(&source, cursors(1), [message_id]), None);
+        harness.tick().await;
+        assert_eq!(harness.target.transactions().len(), 1);
+        assert_eq!(harness.relayer.cursor_commits.observe(&source, cursors(2), []), Some(cursors(2)));
+
+        // 与逐条提交一致，单条批次被合约判定为已执行时视为已送达
+        let mut harness = TwoChainHarness::with_config(config).await.unwrap();
+        harness.target.fail_next(MockOperation::SubmitBatch, Error::AlreadyProcessed("message::EALREADY_PROCESSED".to_string()));
+        harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([1u8; 32], 100));
+        harness.run(2).await;
+        assert!(harness.target.transactions().is_empty());
+        assert_eq!(harness.backlog(), 0);
+        let metrics = harness.relayer.metrics().export().unwrap();
+        assert!(metrics.contains("bridge_submissions_skipped_total{chain=\"mock_target\"} 1"));
+        assert!(!metrics.contains("bridge_messages_failed_total"));
+    }
+
+    fn harness_config(flush_interval: u64) -> Config {
+        let mut config = two_chain_config();
+        config.relayer.batch_size = 4;
+        config.chains[1].batch = Some(BatchConfig { max_size: None, flush_interval });
+        config
+    }
+}
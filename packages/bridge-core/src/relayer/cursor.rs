@@ -0,0 +1,86 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Mutex;
+use crate::types::ChainId;
+
+/// 源链事件游标的提交进度
+///
+/// 每轮读取后的游标与本轮读到的消息一起登记，这些消息全部处理完毕、结果写入存储后游标才可保存；
+/// 重启后从保存的游标继续读取，尚未处理完毕的消息会被重新读到。
+#[derive(Default)]
+pub struct CursorCommits {
+    chains: Mutex<HashMap<ChainId, VecDeque<Snapshot>>>,
+}
+
+/// 一轮读取后的游标及本轮读到、尚未处理完毕的消息
+struct Snapshot {
+    cursors: HashMap<String, u64>,
+    outstanding: HashSet<String>,
+}
+
+impl CursorCommits {
+    /// 登记一轮读取后的游标，返回因此可以保存的游标
+    pub fn observe(&self, chain_id: &ChainId, cursors: HashMap<String, u64>, message_ids: impl IntoIterator<Item = String>) -> Option<HashMap<String, u64>> {
+        if cursors.is_empty() {
+            return None;
+        }
+        let mut chains = self.chains.lock().unwrap();
+        let snapshots = chains.entry(chain_id.clone()).or_default();
+        snapshots.push_back(Snapshot { cursors, outstanding: message_ids.into_iter().collect() });
+        committable(snapshots)
+    }
+
+    /// 消息已处理完毕，返回因此可以保存游标的链及其游标
+    pub fn finish(&self, message_id: &str) -> Vec<(ChainId, HashMap<String, u64>)> {
+        let mut chains = self.chains.lock().unwrap();
+        let mut ready = Vec::new();
+        for (chain_id, snapshots) in chains.iter_mut() {
+            let mut found = false;
+            for snapshot in snapshots.iter_mut() {
+                found |= snapshot.outstanding.remove(message_id);
+            }
+            if let Some(cursors) = found.then(|| committable(snapshots)).flatten() {
+                ready.push((chain_id.clone(), cursors));
+            }
+        }
+        ready
+    }
+}
+
+/// 移出最前面连续已处理完毕的读取，返回其中最新的游标
+fn committable(snapshots: &mut VecDeque<Snapshot>) -> Option<HashMap<String, u64>> {
+    let mut latest = None;
+    while snapshots.front().is_some_and(|snapshot| snapshot.outstanding.is_empty()) {
+        latest = snapshots.pop_front().map(|snapshot| snapshot.cursors);
+    }
+    latest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cursors(position: u64) -> HashMap<String, u64> {
+        HashMap::from([("message".to_string(), position)])
+    }
+
+    #[test]
+    fn test_cursor_waits_for_earlier_messages() {
+        let commits = CursorCommits::default();
+        let chain: ChainId = "rooch_testnet".into();
+
+        assert_eq!(commits.observe(&chain, cursors(10), ["m1".to_string(), "m2".to_string()]), None);
+        assert_eq!(commits.observe(&chain, cursors(20), ["m3".to_string()]), None);
+        // 没有新消息的读取排在未处理完毕的读取之后
+        assert_eq!(commits.observe(&chain, cursors(25), []), None);
+
+        // 后一轮的消息先处理完毕时，游标仍停在前一轮之前
+        assert!(commits.finish("m3").is_empty());
+        assert!(commits.finish("m1").is_empty());
+        assert_eq!(commits.finish("m2"), vec![(chain.clone(), cursors(25))]);
+
+        assert_eq!(commits.observe(&chain, cursors(30), []), Some(cursors(30)));
+        // 不按游标读取的适配器不登记
+        assert_eq!(commits.observe(&chain, HashMap::new(), ["m4".to_string()]), None);
+        assert!(commits.finish("m4").is_empty());
+    }
+}
@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use crate::{
+    keystore::verify_signature,
+    types::{Attestation, ChainId, NumericChainIds},
+    Error,
+};
+
+/// 已签名证明的保留时间（秒），超过后不再用于检测双签
+const SIGNED_RETENTION: u64 = 7 * 24 * 3600;
+
+/// 双签证据：同一验证者对同一源链、同一 nonce 的两条不同消息签名
+///
+/// 两条证明都带有验证者的签名，任何人都可以据此独立验证，提交到支持罚没的链上合约。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EquivocationEvidence {
+    pub validator: String,
+    pub source_chain: ChainId,
+    pub nonce: u64,
+    /// 先收到的证明
+    pub first: Attestation,
+    /// 与之冲突的证明
+    pub second: Attestation,
+    pub detected_at: u64,
+}
+
+impl EquivocationEvidence {
+    /// 证据 ID：每个验证者在同一源链的同一 nonce 上只记录一份证据
+    pub fn evidence_id(&self) -> String {
+        format!("{}:{}:{}", self.validator, self.source_chain, self.nonce)
+    }
+
+    /// 校验两条证明确实构成双签：同一验证者、同一 nonce、消息不同且签名均有效
+    pub fn verify(&self, chains: &impl NumericChainIds) -> Result<(), Error> {
+        for attestation in [&self.first, &self.second] {
+            if attestation.validator != self.validator {
+                return Err(Error::Key(format!("Attestation from {} in evidence against {}", attestation.validator, self.validator)));
+            }
+            let message = &attestation.message;
+            if message.source_chain != self.source_chain || message.nonce != self.nonce {
+                return Err(Error::Key(format!("Attestation {} is not for nonce {} of {}", attestation.message_id, self.nonce, self.source_chain)));
+            }
+            let hash = message.canonical_hash(chains)?;
+            if hex::encode(hash) != attestation.message_id {
+                return Err(Error::Key(format!("Message ID mismatch in attestation {}", attestation.message_id)));
+            }
+            let public_key = hex::decode(attestation.public_key.trim_start_matches("0x"))
+                .map_err(|e| Error::Key(format!("Invalid public key for {}: {}", self.validator, e)))?;
+            verify_signature(&public_key, &hash, &attestation.signature)?;
+        }
+        if self.first.message_id == self.second.message_id {
+            return Err(Error::Key("Evidence attestations sign the same message".to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// 双签检测器
+///
+/// 记录每个验证者对每个源链 nonce 签过的第一条证明，之后收到对同一 nonce 不同消息的证明即为双签。
+/// 证明须已通过签名校验后再交给检测器。
+#[derive(Default)]
+pub struct EquivocationDetector {
+    signed: Mutex<HashMap<(String, ChainId, u64), Attestation>>,
+    evidence: Mutex<Vec<EquivocationEvidence>>,
+    /// 尚未被中继器取走处理的证据
+    unreported: Mutex<Vec<EquivocationEvidence>>,
+}
+
+impl EquivocationDetector {
+    /// 检查一条证明，与先前证明冲突时返回证据
+    ///
+    /// 每个验证者在同一 nonce 上只记录第一份证据，后续的冲突证明仍返回证据但不重复记录。
+    pub fn check(&self, attestation: &Attestation, now: u64) -> Option<EquivocationEvidence> {
+        let key = (
+            attestation.validator.clone(),
+            attestation.message.source_chain.clone(),
+            attestation.message.nonce,
+        );
+        let mut signed = self.signed.lock().unwrap();
+        let first = match signed.get(&key) {
+            Some(first) if first.message_id != attestation.message_id => first.clone(),
+            Some(_) => return None,
+            None => {
+                signed.retain(|_, a| a.timestamp.saturating_add(SIGNED_RETENTION) >= now);
+                signed.insert(key, attestation.clone());
+                return None;
+            }
+        };
+        drop(signed);
+
+        let evidence = EquivocationEvidence {
+            validator: attestation.validator.clone(),
+            source_chain: attestation.message.source_chain.clone(),
+            nonce: attestation.message.nonce,
+            first,
+            second: attestation.clone(),
+            detected_at: now,
+        };
+        let mut recorded = self.evidence.lock().unwrap();
+        if !recorded.iter().any(|e| e.evidence_id() == evidence.evidence_id()) {
+            recorded.push(evidence.clone());
+            self.unreported.lock().unwrap().push(evidence.clone());
+        }
+        Some(evidence)
+    }
+
+    /// 恢复存储中已有的证据，恢复的证据不会再次上报
+    pub fn restore(&self, evidence: Vec<EquivocationEvidence>) {
+        let mut recorded = self.evidence.lock().unwrap();
+        for item in evidence {
+            if !recorded.iter().any(|e| e.evidence_id() == item.evidence_id()) {
+                recorded.push(item);
+            }
+        }
+    }
+
+    /// 已记录的全部证据，按发现顺序排列
+    pub fn evidence(&self) -> Vec<EquivocationEvidence> {
+        self.evidence.lock().unwrap().clone()
+    }
+
+    /// 取出上次调用以来新发现的证据
+    pub fn take_unreported(&self) -> Vec<EquivocationEvidence> {
+        std::mem::take(&mut *self.unreported.lock().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::CrossChainMessage;
+    use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+
+    fn chains() -> HashMap<u64, ChainId> {
+        HashMap::from([(1, ChainId::from("sui")), (2, ChainId::from("rooch"))])
+    }
+
+    fn attest(keypair: &Keypair, payload: Vec<u8>) -> Attestation {
+        let message = CrossChainMessage {
+            nonce: 7,
+            source_chain: "sui".into(),
+            target_chain: "rooch".into(),
+            message_type: "transfer".to_string(),
+            payload,
+        };
+        let hash = message.canonical_hash(&chains()).unwrap();
+        Attestation {
+            message_id: hex::encode(hash),
+            message,
+            validator: "0xv1".to_string(),
+            public_key: hex::encode(keypair.public.to_bytes()),
+            signature: keypair.sign(&hash).to_bytes().to_vec(),
+            timestamp: 100,
+        }
+    }
+
+    #[test]
+    fn test_conflicting_attestations_produce_evidence() {
+        let secret = SecretKey::from_bytes(&[3u8; 32]).unwrap();
+        let public = PublicKey::from(&secret);
+        let keypair = Keypair { secret, public };
+        let detector = EquivocationDetector::default();
+
+        let honest = attest(&keypair, vec![1]);
+        assert!(detector.check(&honest, 100).is_none());
+        assert!(detector.check(&honest, 101).is_none());
+
+        let conflicting = attest(&keypair, vec![2]);
+        let evidence = detector.check(&conflicting, 102).unwrap();
+        evidence.verify(&chains()).unwrap();
+        assert_eq!(evidence.evidence_id(), "0xv1:sui:7");
+        assert_eq!(evidence.first, honest);
+
+        // 同一 nonce 的第三条冲突证明同样被识别，但不重复记录
+        assert!(detector.check(&attest(&keypair, vec![3]), 103).is_some());
+        assert_eq!(detector.take_unreported(), vec![evidence.clone()]);
+        assert!(detector.take_unreported().is_empty());
+        assert_eq!(detector.evidence().len(), 1);
+
+        let mut forged = evidence;
+        forged.second.signature = forged.first.signature.clone();
+        assert!(forged.verify(&chains()).is_err());
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{
+    config::Config,
+    ledger::Transfer,
+    types::{CrossChainMessage, Refund, SignedMessage, REFUND_MESSAGE_TYPE, REFUND_NONCE_FLAG},
+    Error,
+};
+
+/// 消息自源链时间戳起超过 `max_age` 秒仍未中继即为过期
+pub fn is_expired(message: &SignedMessage, max_age: u64, now: u64) -> bool {
+    now.saturating_sub(message.timestamp) > max_age
+}
+
+/// 为过期的转账构造发回源链的退款消息，非转账消息返回 None
+///
+/// 退款给源链上的发起账户，金额为原转账的全部金额（源链精度，未扣手续费）。
+/// 退款消息使用独立的 nonce 空间（见 [`REFUND_NONCE_FLAG`]），源链合约按 `original_message_id` 拒绝重复退款。
+/// 适配器未提供发起账户时无法退款，返回错误，由运营方人工处理。
+pub fn refund_message(config: &Config, message_id: &str, message: &SignedMessage, now: u64) -> Result<Option<SignedMessage>, Error> {
+    let Some(transfer) = Transfer::parse(config, message_id, &message.message) else {
+        return Ok(None);
+    };
+    let sender = message.sender
+        .as_deref()
+        .ok_or_else(|| Error::Rejected(format!("Expired transfer {} has no known sender to refund", message_id)))?;
+    if message.message.nonce & REFUND_NONCE_FLAG != 0 {
+        return Err(Error::Rejected(format!("Expired transfer {} has a nonce in the refund nonce space", message_id)));
+    }
+    let recipient = hex::decode(sender.trim_start_matches("0x"))
+        .map_err(|e| Error::Rejected(format!("Cannot refund expired transfer {} to sender {}: {}", message_id, sender, e)))?;
+
+    let refund = Refund {
+        original_message_id: message.message.canonical_hash(&config.chain_registry()?)?.to_vec(),
+        recipient,
+        amount: transfer.amount,
+    };
+    Ok(Some(SignedMessage {
+        message: CrossChainMessage {
+            nonce: REFUND_NONCE_FLAG | message.message.nonce,
+            source_chain: message.message.target_chain.clone(),
+            target_chain: message.message.source_chain.clone(),
+            message_type: REFUND_MESSAGE_TYPE.to_string(),
+            payload: refund.encode()?,
+        },
+        signature: Vec::new(),
+        timestamp: now,
+        event_name: None,
+        sender: None,
+        provenance: None,
+        proof: None,
+        trace_id: None,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{test_chains, transfer_payload, TwoChainHarness, SOURCE_CHAIN, TARGET_CHAIN};
+    use crate::types::MessageStatus;
+
+    fn expired(nonce: u64, message_type: &str, sender: Option<&str>) -> SignedMessage {
+        SignedMessage {
+            message: CrossChainMessage {
+                nonce,
+                source_chain: SOURCE_CHAIN.into(),
+                target_chain: TARGET_CHAIN.into(),
+                message_type: message_type.to_string(),
+                payload: transfer_payload([1u8; 32], 750),
+            },
+            signature: Vec::new(),
+            timestamp: 1,
+            event_name: None,
+            sender: sender.map(str::to_string),
+            provenance: None,
+            proof: None,
+            trace_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_expired_transfer_is_refunded() {
+        let mut harness = TwoChainHarness::new().await.unwrap();
+        let transfer = expired(1, "transfer", Some("0x0202"));
+        harness.source.emit(transfer.clone());
+        harness.source.emit(expired(2, "ping", None));
+        harness.tick().await;
+
+        // 过期消息不再提交到目标链，转账向源链发出退款
+        assert!(harness.target.submitted().is_empty());
+        assert_eq!(harness.backlog(), 0);
+        let refunds = harness.source.submitted();
+        assert_eq!(refunds.len(), 1);
+        assert_eq!(refunds[0].message.message_type, REFUND_MESSAGE_TYPE);
+        assert_eq!(refunds[0].message.target_chain, SOURCE_CHAIN);
+        assert_eq!(refunds[0].message.nonce, REFUND_NONCE_FLAG | 1);
+        let refund = Refund::decode(&refunds[0].message.payload).unwrap();
+        assert_eq!(refund.recipient, vec![2, 2]);
+        assert_eq!(refund.amount, 750);
+        assert_eq!(refund.original_message_id, transfer.message.canonical_hash(&test_chains()).unwrap().to_vec());
+
+        assert!(is_expired(&transfer, 3_600, 3_602));
+        assert!(!is_expired(&transfer, 3_600, 3_601));
+        let config = crate::testing::two_chain_config();
+        let no_sender = expired(3, "transfer", None);
+        assert!(matches!(refund_message(&config, "id", &no_sender, 10), Err(Error::Rejected(_))));
+        assert_eq!("expired".parse::<MessageStatus>().unwrap(), MessageStatus::Expired);
+    }
+
+    #[tokio::test]
+    async fn test_expired_transfer_already_executed_is_not_refunded() {
+        let mut harness = TwoChainHarness::new().await.unwrap();
+        let transfer = expired(1, "transfer", Some("0x0202"));
+        // 原转账在过期前已在目标链执行，例如提交后卡住、重试期间才上链
+        let submitted = crate::ledger::submitted_message(&crate::testing::two_chain_config(), &transfer.message).unwrap();
+        harness.target.execute_external(SignedMessage { message: submitted, ..transfer.clone() });
+        harness.source.emit(transfer);
+        harness.tick().await;
+
+        assert_eq!(harness.backlog(), 0);
+        assert!(harness.source.submitted().is_empty());
+    }
+}
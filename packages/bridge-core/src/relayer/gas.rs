@@ -0,0 +1,159 @@
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Mutex;
+use crate::{
+    config::Config,
+    types::ChainId,
+    Error,
+};
+use super::scheduler::SubmissionWindow;
+
+/// 单条链的提交成本上限
+struct CostPolicy {
+    max_cost: u64,
+    gas_units: u64,
+    low_fee_windows: Vec<SubmissionWindow>,
+}
+
+/// 成本检查的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CostDecision {
+    /// 未配置上限、价格未知、预估成本不超过上限或处于低费用窗口
+    Submit,
+    /// 预估成本超过上限，`first` 表示消息刚进入低费用队列
+    Defer { estimated: u128, cap: u64, first: bool },
+}
+
+/// 各链最近一次查询到的 gas 价格，以及因成本超限而推迟的消息
+///
+/// 推迟的消息仍保留在中继器的待处理队列中，每轮重新检查；
+/// gas 价格回落到上限以内或进入低费用窗口后按观察顺序提交。
+pub struct GasPriceOracle {
+    policies: HashMap<ChainId, CostPolicy>,
+    prices: Mutex<HashMap<ChainId, u64>>,
+    /// 各链低费用队列中的消息 ID
+    deferred: Mutex<HashMap<ChainId, BTreeSet<String>>>,
+}
+
+impl GasPriceOracle {
+    pub fn new(config: &Config) -> Result<Self, Error> {
+        let mut policies = HashMap::new();
+        for chain in &config.chains {
+            let Some(submission) = &chain.submission else {
+                continue;
+            };
+            let Some(max_cost) = submission.max_relay_cost else {
+                continue;
+            };
+            policies.insert(chain.id.clone(), CostPolicy {
+                max_cost,
+                gas_units: submission.gas_units_per_relay,
+                low_fee_windows: submission.low_fee_windows
+                    .iter()
+                    .map(|w| SubmissionWindow::parse(w))
+                    .collect::<Result<_, _>>()?,
+            });
+        }
+        Ok(Self {
+            policies,
+            prices: Mutex::new(HashMap::new()),
+            deferred: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// 记录查询到的 gas 价格，适配器无法提供时为 None
+    pub fn record(&self, chain_id: &ChainId, price: Option<u64>) {
+        let mut prices = self.prices.lock().unwrap();
+        match price {
+            Some(price) => prices.insert(chain_id.clone(), price),
+            None => prices.remove(chain_id),
+        };
+    }
+
+    pub fn price(&self, chain_id: &str) -> Option<u64> {
+        self.prices.lock().unwrap().get(chain_id).copied()
+    }
+
+    /// 按当前价格估算一次中继的成本，未配置上限或价格未知时为 None
+    pub fn estimate(&self, chain_id: &str) -> Option<u128> {
+        let policy = self.policies.get(chain_id)?;
+        Some(self.price(chain_id)? as u128 * policy.gas_units as u128)
+    }
+
+    /// 检查发往 `chain_id` 的消息能否立即提交，不能提交时将其放入低费用队列
+    pub fn check(&self, chain_id: &str, message_id: &str, now: u64) -> CostDecision {
+        let mut deferred = self.deferred.lock().unwrap();
+        let decision = match (self.policies.get(chain_id), self.estimate(chain_id)) {
+            (Some(policy), Some(estimated))
+                if estimated > policy.max_cost as u128
+                    && !policy.low_fee_windows.iter().any(|w| w.is_open(now)) =>
+            {
+                let first = deferred.entry(chain_id.into()).or_default().insert(message_id.to_string());
+                CostDecision::Defer { estimated, cap: policy.max_cost, first }
+            }
+            _ => CostDecision::Submit,
+        };
+        if decision == CostDecision::Submit {
+            if let Some(queue) = deferred.get_mut(chain_id) {
+                queue.remove(message_id);
+            }
+        }
+        decision
+    }
+
+    /// 低费用队列中发往该链的消息数
+    pub fn deferred(&self, chain_id: &str) -> usize {
+        self.deferred.lock().unwrap().get(chain_id).map_or(0, BTreeSet::len)
+    }
+
+    /// 消息已处理完毕或被丢弃时移出低费用队列
+    pub fn forget(&self, message_id: &str) {
+        for queue in self.deferred.lock().unwrap().values_mut() {
+            queue.remove(message_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SubmissionConfig;
+    use crate::testing::{two_chain_config, TARGET_CHAIN};
+
+    #[test]
+    fn test_cost_cap_defers_until_price_drops() {
+        let mut config = two_chain_config();
+        config.chains[1].submission = Some(SubmissionConfig {
+            windows: vec![],
+            max_per_block: None,
+            block_time: 1,
+            max_relay_cost: Some(10_000_000),
+            gas_units_per_relay: 10_000,
+            low_fee_windows: vec!["02:00-04:00".to_string()],
+        });
+        let oracle = GasPriceOracle::new(&config).unwrap();
+        let target: ChainId = TARGET_CHAIN.into();
+        let noon = 12 * 3600;
+
+        // 价格未知时无法估算，直接提交
+        assert_eq!(oracle.check(TARGET_CHAIN, "m1", noon), CostDecision::Submit);
+
+        oracle.record(&target, Some(2_000));
+        assert_eq!(oracle.estimate(TARGET_CHAIN), Some(20_000_000));
+        assert_eq!(
+            oracle.check(TARGET_CHAIN, "m1", noon),
+            CostDecision::Defer { estimated: 20_000_000, cap: 10_000_000, first: true }
+        );
+        assert!(matches!(oracle.check(TARGET_CHAIN, "m1", noon), CostDecision::Defer { first: false, .. }));
+        assert!(matches!(oracle.check(TARGET_CHAIN, "m2", noon + 1), CostDecision::Defer { first: true, .. }));
+        assert_eq!(oracle.deferred(TARGET_CHAIN), 2);
+
+        // 低费用窗口内不检查上限
+        assert_eq!(oracle.check(TARGET_CHAIN, "m2", 3 * 3600), CostDecision::Submit);
+        assert_eq!(oracle.deferred(TARGET_CHAIN), 1);
+
+        oracle.record(&target, Some(1_000));
+        assert_eq!(oracle.check(TARGET_CHAIN, "m1", noon), CostDecision::Submit);
+        assert_eq!(oracle.deferred(TARGET_CHAIN), 0);
+        assert_eq!(oracle.check("other", "m3", noon), CostDecision::Submit);
+    }
+}
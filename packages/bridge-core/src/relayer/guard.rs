@@ -0,0 +1,207 @@
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use crate::{
+    config::{Config, ValidatorSetGuardConfig},
+    types::{CrossChainMessage, ValidatorSetUpdate, VALIDATOR_SET_UPDATE_MESSAGE_TYPE},
+    Error,
+};
+
+/// 等待人工批准的验证者集合更新
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HeldUpdate {
+    pub message_id: String,
+    pub epoch: u64,
+    /// 相对纪元起始集合的权重变更比例
+    pub weight_change: f64,
+}
+
+/// 检查结果
+#[derive(Debug, Clone, PartialEq)]
+pub enum GuardDecision {
+    Allow,
+    /// 变更超出限制，等待人工批准；`first` 表示本次为首次暂缓
+    Hold { reason: String, first: bool },
+}
+
+struct GuardState {
+    epoch: u64,
+    /// 当前纪元开始前的集合，同一纪元内的多次更新累计与其比较
+    base: HashMap<String, u64>,
+    /// 最近放行的集合
+    current: HashMap<String, u64>,
+    approved: HashSet<String>,
+    held: HashMap<String, HeldUpdate>,
+}
+
+/// 验证者集合更新的变更幅度限制
+///
+/// 单个纪元内的权重变更超过总权重的配置比例时暂缓中继，避免一条恶意治理消息
+/// 直接替换整个验证者集合。暂缓的更新经人工批准后放行。
+pub struct ValidatorSetGuard {
+    max_weight_change: f64,
+    state: Mutex<GuardState>,
+}
+
+impl ValidatorSetGuard {
+    pub fn new(config: &Config, guard: &ValidatorSetGuardConfig) -> Self {
+        let validators: HashMap<_, _> = config.validators
+            .iter()
+            .map(|v| (v.address.clone(), v.weight))
+            .collect();
+        Self {
+            max_weight_change: guard.max_weight_change,
+            state: Mutex::new(GuardState {
+                epoch: config.relayer.validator_set_epoch,
+                base: validators.clone(),
+                current: validators,
+                approved: guard.approved_updates.iter().cloned().collect(),
+                held: HashMap::new(),
+            }),
+        }
+    }
+
+    /// 检查验证者集合更新，其它类型的消息直接放行
+    ///
+    /// 负载无效或纪元早于当前纪元时返回错误，这类消息不会因人工批准而变得有效。
+    pub fn check(&self, message_id: &str, message: &CrossChainMessage) -> Result<GuardDecision, Error> {
+        if message.message_type != VALIDATOR_SET_UPDATE_MESSAGE_TYPE {
+            return Ok(GuardDecision::Allow);
+        }
+        let update = ValidatorSetUpdate::decode(&message.payload)?;
+        let mut state = self.state.lock().unwrap();
+        if update.epoch < state.epoch {
+            return Err(Error::Rejected(format!(
+                "Validator set update for epoch {} is older than current epoch {}",
+                update.epoch, state.epoch
+            )));
+        }
+
+        let base = if update.epoch == state.epoch { &state.base } else { &state.current };
+        let weight_change = weight_change(base, &update);
+        if weight_change > self.max_weight_change && !state.approved.contains(message_id) {
+            let first = !state.held.contains_key(message_id);
+            state.held.insert(message_id.to_string(), HeldUpdate {
+                message_id: message_id.to_string(),
+                epoch: update.epoch,
+                weight_change,
+            });
+            return Ok(GuardDecision::Hold {
+                reason: format!(
+                    "Validator set update for epoch {} changes {:.1}% of voting weight, limit is {:.1}%",
+                    update.epoch,
+                    weight_change * 100.0,
+                    self.max_weight_change * 100.0
+                ),
+                first,
+            });
+        }
+
+        // 放行后以新集合为准，进入新纪元时把上一纪元的集合作为比较基准
+        if update.epoch > state.epoch {
+            state.base = std::mem::take(&mut state.current);
+            state.epoch = update.epoch;
+        }
+        state.current = update.validators.iter().map(|v| (v.address.clone(), v.weight)).collect();
+        state.held.remove(message_id);
+        Ok(GuardDecision::Allow)
+    }
+
+    /// 人工批准暂缓的更新，返回该消息此前是否处于暂缓状态
+    pub fn approve(&self, message_id: &str) -> bool {
+        let mut state = self.state.lock().unwrap();
+        state.approved.insert(message_id.to_string());
+        state.held.contains_key(message_id)
+    }
+
+    /// 等待人工批准的更新
+    pub fn held(&self) -> Vec<HeldUpdate> {
+        let mut held: Vec<_> = self.state.lock().unwrap().held.values().cloned().collect();
+        held.sort_by(|a, b| (a.epoch, &a.message_id).cmp(&(b.epoch, &b.message_id)));
+        held
+    }
+}
+
+/// 权重变更比例：新增与移除权重中的较大者除以原集合总权重
+///
+/// 整体替换验证者集合时为 1。
+fn weight_change(base: &HashMap<String, u64>, update: &ValidatorSetUpdate) -> f64 {
+    let total: u64 = base.values().sum();
+    if total == 0 {
+        return 1.0;
+    }
+    let mut next: HashMap<&str, u64> = HashMap::new();
+    for validator in &update.validators {
+        *next.entry(validator.address.as_str()).or_default() += validator.weight;
+    }
+
+    let removed: u64 = base
+        .iter()
+        .map(|(address, weight)| weight.saturating_sub(next.get(address.as_str()).copied().unwrap_or(0)))
+        .sum();
+    let added: u64 = next
+        .iter()
+        .map(|(address, weight)| weight.saturating_sub(base.get(*address).copied().unwrap_or(0)))
+        .sum();
+    (removed.max(added) as f64 / total as f64).min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ValidatorWeight;
+
+    fn base() -> HashMap<String, u64> {
+        [("a", 40), ("b", 30), ("c", 30)].into_iter().map(|(a, w)| (a.to_string(), w)).collect()
+    }
+
+    fn update(epoch: u64, validators: &[(&str, u64)]) -> CrossChainMessage {
+        CrossChainMessage {
+            nonce: epoch,
+            source_chain: "sui".into(),
+            target_chain: "rooch".into(),
+            message_type: VALIDATOR_SET_UPDATE_MESSAGE_TYPE.to_string(),
+            payload: ValidatorSetUpdate {
+                epoch,
+                validators: validators
+                    .iter()
+                    .map(|(address, weight)| ValidatorWeight { address: address.to_string(), weight: *weight })
+                    .collect(),
+            }
+            .encode()
+            .unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_guard_holds_large_changes_until_approved() {
+        let base = base();
+        let guard = ValidatorSetGuard {
+            max_weight_change: 0.34,
+            state: Mutex::new(GuardState {
+                epoch: 1,
+                current: base.clone(),
+                base,
+                approved: HashSet::new(),
+                held: HashMap::new(),
+            }),
+        };
+
+        // 替换 c：变更 30%，放行
+        let rotate = update(2, &[("a", 40), ("b", 30), ("d", 30)]);
+        assert_eq!(guard.check("rotate", &rotate).unwrap(), GuardDecision::Allow);
+
+        // 同一纪元内再替换 b：相对纪元起始集合累计变更 60%，暂缓
+        let second = update(2, &[("a", 40), ("e", 30), ("d", 30)]);
+        assert!(matches!(guard.check("second", &second).unwrap(), GuardDecision::Hold { first: true, .. }));
+        assert!(matches!(guard.check("second", &second).unwrap(), GuardDecision::Hold { first: false, .. }));
+        assert_eq!(guard.held()[0].message_id, "second");
+
+        assert!(guard.approve("second"));
+        assert_eq!(guard.check("second", &second).unwrap(), GuardDecision::Allow);
+        assert!(guard.held().is_empty());
+
+        // 旧纪元的更新直接拒绝
+        assert!(guard.check("stale", &update(1, &[("a", 1)])).is_err());
+    }
+}
@@ -0,0 +1,587 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::process::Stdio;
+use std::sync::{Arc, LazyLock, RwLock};
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+use tracing::{debug, warn};
+use crate::{
+    config::{AssetConfig, ChainConfig, Config, EventFilter, NftCollectionConfig},
+    confidential,
+    types::{ChainId, Governance, NftTransfer, SignedMessage, GOVERNANCE_MESSAGE_TYPE, NFT_TRANSFER_MESSAGE_TYPE},
+    Error,
+};
+
+/// 通用消息事件处理器名称，兼容现有配置中的 `handle_message_event`
+pub const MESSAGE_EVENT_HANDLER: &str = "handle_message_event";
+/// 资产跨链转账处理器名称
+pub const ASSET_TRANSFER_HANDLER: &str = "asset_transfer";
+/// 治理消息处理器名称
+pub const GOVERNANCE_HANDLER: &str = "governance";
+/// NFT 跨链处理器名称
+pub const NFT_HANDLER: &str = "nft";
+/// 机密消息处理器名称
+pub const CONFIDENTIAL_HANDLER: &str = "confidential";
+/// 自定义脚本处理器前缀，格式为 `script:<可执行文件路径>`
+pub const SCRIPT_HANDLER_PREFIX: &str = "script:";
+/// 未按配置创建的注册表中 `script:` 处理器的运行时限（秒）
+const DEFAULT_SCRIPT_TIMEOUT: u64 = 30;
+
+/// 内置处理器名称，不能被第三方注册覆盖
+const BUILTIN_HANDLERS: &[&str] = &[
+    MESSAGE_EVENT_HANDLER, ASSET_TRANSFER_HANDLER, GOVERNANCE_HANDLER, NFT_HANDLER, CONFIDENTIAL_HANDLER,
+];
+
+/// 第三方处理器的构造函数，每次创建注册表时按配置调用
+pub type HandlerBuilder = Arc<dyn Fn(&Config) -> Result<Arc<dyn EventHandler>, Error> + Send + Sync>;
+
+/// 第三方注册的处理器构造函数，按处理器名称索引
+static PLUGINS: LazyLock<RwLock<HashMap<String, HandlerBuilder>>> = LazyLock::new(Default::default);
+
+/// 注册第三方事件处理器，`name` 对应事件过滤器中的 `handler`
+///
+/// 须在创建中继器或守望者之前调用，之后 [`HandlerRegistry::with_defaults`] 用 `builder` 创建该处理器。
+/// 内置处理器与 `script:` 前缀不能被占用；重复注册同一名称时后注册的生效。
+pub fn register_handler(name: &str, builder: HandlerBuilder) -> Result<(), Error> {
+    if name.is_empty() || name.starts_with(SCRIPT_HANDLER_PREFIX) {
+        return Err(Error::Config(format!("Invalid event handler name: '{}'", name)));
+    }
+    if BUILTIN_HANDLERS.contains(&name) {
+        return Err(Error::Config(format!("Event handler {} is built in and cannot be replaced", name)));
+    }
+    PLUGINS.write().unwrap().insert(name.to_string(), builder);
+    Ok(())
+}
+
+/// 链上事件处理器
+///
+/// 每个 `EventFilter.handler` 对应一个处理器，决定该类事件产生的消息
+/// 是否进入中继流程，以及进入前需要做的处理。
+#[async_trait]
+pub trait EventHandler: Send + Sync {
+    /// 处理器名称，与配置中的 `handler` 字段对应
+    fn name(&self) -> &str;
+
+    /// 处理事件消息，返回需要中继的消息；返回 None 表示丢弃该消息
+    async fn handle(&self, message: SignedMessage) -> Result<Option<SignedMessage>, Error>;
+}
+
+/// 按消息类型解码负载后处理的处理器
+///
+/// 只处理 `message_type` 类型的消息，其它类型的消息被丢弃；负载解码失败时返回错误。
+/// 通过 [`Typed`] 包装后作为 [`EventHandler`] 注册。
+#[async_trait]
+pub trait TypedEventHandler: Send + Sync {
+    /// 负载解码后的类型
+    type Event: Send;
+
+    /// 处理器名称，与配置中的 `handler` 字段对应
+    fn name(&self) -> &str;
+
+    /// 处理的消息类型
+    fn message_type(&self) -> &str;
+
+    fn decode(&self, payload: &[u8]) -> Result<Self::Event, Error>;
+
+    /// 处理解码后的负载，返回需要中继的消息；返回 None 表示丢弃该消息
+    async fn process(&self, event: Self::Event, message: SignedMessage) -> Result<Option<SignedMessage>, Error>;
+}
+
+/// 将 [`TypedEventHandler`] 包装为 [`EventHandler`]
+pub struct Typed<H>(pub H);
+
+#[async_trait]
+impl<H: TypedEventHandler> EventHandler for Typed<H> {
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    async fn handle(&self, message: SignedMessage) -> Result<Option<SignedMessage>, Error> {
+        if message.message.message_type != self.0.message_type() {
+            warn!("Handler {} ignoring message type: {}", self.0.name(), message.message.message_type);
+            return Ok(None);
+        }
+        let event = self.0.decode(&message.message.payload)?;
+        self.0.process(event, message).await
+    }
+}
+
+/// 通用消息处理器，原样进入中继流程
+pub struct MessageEventHandler;
+
+#[async_trait]
+impl EventHandler for MessageEventHandler {
+    fn name(&self) -> &str {
+        MESSAGE_EVENT_HANDLER
+    }
+
+    async fn handle(&self, message: SignedMessage) -> Result<Option<SignedMessage>, Error> {
+        Ok(Some(message))
+    }
+}
+
+/// 资产跨链转账处理器，只接受已配置资产映射的转账消息
+pub struct AssetTransferHandler {
+    assets: Vec<AssetConfig>,
+}
+
+impl AssetTransferHandler {
+    pub fn new(assets: Vec<AssetConfig>) -> Self {
+        Self { assets }
+    }
+}
+
+#[async_trait]
+impl EventHandler for AssetTransferHandler {
+    fn name(&self) -> &str {
+        ASSET_TRANSFER_HANDLER
+    }
+
+    async fn handle(&self, message: SignedMessage) -> Result<Option<SignedMessage>, Error> {
+        if message.message.message_type != "transfer" {
+            warn!("Asset transfer handler ignoring message type: {}", message.message.message_type);
+            return Ok(None);
+        }
+        if message.message.payload.is_empty() {
+            return Err(Error::Chain("Asset transfer payload is empty".to_string()));
+        }
+
+        let mapped = self.assets.iter().any(|asset| {
+            message.message.source_chain == asset.native_chain
+                && asset.mappings.contains_key(message.message.target_chain.as_str())
+        });
+        if !mapped {
+            return Err(Error::Chain(format!(
+                "No asset mapping from {} to {}",
+                message.message.source_chain, message.message.target_chain
+            )));
+        }
+
+        Ok(Some(message))
+    }
+}
+
+/// 治理消息处理器，只接受负载有效的治理消息
+pub struct GovernanceHandler;
+
+#[async_trait]
+impl TypedEventHandler for GovernanceHandler {
+    type Event = Governance;
+
+    fn name(&self) -> &str {
+        GOVERNANCE_HANDLER
+    }
+
+    fn message_type(&self) -> &str {
+        GOVERNANCE_MESSAGE_TYPE
+    }
+
+    fn decode(&self, payload: &[u8]) -> Result<Governance, Error> {
+        Governance::decode(payload)
+    }
+
+    async fn process(&self, governance: Governance, message: SignedMessage) -> Result<Option<SignedMessage>, Error> {
+        debug!("Governance proposal {}: {:?}", governance.proposal_id, governance.action);
+        Ok(Some(message))
+    }
+}
+
+/// NFT 跨链处理器，只接受负载有效且集合已配置映射的 NFT 转移消息
+pub struct NftHandler {
+    collections: Vec<NftCollectionConfig>,
+}
+
+impl NftHandler {
+    pub fn new(collections: Vec<NftCollectionConfig>) -> Self {
+        Self { collections }
+    }
+}
+
+#[async_trait]
+impl TypedEventHandler for NftHandler {
+    type Event = NftTransfer;
+
+    fn name(&self) -> &str {
+        NFT_HANDLER
+    }
+
+    fn message_type(&self) -> &str {
+        NFT_TRANSFER_MESSAGE_TYPE
+    }
+
+    fn decode(&self, payload: &[u8]) -> Result<NftTransfer, Error> {
+        NftTransfer::decode(payload)
+    }
+
+    async fn process(&self, transfer: NftTransfer, message: SignedMessage) -> Result<Option<SignedMessage>, Error> {
+        if transfer.recipient.is_empty() {
+            return Err(Error::Chain("NFT transfer recipient is empty".to_string()));
+        }
+
+        let (source, target) = (&message.message.source_chain, &message.message.target_chain);
+        let mapped = self.collections.iter().any(|collection| {
+            collection.type_on(source) == Some(transfer.collection.as_str()) && collection.type_on(target).is_some()
+        });
+        if !mapped {
+            return Err(Error::Chain(format!(
+                "No NFT collection mapping for {} from {} to {}",
+                transfer.collection, source, target
+            )));
+        }
+
+        Ok(Some(message))
+    }
+}
+
+/// 机密消息处理器
+///
+/// 中继器无法解密负载，只校验信封格式，并确认信封加密到目标链登记的公钥，
+/// 避免把机密数据发往无法解密的合约。
+pub struct ConfidentialHandler {
+    /// 目标链 ID 到登记公钥标识的映射
+    key_ids: HashMap<ChainId, [u8; 8]>,
+}
+
+impl ConfidentialHandler {
+    pub fn new(chains: &[ChainConfig]) -> Result<Self, Error> {
+        let mut key_ids = HashMap::new();
+        for chain in chains {
+            if let Some(public_key) = &chain.payload_public_key {
+                key_ids.insert(chain.id.clone(), confidential::key_id(&confidential::parse_public_key(public_key)?));
+            }
+        }
+        Ok(Self { key_ids })
+    }
+}
+
+#[async_trait]
+impl EventHandler for ConfidentialHandler {
+    fn name(&self) -> &str {
+        CONFIDENTIAL_HANDLER
+    }
+
+    async fn handle(&self, message: SignedMessage) -> Result<Option<SignedMessage>, Error> {
+        if message.message.message_type != confidential::CONFIDENTIAL_MESSAGE_TYPE {
+            warn!("Confidential handler ignoring message type: {}", message.message.message_type);
+            return Ok(None);
+        }
+        let target = &message.message.target_chain;
+        let key_id = self.key_ids.get(target).ok_or_else(|| {
+            Error::Config(format!("Chain {} has no payload public key for confidential messages", target))
+        })?;
+        let envelope = confidential::Envelope::parse(&message.message.payload)?;
+        if &envelope.key_id != key_id {
+            return Err(Error::Key(format!("Confidential payload is not sealed to the key registered for {}", target)));
+        }
+        Ok(Some(message))
+    }
+}
+
+/// 自定义脚本处理器
+///
+/// 消息以 JSON 写入脚本标准输入。脚本退出码为 0 时中继消息，
+/// 若标准输出非空则按 JSON 解析为替换后的消息；退出码非 0 时丢弃消息。
+/// 超过 `timeout` 仍未退出的脚本被终止，消息按处理失败丢弃。
+pub struct ScriptHandler {
+    name: String,
+    program: String,
+    timeout: Duration,
+}
+
+impl ScriptHandler {
+    pub fn new(program: &str, timeout: Duration) -> Self {
+        Self {
+            name: format!("{}{}", SCRIPT_HANDLER_PREFIX, program),
+            program: program.to_string(),
+            timeout,
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler for ScriptHandler {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn handle(&self, message: SignedMessage) -> Result<Option<SignedMessage>, Error> {
+        let input = serde_json::to_vec(&message)
+            .map_err(|e| Error::Serialization(e.to_string()))?;
+
+        let mut child = Command::new(&self.program)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| Error::Config(format!("Failed to run handler script {}: {}", self.program, e)))?;
+
+        // 超时后子进程随 future 一起被丢弃并终止
+        let run = async move {
+            if let Some(mut stdin) = child.stdin.take() {
+                stdin
+                    .write_all(&input)
+                    .await
+                    .map_err(|e| Error::Chain(format!("Failed to write to handler script: {}", e)))?;
+            }
+            child
+                .wait_with_output()
+                .await
+                .map_err(|e| Error::Chain(format!("Handler script failed: {}", e)))
+        };
+        let output = tokio::time::timeout(self.timeout, run)
+            .await
+            .map_err(|_| Error::Chain(format!(
+                "Handler script {} did not finish within {} seconds", self.program, self.timeout.as_secs()
+            )))??;
+
+        if !output.status.success() {
+            debug!("Handler script {} rejected message {}", self.program, message.message.nonce);
+            return Ok(None);
+        }
+        if output.stdout.iter().all(|b| b.is_ascii_whitespace()) {
+            return Ok(Some(message));
+        }
+
+        let replaced = serde_json::from_slice(&output.stdout)
+            .map_err(|e| Error::Serialization(format!("Invalid handler script output: {}", e)))?;
+        Ok(Some(replaced))
+    }
+}
+
+/// 事件处理器注册表，按 `EventFilter.handler` 名称分发事件
+pub struct HandlerRegistry {
+    handlers: HashMap<String, Arc<dyn EventHandler>>,
+    /// `script:` 处理器的运行时限
+    script_timeout: Duration,
+}
+
+impl HandlerRegistry {
+    /// 创建空的注册表
+    pub fn new() -> Self {
+        Self {
+            handlers: HashMap::new(),
+            script_timeout: Duration::from_secs(DEFAULT_SCRIPT_TIMEOUT),
+        }
+    }
+
+    /// 创建包含全部内置处理器与已注册第三方处理器的注册表
+    pub fn with_defaults(config: &Config) -> Result<Self, Error> {
+        let mut registry = Self::new();
+        registry.script_timeout = Duration::from_secs(config.relayer.script_timeout);
+        registry.register(Arc::new(MessageEventHandler));
+        registry.register(Arc::new(AssetTransferHandler::new(config.assets.clone())));
+        registry.register(Arc::new(Typed(GovernanceHandler)));
+        registry.register(Arc::new(Typed(NftHandler::new(config.nft_collections.clone()))));
+        registry.register(Arc::new(ConfidentialHandler::new(&config.chains)?));
+        // 第三方处理器按注册的名称索引
+        for (name, builder) in PLUGINS.read().unwrap().iter() {
+            registry.handlers.insert(name.clone(), builder(config)?);
+        }
+        Ok(registry)
+    }
+
+    /// 注册处理器，同名处理器会被覆盖
+    pub fn register(&mut self, handler: Arc<dyn EventHandler>) {
+        self.handlers.insert(handler.name().to_string(), handler);
+    }
+
+    /// 按名称查找处理器，`script:` 前缀的处理器按需创建
+    pub fn resolve(&self, name: &str) -> Option<Arc<dyn EventHandler>> {
+        if let Some(handler) = self.handlers.get(name) {
+            return Some(handler.clone());
+        }
+        name.strip_prefix(SCRIPT_HANDLER_PREFIX)
+            .filter(|program| !program.is_empty())
+            .map(|program| Arc::new(ScriptHandler::new(program, self.script_timeout)) as Arc<dyn EventHandler>)
+    }
+
+    /// 确认链配置中引用的处理器都已注册
+    pub fn check_chain(&self, chain: &ChainConfig) -> Result<(), Error> {
+        for filter in &chain.event_filters {
+            if self.resolve(&filter.handler).is_none() {
+                return Err(Error::Config(format!(
+                    "Unknown event handler '{}' for filter '{}' on chain {}",
+                    filter.handler, filter.name, chain.id
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// 为消息找到匹配的事件过滤器
+    ///
+    /// 优先按消息的事件名称匹配；消息未携带事件名称且链上只配置了一个过滤器时，
+    /// 使用该过滤器。
+    pub fn filter_for<'a>(chain: &'a ChainConfig, message: &SignedMessage) -> Option<&'a EventFilter> {
+        match &message.event_name {
+            Some(event_name) => chain.event_filters.iter().find(|f| &f.name == event_name),
+            None if chain.event_filters.len() == 1 => chain.event_filters.first(),
+            None => None,
+        }
+    }
+
+    /// 将消息分发给匹配的处理器
+    pub async fn dispatch(&self, chain: &ChainConfig, message: SignedMessage) -> Result<Option<SignedMessage>, Error> {
+        let filter = match Self::filter_for(chain, &message) {
+            Some(filter) => filter,
+            None => {
+                warn!(
+                    "No event filter on chain {} matches event {:?}, skipping message {}",
+                    chain.id, message.event_name, message.message.nonce
+                );
+                return Ok(None);
+            }
+        };
+
+        let handler = self.resolve(&filter.handler)
+            .ok_or_else(|| Error::Config(format!("Unknown event handler: {}", filter.handler)))?;
+        handler.handle(message).await
+    }
+}
+
+impl Default for HandlerRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::CrossChainMessage;
+
+    fn nft_message(collection: &str, target_chain: &str) -> SignedMessage {
+        let transfer = NftTransfer {
+            collection: collection.to_string(),
+            token_id: vec![1; 32],
+            metadata_hash: vec![2; 32],
+            recipient: vec![3; 32],
+        };
+        SignedMessage {
+            message: CrossChainMessage {
+                nonce: 1,
+                source_chain: "sui_testnet".into(),
+                target_chain: target_chain.into(),
+                message_type: NFT_TRANSFER_MESSAGE_TYPE.to_string(),
+                payload: transfer.encode().unwrap(),
+            },
+            signature: vec![],
+            timestamp: 0,
+            event_name: None,
+            sender: None,
+            provenance: None,
+            proof: None,
+            trace_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_nft_handler_checks_collection_mapping() {
+        let handler = Typed(NftHandler::new(vec![NftCollectionConfig {
+            name: "Capsules".to_string(),
+            native_chain: "sui_testnet".to_string(),
+            type_: "0x2::capsule::Capsule".to_string(),
+            mappings: HashMap::from([("rooch_testnet".to_string(), "0x3::capsule::Capsule".to_string())]),
+        }]));
+
+        let message = nft_message("0x2::capsule::Capsule", "rooch_testnet");
+        assert!(handler.handle(message).await.unwrap().is_some());
+        assert!(handler.handle(nft_message("0x2::other::Other", "rooch_testnet")).await.is_err());
+        assert!(handler.handle(nft_message("0x2::capsule::Capsule", "aptos")).await.is_err());
+
+        let mut truncated = nft_message("0x2::capsule::Capsule", "rooch_testnet");
+        truncated.message.payload.pop();
+        assert!(handler.handle(truncated).await.is_err());
+    }
+
+    /// 只中继点数不超过上限的 `points` 消息
+    struct PointsHandler {
+        limit: u64,
+    }
+
+    #[async_trait]
+    impl TypedEventHandler for PointsHandler {
+        type Event = u64;
+
+        fn name(&self) -> &str {
+            "points"
+        }
+
+        fn message_type(&self) -> &str {
+            "points"
+        }
+
+        fn decode(&self, payload: &[u8]) -> Result<u64, Error> {
+            bcs::from_bytes(payload).map_err(|e| Error::Serialization(e.to_string()))
+        }
+
+        async fn process(&self, points: u64, message: SignedMessage) -> Result<Option<SignedMessage>, Error> {
+            Ok((points <= self.limit).then_some(message))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_registered_handler_dispatches_by_filter() {
+        let mut config = crate::testing::two_chain_config();
+        config.chains[0].event_filters.push(EventFilter { name: "PointsEvent".to_string(), handler: "points".to_string() });
+        assert!(HandlerRegistry::with_defaults(&config).unwrap().check_chain(&config.chains[0]).is_err());
+
+        register_handler("points", Arc::new(|_: &Config| Ok(Arc::new(Typed(PointsHandler { limit: 10 })) as Arc<dyn EventHandler>))).unwrap();
+        assert!(register_handler(NFT_HANDLER, Arc::new(|_: &Config| Ok(Arc::new(MessageEventHandler) as Arc<dyn EventHandler>))).is_err());
+        assert!(register_handler("script:points", Arc::new(|_: &Config| Ok(Arc::new(MessageEventHandler) as Arc<dyn EventHandler>))).is_err());
+        let registry = HandlerRegistry::with_defaults(&config).unwrap();
+        registry.check_chain(&config.chains[0]).unwrap();
+
+        let points = |amount: u64, event_name: &str| {
+            let mut message = nft_message("", "rooch_testnet");
+            message.message.message_type = "points".to_string();
+            message.message.payload = bcs::to_bytes(&amount).unwrap();
+            message.event_name = Some(event_name.to_string());
+            message
+        };
+        assert!(registry.dispatch(&config.chains[0], points(7, "PointsEvent")).await.unwrap().is_some());
+        assert!(registry.dispatch(&config.chains[0], points(70, "PointsEvent")).await.unwrap().is_none());
+        // 其它事件仍交给原有的处理器
+        let mut truncated = points(7, "MessageEvent");
+        truncated.message.payload.truncate(3);
+        assert!(registry.dispatch(&config.chains[0], truncated.clone()).await.unwrap().is_some());
+        truncated.event_name = Some("PointsEvent".to_string());
+        assert!(registry.dispatch(&config.chains[0], truncated).await.is_err());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_script_handler_times_out() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir().join(format!("bridge-script-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let script = |name: &str, body: &str| {
+            let path = dir.join(name);
+            std::fs::write(&path, format!("#!/bin/sh\n{}\n", body)).unwrap();
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+            path.to_string_lossy().into_owned()
+        };
+        let message = nft_message("", "rooch_testnet");
+
+        // 按时退出且没有输出的脚本原样放行
+        let pass = ScriptHandler::new(&script("pass.sh", "cat > /dev/null"), Duration::from_secs(5));
+        assert_eq!(pass.handle(message.clone()).await.unwrap().unwrap().message, message.message);
+
+        // 超时的脚本被终止，不再等待其退出
+        let hang = ScriptHandler::new(&script("hang.sh", "sleep 30"), Duration::from_millis(200));
+        let started = std::time::Instant::now();
+        let error = hang.handle(message.clone()).await.unwrap_err();
+        assert!(error.to_string().contains("did not finish"));
+        assert!(started.elapsed() < Duration::from_secs(10));
+
+        // 注册表按配置的时限创建脚本处理器
+        let mut config = crate::testing::two_chain_config();
+        config.relayer.script_timeout = 1;
+        let registry = HandlerRegistry::with_defaults(&config).unwrap();
+        let handler = registry.resolve(&format!("{}{}", SCRIPT_HANDLER_PREFIX, script("slow.sh", "sleep 30"))).unwrap();
+        assert!(handler.handle(message).await.is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
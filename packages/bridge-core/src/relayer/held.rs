@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use crate::storage::HoldCheck;
+
+/// 已从暂缓队列放行、重新进入待处理队列的消息
+///
+/// 放行的消息跳过其未通过的检查，直到处理完毕；其它检查照常进行。
+#[derive(Default)]
+pub struct Releases {
+    released: Mutex<HashMap<String, HoldCheck>>,
+}
+
+impl Releases {
+    /// 登记放行的消息及其跳过的检查
+    pub fn admit(&self, message_id: &str, check: HoldCheck) {
+        self.released.lock().unwrap().insert(message_id.to_string(), check);
+    }
+
+    pub fn is_released(&self, message_id: &str) -> bool {
+        self.released.lock().unwrap().contains_key(message_id)
+    }
+
+    /// 消息已放行且跳过 `check`
+    pub fn bypasses(&self, message_id: &str, check: HoldCheck) -> bool {
+        self.released.lock().unwrap().get(message_id) == Some(&check)
+    }
+
+    /// 消息处理完毕后清理
+    pub fn forget(&self, message_id: &str) {
+        self.released.lock().unwrap().remove(message_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_release_bypasses_only_the_failed_check() {
+        let releases = Releases::default();
+        assert!(!releases.bypasses("m1", HoldCheck::Schema));
+
+        releases.admit("m1", HoldCheck::Schema);
+        assert!(releases.is_released("m1"));
+        assert!(releases.bypasses("m1", HoldCheck::Schema));
+        assert!(!releases.bypasses("m1", HoldCheck::Recipient));
+        assert!(!releases.bypasses("m2", HoldCheck::Schema));
+
+        releases.forget("m1");
+        assert!(!releases.is_released("m1"));
+    }
+}
@@ -0,0 +1,147 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::time::sleep;
+use tracing::{info, error, warn};
+use crate::{
+    config::CoordinationConfig,
+    storage::MessageStore,
+    Error,
+};
+use super::now_secs;
+
+/// 中继器领导者租约名称
+const LEADER_LEASE: &str = "relayer-leader";
+
+/// 基于存储后端租约的领导者选举
+///
+/// 同一配置下的多个实例竞争同一租约，只有持有者提交交易；
+/// 其它实例作为备用持续观察，租约过期后由备用实例接管。
+pub struct LeaderElection {
+    store: Arc<dyn MessageStore>,
+    instance_id: String,
+    lease_ttl: u64,
+    leader: AtomicBool,
+}
+
+impl LeaderElection {
+    pub fn new(store: Arc<dyn MessageStore>, config: &CoordinationConfig) -> Self {
+        let instance_id = config.instance_id.clone().unwrap_or_else(|| {
+            let host = std::env::var("HOSTNAME").unwrap_or_else(|_| "relayer".to_string());
+            format!("{}-{}", host, std::process::id())
+        });
+        Self {
+            store,
+            instance_id,
+            lease_ttl: config.lease_ttl,
+            leader: AtomicBool::new(false),
+        }
+    }
+
+    pub fn instance_id(&self) -> &str {
+        &self.instance_id
+    }
+
+    /// 本实例当前是否为领导者
+    pub fn is_leader(&self) -> bool {
+        self.leader.load(Ordering::SeqCst)
+    }
+
+    /// 获取或续期租约，返回本实例是否为领导者
+    ///
+    /// 租约正在移交给其它实例时不再续期，领导者主动让出；接手实例取得租约后清除移交请求。
+    pub async fn refresh(&self) -> Result<bool, Error> {
+        let now = now_secs();
+        let handoff = self.store.handoff(LEADER_LEASE, now).await?;
+        if let Some(successor) = handoff.as_ref().filter(|successor| **successor != self.instance_id) {
+            if self.is_leader() {
+                info!("Instance {} handing leadership to {}", self.instance_id, successor);
+                self.step_down().await?;
+            }
+            return Ok(false);
+        }
+
+        let acquired = self.store
+            .acquire_lease(LEADER_LEASE, &self.instance_id, self.lease_ttl, now)
+            .await?;
+        if acquired && handoff.is_some() {
+            self.store.clear_handoff(LEADER_LEASE, &self.instance_id).await?;
+        }
+        let was_leader = self.leader.swap(acquired, Ordering::SeqCst);
+        match (was_leader, acquired) {
+            (false, true) => info!("Instance {} became leader", self.instance_id),
+            (true, false) => warn!("Instance {} lost leadership, standing by", self.instance_id),
+            _ => {}
+        }
+        Ok(acquired)
+    }
+
+    /// 持续续期租约，续期间隔为租约有效期的三分之一
+    pub async fn run(self: Arc<Self>) {
+        let interval = Duration::from_secs((self.lease_ttl / 3).max(1));
+        loop {
+            if let Err(e) = self.refresh().await {
+                // 无法确认租约时立即停止提交，避免与新领导者重复提交
+                error!("Failed to refresh leader lease: {}", e);
+                self.leader.store(false, Ordering::SeqCst);
+            }
+            sleep(interval).await;
+        }
+    }
+
+    /// 主动释放租约，便于备用实例立即接管
+    pub async fn step_down(&self) -> Result<(), Error> {
+        self.leader.store(false, Ordering::SeqCst);
+        self.store.release_lease(LEADER_LEASE, &self.instance_id).await
+    }
+}
+
+/// 请求把领导者租约移交给实例 `instance_id`，返回请求的失效时间
+///
+/// 当前领导者下次续期时让出租约，接手实例随后取得租约；
+/// 租约有效期的三倍时间内未完成移交（例如接手实例未运行）时请求失效，恢复正常选举。
+pub async fn request_promotion(store: &dyn MessageStore, instance_id: &str, config: &CoordinationConfig) -> Result<u64, Error> {
+    let expires_at = now_secs() + config.lease_ttl * 3;
+    store.request_handoff(LEADER_LEASE, instance_id, expires_at).await?;
+    Ok(expires_at)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::FileStore;
+
+    fn election(store: &Arc<dyn MessageStore>, instance_id: &str) -> LeaderElection {
+        let config = CoordinationConfig {
+            instance_id: Some(instance_id.to_string()),
+            lease_ttl: 30,
+            follow_interval: 5,
+        };
+        LeaderElection::new(store.clone(), &config)
+    }
+
+    #[tokio::test]
+    async fn test_promotion_hands_lease_to_standby() {
+        let path = std::env::temp_dir().join(format!("bridge-leader-test-{}.jsonl", std::process::id()));
+        let store: Arc<dyn MessageStore> = Arc::new(FileStore::open(&path).unwrap());
+        let primary = election(&store, "primary");
+        let standby = election(&store, "standby");
+        assert!(primary.refresh().await.unwrap());
+        assert!(!standby.refresh().await.unwrap());
+
+        let config = CoordinationConfig { instance_id: None, lease_ttl: 30, follow_interval: 5 };
+        request_promotion(store.as_ref(), "standby", &config).await.unwrap();
+        assert!(!primary.refresh().await.unwrap());
+        assert!(!primary.is_leader());
+        assert!(standby.refresh().await.unwrap());
+
+        // 移交完成后请求清除，原领导者作为备用继续竞争
+        assert_eq!(store.handoff(LEADER_LEASE, now_secs()).await.unwrap(), None);
+        assert!(!primary.refresh().await.unwrap());
+        assert!(standby.refresh().await.unwrap());
+
+        for extension in ["", ".ledger", ".schemas", ".lease"] {
+            let _ = std::fs::remove_file(format!("{}{}", path.display(), extension));
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use async_trait::async_trait;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use crate::{types::SignedMessage, Error};
+
+/// 中间件对消息的决定
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// 继续后续处理
+    Continue,
+    /// 暂不处理，消息留在待处理队列中，下一轮再次调用同一钩子
+    Defer,
+    /// 拒绝中继，消息标记为失败
+    Reject(String),
+}
+
+/// 中继流程中间件
+///
+/// 嵌入 bridge-core 的应用通过 [`Relayer::add_middleware`](super::Relayer::add_middleware) 注册，
+/// 无需修改中继循环即可加入额外校验、记账或通知。钩子默认不做任何处理；
+/// 钩子返回错误时消息留在待处理队列中，下一轮重试。多实例部署时只有领导者调用钩子。
+#[async_trait]
+pub trait Middleware: Send + Sync {
+    /// 中间件名称，用于日志与拒绝原因
+    fn name(&self) -> &str;
+
+    /// 观察到消息、经事件处理器处理后调用，返回 `Continue` 后同一消息不再调用
+    async fn on_message_observed(&self, _message_id: &str, _message: &SignedMessage) -> Result<Verdict, Error> {
+        Ok(Verdict::Continue)
+    }
+
+    /// 内置检查全部通过、提交到目标链之前调用；提交窗口或批次推迟时同一消息可能再次调用
+    async fn before_submit(&self, _message_id: &str, _message: &SignedMessage) -> Result<Verdict, Error> {
+        Ok(Verdict::Continue)
+    }
+
+    /// 消息在目标链执行成功后调用，治理与元数据同步等不提交的消息不调用
+    async fn after_confirm(&self, _message_id: &str, _message: &SignedMessage) {}
+}
+
+/// 已注册的中间件，按注册顺序调用
+#[derive(Default)]
+pub struct MiddlewareChain {
+    middlewares: Vec<Arc<dyn Middleware>>,
+    /// 已通过 `on_message_observed` 的消息 ID
+    observed: Mutex<HashSet<String>>,
+}
+
+impl MiddlewareChain {
+    pub fn push(&mut self, middleware: Arc<dyn Middleware>) {
+        self.middlewares.push(middleware);
+    }
+
+    /// 依次调用 `on_message_observed`，遇到第一个非 `Continue` 的决定即返回
+    pub async fn observe(&self, message_id: &str, message: &SignedMessage) -> Result<Verdict, Error> {
+        if self.middlewares.is_empty() || self.observed.lock().unwrap().contains(message_id) {
+            return Ok(Verdict::Continue);
+        }
+        for middleware in &self.middlewares {
+            match middleware.on_message_observed(message_id, message).await? {
+                Verdict::Continue => {}
+                Verdict::Reject(reason) => return Ok(Verdict::Reject(format!("{}: {}", middleware.name(), reason))),
+                Verdict::Defer => return Ok(Verdict::Defer),
+            }
+        }
+        self.observed.lock().unwrap().insert(message_id.to_string());
+        Ok(Verdict::Continue)
+    }
+
+    /// 依次调用 `before_submit`，遇到第一个非 `Continue` 的决定即返回
+    pub async fn before_submit(&self, message_id: &str, message: &SignedMessage) -> Result<Verdict, Error> {
+        for middleware in &self.middlewares {
+            match middleware.before_submit(message_id, message).await? {
+                Verdict::Continue => {}
+                Verdict::Reject(reason) => return Ok(Verdict::Reject(format!("{}: {}", middleware.name(), reason))),
+                Verdict::Defer => return Ok(Verdict::Defer),
+            }
+        }
+        Ok(Verdict::Continue)
+    }
+
+    pub async fn after_confirm(&self, message_id: &str, message: &SignedMessage) {
+        for middleware in &self.middlewares {
+            middleware.after_confirm(message_id, message).await;
+        }
+    }
+
+    /// 消息已处理完毕或被丢弃时移出已观察集合
+    pub fn forget(&self, message_id: &str) {
+        if !self.middlewares.is_empty() {
+            self.observed.lock().unwrap().remove(message_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use crate::testing::{test_chains, transfer_payload, TwoChainHarness, TARGET_CHAIN};
+
+    #[derive(Default)]
+    struct Policy {
+        hold: AtomicBool,
+        confirmed: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl Middleware for Policy {
+        fn name(&self) -> &str {
+            "policy"
+        }
+
+        async fn on_message_observed(&self, _message_id: &str, message: &SignedMessage) -> Result<Verdict, Error> {
+            if message.message.message_type == "blocked" {
+                return Ok(Verdict::Reject("message type not allowed".to_string()));
+            }
+            Ok(Verdict::Continue)
+        }
+
+        async fn before_submit(&self, _message_id: &str, _message: &SignedMessage) -> Result<Verdict, Error> {
+            if self.hold.load(Ordering::SeqCst) {
+                return Ok(Verdict::Defer);
+            }
+            Ok(Verdict::Continue)
+        }
+
+        async fn after_confirm(&self, message_id: &str, _message: &SignedMessage) {
+            self.confirmed.lock().unwrap().push(message_id.to_string());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_middleware_hooks_shape_relaying() {
+        let mut harness = TwoChainHarness::new().await.unwrap();
+        let policy = Arc::new(Policy::default());
+        harness.relayer.add_middleware(policy.clone());
+
+        harness.source.send(TARGET_CHAIN, "blocked", vec![]);
+        harness.tick().await;
+        assert!(harness.target.submitted().is_empty());
+        assert_eq!(harness.backlog(), 0);
+
+        // 推迟的消息留在待处理队列中，放行后提交并通知
+        policy.hold.store(true, Ordering::SeqCst);
+        let sent = harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([1u8; 32], 500));
+        harness.run(2).await;
+        assert!(harness.target.submitted().is_empty());
+        assert_eq!(harness.backlog(), 1);
+
+        policy.hold.store(false, Ordering::SeqCst);
+        harness.tick().await;
+        assert_eq!(harness.target.submitted().len(), 1);
+        assert_eq!(*policy.confirmed.lock().unwrap(), [sent.message.message_id(&test_chains()).unwrap()]);
+    }
+}
@@ -3,133 +3,1935 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use crate::{
-    config::{Config, ChainConfig},
-    types::{SignedMessage, MessageStatus},
+    alert::AlertManager,
+    config::{AlertKind, Config, ChainConfig},
+    types::{
+        AssetAction, AssetGovernance, ChainId, ChainRegistry, CrossChainMessage, MetadataSync, SignedMessage, MessageStatus,
+        ASSET_GOVERNANCE_MESSAGE_TYPE, METADATA_SYNC_MESSAGE_TYPE, VALIDATOR_GOVERNANCE_MESSAGE_TYPE,
+    },
     Error,
-    chain_adapter::{ChainAdapter, SuiAdapter, RoochAdapter},
+    chain_adapter::{registry, version, ChainAdapter, SuiAdapter, RoochAdapter, SolanaAdapter, MovementAdapter, CosmosAdapter, GenericJsonRpcAdapter, json_rpc, rooch_tx::RoochSigner},
+    chain_adapter::cosmos_tx::CosmosSigner,
+    chain_adapter::movement_tx::MovementSigner,
+    chain_adapter::solana_tx::{Pubkey, SolanaSigner},
+    info::BuildInfo,
+    keystore::Keystore,
+    metrics::{self, Metrics, RouteLabels},
+    proof::ProofVerifier,
+    storage::{self, retention, HeldMessage, HoldCheck, MessageRecord, MessageStore, RetryRecord, SourceRecord, SubmissionRecord},
+    api::{self, stream::{StatusEvent, StatusFeed}, ApiState},
+    audit::{AuditDecision, AuditLog, AuditRecord},
+    bootstrap::{NonceSet, SignedBootstrapBundle},
+    confidential,
+    health::{self, HealthMonitor, HealthState},
+    ledger::{self, fees, supply, Transfer},
+    schema::SchemaRegistry,
+    screening::{ScreeningDecision, Screener},
+    verify,
 };
+#[cfg(feature = "wasm-plugins")]
+use crate::chain_adapter::wasm;
+use aggregator::AttestationAggregator;
+use batch::{BatchEntry, SubmissionBatcher};
+use cursor::CursorCommits;
+use guard::{GuardDecision, ValidatorSetGuard};
+use handler::HandlerRegistry;
+use held::Releases;
+use leader::LeaderElection;
+use middleware::{Middleware, MiddlewareChain, Verdict};
+use ordering::DeliveryOrdering;
+use pause::{AssetPauses, PauseDecision};
+use priority::PriorityScorer;
+use queue::{Ingestor, WorkQueue};
+use rotation::ValidatorRotation;
+use scheduler::{Slot, SubmissionScheduler};
+use timelock::{Timelock, TimelockDecision};
+use watchdog::{StuckDecision, Watchdog};
+use gas::{CostDecision, GasPriceOracle};
 use tokio::time::{sleep, Duration};
 use std::collections::HashSet;
-use log::{info, error, warn};
+use tracing::{debug, info, error, warn};
 
+/// 查询 gas 余额与 gas 价格的间隔（秒）
+const GAS_BALANCE_INTERVAL: u64 = 60;
+
+/// 链适配器工厂，返回已建立连接的适配器
 #[async_trait]
 pub trait ChainAdapterFactory: Send + Sync {
     async fn create_adapter(&self, config: &ChainConfig) -> Result<Box<dyn ChainAdapter>, Error>;
 }
 
-pub struct DefaultChainAdapterFactory;
+/// 按链配置中的 `adapter_type` 创建适配器的工厂
+///
+/// 内置 Sui 与 Rooch 适配器（启用 `wasm-plugins` 时还有 WASM 插件适配器），其它类型交给通过 [`registry::register_adapter`] 注册的第三方工厂。
+/// 无论来源如何，适配器都在连接并通过节点版本检查后返回。
+pub struct DefaultChainAdapterFactory {
+    /// 数字链 ID 到配置链 ID 的映射，供需要解析链上数字 ID 的适配器使用
+    chain_ids: HashMap<u64, ChainId>,
+}
+
+impl DefaultChainAdapterFactory {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            chain_ids: config.chains
+                .iter()
+                .filter_map(|c| c.contract_chain_id().map(|id| (id, c.id.clone())))
+                .collect(),
+        }
+    }
+
+    /// 创建内置类型的适配器，不是内置类型时返回 None
+    fn create_builtin(&self, config: &ChainConfig) -> Result<Option<Box<dyn ChainAdapter>>, Error> {
+        Ok(Some(match config.adapter_type.as_str() {
+            "sui" => Box::new(SuiAdapter::new(config.clone()).with_chain_ids(self.chain_ids.clone())),
+            "rooch" => {
+                let mut adapter = RoochAdapter::new(config.clone())?.with_chain_ids(self.chain_ids.clone());
+                if let Some(path) = &config.sender_keystore {
+                    adapter = adapter.with_signer(RoochSigner::load(path)?);
+                }
+                Box::new(adapter)
+            }
+            "solana" => {
+                let mut adapter = SolanaAdapter::new(config.clone())?.with_chain_ids(self.chain_ids.clone());
+                if let (Some(path), Some(solana)) = (&config.sender_keystore, &config.solana) {
+                    adapter = adapter.with_signer(SolanaSigner::load(path)?, Pubkey::parse(&solana.nonce_account)?);
+                }
+                Box::new(adapter)
+            }
+            "movement" => {
+                let mut adapter = MovementAdapter::new(config.clone()).with_chain_ids(self.chain_ids.clone());
+                if let Some(path) = &config.sender_keystore {
+                    adapter = adapter.with_signer(MovementSigner::load(path)?);
+                }
+                Box::new(adapter)
+            }
+            "cosmos" => {
+                let mut adapter = CosmosAdapter::new(config.clone())?.with_chain_ids(self.chain_ids.clone());
+                if let (Some(path), Some(cosmos)) = (&config.sender_keystore, &config.cosmos) {
+                    adapter = adapter.with_signer(CosmosSigner::load(path, &cosmos.address_prefix)?);
+                }
+                Box::new(adapter)
+            }
+            json_rpc::JSON_RPC_ADAPTER_TYPE => {
+                Box::new(GenericJsonRpcAdapter::new(config.clone())?.with_chain_ids(self.chain_ids.clone()))
+            }
+            #[cfg(feature = "wasm-plugins")]
+            wasm::WASM_ADAPTER_TYPE => Box::new(wasm::WasmAdapter::new(config.clone())?),
+            _ => return Ok(None),
+        }))
+    }
+}
 
 #[async_trait]
 impl ChainAdapterFactory for DefaultChainAdapterFactory {
     async fn create_adapter(&self, config: &ChainConfig) -> Result<Box<dyn ChainAdapter>, Error> {
-        match config.adapter_type.as_str() {
-            "sui" => {
-                let adapter = SuiAdapter::new(config.clone()).await?;
-                Ok(Box::new(adapter))
-            }
-            "rooch" => {
-                let adapter = RoochAdapter::new(&config.rpc_url).await?;
-                Ok(Box::new(adapter))
-            }
-            _ => Err(Error::Chain(format!("Unsupported adapter type: {}", config.adapter_type))),
+        let adapter = match self.create_builtin(config)? {
+            Some(adapter) => adapter,
+            None => registry::plugin(&config.adapter_type)
+                .ok_or_else(|| Error::Chain(format!("Unsupported adapter type: {}", config.adapter_type)))?
+                .create_adapter(config)
+                .await?,
+        };
+
+        adapter.connect().await?;
+        // 开始使用前确认节点版本满足配置要求
+        if let Err(e) = version::ensure_compatible(adapter.as_ref()).await {
+            adapter.close().await?;
+            return Err(e);
         }
+        debug!("Connected to chain {} ({:?})", config.id, adapter.capabilities());
+        Ok(adapter)
+    }
+}
+
+/// 中继循环在各轮之间保留的状态
+pub struct RelayState {
+    observed_messages: HashSet<String>,
+    consumed: HashMap<String, NonceSet>,
+    /// 尚未处理完毕的消息；按游标读取事件的适配器不会重复返回同一事件，需在此保留以便重试
+    pending: HashMap<String, (ChainId, SignedMessage)>,
+    gas_checked_at: u64,
+    /// 上次资产对账的时间
+    reconciled_at: u64,
+    /// 各链上次认领 gas 报销的时间
+    refunds_claimed_at: HashMap<String, u64>,
+    /// 备用实例上次跟随领导者处理结果的时间
+    followed_at: u64,
+    /// 读取事件与中继处理之间的有界队列
+    queue: WorkQueue,
+    /// 由 `tick` 在同一任务中读取事件时持有；`start` 将其移到单独的读取循环
+    ingestor: Option<Ingestor>,
+}
+
+impl RelayState {
+    /// 尚未处理完毕的消息数，包括仍在队列与读取暂存中的消息
+    pub fn backlog(&self) -> usize {
+        self.pending.len() + self.queue.depth() + self.ingestor.as_ref().map_or(0, Ingestor::buffered)
     }
 }
 
 pub struct Relayer {
     config: Config,
-    chain_adapters: Arc<RwLock<HashMap<String, Box<dyn ChainAdapter>>>>,
+    /// 已配置的链，消息中的链 ID 以此校验
+    chains: ChainRegistry,
+    chain_adapters: Arc<RwLock<HashMap<ChainId, Box<dyn ChainAdapter>>>>,
+    keystore: Option<Keystore>,
+    handlers: HandlerRegistry,
+    metrics: Arc<Metrics>,
+    store: Option<Arc<dyn MessageStore>>,
+    aggregator: Option<Arc<AttestationAggregator>>,
+    /// 各源链已消费的 nonce，可由引导包预先恢复
+    consumed: HashMap<String, NonceSet>,
+    /// 多实例部署时的领导者选举，未配置协调时为 None
+    election: Option<Arc<LeaderElection>>,
+    scheduler: SubmissionScheduler,
+    /// 各链 gas 价格与成本超限推迟的消息
+    gas_prices: GasPriceOracle,
+    /// 按目标链攒批提交，未配置批量提交的链逐条提交
+    batcher: SubmissionBatcher,
+    /// 按目标链配置的交付顺序约束
+    ordering: DeliveryOrdering,
+    /// 待处理消息的优先级评分
+    priority: PriorityScorer,
+    /// 已暂停中继的资产
+    pauses: Arc<AssetPauses>,
+    /// 从暂缓队列放行、重新处理的消息
+    releases: Releases,
+    /// 等待读到的消息处理完毕后保存的源链事件游标
+    cursor_commits: CursorCommits,
+    /// 嵌入方注册的中继流程中间件
+    middleware: MiddlewareChain,
+    health: Arc<HealthMonitor>,
+    /// 负载结构检查，未配置时为 None
+    schemas: Option<SchemaRegistry>,
+    /// 验证者集合更新的变更幅度限制，未配置时为 None
+    validator_guard: Option<Arc<ValidatorSetGuard>>,
+    /// 治理消息驱动的验证者集合轮换
+    rotation: Arc<ValidatorRotation>,
+    /// 转账地址筛查，未配置时为 None
+    screener: Option<Screener>,
+    /// 大额转账与筛查延迟的时间锁，两者都未启用时为 None
+    timelock: Option<Arc<Timelock>>,
+    /// 超时未确认交易的重新提交计数
+    watchdog: Watchdog,
+    /// 中继决策审计日志，未配置时为 None
+    audit: Option<AuditLog>,
+    /// 已记录接受决策的消息，避免等待重试时重复记录，处理完毕后移除
+    audited: std::sync::Mutex<HashSet<String>>,
+    /// 源链事件包含证明的验证，没有链配置轻客户端时为 None
+    proofs: Option<ProofVerifier>,
+    /// 已通过包含证明验证的消息，等待重试时不再重复验证，处理完毕后移除
+    proven: std::sync::Mutex<HashSet<String>>,
+    /// 消息状态变化的广播，供 WebSocket 订阅
+    status_feed: StatusFeed,
+    /// 告警条件检查与推送，未配置时为 None
+    alerts: Option<AlertManager>,
 }
 
 impl Relayer {
     pub async fn new(config: Config) -> Result<Self, Error> {
+        let factory = DefaultChainAdapterFactory::new(&config);
+        Self::with_factory(config, &factory).await
+    }
+
+    /// 使用指定的适配器工厂创建中继器，测试时可替换为模拟链
+    pub async fn with_factory(config: Config, factory: &dyn ChainAdapterFactory) -> Result<Self, Error> {
         let mut chain_adapters = HashMap::new();
-        let factory = DefaultChainAdapterFactory;
+
+        // 确认每个事件过滤器都有对应的处理器
+        let handlers = HandlerRegistry::with_defaults(&config)?;
+        for chain in &config.chains {
+            handlers.check_chain(chain)?;
+        }
 
         for chain in &config.chains {
             let adapter = factory.create_adapter(chain).await?;
+            if chain.batch.is_some() && !adapter.capabilities().batch_submit {
+                return Err(Error::Config(format!("Chain {} does not support batch submission", chain.id)));
+            }
             chain_adapters.insert(chain.id.clone(), adapter);
         }
 
+        // 启动时核对本地密钥与链上注册表，不一致时拒绝启动
+        let keystore = match Keystore::open(&config.relayer).await? {
+            Some(mut keystore) => {
+                keystore.verify_registrations(&config, &chain_adapters).await?;
+                Some(keystore)
+            }
+            None => None,
+        };
+
+        let store: Option<Arc<dyn MessageStore>> = match &config.storage {
+            Some(storage_config) => Some(Arc::from(storage::open(storage_config).await?)),
+            None => None,
+        };
+
+        // 从消息存储恢复各链的事件读取游标，重启后从最后处理完毕的位置继续读取
+        if let Some(store) = &store {
+            for (chain_id, adapter) in &chain_adapters {
+                let cursors = store.cursors(chain_id.as_str()).await?;
+                if !cursors.is_empty() {
+                    info!("Resuming event polling on {} from saved cursors {:?}", chain_id, cursors);
+                    adapter.restore_cursors(cursors).await?;
+                }
+            }
+        }
+
+        let election = match (&config.relayer.coordination, &store) {
+            (Some(coordination), Some(store)) => Some(Arc::new(LeaderElection::new(store.clone(), coordination))),
+            (Some(_), None) => {
+                return Err(Error::Config("Relayer coordination requires a shared storage backend".to_string()));
+            }
+            (None, _) => None,
+        };
+
+        let chains = config.chain_registry()?;
+        let aggregator = config.relayer.require_attestations.then(|| {
+            Arc::new(
+                AttestationAggregator::new(config.validators.clone(), config.relayer.attestation_threshold, chains.clone())
+                    .with_governance_threshold(config.relayer.governance_threshold),
+            )
+        });
+
+        // 恢复已保存的双签证据，重启后接口仍能查询
+        if let (Some(aggregator), Some(store)) = (&aggregator, &store) {
+            aggregator.equivocations().restore(store.equivocations().await?);
+        }
+
+        let scheduler = SubmissionScheduler::new(&config)?;
+        let gas_prices = GasPriceOracle::new(&config)?;
+        let batcher = SubmissionBatcher::new(&config);
+        let ordering = DeliveryOrdering::new(&config);
+        let priority = PriorityScorer::new(&config);
+        let pauses = Arc::new(AssetPauses::new(&config, now_secs()));
+        let schemas = match (&config.schemas, &store) {
+            (Some(schema_config), Some(store)) => Some(SchemaRegistry::new(store.clone(), schema_config.clone())),
+            _ => None,
+        };
+        let audit = config.audit.as_ref().map(AuditLog::open).transpose()?;
+        let validator_guard = config.relayer.validator_set_guard
+            .as_ref()
+            .map(|guard| Arc::new(ValidatorSetGuard::new(&config, guard)));
+        let rotation = Arc::new(ValidatorRotation::new(&config));
+        let screener = config.screening.as_ref().map(Screener::new).transpose()?;
+        let timelock = (config.assets.iter().any(|asset| asset.timelock.is_some())
+            || screener.as_ref().is_some_and(Screener::delays))
+            .then(|| Arc::new(Timelock::default()));
+        let health = Arc::new(HealthMonitor::new(config.chains.iter().map(|c| c.id.to_string())));
+        let proofs = ProofVerifier::from_config(&config)?;
+        let alerts = config.alerts.as_ref().map(|alerts| AlertManager::new(alerts, now_secs()));
+
         Ok(Self {
             config,
+            chains,
             chain_adapters: Arc::new(RwLock::new(chain_adapters)),
+            keystore,
+            handlers,
+            metrics: Arc::new(Metrics::new()?),
+            store,
+            aggregator,
+            consumed: HashMap::new(),
+            election,
+            scheduler,
+            gas_prices,
+            batcher,
+            ordering,
+            priority,
+            pauses,
+            releases: Releases::default(),
+            cursor_commits: CursorCommits::default(),
+            middleware: MiddlewareChain::default(),
+            health,
+            schemas,
+            validator_guard,
+            rotation,
+            screener,
+            timelock,
+            watchdog: Watchdog::default(),
+            audit,
+            audited: std::sync::Mutex::new(HashSet::new()),
+            proofs,
+            proven: std::sync::Mutex::new(HashSet::new()),
+            status_feed: StatusFeed::default(),
+            alerts,
+        })
+    }
+
+    /// 获取已通过启动核对的本地密钥库
+    pub fn keystore(&self) -> Option<&Keystore> {
+        self.keystore.as_ref()
+    }
+
+    /// 从可信引导包恢复已消费 nonce，避免新实例从创世区块重新扫描
+    pub fn restore(&mut self, bundle: &SignedBootstrapBundle) -> Result<(), Error> {
+        let bundle = bundle.verify(&self.config)?;
+        self.consumed = bundle.consumed();
+        info!(
+            "Restored bootstrap bundle from {} (epoch {}, {} chain(s))",
+            bundle.created_at,
+            bundle.validator_set_epoch,
+            self.consumed.len()
+        );
+        Ok(())
+    }
+
+    /// 获取中继器指标
+    pub fn metrics(&self) -> Arc<Metrics> {
+        self.metrics.clone()
+    }
+
+    /// 获取已暂停中继的资产，供运营方暂停与恢复
+    pub fn asset_pauses(&self) -> Arc<AssetPauses> {
+        self.pauses.clone()
+    }
+
+    /// 注册中继流程中间件，须在 `start` 之前调用；多个中间件按注册顺序调用
+    pub fn add_middleware(&mut self, middleware: Arc<dyn Middleware>) {
+        self.middleware.push(middleware);
+    }
+
+    /// 获取健康状态，供外部嵌入自定义的探针
+    pub fn health(&self) -> Arc<HealthMonitor> {
+        self.health.clone()
+    }
+
+    /// HTTP 与 gRPC 接口共享的状态
+    pub fn api_state(&self) -> Result<ApiState, Error> {
+        Ok(ApiState {
+            info: BuildInfo::current(Some(&self.config))?,
+            aggregator: self.aggregator.clone(),
+            store: self.store.clone(),
+            validator_guard: self.validator_guard.clone(),
+            validator_rotation: Some(self.rotation.clone()),
+            timelock: self.timelock.clone(),
+            asset_pauses: Some(self.pauses.clone()),
+            health: Some(self.health.clone()),
+            feed: Some(self.status_feed.clone()),
         })
     }
 
     pub async fn start(&self) -> Result<(), Error> {
         info!("Starting relayer...");
-        
+
+        if let Some(api_config) = &self.config.api {
+            let router = api::router(self.api_state()?);
+            let listen = api_config.listen.clone();
+            tokio::spawn(async move {
+                if let Err(e) = api::serve(&listen, router).await {
+                    error!("HTTP API stopped: {}", e);
+                }
+            });
+
+            #[cfg(feature = "grpc")]
+            if let Some(listen) = api_config.grpc_listen.clone() {
+                let state = self.api_state()?;
+                tokio::spawn(async move {
+                    if let Err(e) = api::grpc::serve(&listen, state).await {
+                        error!("gRPC API stopped: {}", e);
+                    }
+                });
+            }
+        }
+
+        if let Some(health_config) = &self.config.health {
+            let router = health::router(HealthState {
+                monitor: self.health.clone(),
+                store: self.store.clone(),
+            });
+            let listen = health_config.listen.clone();
+            tokio::spawn(async move {
+                if let Err(e) = api::serve(&listen, router).await {
+                    error!("Health server stopped: {}", e);
+                }
+            });
+        }
+
+        // 按保留策略在后台清理已进入终态的历史消息
+        let retention = self.config.storage.as_ref().and_then(|storage| storage.retention.clone());
+        if let (Some(store), Some(retention)) = (&self.store, retention) {
+            tokio::spawn(retention::run(store.clone(), retention));
+        }
+
+        // 加入 p2p 网络，接收验证者广播的证明；句柄需在中继循环期间保持存活
+        #[cfg(feature = "p2p")]
+        let _p2p = match &self.config.p2p {
+            Some(_) => {
+                let identity = crate::p2p::node_identity(self.keystore.as_ref())?;
+                Some(crate::p2p::spawn(&self.config, identity, self.aggregator.clone())?)
+            }
+            None => None,
+        };
+
+        // 协调模式下先竞争一次租约，再在后台持续续期
+        if let Some(election) = &self.election {
+            let leader = election.refresh().await?;
+            info!(
+                "Relayer instance {} starting as {}",
+                election.instance_id(),
+                if leader { "leader" } else { "standby" }
+            );
+            tokio::spawn(election.clone().run());
+        }
+
+        // 事件读取与中继处理并发运行，经有界队列衔接，处理跟不上时读取暂停；
+        // 循环按最短的链轮询间隔运行，各链按自己的间隔轮询
+        let mut state = self.relay_state();
+        let mut ingestor = state.ingestor.take().expect("new relay state owns its ingestor");
+        let poll_interval = Duration::from_secs(self.config.shortest_poll_interval());
+        let ingestion = async {
+            loop {
+                self.ingest(&mut ingestor).await;
+                sleep(poll_interval).await;
+            }
+        };
+        let relay = async {
+            loop {
+                self.process(&mut state).await;
+                sleep(poll_interval).await;
+            }
+        };
+        tokio::join!(ingestion, relay);
+        Ok(())
+    }
+
+    /// 创建中继循环的初始状态，已消费 nonce 取自引导包
+    pub fn relay_state(&self) -> RelayState {
+        let (ingestor, queue) = queue::work_queue(self.config.relayer.queue_capacity);
+        RelayState {
+            observed_messages: HashSet::new(),
+            consumed: self.consumed.clone(),
+            pending: HashMap::new(),
+            gas_checked_at: 0,
+            reconciled_at: 0,
+            refunds_claimed_at: HashMap::new(),
+            followed_at: 0,
+            queue,
+            ingestor: Some(ingestor),
+        }
+    }
+
+    /// 执行一轮中继：读取各链事件并处理待处理队列中的消息
+    pub async fn tick(&self, state: &mut RelayState) {
+        if let Some(ingestor) = state.ingestor.as_mut() {
+            self.ingest(ingestor).await;
+        }
+        self.process(state).await;
+    }
+
+    /// 将各链限速器本轮延迟的 RPC 调用数计入指标
+    async fn record_rpc_throttling(&self) {
+        for (chain_id, adapter) in self.chain_adapters.read().await.iter() {
+            if let Some(rate_limiter) = adapter.rate_limiter() {
+                self.metrics.record_rpc_throttled(chain_id, rate_limiter.take_throttled());
+            }
+        }
+    }
+
+    /// 重新中继回放得到的消息：加入待处理队列后执行一轮处理，返回仍未处理完毕的消息 ID
+    ///
+    /// 回放的消息与观察到的消息经过相同的检查，等待证明、时间锁或提交窗口的消息留在待处理队列中。
+    pub async fn replay(&self, state: &mut RelayState, chain_id: &ChainId, messages: Vec<SignedMessage>) -> Result<Vec<String>, Error> {
+        let mut message_ids = Vec::new();
+        for message in messages {
+            let message_id = message.message.message_id(&self.chains)?;
+            state.pending.entry(message_id.clone()).or_insert_with(|| (chain_id.clone(), message));
+            message_ids.push(message_id);
+        }
+        self.process(state).await;
+        Ok(message_ids.into_iter().filter(|id| state.pending.contains_key(id)).collect())
+    }
+
+    /// 读取各链事件写入工作队列，队列已满或未到轮询间隔的链本轮不轮询
+    async fn ingest(&self, ingestor: &mut Ingestor) {
         let adapters = self.chain_adapters.read().await;
-        let mut processed_messages: HashSet<String> = HashSet::new();
+        for (chain_id, adapter) in adapters.iter() {
+            if !ingestor.drain_overflow(chain_id) {
+                debug!("Work queue full, pausing event polling on {}", chain_id);
+                self.metrics.record_ingestion_paused(chain_id);
+                continue;
+            }
+            let interval = Duration::from_secs(self.config.relayer_settings(chain_id).poll_interval);
+            if !ingestor.poll_due(chain_id, interval) {
+                continue;
+            }
+            match self.process_chain_events(adapter.as_ref()).await {
+                Ok(messages) => {
+                    self.health.record_poll(chain_id, now_secs());
+                    let message_ids: Vec<String> = messages
+                        .iter()
+                        .filter_map(|message| message.message.message_id(&self.chains).ok())
+                        .collect();
+                    let cursors = adapter.event_cursors().await;
+                    if let Some(cursors) = self.cursor_commits.observe(chain_id, cursors, message_ids) {
+                        self.save_cursors(chain_id, &cursors).await;
+                    }
+                    ingestor.offer(chain_id, messages);
+                }
+                Err(e) => {
+                    error!("Failed to process events for chain {}: {}", chain_id, e);
+                    self.health.record_failure(chain_id, &e);
+                }
+            }
+        }
+    }
 
-        loop {
-            for (chain_id, adapter) in adapters.iter() {
-                match self.process_chain_events(chain_id, adapter.as_ref()).await {
-                    Ok(messages) => {
-                        for message in messages {
-                            let message_id = hex::encode(&message.signature);
-                            if !processed_messages.contains(&message_id) {
-                                if let Err(e) = self.relay_message(chain_id, message.clone()).await {
-                                    error!("Failed to relay message {}: {}", message_id, e);
-                                    continue;
-                                }
-                                processed_messages.insert(message_id);
-                            }
+    /// 从工作队列取出消息并处理待处理的消息，待处理消息数不超过队列容量
+    async fn process(&self, state: &mut RelayState) {
+        if now_secs() >= state.gas_checked_at + GAS_BALANCE_INTERVAL {
+            let adapters = self.chain_adapters.read().await;
+            self.refresh_gas(&adapters).await;
+            state.gas_checked_at = now_secs();
+        }
+        if let Some(reconciliation) = &self.config.reconciliation {
+            if now_secs() >= state.reconciled_at + reconciliation.interval {
+                self.reconcile_supply().await;
+                state.reconciled_at = now_secs();
+            }
+        }
+
+        while state.pending.len() < self.config.relayer.queue_capacity {
+            let Some((chain_id, message)) = state.queue.try_recv() else {
+                break;
+            };
+            match message.message.message_id(&self.chains) {
+                Ok(message_id) => {
+                    state.pending.entry(message_id).or_insert_with(|| (chain_id, message));
+                }
+                Err(e) => error!("Failed to compute message ID on chain {}: {}", chain_id, e),
+            }
+        }
+        self.metrics.set_queue_depth(state.queue.depth());
+        if self.election.is_some() && !self.is_leader() {
+            self.follow(state).await;
+        } else {
+            self.resume_held(state).await;
+        }
+
+        // 按优先级分数处理，同分时按观察顺序，提交名额受限时分数高、较早的消息优先
+        let now = now_secs();
+        let mut queue = Vec::with_capacity(state.pending.len());
+        let mut bands = Vec::with_capacity(state.pending.len());
+        for (message_id, (chain_id, message)) in &state.pending {
+            let transfer = Transfer::parse(&self.config, message_id, &message.message);
+            bands.push(self.priority.band(transfer.as_ref()).map(|band| band.name.as_str()));
+            queue.push((self.priority.score(message, transfer.as_ref(), now), message_id, chain_id, message));
+        }
+        queue.sort_by_key(|(score, _, _, message)| (std::cmp::Reverse(*score), message.timestamp, message.message.nonce));
+        self.metrics.set_pending_composition(&self.priority.composition(bands));
+
+        let mut finished = Vec::new();
+        for (_, message_id, chain_id, message) in queue {
+            let source_chain = message.message.source_chain.clone();
+            let nonce = message.message.nonce;
+            // 放行的暂缓消息此前已记为消费，仍需重新处理
+            if state.consumed.get(source_chain.as_str()).is_some_and(|set| set.contains(nonce)) && !self.releases.is_released(message_id) {
+                finished.push(message_id.clone());
+                continue;
+            }
+            // 源链事件未达到确认数前不中继
+            let confirmed_at = message.timestamp + self.config.relayer_settings(&source_chain).confirmation_delay();
+            if now_secs() < confirmed_at {
+                debug!("Waiting for confirmations of message {} until {}", message_id, confirmed_at);
+                continue;
+            }
+            // 已在批次中等待提交，由 `flush_batches` 完成
+            if self.batcher.contains(message_id) {
+                continue;
+            }
+            // 严格顺序下等待同一链对上较小 nonce 的消息确认；已入批的前序消息会在同一笔交易中先执行
+            let outstanding = state.pending
+                .iter()
+                .filter(|(id, _)| !finished.contains(*id) && !self.batcher.contains(id))
+                .map(|(_, (_, pending))| pending);
+            if let Some(predecessor) = self.ordering.held_back_by(message, outstanding, now_secs()) {
+                debug!("Holding back message {} until nonce {} is confirmed", message_id, predecessor);
+                continue;
+            }
+
+            let first_seen = state.observed_messages.insert(message_id.clone());
+            if self.handle_message(chain_id, message_id, message.clone(), first_seen).await {
+                finished.push(message_id.clone());
+            }
+        }
+        for message_id in finished {
+            self.finish_message(state, &message_id).await;
+        }
+        self.flush_batches(state).await;
+        self.rotate_validators().await;
+        self.report_equivocations().await;
+        self.claim_refunds(state).await;
+        self.health.set_backlog(state.pending.len());
+        for chain in &self.config.chains {
+            self.health.set_cost_deferred(&chain.id, self.gas_prices.deferred(&chain.id));
+        }
+        if let Some(alerts) = &self.alerts {
+            alerts.check_chains(&self.health.chains(), now_secs()).await;
+        }
+        self.record_rpc_throttling().await;
+    }
+
+    /// 提交到期的批次
+    ///
+    /// 批量提交不在轮内重试，可重试的失败留在待处理队列中，下一轮重新进入中继流程；重试无法成功的失败记为失败并移出队列。
+    async fn flush_batches(&self, state: &mut RelayState) {
+        let now = now_secs();
+        for (target_chain_id, entries) in self.batcher.take_due(now) {
+            if let Slot::Deferred { until } = self.scheduler.reserve(&target_chain_id, now) {
+                debug!("Deferring batch of {} messages to {} until {}", entries.len(), target_chain_id, until);
+                self.batcher.requeue(&target_chain_id, entries, now);
+                continue;
+            }
+
+            for (entry, result) in self.relay_batch(&target_chain_id, entries).await {
+                let BatchEntry { message_id, message, .. } = entry;
+                let route = RouteLabels::for_message(&self.config, &message.message);
+                if let Err(e) = result {
+                    self.audit(&message_id, AuditDecision::Failed, &target_chain_id, Some(e.to_string()), None).await;
+                    if e.is_retryable() {
+                        warn!("Failed to relay message {}, will retry: {}", message_id, e);
+                        continue;
+                    }
+                    error!("Failed to relay message {}: {}", message_id, e);
+                    self.metrics.record_failed(&route);
+                    self.record_status(&message_id, &message, MessageStatus::Failed, Some(&e)).await;
+                    if let Some(aggregator) = &self.aggregator {
+                        aggregator.remove(&message_id).await;
+                    }
+                    self.finish_message(state, &message_id).await;
+                    continue;
+                }
+
+                let latency = now_secs().saturating_sub(message.timestamp);
+                self.metrics.record_relayed(&route, latency as f64);
+                self.record_status(&message_id, &message, MessageStatus::Processed, None).await;
+                self.middleware.after_confirm(&message_id, &message).await;
+                if let Some(aggregator) = &self.aggregator {
+                    aggregator.remove(&message_id).await;
+                }
+                self.finish_message(state, &message_id).await;
+            }
+        }
+    }
+
+    /// 消息处理完毕：移出待处理队列并记为已消费，清除各环节为其保留的状态，保存因此可以前进的源链游标
+    async fn finish_message(&self, state: &mut RelayState, message_id: &str) {
+        if let Some((_, message)) = state.pending.remove(message_id) {
+            state.consumed
+                .entry(message.message.source_chain.to_string())
+                .or_default()
+                .insert(message.message.nonce);
+        }
+        self.gas_prices.forget(message_id);
+        self.watchdog.resolve(message_id);
+        self.pauses.forget(message_id);
+        self.releases.forget(message_id);
+        self.middleware.forget(message_id);
+        self.proven.lock().unwrap().remove(message_id);
+        self.audited.lock().unwrap().remove(message_id);
+        if let Some(screener) = &self.screener {
+            screener.forget(message_id);
+        }
+        self.finish_cursors(message_id).await;
+    }
+
+    /// 备用实例跟随共享存储中领导者的处理结果：已进入终态的消息移出待处理队列并记为已消费
+    ///
+    /// 备用实例只观察不处理，不跟随时待处理队列会被占满并暂停事件读取；
+    /// 跟随后接管时游标与已消费 nonce 与领导者一致，只需处理领导者尚未完成的消息。
+    async fn follow(&self, state: &mut RelayState) {
+        let (Some(store), Some(coordination)) = (&self.store, &self.config.relayer.coordination) else {
+            return;
+        };
+        let now = now_secs();
+        if now < state.followed_at + coordination.follow_interval {
+            return;
+        }
+        state.followed_at = now;
+        if let Err(e) = store.reload().await {
+            warn!("Failed to reload shared store: {}", e);
+            return;
+        }
+
+        let mut finished = Vec::new();
+        for message_id in state.pending.keys() {
+            match store.get(message_id).await {
+                Ok(Some(record)) if record.status.is_terminal() => finished.push(message_id.clone()),
+                Ok(_) => {}
+                Err(e) => {
+                    warn!("Failed to follow message {}: {}", message_id, e);
+                    return;
+                }
+            }
+        }
+        if !finished.is_empty() {
+            debug!("Followed {} message(s) finished by the leader", finished.len());
+        }
+        for message_id in finished {
+            self.finish_message(state, &message_id).await;
+        }
+    }
+
+    /// 按各链的认领间隔认领桥合约累计的 gas 报销，并记入手续费账目
+    ///
+    /// 报销记在各实例自己的收款地址名下，备用实例同样认领；记账金额为认领前查询到的累计报销。
+    async fn claim_refunds(&self, state: &mut RelayState) {
+        let now = now_secs();
+        let adapters = self.chain_adapters.read().await;
+        for chain in &self.config.chains {
+            let Some(reimbursement) = &chain.reimbursement else {
+                continue;
+            };
+            let claimed_at = state.refunds_claimed_at.get(chain.id.as_str()).copied().unwrap_or(0);
+            if now < claimed_at + reimbursement.claim_interval {
+                continue;
+            }
+            let Some(adapter) = adapters.get(&chain.id) else {
+                continue;
+            };
+            state.refunds_claimed_at.insert(chain.id.to_string(), now);
+
+            let accrued = match adapter.accrued_refund().await {
+                Ok(Some(accrued)) => accrued,
+                Ok(None) => continue,
+                Err(e) => {
+                    warn!("Failed to query accrued gas refund on {}: {}", chain.id, e);
+                    continue;
+                }
+            };
+            if accrued == 0 || accrued < reimbursement.min_claim {
+                debug!("Accrued gas refund {} on {} below claim threshold {}", accrued, chain.id, reimbursement.min_claim);
+                continue;
+            }
+            let tx_hash = match adapter.claim_refund().await {
+                Ok(Some(tx_hash)) => tx_hash,
+                Ok(None) => continue,
+                Err(e) => {
+                    warn!("Failed to claim gas refund on {}: {}", chain.id, e);
+                    continue;
+                }
+            };
+            info!("Claimed gas refund of {} on {} to {} in {}", accrued, chain.id, reimbursement.payout_address, tx_hash);
+            self.metrics.record_refund_claimed(&chain.id, accrued);
+            if let Some(store) = &self.store {
+                if let Err(e) = store.append_ledger(&fees::refund_claimed(&chain.id, &tx_hash, accrued, now)).await {
+                    warn!("Failed to record gas refund claimed in {}: {}", tx_hash, e);
+                }
+            }
+        }
+    }
+
+    /// 按资产治理消息暂停或恢复资产
+    fn apply_asset_governance(&self, message: &CrossChainMessage) -> Result<AssetAction, Error> {
+        let governance = AssetGovernance::decode(&message.payload)?;
+        match &governance.action {
+            AssetAction::Pause { asset, reason } => {
+                self.pauses.pause(asset, reason.clone(), now_secs())?;
+            }
+            AssetAction::Resume { asset } => {
+                self.pauses.resume(asset)?;
+            }
+        }
+        Ok(governance.action)
+    }
+
+    /// 核对各资产的锁定量与铸造量，铸造量超过锁定量时告警，按配置暂停该资产
+    ///
+    /// 链无法提供余额的资产跳过；多实例部署时只由领导者对账。
+    async fn reconcile_supply(&self) {
+        let Some(reconciliation) = &self.config.reconciliation else {
+            return;
+        };
+        if !self.is_leader() {
+            return;
+        }
+        let adapters = self.chain_adapters.read().await;
+        let assets = self
+            .config
+            .assets
+            .iter()
+            .filter(|asset| reconciliation.assets.is_empty() || reconciliation.assets.contains(&asset.name));
+        for asset in assets {
+            let report = match supply::check(asset, &adapters).await {
+                Ok(Some(report)) => report,
+                Ok(None) => continue,
+                Err(e) => {
+                    warn!("Failed to reconcile supply of {}: {}", asset.name, e);
+                    continue;
+                }
+            };
+            self.metrics.set_supply_shortfall(&asset.name, report.shortfall());
+            if report.holds() {
+                debug!("Supply of {} reconciled: locked {}, minted {}", asset.name, report.locked, report.total_minted());
+                continue;
+            }
+
+            let summary = format!(
+                "Wrapped supply of {} exceeds the amount locked on {}: locked {}, minted {}",
+                asset.name,
+                asset.native_chain,
+                report.locked,
+                report.total_minted()
+            );
+            error!("{}", summary);
+            if let Some(alerts) = &self.alerts {
+                alerts.raise(AlertKind::SupplyMismatch, &asset.name, summary.clone(), now_secs()).await;
+            }
+            if reconciliation.auto_pause && matches!(self.pauses.pause(&asset.name, summary, now_secs()), Ok(true)) {
+                warn!("Paused relaying of {} after supply mismatch", asset.name);
+            }
+        }
+    }
+
+    /// 应用源链已到生效高度的验证者轮换，并把新集合同步到尚未同步的链
+    ///
+    /// 适配器无法提供链高度时立即生效。推送失败的链下一轮重试。
+    async fn rotate_validators(&self) {
+        if !self.is_leader() {
+            return;
+        }
+        let adapters = self.chain_adapters.read().await;
+        for chain_id in self.rotation.pending_chains() {
+            let height = match adapters.get(&chain_id) {
+                Some(adapter) => match adapter.latest_height().await {
+                    Ok(height) => height,
+                    Err(e) => {
+                        warn!("Failed to query height of chain {}: {}", chain_id, e);
+                        continue;
+                    }
+                },
+                None => None,
+            };
+            if height.is_none() {
+                warn!("Cannot determine height of chain {}, activating validator rotations immediately", chain_id);
+            }
+            let (applied, rejected) = self.rotation.activate(&chain_id, height, now_secs());
+            for (message_id, e) in rejected {
+                error!("Rejected validator governance {}: {}", message_id, e);
+                self.audit(&message_id, AuditDecision::Rejected, &chain_id, Some(e.to_string()), None).await;
+            }
+            if applied.is_empty() {
+                continue;
+            }
+            let set = self.rotation.current();
+            info!(
+                "Applied validator governance {} at height {:?} of {}: {} validator(s), threshold {}",
+                applied.join(", "),
+                height,
+                chain_id,
+                set.validators.len(),
+                set.threshold()
+            );
+            if let Some(aggregator) = &self.aggregator {
+                aggregator.update(set);
+            }
+        }
+
+        let unsynced = self.rotation.unsynced();
+        if unsynced.is_empty() {
+            return;
+        }
+        let set = self.rotation.current();
+        for chain_id in unsynced {
+            let Some(adapter) = adapters.get(&chain_id) else {
+                self.rotation.mark_synced(&chain_id);
+                continue;
+            };
+            match adapter.update_validator_set(&set).await {
+                Ok(tx_hash) => {
+                    if let Some(tx_hash) = tx_hash {
+                        info!("Pushed validator set to {} in transaction {}", chain_id, tx_hash);
+                    }
+                    self.rotation.mark_synced(&chain_id);
+                }
+                Err(e) => warn!("Failed to push validator set to {}: {}", chain_id, e),
+            }
+        }
+    }
+
+    /// 保存新发现的双签证据，配置了罚没时由领导者提交到支持罚没的链
+    ///
+    /// 未指定 `slashing.chains` 时提交到双签验证者登记的全部链；提交失败只记录日志，不重试。
+    async fn report_equivocations(&self) {
+        let Some(aggregator) = &self.aggregator else {
+            return;
+        };
+        let found = aggregator.equivocations().take_unreported();
+        if found.is_empty() {
+            return;
+        }
+        let adapters = self.chain_adapters.read().await;
+        for evidence in found {
+            let source_chain = evidence.source_chain.to_string();
+            error!(
+                "Validator {} signed conflicting messages {} and {} for nonce {} of {}",
+                evidence.validator, evidence.first.message_id, evidence.second.message_id, evidence.nonce, source_chain
+            );
+            let reason = format!("Equivocation by validator {}", evidence.validator);
+            self.audit(&evidence.second.message_id, AuditDecision::Rejected, &source_chain, Some(reason), None).await;
+            if let Some(store) = &self.store {
+                if let Err(e) = store.record_equivocation(&evidence).await {
+                    warn!("Failed to store equivocation evidence {}: {}", evidence.evidence_id(), e);
+                }
+            }
+
+            let Some(slashing) = &self.config.slashing else {
+                continue;
+            };
+            if !self.is_leader() {
+                continue;
+            }
+            let chains = if slashing.chains.is_empty() {
+                self.rotation.current()
+                    .validators
+                    .iter()
+                    .find(|v| v.address == evidence.validator)
+                    .map(|v| v.chains.clone())
+                    .unwrap_or_default()
+            } else {
+                slashing.chains.clone()
+            };
+            for chain_id in chains {
+                let Some(adapter) = adapters.get(chain_id.as_str()) else {
+                    continue;
+                };
+                match adapter.submit_equivocation(&evidence).await {
+                    Ok(Some(tx_hash)) => info!("Submitted equivocation evidence {} to {} in transaction {}", evidence.evidence_id(), chain_id, tx_hash),
+                    Ok(None) => debug!("Chain {} does not support slashing", chain_id),
+                    Err(e) => error!("Failed to submit equivocation evidence {} to {}: {}", evidence.evidence_id(), chain_id, e),
+                }
+            }
+        }
+    }
+
+    /// 写入一条审计记录，未配置审计日志时忽略
+    async fn audit(&self, message_id: &str, decision: AuditDecision, chain: &str, reason: Option<String>, tx_hash: Option<String>) {
+        let Some(audit) = &self.audit else {
+            return;
+        };
+        let record = AuditRecord {
+            message_id: message_id.to_string(),
+            decision,
+            chain: chain.to_string(),
+            reason,
+            tx_hash,
+        };
+        if let Err(e) = audit.append(record, now_secs()).await {
+            error!("Failed to write audit entry for {}: {}", message_id, e);
+        }
+    }
+
+    /// 查询各链提交账户的 gas 余额与当前 gas 价格，写入健康状态
+    ///
+    /// 价格查询失败时沿用上一次的价格，避免节点抖动导致推迟的消息被立即提交。
+    async fn refresh_gas(&self, adapters: &HashMap<ChainId, Box<dyn ChainAdapter>>) {
+        for (chain_id, adapter) in adapters {
+            match adapter.gas_balance().await {
+                Ok(balance) => self.health.record_gas_balance(chain_id, balance),
+                Err(e) => warn!("Failed to query gas balance on chain {}: {}", chain_id, e),
+            }
+            match adapter.gas_price().await {
+                Ok(price) => {
+                    self.gas_prices.record(chain_id, price);
+                    self.health.record_gas_price(chain_id, price);
+                }
+                Err(e) => warn!("Failed to query gas price on chain {}: {}", chain_id, e),
+            }
+        }
+    }
+
+    /// 处理一条观察到的消息，返回消息是否已处理完毕、无需再次处理
+    async fn handle_message(&self, chain_id: &str, message_id: &str, message: SignedMessage, first_seen: bool) -> bool {
+        let route = RouteLabels::for_message(&self.config, &message.message);
+        if first_seen {
+            self.metrics.record_observed(&route);
+        }
+
+        // 备用实例只观察，不写存储也不提交，接管后再处理
+        if !self.is_leader() {
+            return false;
+        }
+        if self.election.is_some() && self.is_processed(message_id).await {
+            // 前任领导者已完成该消息
+            return true;
+        }
+        if first_seen {
+            self.record_status(message_id, &message, MessageStatus::Pending, None).await;
+            self.record_source(chain_id, message_id, &message).await;
+        }
+
+        // 等待证明或调度期间也可能过期，每轮先检查
+        if expiry::is_expired(&message, self.config.relayer.max_message_age, now_secs()) {
+            if !self.expire(chain_id, message_id, &message).await {
+                return false;
+            }
+            if let Some(aggregator) = &self.aggregator {
+                aggregator.remove(message_id).await;
+            }
+            return true;
+        }
+
+        // 配置了轻客户端的源链，事件须通过包含证明验证后才继续处理
+        if self.proofs.as_ref().is_some_and(|proofs| proofs.covers(chain_id)) && !self.proven.lock().unwrap().contains(message_id) {
+            match self.check_proof(chain_id, &message).await {
+                Ok(()) => {
+                    self.proven.lock().unwrap().insert(message_id.to_string());
+                }
+                Err(e @ Error::InvalidProof(_)) => {
+                    error!("Rejected message {}: {}", message_id, e);
+                    self.audit(message_id, AuditDecision::Rejected, chain_id, Some(e.to_string()), None).await;
+                    self.record_status(message_id, &message, MessageStatus::Failed, Some(&e)).await;
+                    if let Some(aggregator) = &self.aggregator {
+                        aggregator.remove(message_id).await;
+                    }
+                    return true;
+                }
+                Err(e) => {
+                    warn!("Failed to obtain inclusion proof for message {}: {}", message_id, e);
+                    return false;
+                }
+            }
+        }
+
+        let message = match self.dispatch_event(chain_id, message).await {
+            Ok(Some(message)) => message,
+            Ok(None) => {
+                self.audit(message_id, AuditDecision::Rejected, chain_id, Some("Dropped by event handler".to_string()), None).await;
+                return true;
+            }
+            Err(e) => {
+                error!("Event handler rejected message {}: {}", message_id, e);
+                self.audit(message_id, AuditDecision::Rejected, chain_id, Some(e.to_string()), None).await;
+                return true;
+            }
+        };
+
+        match self.middleware.observe(message_id, &message).await {
+            Ok(Verdict::Continue) => {}
+            Ok(Verdict::Defer) => return false,
+            Ok(Verdict::Reject(reason)) => {
+                warn!("Middleware rejected message {}: {}", message_id, reason);
+                self.audit(message_id, AuditDecision::Rejected, chain_id, Some(reason.clone()), None).await;
+                self.record_status(message_id, &message, MessageStatus::Failed, Some(&Error::Rejected(reason))).await;
+                if let Some(aggregator) = &self.aggregator {
+                    aggregator.remove(message_id).await;
+                }
+                return true;
+            }
+            Err(e) => {
+                warn!("Middleware failed to check message {}, will retry: {}", message_id, e);
+                return false;
+            }
+        }
+
+        // 负载不符合注册结构时转入暂缓队列，登记结构后可人工放行
+        if let Some(schemas) = self.schemas.as_ref().filter(|_| !self.releases.bypasses(message_id, HoldCheck::Schema)) {
+            match schemas.check(&message.message).await {
+                Ok(None) => {}
+                Ok(Some(reason)) => {
+                    let error = Error::Schema(reason.clone());
+                    return self.hold(chain_id, message_id, &message, HoldCheck::Schema, reason, &error).await;
+                }
+                Err(e) => {
+                    error!("Failed to check payload schema of message {}: {}", message_id, e);
+                    return false;
+                }
+            }
+        }
+
+        // 大幅变更验证者集合的更新保留在待处理队列中，人工批准后下一轮放行
+        if let Some(guard) = &self.validator_guard {
+            match guard.check(message_id, &message.message) {
+                Ok(GuardDecision::Allow) => {}
+                Ok(GuardDecision::Hold { reason, first }) => {
+                    if first {
+                        warn!("Holding validator set update {}: {}", message_id, reason);
+                        if let Some(alerts) = &self.alerts {
+                            let summary = format!("Validator set update {} held for approval: {}", message_id, reason);
+                            alerts.raise(AlertKind::CircuitBreaker, message_id, summary, now_secs()).await;
                         }
+                        self.audit(message_id, AuditDecision::Held, chain_id, Some(reason.clone()), None).await;
+                        self.record_status(message_id, &message, MessageStatus::Held, Some(&Error::ApprovalRequired(reason))).await;
+                    }
+                    return false;
+                }
+                Err(e) => {
+                    error!("Rejected validator set update {}: {}", message_id, e);
+                    self.audit(message_id, AuditDecision::Rejected, chain_id, Some(e.to_string()), None).await;
+                    self.record_status(message_id, &message, MessageStatus::Failed, Some(&e)).await;
+                    return true;
+                }
+            }
+        }
+
+        if let Some(transfer) = Transfer::parse(&self.config, message_id, &message.message) {
+            // 资产暂停期间转账保留在待处理队列中，恢复后继续中继
+            if let PauseDecision::Hold { reason, first } = self.pauses.check(&transfer.asset.name, message_id) {
+                if first {
+                    warn!("Holding transfer {} of paused asset {}: {}", message_id, transfer.asset.name, reason);
+                    self.audit(message_id, AuditDecision::Held, chain_id, Some(reason.clone()), None).await;
+                    let error = Error::AssetPaused { asset: transfer.asset.name.clone(), reason };
+                    self.record_status(message_id, &message, MessageStatus::Held, Some(&error)).await;
+                }
+                return false;
+            }
+
+            // 接收者不符合目标链的地址格式时交易必然在链上失败，直接拒绝
+            let recipient_error = self
+                .config
+                .get_chain_config(&message.message.target_chain)
+                .and_then(|chain| chain.address_format())
+                .and_then(|format| format.check_bytes(transfer.recipient()));
+            if let Some(reason) = recipient_error {
+                warn!("Rejecting transfer {}: {}", message_id, reason);
+                self.audit(message_id, AuditDecision::Rejected, chain_id, Some(reason.clone()), None).await;
+                self.record_status(message_id, &message, MessageStatus::Failed, Some(&Error::Rejected(reason))).await;
+                if let Some(aggregator) = &self.aggregator {
+                    aggregator.remove(message_id).await;
+                }
+                return true;
+            }
+
+            // 发送方或接收方地址命中筛查名单时按策略拒绝、标记或延迟
+            let mut screening_release = None;
+            if let Some(screener) = &self.screener {
+                match screener.check(&transfer, message.sender.as_deref(), message.timestamp).await {
+                    Ok(ScreeningDecision::Allow) => {}
+                    Ok(ScreeningDecision::Block(reason)) => {
+                        warn!("Blocking transfer {}: {}", message_id, reason);
+                        self.audit(message_id, AuditDecision::Rejected, chain_id, Some(reason.clone()), None).await;
+                        self.record_status(message_id, &message, MessageStatus::Failed, Some(&Error::Screened(reason))).await;
+                        if let Some(aggregator) = &self.aggregator {
+                            aggregator.remove(message_id).await;
+                        }
+                        return true;
+                    }
+                    Ok(ScreeningDecision::Flag { reason, first }) => {
+                        if first {
+                            warn!("Flagged transfer {}: {}", message_id, reason);
+                            self.audit(message_id, AuditDecision::Flagged, chain_id, Some(reason), None).await;
+                        }
+                    }
+                    Ok(ScreeningDecision::Delay { reason, release_at, first }) => {
+                        if first {
+                            warn!("Delaying transfer {} until {}: {}", message_id, release_at, reason);
+                            self.audit(message_id, AuditDecision::Flagged, chain_id, Some(reason), None).await;
+                        }
+                        screening_release = Some(release_at);
                     }
                     Err(e) => {
-                        error!("Failed to process events for chain {}: {}", chain_id, e);
+                        error!("Failed to screen transfer {}: {}", message_id, e);
+                        return false;
+                    }
+                }
+            }
+
+            // 大额转账在延迟期内保留在待处理队列中，期间可人工取消
+            if let Some(timelock) = &self.timelock {
+                let release_at = timelock::release_at(&transfer, message.timestamp).max(screening_release);
+                match timelock.check(&transfer, release_at, now_secs()) {
+                    TimelockDecision::Release => {}
+                    TimelockDecision::Hold { release_at, first } => {
+                        if first {
+                            warn!("Holding transfer {} of {} {} until {}", message_id, transfer.amount, transfer.asset.name, release_at);
+                            let reason = format!("Timelocked until {}", release_at);
+                            self.audit(message_id, AuditDecision::Held, chain_id, Some(reason), None).await;
+                            self.record_status(message_id, &message, MessageStatus::Held, Some(&Error::Timelocked { release_at })).await;
+                        }
+                        return false;
+                    }
+                    TimelockDecision::Cancelled => {
+                        warn!("Transfer {} cancelled during timelock", message_id);
+                        let error = Error::Cancelled("Transfer cancelled during timelock".to_string());
+                        self.audit(message_id, AuditDecision::Rejected, chain_id, Some(error.to_string()), None).await;
+                        self.record_status(message_id, &message, MessageStatus::Failed, Some(&error)).await;
+                        if let Some(aggregator) = &self.aggregator {
+                            aggregator.remove(message_id).await;
+                        }
+                        return true;
+                    }
+                }
+            }
+        }
+
+        // 要求验证者证明时，等待证明权重达到阈值后再中继；验证者签名的是换算金额后提交到目标链的消息
+        if let Some(aggregator) = &self.aggregator {
+            let observed = match ledger::submitted_message(&self.config, &message.message) {
+                Ok(submitted) => aggregator.observe(message_id, &submitted).await,
+                Err(e) => Err(e),
+            };
+            match observed {
+                Ok(progress) if progress.reached() => self.record_attestations(message_id).await,
+                Ok(progress) => {
+                    debug!("Waiting for quorum on message {} ({}/{})", message_id, progress.weight, progress.threshold);
+                    if let Some(alerts) = &self.alerts {
+                        alerts.check_quorum(message_id, message.timestamp, progress.weight, progress.threshold, now_secs()).await;
+                    }
+                    return false;
+                }
+                Err(e) => {
+                    error!("Failed to track quorum for message {}: {}", message_id, e);
+                    return false;
+                }
+            }
+        }
+
+        // 治理消息不向目标链中继：登记后在源链达到生效高度时应用，再把新集合推送到各链合约
+        if message.message.message_type == VALIDATOR_GOVERNANCE_MESSAGE_TYPE {
+            match self.rotation.schedule(message_id, &message.message) {
+                Ok(height) => {
+                    info!("Scheduled validator governance {} from {} at height {}", message_id, chain_id, height);
+                    self.audit(message_id, AuditDecision::Accepted, chain_id, None, None).await;
+                    self.record_status(message_id, &message, MessageStatus::Processed, None).await;
+                }
+                Err(e) => {
+                    error!("Rejected validator governance {}: {}", message_id, e);
+                    self.audit(message_id, AuditDecision::Rejected, chain_id, Some(e.to_string()), None).await;
+                    self.record_status(message_id, &message, MessageStatus::Failed, Some(&e)).await;
+                }
+            }
+            if let Some(aggregator) = &self.aggregator {
+                aggregator.remove(message_id).await;
+            }
+            return true;
+        }
+
+        // 资产治理消息不向目标链中继，收到后立即切换资产的中继开关
+        if message.message.message_type == ASSET_GOVERNANCE_MESSAGE_TYPE {
+            match self.apply_asset_governance(&message.message) {
+                Ok(action) => {
+                    info!("Applied asset governance {} from {}: {:?}", message_id, chain_id, action);
+                    self.audit(message_id, AuditDecision::Accepted, chain_id, None, None).await;
+                    self.record_status(message_id, &message, MessageStatus::Processed, None).await;
+                }
+                Err(e) => {
+                    error!("Rejected asset governance {}: {}", message_id, e);
+                    self.audit(message_id, AuditDecision::Rejected, chain_id, Some(e.to_string()), None).await;
+                    self.record_status(message_id, &message, MessageStatus::Failed, Some(&e)).await;
+                }
+            }
+            if let Some(aggregator) = &self.aggregator {
+                aggregator.remove(message_id).await;
+            }
+            return true;
+        }
+
+        // 元数据同步消息不作为普通消息提交，由中继器在目标链合约中登记包装代币
+        if message.message.message_type == METADATA_SYNC_MESSAGE_TYPE {
+            let result = self.register_asset(&message.message).await;
+            match &result {
+                Ok(tx_hash) => {
+                    info!("Registered asset metadata {} on {}", message_id, message.message.target_chain);
+                    self.audit(message_id, AuditDecision::Submitted, &message.message.target_chain, None, tx_hash.clone()).await;
+                    self.record_status(message_id, &message, MessageStatus::Processed, None).await;
+                }
+                Err(e) if e.is_retryable() => {
+                    warn!("Failed to register asset metadata {}, will retry: {}", message_id, e);
+                    return false;
+                }
+                Err(e) => {
+                    error!("Rejected asset metadata {}: {}", message_id, e);
+                    self.audit(message_id, AuditDecision::Rejected, chain_id, Some(e.to_string()), None).await;
+                    self.record_status(message_id, &message, MessageStatus::Failed, Some(e)).await;
+                }
+            }
+            if let Some(aggregator) = &self.aggregator {
+                aggregator.remove(message_id).await;
+            }
+            return true;
+        }
+
+        // 接收者无法接收资产时交易必然在链上失败，转入暂缓队列而不是提交；
+        // 证明已在达到阈值时保存，放行后从存储恢复
+        if self.config.relayer.check_recipients && !self.releases.bypasses(message_id, HoldCheck::Recipient) {
+            match self.check_recipient(message_id, &message).await {
+                Ok(None) => {}
+                Ok(Some(reason)) => {
+                    let error = Error::Rejected(reason.clone());
+                    if !self.hold(chain_id, message_id, &message, HoldCheck::Recipient, reason, &error).await {
+                        return false;
                     }
+                    if let Some(aggregator) = &self.aggregator {
+                        aggregator.remove(message_id).await;
+                    }
+                    return true;
+                }
+                Err(e) => {
+                    error!("Failed to check recipient of message {}: {}", message_id, e);
+                    return false;
                 }
             }
+        }
 
-            sleep(Duration::from_secs(self.config.relayer.poll_interval)).await;
+        // 预估成本超过上限时留在低费用队列，gas 价格回落或进入低费用窗口后再提交
+        if let CostDecision::Defer { estimated, cap, first } = self.gas_prices.check(&message.message.target_chain, message_id, now_secs()) {
+            if first {
+                info!("Deferring message {} to {}: estimated cost {} exceeds cap {}", message_id, message.message.target_chain, estimated, cap);
+            }
+            return false;
+        }
+
+        match self.middleware.before_submit(message_id, &message).await {
+            Ok(Verdict::Continue) => {}
+            Ok(Verdict::Defer) => return false,
+            Ok(Verdict::Reject(reason)) => {
+                warn!("Middleware rejected submission of message {}: {}", message_id, reason);
+                self.audit(message_id, AuditDecision::Rejected, chain_id, Some(reason.clone()), None).await;
+                self.record_status(message_id, &message, MessageStatus::Failed, Some(&Error::Rejected(reason))).await;
+                if let Some(aggregator) = &self.aggregator {
+                    aggregator.remove(message_id).await;
+                }
+                return true;
+            }
+            Err(e) => {
+                warn!("Middleware failed before submitting message {}, will retry: {}", message_id, e);
+                return false;
+            }
+        }
+
+        // 批量提交的链在整批提交时占用一个提交名额
+        if self.batcher.is_enabled(&message.message.target_chain) {
+            if self.audit.is_some() && self.audited.lock().unwrap().insert(message_id.to_string()) {
+                self.audit(message_id, AuditDecision::Accepted, chain_id, None, None).await;
+            }
+            let entry = BatchEntry {
+                message_id: message_id.to_string(),
+                chain_id: chain_id.into(),
+                message,
+            };
+            self.batcher.push(entry, now_secs());
+            return false;
+        }
+
+        if let Slot::Deferred { until } = self.scheduler.reserve(&message.message.target_chain, now_secs()) {
+            debug!("Deferring message {} to {} until {}", message_id, message.message.target_chain, until);
+            return false;
+        }
+
+        if self.audit.is_some() && self.audited.lock().unwrap().insert(message_id.to_string()) {
+            self.audit(message_id, AuditDecision::Accepted, chain_id, None, None).await;
+        }
+        if let Err(e) = self.relay_message(chain_id, message_id, message.clone()).await {
+            self.audit(message_id, AuditDecision::Failed, &message.message.target_chain, Some(e.to_string()), None).await;
+            // 可重试的错误留在待处理队列中下一轮重试，重试无法成功的错误直接记为失败，避免反复提交消耗 gas
+            if e.is_retryable() {
+                warn!("Failed to relay message {}, will retry: {}", message_id, e);
+                return false;
+            }
+            error!("Failed to relay message {}: {}", message_id, e);
+            self.metrics.record_failed(&route);
+            self.record_status(message_id, &message, MessageStatus::Failed, Some(&e)).await;
+            if let Some(aggregator) = &self.aggregator {
+                aggregator.remove(message_id).await;
+            }
+            return true;
+        }
+
+        let latency = now_secs().saturating_sub(message.timestamp);
+        self.metrics.record_relayed(&route, latency as f64);
+        self.record_status(message_id, &message, MessageStatus::Processed, None).await;
+        self.middleware.after_confirm(message_id, &message).await;
+        if let Some(aggregator) = &self.aggregator {
+            aggregator.remove(message_id).await;
+        }
+        true
+    }
+
+    /// 将过期消息标记为 Expired，转账消息向源链提交退款，返回消息是否处理完毕
+    ///
+    /// 过期前可能已提交过的消息先向目标链确认原消息未执行：已执行的按已交付处理，不再退款；
+    /// 无法确认时留到下一轮重新检查。退款提交失败时记录为失败的退款消息，不再自动重试，由运营方人工处理。
+    async fn expire(&self, chain_id: &str, message_id: &str, message: &SignedMessage) -> bool {
+        match self.delivered_before_expiry(message_id, message).await {
+            Ok(true) => {
+                info!("Expired message {} was already executed on {}, not refunding", message_id, message.message.target_chain);
+                self.record_status(message_id, message, MessageStatus::Processed, None).await;
+                return true;
+            }
+            Ok(false) => {}
+            Err(e) => {
+                warn!("Cannot confirm expired message {} was not executed, will retry: {}", message_id, e);
+                return false;
+            }
+        }
+
+        let age = now_secs().saturating_sub(message.timestamp);
+        warn!("Message {} expired after {} seconds without being relayed", message_id, age);
+        self.audit(message_id, AuditDecision::Rejected, chain_id, Some(format!("Expired after {} seconds", age)), None).await;
+
+        let refund = match expiry::refund_message(&self.config, message_id, message, now_secs()) {
+            Ok(Some(refund)) => refund,
+            Ok(None) => {
+                self.record_status(message_id, message, MessageStatus::Expired, None).await;
+                return true;
+            }
+            Err(e) => {
+                error!("Cannot refund expired message {}: {}", message_id, e);
+                self.record_status(message_id, message, MessageStatus::Expired, Some(&e)).await;
+                return true;
+            }
+        };
+        self.record_status(message_id, message, MessageStatus::Expired, None).await;
+
+        let refund_id = match refund.message.message_id(&self.chains) {
+            Ok(refund_id) => refund_id,
+            Err(e) => {
+                error!("Failed to compute refund message ID for {}: {}", message_id, e);
+                return true;
+            }
+        };
+        let refund_chain = refund.message.source_chain.clone();
+        self.record_status(&refund_id, &refund, MessageStatus::Pending, None).await;
+        match self.relay_message(&refund_chain, &refund_id, refund.clone()).await {
+            Ok(()) => {
+                info!("Refunded expired message {} with {}", message_id, refund_id);
+                self.record_status(&refund_id, &refund, MessageStatus::Processed, None).await;
+            }
+            Err(e) => {
+                error!("Failed to refund expired message {}: {}", message_id, e);
+                self.audit(&refund_id, AuditDecision::Failed, &refund.message.target_chain, Some(e.to_string()), None).await;
+                self.record_status(&refund_id, &refund, MessageStatus::Failed, Some(&e)).await;
+            }
+        }
+        true
+    }
+
+    /// 目标链合约是否已执行过期的原消息，按提交时的形式（换算金额后）查询
+    ///
+    /// 目标链未知或金额无法换算的消息不可能被提交过，视为未执行。
+    async fn delivered_before_expiry(&self, message_id: &str, message: &SignedMessage) -> Result<bool, Error> {
+        let adapters = self.chain_adapters.read().await;
+        let Some(adapter) = adapters.get(&message.message.target_chain) else {
+            return Ok(false);
+        };
+        let Ok(submitted) = self.rescale_amount(message_id, message.clone()) else {
+            return Ok(false);
+        };
+        adapter.is_message_processed(&submitted).await
+    }
+
+    async fn process_chain_events(&self, adapter: &dyn ChainAdapter) -> Result<Vec<SignedMessage>, Error> {
+        match adapter.listen_events().await {
+            Ok(messages) => Ok(messages),
+            Err(e) => Err(version::classify_error(adapter, e).await),
         }
     }
 
-    async fn process_chain_events(&self, chain_id: &str, adapter: &dyn ChainAdapter) -> Result<Vec<SignedMessage>, Error> {
+    /// 本实例是否负责提交交易，未配置协调时始终为 true
+    pub fn is_leader(&self) -> bool {
+        self.election.as_ref().is_none_or(|election| election.is_leader())
+    }
+
+    /// 获取领导者选举状态，未配置协调时为 None
+    pub fn election(&self) -> Option<Arc<LeaderElection>> {
+        self.election.clone()
+    }
+
+    /// 存储中的消息是否已处理完成
+    async fn is_processed(&self, message_id: &str) -> bool {
+        let Some(store) = &self.store else {
+            return false;
+        };
+        matches!(
+            store.get(message_id).await,
+            Ok(Some(record)) if record.status == MessageStatus::Processed
+        )
+    }
+
+    /// 获取证明聚合器，未启用证明收集时为 None
+    pub fn aggregator(&self) -> Option<Arc<AttestationAggregator>> {
+        self.aggregator.clone()
+    }
+
+    /// 获取消息状态变化的广播源
+    pub fn status_feed(&self) -> StatusFeed {
+        self.status_feed.clone()
+    }
+
+    /// 广播消息状态并写入存储，未配置存储时只广播
+    async fn record_status(&self, message_id: &str, message: &SignedMessage, status: MessageStatus, error: Option<&Error>) {
+        let now = now_secs();
+        self.status_feed.publish(StatusEvent {
+            message_id: message_id.to_string(),
+            source_chain: message.message.source_chain.to_string(),
+            target_chain: message.message.target_chain.to_string(),
+            nonce: message.message.nonce,
+            status: status.clone(),
+            sender: message.sender.clone(),
+            recipient: Transfer::parse(&self.config, message_id, &message.message)
+                .map(|transfer| hex::encode(transfer.recipient())),
+            error: error.map(|e| e.to_string()),
+            timestamp: now,
+        });
+        if let (Some(alerts), MessageStatus::Failed) = (&self.alerts, &status) {
+            alerts.record_dead_letter(now).await;
+        }
+        let Some(store) = &self.store else {
+            return;
+        };
+
+        // 保留首次观察到消息的时间，用于计算交付延迟
+        let observed_at = match store.get(message_id).await {
+            Ok(Some(existing)) => existing.observed_at,
+            _ => now,
+        };
+        let transfer = Transfer::parse(&self.config, message_id, &message.message);
+        let record = MessageRecord {
+            message_id: message_id.to_string(),
+            source_chain: message.message.source_chain.to_string(),
+            target_chain: message.message.target_chain.to_string(),
+            asset: metrics::route_asset(&self.config, &message.message.source_chain, &message.message.target_chain),
+            sender: message.sender.clone(),
+            recipient: transfer.as_ref().map(|t| hex::encode(t.recipient())),
+            nonce: message.message.nonce,
+            completed_at: (status != MessageStatus::Pending).then_some(now),
+            status,
+            observed_at,
+            error: error.map(|e| e.to_string()),
+            cause: error.map(Error::cause),
+        };
+        if let Err(e) = store.upsert(&record).await {
+            warn!("Failed to persist message {}: {}", message_id, e);
+        }
+
+        // 转账消息同步记账：观察到时记转出，到账时补记转出并记到账，分录幂等
+        let Some(transfer) = transfer else {
+            return;
+        };
+        let entries = match record.status {
+            MessageStatus::Pending => transfer.initiated(observed_at),
+            MessageStatus::Processed => [transfer.initiated(observed_at), transfer.delivered(now)].concat(),
+            MessageStatus::Failed | MessageStatus::Held | MessageStatus::Expired => return,
+        };
+        if let Err(e) = store.append_ledger(&entries).await {
+            warn!("Failed to record ledger entries for {}: {}", message_id, e);
+        }
+    }
+
+    /// 按资产配置核对元数据同步消息后在目标链登记，返回交易哈希
+    ///
+    /// 消息须来自资产的原生链，且内容与本地配置生成的元数据一致，避免登记被篡改的名称或精度。
+    async fn register_asset(&self, message: &CrossChainMessage) -> Result<Option<String>, Error> {
+        let metadata = MetadataSync::decode(&message.payload)?;
+        let asset = self
+            .config
+            .assets
+            .iter()
+            .find(|asset| asset.name == metadata.name && asset.native_chain == message.source_chain.as_str())
+            .ok_or_else(|| Error::Rejected(format!("Unknown asset {} from {}", metadata.name, message.source_chain)))?;
+        if MetadataSync::from_asset(asset, &message.target_chain)? != metadata {
+            return Err(Error::Rejected(format!("Metadata of asset {} does not match the configuration", metadata.name)));
+        }
+        let adapters = self.chain_adapters.read().await;
+        let adapter = adapters
+            .get(&message.target_chain)
+            .ok_or_else(|| Error::Chain(format!("Target chain adapter not found: {}", message.target_chain)))?;
+        adapter.register_asset(&metadata).await
+    }
+
+    /// 在目标链检查转账接收者，非转账消息不检查
+    async fn check_recipient(&self, message_id: &str, message: &SignedMessage) -> Result<Option<String>, Error> {
+        let Some(transfer) = Transfer::parse(&self.config, message_id, &message.message) else {
+            return Ok(None);
+        };
+        let target_chain_id = &message.message.target_chain;
+        let asset_type = transfer.target_asset_type().ok_or_else(|| {
+            Error::Config(format!("Asset {} has no mapping on chain {}", transfer.asset.name, target_chain_id))
+        })?;
+        let adapters = self.chain_adapters.read().await;
+        let adapter = adapters
+            .get(target_chain_id)
+            .ok_or_else(|| Error::Chain(format!("Target chain adapter not found: {}", target_chain_id)))?;
+        adapter.check_recipient(transfer.recipient(), asset_type).await
+    }
+
+    /// 验证消息的包含证明，消息未附带证明时向源链适配器获取
+    async fn check_proof(&self, chain_id: &str, message: &SignedMessage) -> Result<(), Error> {
+        let Some(proofs) = &self.proofs else {
+            return Ok(());
+        };
+        let proof = match &message.proof {
+            Some(proof) => proof.clone(),
+            None => {
+                let adapters = self.chain_adapters.read().await;
+                let adapter = adapters
+                    .get(chain_id)
+                    .ok_or_else(|| Error::Chain(format!("Source chain adapter not found: {}", chain_id)))?;
+                adapter.inclusion_proof(message).await?
+                    .ok_or_else(|| Error::InvalidProof(format!("Chain {} provided no inclusion proof", chain_id)))?
+            }
+        };
+        if let Some(provenance) = proofs.verify(chain_id, &message.message, &proof)? {
+            debug!("Proved message {} at block {} in {}", message.message.nonce, provenance.block, provenance.tx_hash);
+        }
+        Ok(())
+    }
+
+    /// 保存源链事件，供排查时导出
+    async fn record_source(&self, chain_id: &str, message_id: &str, message: &SignedMessage) {
+        let Some(store) = &self.store else {
+            return;
+        };
+        let redacted = confidential::redact(&message.message);
+        let source = SourceRecord {
+            message_id: message_id.to_string(),
+            chain: chain_id.to_string(),
+            event_name: message.event_name.clone(),
+            sender: message.sender.clone(),
+            provenance: message.provenance.clone(),
+            payload_redacted: redacted.is_some(),
+            message: redacted.unwrap_or_else(|| message.message.clone()),
+            signature: message.signature.clone(),
+            observed_at: now_secs(),
+        };
+        if let Err(e) = store.record_source(&source).await {
+            warn!("Failed to record source event of {}: {}", message_id, e);
+        }
+    }
+
+    /// 查询交易消耗的 gas 并分摊记入各条消息的账目，未配置存储或无法查询时忽略
+    async fn record_gas(&self, adapter: &dyn ChainAdapter, tx_hash: &str, messages: &[(&str, &CrossChainMessage)]) {
+        let Some(store) = &self.store else {
+            return;
+        };
+        let gas = match adapter.transaction_gas(tx_hash).await {
+            Ok(Some(gas)) => gas,
+            Ok(None) => return,
+            Err(e) => {
+                warn!("Failed to query gas used by {}: {}", tx_hash, e);
+                return;
+            }
+        };
+        let now = now_secs();
+        let entries: Vec<_> = messages
+            .iter()
+            .zip(fees::split_gas(gas, messages.len()))
+            .flat_map(|((message_id, message), gas)| fees::gas_spent(message_id, message, gas, now))
+            .collect();
+        if let Err(e) = store.append_ledger(&entries).await {
+            warn!("Failed to record gas spent by {}: {}", tx_hash, e);
+        }
+    }
+
+    /// 保存一次提交尝试，未配置存储时忽略
+    async fn record_submission(&self, submission: SubmissionRecord) {
+        if let Some(store) = &self.store {
+            if let Err(e) = store.record_submission(&submission).await {
+                warn!("Failed to record submission of {}: {}", submission.message_id, e);
+            }
+        }
+    }
+
+    async fn record_retry(&self, retry: RetryRecord) {
+        if let Some(store) = &self.store {
+            if let Err(e) = store.record_retry(&retry).await {
+                warn!("Failed to record retry of {}: {}", retry.message_id, e);
+            }
+        }
+    }
+
+    /// 将未通过中继前检查的消息转入暂缓队列，返回消息是否移出待处理队列
+    ///
+    /// 暂缓队列保存在消息存储中，经管理接口或 `bridge-cli held release` 放行后重新处理；
+    /// 配置校验要求会暂缓消息的检查配置存储，写入存储失败时消息留在待处理队列中每轮重新检查。
+    async fn hold(&self, chain_id: &str, message_id: &str, message: &SignedMessage, check: HoldCheck, reason: String, error: &Error) -> bool {
+        let Some(store) = &self.store else {
+            debug!("Message {} held without storage, will recheck: {}", message_id, reason);
+            return false;
+        };
+        warn!("Holding message {}: {}", message_id, reason);
+        self.audit(message_id, AuditDecision::Held, chain_id, Some(reason.clone()), None).await;
+        if let Err(e) = store.hold(&HeldMessage::new(chain_id, message_id, message, check, reason, now_secs())).await {
+            error!("Failed to persist held message {}, will recheck: {}", message_id, e);
+            return false;
+        }
+        self.record_status(message_id, message, MessageStatus::Held, Some(error)).await;
+        true
+    }
+
+    /// 人工放行的暂缓消息移出暂缓队列，重新加入待处理队列并恢复已保存的证明
+    async fn resume_held(&self, state: &mut RelayState) {
+        let Some(store) = &self.store else {
+            return;
+        };
+        let held = match store.held().await {
+            Ok(held) => held,
+            Err(e) => {
+                warn!("Failed to read held messages: {}", e);
+                return;
+            }
+        };
+        for held in held.into_iter().filter(|held| held.released_at.is_some()) {
+            if state.pending.len() >= self.config.relayer.queue_capacity {
+                break;
+            }
+            let message = held.signed_message();
+            if let Some(aggregator) = &self.aggregator {
+                let attested = ledger::submitted_message(&self.config, &message.message)
+                    .and_then(|submitted| submitted.message_id(&self.chains));
+                match attested {
+                    Ok(attested_id) => match store.attestations(&attested_id).await {
+                        Ok(attestations) => {
+                            aggregator.submit_batch(attestations).await;
+                        }
+                        Err(e) => warn!("Failed to restore attestations of {}: {}", held.message_id, e),
+                    },
+                    Err(e) => warn!("Failed to restore attestations of {}: {}", held.message_id, e),
+                }
+            }
+            if let Err(e) = store.remove_held(&held.message_id).await {
+                warn!("Failed to remove released message {} from held queue: {}", held.message_id, e);
+                continue;
+            }
+            info!("Resuming released message {}", held.message_id);
+            self.releases.admit(&held.message_id, held.check);
+            self.record_status(&held.message_id, &message, MessageStatus::Pending, None).await;
+            state.pending.insert(held.message_id, (held.chain.as_str().into(), message));
+        }
+    }
+
+    /// 消息处理完毕，保存因此可以前进的源链事件游标
+    async fn finish_cursors(&self, message_id: &str) {
+        for (chain_id, cursors) in self.cursor_commits.finish(message_id) {
+            self.save_cursors(&chain_id, &cursors).await;
+        }
+    }
+
+    /// 把源链事件游标写入存储；备用实例不写，避免以落后的进度覆盖领导者保存的游标
+    async fn save_cursors(&self, chain_id: &ChainId, cursors: &HashMap<String, u64>) {
+        let Some(store) = &self.store else {
+            return;
+        };
+        if !self.is_leader() {
+            return;
+        }
+        if let Err(e) = store.save_cursors(chain_id.as_str(), cursors).await {
+            warn!("Failed to save event cursors of {}: {}", chain_id, e);
+        }
+    }
+
+    /// 保存消息达到阈值时的验证者证明
+    async fn record_attestations(&self, message_id: &str) {
+        let (Some(store), Some(aggregator)) = (&self.store, &self.aggregator) else {
+            return;
+        };
+        for mut attestation in aggregator.attestations(message_id).await {
+            if let Some(redacted) = confidential::redact(&attestation.message) {
+                attestation.message = redacted;
+            }
+            if let Err(e) = store.record_attestation(&attestation).await {
+                warn!("Failed to record attestation of {}: {}", message_id, e);
+            }
+        }
+    }
+
+    /// 按事件过滤器配置的处理器处理消息
+    async fn dispatch_event(&self, chain_id: &str, message: SignedMessage) -> Result<Option<SignedMessage>, Error> {
         let chain_config = self.config.get_chain_config(chain_id)
             .ok_or_else(|| Error::Config(format!("Chain config not found: {}", chain_id)))?;
-        adapter.listen_events(chain_config).await
+        self.handlers.dispatch(chain_config, message).await
     }
 
-    async fn relay_message(&self, source_chain_id: &str, message: SignedMessage) -> Result<(), Error> {
-        let target_chain_id = &message.message.target_chain;
+    async fn relay_message(&self, source_chain_id: &str, message_id: &str, message: SignedMessage) -> Result<(), Error> {
+        let target_chain_id = message.message.target_chain.clone();
         let adapters = self.chain_adapters.read().await;
         let target_adapter = adapters
-            .get(target_chain_id)
+            .get(&target_chain_id)
             .ok_or_else(|| Error::Chain(format!("Target chain adapter not found: {}", target_chain_id)))?;
         
-        let target_config = self.config.get_chain_config(target_chain_id)
-            .ok_or_else(|| Error::Config(format!("Chain config not found: {}", target_chain_id)))?;
-        
         // 验证消息
         if let Err(e) = self.verify_message(&message).await {
             error!("Message verification failed: {}", e);
             return Err(e);
         }
 
-        // 重试提交消息
+        // 转账金额按目标链精度换算后提交
+        let message = self.rescale_amount(message_id, message)?;
+
+        // 多个中继器竞争提交时，合约已处理的消息不再提交
+        if self.already_processed(target_adapter.as_ref(), message_id, &message).await {
+            return Ok(());
+        }
+
+        // 按目标链的重试参数重试提交消息
+        let settings = self.config.relayer_settings(&target_chain_id);
         let mut retry_count = 0;
-        let max_retries = self.config.relayer.max_retries;
-        let base_delay = self.config.relayer.retry_delay;
+        let mut attempt = 0;
+        let max_retries = settings.max_retries;
+        let base_delay = settings.retry_delay;
+        // 上一笔卡住的交易及本次为第几次重新提交
+        let mut resubmit: Option<(String, u32)> = None;
 
         loop {
-            match target_adapter.submit_message(target_config, message.clone()).await {
-                Ok(_) => {
-                    info!("Successfully relayed message from {} to {}", source_chain_id, target_chain_id);
+            attempt += 1;
+            let result = match &resubmit {
+                Some((stuck_tx, resubmission)) => target_adapter.resubmit_message(message.clone(), stuck_tx, *resubmission).await,
+                None => target_adapter.submit_message(message.clone()).await,
+            };
+            self.record_submission(SubmissionRecord {
+                message_id: message_id.to_string(),
+                chain: target_chain_id.to_string(),
+                attempt,
+                tx_hash: result.as_ref().ok().cloned(),
+                error: result.as_ref().err().map(|e| e.to_string()),
+                submitted_at: now_secs(),
+            }).await;
+
+            // 超时未确认的交易不计入重试次数，由看门狗限制重新提交次数
+            if let Err(Error::Stuck { tx_hash, timeout }) = &result {
+                match self.watchdog.observe(message_id, &target_chain_id, tx_hash, settings.max_resubmissions, now_secs()) {
+                    StuckDecision::Resubmit { attempt: resubmission } => {
+                        warn!("Transaction {} for message {} not confirmed within {} seconds, resubmitting ({}/{})",
+                              tx_hash, message_id, timeout, resubmission, settings.max_resubmissions);
+                        resubmit = Some((tx_hash.clone(), resubmission));
+                        continue;
+                    }
+                    StuckDecision::GiveUp { resubmissions } => {
+                        error!("Transaction {} for message {} still not confirmed after {} resubmissions", tx_hash, message_id, resubmissions);
+                        return Err(Error::RetriesExhausted {
+                            attempts: resubmissions + 1,
+                            last: format!("Transaction {} not confirmed within {} seconds", tx_hash, timeout),
+                        });
+                    }
+                }
+            }
+
+            match result {
+                Ok(tx_hash) => {
+                    info!("Successfully relayed message from {} to {} in {}", source_chain_id, target_chain_id, tx_hash);
+                    self.watchdog.resolve(message_id);
+                    self.record_gas(target_adapter.as_ref(), &tx_hash, &[(message_id, &message.message)]).await;
+                    self.audit(message_id, AuditDecision::Submitted, &target_chain_id, None, Some(tx_hash)).await;
                     return Ok(());
                 }
                 Err(e) => {
+                    // 节点版本不兼容、合约拒绝等重试无意义的错误直接返回
+                    let e = version::classify_error(target_adapter.as_ref(), e).await;
+                    // 检查之后被其他中继器抢先提交，消息已送达
+                    if let Error::AlreadyProcessed(_) = &e {
+                        info!("Message {} was processed on {} by another submission", message_id, target_chain_id);
+                        self.watchdog.resolve(message_id);
+                        self.audit(message_id, AuditDecision::Submitted, &target_chain_id, Some(e.to_string()), None).await;
+                        self.metrics.record_submission_skipped(&target_chain_id);
+                        return Ok(());
+                    }
+                    if e.is_terminal() {
+                        return Err(e);
+                    }
+
                     retry_count += 1;
                     if retry_count >= max_retries {
                         error!("Max retries ({}) reached for message relay. Last error: {}", max_retries, e);
-                        return Err(Error::Chain(format!("Max retries reached: {}", e)));
+                        return Err(Error::RetriesExhausted { attempts: attempt, last: e.to_string() });
                     }
                     
                     // 使用线性增长的重试延迟，避免等待时间过长
                     let delay = base_delay * retry_count as u64;
+                    self.record_retry(RetryRecord {
+                        message_id: message_id.to_string(),
+                        attempt: retry_count + 1,
+                        reason: e.to_string(),
+                        retry_at: now_secs() + delay,
+                    }).await;
                     warn!("Retry {}/{} for message relay after {} seconds. Error: {}", 
                           retry_count, max_retries, delay, e);
                     sleep(Duration::from_secs(delay)).await;
@@ -138,6 +1940,135 @@ impl Relayer {
         }
     }
 
+    /// 验证一批消息并在一笔交易中提交，返回每条消息的结果
+    ///
+    /// 未通过验证的消息不进入交易；交易失败时整批消息均视为失败，多条消息的批次被合约拒绝时改为逐条提交。
+    async fn relay_batch(&self, target_chain_id: &ChainId, entries: Vec<BatchEntry>) -> Vec<(BatchEntry, Result<(), Error>)> {
+        let mut results = Vec::new();
+        let mut batch = Vec::new();
+        for entry in entries {
+            let verified = match self.verify_message(&entry.message).await {
+                Ok(_) => self.rescale_amount(&entry.message_id, entry.message.clone()),
+                Err(e) => Err(e),
+            };
+            match verified {
+                Ok(message) => batch.push((entry, message)),
+                Err(e) => {
+                    error!("Message verification failed: {}", e);
+                    results.push((entry, Err(e)));
+                }
+            }
+        }
+        if batch.is_empty() {
+            return results;
+        }
+
+        let adapters = self.chain_adapters.read().await;
+        // 已由其他中继器提交的消息不进入交易
+        if let Some(adapter) = adapters.get(target_chain_id) {
+            let mut unprocessed = Vec::with_capacity(batch.len());
+            for (entry, message) in batch {
+                if self.already_processed(adapter.as_ref(), &entry.message_id, &message).await {
+                    results.push((entry, Ok(())));
+                } else {
+                    unprocessed.push((entry, message));
+                }
+            }
+            batch = unprocessed;
+            if batch.is_empty() {
+                return results;
+            }
+        }
+        let messages = batch.iter().map(|(_, message)| message.clone()).collect();
+        let result = match adapters.get(target_chain_id) {
+            Some(adapter) => match adapter.submit_messages_batch(messages).await {
+                Ok(tx_hash) => Ok(tx_hash),
+                Err(e) => Err(version::classify_error(adapter.as_ref(), e).await),
+            },
+            None => Err(Error::Chain(format!("Target chain adapter not found: {}", target_chain_id))),
+        };
+        if let (Ok(tx_hash), Some(adapter)) = (&result, adapters.get(target_chain_id)) {
+            let messages: Vec<_> = batch.iter().map(|(entry, message)| (entry.message_id.as_str(), &message.message)).collect();
+            self.record_gas(adapter.as_ref(), tx_hash, &messages).await;
+        }
+        drop(adapters);
+
+        for (entry, _) in &batch {
+            self.record_submission(SubmissionRecord {
+                message_id: entry.message_id.clone(),
+                chain: target_chain_id.to_string(),
+                attempt: 1,
+                tx_hash: result.as_ref().ok().cloned(),
+                error: result.as_ref().err().map(|e| e.to_string()),
+                submitted_at: now_secs(),
+            }).await;
+        }
+        match result {
+            Ok(tx_hash) => {
+                info!("Successfully relayed {} messages to {} in {}", batch.len(), target_chain_id, tx_hash);
+                for (entry, _) in batch {
+                    self.audit(&entry.message_id, AuditDecision::Submitted, target_chain_id, None, Some(tx_hash.clone())).await;
+                    results.push((entry, Ok(())));
+                }
+            }
+            // 合约拒绝时无法确定是哪条消息导致，逐条提交以免整批反复失败
+            Err(e) if e.is_terminal() && batch.len() > 1 => {
+                warn!("Batch submission to {} rejected, submitting {} messages individually: {}", target_chain_id, batch.len(), e);
+                for (entry, _) in batch {
+                    let result = self.relay_message(&entry.chain_id, &entry.message_id, entry.message.clone()).await;
+                    results.push((entry, result));
+                }
+            }
+            Err(e) if e.is_terminal() => {
+                if let Some((entry, _)) = batch.into_iter().next() {
+                    // 与逐条提交一致，合约拒绝重复执行视为已送达
+                    if let Error::AlreadyProcessed(_) = &e {
+                        info!("Message {} was processed on {} by another submission", entry.message_id, target_chain_id);
+                        self.audit(&entry.message_id, AuditDecision::Submitted, target_chain_id, Some(e.to_string()), None).await;
+                        self.metrics.record_submission_skipped(target_chain_id);
+                        results.push((entry, Ok(())));
+                    } else {
+                        results.push((entry, Err(e)));
+                    }
+                }
+            }
+            Err(e) => {
+                for (entry, _) in batch {
+                    results.push((entry, Err(Error::Chain(format!("Batch submission failed: {}", e)))));
+                }
+            }
+        }
+        results
+    }
+
+    /// 目标链合约是否已处理该消息；查询失败时按未处理继续提交，重复提交由合约拒绝
+    async fn already_processed(&self, adapter: &dyn ChainAdapter, message_id: &str, message: &SignedMessage) -> bool {
+        let target_chain_id = &message.message.target_chain;
+        match adapter.is_message_processed(message).await {
+            Ok(true) => {
+                info!("Message {} already processed on {}, skipping submission", message_id, target_chain_id);
+                let reason = "Already processed on target chain".to_string();
+                self.audit(message_id, AuditDecision::Submitted, target_chain_id, Some(reason), None).await;
+                self.metrics.record_submission_skipped(target_chain_id);
+                true
+            }
+            Ok(false) => false,
+            Err(e) => {
+                warn!("Failed to check whether message {} was processed on {}: {}", message_id, target_chain_id, e);
+                false
+            }
+        }
+    }
+
+    /// 将转账负载中的金额换算为目标链精度，非转账消息原样返回；提交的消息均带上源链消息 ID 作为追踪 ID
+    ///
+    /// 换算结果与验证者签名的消息一致（见 [`ledger::submitted_message`]），尾差在交付时记入账本。
+    fn rescale_amount(&self, message_id: &str, mut message: SignedMessage) -> Result<SignedMessage, Error> {
+        message.trace_id = Some(message_id.to_string());
+        message.message = ledger::submitted_message(&self.config, &message.message)?;
+        Ok(message)
+    }
+
     async fn verify_message(&self, message: &SignedMessage) -> Result<bool, Error> {
         // 验证时间戳
         let current_time = std::time::SystemTime::now()
@@ -145,31 +2076,23 @@ impl Relayer {
             .map_err(|e| Error::Chain(format!("Failed to get current time: {}", e)))?
             .as_secs();
 
-        // 检查消息是否在有效时间窗口内（1小时内）
+        // 过期消息在进入提交流程前已由 `expire` 处理
         if message.timestamp > current_time {
-            return Err(Error::Chain("Message timestamp is in the future".to_string()));
-        }
-        if current_time - message.timestamp > 3600 {
-            return Err(Error::Chain("Message has expired (older than 1 hour)".to_string()));
+            return Err(Error::InvalidMessage("Message timestamp is in the future".to_string()));
         }
 
         // 验证源链和目标链
-        if !self.config.chains.iter().any(|c| c.id == message.message.source_chain) {
-            return Err(Error::Chain(format!("Invalid source chain: {}", message.message.source_chain)));
-        }
-        if !self.config.chains.iter().any(|c| c.id == message.message.target_chain) {
-            return Err(Error::Chain(format!("Invalid target chain: {}", message.message.target_chain)));
-        }
+        verify::check_route(&self.chains, &message.message)?;
 
         // 验证资产映射
         if message.message.message_type == "transfer" {
             let asset_configs = &self.config.assets;
             let valid_transfer = asset_configs.iter().any(|asset| {
-                asset.native_chain == message.message.source_chain &&
-                asset.mappings.contains_key(&message.message.target_chain)
+                message.message.source_chain == asset.native_chain &&
+                asset.mappings.contains_key(message.message.target_chain.as_str())
             });
             if !valid_transfer {
-                return Err(Error::Chain(format!(
+                return Err(Error::InvalidMessage(format!(
                     "Invalid asset transfer mapping from {} to {}", 
                     message.message.source_chain, 
                     message.message.target_chain
@@ -184,15 +2107,59 @@ impl Relayer {
     }
 
     pub async fn process_message(&self, chain_id: &str, message: SignedMessage) -> Result<(), Error> {
-        let chain_config = self.config.get_chain_config(chain_id)
-            .ok_or_else(|| Error::Config(format!("Chain config not found: {}", chain_id)))?;
-            
         let adapters = self.chain_adapters.read().await;
         if let Some(adapter) = adapters.get(chain_id) {
-            adapter.submit_message(chain_config, message).await?;
+            let tx_hash = adapter.submit_message(message).await?;
+            debug!("Submitted message to {} in {}", chain_id, tx_hash);
             Ok(())
         } else {
             Err(Error::Chain(format!("Chain adapter not found: {}", chain_id)))
         }
     }
-}
\ No newline at end of file
+}
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+// 事件处理器
+pub mod handler;
+// 验证者证明聚合
+pub mod aggregator;
+// 验证者双签检测与证据
+pub mod equivocation;
+// 多实例领导者选举
+pub mod leader;
+// 按链调度交易提交
+pub mod scheduler;
+// gas 价格与提交成本上限
+pub mod gas;
+// 验证者集合更新的变更幅度限制
+pub mod guard;
+// 治理消息驱动的验证者集合轮换
+pub mod rotation;
+// 大额转账时间锁
+pub mod timelock;
+// 卡住交易的检测与重新提交
+pub mod watchdog;
+// 过期消息与退款
+pub mod expiry;
+// 按目标链批量提交
+pub mod batch;
+// 同一链对的交付顺序
+pub mod ordering;
+// 事件读取与中继处理之间的有界队列
+pub mod queue;
+// 待处理消息的优先级评分
+pub mod priority;
+// 按资产暂停中继
+pub mod pause;
+// 暂缓队列中放行的消息
+pub mod held;
+// 源链事件游标的提交进度
+pub mod cursor;
+// 嵌入方注入的中继流程中间件
+pub mod middleware;
@@ -0,0 +1,104 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use tracing::warn;
+use crate::{
+    config::{Config, OrderingConfig, OrderingMode},
+    types::{ChainId, SignedMessage},
+};
+
+/// 按目标链配置的交付顺序约束
+///
+/// 严格模式下，同一源链发往同一目标链的消息按 nonce 依次交付：只要中继器已知的
+/// 较小 nonce 消息尚未确认，较大 nonce 的消息就暂缓提交。中继器从未观察到的
+/// 前序消息无法等待，顺序只在已观察到的消息之间保证。
+pub struct DeliveryOrdering {
+    configs: HashMap<ChainId, OrderingConfig>,
+    /// 已因超时不再等待的前序消息（源链、目标链、nonce），只记录一次警告
+    skipped: Mutex<HashSet<(ChainId, ChainId, u64)>>,
+}
+
+impl DeliveryOrdering {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            configs: config.chains
+                .iter()
+                .filter_map(|chain| {
+                    let ordering = chain.ordering.clone()?;
+                    (ordering.mode == OrderingMode::Strict).then(|| (chain.id.clone(), ordering))
+                })
+                .collect(),
+            skipped: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// 返回阻塞该消息的最小前序 nonce，无需等待时返回 None
+    ///
+    /// `outstanding` 为尚未确认的消息；前序消息超过 `skip_after` 仍未确认时不再阻塞。
+    pub fn held_back_by<'a>(
+        &self,
+        message: &SignedMessage,
+        outstanding: impl IntoIterator<Item = &'a SignedMessage>,
+        now: u64,
+    ) -> Option<u64> {
+        let route = &message.message;
+        let config = self.configs.get(&route.target_chain)?;
+        let mut blocking = None;
+        for predecessor in outstanding {
+            let candidate = &predecessor.message;
+            if candidate.source_chain != route.source_chain
+                || candidate.target_chain != route.target_chain
+                || candidate.nonce >= route.nonce
+            {
+                continue;
+            }
+            if now >= predecessor.timestamp.saturating_add(config.skip_after) {
+                let key = (candidate.source_chain.clone(), candidate.target_chain.clone(), candidate.nonce);
+                if self.skipped.lock().unwrap().insert(key) {
+                    warn!(
+                        "Nonce {} from {} to {} unconfirmed after {} seconds, no longer holding back later messages",
+                        candidate.nonce, candidate.source_chain, candidate.target_chain, config.skip_after
+                    );
+                }
+                continue;
+            }
+            blocking = Some(blocking.map_or(candidate.nonce, |nonce: u64| nonce.min(candidate.nonce)));
+        }
+        blocking
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TimelockConfig;
+    use crate::testing::{transfer_payload, two_chain_config, TwoChainHarness, TARGET_CHAIN};
+
+    #[tokio::test]
+    async fn test_strict_ordering_holds_back_later_nonces() {
+        let mut config = two_chain_config();
+        config.chains[1].ordering = Some(OrderingConfig { mode: OrderingMode::Strict, skip_after: 600 });
+        config.assets[0].timelock = Some(TimelockConfig { threshold: 150, delay: 1_800 });
+        let mut harness = TwoChainHarness::with_config(config.clone()).await.unwrap();
+        let first = harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([1u8; 32], 200));
+        let second = harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([1u8; 32], 100));
+
+        // 第一条处于时间锁延迟期，第二条不越过它先提交
+        harness.tick().await;
+        assert!(harness.target.submitted().is_empty());
+        assert_eq!(harness.backlog(), 2);
+
+        // 第一条被取消后不再阻塞
+        let timelock = harness.relayer.api_state().unwrap().timelock.unwrap();
+        assert!(timelock.cancel(&timelock.held()[0].message_id));
+        harness.tick().await;
+        let nonces: Vec<_> = harness.target.submitted().iter().map(|m| m.message.nonce).collect();
+        assert_eq!(nonces, vec![second.message.nonce]);
+        assert_eq!(harness.backlog(), 0);
+
+        // 前序消息超时后不再阻塞
+        let ordering = DeliveryOrdering::new(&config);
+        assert_eq!(ordering.held_back_by(&second, [&first], first.timestamp), Some(first.message.nonce));
+        assert_eq!(ordering.held_back_by(&second, [&first], first.timestamp + 600), None);
+        assert_eq!(ordering.held_back_by(&first, [&second], first.timestamp), None);
+    }
+}
@@ -0,0 +1,128 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Mutex;
+use crate::{config::Config, Error};
+
+/// 一个已暂停的资产
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AssetPause {
+    pub asset: String,
+    pub reason: String,
+    pub paused_at: u64,
+}
+
+/// 暂停检查的结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PauseDecision {
+    Allow,
+    /// 资产已暂停，`first` 表示消息刚因暂停被保留
+    Hold { reason: String, first: bool },
+}
+
+/// 已暂停中继的资产
+///
+/// 暂停资产的转账保留在待处理队列中，恢复后下一轮继续中继；其它资产与非转账消息不受影响。
+/// 初始状态取自资产配置的 `paused`，运行时由管理接口、资产治理消息或资产对账切换，重启后恢复为配置状态。
+pub struct AssetPauses {
+    /// 已配置的资产，只能暂停其中的资产
+    assets: Vec<String>,
+    paused: Mutex<BTreeMap<String, AssetPause>>,
+    /// 因资产暂停而保留的消息 ID
+    held: Mutex<HashSet<String>>,
+}
+
+impl AssetPauses {
+    pub fn new(config: &Config, now: u64) -> Self {
+        let paused = config
+            .assets
+            .iter()
+            .filter(|asset| asset.paused)
+            .map(|asset| {
+                let pause = AssetPause { asset: asset.name.clone(), reason: "Paused by configuration".to_string(), paused_at: now };
+                (asset.name.clone(), pause)
+            })
+            .collect();
+        Self {
+            assets: config.assets.iter().map(|asset| asset.name.clone()).collect(),
+            paused: Mutex::new(paused),
+            held: Mutex::new(HashSet::new()),
+        }
+    }
+
+    fn ensure_known(&self, asset: &str) -> Result<(), Error> {
+        if !self.assets.iter().any(|name| name == asset) {
+            return Err(Error::Config(format!("Unknown asset: {}", asset)));
+        }
+        Ok(())
+    }
+
+    /// 暂停资产，返回资产此前是否未暂停；已暂停时保留原来的原因
+    pub fn pause(&self, asset: &str, reason: String, now: u64) -> Result<bool, Error> {
+        self.ensure_known(asset)?;
+        let mut paused = self.paused.lock().unwrap();
+        if paused.contains_key(asset) {
+            return Ok(false);
+        }
+        paused.insert(asset.to_string(), AssetPause { asset: asset.to_string(), reason, paused_at: now });
+        Ok(true)
+    }
+
+    /// 恢复资产，返回资产此前是否已暂停
+    pub fn resume(&self, asset: &str) -> Result<bool, Error> {
+        self.ensure_known(asset)?;
+        Ok(self.paused.lock().unwrap().remove(asset).is_some())
+    }
+
+    pub fn is_paused(&self, asset: &str) -> bool {
+        self.paused.lock().unwrap().contains_key(asset)
+    }
+
+    /// 已暂停的资产，按名称排序
+    pub fn list(&self) -> Vec<AssetPause> {
+        self.paused.lock().unwrap().values().cloned().collect()
+    }
+
+    /// 检查资产 `asset` 的转账 `message_id` 能否继续中继
+    pub fn check(&self, asset: &str, message_id: &str) -> PauseDecision {
+        let reason = self.paused.lock().unwrap().get(asset).map(|pause| pause.reason.clone());
+        let mut held = self.held.lock().unwrap();
+        match reason {
+            Some(reason) => PauseDecision::Hold { reason, first: held.insert(message_id.to_string()) },
+            None => {
+                held.remove(message_id);
+                PauseDecision::Allow
+            }
+        }
+    }
+
+    /// 消息已处理完毕或被丢弃时移出保留集合
+    pub fn forget(&self, message_id: &str) {
+        self.held.lock().unwrap().remove(message_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{two_chain_config, TEST_ASSET};
+
+    #[test]
+    fn test_holds_transfers_of_paused_asset() {
+        let mut config = two_chain_config();
+        config.assets[0].paused = true;
+        let pauses = AssetPauses::new(&config, 100);
+        assert_eq!(pauses.list()[0].paused_at, 100);
+        assert!(matches!(pauses.check(TEST_ASSET, "m1"), PauseDecision::Hold { first: true, .. }));
+        assert!(matches!(pauses.check(TEST_ASSET, "m1"), PauseDecision::Hold { first: false, .. }));
+
+        // 已暂停时保留最初的原因
+        assert!(!pauses.pause(TEST_ASSET, "exploit".to_string(), 200).unwrap());
+        assert!(pauses.resume(TEST_ASSET).unwrap());
+        assert_eq!(pauses.check(TEST_ASSET, "m1"), PauseDecision::Allow);
+        assert!(pauses.pause(TEST_ASSET, "exploit".to_string(), 200).unwrap());
+        assert!(matches!(pauses.check(TEST_ASSET, "m1"), PauseDecision::Hold { first: true, .. }));
+
+        assert!(pauses.pause("WETH", "exploit".to_string(), 200).is_err());
+        assert!(pauses.resume("WETH").is_err());
+    }
+}
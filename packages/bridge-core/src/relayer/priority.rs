@@ -0,0 +1,142 @@
+use std::collections::BTreeMap;
+use crate::{
+    config::{Config, PriorityConfig, ValueBandConfig},
+    ledger::Transfer,
+    types::SignedMessage,
+};
+
+/// 不属于任何金额档位的消息在指标中的档位名称
+pub const UNBANDED: &str = "unbanded";
+
+/// 待处理消息的优先级评分
+///
+/// 分数为金额档位、链对与等待时长三部分之和，未配置时所有消息得分为 0，按观察顺序处理。
+/// 等待时长部分随时间增长，小额消息等待足够久后仍能排到大额消息之前。
+pub struct PriorityScorer {
+    config: PriorityConfig,
+}
+
+impl PriorityScorer {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            config: config.relayer.priority.clone().unwrap_or_default(),
+        }
+    }
+
+    /// 转账所属的金额档位：适用于该资产、金额下界不超过转账金额的档位中下界最高的一个
+    pub fn band(&self, transfer: Option<&Transfer>) -> Option<&ValueBandConfig> {
+        let transfer = transfer?;
+        self.config
+            .value_bands
+            .iter()
+            .filter(|band| band.asset.as_ref().is_none_or(|asset| *asset == transfer.asset.name))
+            .filter(|band| transfer.amount >= band.min_amount)
+            .max_by_key(|band| band.min_amount)
+    }
+
+    /// 消息在 `now` 时的优先级分数
+    pub fn score(&self, message: &SignedMessage, transfer: Option<&Transfer>, now: u64) -> u64 {
+        let band = self.band(transfer).map_or(0, |band| band.score);
+        let route = self
+            .config
+            .routes
+            .iter()
+            .filter(|route| {
+                message.message.source_chain == route.source_chain && message.message.target_chain == route.target_chain
+            })
+            .map(|route| route.score)
+            .max()
+            .unwrap_or(0);
+        let minutes = now.saturating_sub(message.timestamp) / 60;
+        band.saturating_add(route).saturating_add(minutes.saturating_mul(self.config.age_score_per_minute))
+    }
+
+    /// 按档位统计待处理消息数，已配置但没有消息的档位计为 0
+    pub fn composition<'a>(&self, bands: impl IntoIterator<Item = Option<&'a str>>) -> BTreeMap<String, usize> {
+        let mut counts: BTreeMap<String, usize> = self
+            .config
+            .value_bands
+            .iter()
+            .map(|band| (band.name.clone(), 0))
+            .chain([(UNBANDED.to_string(), 0)])
+            .collect();
+        for band in bands {
+            *counts.entry(band.unwrap_or(UNBANDED).to_string()).or_default() += 1;
+        }
+        counts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain_adapter::mock::MockChain;
+    use crate::config::{RoutePriorityConfig, SubmissionConfig};
+    use crate::testing::{transfer_payload, two_chain_config, TwoChainHarness, SOURCE_CHAIN, TARGET_CHAIN, TEST_ASSET};
+
+    fn priority_config() -> PriorityConfig {
+        PriorityConfig {
+            value_bands: vec![
+                ValueBandConfig { name: "retail".to_string(), asset: None, min_amount: 1_000, score: 10 },
+                ValueBandConfig { name: "whale".to_string(), asset: Some(TEST_ASSET.to_string()), min_amount: 1_000_000, score: 100 },
+            ],
+            routes: vec![RoutePriorityConfig {
+                source_chain: SOURCE_CHAIN.to_string(),
+                target_chain: TARGET_CHAIN.to_string(),
+                score: 5,
+            }],
+            age_score_per_minute: 1,
+        }
+    }
+
+    #[test]
+    fn test_scores_by_band_route_and_age() {
+        let mut config = two_chain_config();
+        config.relayer.priority = Some(priority_config());
+        let scorer = PriorityScorer::new(&config);
+
+        let mut message = MockChain::new(SOURCE_CHAIN).send(TARGET_CHAIN, "transfer", transfer_payload([1; 32], 5_000_000));
+        message.timestamp = 1_000;
+        let transfer = Transfer::parse(&config, "m1", &message.message);
+        assert_eq!(scorer.band(transfer.as_ref()).unwrap().name, "whale");
+        assert_eq!(scorer.score(&message, transfer.as_ref(), 1_000), 105);
+        assert_eq!(scorer.score(&message, transfer.as_ref(), 1_000 + 30 * 60), 135);
+
+        // 低于所有档位的转账与非转账消息只有链对与等待时长的分数
+        message.message.payload = transfer_payload([1; 32], 10);
+        let dust = Transfer::parse(&config, "m2", &message.message);
+        assert!(scorer.band(dust.as_ref()).is_none());
+        assert_eq!(scorer.score(&message, dust.as_ref(), 1_000), 5);
+
+        let composition = scorer.composition([Some("whale"), None, None]);
+        assert_eq!(composition["whale"], 1);
+        assert_eq!(composition["retail"], 0);
+        assert_eq!(composition[UNBANDED], 2);
+    }
+
+    #[tokio::test]
+    async fn test_high_value_transfer_takes_first_slot() {
+        let mut config = two_chain_config();
+        config.relayer.priority = Some(priority_config());
+        config.chains[1].submission = Some(SubmissionConfig {
+            windows: vec![],
+            max_per_block: Some(1),
+            block_time: 3_600,
+            max_relay_cost: None,
+            gas_units_per_relay: 10_000,
+            low_fee_windows: vec![],
+        });
+        let mut harness = TwoChainHarness::with_config(config).await.unwrap();
+        harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([1; 32], 10));
+        let large = harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([2; 32], 2_000_000));
+        harness.tick().await;
+
+        // 大额转账后观察到，但先占用了唯一的提交名额
+        let submitted = harness.target.submitted();
+        assert_eq!(submitted.len(), 1);
+        assert_eq!(submitted[0].message.nonce, large.message.nonce);
+        let metrics = harness.relayer.metrics().export().unwrap();
+        assert!(metrics.contains("bridge_relay_pending_messages{band=\"unbanded\"} 1"));
+        assert!(metrics.contains("bridge_relay_pending_messages{band=\"whale\"} 1"));
+    }
+}
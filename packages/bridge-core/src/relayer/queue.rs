@@ -0,0 +1,138 @@
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::{self, error::TrySendError};
+use tracing::error;
+use crate::types::{ChainId, SignedMessage};
+
+/// 队列中的一条消息及观察到它的源链
+pub type QueuedMessage = (ChainId, SignedMessage);
+
+/// 创建容量为 `capacity` 的有界工作队列，返回事件读取端与中继处理端
+pub fn work_queue(capacity: usize) -> (Ingestor, WorkQueue) {
+    let (sender, receiver) = mpsc::channel(capacity.max(1));
+    let ingestor = Ingestor {
+        sender: sender.clone(),
+        overflow: HashMap::new(),
+        polled_at: HashMap::new(),
+    };
+    (ingestor, WorkQueue { sender, receiver })
+}
+
+/// 事件读取端
+///
+/// 一次轮询读出的事件可能多于队列剩余容量，放不下的事件按链暂存，
+/// 暂存清空之前不再轮询该链，由此把中继处理的速度反压到源链读取。
+pub struct Ingestor {
+    sender: mpsc::Sender<QueuedMessage>,
+    /// 上次未能入队的事件
+    overflow: HashMap<ChainId, VecDeque<SignedMessage>>,
+    /// 各链上次轮询的时间
+    polled_at: HashMap<ChainId, Instant>,
+}
+
+impl Ingestor {
+    /// 先写入该链暂存的事件，返回是否可以继续轮询该链
+    pub fn drain_overflow(&mut self, chain_id: &ChainId) -> bool {
+        let Some(overflow) = self.overflow.get_mut(chain_id) else {
+            return true;
+        };
+        while let Some(message) = overflow.pop_front() {
+            if let Some(message) = try_send(&self.sender, chain_id, message) {
+                overflow.push_front(message);
+                return false;
+            }
+        }
+        self.overflow.remove(chain_id);
+        true
+    }
+
+    /// 距上次轮询该链是否已过 `interval`，是则记为本次轮询
+    pub fn poll_due(&mut self, chain_id: &ChainId, interval: Duration) -> bool {
+        let now = Instant::now();
+        if self.polled_at.get(chain_id).is_some_and(|last| now.duration_since(*last) < interval) {
+            return false;
+        }
+        self.polled_at.insert(chain_id.clone(), now);
+        true
+    }
+
+    /// 写入新读取的事件，队列满时剩余事件暂存到下次写入
+    pub fn offer(&mut self, chain_id: &ChainId, messages: Vec<SignedMessage>) {
+        let mut messages = messages.into_iter();
+        for message in messages.by_ref() {
+            if let Some(message) = try_send(&self.sender, chain_id, message) {
+                let overflow = self.overflow.entry(chain_id.clone()).or_default();
+                overflow.push_back(message);
+                overflow.extend(messages);
+                return;
+            }
+        }
+    }
+
+    /// 暂存中尚未入队的事件数
+    pub fn buffered(&self) -> usize {
+        self.overflow.values().map(VecDeque::len).sum()
+    }
+}
+
+/// 队列已满时交还消息；处理端已关闭时丢弃消息
+fn try_send(sender: &mpsc::Sender<QueuedMessage>, chain_id: &ChainId, message: SignedMessage) -> Option<SignedMessage> {
+    match sender.try_send((chain_id.clone(), message)) {
+        Ok(()) => None,
+        Err(TrySendError::Full((_, message))) => Some(message),
+        Err(TrySendError::Closed((_, message))) => {
+            error!("Work queue closed, dropping message {} from {}", message.message.nonce, chain_id);
+            None
+        }
+    }
+}
+
+/// 中继处理端
+pub struct WorkQueue {
+    /// 仅用于计算队列深度
+    sender: mpsc::Sender<QueuedMessage>,
+    receiver: mpsc::Receiver<QueuedMessage>,
+}
+
+impl WorkQueue {
+    /// 取出一条消息，队列为空时返回 None
+    pub fn try_recv(&mut self) -> Option<QueuedMessage> {
+        self.receiver.try_recv().ok()
+    }
+
+    /// 队列中等待处理的消息数
+    pub fn depth(&self) -> usize {
+        self.sender.max_capacity() - self.sender.capacity()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{two_chain_config, TwoChainHarness, TARGET_CHAIN};
+
+    #[tokio::test]
+    async fn test_full_queue_pauses_polling() {
+        let mut config = two_chain_config();
+        config.relayer.queue_capacity = 1;
+        let mut harness = TwoChainHarness::with_config(config).await.unwrap();
+        for _ in 0..3 {
+            harness.source.send(TARGET_CHAIN, "ping", vec![]);
+        }
+        harness.tick().await;
+        assert_eq!(harness.target.submitted().len(), 1);
+        assert_eq!(harness.backlog(), 2);
+
+        // 上一轮读出的事件仍在暂存，本轮不再轮询源链
+        harness.source.send(TARGET_CHAIN, "ping", vec![]);
+        harness.tick().await;
+        assert_eq!(harness.target.submitted().len(), 2);
+        assert_eq!(harness.source.pending_events(), 1);
+
+        harness.run(2).await;
+        assert_eq!(harness.target.submitted().len(), 4);
+        assert_eq!(harness.backlog(), 0);
+        let metrics = harness.relayer.metrics().export().unwrap();
+        assert!(metrics.contains("bridge_relay_queue_depth 0"));
+        assert!(metrics.contains("bridge_ingestion_paused_total"));
+    }
+}
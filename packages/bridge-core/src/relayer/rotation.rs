@@ -0,0 +1,358 @@
+use serde::Serialize;
+use std::collections::HashSet;
+use std::sync::Mutex;
+use crate::{
+    config::{Config, ValidatorConfig},
+    keystore::normalize_key,
+    types::{ChainId, CrossChainMessage, GovernanceAction, KeyRotation, ValidatorGovernance, VALIDATOR_GOVERNANCE_MESSAGE_TYPE},
+    Error,
+};
+
+/// 已被轮换、宽限期内仍可签名的旧密钥
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RetiringKey {
+    pub address: String,
+    /// 十六进制公钥
+    pub public_key: String,
+    pub valid_until: u64,
+}
+
+/// 验证者集合及其签名阈值
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidatorSet {
+    pub validators: Vec<ValidatorConfig>,
+    /// 显式设置的阈值，未设置时为总权重的 2/3（与链上合约一致）
+    pub threshold: Option<u64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub retiring: Vec<RetiringKey>,
+}
+
+impl ValidatorSet {
+    pub fn total_weight(&self) -> u64 {
+        self.validators.iter().map(|v| v.weight).sum()
+    }
+
+    /// 达到法定人数所需的权重
+    pub fn threshold(&self) -> u64 {
+        self.threshold.unwrap_or(self.total_weight() * 2 / 3).max(1)
+    }
+
+    /// 治理消息达到法定人数所需的权重，`governance` 未设置时为总权重的 3/4，且不低于普通消息的阈值
+    pub fn governance_threshold(&self, governance: Option<u64>) -> u64 {
+        governance.unwrap_or(self.total_weight() * 3 / 4).max(self.threshold())
+    }
+
+    /// 按成员顺序解码的公钥，推送到合约时与权重按下标对应
+    pub fn public_keys(&self) -> Result<Vec<Vec<u8>>, Error> {
+        self.validators
+            .iter()
+            .map(|v| {
+                hex::decode(v.public_key.trim_start_matches("0x"))
+                    .map_err(|e| Error::Key(format!("Invalid public key for {}: {}", v.address, e)))
+            })
+            .collect()
+    }
+
+    pub fn weights(&self) -> Vec<u64> {
+        self.validators.iter().map(|v| v.weight).collect()
+    }
+
+    /// 验证者 `address` 能否在 `now` 使用 `public_key` 签名：当前密钥，或宽限期内的旧密钥
+    pub fn accepts_key(&self, address: &str, public_key: &str, now: u64) -> bool {
+        let public_key = normalize_key(public_key);
+        let current = self.validators
+            .iter()
+            .any(|v| v.address == address && normalize_key(&v.public_key) == public_key);
+        current || self.retiring
+            .iter()
+            .any(|k| k.address == address && k.public_key == public_key && now <= k.valid_until)
+    }
+
+    /// 应用一项治理操作，`now` 为宽限期的起点；操作无效时返回错误且集合不变
+    pub fn apply(&mut self, action: &GovernanceAction, now: u64) -> Result<(), Error> {
+        let mut next = self.clone();
+        next.retiring.retain(|k| now <= k.valid_until);
+        match action {
+            GovernanceAction::ValidatorAdded { address, public_key, weight, chains } => {
+                if next.validators.iter().any(|v| &v.address == address) {
+                    return Err(Error::Rejected(format!("Validator {} is already in the set", address)));
+                }
+                if *weight == 0 {
+                    return Err(Error::Rejected(format!("Validator {} has no voting weight", address)));
+                }
+                next.validators.push(ValidatorConfig {
+                    address: address.clone(),
+                    public_key: hex::encode(public_key),
+                    bls_public_key: None,
+                    weight: *weight,
+                    chains: chains.clone(),
+                });
+            }
+            GovernanceAction::ValidatorRemoved { address } => {
+                let index = next.validators
+                    .iter()
+                    .position(|v| &v.address == address)
+                    .ok_or_else(|| Error::Rejected(format!("Validator {} is not in the set", address)))?;
+                next.validators.remove(index);
+            }
+            GovernanceAction::ThresholdChanged { threshold } => {
+                if *threshold == 0 {
+                    return Err(Error::Rejected("Validator threshold must be positive".to_string()));
+                }
+                next.threshold = Some(*threshold);
+            }
+            GovernanceAction::KeyRotated(rotation) => {
+                rotation.verify()?;
+                let old_key = hex::encode(&rotation.old_public_key);
+                let new_key = hex::encode(&rotation.new_public_key);
+                if next.validators.iter().any(|v| normalize_key(&v.public_key) == new_key) {
+                    return Err(Error::Rejected(format!("Key {} is already used by a validator", new_key)));
+                }
+                let validator = next.validators
+                    .iter_mut()
+                    .find(|v| v.address == rotation.address)
+                    .ok_or_else(|| Error::Rejected(format!("Validator {} is not in the set", rotation.address)))?;
+                if normalize_key(&validator.public_key) != old_key {
+                    return Err(Error::Rejected(format!("Key rotation for {} is not signed by its current key", rotation.address)));
+                }
+                validator.public_key = new_key;
+                next.retiring.push(RetiringKey {
+                    address: rotation.address.clone(),
+                    public_key: old_key,
+                    valid_until: now.saturating_add(rotation.grace_period),
+                });
+            }
+        }
+        if next.total_weight() == 0 {
+            return Err(Error::Rejected("Validator set would have no voting weight".to_string()));
+        }
+        if next.threshold() > next.total_weight() {
+            return Err(Error::Rejected(format!(
+                "Validator threshold {} exceeds total weight {}",
+                next.threshold(),
+                next.total_weight()
+            )));
+        }
+        *self = next;
+        Ok(())
+    }
+}
+
+/// 等待生效的治理操作
+#[derive(Debug, Clone)]
+struct PendingRotation {
+    message_id: String,
+    source_chain: ChainId,
+    activation_height: u64,
+    action: GovernanceAction,
+}
+
+struct RotationState {
+    set: ValidatorSet,
+    pending: Vec<PendingRotation>,
+    /// 集合变化后尚未成功同步到合约的链
+    unsynced: HashSet<ChainId>,
+}
+
+/// 验证者集合轮换
+///
+/// 治理消息达到法定人数后登记为待生效操作，源链达到生效高度时按高度顺序应用到内存中的集合，
+/// 之后由中继器把新集合推送到各链的桥合约。
+pub struct ValidatorRotation {
+    /// 集合变化后需要同步的链
+    chains: Vec<ChainId>,
+    state: Mutex<RotationState>,
+}
+
+impl ValidatorRotation {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            chains: config.chains.iter().map(|c| c.id.clone()).collect(),
+            state: Mutex::new(RotationState {
+                set: ValidatorSet {
+                    validators: config.validators.clone(),
+                    threshold: config.relayer.attestation_threshold,
+                    retiring: Vec::new(),
+                },
+                pending: Vec::new(),
+                unsynced: HashSet::new(),
+            }),
+        }
+    }
+
+    /// 当前生效的集合
+    pub fn current(&self) -> ValidatorSet {
+        self.state.lock().unwrap().set.clone()
+    }
+
+    /// 登记一条治理消息，返回生效高度；重复登记被忽略
+    pub fn schedule(&self, message_id: &str, message: &CrossChainMessage) -> Result<u64, Error> {
+        if message.message_type != VALIDATOR_GOVERNANCE_MESSAGE_TYPE {
+            return Err(Error::Rejected(format!("Message {} is not a validator governance message", message_id)));
+        }
+        let governance = ValidatorGovernance::decode(&message.payload)?;
+        let mut state = self.state.lock().unwrap();
+        if !state.pending.iter().any(|p| p.message_id == message_id) {
+            state.pending.push(PendingRotation {
+                message_id: message_id.to_string(),
+                source_chain: message.source_chain.clone(),
+                activation_height: governance.activation_height,
+                action: governance.action,
+            });
+        }
+        Ok(governance.activation_height)
+    }
+
+    /// 有待生效操作的源链
+    pub fn pending_chains(&self) -> Vec<ChainId> {
+        let state = self.state.lock().unwrap();
+        let mut chains: Vec<_> = state.pending.iter().map(|p| p.source_chain.clone()).collect();
+        chains.sort();
+        chains.dedup();
+        chains
+    }
+
+    /// 立即应用一份密钥轮换声明，返回更新后的集合
+    ///
+    /// 轮换由验证者直接提交而非经源链治理消息传递时使用，宽限期从 `now` 开始。
+    pub fn rotate_key(&self, rotation: &KeyRotation, now: u64) -> Result<ValidatorSet, Error> {
+        let mut state = self.state.lock().unwrap();
+        state.set.apply(&GovernanceAction::KeyRotated(rotation.clone()), now)?;
+        state.unsynced.extend(self.chains.iter().cloned());
+        Ok(state.set.clone())
+    }
+
+    /// 应用 `chain` 上生效高度不超过 `height` 的操作，`height` 为 None 时全部应用
+    ///
+    /// 返回已应用的操作所属的消息 ID，以及被拒绝的操作及原因。集合发生变化时全部链标记为待同步。
+    pub fn activate(&self, chain: &str, height: Option<u64>, now: u64) -> (Vec<String>, Vec<(String, Error)>) {
+        let mut state = self.state.lock().unwrap();
+        let (mut due, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut state.pending)
+            .into_iter()
+            .partition(|p| p.source_chain.as_str() == chain && height.is_none_or(|h| p.activation_height <= h));
+        state.pending = pending;
+        due.sort_by_key(|p| p.activation_height);
+
+        let mut applied = Vec::new();
+        let mut rejected = Vec::new();
+        for rotation in due {
+            match state.set.apply(&rotation.action, now) {
+                Ok(()) => applied.push(rotation.message_id),
+                Err(e) => rejected.push((rotation.message_id, e)),
+            }
+        }
+        if !applied.is_empty() {
+            state.unsynced.extend(self.chains.iter().cloned());
+        }
+        (applied, rejected)
+    }
+
+    /// 需要同步新集合的链
+    pub fn unsynced(&self) -> Vec<ChainId> {
+        let mut chains: Vec<_> = self.state.lock().unwrap().unsynced.iter().cloned().collect();
+        chains.sort();
+        chains
+    }
+
+    /// 记录 `chain` 已同步到当前集合
+    pub fn mark_synced(&self, chain: &str) {
+        self.state.lock().unwrap().unsynced.remove(chain);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validator(address: &str, weight: u64) -> ValidatorConfig {
+        ValidatorConfig {
+            address: address.to_string(),
+            public_key: "00".repeat(32),
+            bls_public_key: None,
+            weight,
+            chains: vec![],
+        }
+    }
+
+    fn governance(nonce: u64, activation_height: u64, action: GovernanceAction) -> CrossChainMessage {
+        CrossChainMessage {
+            nonce,
+            source_chain: "sui".into(),
+            target_chain: "rooch".into(),
+            message_type: VALIDATOR_GOVERNANCE_MESSAGE_TYPE.to_string(),
+            payload: ValidatorGovernance { activation_height, action }.encode().unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_rotations_apply_at_activation_height() {
+        let rotation = ValidatorRotation {
+            chains: vec![ChainId::from("sui"), ChainId::from("rooch")],
+            state: Mutex::new(RotationState {
+                set: ValidatorSet {
+                    validators: vec![validator("a", 1), validator("b", 1), validator("c", 1)],
+                    threshold: None,
+                    retiring: Vec::new(),
+                },
+                pending: Vec::new(),
+                unsynced: HashSet::new(),
+            }),
+        };
+        let add = GovernanceAction::ValidatorAdded {
+            address: "d".to_string(),
+            public_key: vec![4; 32],
+            weight: 3,
+            chains: vec!["sui".to_string()],
+        };
+        rotation.schedule("add", &governance(1, 100, add)).unwrap();
+        rotation.schedule("remove", &governance(2, 120, GovernanceAction::ValidatorRemoved { address: "a".to_string() })).unwrap();
+        rotation.schedule("threshold", &governance(3, 110, GovernanceAction::ThresholdChanged { threshold: 10 })).unwrap();
+        assert_eq!(rotation.pending_chains(), vec![ChainId::from("sui")]);
+
+        // 未到生效高度时集合不变
+        assert!(rotation.activate("sui", Some(99), 0).0.is_empty());
+        assert_eq!(rotation.current().threshold(), 2);
+
+        // 按生效高度顺序应用；阈值超过总权重的操作被拒绝
+        let (applied, rejected) = rotation.activate("sui", Some(120), 0);
+        assert_eq!(applied, vec!["add", "remove"]);
+        assert_eq!(rejected[0].0, "threshold");
+        let current = rotation.current();
+        let addresses: Vec<_> = current.validators.iter().map(|v| v.address.as_str()).collect();
+        assert_eq!(addresses, vec!["b", "c", "d"]);
+        assert_eq!(current.validators[2].public_key, "04".repeat(32));
+        assert_eq!(current.threshold(), 3);
+
+        assert_eq!(rotation.unsynced(), vec![ChainId::from("rooch"), ChainId::from("sui")]);
+        rotation.mark_synced("rooch");
+        assert_eq!(rotation.unsynced(), vec![ChainId::from("sui")]);
+        assert!(rotation.pending_chains().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rotated_key_keeps_old_key_during_grace() {
+        let keystore = crate::keystore::Keystore::from_secret_keys(&[vec![1u8; 32]]).unwrap();
+        let old_key = keystore.public_keys().remove(0);
+        let mut set = ValidatorSet {
+            validators: vec![ValidatorConfig { public_key: old_key.clone(), ..validator("a", 1) }],
+            threshold: None,
+            retiring: Vec::new(),
+        };
+        let (_, key_rotation) = keystore.rotate(&old_key, "a", 60, 1_000).await.unwrap();
+        let new_key = hex::encode(&key_rotation.new_public_key);
+
+        // 声明的验证者不符时拒绝
+        let forged = KeyRotation { address: "b".to_string(), ..key_rotation.clone() };
+        assert!(set.apply(&GovernanceAction::KeyRotated(forged), 1_000).is_err());
+
+        set.apply(&GovernanceAction::KeyRotated(key_rotation.clone()), 1_000).unwrap();
+        assert_eq!(set.validators[0].public_key, new_key);
+        assert!(set.accepts_key("a", &new_key, 1_060));
+        assert!(set.accepts_key("a", &old_key, 1_060));
+        assert!(!set.accepts_key("a", &old_key, 1_061));
+        assert!(!set.accepts_key("b", &new_key, 1_000));
+
+        // 旧密钥已不是当前密钥，重放同一声明被拒绝
+        assert!(set.apply(&GovernanceAction::KeyRotated(key_rotation), 1_000).is_err());
+    }
+}
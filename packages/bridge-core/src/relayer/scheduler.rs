@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use crate::{
+    config::{Config, SubmissionConfig},
+    types::ChainId,
+    Error,
+};
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// 每日提交时间窗口（UTC），结束时间早于开始时间时跨越午夜
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubmissionWindow {
+    /// 开始时间，距当日零点的秒数（含）
+    start: u64,
+    /// 结束时间，距当日零点的秒数（不含）
+    end: u64,
+}
+
+impl SubmissionWindow {
+    /// 解析 `HH:MM-HH:MM` 格式的时间窗口
+    pub fn parse(window: &str) -> Result<Self, Error> {
+        let invalid = || Error::Config(format!("Invalid submission window: {}", window));
+        let parse_time = |time: &str| -> Result<u64, Error> {
+            let (hours, minutes) = time.trim().split_once(':').ok_or_else(invalid)?;
+            let hours: u64 = hours.parse().map_err(|_| invalid())?;
+            let minutes: u64 = minutes.parse().map_err(|_| invalid())?;
+            if hours > 23 || minutes > 59 {
+                return Err(invalid());
+            }
+            Ok(hours * 3600 + minutes * 60)
+        };
+
+        let (start, end) = window.split_once('-').ok_or_else(invalid)?;
+        let (start, end) = (parse_time(start)?, parse_time(end)?);
+        if start == end {
+            return Err(invalid());
+        }
+        Ok(Self { start, end })
+    }
+
+    /// 时间（Unix 秒）是否落在窗口内
+    pub(crate) fn is_open(&self, now: u64) -> bool {
+        self.contains(now % SECONDS_PER_DAY)
+    }
+
+    fn contains(&self, second_of_day: u64) -> bool {
+        if self.start < self.end {
+            (self.start..self.end).contains(&second_of_day)
+        } else {
+            second_of_day >= self.start || second_of_day < self.end
+        }
+    }
+
+    /// 距离下一次窗口开始的秒数
+    fn wait_until_open(&self, second_of_day: u64) -> u64 {
+        (self.start + SECONDS_PER_DAY - second_of_day) % SECONDS_PER_DAY
+    }
+}
+
+/// 单条链的提交约束
+#[derive(Debug, Clone)]
+struct ChainSchedule {
+    windows: Vec<SubmissionWindow>,
+    max_per_block: Option<u32>,
+    block_time: u64,
+}
+
+impl ChainSchedule {
+    fn from_config(config: &SubmissionConfig) -> Result<Self, Error> {
+        Ok(Self {
+            windows: config.windows
+                .iter()
+                .map(|w| SubmissionWindow::parse(w))
+                .collect::<Result<_, _>>()?,
+            max_per_block: config.max_per_block,
+            block_time: config.block_time.max(1),
+        })
+    }
+
+    /// 当前不在任何窗口内时，返回下一个窗口的开始时间
+    fn next_open(&self, now: u64) -> Option<u64> {
+        if self.windows.is_empty() {
+            return None;
+        }
+        let second_of_day = now % SECONDS_PER_DAY;
+        if self.windows.iter().any(|w| w.contains(second_of_day)) {
+            return None;
+        }
+        self.windows
+            .iter()
+            .map(|w| now + w.wait_until_open(second_of_day))
+            .min()
+    }
+}
+
+/// 申请提交名额的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Slot {
+    /// 允许立即提交，名额已占用
+    Granted,
+    /// 需推迟到指定时间（秒）之后再提交
+    Deferred { until: u64 },
+}
+
+/// 按链限制提交时间窗口与每个区块的交易数
+///
+/// 区块按配置的出块间隔估算，同一区块内的名额用完后推迟到下一个区块；
+/// 推迟的消息保留在中继器的待处理队列中，后续轮询按 nonce 顺序重新申请。
+pub struct SubmissionScheduler {
+    schedules: HashMap<ChainId, ChainSchedule>,
+    /// 各链当前区块序号与已占用的名额
+    usage: Mutex<HashMap<String, (u64, u32)>>,
+}
+
+impl SubmissionScheduler {
+    pub fn new(config: &Config) -> Result<Self, Error> {
+        let mut schedules = HashMap::new();
+        for chain in &config.chains {
+            if let Some(submission) = &chain.submission {
+                schedules.insert(chain.id.clone(), ChainSchedule::from_config(submission)?);
+            }
+        }
+        Ok(Self {
+            schedules,
+            usage: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// 为目标链申请一个提交名额
+    pub fn reserve(&self, chain_id: &str, now: u64) -> Slot {
+        let Some(schedule) = self.schedules.get(chain_id) else {
+            return Slot::Granted;
+        };
+        if let Some(until) = schedule.next_open(now) {
+            return Slot::Deferred { until };
+        }
+
+        let Some(max_per_block) = schedule.max_per_block else {
+            return Slot::Granted;
+        };
+        let block = now / schedule.block_time;
+        let mut usage = self.usage.lock().unwrap_or_else(|e| e.into_inner());
+        let (current_block, used) = usage.entry(chain_id.to_string()).or_insert((block, 0));
+        if *current_block != block {
+            *current_block = block;
+            *used = 0;
+        }
+        if *used >= max_per_block {
+            return Slot::Deferred { until: (block + 1) * schedule.block_time };
+        }
+        *used += 1;
+        Slot::Granted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(windows: &[&str], max_per_block: Option<u32>) -> SubmissionScheduler {
+        let config = SubmissionConfig {
+            windows: windows.iter().map(|w| w.to_string()).collect(),
+            max_per_block,
+            block_time: 10,
+            max_relay_cost: None,
+            gas_units_per_relay: 10_000,
+            low_fee_windows: vec![],
+        };
+        SubmissionScheduler {
+            schedules: HashMap::from([("rooch".into(), ChainSchedule::from_config(&config).unwrap())]),
+            usage: Mutex::new(HashMap::new()),
+        }
+    }
+
+    #[test]
+    fn test_windows_and_block_limit() {
+        // 22:00 - 02:00 跨越午夜
+        let scheduler = schedule(&["22:00-02:00"], Some(2));
+        let noon = 12 * 3600;
+        assert_eq!(scheduler.reserve("rooch", noon), Slot::Deferred { until: 22 * 3600 });
+        assert_eq!(scheduler.reserve("sui", noon), Slot::Granted);
+
+        let night = SECONDS_PER_DAY + 3600;
+        assert_eq!(scheduler.reserve("rooch", night), Slot::Granted);
+        assert_eq!(scheduler.reserve("rooch", night + 1), Slot::Granted);
+        assert_eq!(scheduler.reserve("rooch", night + 2), Slot::Deferred { until: night + 10 });
+        assert_eq!(scheduler.reserve("rooch", night + 10), Slot::Granted);
+
+        assert!(SubmissionWindow::parse("25:00-01:00").is_err());
+    }
+}
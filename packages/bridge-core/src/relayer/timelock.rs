@@ -0,0 +1,141 @@
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use crate::ledger::Transfer;
+
+/// 处于时间锁延迟期的大额转账
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HeldTransfer {
+    pub message_id: String,
+    pub asset: String,
+    pub amount: u64,
+    /// 延迟结束、可以提交的时间（Unix 秒）
+    pub release_at: u64,
+}
+
+/// 时间锁检查结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimelockDecision {
+    Release,
+    /// 仍在延迟期内，`first` 表示本次为首次进入时间锁
+    Hold { release_at: u64, first: bool },
+    /// 延迟期内已被运营方取消
+    Cancelled,
+}
+
+#[derive(Default)]
+struct TimelockState {
+    held: HashMap<String, HeldTransfer>,
+    cancelled: HashSet<String>,
+}
+
+/// 大额转账时间锁
+///
+/// 金额超过资产阈值或地址命中筛查名单的转账延迟提交，延迟期内可人工取消。
+/// 验证者密钥泄露时，伪造的大额转账在提交前有机会被发现并拦截。
+#[derive(Default)]
+pub struct Timelock {
+    state: Mutex<TimelockState>,
+}
+
+impl Timelock {
+    /// 检查转账能否提交，`release_at` 为 [`release_at`] 与其它延迟（例如地址筛查）中较晚者
+    pub fn check(&self, transfer: &Transfer, release_at: Option<u64>, now: u64) -> TimelockDecision {
+        let mut state = self.state.lock().unwrap();
+        if state.cancelled.remove(transfer.message_id) {
+            state.held.remove(transfer.message_id);
+            return TimelockDecision::Cancelled;
+        }
+        let Some(release_at) = release_at else {
+            return TimelockDecision::Release;
+        };
+        if now >= release_at {
+            state.held.remove(transfer.message_id);
+            return TimelockDecision::Release;
+        }
+        let first = !state.held.contains_key(transfer.message_id);
+        state.held.insert(transfer.message_id.to_string(), HeldTransfer {
+            message_id: transfer.message_id.to_string(),
+            asset: transfer.asset.name.clone(),
+            amount: transfer.amount,
+            release_at,
+        });
+        TimelockDecision::Hold { release_at, first }
+    }
+
+    /// 取消处于延迟期的转账，返回该转账是否处于时间锁中
+    ///
+    /// 取消在中继器下一次处理该消息时生效；不在时间锁中的消息不受影响。
+    pub fn cancel(&self, message_id: &str) -> bool {
+        let mut state = self.state.lock().unwrap();
+        let held = state.held.contains_key(message_id);
+        if held {
+            state.cancelled.insert(message_id.to_string());
+        }
+        held
+    }
+
+    /// 处于延迟期的转账，按可提交时间排序
+    pub fn held(&self) -> Vec<HeldTransfer> {
+        let mut held: Vec<_> = self.state.lock().unwrap().held.values().cloned().collect();
+        held.sort_by(|a, b| (a.release_at, &a.message_id).cmp(&(b.release_at, &b.message_id)));
+        held
+    }
+}
+
+/// 金额超过资产阈值的转账可以提交的时间，`timestamp` 为消息的源链时间戳
+pub fn release_at(transfer: &Transfer, timestamp: u64) -> Option<u64> {
+    let timelock = transfer.asset.timelock.as_ref().filter(|t| transfer.amount > t.threshold)?;
+    Some(timestamp.saturating_add(timelock.delay))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TimelockConfig;
+    use crate::testing::{transfer_payload, two_chain_config, SOURCE_CHAIN, TARGET_CHAIN};
+    use crate::types::{CrossChainMessage, TRANSFER_MESSAGE_TYPE};
+
+    fn transfer(nonce: u64, amount: u64) -> CrossChainMessage {
+        CrossChainMessage {
+            nonce,
+            source_chain: SOURCE_CHAIN.into(),
+            target_chain: TARGET_CHAIN.into(),
+            message_type: TRANSFER_MESSAGE_TYPE.to_string(),
+            payload: transfer_payload([1; 32], amount),
+        }
+    }
+
+    #[test]
+    fn test_timelock_holds_large_transfers() {
+        let mut config = two_chain_config();
+        config.assets[0].timelock = Some(TimelockConfig { threshold: 1_000, delay: 1_800 });
+        let timelock = Timelock::default();
+        let small = transfer(1, 1_000);
+        let large = transfer(2, 5_000);
+        let other = transfer(3, 9_000);
+        let small = Transfer::parse(&config, "small", &small).unwrap();
+        let large = Transfer::parse(&config, "large", &large).unwrap();
+        let other = Transfer::parse(&config, "other", &other).unwrap();
+
+        // 未超过阈值的转账直接放行
+        assert_eq!(release_at(&small, 100), None);
+        assert_eq!(timelock.check(&small, None, 100), TimelockDecision::Release);
+
+        let large_release = release_at(&large, 100);
+        assert_eq!(large_release, Some(1_900));
+        assert_eq!(timelock.check(&large, large_release, 100), TimelockDecision::Hold { release_at: 1_900, first: true });
+        assert_eq!(timelock.check(&large, large_release, 1_000), TimelockDecision::Hold { release_at: 1_900, first: false });
+        assert!(matches!(timelock.check(&other, release_at(&other, 200), 1_000), TimelockDecision::Hold { first: true, .. }));
+        assert_eq!(timelock.held().iter().map(|h| h.message_id.as_str()).collect::<Vec<_>>(), ["large", "other"]);
+
+        // 延迟期满后放行
+        assert_eq!(timelock.check(&large, large_release, 1_900), TimelockDecision::Release);
+
+        // 只能取消处于延迟期的转账
+        assert!(!timelock.cancel("large"));
+        assert!(timelock.cancel("other"));
+        assert_eq!(timelock.check(&other, release_at(&other, 200), 1_000), TimelockDecision::Cancelled);
+        assert!(timelock.held().is_empty());
+    }
+}
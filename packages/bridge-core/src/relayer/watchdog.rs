@@ -0,0 +1,127 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// 广播后超时未确认的交易
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StuckTransaction {
+    pub message_id: String,
+    pub chain: String,
+    /// 最近一次未确认的交易哈希
+    pub tx_hash: String,
+    /// 已重新提交的次数
+    pub resubmissions: u32,
+    /// 首次发现交易卡住的时间（Unix 秒）
+    pub detected_at: u64,
+}
+
+/// 交易卡住后的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StuckDecision {
+    /// 替换原交易重新提交，`attempt` 从 1 开始
+    Resubmit { attempt: u32 },
+    /// 已达到重新提交上限
+    GiveUp { resubmissions: u32 },
+}
+
+/// 卡住交易的看门狗
+///
+/// 交易因 gas 不足、序列号空洞或被节点丢弃而迟迟不能确认时，按消息累计重新提交次数，
+/// 达到上限后放弃，消息记为失败。计数跨中继轮次保留，直到消息提交成功或处理完毕。
+#[derive(Default)]
+pub struct Watchdog {
+    state: Mutex<WatchdogState>,
+}
+
+#[derive(Default)]
+struct WatchdogState {
+    stuck: HashMap<String, StuckTransaction>,
+    /// 已放弃的消息及放弃时的重新提交次数，消息处理完毕前再次卡住仍直接放弃
+    given_up: HashMap<String, u32>,
+}
+
+impl Watchdog {
+    /// 记录超时未确认的交易，`limit` 为允许的重新提交次数
+    pub fn observe(&self, message_id: &str, chain: &str, tx_hash: &str, limit: u32, now: u64) -> StuckDecision {
+        let mut state = self.state.lock().unwrap();
+        if let Some(&resubmissions) = state.given_up.get(message_id) {
+            return StuckDecision::GiveUp { resubmissions };
+        }
+        let entry = state.stuck.entry(message_id.to_string()).or_insert_with(|| StuckTransaction {
+            message_id: message_id.to_string(),
+            chain: chain.to_string(),
+            tx_hash: tx_hash.to_string(),
+            resubmissions: 0,
+            detected_at: now,
+        });
+        entry.tx_hash = tx_hash.to_string();
+        if entry.resubmissions >= limit {
+            let resubmissions = entry.resubmissions;
+            state.stuck.remove(message_id);
+            state.given_up.insert(message_id.to_string(), resubmissions);
+            return StuckDecision::GiveUp { resubmissions };
+        }
+        entry.resubmissions += 1;
+        StuckDecision::Resubmit { attempt: entry.resubmissions }
+    }
+
+    /// 消息提交成功或处理完毕后清除记录
+    pub fn resolve(&self, message_id: &str) -> Option<StuckTransaction> {
+        let mut state = self.state.lock().unwrap();
+        state.given_up.remove(message_id);
+        state.stuck.remove(message_id)
+    }
+
+    /// 仍在重新提交中的交易，按发现时间排序
+    pub fn stuck(&self) -> Vec<StuckTransaction> {
+        let mut stuck: Vec<_> = self.state.lock().unwrap().stuck.values().cloned().collect();
+        stuck.sort_by(|a, b| (a.detected_at, &a.message_id).cmp(&(b.detected_at, &b.message_id)));
+        stuck
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain_adapter::mock::MockOperation;
+    use crate::testing::{transfer_payload, TwoChainHarness, TARGET_CHAIN};
+    use crate::Error;
+
+    fn stuck(tx_hash: &str) -> Error {
+        Error::Stuck { tx_hash: tx_hash.to_string(), timeout: 20 }
+    }
+
+    #[tokio::test]
+    async fn test_resubmits_stuck_transactions() {
+        let watchdog = Watchdog::default();
+        assert_eq!(watchdog.observe("m1", TARGET_CHAIN, "0xa", 2, 10), StuckDecision::Resubmit { attempt: 1 });
+        assert_eq!(watchdog.observe("m1", TARGET_CHAIN, "0xb", 2, 20), StuckDecision::Resubmit { attempt: 2 });
+        assert_eq!(watchdog.stuck()[0].tx_hash, "0xb");
+        assert_eq!(watchdog.stuck()[0].detected_at, 10);
+        assert_eq!(watchdog.observe("m1", TARGET_CHAIN, "0xc", 2, 30), StuckDecision::GiveUp { resubmissions: 2 });
+        assert!(watchdog.stuck().is_empty());
+        // 放弃后再次卡住不重新计数，消息处理完毕后才清除
+        assert_eq!(watchdog.observe("m1", TARGET_CHAIN, "0xd", 2, 40), StuckDecision::GiveUp { resubmissions: 2 });
+        assert!(watchdog.stuck().is_empty());
+        watchdog.resolve("m1");
+        assert_eq!(watchdog.observe("m1", TARGET_CHAIN, "0xe", 2, 50), StuckDecision::Resubmit { attempt: 1 });
+
+        // 首次提交卡住后替换提交成功
+        let mut harness = TwoChainHarness::new().await.unwrap();
+        harness.target.fail_next(MockOperation::Submit, stuck("mock_target-tx-0"));
+        harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([1; 32], 100));
+        harness.tick().await;
+        assert_eq!(harness.target.submitted().len(), 1);
+        assert_eq!(harness.backlog(), 0);
+
+        // 替换交易也一直卡住时，达到上限后放弃，消息记为失败
+        harness.target.fail_next(MockOperation::Submit, stuck("mock_target-tx-1"));
+        for attempt in 0..3 {
+            harness.target.fail_next(MockOperation::Resubmit, stuck(&format!("mock_target-tx-1-{}", attempt)));
+        }
+        harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([2; 32], 200));
+        harness.tick().await;
+        assert_eq!(harness.target.submitted().len(), 1);
+        assert_eq!(harness.backlog(), 0);
+    }
+}
@@ -0,0 +1,220 @@
+use serde_json::{Map, Value};
+use super::{check_json, BcsLayout, SchemaDefinition};
+
+/// 带版本负载的首字节，其后一个字节为结构版本
+///
+/// 未以该字节开头、或版本未注册的负载按旧的无版本格式处理。
+pub const ENVELOPE_TAG: u8 = 0xb5;
+
+/// 为负载加上版本字节
+pub fn seal(version: u8, body: &[u8]) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(body.len() + 2);
+    payload.push(ENVELOPE_TAG);
+    payload.push(version);
+    payload.extend_from_slice(body);
+    payload
+}
+
+/// 拆出版本字节与负载正文，无版本的负载返回 `None`
+pub fn open(payload: &[u8]) -> Option<(u32, &[u8])> {
+    match payload {
+        [ENVELOPE_TAG, version, body @ ..] if *version > 0 => Some((u32::from(*version), body)),
+        _ => None,
+    }
+}
+
+/// 按结构定义将 JSON 值编码为负载正文
+///
+/// BCS 布局中 `u128` 使用十进制字符串，`u256`、地址与字节数组使用 `0x` 开头的十六进制字符串，
+/// `Option` 以 `null` 表示空值。
+pub fn encode(definition: &SchemaDefinition, value: &Value) -> Result<Vec<u8>, String> {
+    match definition {
+        SchemaDefinition::Bcs { layout } => {
+            let mut bytes = Vec::new();
+            write(layout, value, "$", &mut bytes)?;
+            Ok(bytes)
+        }
+        SchemaDefinition::Json { schema } => {
+            check_json(schema, value, "$")?;
+            serde_json::to_vec(value).map_err(|e| e.to_string())
+        }
+    }
+}
+
+/// 按结构定义将负载正文解码为 JSON 值，表示方式与 [`encode`] 一致
+pub fn decode(definition: &SchemaDefinition, body: &[u8]) -> Result<Value, String> {
+    match definition {
+        SchemaDefinition::Bcs { layout } => {
+            let mut bytes = body;
+            let value = read(layout, &mut bytes, "$")?;
+            if !bytes.is_empty() {
+                return Err(format!("{} trailing byte(s) after payload", bytes.len()));
+            }
+            Ok(value)
+        }
+        SchemaDefinition::Json { schema } => {
+            let value: Value = serde_json::from_slice(body).map_err(|e| format!("invalid JSON: {}", e))?;
+            check_json(schema, &value, "$")?;
+            Ok(value)
+        }
+    }
+}
+
+fn write(layout: &BcsLayout, value: &Value, path: &str, out: &mut Vec<u8>) -> Result<(), String> {
+    let mismatch = |expected: &str| format!("{} is not {}", path, expected);
+    let unsigned = |max: u64| value.as_u64().filter(|v| *v <= max).ok_or_else(|| mismatch("an unsigned integer in range"));
+    match layout {
+        BcsLayout::Bool => out.push(u8::from(value.as_bool().ok_or_else(|| mismatch("a boolean"))?)),
+        BcsLayout::U8 => out.push(unsigned(u8::MAX.into())? as u8),
+        BcsLayout::U16 => out.extend_from_slice(&(unsigned(u16::MAX.into())? as u16).to_le_bytes()),
+        BcsLayout::U32 => out.extend_from_slice(&(unsigned(u32::MAX.into())? as u32).to_le_bytes()),
+        BcsLayout::U64 => out.extend_from_slice(&unsigned(u64::MAX)?.to_le_bytes()),
+        BcsLayout::U128 => {
+            let number = match value {
+                Value::String(s) => s.parse::<u128>().ok(),
+                _ => value.as_u64().map(u128::from),
+            };
+            out.extend_from_slice(&number.ok_or_else(|| mismatch("a u128"))?.to_le_bytes());
+        }
+        BcsLayout::U256 | BcsLayout::Address => {
+            let bytes = hex_value(value).filter(|b| b.len() <= 32).ok_or_else(|| mismatch("a 32 byte hex string"))?;
+            // 十六进制按大端书写，高位补零
+            let mut padded = vec![0; 32 - bytes.len()];
+            padded.extend(bytes);
+            if matches!(layout, BcsLayout::U256) {
+                padded.reverse();
+            }
+            out.extend_from_slice(&padded);
+        }
+        BcsLayout::String => {
+            let s = value.as_str().ok_or_else(|| mismatch("a string"))?;
+            uleb128(s.len(), out);
+            out.extend_from_slice(s.as_bytes());
+        }
+        BcsLayout::Bytes => {
+            let bytes = hex_value(value).ok_or_else(|| mismatch("a hex string"))?;
+            uleb128(bytes.len(), out);
+            out.extend_from_slice(&bytes);
+        }
+        BcsLayout::Vector { element } => {
+            let items = value.as_array().ok_or_else(|| mismatch("an array"))?;
+            uleb128(items.len(), out);
+            for (index, item) in items.iter().enumerate() {
+                write(element, item, &format!("{}[{}]", path, index), out)?;
+            }
+        }
+        BcsLayout::Option { element } => match value {
+            Value::Null => out.push(0),
+            _ => {
+                out.push(1);
+                write(element, value, path, out)?;
+            }
+        },
+        BcsLayout::Struct { fields } => {
+            let object = value.as_object().ok_or_else(|| mismatch("an object"))?;
+            for field in fields {
+                let field_path = format!("{}.{}", path, field.name);
+                match object.get(&field.name) {
+                    Some(item) => write(&field.layout, item, &field_path, out)?,
+                    // 缺少的可选字段按空值编码
+                    None if matches!(field.layout, BcsLayout::Option { .. }) => out.push(0),
+                    None => return Err(format!("{} is missing", field_path)),
+                }
+            }
+            if let Some(name) = object.keys().find(|name| !fields.iter().any(|field| &&field.name == name)) {
+                return Err(format!("{} has unexpected field {}", path, name));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn read(layout: &BcsLayout, bytes: &mut &[u8], path: &str) -> Result<Value, String> {
+    let value = match layout {
+        BcsLayout::Bool => match take_exact(bytes, 1, path)?[0] {
+            0 => Value::Bool(false),
+            1 => Value::Bool(true),
+            other => return Err(format!("invalid bool {} at {}", other, path)),
+        },
+        BcsLayout::U8 => take_exact(bytes, 1, path)?[0].into(),
+        BcsLayout::U16 => u16::from_le_bytes(take_exact(bytes, 2, path)?.try_into().unwrap_or_default()).into(),
+        BcsLayout::U32 => u32::from_le_bytes(take_exact(bytes, 4, path)?.try_into().unwrap_or_default()).into(),
+        BcsLayout::U64 => u64::from_le_bytes(take_exact(bytes, 8, path)?.try_into().unwrap_or_default()).into(),
+        BcsLayout::U128 => u128::from_le_bytes(take_exact(bytes, 16, path)?.try_into().unwrap_or_default()).to_string().into(),
+        BcsLayout::Address => format!("0x{}", hex::encode(take_exact(bytes, 32, path)?)).into(),
+        BcsLayout::U256 => {
+            let mut be = take_exact(bytes, 32, path)?.to_vec();
+            be.reverse();
+            format!("0x{}", hex::encode(be)).into()
+        }
+        BcsLayout::String => {
+            let len = read_uleb128(bytes, path)?;
+            let s = std::str::from_utf8(take_exact(bytes, len, path)?).map_err(|_| format!("invalid UTF-8 string at {}", path))?;
+            s.into()
+        }
+        BcsLayout::Bytes => {
+            let len = read_uleb128(bytes, path)?;
+            format!("0x{}", hex::encode(take_exact(bytes, len, path)?)).into()
+        }
+        BcsLayout::Vector { element } => {
+            let len = read_uleb128(bytes, path)?;
+            let items = (0..len)
+                .map(|index| read(element, bytes, &format!("{}[{}]", path, index)))
+                .collect::<Result<_, _>>()?;
+            Value::Array(items)
+        }
+        BcsLayout::Option { element } => match take_exact(bytes, 1, path)?[0] {
+            0 => Value::Null,
+            1 => read(element, bytes, path)?,
+            other => return Err(format!("invalid option tag {} at {}", other, path)),
+        },
+        BcsLayout::Struct { fields } => {
+            let mut object = Map::new();
+            for field in fields {
+                let item = read(&field.layout, bytes, &format!("{}.{}", path, field.name))?;
+                object.insert(field.name.clone(), item);
+            }
+            Value::Object(object)
+        }
+    };
+    Ok(value)
+}
+
+fn take_exact<'a>(bytes: &mut &'a [u8], len: usize, path: &str) -> Result<&'a [u8], String> {
+    if bytes.len() < len {
+        return Err(format!("unexpected end of payload at {}", path));
+    }
+    let (head, tail) = bytes.split_at(len);
+    *bytes = tail;
+    Ok(head)
+}
+
+fn read_uleb128(bytes: &mut &[u8], path: &str) -> Result<usize, String> {
+    let mut value: u64 = 0;
+    for shift in (0..64).step_by(7) {
+        let byte = take_exact(bytes, 1, path)?[0];
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return usize::try_from(value).map_err(|_| format!("length overflow at {}", path));
+        }
+    }
+    Err(format!("invalid length prefix at {}", path))
+}
+
+fn uleb128(mut len: usize, out: &mut Vec<u8>) {
+    while len >= 0x80 {
+        out.push((len as u8 & 0x7f) | 0x80);
+        len >>= 7;
+    }
+    out.push(len as u8);
+}
+
+fn hex_value(value: &Value) -> Option<Vec<u8>> {
+    let s = value.as_str()?;
+    let s = s.strip_prefix("0x").unwrap_or(s);
+    if s.len() % 2 == 1 {
+        hex::decode(format!("0{}", s)).ok()
+    } else {
+        hex::decode(s).ok()
+    }
+}
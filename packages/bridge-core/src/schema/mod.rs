@@ -0,0 +1,585 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+use crate::{
+    config::{RouteSchemaPolicy, SchemaConfig, SchemaPolicy},
+    confidential::CONFIDENTIAL_MESSAGE_TYPE,
+    storage::MessageStore,
+    types::CrossChainMessage,
+    Error,
+};
+
+// 负载编解码与版本字节
+pub mod codec;
+
+/// BCS 负载布局，与 Move 结构体的字段顺序一致
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BcsLayout {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    U256,
+    /// 32 字节地址
+    Address,
+    /// UTF-8 字符串
+    String,
+    /// `vector<u8>`
+    Bytes,
+    Vector { element: Box<BcsLayout> },
+    Option { element: Box<BcsLayout> },
+    Struct { fields: Vec<FieldLayout> },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldLayout {
+    pub name: String,
+    pub layout: BcsLayout,
+}
+
+/// 负载的结构定义
+///
+/// JSON 负载只支持 JSON Schema 的常用子集：`type`、`properties`、`required`、
+/// `additionalProperties: false`、`items` 与 `enum`，其余关键字被忽略。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "format", rename_all = "snake_case")]
+pub enum SchemaDefinition {
+    Bcs { layout: BcsLayout },
+    Json { schema: Value },
+}
+
+/// 新版本与上一版本的兼容规则，登记时检查
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Compatibility {
+    /// 新版本可以读取旧版本的负载：BCS 只能在末尾追加 `Option` 字段，JSON 不能新增必填属性
+    #[default]
+    Backward,
+    /// 旧版本可以读取新版本的负载：BCS 只能在末尾追加字段，JSON 不能删除必填属性；
+    /// 目标链尚未升级时，新版本的消息按旧版本中继
+    Forward,
+    /// 同时满足前两者
+    Full,
+    /// 不检查，目标链升级前新版本的消息不会中继
+    None,
+}
+
+impl Compatibility {
+    fn backward(self) -> bool {
+        matches!(self, Compatibility::Backward | Compatibility::Full)
+    }
+
+    fn forward(self) -> bool {
+        matches!(self, Compatibility::Forward | Compatibility::Full)
+    }
+}
+
+/// 注册表中的一个负载结构版本，同一类型的已注册版本不可修改
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchemaDescriptor {
+    /// 负载类型标识，即消息的 `message_type`
+    pub message_type: String,
+    pub version: u32,
+    pub definition: SchemaDefinition,
+    /// 与上一版本的兼容规则
+    #[serde(default)]
+    pub compatibility: Compatibility,
+    #[serde(default)]
+    pub registered_at: u64,
+}
+
+impl SchemaDescriptor {
+    /// 校验描述本身是否合法
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.message_type.is_empty() {
+            return Err(Error::Config("Schema message type is empty".to_string()));
+        }
+        if self.version == 0 || self.version > u32::from(u8::MAX) {
+            return Err(Error::Config("Schema version must be between 1 and 255".to_string()));
+        }
+        if self.message_type == CONFIDENTIAL_MESSAGE_TYPE {
+            return Err(Error::Config("Confidential payloads are encrypted and cannot have a schema".to_string()));
+        }
+        if let SchemaDefinition::Json { schema } = &self.definition {
+            if !schema.is_object() {
+                return Err(Error::Config("JSON schema must be an object".to_string()));
+            }
+        }
+        Ok(())
+    }
+
+    /// 检查本版本与上一版本 `previous` 是否满足声明的兼容规则
+    pub fn check_compatible(&self, previous: &SchemaDescriptor) -> Result<(), String> {
+        let rule = self.compatibility;
+        match (&previous.definition, &self.definition) {
+            _ if rule == Compatibility::None => Ok(()),
+            (SchemaDefinition::Bcs { layout: old }, SchemaDefinition::Bcs { layout: new }) => {
+                let (BcsLayout::Struct { fields: old }, BcsLayout::Struct { fields: new }) = (old, new) else {
+                    return if old == new { Ok(()) } else { Err("layout changed".to_string()) };
+                };
+                if new.len() < old.len() || new[..old.len()] != old[..] {
+                    return Err("existing fields were changed or removed".to_string());
+                }
+                match new[old.len()..].iter().find(|field| !matches!(field.layout, BcsLayout::Option { .. })) {
+                    Some(field) if rule.backward() => Err(format!("appended field {} is not an option", field.name)),
+                    _ => Ok(()),
+                }
+            }
+            (SchemaDefinition::Json { schema: old }, SchemaDefinition::Json { schema: new }) => {
+                if rule.backward() {
+                    json_compatible(old, new).map_err(|e| format!("new schema rejects old payloads: {}", e))?;
+                }
+                if rule.forward() {
+                    json_compatible(new, old).map_err(|e| format!("old schema rejects new payloads: {}", e))?;
+                }
+                Ok(())
+            }
+            _ => Err("payload format changed".to_string()),
+        }
+    }
+
+    /// 按结构定义校验负载
+    pub fn check(&self, payload: &[u8]) -> Result<(), String> {
+        match &self.definition {
+            SchemaDefinition::Bcs { layout } => {
+                let mut reader = BcsReader { bytes: payload, path: String::new() };
+                reader.read(layout)?;
+                if !reader.bytes.is_empty() {
+                    return Err(format!("{} trailing byte(s) after payload", reader.bytes.len()));
+                }
+                Ok(())
+            }
+            SchemaDefinition::Json { schema } => {
+                let value: Value = serde_json::from_slice(payload).map_err(|e| format!("invalid JSON: {}", e))?;
+                check_json(schema, &value, "$")
+            }
+        }
+    }
+}
+
+/// 按布局逐字段读取 BCS 字节，只校验结构不保留解码结果
+struct BcsReader<'a> {
+    bytes: &'a [u8],
+    /// 当前字段路径，用于错误提示
+    path: String,
+}
+
+impl BcsReader<'_> {
+    fn take(&mut self, len: usize) -> Result<&[u8], String> {
+        if self.bytes.len() < len {
+            return Err(format!("unexpected end of payload at {}", self.location()));
+        }
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn uleb128(&mut self) -> Result<usize, String> {
+        let mut value: u64 = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.take(1)?[0];
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return usize::try_from(value).map_err(|_| format!("length overflow at {}", self.location()));
+            }
+        }
+        Err(format!("invalid length prefix at {}", self.location()))
+    }
+
+    fn read(&mut self, layout: &BcsLayout) -> Result<(), String> {
+        match layout {
+            BcsLayout::Bool => match self.take(1)?[0] {
+                0 | 1 => Ok(()),
+                other => Err(format!("invalid bool {} at {}", other, self.location())),
+            },
+            BcsLayout::U8 => self.take(1).map(drop),
+            BcsLayout::U16 => self.take(2).map(drop),
+            BcsLayout::U32 => self.take(4).map(drop),
+            BcsLayout::U64 => self.take(8).map(drop),
+            BcsLayout::U128 => self.take(16).map(drop),
+            BcsLayout::U256 | BcsLayout::Address => self.take(32).map(drop),
+            BcsLayout::Bytes => {
+                let len = self.uleb128()?;
+                self.take(len).map(drop)
+            }
+            BcsLayout::String => {
+                let len = self.uleb128()?;
+                let location = self.location();
+                std::str::from_utf8(self.take(len)?)
+                    .map(drop)
+                    .map_err(|_| format!("invalid UTF-8 string at {}", location))
+            }
+            BcsLayout::Vector { element } => {
+                for _ in 0..self.uleb128()? {
+                    self.read(element)?;
+                }
+                Ok(())
+            }
+            BcsLayout::Option { element } => match self.take(1)?[0] {
+                0 => Ok(()),
+                1 => self.read(element),
+                other => Err(format!("invalid option tag {} at {}", other, self.location())),
+            },
+            BcsLayout::Struct { fields } => {
+                for field in fields {
+                    let parent = self.path.len();
+                    self.path.push('.');
+                    self.path.push_str(&field.name);
+                    self.read(&field.layout)?;
+                    self.path.truncate(parent);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn location(&self) -> String {
+        format!("${}", self.path)
+    }
+}
+
+fn check_json(schema: &Value, value: &Value, path: &str) -> Result<(), String> {
+    if let Some(expected) = schema.get("type").and_then(Value::as_str) {
+        let matches = match expected {
+            "object" => value.is_object(),
+            "array" => value.is_array(),
+            "string" => value.is_string(),
+            "boolean" => value.is_boolean(),
+            "null" => value.is_null(),
+            "number" => value.is_number(),
+            "integer" => value.is_i64() || value.is_u64(),
+            _ => true,
+        };
+        if !matches {
+            return Err(format!("{} is not of type {}", path, expected));
+        }
+    }
+    if let Some(options) = schema.get("enum").and_then(Value::as_array) {
+        if !options.contains(value) {
+            return Err(format!("{} is not one of the allowed values", path));
+        }
+    }
+    if let Some(object) = value.as_object() {
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for required in schema.get("required").and_then(Value::as_array).into_iter().flatten() {
+            if let Some(name) = required.as_str().filter(|name| !object.contains_key(*name)) {
+                return Err(format!("{} is missing required property {}", path, name));
+            }
+        }
+        for (name, field) in object {
+            match properties.and_then(|properties| properties.get(name)) {
+                Some(field_schema) => check_json(field_schema, field, &format!("{}.{}", path, name))?,
+                None if schema.get("additionalProperties") == Some(&Value::Bool(false)) => {
+                    return Err(format!("{} has unexpected property {}", path, name));
+                }
+                None => {}
+            }
+        }
+    }
+    if let (Some(items), Some(array)) = (schema.get("items"), value.as_array()) {
+        for (index, item) in array.iter().enumerate() {
+            check_json(items, item, &format!("{}[{}]", path, index))?;
+        }
+    }
+    Ok(())
+}
+
+/// 检查符合 `writer` 的数据是否都符合 `reader`：`reader` 的必填属性 `writer` 也必填，
+/// 共有属性定义相同，`reader` 禁止额外属性时 `writer` 不能多出属性
+fn json_compatible(writer: &Value, reader: &Value) -> Result<(), String> {
+    let required = |schema: &Value| -> Vec<Value> { schema.get("required").and_then(Value::as_array).cloned().unwrap_or_default() };
+    let empty = serde_json::Map::new();
+    let properties = |schema: &Value| schema.get("properties").and_then(Value::as_object).unwrap_or(&empty).clone();
+    if writer.get("type") != reader.get("type") {
+        return Err("type changed".to_string());
+    }
+    let writer_required = required(writer);
+    if let Some(name) = required(reader).iter().find(|name| !writer_required.contains(name)) {
+        return Err(format!("property {} is required", name));
+    }
+    let reader_properties = properties(reader);
+    for (name, field) in &properties(writer) {
+        match reader_properties.get(name) {
+            Some(reader_field) if reader_field != field => return Err(format!("property {} changed", name)),
+            None if reader.get("additionalProperties") == Some(&Value::Bool(false)) => {
+                return Err(format!("property {} is not allowed", name));
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// 按已注册的版本校验负载，返回匹配的版本；未匹配时返回最新版本的校验错误
+///
+/// 带版本字节的负载按对应版本校验；无版本的旧负载依次尝试各版本，新版本注册后在途的旧版本消息仍可通过校验。
+pub fn check_payload(schemas: &[SchemaDescriptor], payload: &[u8]) -> Result<u32, String> {
+    if let Some((version, body)) = codec::open(payload) {
+        if let Some(schema) = schemas.iter().find(|schema| schema.version == version) {
+            if schema.check(body).is_ok() {
+                return Ok(version);
+            }
+        }
+    }
+    let mut latest_error = None;
+    let mut versions: Vec<_> = schemas.iter().collect();
+    versions.sort_by_key(|schema| std::cmp::Reverse(schema.version));
+    for schema in versions {
+        match schema.check(payload) {
+            Ok(()) => return Ok(schema.version),
+            Err(e) => {
+                latest_error.get_or_insert(format!("version {}: {}", schema.version, e));
+            }
+        }
+    }
+    Err(latest_error.unwrap_or_else(|| "no schema registered".to_string()))
+}
+
+/// 按版本编码负载，结果带版本字节
+pub fn encode_payload(schemas: &[SchemaDescriptor], version: u32, value: &Value) -> Result<Vec<u8>, String> {
+    let schema = schemas
+        .iter()
+        .find(|schema| schema.version == version)
+        .ok_or_else(|| format!("version {} is not registered", version))?;
+    let version = u8::try_from(version).map_err(|_| format!("version {} does not fit in the version byte", version))?;
+    Ok(codec::seal(version, &codec::encode(&schema.definition, value)?))
+}
+
+/// 解码负载，返回负载的版本与内容；无版本的旧负载按匹配的版本解码
+pub fn decode_payload(schemas: &[SchemaDescriptor], payload: &[u8]) -> Result<(u32, Value), String> {
+    let find = |version: u32| schemas.iter().find(|schema| schema.version == version);
+    if let Some((version, body)) = codec::open(payload) {
+        if let Some(value) = find(version).and_then(|schema| codec::decode(&schema.definition, body).ok()) {
+            return Ok((version, value));
+        }
+    }
+    let version = check_payload(schemas, payload)?;
+    let schema = find(version).ok_or("no schema registered")?;
+    Ok((version, codec::decode(&schema.definition, payload)?))
+}
+
+/// 登记新的负载结构版本，同一版本重复登记相同定义时视为成功
+///
+/// 新版本须与已登记的上一版本满足声明的兼容规则。
+pub async fn register(store: &dyn MessageStore, descriptor: &SchemaDescriptor) -> Result<(), Error> {
+    descriptor.validate()?;
+    let existing = store.schemas(Some(&descriptor.message_type)).await?;
+    if let Some(current) = existing.iter().find(|schema| schema.version == descriptor.version) {
+        if current.definition == descriptor.definition {
+            return Ok(());
+        }
+        return Err(Error::Config(format!(
+            "Schema {} version {} is already registered with a different definition",
+            descriptor.message_type, descriptor.version
+        )));
+    }
+    let previous = existing
+        .iter()
+        .filter(|schema| schema.version < descriptor.version)
+        .max_by_key(|schema| schema.version);
+    if let Some(previous) = previous {
+        descriptor.check_compatible(previous).map_err(|e| {
+            Error::Config(format!(
+                "Schema {} version {} is not {:?} compatible with version {}: {}",
+                descriptor.message_type, descriptor.version, descriptor.compatibility, previous.version, e
+            ))
+        })?;
+    }
+    store.register_schema(descriptor).await
+}
+
+/// 中继前的负载结构检查
+pub struct SchemaRegistry {
+    store: Arc<dyn MessageStore>,
+    config: SchemaConfig,
+}
+
+impl SchemaRegistry {
+    pub fn new(store: Arc<dyn MessageStore>, config: SchemaConfig) -> Self {
+        Self { store, config }
+    }
+
+    fn route(&self, source_chain: &str, target_chain: &str) -> Option<&RouteSchemaPolicy> {
+        self.config
+            .routes
+            .iter()
+            .find(|route| route.source_chain == source_chain && route.target_chain == target_chain)
+    }
+
+    /// 路由适用的策略，未单独配置的路由使用默认策略
+    pub fn policy(&self, source_chain: &str, target_chain: &str) -> SchemaPolicy {
+        self.route(source_chain, target_chain).map_or(self.config.default_policy, |route| route.policy)
+    }
+
+    /// 检查消息负载，返回拒绝中继的原因；机密消息的负载已加密，不做检查
+    pub async fn check(&self, message: &CrossChainMessage) -> Result<Option<String>, Error> {
+        let policy = self.policy(&message.source_chain, &message.target_chain);
+        if policy == SchemaPolicy::Off || message.message_type == CONFIDENTIAL_MESSAGE_TYPE {
+            return Ok(None);
+        }
+        let schemas = self.store.schemas(Some(&message.message_type)).await?;
+        if schemas.is_empty() {
+            return Ok((policy == SchemaPolicy::Strict)
+                .then(|| format!("No schema registered for payload type {}", message.message_type)));
+        }
+        let version = match check_payload(&schemas, &message.payload) {
+            Ok(version) => version,
+            Err(e) => return Ok(Some(format!("Payload does not match schema {}: {}", message.message_type, e))),
+        };
+        let accepted = self
+            .route(&message.source_chain, &message.target_chain)
+            .and_then(|route| route.max_version);
+        Ok(accepted.and_then(|accepted| negotiate(&schemas, version, accepted).err()).map(|e| {
+            format!("Payload {} version {} is not accepted by {}: {}", message.message_type, version, message.target_chain, e)
+        }))
+    }
+}
+
+/// 目标链最高支持 `accepted` 版本时，`version` 版本的负载能否中继：
+/// 两者之间的每个版本都须声明向前兼容，目标链才能按旧版本读取
+pub fn negotiate(schemas: &[SchemaDescriptor], version: u32, accepted: u32) -> Result<(), String> {
+    if version <= accepted {
+        return Ok(());
+    }
+    match schemas
+        .iter()
+        .filter(|schema| schema.version > accepted && schema.version <= version)
+        .find(|schema| !schema.compatibility.forward())
+    {
+        Some(schema) => Err(format!("version {} is not forward compatible", schema.version)),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn descriptor(version: u32, definition: SchemaDefinition) -> SchemaDescriptor {
+        SchemaDescriptor {
+            message_type: "memo".to_string(),
+            version,
+            definition,
+            compatibility: Compatibility::default(),
+            registered_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_check_payload_against_versions() {
+        let v1 = descriptor(1, SchemaDefinition::Bcs {
+            layout: BcsLayout::Struct {
+                fields: vec![
+                    FieldLayout { name: "amount".to_string(), layout: BcsLayout::U64 },
+                    FieldLayout { name: "memo".to_string(), layout: BcsLayout::String },
+                ],
+            },
+        });
+        let v2 = descriptor(2, SchemaDefinition::Bcs {
+            layout: BcsLayout::Struct {
+                fields: vec![
+                    FieldLayout { name: "amount".to_string(), layout: BcsLayout::U64 },
+                    FieldLayout { name: "memo".to_string(), layout: BcsLayout::String },
+                    FieldLayout {
+                        name: "tag".to_string(),
+                        layout: BcsLayout::Option { element: Box::new(BcsLayout::U32) },
+                    },
+                ],
+            },
+        });
+        let schemas = [v1, v2];
+
+        let old = bcs::to_bytes(&(5u64, "hi".to_string())).unwrap();
+        let new = bcs::to_bytes(&(5u64, "hi".to_string(), Some(7u32))).unwrap();
+        assert_eq!(check_payload(&schemas, &old), Ok(1));
+        assert_eq!(check_payload(&schemas, &new), Ok(2));
+
+        let error = check_payload(&schemas, &old[..old.len() - 1]).unwrap_err();
+        assert!(error.starts_with("version 2: unexpected end of payload at $.memo"), "{}", error);
+
+        let json = [descriptor(1, SchemaDefinition::Json {
+            schema: serde_json::json!({
+                "type": "object",
+                "required": ["order"],
+                "additionalProperties": false,
+                "properties": { "order": { "type": "integer" } }
+            }),
+        })];
+        assert_eq!(check_payload(&json, br#"{"order": 42}"#), Ok(1));
+        assert!(check_payload(&json, br#"{"order": "42"}"#).unwrap_err().contains("$.order is not of type integer"));
+        assert!(check_payload(&json, br#"{"order": 1, "extra": true}"#).is_err());
+    }
+
+    #[test]
+    fn test_versioned_payload_roundtrip_and_compatibility() {
+        let field = |name: &str, layout| FieldLayout { name: name.to_string(), layout };
+        let v1 = descriptor(1, SchemaDefinition::Bcs {
+            layout: BcsLayout::Struct {
+                fields: vec![field("amount", BcsLayout::U64), field("to", BcsLayout::Address)],
+            },
+        });
+        let mut v2 = descriptor(2, SchemaDefinition::Bcs {
+            layout: BcsLayout::Struct {
+                fields: vec![
+                    field("amount", BcsLayout::U64),
+                    field("to", BcsLayout::Address),
+                    field("memo", BcsLayout::Option { element: Box::new(BcsLayout::String) }),
+                ],
+            },
+        });
+        assert_eq!(v2.check_compatible(&v1), Ok(()));
+
+        let value = serde_json::json!({ "amount": 5, "to": "0xb1", "memo": "hi" });
+        let schemas = [v1.clone(), v2.clone()];
+        let payload = encode_payload(&schemas, 2, &value).unwrap();
+        assert_eq!(&payload[..2], &[codec::ENVELOPE_TAG, 2]);
+        let (version, decoded) = decode_payload(&schemas, &payload).unwrap();
+        assert_eq!(version, 2);
+        assert_eq!(decoded["to"], format!("0x{}b1", "0".repeat(62)));
+        assert_eq!(decoded["memo"], "hi");
+
+        // 无版本的旧负载仍按匹配的版本解码
+        let legacy = bcs::to_bytes(&(7u64, [0u8; 32])).unwrap();
+        assert_eq!(decode_payload(&schemas, &legacy).unwrap().0, 1);
+        assert!(encode_payload(&schemas, 1, &serde_json::json!({ "amount": 1 })).unwrap_err().contains("$.to is missing"));
+
+        // 向后兼容的新版本只能追加可选字段
+        let mut v3 = descriptor(3, SchemaDefinition::Bcs {
+            layout: BcsLayout::Struct {
+                fields: vec![
+                    field("amount", BcsLayout::U64),
+                    field("to", BcsLayout::Address),
+                    field("memo", BcsLayout::Option { element: Box::new(BcsLayout::String) }),
+                    field("fee", BcsLayout::U64),
+                ],
+            },
+        });
+        assert!(v3.check_compatible(&v2).unwrap_err().contains("fee"));
+        v3.compatibility = Compatibility::Forward;
+        assert_eq!(v3.check_compatible(&v2), Ok(()));
+        v3.definition = v1.definition.clone();
+        assert!(v3.check_compatible(&v2).is_err());
+
+        // 目标链只支持 v1 时，v2 须声明向前兼容
+        assert!(negotiate(&schemas, 2, 1).is_err());
+        v2.compatibility = Compatibility::Full;
+        assert_eq!(negotiate(&[v1, v2], 2, 1), Ok(()));
+
+        let json = |required: serde_json::Value, compatibility| SchemaDescriptor {
+            compatibility,
+            ..descriptor(2, SchemaDefinition::Json {
+                schema: serde_json::json!({
+                    "type": "object",
+                    "required": required,
+                    "properties": { "order": { "type": "integer" }, "note": { "type": "string" } }
+                }),
+            })
+        };
+        let old = json(serde_json::json!(["order"]), Compatibility::Backward);
+        assert!(json(serde_json::json!(["order", "note"]), Compatibility::Backward).check_compatible(&old).is_err());
+        assert_eq!(json(serde_json::json!(["order", "note"]), Compatibility::Forward).check_compatible(&old), Ok(()));
+    }
+}
@@ -0,0 +1,285 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Duration;
+use crate::{
+    config::{HttpScreeningConfig, ScreeningConfig, ScreeningPolicy, ScreeningProviderConfig},
+    ledger::Transfer,
+    Error,
+};
+
+/// 地址筛查接口
+///
+/// 中继器对每笔转账的发送方（源链）与接收方（目标链）地址各调用一次，
+/// 返回错误时消息留在待处理队列中，下一轮重新筛查。
+#[async_trait]
+pub trait ScreeningProvider: Send + Sync {
+    /// 提供方名称，出现在日志与审计原因中
+    fn name(&self) -> &str;
+
+    /// 筛查链上地址，命中名单时返回原因
+    async fn screen(&self, chain: &str, address: &str) -> Result<Option<String>, Error>;
+}
+
+/// 静态名单文件格式
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StaticList {
+    /// 非空时只放行名单内的地址
+    #[serde(default)]
+    pub allowlist: Vec<String>,
+    #[serde(default)]
+    pub denylist: Vec<String>,
+}
+
+/// 基于静态名单的筛查，地址不区分大小写
+pub struct StaticListProvider {
+    allowlist: HashSet<String>,
+    denylist: HashSet<String>,
+}
+
+impl StaticListProvider {
+    pub fn new(list: StaticList) -> Self {
+        Self {
+            allowlist: list.allowlist.iter().map(|a| normalize(a)).collect(),
+            denylist: list.denylist.iter().map(|a| normalize(a)).collect(),
+        }
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let content = std::fs::read_to_string(path.as_ref())
+            .map_err(|e| Error::Config(format!("Failed to read screening list {}: {}", path.as_ref().display(), e)))?;
+        let list: StaticList = serde_json::from_str(&content)
+            .map_err(|e| Error::Config(format!("Invalid screening list {}: {}", path.as_ref().display(), e)))?;
+        Ok(Self::new(list))
+    }
+}
+
+#[async_trait]
+impl ScreeningProvider for StaticListProvider {
+    fn name(&self) -> &str {
+        "static"
+    }
+
+    async fn screen(&self, _chain: &str, address: &str) -> Result<Option<String>, Error> {
+        let address = normalize(address);
+        if self.denylist.contains(&address) {
+            return Ok(Some(format!("Address {} is on the denylist", address)));
+        }
+        if !self.allowlist.is_empty() && !self.allowlist.contains(&address) {
+            return Ok(Some(format!("Address {} is not on the allowlist", address)));
+        }
+        Ok(None)
+    }
+}
+
+#[derive(Serialize)]
+struct ScreeningRequest<'a> {
+    chain: &'a str,
+    address: &'a str,
+}
+
+#[derive(Deserialize)]
+struct ScreeningResponse {
+    flagged: bool,
+    #[serde(default)]
+    reason: Option<String>,
+}
+
+/// 外部筛查服务，响应为 `{"flagged": bool, "reason": string?}`
+pub struct HttpScreeningProvider {
+    client: reqwest::Client,
+    config: HttpScreeningConfig,
+}
+
+impl HttpScreeningProvider {
+    pub fn new(config: &HttpScreeningConfig) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            config: config.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl ScreeningProvider for HttpScreeningProvider {
+    fn name(&self) -> &str {
+        "http"
+    }
+
+    async fn screen(&self, chain: &str, address: &str) -> Result<Option<String>, Error> {
+        let mut request = self.client
+            .post(&self.config.url)
+            .timeout(Duration::from_millis(self.config.timeout_ms))
+            .json(&ScreeningRequest { chain, address });
+        if let Some(token) = &self.config.token {
+            request = request.bearer_auth(token);
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| Error::Network(format!("Screening request failed: {}", e)))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(Error::Network(format!("Screening service returned {}", status)));
+        }
+        let response: ScreeningResponse = response
+            .json()
+            .await
+            .map_err(|e| Error::Serialization(format!("Invalid screening response: {}", e)))?;
+        Ok(response.flagged.then(|| {
+            response.reason.unwrap_or_else(|| format!("Address {} flagged by screening service", address))
+        }))
+    }
+}
+
+/// 筛查结果按配置策略得到的处理方式
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScreeningDecision {
+    Allow,
+    Block(String),
+    /// 照常中继，`first` 表示本次为首次筛查
+    Flag { reason: String, first: bool },
+    /// 延迟到 `release_at` 后再中继
+    Delay { reason: String, release_at: u64, first: bool },
+}
+
+/// 转账地址筛查
+///
+/// 每条消息只筛查一次，结果缓存到消息处理完毕，避免等待证明或时间锁期间重复请求外部服务。
+pub struct Screener {
+    provider: Box<dyn ScreeningProvider>,
+    policy: ScreeningPolicy,
+    delay: u64,
+    verdicts: Mutex<HashMap<String, Option<String>>>,
+}
+
+impl Screener {
+    pub fn new(config: &ScreeningConfig) -> Result<Self, Error> {
+        let provider: Box<dyn ScreeningProvider> = match &config.provider {
+            ScreeningProviderConfig::Static { path } => Box::new(StaticListProvider::load(path)?),
+            ScreeningProviderConfig::Http(http) => Box::new(HttpScreeningProvider::new(http)),
+        };
+        Ok(Self::with_provider(provider, config))
+    }
+
+    /// 使用自定义的筛查提供方
+    pub fn with_provider(provider: Box<dyn ScreeningProvider>, config: &ScreeningConfig) -> Self {
+        Self {
+            provider,
+            policy: config.policy,
+            delay: config.delay,
+            verdicts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 筛查转账的发送方与接收方，`timestamp` 为消息的源链时间戳
+    pub async fn check(&self, transfer: &Transfer<'_>, sender: Option<&str>, timestamp: u64) -> Result<ScreeningDecision, Error> {
+        let cached = self.verdicts.lock().unwrap().get(transfer.message_id).cloned();
+        let first = cached.is_none();
+        let verdict = match cached {
+            Some(verdict) => verdict,
+            None => {
+                let verdict = self.screen(transfer, sender).await?;
+                self.verdicts.lock().unwrap().insert(transfer.message_id.to_string(), verdict.clone());
+                verdict
+            }
+        };
+        let Some(reason) = verdict else {
+            return Ok(ScreeningDecision::Allow);
+        };
+        let reason = format!("{} ({})", reason, self.provider.name());
+        Ok(match self.policy {
+            ScreeningPolicy::Block => ScreeningDecision::Block(reason),
+            ScreeningPolicy::Flag => ScreeningDecision::Flag { reason, first },
+            ScreeningPolicy::Delay => ScreeningDecision::Delay {
+                reason,
+                release_at: timestamp.saturating_add(self.delay),
+                first,
+            },
+        })
+    }
+
+    async fn screen(&self, transfer: &Transfer<'_>, sender: Option<&str>) -> Result<Option<String>, Error> {
+        if let Some(sender) = sender {
+            if let Some(reason) = self.provider.screen(&transfer.message.source_chain, sender).await? {
+                return Ok(Some(reason));
+            }
+        }
+        let recipient = format!("0x{}", hex::encode(transfer.recipient()));
+        self.provider.screen(&transfer.message.target_chain, &recipient).await
+    }
+
+    /// 消息处理完毕后丢弃缓存的筛查结果
+    pub fn forget(&self, message_id: &str) {
+        self.verdicts.lock().unwrap().remove(message_id);
+    }
+
+    /// 命中名单时是否需要时间锁
+    pub fn delays(&self) -> bool {
+        self.policy == ScreeningPolicy::Delay
+    }
+}
+
+fn normalize(address: &str) -> String {
+    format!("0x{}", address.trim().trim_start_matches("0x").to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{transfer_payload, two_chain_config, SOURCE_CHAIN, TARGET_CHAIN};
+    use crate::types::{CrossChainMessage, TRANSFER_MESSAGE_TYPE};
+
+    fn screener(policy: ScreeningPolicy) -> Screener {
+        let list = StaticList {
+            allowlist: vec![],
+            denylist: vec!["0xBAD".to_string(), format!("0x{}", "02".repeat(32))],
+        };
+        let config = ScreeningConfig {
+            provider: ScreeningProviderConfig::Static { path: String::new() },
+            policy,
+            delay: 600,
+        };
+        Screener::with_provider(Box::new(StaticListProvider::new(list)), &config)
+    }
+
+    #[tokio::test]
+    async fn test_screening_policies() {
+        let config = two_chain_config();
+        let message = |recipient: [u8; 32]| CrossChainMessage {
+            nonce: 1,
+            source_chain: SOURCE_CHAIN.into(),
+            target_chain: TARGET_CHAIN.into(),
+            message_type: TRANSFER_MESSAGE_TYPE.to_string(),
+            payload: transfer_payload(recipient, 100),
+        };
+        let clean = message([1; 32]);
+        let denied = message([2; 32]);
+        let clean = Transfer::parse(&config, "clean", &clean).unwrap();
+        let denied = Transfer::parse(&config, "denied", &denied).unwrap();
+
+        let block = screener(ScreeningPolicy::Block);
+        assert_eq!(block.check(&clean, Some("0xabc"), 0).await.unwrap(), ScreeningDecision::Allow);
+        // 同一消息只筛查一次，丢弃缓存后重新筛查；地址不区分大小写
+        assert_eq!(block.check(&clean, Some("0xbad"), 0).await.unwrap(), ScreeningDecision::Allow);
+        block.forget("clean");
+        assert!(matches!(block.check(&clean, Some("0xbad"), 0).await.unwrap(), ScreeningDecision::Block(_)));
+        assert!(matches!(block.check(&denied, None, 0).await.unwrap(), ScreeningDecision::Block(_)));
+
+        let flag = screener(ScreeningPolicy::Flag);
+        assert!(matches!(flag.check(&denied, None, 0).await.unwrap(), ScreeningDecision::Flag { first: true, .. }));
+        assert!(matches!(flag.check(&denied, None, 0).await.unwrap(), ScreeningDecision::Flag { first: false, .. }));
+
+        let delay = screener(ScreeningPolicy::Delay);
+        assert!(matches!(
+            delay.check(&denied, None, 100).await.unwrap(),
+            ScreeningDecision::Delay { release_at: 700, first: true, .. }
+        ));
+
+        let allowlist = StaticListProvider::new(StaticList { allowlist: vec!["0xA".to_string()], denylist: vec![] });
+        assert_eq!(allowlist.screen(SOURCE_CHAIN, "0xa").await.unwrap(), None);
+        assert!(allowlist.screen(SOURCE_CHAIN, "0xb").await.unwrap().is_some());
+    }
+}
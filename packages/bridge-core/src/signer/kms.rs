@@ -0,0 +1,250 @@
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use crate::{config::KmsSignerConfig, Error};
+use super::{check_signature, decode_base64, encode_base64, failure, public_key_array, timed, Signer};
+
+const BACKEND: &str = "aws_kms";
+const SERVICE: &str = "kms";
+const KEY_SPEC: &str = "ECC_NIST_EDWARDS25519";
+const SIGNING_ALGORITHM: &str = "ED25519_SHA_512";
+/// Ed25519 SubjectPublicKeyInfo 的 DER 前缀，其后为 32 字节公钥
+const ED25519_SPKI_PREFIX: [u8; 12] = [0x30, 0x2a, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x03, 0x21, 0x00];
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GetPublicKeyResponse {
+    key_spec: String,
+    public_key: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SignResponse {
+    signature: String,
+}
+
+/// AWS KMS 签名后端
+///
+/// 通过 KMS JSON 接口调用 `GetPublicKey` 与 `Sign`，请求使用 SigV4 签名。
+/// 密钥须为 `ECC_NIST_EDWARDS25519` 规格，签名算法为 `ED25519_SHA_512`。
+pub struct AwsKmsSigner {
+    client: reqwest::Client,
+    config: KmsSignerConfig,
+    endpoint: reqwest::Url,
+    public_key: [u8; 32],
+}
+
+impl AwsKmsSigner {
+    pub async fn connect(config: &KmsSignerConfig) -> Result<Self, Error> {
+        let endpoint = config.endpoint
+            .clone()
+            .unwrap_or_else(|| format!("https://kms.{}.amazonaws.com", config.region));
+        let endpoint = reqwest::Url::parse(&endpoint)
+            .map_err(|e| Error::Config(format!("Invalid AWS KMS endpoint {}: {}", endpoint, e)))?;
+        let mut signer = Self {
+            client: reqwest::Client::new(),
+            config: config.clone(),
+            endpoint,
+            public_key: [0u8; 32],
+        };
+
+        let body = json!({ "KeyId": config.key_id });
+        let response: GetPublicKeyResponse = timed(BACKEND, signer.timeout(), signer.call("GetPublicKey", &body)).await?;
+        if response.key_spec != KEY_SPEC {
+            return Err(failure(BACKEND, format!("key {} is {}, expected {}", config.key_id, response.key_spec, KEY_SPEC)));
+        }
+        let der = decode_base64(BACKEND, "PublicKey", &response.public_key)?;
+        let raw = der
+            .strip_prefix(&ED25519_SPKI_PREFIX[..])
+            .ok_or_else(|| failure(BACKEND, "PublicKey is not an Ed25519 SubjectPublicKeyInfo"))?;
+        signer.public_key = public_key_array(BACKEND, raw)?;
+        Ok(signer)
+    }
+
+    fn timeout(&self) -> Duration {
+        Duration::from_millis(self.config.timeout_ms)
+    }
+
+    /// 调用 KMS 接口，`action` 为 `TrentService` 下的操作名
+    async fn call<T: for<'de> Deserialize<'de>>(&self, action: &str, body: &serde_json::Value) -> Result<T, Error> {
+        let body = serde_json::to_vec(body).map_err(|e| Error::Serialization(e.to_string()))?;
+        let host = match (self.endpoint.host_str(), self.endpoint.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err(Error::Config(format!("AWS KMS endpoint has no host: {}", self.endpoint))),
+        };
+        let amz_date = amz_date(
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_err(|e| Error::Chain(format!("Failed to get current time: {}", e)))?
+                .as_secs(),
+        );
+
+        let mut headers = vec![
+            ("content-type", "application/x-amz-json-1.1".to_string()),
+            ("host", host),
+            ("x-amz-date", amz_date.clone()),
+            ("x-amz-target", format!("TrentService.{}", action)),
+        ];
+        if let Some(token) = &self.config.session_token {
+            headers.push(("x-amz-security-token", token.clone()));
+        }
+        let credentials = SigV4 {
+            access_key_id: &self.config.access_key_id,
+            secret_access_key: &self.config.secret_access_key,
+            region: &self.config.region,
+            service: SERVICE,
+        };
+        let authorization = credentials.authorization("POST", self.endpoint.path(), &mut headers, &body, &amz_date);
+
+        let mut request = self.client.post(self.endpoint.clone()).body(body);
+        for (name, value) in &headers {
+            if *name != "host" {
+                request = request.header(*name, value);
+            }
+        }
+        let response = request
+            .header("authorization", authorization)
+            .send()
+            .await
+            .map_err(|e| failure(BACKEND, format!("request failed: {}", e)))?;
+        let status = response.status();
+        if !status.is_success() {
+            let detail = response.text().await.unwrap_or_default();
+            return Err(failure(BACKEND, format!("{} returned {}: {}", action, status, detail)));
+        }
+        response
+            .json()
+            .await
+            .map_err(|e| failure(BACKEND, format!("invalid {} response: {}", action, e)))
+    }
+}
+
+#[async_trait]
+impl Signer for AwsKmsSigner {
+    fn backend(&self) -> &str {
+        BACKEND
+    }
+
+    fn public_key(&self) -> [u8; 32] {
+        self.public_key
+    }
+
+    async fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+        let body = json!({
+            "KeyId": self.config.key_id,
+            "Message": encode_base64(message),
+            "MessageType": "RAW",
+            "SigningAlgorithm": SIGNING_ALGORITHM,
+        });
+        let response: SignResponse = timed(BACKEND, self.timeout(), self.call("Sign", &body)).await?;
+        let signature = decode_base64(BACKEND, "Signature", &response.signature)?;
+        check_signature(BACKEND, &self.public_key, message, signature)
+    }
+}
+
+/// AWS Signature Version 4 请求签名
+struct SigV4<'a> {
+    access_key_id: &'a str,
+    secret_access_key: &'a str,
+    region: &'a str,
+    service: &'a str,
+}
+
+impl SigV4<'_> {
+    /// 计算 `Authorization` 头，`headers` 须全部参与签名，名称为小写
+    fn authorization(&self, method: &str, path: &str, headers: &mut [(&str, String)], body: &[u8], amz_date: &str) -> String {
+        headers.sort_by(|a, b| a.0.cmp(b.0));
+        let canonical_headers: String = headers
+            .iter()
+            .map(|(name, value)| format!("{}:{}\n", name, value.trim()))
+            .collect();
+        let signed_headers = headers.iter().map(|(name, _)| *name).collect::<Vec<_>>().join(";");
+        let canonical_request = format!(
+            "{}\n{}\n\n{}\n{}\n{}",
+            method,
+            if path.is_empty() { "/" } else { path },
+            canonical_headers,
+            signed_headers,
+            hex::encode(Sha256::digest(body))
+        );
+
+        let date = &amz_date[..8];
+        let scope = format!("{}/{}/{}/aws4_request", date, self.region, self.service);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date,
+            scope,
+            hex::encode(Sha256::digest(canonical_request.as_bytes()))
+        );
+
+        let mut key = hmac_sha256(format!("AWS4{}", self.secret_access_key).as_bytes(), date.as_bytes());
+        for part in [self.region, self.service, "aws4_request"] {
+            key = hmac_sha256(&key, part.as_bytes());
+        }
+        let signature = hex::encode(hmac_sha256(&key, string_to_sign.as_bytes()));
+        format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            self.access_key_id, scope, signed_headers, signature
+        )
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// 把 Unix 时间戳格式化为 SigV4 使用的 `YYYYMMDD'T'HHMMSS'Z'`
+fn amz_date(unix_secs: u64) -> String {
+    let secs = unix_secs % 86_400;
+    // 按公历由天数换算年月日
+    let z = (unix_secs / 86_400) as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}{:02}{:02}T{:02}{:02}{:02}Z",
+        year, month, day, secs / 3_600, secs % 3_600 / 60, secs % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sigv4_reference_request() {
+        assert_eq!(amz_date(0), "19700101T000000Z");
+        assert_eq!(amz_date(1_700_000_000), "20231114T221320Z");
+        assert_eq!(amz_date(1_440_938_160), "20150830T123600Z");
+
+        // AWS SigV4 测试套件中的 get-vanilla 请求
+        let credentials = SigV4 {
+            access_key_id: "AKIDEXAMPLE",
+            secret_access_key: "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+            region: "us-east-1",
+            service: "service",
+        };
+        let mut headers = vec![
+            ("x-amz-date", "20150830T123600Z".to_string()),
+            ("host", "example.amazonaws.com".to_string()),
+        ];
+        assert_eq!(
+            credentials.authorization("GET", "/", &mut headers, b"", "20150830T123600Z"),
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=host;x-amz-date, \
+             Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"
+        );
+    }
+}
@@ -0,0 +1,214 @@
+use async_trait::async_trait;
+use chacha20poly1305::aead::{rand_core::RngCore, OsRng};
+use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer as _};
+use std::future::Future;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::debug;
+use crate::{
+    config::SignerConfig,
+    keystore::verify_signature,
+    types::{ChainRegistry, CrossChainMessage},
+    Error,
+};
+
+pub mod kms;
+#[cfg(feature = "remote-signer")]
+pub mod remote;
+pub mod vault;
+
+pub use kms::AwsKmsSigner;
+#[cfg(feature = "remote-signer")]
+pub use remote::RemoteSigner;
+pub use vault::VaultTransitSigner;
+
+/// 原始数据签名的域分隔前缀
+///
+/// 除跨链消息外，验证者密钥只对以此开头的数据签名（密钥轮换声明、引导包等），
+/// 原始数据签名因此不会与跨链消息的规范哈希混用；远程签名服务拒绝不带该前缀的原始数据。
+pub const RAW_SIGNING_DOMAIN: &[u8] = b"move-bridge/";
+
+/// ed25519 签名后端
+///
+/// 密钥库与消息验证器只通过该接口签名。远程后端的私钥保存在 Vault 或 KMS 中，
+/// 进程内只持有公钥；后端超时返回 `Error::SignerTimeout`，拒绝签名或返回无效签名时
+/// 返回 `Error::SignerFailure`。
+#[async_trait]
+pub trait Signer: Send + Sync {
+    /// 后端名称，出现在错误与日志中
+    fn backend(&self) -> &str;
+
+    /// ed25519 公钥
+    fn public_key(&self) -> [u8; 32];
+
+    /// 对消息签名，返回 64 字节 ed25519 签名；原始数据应以 [`RAW_SIGNING_DOMAIN`] 开头
+    async fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error>;
+
+    /// 对跨链消息的规范哈希签名
+    ///
+    /// 远程签名服务需要看到完整消息才能按消息类型做白名单检查，因此单独提供该入口。
+    async fn sign_message(&self, message: &CrossChainMessage, chains: &ChainRegistry) -> Result<Vec<u8>, Error> {
+        self.sign(&message.canonical_hash(chains)?).await
+    }
+
+    /// 可导出的私钥，只有本地后端提供，用于派生 p2p 节点身份
+    fn secret_key_bytes(&self) -> Option<[u8; 32]> {
+        None
+    }
+}
+
+/// 进程内持有私钥的签名后端
+pub struct LocalSigner {
+    keypair: Keypair,
+}
+
+impl LocalSigner {
+    pub fn from_bytes(secret_key: &[u8]) -> Result<Self, Error> {
+        let secret = SecretKey::from_bytes(secret_key)
+            .map_err(|e| Error::Key(format!("Invalid secret key: {}", e)))?;
+        let public = PublicKey::from(&secret);
+        Ok(Self {
+            keypair: Keypair { secret, public },
+        })
+    }
+
+    /// 使用系统随机源生成新私钥
+    pub fn generate() -> Self {
+        let mut secret = [0u8; 32];
+        OsRng.fill_bytes(&mut secret);
+        Self::from_bytes(&secret).expect("any 32 bytes form a valid ed25519 secret key")
+    }
+
+    /// 从十六进制编码的私钥文件加载
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| Error::Key(format!("Failed to read signing key: {}", e)))?;
+        let bytes = hex::decode(content.trim().trim_start_matches("0x"))
+            .map_err(|e| Error::Key(format!("Invalid signing key encoding: {}", e)))?;
+        Self::from_bytes(&bytes)
+    }
+}
+
+#[async_trait]
+impl Signer for LocalSigner {
+    fn backend(&self) -> &str {
+        "local"
+    }
+
+    fn public_key(&self) -> [u8; 32] {
+        self.keypair.public.to_bytes()
+    }
+
+    async fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+        Ok(self.keypair.sign(message).to_bytes().to_vec())
+    }
+
+    fn secret_key_bytes(&self) -> Option<[u8; 32]> {
+        Some(self.keypair.secret.to_bytes())
+    }
+}
+
+/// 连接配置的远程签名后端，连接时取回并缓存公钥
+pub async fn connect(config: &SignerConfig) -> Result<Arc<dyn Signer>, Error> {
+    let signer: Arc<dyn Signer> = match config {
+        SignerConfig::Vault(vault) => Arc::new(VaultTransitSigner::connect(vault).await?),
+        SignerConfig::AwsKms(kms) => Arc::new(AwsKmsSigner::connect(kms).await?),
+        #[cfg(feature = "remote-signer")]
+        SignerConfig::Remote(remote) => Arc::new(RemoteSigner::connect(remote).await?),
+        #[cfg(not(feature = "remote-signer"))]
+        SignerConfig::Remote(_) => {
+            return Err(Error::Config("Remote signer requires the remote-signer feature".to_string()));
+        }
+    };
+    debug!("Connected {} signer with public key {}", signer.backend(), hex::encode(signer.public_key()));
+    Ok(signer)
+}
+
+/// 在超时内完成一次远程请求
+pub(crate) async fn timed<T, F>(backend: &str, timeout: Duration, request: F) -> Result<T, Error>
+where
+    F: Future<Output = Result<T, Error>>,
+{
+    let started = Instant::now();
+    let result = tokio::time::timeout(timeout, request)
+        .await
+        .map_err(|_| Error::SignerTimeout {
+            backend: backend.to_string(),
+            timeout_ms: timeout.as_millis() as u64,
+        })?;
+    debug!("{} signer request took {} ms", backend, started.elapsed().as_millis());
+    result
+}
+
+/// 校验远程后端返回的签名确实由缓存的公钥签出
+pub(crate) fn check_signature(backend: &str, public_key: &[u8; 32], message: &[u8], signature: Vec<u8>) -> Result<Vec<u8>, Error> {
+    verify_signature(public_key, message, &signature)
+        .map_err(|e| failure(backend, format!("returned an invalid signature: {}", e)))?;
+    Ok(signature)
+}
+
+pub(crate) fn failure(backend: &str, message: impl Into<String>) -> Error {
+    Error::SignerFailure {
+        backend: backend.to_string(),
+        message: message.into(),
+    }
+}
+
+/// 解码远程后端返回的 base64 字段
+pub(crate) fn decode_base64(backend: &str, field: &str, value: &str) -> Result<Vec<u8>, Error> {
+    use base64::Engine as _;
+    base64::engine::general_purpose::STANDARD
+        .decode(value)
+        .map_err(|e| failure(backend, format!("invalid base64 in {}: {}", field, e)))
+}
+
+pub(crate) fn encode_base64(bytes: &[u8]) -> String {
+    use base64::Engine as _;
+    base64::engine::general_purpose::STANDARD.encode(bytes)
+}
+
+/// 把 32 字节公钥转换为定长数组
+pub(crate) fn public_key_array(backend: &str, bytes: &[u8]) -> Result<[u8; 32], Error> {
+    bytes
+        .try_into()
+        .map_err(|_| failure(backend, format!("expected a 32-byte ed25519 public key, got {} bytes", bytes.len())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct StalledSigner;
+
+    #[async_trait]
+    impl Signer for StalledSigner {
+        fn backend(&self) -> &str {
+            "stalled"
+        }
+
+        fn public_key(&self) -> [u8; 32] {
+            [0u8; 32]
+        }
+
+        async fn sign(&self, _message: &[u8]) -> Result<Vec<u8>, Error> {
+            timed(self.backend(), Duration::from_millis(10), async {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                Ok(Vec::new())
+            })
+            .await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_signer_errors_are_distinct() {
+        let local = LocalSigner::from_bytes(&[3u8; 32]).unwrap();
+        let signature = local.sign(b"message").await.unwrap();
+        assert!(check_signature("local", &local.public_key(), b"message", signature.clone()).is_ok());
+        assert!(matches!(
+            check_signature("local", &local.public_key(), b"other", signature),
+            Err(Error::SignerFailure { .. })
+        ));
+        assert!(matches!(StalledSigner.sign(b"message").await, Err(Error::SignerTimeout { timeout_ms: 10, .. })));
+    }
+}
@@ -0,0 +1,185 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::time::Duration;
+use tonic::transport::{Certificate, Channel, ClientTlsConfig, Identity};
+use crate::{
+    config::RemoteSignerConfig,
+    types::{ChainId, ChainRegistry, CrossChainMessage, NumericChainIds},
+    Error,
+};
+use super::{check_signature, failure, public_key_array, timed, Signer};
+
+pub mod proto;
+mod server;
+
+pub use server::{serve, RateLimitConfig, ServiceTlsConfig, SignerService, SignerServiceConfig};
+
+use proto::signer_client::SignerClient;
+
+const BACKEND: &str = "remote";
+
+/// 原始数据签名的用途，服务端白名单中需要显式列出
+///
+/// 原始数据签名不经过消息类型检查，服务端只接受以 [`RAW_SIGNING_DOMAIN`](super::RAW_SIGNING_DOMAIN) 开头的数据，
+/// 只应在需要导出引导包、轮换密钥等场景的服务上开启。
+pub const RAW_PURPOSE: &str = "raw";
+
+impl proto::BridgeMessage {
+    /// 附带源链与目标链的合约数字链 ID，服务端不需要链配置即可重新计算规范哈希
+    pub fn new(message: &CrossChainMessage, chains: &impl NumericChainIds) -> Result<Self, Error> {
+        let numeric = |id: &ChainId| {
+            chains
+                .numeric_chain_id(id)
+                .ok_or_else(|| Error::Config(format!("No numeric chain ID registered for {}", id)))
+        };
+        Ok(Self {
+            nonce: message.nonce,
+            source_chain: message.source_chain.to_string(),
+            target_chain: message.target_chain.to_string(),
+            message_type: message.message_type.clone(),
+            payload: message.payload.clone(),
+            source_chain_id: numeric(&message.source_chain)?,
+            target_chain_id: numeric(&message.target_chain)?,
+        })
+    }
+
+    /// 消息自带的数字链 ID 映射，用于计算规范哈希
+    pub fn chain_ids(&self) -> HashMap<u64, ChainId> {
+        HashMap::from([
+            (self.source_chain_id, self.source_chain.clone().into()),
+            (self.target_chain_id, self.target_chain.clone().into()),
+        ])
+    }
+}
+
+impl From<proto::BridgeMessage> for CrossChainMessage {
+    fn from(message: proto::BridgeMessage) -> Self {
+        Self {
+            nonce: message.nonce,
+            source_chain: message.source_chain.into(),
+            target_chain: message.target_chain.into(),
+            message_type: message.message_type,
+            payload: message.payload,
+        }
+    }
+}
+
+/// `bridge-signer` gRPC 签名服务的客户端
+///
+/// 通过双向 TLS 连接服务，连接时取回并缓存公钥。跨链消息以完整内容发送，
+/// 由服务端重新计算规范哈希并检查消息类型白名单；返回的签名在本地再次校验。
+pub struct RemoteSigner {
+    client: SignerClient<Channel>,
+    timeout: Duration,
+    public_key: [u8; 32],
+}
+
+impl RemoteSigner {
+    pub async fn connect(config: &RemoteSignerConfig) -> Result<Self, Error> {
+        let timeout = Duration::from_millis(config.timeout_ms);
+        let endpoint = Channel::from_shared(config.endpoint.clone())
+            .map_err(|e| Error::Config(format!("Invalid remote signer endpoint {}: {}", config.endpoint, e)))?
+            .tls_config(client_tls(config)?)
+            .map_err(|e| Error::Config(format!("Invalid remote signer TLS config: {}", e)))?
+            .connect_timeout(timeout);
+        let channel = timed(BACKEND, timeout, async {
+            endpoint
+                .connect()
+                .await
+                .map_err(|e| failure(BACKEND, format!("failed to connect to {}: {}", config.endpoint, e)))
+        })
+        .await?;
+
+        let mut signer = Self {
+            client: SignerClient::new(channel),
+            timeout,
+            public_key: [0u8; 32],
+        };
+        let mut client = signer.client.clone();
+        let request = signer.request(proto::GetPublicKeyRequest {});
+        let response = timed(BACKEND, timeout, async {
+            client.get_public_key(request).await.map_err(|status| signer.status_error(status))
+        })
+        .await?;
+        signer.public_key = public_key_array(BACKEND, &response.into_inner().public_key)?;
+        Ok(signer)
+    }
+
+    /// 附带 `grpc-timeout`，让服务端在客户端放弃后不再继续签名
+    fn request<T>(&self, message: T) -> tonic::Request<T> {
+        let mut request = tonic::Request::new(message);
+        request.set_timeout(self.timeout);
+        request
+    }
+
+    async fn call(&self, request: proto::SignRequest) -> Result<Vec<u8>, Error> {
+        let mut client = self.client.clone();
+        let request = self.request(request);
+        let response = timed(BACKEND, self.timeout, async {
+            client.sign(request).await.map_err(|status| self.status_error(status))
+        })
+        .await?;
+        Ok(response.into_inner().signature)
+    }
+
+    fn status_error(&self, status: tonic::Status) -> Error {
+        match status.code() {
+            tonic::Code::DeadlineExceeded => Error::SignerTimeout {
+                backend: BACKEND.to_string(),
+                timeout_ms: self.timeout.as_millis() as u64,
+            },
+            code => failure(BACKEND, format!("{:?}: {}", code, status.message())),
+        }
+    }
+}
+
+fn client_tls(config: &RemoteSignerConfig) -> Result<ClientTlsConfig, Error> {
+    let ca = read_pem(&config.ca_cert)?;
+    let cert = read_pem(&config.client_cert)?;
+    let key = read_pem(&config.client_key)?;
+    let mut tls = ClientTlsConfig::new()
+        .ca_certificate(Certificate::from_pem(ca))
+        .identity(Identity::from_pem(cert, key));
+    if let Some(domain) = &config.domain_name {
+        tls = tls.domain_name(domain.clone());
+    }
+    Ok(tls)
+}
+
+pub(crate) fn read_pem(path: &str) -> Result<Vec<u8>, Error> {
+    std::fs::read(path).map_err(|e| Error::Config(format!("Failed to read {}: {}", path, e)))
+}
+
+#[async_trait]
+impl Signer for RemoteSigner {
+    fn backend(&self) -> &str {
+        BACKEND
+    }
+
+    fn public_key(&self) -> [u8; 32] {
+        self.public_key
+    }
+
+    async fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+        let signature = self
+            .call(proto::SignRequest {
+                message: None,
+                purpose: RAW_PURPOSE.to_string(),
+                data: message.to_vec(),
+            })
+            .await?;
+        check_signature(BACKEND, &self.public_key, message, signature)
+    }
+
+    async fn sign_message(&self, message: &CrossChainMessage, chains: &ChainRegistry) -> Result<Vec<u8>, Error> {
+        let hash = message.canonical_hash(chains)?;
+        let signature = self
+            .call(proto::SignRequest {
+                message: Some(proto::BridgeMessage::new(message, chains)?),
+                purpose: String::new(),
+                data: Vec::new(),
+            })
+            .await?;
+        check_signature(BACKEND, &self.public_key, &hash, signature)
+    }
+}
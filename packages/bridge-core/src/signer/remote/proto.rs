@@ -0,0 +1,208 @@
+//! `bridge.signer.v1` 协议的消息类型与服务桩
+//!
+//! 按 `proto/signer.proto` 手工维护，结构与 tonic 生成代码一致，
+//! 构建时不依赖 protoc。
+
+use tonic::codegen::*;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GetPublicKeyRequest {}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GetPublicKeyResponse {
+    #[prost(bytes = "vec", tag = "1")]
+    pub public_key: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct BridgeMessage {
+    #[prost(uint64, tag = "1")]
+    pub nonce: u64,
+    #[prost(string, tag = "2")]
+    pub source_chain: String,
+    #[prost(string, tag = "3")]
+    pub target_chain: String,
+    #[prost(string, tag = "4")]
+    pub message_type: String,
+    #[prost(bytes = "vec", tag = "5")]
+    pub payload: Vec<u8>,
+    /// 源链的合约数字链 ID，参与规范哈希
+    #[prost(uint64, tag = "6")]
+    pub source_chain_id: u64,
+    /// 目标链的合约数字链 ID，参与规范哈希
+    #[prost(uint64, tag = "7")]
+    pub target_chain_id: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SignRequest {
+    /// 设置时签名消息的规范哈希，忽略 `purpose` 与 `data`
+    #[prost(message, optional, tag = "1")]
+    pub message: Option<BridgeMessage>,
+    /// 原始数据签名的用途，按消息类型参与白名单检查
+    #[prost(string, tag = "2")]
+    pub purpose: String,
+    #[prost(bytes = "vec", tag = "3")]
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SignResponse {
+    #[prost(bytes = "vec", tag = "1")]
+    pub signature: Vec<u8>,
+}
+
+const SERVICE_NAME: &str = "bridge.signer.v1.Signer";
+const GET_PUBLIC_KEY_PATH: &str = "/bridge.signer.v1.Signer/GetPublicKey";
+const SIGN_PATH: &str = "/bridge.signer.v1.Signer/Sign";
+
+pub mod signer_client {
+    use super::*;
+    use tonic::codegen::http::uri::PathAndQuery;
+
+    #[derive(Debug, Clone)]
+    pub struct SignerClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+
+    impl<T> SignerClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            Self {
+                inner: tonic::client::Grpc::new(inner),
+            }
+        }
+
+        pub async fn get_public_key(
+            &mut self,
+            request: impl tonic::IntoRequest<GetPublicKeyRequest>,
+        ) -> Result<tonic::Response<GetPublicKeyResponse>, tonic::Status> {
+            self.ready().await?;
+            let mut request = request.into_request();
+            request.extensions_mut().insert(GrpcMethod::new(SERVICE_NAME, "GetPublicKey"));
+            let codec = tonic_prost::ProstCodec::default();
+            self.inner.unary(request, PathAndQuery::from_static(GET_PUBLIC_KEY_PATH), codec).await
+        }
+
+        pub async fn sign(
+            &mut self,
+            request: impl tonic::IntoRequest<SignRequest>,
+        ) -> Result<tonic::Response<SignResponse>, tonic::Status> {
+            self.ready().await?;
+            let mut request = request.into_request();
+            request.extensions_mut().insert(GrpcMethod::new(SERVICE_NAME, "Sign"));
+            let codec = tonic_prost::ProstCodec::default();
+            self.inner.unary(request, PathAndQuery::from_static(SIGN_PATH), codec).await
+        }
+
+        async fn ready(&mut self) -> Result<(), tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| tonic::Status::unknown(format!("Service was not ready: {}", e.into())))
+        }
+    }
+}
+
+pub mod signer_server {
+    use super::*;
+
+    #[async_trait]
+    pub trait Signer: Send + Sync + 'static {
+        async fn get_public_key(
+            &self,
+            request: tonic::Request<GetPublicKeyRequest>,
+        ) -> Result<tonic::Response<GetPublicKeyResponse>, tonic::Status>;
+
+        async fn sign(
+            &self,
+            request: tonic::Request<SignRequest>,
+        ) -> Result<tonic::Response<SignResponse>, tonic::Status>;
+    }
+
+    #[derive(Debug)]
+    pub struct SignerServer<T> {
+        inner: Arc<T>,
+    }
+
+    impl<T> SignerServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self { inner }
+        }
+    }
+
+    impl<T> Clone for SignerServer<T> {
+        fn clone(&self) -> Self {
+            Self {
+                inner: self.inner.clone(),
+            }
+        }
+    }
+
+    impl<T: Signer> tonic::server::NamedService for SignerServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+
+    struct GetPublicKeySvc<T: Signer>(Arc<T>);
+
+    impl<T: Signer> tonic::server::UnaryService<GetPublicKeyRequest> for GetPublicKeySvc<T> {
+        type Response = GetPublicKeyResponse;
+        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+
+        fn call(&mut self, request: tonic::Request<GetPublicKeyRequest>) -> Self::Future {
+            let inner = Arc::clone(&self.0);
+            Box::pin(async move { inner.get_public_key(request).await })
+        }
+    }
+
+    struct SignSvc<T: Signer>(Arc<T>);
+
+    impl<T: Signer> tonic::server::UnaryService<SignRequest> for SignSvc<T> {
+        type Response = SignResponse;
+        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+
+        fn call(&mut self, request: tonic::Request<SignRequest>) -> Self::Future {
+            let inner = Arc::clone(&self.0);
+            Box::pin(async move { inner.sign(request).await })
+        }
+    }
+
+    impl<T, B> Service<http::Request<B>> for SignerServer<T>
+    where
+        T: Signer,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = Arc::clone(&self.inner);
+            match req.uri().path() {
+                GET_PUBLIC_KEY_PATH => Box::pin(async move {
+                    let mut grpc = tonic::server::Grpc::new(tonic_prost::ProstCodec::default());
+                    Ok(grpc.unary(GetPublicKeySvc(inner), req).await)
+                }),
+                SIGN_PATH => Box::pin(async move {
+                    let mut grpc = tonic::server::Grpc::new(tonic_prost::ProstCodec::default());
+                    Ok(grpc.unary(SignSvc(inner), req).await)
+                }),
+                _ => Box::pin(async move { Ok(tonic::Status::unimplemented("").into_http()) }),
+            }
+        }
+    }
+}
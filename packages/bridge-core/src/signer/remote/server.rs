@@ -0,0 +1,329 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tracing::{info, warn};
+use tonic::transport::{Certificate, Identity, Server, ServerTlsConfig};
+use crate::{
+    config::{env, ConfigSources, LoggingConfig, SignerConfig},
+    signer::{self, LocalSigner, Signer, RAW_SIGNING_DOMAIN},
+    types::CrossChainMessage,
+    Error,
+};
+use super::proto::{self, signer_server::SignerServer};
+use super::{read_pem, RAW_PURPOSE};
+
+/// `bridge-signer` 服务配置
+///
+/// 配置文件为 JSON，字符串值中的 `${VAR}` 按环境变量展开。
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SignerServiceConfig {
+    /// 监听地址，例如 `0.0.0.0:50051`
+    pub listen: String,
+    /// 十六进制编码的 ed25519 私钥文件，与 `backend` 二选一
+    #[serde(default)]
+    pub key_file: Option<String>,
+    /// 由 Vault 或 KMS 持有私钥
+    #[serde(default)]
+    pub backend: Option<SignerConfig>,
+    pub tls: ServiceTlsConfig,
+    /// 允许签名的消息类型；原始数据签名需显式列出 `raw`
+    pub allowed_message_types: Vec<String>,
+    #[serde(default)]
+    pub rate_limit: RateLimitConfig,
+    /// 日志配置，未配置时以 info 级别输出到终端
+    #[serde(default)]
+    pub logging: LoggingConfig,
+}
+
+/// 双向 TLS 证书，均为 PEM 文件路径
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ServiceTlsConfig {
+    pub cert: String,
+    pub key: String,
+    /// 签发客户端证书的 CA，未持有其签发证书的客户端无法建立连接
+    pub client_ca: String,
+}
+
+/// 令牌桶限速，所有客户端共享
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RateLimitConfig {
+    /// 每秒补充的签名次数
+    pub per_second: u32,
+    /// 桶容量，即允许的突发签名次数
+    pub burst: u32,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            per_second: 10,
+            burst: 20,
+        }
+    }
+}
+
+impl SignerServiceConfig {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| Error::Config(format!("Failed to read signer config: {}", e)))?;
+        let mut value: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|e| Error::Config(format!("Failed to parse signer config: {}", e)))?;
+        let mut sources = ConfigSources::default();
+        env::interpolate(&mut value, &std::env::vars().collect(), &mut sources)?;
+        let config: Self = serde_json::from_value(value)
+            .map_err(|e| sources.annotate(Error::Config(format!("Failed to parse signer config: {}", e))))?;
+        config.validate().map_err(|e| sources.annotate(e))?;
+        Ok(config)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        self.listen
+            .parse::<SocketAddr>()
+            .map_err(|e| Error::Config(format!("Invalid listen address {}: {}", self.listen, e)))?;
+        match (&self.key_file, &self.backend) {
+            (Some(_), None) | (None, Some(SignerConfig::Vault(_) | SignerConfig::AwsKms(_))) => {}
+            (None, Some(SignerConfig::Remote(_))) => {
+                return Err(Error::Config("Signer service cannot forward to another remote signer".to_string()));
+            }
+            _ => {
+                return Err(Error::Config("Exactly one of key_file and backend must be configured".to_string()));
+            }
+        }
+        if self.allowed_message_types.is_empty() {
+            return Err(Error::Config("allowed_message_types must not be empty".to_string()));
+        }
+        if self.rate_limit.per_second == 0 || self.rate_limit.burst == 0 {
+            return Err(Error::Config("Signer rate limit must be greater than 0".to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// 令牌桶
+struct RateLimiter {
+    per_second: f64,
+    burst: f64,
+    /// 当前令牌数与上次补充时间
+    state: Mutex<(f64, Instant)>,
+}
+
+impl RateLimiter {
+    fn new(config: &RateLimitConfig) -> Self {
+        Self {
+            per_second: f64::from(config.per_second),
+            burst: f64::from(config.burst),
+            state: Mutex::new((f64::from(config.burst), Instant::now())),
+        }
+    }
+
+    fn try_acquire(&self) -> bool {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        let (tokens, refilled_at) = *state;
+        let tokens = (tokens + now.duration_since(refilled_at).as_secs_f64() * self.per_second).min(self.burst);
+        if tokens < 1.0 {
+            *state = (tokens, now);
+            return false;
+        }
+        *state = (tokens - 1.0, now);
+        true
+    }
+}
+
+/// 远程签名服务
+///
+/// 只对白名单中的消息类型签名；跨链消息的签名内容由服务端根据消息重新计算，
+/// 客户端无法让服务对与消息不符的哈希签名。原始数据只以 `raw` 用途接受，且须带域分隔前缀。
+pub struct SignerService {
+    signer: Arc<dyn Signer>,
+    allowed_message_types: HashSet<String>,
+    limiter: RateLimiter,
+}
+
+impl SignerService {
+    pub fn new(signer: Arc<dyn Signer>, allowed_message_types: &[String], rate_limit: &RateLimitConfig) -> Self {
+        Self {
+            signer,
+            allowed_message_types: allowed_message_types.iter().cloned().collect(),
+            limiter: RateLimiter::new(rate_limit),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl proto::signer_server::Signer for SignerService {
+    async fn get_public_key(
+        &self,
+        _request: tonic::Request<proto::GetPublicKeyRequest>,
+    ) -> Result<tonic::Response<proto::GetPublicKeyResponse>, tonic::Status> {
+        Ok(tonic::Response::new(proto::GetPublicKeyResponse {
+            public_key: self.signer.public_key().to_vec(),
+        }))
+    }
+
+    async fn sign(
+        &self,
+        request: tonic::Request<proto::SignRequest>,
+    ) -> Result<tonic::Response<proto::SignResponse>, tonic::Status> {
+        let peer = request
+            .remote_addr()
+            .map(|addr| addr.to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+        let request = request.into_inner();
+        let (message_type, data) = match request.message {
+            Some(message) => {
+                let chains = message.chain_ids();
+                let message = CrossChainMessage::from(message);
+                let hash = message
+                    .canonical_hash(&chains)
+                    .map_err(|e| tonic::Status::invalid_argument(e.to_string()))?;
+                (message.message_type, hash.to_vec())
+            }
+            None if request.purpose.is_empty() => {
+                return Err(tonic::Status::invalid_argument("Sign request has neither message nor purpose"));
+            }
+            // 其它用途不是消息类型，不能借白名单中的消息类型签任意数据
+            None if request.purpose != RAW_PURPOSE => {
+                warn!("Rejected signing request from {} with unknown purpose {}", peer, request.purpose);
+                return Err(tonic::Status::invalid_argument(format!("Unknown signing purpose {}", request.purpose)));
+            }
+            None if !request.data.starts_with(RAW_SIGNING_DOMAIN) => {
+                warn!("Rejected raw signing request from {} without domain prefix", peer);
+                return Err(tonic::Status::invalid_argument("Raw signing data must start with the raw signing domain"));
+            }
+            None => (request.purpose, request.data),
+        };
+
+        if !self.allowed_message_types.contains(&message_type) {
+            warn!("Rejected {} signing request from {}: not allow-listed", message_type, peer);
+            return Err(tonic::Status::permission_denied(format!("Message type {} is not allowed", message_type)));
+        }
+        if !self.limiter.try_acquire() {
+            warn!("Rate limited {} signing request from {}", message_type, peer);
+            return Err(tonic::Status::resource_exhausted("Signing rate limit exceeded"));
+        }
+
+        let signature = self.signer.sign(&data).await.map_err(|e| match e {
+            Error::SignerTimeout { .. } => tonic::Status::deadline_exceeded(e.to_string()),
+            e => tonic::Status::internal(e.to_string()),
+        })?;
+        info!("Signed {} request from {}", message_type, peer);
+        Ok(tonic::Response::new(proto::SignResponse { signature }))
+    }
+}
+
+/// 按配置启动签名服务，`shutdown` 完成后停止
+pub async fn serve(config: &SignerServiceConfig, shutdown: impl Future<Output = ()>) -> Result<(), Error> {
+    config.validate()?;
+    let signer: Arc<dyn Signer> = match (&config.key_file, &config.backend) {
+        (Some(path), _) => Arc::new(LocalSigner::load(path)?),
+        (None, Some(backend)) => signer::connect(backend).await?,
+        (None, None) => unreachable!("validated above"),
+    };
+    let addr: SocketAddr = config.listen
+        .parse()
+        .map_err(|e| Error::Config(format!("Invalid listen address {}: {}", config.listen, e)))?;
+    let tls = ServerTlsConfig::new()
+        .identity(Identity::from_pem(read_pem(&config.tls.cert)?, read_pem(&config.tls.key)?))
+        .client_ca_root(Certificate::from_pem(read_pem(&config.tls.client_ca)?));
+
+    info!(
+        "Signer service listening on {} with {} key {}",
+        addr,
+        signer.backend(),
+        hex::encode(signer.public_key())
+    );
+    let service = SignerService::new(signer, &config.allowed_message_types, &config.rate_limit);
+    Server::builder()
+        .tls_config(tls)
+        .map_err(|e| Error::Config(format!("Invalid signer TLS config: {}", e)))?
+        .add_service(SignerServer::new(service))
+        .serve_with_shutdown(addr, shutdown)
+        .await
+        .map_err(|e| Error::Network(format!("Signer service failed: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proto::signer_server::Signer as _;
+
+    fn sign_request(message_type: &str) -> tonic::Request<proto::SignRequest> {
+        let message = CrossChainMessage {
+            nonce: 1,
+            source_chain: "sui".into(),
+            target_chain: "rooch".into(),
+            message_type: message_type.to_string(),
+            payload: vec![1, 2, 3],
+        };
+        let chains = HashMap::from([(1, "sui".into()), (2, "rooch".into())]);
+        tonic::Request::new(proto::SignRequest {
+            message: Some(proto::BridgeMessage::new(&message, &chains).unwrap()),
+            purpose: String::new(),
+            data: Vec::new(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_allow_list_and_rate_limit() {
+        let signer = Arc::new(LocalSigner::from_bytes(&[7u8; 32]).unwrap());
+        let rate_limit = RateLimitConfig { per_second: 1, burst: 2 };
+        let service = SignerService::new(signer.clone(), &["transfer".to_string()], &rate_limit);
+
+        // 服务端按消息重新计算规范哈希后签名
+        let response = service.sign(sign_request("transfer")).await.unwrap().into_inner();
+        let message = sign_request("transfer").into_inner().message.unwrap();
+        let chains = message.chain_ids();
+        let hash = CrossChainMessage::from(message).canonical_hash(&chains).unwrap();
+        assert_eq!(response.signature, signer.sign(&hash).await.unwrap());
+
+        let denied = service.sign(sign_request("upgrade")).await.unwrap_err();
+        assert_eq!(denied.code(), tonic::Code::PermissionDenied);
+        let raw = tonic::Request::new(proto::SignRequest {
+            message: None,
+            purpose: RAW_PURPOSE.to_string(),
+            data: b"move-bridge/bundle".to_vec(),
+        });
+        assert_eq!(service.sign(raw).await.unwrap_err().code(), tonic::Code::PermissionDenied);
+
+        // 被拒绝的请求不消耗令牌，桶容量为 2
+        assert!(service.sign(sign_request("transfer")).await.is_ok());
+        let limited = service.sign(sign_request("transfer")).await.unwrap_err();
+        assert_eq!(limited.code(), tonic::Code::ResourceExhausted);
+    }
+
+    fn raw_request(purpose: &str, data: &[u8]) -> tonic::Request<proto::SignRequest> {
+        tonic::Request::new(proto::SignRequest {
+            message: None,
+            purpose: purpose.to_string(),
+            data: data.to_vec(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_raw_data_needs_raw_purpose_and_domain() {
+        let signer = Arc::new(LocalSigner::from_bytes(&[7u8; 32]).unwrap());
+        let rate_limit = RateLimitConfig::default();
+        let allowed = ["transfer".to_string(), RAW_PURPOSE.to_string()];
+        let service = SignerService::new(signer.clone(), &allowed, &rate_limit);
+
+        // 以白名单中的消息类型作为用途，对治理消息的规范哈希签名
+        let governance = sign_request("governance").into_inner().message.unwrap();
+        let chains = governance.chain_ids();
+        let hash = CrossChainMessage::from(governance).canonical_hash(&chains).unwrap();
+        let denied = service.sign(raw_request("transfer", &hash)).await.unwrap_err();
+        assert_eq!(denied.code(), tonic::Code::InvalidArgument);
+
+        // 即使允许原始数据签名，不带域分隔前缀的数据也被拒绝
+        let denied = service.sign(raw_request(RAW_PURPOSE, &hash)).await.unwrap_err();
+        assert_eq!(denied.code(), tonic::Code::InvalidArgument);
+
+        let data = b"move-bridge/key-rotation/v1 fields";
+        let response = service.sign(raw_request(RAW_PURPOSE, data)).await.unwrap().into_inner();
+        assert_eq!(response.signature, signer.sign(data).await.unwrap());
+    }
+}
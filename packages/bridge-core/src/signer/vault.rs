@@ -0,0 +1,183 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::json;
+use std::collections::HashMap;
+use std::time::Duration;
+use crate::{config::VaultSignerConfig, Error};
+use super::{check_signature, decode_base64, encode_base64, failure, public_key_array, timed, Signer};
+
+const BACKEND: &str = "vault";
+
+#[derive(Deserialize)]
+struct VaultResponse<T> {
+    data: T,
+}
+
+#[derive(Deserialize)]
+struct KeyData {
+    #[serde(rename = "type")]
+    key_type: String,
+    latest_version: u32,
+    keys: HashMap<String, KeyVersion>,
+}
+
+#[derive(Deserialize)]
+struct KeyVersion {
+    public_key: String,
+}
+
+#[derive(Deserialize)]
+struct SignData {
+    signature: String,
+}
+
+/// Vault transit 引擎签名后端
+///
+/// 连接时读取密钥的最新版本与公钥，之后的签名请求都固定使用该版本，
+/// 密钥在 Vault 中轮换后需要重启并重新核对链上注册。
+pub struct VaultTransitSigner {
+    client: reqwest::Client,
+    config: VaultSignerConfig,
+    key_version: u32,
+    public_key: [u8; 32],
+}
+
+impl VaultTransitSigner {
+    pub async fn connect(config: &VaultSignerConfig) -> Result<Self, Error> {
+        let mut signer = Self {
+            client: reqwest::Client::new(),
+            config: config.clone(),
+            key_version: 0,
+            public_key: [0u8; 32],
+        };
+        let key: KeyData = timed(BACKEND, signer.timeout(), signer.request(reqwest::Method::GET, "keys", None)).await?;
+        if key.key_type != "ed25519" {
+            return Err(failure(BACKEND, format!("transit key {} is {}, expected ed25519", config.key_name, key.key_type)));
+        }
+        let version = key.keys.get(&key.latest_version.to_string()).ok_or_else(|| {
+            failure(BACKEND, format!("transit key {} has no version {}", config.key_name, key.latest_version))
+        })?;
+        signer.public_key = public_key_array(BACKEND, &decode_base64(BACKEND, "public_key", &version.public_key)?)?;
+        signer.key_version = key.latest_version;
+        Ok(signer)
+    }
+
+    fn timeout(&self) -> Duration {
+        Duration::from_millis(self.config.timeout_ms)
+    }
+
+    async fn request<T: for<'de> Deserialize<'de>>(
+        &self,
+        method: reqwest::Method,
+        action: &str,
+        body: Option<serde_json::Value>,
+    ) -> Result<T, Error> {
+        let url = format!(
+            "{}/v1/{}/{}/{}",
+            self.config.address.trim_end_matches('/'),
+            self.config.mount.trim_matches('/'),
+            action,
+            self.config.key_name
+        );
+        let mut request = self.client
+            .request(method, url)
+            .header("X-Vault-Token", &self.config.token);
+        if let Some(namespace) = &self.config.namespace {
+            request = request.header("X-Vault-Namespace", namespace);
+        }
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| failure(BACKEND, format!("request failed: {}", e)))?;
+        let status = response.status();
+        if !status.is_success() {
+            let detail = response.text().await.unwrap_or_default();
+            return Err(failure(BACKEND, format!("{} {} returned {}: {}", action, self.config.key_name, status, detail)));
+        }
+        let response: VaultResponse<T> = response
+            .json()
+            .await
+            .map_err(|e| failure(BACKEND, format!("invalid {} response: {}", action, e)))?;
+        Ok(response.data)
+    }
+}
+
+#[async_trait]
+impl Signer for VaultTransitSigner {
+    fn backend(&self) -> &str {
+        BACKEND
+    }
+
+    fn public_key(&self) -> [u8; 32] {
+        self.public_key
+    }
+
+    async fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+        let body = json!({
+            "input": encode_base64(message),
+            "key_version": self.key_version,
+        });
+        let data: SignData = timed(BACKEND, self.timeout(), self.request(reqwest::Method::POST, "sign", Some(body))).await?;
+        // 签名格式为 `vault:v<版本>:<base64>`
+        let encoded = data.signature
+            .rsplit_once(':')
+            .map(|(_, encoded)| encoded)
+            .ok_or_else(|| failure(BACKEND, format!("unexpected signature format: {}", data.signature)))?;
+        let signature = decode_base64(BACKEND, "signature", encoded)?;
+        check_signature(BACKEND, &self.public_key, message, signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signer::LocalSigner;
+
+    #[tokio::test]
+    async fn test_vault_transit_sign() {
+        let key = LocalSigner::from_bytes(&[5u8; 32]).unwrap();
+        let message = b"canonical hash";
+        let signature = key.sign(message).await.unwrap();
+
+        let mut server = mockito::Server::new_async().await;
+        let keys = server.mock("GET", "/v1/transit/keys/bridge")
+            .match_header("X-Vault-Token", "token")
+            .with_body(json!({"data": {
+                "type": "ed25519",
+                "latest_version": 2,
+                "keys": {"2": {"public_key": encode_base64(&key.public_key())}},
+            }}).to_string())
+            .create_async()
+            .await;
+        let sign = server.mock("POST", "/v1/transit/sign/bridge")
+            .match_body(mockito::Matcher::PartialJson(json!({"input": encode_base64(message), "key_version": 2})))
+            .with_body(json!({"data": {"signature": format!("vault:v2:{}", encode_base64(&signature))}}).to_string())
+            .create_async()
+            .await;
+        // 对其他消息返回同一签名，模拟后端签错了内容
+        server.mock("POST", "/v1/transit/sign/bridge")
+            .match_body(mockito::Matcher::PartialJson(json!({"input": encode_base64(b"other")})))
+            .with_body(json!({"data": {"signature": format!("vault:v2:{}", encode_base64(&signature))}}).to_string())
+            .create_async()
+            .await;
+
+        let config = VaultSignerConfig {
+            address: server.url(),
+            mount: "transit".to_string(),
+            key_name: "bridge".to_string(),
+            token: "token".to_string(),
+            namespace: None,
+            timeout_ms: 1_000,
+        };
+        let signer = VaultTransitSigner::connect(&config).await.unwrap();
+        assert_eq!(signer.public_key(), key.public_key());
+        assert_eq!(signer.sign(message).await.unwrap(), signature);
+        assert!(matches!(signer.sign(b"other").await, Err(Error::SignerFailure { .. })));
+        keys.assert_async().await;
+        sign.assert_async().await;
+    }
+}
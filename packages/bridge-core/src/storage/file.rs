@@ -0,0 +1,482 @@
+use async_trait::async_trait;
+use fs2::FileExt;
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use tokio::sync::{Mutex, RwLock};
+use super::{handoff_key, HeldMessage, Lease, MessageQuery, MessageRecord, MessageStore};
+use crate::{
+    ledger::{LedgerEntry, LedgerQuery},
+    schema::SchemaDescriptor,
+    Error,
+};
+
+/// 基于 JSON Lines 文件的消息存储
+///
+/// 每次更新追加一行记录，加载时同一消息以最后一行为准。
+/// 记账分录以同样方式追加到 `<path>.ledger`，负载结构追加到 `<path>.schemas`，暂缓队列追加到 `<path>.held`。
+/// 各链的事件读取游标只保留最新值，每次保存时整体改写 `<path>.cursors`。
+/// 租约保存在同目录的 `<path>.lease` 文件中，读写时加文件锁，可供同一主机上的多个实例协调。
+pub struct FileStore {
+    path: PathBuf,
+    records: RwLock<HashMap<String, MessageRecord>>,
+    file: Mutex<File>,
+    ledger: RwLock<HashMap<String, LedgerEntry>>,
+    ledger_file: Mutex<File>,
+    schemas: RwLock<HashMap<String, SchemaDescriptor>>,
+    schemas_file: Mutex<File>,
+    held: RwLock<HashMap<String, HeldMessage>>,
+    held_file: Mutex<File>,
+    /// 各链的事件读取游标
+    cursors: Mutex<HashMap<String, HashMap<String, u64>>>,
+}
+
+impl FileStore {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let records = load_lines(path, |record: &MessageRecord| record.message_id.clone())?;
+        let ledger_path = sibling_path(path, "ledger");
+        let ledger = load_lines(&ledger_path, |entry: &LedgerEntry| entry.entry_id.clone())?;
+        let schemas_path = sibling_path(path, "schemas");
+        let schemas = load_lines(&schemas_path, schema_key)?;
+        let held_path = sibling_path(path, "held");
+        let held = load_lines(&held_path, |held: &HeldMessage| held.message_id.clone())?;
+        let cursors = load_cursors(&sibling_path(path, "cursors"))?;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            records: RwLock::new(records),
+            file: Mutex::new(open_append(path)?),
+            ledger: RwLock::new(ledger),
+            ledger_file: Mutex::new(open_append(&ledger_path)?),
+            schemas: RwLock::new(schemas),
+            schemas_file: Mutex::new(open_append(&schemas_path)?),
+            held: RwLock::new(held),
+            held_file: Mutex::new(open_append(&held_path)?),
+            cursors: Mutex::new(cursors),
+        })
+    }
+
+    /// 在文件锁保护下读取、修改并写回租约表
+    fn update_leases<T>(&self, update: impl FnOnce(&mut HashMap<String, Lease>) -> T) -> Result<T, Error> {
+        let lease_path = sibling_path(&self.path, "lease");
+        let mut file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .read(true)
+            .write(true)
+            .open(&lease_path)
+            .map_err(|e| Error::Config(format!("Failed to open lease file: {}", e)))?;
+        file.lock_exclusive()
+            .map_err(|e| Error::Config(format!("Failed to lock lease file: {}", e)))?;
+
+        let result = (|| {
+            let mut content = String::new();
+            file.read_to_string(&mut content)
+                .map_err(|e| Error::Config(format!("Failed to read lease file: {}", e)))?;
+            let mut leases: HashMap<String, Lease> = if content.trim().is_empty() {
+                HashMap::new()
+            } else {
+                serde_json::from_str(&content)
+                    .map_err(|e| Error::Serialization(format!("Corrupted lease file: {}", e)))?
+            };
+
+            let result = update(&mut leases);
+
+            let content = serde_json::to_vec(&leases)
+                .map_err(|e| Error::Serialization(e.to_string()))?;
+            file.set_len(0)
+                .and_then(|_| file.seek(SeekFrom::Start(0)))
+                .and_then(|_| file.write_all(&content))
+                .map_err(|e| Error::Config(format!("Failed to write lease file: {}", e)))?;
+            Ok(result)
+        })();
+
+        let _ = FileExt::unlock(&file);
+        result
+    }
+}
+
+/// 存储文件旁的附属文件路径，例如 `<path>.lease`
+fn sibling_path(path: &Path, extension: &str) -> PathBuf {
+    let mut sibling = path.as_os_str().to_owned();
+    sibling.push(".");
+    sibling.push(extension);
+    PathBuf::from(sibling)
+}
+
+/// 读取游标文件，文件不存在时为空
+fn load_cursors(path: &Path) -> Result<HashMap<String, HashMap<String, u64>>, Error> {
+    match std::fs::read_to_string(path) {
+        Ok(content) if !content.trim().is_empty() => serde_json::from_str(&content)
+            .map_err(|e| Error::Serialization(format!("Corrupted cursor file: {}", e))),
+        Ok(_) => Ok(HashMap::new()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(HashMap::new()),
+        Err(e) => Err(Error::Config(format!("Failed to read cursor file: {}", e))),
+    }
+}
+
+fn schema_key(schema: &SchemaDescriptor) -> String {
+    format!("{}@{}", schema.message_type, schema.version)
+}
+
+fn open_append(path: &Path) -> Result<File, Error> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| Error::Config(format!("Failed to open store: {}", e)))
+}
+
+/// 加载 JSON Lines 文件，同一键以最后一行为准
+fn load_lines<T, K>(path: &Path, key: K) -> Result<HashMap<String, T>, Error>
+where
+    T: serde::de::DeserializeOwned,
+    K: Fn(&T) -> String,
+{
+    let mut items = HashMap::new();
+    if !path.exists() {
+        return Ok(items);
+    }
+
+    let reader = BufReader::new(
+        File::open(path).map_err(|e| Error::Config(format!("Failed to open store: {}", e)))?,
+    );
+    for line in reader.lines() {
+        let line = line.map_err(|e| Error::Config(format!("Failed to read store: {}", e)))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let item: T = serde_json::from_str(&line)
+            .map_err(|e| Error::Serialization(format!("Corrupted store record: {}", e)))?;
+        items.insert(key(&item), item);
+    }
+    Ok(items)
+}
+
+#[async_trait]
+impl MessageStore for FileStore {
+    async fn upsert(&self, record: &MessageRecord) -> Result<(), Error> {
+        let mut line = serde_json::to_vec(record)
+            .map_err(|e| Error::Serialization(e.to_string()))?;
+        line.push(b'\n');
+
+        let mut file = self.file.lock().await;
+        file.write_all(&line)
+            .map_err(|e| Error::Config(format!("Failed to write store: {}", e)))?;
+        self.records
+            .write()
+            .await
+            .insert(record.message_id.clone(), record.clone());
+        Ok(())
+    }
+
+    async fn get(&self, message_id: &str) -> Result<Option<MessageRecord>, Error> {
+        Ok(self.records.read().await.get(message_id).cloned())
+    }
+
+    async fn query(&self, query: &MessageQuery) -> Result<Vec<MessageRecord>, Error> {
+        let records = self.records.read().await;
+        let mut result: Vec<_> = records
+            .values()
+            .filter(|r| query.matches(r))
+            .cloned()
+            .collect();
+        result.sort_by_key(|r| r.observed_at);
+        if let Some(limit) = query.limit {
+            result.truncate(limit);
+        }
+        Ok(result)
+    }
+
+    async fn remove(&self, message_ids: &[String]) -> Result<usize, Error> {
+        let mut file = self.file.lock().await;
+        let mut records = self.records.write().await;
+        let removed = message_ids.iter().filter(|id| records.remove(id.as_str()).is_some()).count();
+        if removed == 0 {
+            return Ok(0);
+        }
+
+        // 重写存储文件，每条消息只保留最新的一行
+        let mut remaining: Vec<_> = records.values().collect();
+        remaining.sort_by(|a, b| (a.observed_at, &a.message_id).cmp(&(b.observed_at, &b.message_id)));
+        let mut lines = Vec::new();
+        for record in remaining {
+            serde_json::to_writer(&mut lines, record)
+                .map_err(|e| Error::Serialization(e.to_string()))?;
+            lines.push(b'\n');
+        }
+        let compacted = sibling_path(&self.path, "compact");
+        std::fs::write(&compacted, &lines)
+            .and_then(|_| std::fs::rename(&compacted, &self.path))
+            .map_err(|e| Error::Config(format!("Failed to rewrite store: {}", e)))?;
+        *file = open_append(&self.path)?;
+        Ok(removed)
+    }
+
+    async fn append_ledger(&self, entries: &[LedgerEntry]) -> Result<(), Error> {
+        let mut lines = Vec::new();
+        for entry in entries {
+            serde_json::to_writer(&mut lines, entry)
+                .map_err(|e| Error::Serialization(e.to_string()))?;
+            lines.push(b'\n');
+        }
+
+        let mut file = self.ledger_file.lock().await;
+        file.write_all(&lines)
+            .map_err(|e| Error::Config(format!("Failed to write ledger: {}", e)))?;
+        let mut ledger = self.ledger.write().await;
+        for entry in entries {
+            ledger.insert(entry.entry_id.clone(), entry.clone());
+        }
+        Ok(())
+    }
+
+    async fn query_ledger(&self, query: &LedgerQuery) -> Result<Vec<LedgerEntry>, Error> {
+        let ledger = self.ledger.read().await;
+        let mut result: Vec<_> = ledger
+            .values()
+            .filter(|e| query.matches(e))
+            .cloned()
+            .collect();
+        result.sort_by(|a, b| (a.timestamp, &a.entry_id).cmp(&(b.timestamp, &b.entry_id)));
+        Ok(result)
+    }
+
+    async fn acquire_lease(&self, name: &str, holder: &str, ttl: u64, now: u64) -> Result<bool, Error> {
+        let (acquired, taken_over) = self.update_leases(|leases| match leases.get(name) {
+            Some(lease) if lease.holder != holder && lease.expires_at > now => (false, false),
+            current => {
+                let taken_over = current.is_none_or(|lease| lease.holder != holder);
+                leases.insert(name.to_string(), Lease {
+                    holder: holder.to_string(),
+                    expires_at: now + ttl,
+                });
+                (true, taken_over)
+            }
+        })?;
+
+        // 接管租约时重新加载其它实例写入的记录
+        if taken_over {
+            self.reload().await?;
+        }
+        Ok(acquired)
+    }
+
+    async fn release_lease(&self, name: &str, holder: &str) -> Result<(), Error> {
+        self.update_leases(|leases| {
+            if leases.get(name).is_some_and(|lease| lease.holder == holder) {
+                leases.remove(name);
+            }
+        })
+    }
+
+    async fn request_handoff(&self, name: &str, holder: &str, expires_at: u64) -> Result<(), Error> {
+        self.update_leases(|leases| {
+            leases.insert(handoff_key(name), Lease { holder: holder.to_string(), expires_at });
+        })
+    }
+
+    async fn handoff(&self, name: &str, now: u64) -> Result<Option<String>, Error> {
+        self.update_leases(|leases| {
+            leases
+                .get(&handoff_key(name))
+                .filter(|handoff| handoff.expires_at > now)
+                .map(|handoff| handoff.holder.clone())
+        })
+    }
+
+    async fn clear_handoff(&self, name: &str, holder: &str) -> Result<(), Error> {
+        self.release_lease(&handoff_key(name), holder).await
+    }
+
+    async fn reload(&self) -> Result<(), Error> {
+        *self.records.write().await =
+            load_lines(&self.path, |record: &MessageRecord| record.message_id.clone())?;
+        *self.ledger.write().await =
+            load_lines(&sibling_path(&self.path, "ledger"), |entry: &LedgerEntry| entry.entry_id.clone())?;
+        *self.schemas.write().await = load_lines(&sibling_path(&self.path, "schemas"), schema_key)?;
+        *self.held.write().await =
+            load_lines(&sibling_path(&self.path, "held"), |held: &HeldMessage| held.message_id.clone())?;
+        *self.cursors.lock().await = load_cursors(&sibling_path(&self.path, "cursors"))?;
+        Ok(())
+    }
+
+    async fn register_schema(&self, schema: &SchemaDescriptor) -> Result<(), Error> {
+        let mut line = serde_json::to_vec(schema)
+            .map_err(|e| Error::Serialization(e.to_string()))?;
+        line.push(b'\n');
+
+        let mut file = self.schemas_file.lock().await;
+        file.write_all(&line)
+            .map_err(|e| Error::Config(format!("Failed to write schemas: {}", e)))?;
+        self.schemas.write().await.insert(schema_key(schema), schema.clone());
+        Ok(())
+    }
+
+    async fn schemas(&self, message_type: Option<&str>) -> Result<Vec<SchemaDescriptor>, Error> {
+        let schemas = self.schemas.read().await;
+        let mut result: Vec<_> = schemas
+            .values()
+            .filter(|s| message_type.is_none_or(|t| s.message_type == t))
+            .cloned()
+            .collect();
+        result.sort_by(|a, b| (&a.message_type, a.version).cmp(&(&b.message_type, b.version)));
+        Ok(result)
+    }
+
+    async fn hold(&self, held: &HeldMessage) -> Result<(), Error> {
+        let mut line = serde_json::to_vec(held)
+            .map_err(|e| Error::Serialization(e.to_string()))?;
+        line.push(b'\n');
+
+        let mut file = self.held_file.lock().await;
+        file.write_all(&line)
+            .map_err(|e| Error::Config(format!("Failed to write held queue: {}", e)))?;
+        self.held.write().await.insert(held.message_id.clone(), held.clone());
+        Ok(())
+    }
+
+    async fn held(&self) -> Result<Vec<HeldMessage>, Error> {
+        let mut result: Vec<_> = self.held.read().await.values().cloned().collect();
+        result.sort_by(|a, b| (a.held_at, &a.message_id).cmp(&(b.held_at, &b.message_id)));
+        Ok(result)
+    }
+
+    async fn remove_held(&self, message_id: &str) -> Result<bool, Error> {
+        let mut file = self.held_file.lock().await;
+        let mut held = self.held.write().await;
+        if held.remove(message_id).is_none() {
+            return Ok(false);
+        }
+
+        // 重写暂缓队列文件，只保留仍在队列中的消息
+        let held_path = sibling_path(&self.path, "held");
+        let mut lines = Vec::new();
+        for item in held.values() {
+            serde_json::to_writer(&mut lines, item)
+                .map_err(|e| Error::Serialization(e.to_string()))?;
+            lines.push(b'\n');
+        }
+        let compacted = sibling_path(&held_path, "compact");
+        std::fs::write(&compacted, &lines)
+            .and_then(|_| std::fs::rename(&compacted, &held_path))
+            .map_err(|e| Error::Config(format!("Failed to rewrite held queue: {}", e)))?;
+        *file = open_append(&held_path)?;
+        Ok(true)
+    }
+
+    async fn cursors(&self, chain: &str) -> Result<HashMap<String, u64>, Error> {
+        Ok(self.cursors.lock().await.get(chain).cloned().unwrap_or_default())
+    }
+
+    async fn save_cursors(&self, chain: &str, cursors: &HashMap<String, u64>) -> Result<(), Error> {
+        let mut all = self.cursors.lock().await;
+        all.insert(chain.to_string(), cursors.clone());
+
+        // 先写临时文件再改名，进程中途退出不会留下写了一半的游标文件
+        let content = serde_json::to_vec(&*all)
+            .map_err(|e| Error::Serialization(e.to_string()))?;
+        let cursors_path = sibling_path(&self.path, "cursors");
+        let tmp = sibling_path(&cursors_path, "tmp");
+        std::fs::write(&tmp, &content)
+            .and_then(|_| std::fs::rename(&tmp, &cursors_path))
+            .map_err(|e| Error::Config(format!("Failed to write cursor file: {}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_lease_exclusive_until_expiry() {
+        let path = std::env::temp_dir().join(format!("bridge-lease-test-{}.jsonl", std::process::id()));
+        let store = FileStore::open(&path).unwrap();
+
+        assert!(store.acquire_lease("relayer", "a", 30, 100).await.unwrap());
+        assert!(!store.acquire_lease("relayer", "b", 30, 110).await.unwrap());
+        // 持有者续期
+        assert!(store.acquire_lease("relayer", "a", 30, 120).await.unwrap());
+        // 过期后由其它实例接管
+        assert!(store.acquire_lease("relayer", "b", 30, 151).await.unwrap());
+        store.release_lease("relayer", "b").await.unwrap();
+        assert!(store.acquire_lease("relayer", "a", 30, 152).await.unwrap());
+
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(sibling_path(&path, "lease"));
+        let _ = std::fs::remove_file(sibling_path(&path, "ledger"));
+        let _ = std::fs::remove_file(sibling_path(&path, "schemas"));
+        let _ = std::fs::remove_file(sibling_path(&path, "held"));
+    }
+
+    #[tokio::test]
+    async fn test_cursors_survive_reopen() {
+        let path = std::env::temp_dir().join(format!("bridge-cursors-test-{}.jsonl", std::process::id()));
+        {
+            let store = FileStore::open(&path).unwrap();
+            assert!(store.cursors("rooch_testnet").await.unwrap().is_empty());
+            store.save_cursors("rooch_testnet", &HashMap::from([("0x42::message::MessageSentEvent".to_string(), 120)])).await.unwrap();
+            store.save_cursors("sui_testnet", &HashMap::from([("checkpoint".to_string(), 7)])).await.unwrap();
+            store.save_cursors("rooch_testnet", &HashMap::from([("0x42::message::MessageSentEvent".to_string(), 130)])).await.unwrap();
+        }
+
+        let store = FileStore::open(&path).unwrap();
+        assert_eq!(store.cursors("rooch_testnet").await.unwrap()["0x42::message::MessageSentEvent"], 130);
+        assert_eq!(store.cursors("sui_testnet").await.unwrap()["checkpoint"], 7);
+
+        for extension in ["ledger", "schemas", "held", "cursors"] {
+            let _ = std::fs::remove_file(sibling_path(&path, extension));
+        }
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_held_queue_survives_reopen() {
+        use crate::storage::{release_held, HeldMessage, HoldCheck};
+        use crate::types::{CrossChainMessage, SignedMessage};
+
+        let path = std::env::temp_dir().join(format!("bridge-held-test-{}.jsonl", std::process::id()));
+        let message = SignedMessage {
+            message: CrossChainMessage {
+                nonce: 1,
+                source_chain: "sui_testnet".into(),
+                target_chain: "rooch_testnet".into(),
+                message_type: "call".to_string(),
+                payload: vec![1, 2, 3],
+            },
+            signature: vec![],
+            timestamp: 10,
+            event_name: Some("MessageSent".to_string()),
+            sender: Some("0xa".to_string()),
+            provenance: None,
+            proof: None,
+            trace_id: None,
+        };
+        {
+            let store = FileStore::open(&path).unwrap();
+            store.hold(&HeldMessage::new("sui_testnet", "m1", &message, HoldCheck::Schema, "unknown".to_string(), 20)).await.unwrap();
+            store.hold(&HeldMessage::new("sui_testnet", "m2", &message, HoldCheck::Recipient, "no account".to_string(), 30)).await.unwrap();
+            assert_eq!(release_held(&store, "m1", 40).await.unwrap().unwrap().released_at, Some(40));
+            assert!(release_held(&store, "m3", 40).await.unwrap().is_none());
+        }
+
+        let store = FileStore::open(&path).unwrap();
+        let held = store.held().await.unwrap();
+        assert_eq!(held.iter().map(|h| h.message_id.as_str()).collect::<Vec<_>>(), ["m1", "m2"]);
+        assert_eq!(held[0].released_at, Some(40));
+        assert_eq!(held[0].signed_message().sender.as_deref(), Some("0xa"));
+        assert_eq!(held[1].check, HoldCheck::Recipient);
+
+        assert!(store.remove_held("m1").await.unwrap());
+        assert!(!store.remove_held("m1").await.unwrap());
+        let store = FileStore::open(&path).unwrap();
+        assert_eq!(store.held().await.unwrap().len(), 1);
+
+        for extension in ["ledger", "schemas", "held"] {
+            let _ = std::fs::remove_file(sibling_path(&path, extension));
+        }
+        let _ = std::fs::remove_file(&path);
+    }
+}
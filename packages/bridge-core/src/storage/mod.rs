@@ -0,0 +1,344 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use crate::{
+    config::StorageConfig,
+    ledger::{LedgerEntry, LedgerQuery},
+    relayer::equivocation::EquivocationEvidence,
+    schema::SchemaDescriptor,
+    types::{Attestation, CrossChainMessage, EventProvenance, FailureCause, MessageStatus, SignedMessage},
+    Error,
+};
+
+/// 消息处理记录
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageRecord {
+    pub message_id: String,
+    pub source_chain: String,
+    pub target_chain: String,
+    pub asset: Option<String>,
+    /// 源链上发起转账的账户，适配器无法提供时为 None
+    #[serde(default)]
+    pub sender: Option<String>,
+    /// 转账接收者地址（十六进制，不带 0x 前缀），非转账消息为 None
+    #[serde(default)]
+    pub recipient: Option<String>,
+    pub nonce: u64,
+    pub status: MessageStatus,
+    /// 中继器观察到消息的时间（秒）
+    pub observed_at: u64,
+    /// 消息进入终态的时间（秒）
+    pub completed_at: Option<u64>,
+    pub error: Option<String>,
+    /// 结构化的失败原因，供接入方展示失败原因与处理建议
+    #[serde(default)]
+    pub cause: Option<FailureCause>,
+}
+
+/// 消息查询条件，未设置的字段不参与过滤
+#[derive(Debug, Clone, Default)]
+pub struct MessageQuery {
+    pub status: Option<MessageStatus>,
+    pub source_chain: Option<String>,
+    pub target_chain: Option<String>,
+    /// 源链或目标链为该链
+    pub chain: Option<String>,
+    pub sender: Option<String>,
+    /// 转账接收者，格式同 `MessageRecord.recipient`
+    pub recipient: Option<String>,
+    /// 观察时间下界（含）
+    pub since: Option<u64>,
+    /// 观察时间上界（不含）
+    pub until: Option<u64>,
+    pub limit: Option<usize>,
+}
+
+impl MessageQuery {
+    /// 判断记录是否满足查询条件
+    pub fn matches(&self, record: &MessageRecord) -> bool {
+        self.status.as_ref().is_none_or(|s| &record.status == s)
+            && self.source_chain.as_ref().is_none_or(|c| &record.source_chain == c)
+            && self.target_chain.as_ref().is_none_or(|c| &record.target_chain == c)
+            && self.chain.as_ref().is_none_or(|c| &record.source_chain == c || &record.target_chain == c)
+            && self.sender.as_ref().is_none_or(|s| record.sender.as_ref() == Some(s))
+            && self.recipient.as_ref().is_none_or(|r| record.recipient.as_ref() == Some(r))
+            && self.since.is_none_or(|t| record.observed_at >= t)
+            && self.until.is_none_or(|t| record.observed_at < t)
+    }
+}
+
+/// 中继器观察到的源链事件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourceRecord {
+    pub message_id: String,
+    /// 观察到事件的链
+    pub chain: String,
+    pub event_name: Option<String>,
+    pub sender: Option<String>,
+    pub message: CrossChainMessage,
+    pub signature: Vec<u8>,
+    pub observed_at: u64,
+    /// 机密消息的负载未保存，消息只能通过消息 ID 追溯
+    #[serde(default)]
+    pub payload_redacted: bool,
+    /// 事件在源链上的位置
+    #[serde(default)]
+    pub provenance: Option<EventProvenance>,
+}
+
+/// 消息进入暂缓队列时未通过的中继前检查
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HoldCheck {
+    /// 负载不符合注册的结构
+    Schema,
+    /// 接收者无法在目标链上接收资产
+    Recipient,
+}
+
+/// 暂缓队列中的消息
+///
+/// 保存重新中继所需的完整消息，人工放行后中继器跳过 `check` 重新处理，不依赖源链事件仍可读取。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeldMessage {
+    pub message_id: String,
+    /// 观察到消息的链
+    pub chain: String,
+    pub message: CrossChainMessage,
+    pub signature: Vec<u8>,
+    /// 源链事件的时间
+    pub timestamp: u64,
+    #[serde(default)]
+    pub event_name: Option<String>,
+    #[serde(default)]
+    pub sender: Option<String>,
+    #[serde(default)]
+    pub provenance: Option<EventProvenance>,
+    pub check: HoldCheck,
+    pub reason: String,
+    pub held_at: u64,
+    /// 人工放行的时间，未放行时为 None
+    #[serde(default)]
+    pub released_at: Option<u64>,
+}
+
+impl HeldMessage {
+    pub fn new(chain: &str, message_id: &str, message: &SignedMessage, check: HoldCheck, reason: String, held_at: u64) -> Self {
+        Self {
+            message_id: message_id.to_string(),
+            chain: chain.to_string(),
+            message: message.message.clone(),
+            signature: message.signature.clone(),
+            timestamp: message.timestamp,
+            event_name: message.event_name.clone(),
+            sender: message.sender.clone(),
+            provenance: message.provenance.clone(),
+            check,
+            reason,
+            held_at,
+            released_at: None,
+        }
+    }
+
+    /// 还原为观察到的消息，重新进入待处理队列
+    pub fn signed_message(&self) -> SignedMessage {
+        SignedMessage {
+            message: self.message.clone(),
+            signature: self.signature.clone(),
+            timestamp: self.timestamp,
+            event_name: self.event_name.clone(),
+            sender: self.sender.clone(),
+            provenance: self.provenance.clone(),
+            proof: None,
+            trace_id: None,
+        }
+    }
+}
+
+/// 放行暂缓队列中的消息，中继器下一轮将其重新加入待处理队列；消息不在队列中时返回 None
+pub async fn release_held(store: &dyn MessageStore, message_id: &str, now: u64) -> Result<Option<HeldMessage>, Error> {
+    let Some(mut held) = store.held().await?.into_iter().find(|h| h.message_id == message_id) else {
+        return Ok(None);
+    };
+    if held.released_at.is_none() {
+        held.released_at = Some(now);
+        store.hold(&held).await?;
+    }
+    Ok(Some(held))
+}
+
+/// 一次交易提交尝试
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubmissionRecord {
+    pub message_id: String,
+    /// 提交的目标链
+    pub chain: String,
+    /// 第几次尝试，从 1 开始
+    pub attempt: u32,
+    pub tx_hash: Option<String>,
+    pub error: Option<String>,
+    pub submitted_at: u64,
+}
+
+/// 提交失败后安排的一次重试
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetryRecord {
+    pub message_id: String,
+    /// 即将进行的尝试序号
+    pub attempt: u32,
+    pub reason: String,
+    /// 计划重试时间（秒）
+    pub retry_at: u64,
+}
+
+/// 多实例协调使用的租约
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Lease {
+    pub holder: String,
+    /// 租约到期时间（秒）
+    pub expires_at: u64,
+}
+
+/// 租约移交请求的存储键，与租约保存在一起
+pub(crate) fn handoff_key(name: &str) -> String {
+    format!("{}.handoff", name)
+}
+
+/// 消息存储特征
+#[async_trait]
+pub trait MessageStore: Send + Sync {
+    /// 写入或更新消息记录
+    async fn upsert(&self, record: &MessageRecord) -> Result<(), Error>;
+
+    /// 按消息 ID 查询记录
+    async fn get(&self, message_id: &str) -> Result<Option<MessageRecord>, Error>;
+
+    /// 按条件查询记录，结果按观察时间升序排列
+    async fn query(&self, query: &MessageQuery) -> Result<Vec<MessageRecord>, Error>;
+
+    /// 删除消息记录及其处理历史，返回删除的消息数；记账分录与双签证据保留
+    async fn remove(&self, message_ids: &[String]) -> Result<usize, Error>;
+
+    /// 写入记账分录，相同 `entry_id` 的分录覆盖写入
+    async fn append_ledger(&self, entries: &[LedgerEntry]) -> Result<(), Error>;
+
+    /// 按条件查询记账分录，结果按时间升序排列
+    async fn query_ledger(&self, query: &LedgerQuery) -> Result<Vec<LedgerEntry>, Error>;
+
+    /// 尝试获取或续期租约：租约空闲、已过期或已由 `holder` 持有时成功，返回是否持有租约
+    async fn acquire_lease(&self, name: &str, holder: &str, ttl: u64, now: u64) -> Result<bool, Error>;
+
+    /// 释放 `holder` 持有的租约
+    async fn release_lease(&self, name: &str, holder: &str) -> Result<(), Error>;
+
+    /// 请求把租约移交给 `holder`：当前持有者续期时让出租约，`expires_at` 前未完成移交时请求失效
+    async fn request_handoff(&self, name: &str, holder: &str, expires_at: u64) -> Result<(), Error>;
+
+    /// 查询租约尚未失效的移交请求，返回接手的实例
+    async fn handoff(&self, name: &str, now: u64) -> Result<Option<String>, Error>;
+
+    /// 清除移交给 `holder` 的请求，接手实例取得租约后调用
+    async fn clear_handoff(&self, name: &str, holder: &str) -> Result<(), Error>;
+
+    /// 重新加载其它实例写入的记录，直接读取共享数据库的后端无需实现
+    async fn reload(&self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// 保存负载结构版本，相同类型与版本覆盖写入；不可修改的检查由 `schema::register` 负责
+    async fn register_schema(&self, schema: &SchemaDescriptor) -> Result<(), Error>;
+
+    /// 查询负载结构，`message_type` 为 None 时返回全部，结果按类型与版本升序排列
+    async fn schemas(&self, message_type: Option<&str>) -> Result<Vec<SchemaDescriptor>, Error>;
+
+    /// 写入暂缓队列，相同消息 ID 覆盖写入
+    async fn hold(&self, held: &HeldMessage) -> Result<(), Error>;
+
+    /// 按暂缓时间升序返回暂缓队列中的消息
+    async fn held(&self) -> Result<Vec<HeldMessage>, Error>;
+
+    /// 从暂缓队列移除消息，返回消息是否在队列中
+    async fn remove_held(&self, message_id: &str) -> Result<bool, Error>;
+
+    /// 查询链的事件读取游标，按游标名记录下一次读取的位置；未保存过时为空
+    async fn cursors(&self, chain: &str) -> Result<HashMap<String, u64>, Error>;
+
+    /// 覆盖保存链的事件读取游标
+    async fn save_cursors(&self, chain: &str, cursors: &HashMap<String, u64>) -> Result<(), Error>;
+
+    // 以下为消息历史记录，只保存最新状态的后端可以忽略写入、查询返回空
+
+    /// 保存消息的源链事件
+    async fn record_source(&self, _source: &SourceRecord) -> Result<(), Error> {
+        Ok(())
+    }
+
+    async fn source(&self, _message_id: &str) -> Result<Option<SourceRecord>, Error> {
+        Ok(None)
+    }
+
+    /// 保存消息收到的验证者证明
+    async fn record_attestation(&self, _attestation: &Attestation) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// 保存一次提交尝试
+    async fn record_submission(&self, _submission: &SubmissionRecord) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// 保存一次重试安排
+    async fn record_retry(&self, _retry: &RetryRecord) -> Result<(), Error> {
+        Ok(())
+    }
+
+    async fn attestations(&self, _message_id: &str) -> Result<Vec<Attestation>, Error> {
+        Ok(Vec::new())
+    }
+
+    /// 按尝试顺序返回消息的提交记录
+    async fn submissions(&self, _message_id: &str) -> Result<Vec<SubmissionRecord>, Error> {
+        Ok(Vec::new())
+    }
+
+    async fn retries(&self, _message_id: &str) -> Result<Vec<RetryRecord>, Error> {
+        Ok(Vec::new())
+    }
+
+    /// 保存双签证据，相同证据 ID 覆盖写入
+    async fn record_equivocation(&self, _evidence: &EquivocationEvidence) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// 按发现时间升序返回全部双签证据
+    async fn equivocations(&self) -> Result<Vec<EquivocationEvidence>, Error> {
+        Ok(Vec::new())
+    }
+}
+
+/// 根据存储配置打开消息存储
+pub async fn open(config: &StorageConfig) -> Result<Box<dyn MessageStore>, Error> {
+    match config.backend.as_str() {
+        "file" => Ok(Box::new(FileStore::open(&config.path)?)),
+        #[cfg(feature = "sqlite")]
+        "sqlite" => Ok(Box::new(SqliteStore::open(&config.path).await?)),
+        #[cfg(feature = "postgres")]
+        "postgres" => Ok(Box::new(PostgresStore::connect(config).await?)),
+        _ => Err(Error::Config(format!("Unsupported storage backend: {}", config.backend))),
+    }
+}
+
+// 存储后端实现
+pub mod file;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+// 保留策略与历史消息清理
+pub mod retention;
+
+pub use file::FileStore;
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteStore;
+#[cfg(feature = "postgres")]
+pub use postgres::PostgresStore;
@@ -0,0 +1,600 @@
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Serialize};
+use sqlx::{
+    pool::PoolConnection,
+    postgres::{PgPool, PgPoolOptions},
+    Postgres, QueryBuilder, Row,
+};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+use super::{handoff_key, HeldMessage, MessageQuery, MessageRecord, MessageStore, RetryRecord, SourceRecord, SubmissionRecord};
+use crate::{
+    config::StorageConfig,
+    ledger::{LedgerEntry, LedgerQuery},
+    relayer::equivocation::EquivocationEvidence,
+    schema::SchemaDescriptor,
+    types::{Attestation, MessageStatus},
+    Error,
+};
+
+/// 获取连接的超时时间
+const ACQUIRE_TIMEOUT: Duration = Duration::from_secs(10);
+/// 迁移期间持有的咨询锁，避免多个实例同时迁移
+const MIGRATION_LOCK: &str = "move-bridge-migrations";
+
+/// 按版本顺序执行的数据库迁移，已发布的迁移不可修改，只能追加
+const MIGRATIONS: &[(i64, &str)] = &[
+    (1, "CREATE TABLE messages (
+        message_id TEXT PRIMARY KEY,
+        source_chain TEXT NOT NULL,
+        target_chain TEXT NOT NULL,
+        sender TEXT,
+        status TEXT NOT NULL,
+        observed_at BIGINT NOT NULL,
+        data TEXT NOT NULL
+    );
+    CREATE INDEX messages_route ON messages (source_chain, target_chain, observed_at);
+    CREATE INDEX messages_status ON messages (status, observed_at);
+    CREATE INDEX messages_sender ON messages (sender, observed_at);
+    CREATE TABLE sources (
+        message_id TEXT PRIMARY KEY,
+        data TEXT NOT NULL
+    );
+    CREATE TABLE attestations (
+        message_id TEXT NOT NULL,
+        validator TEXT NOT NULL,
+        received_at BIGINT NOT NULL,
+        data TEXT NOT NULL,
+        PRIMARY KEY (message_id, validator)
+    );
+    CREATE TABLE submissions (
+        id BIGSERIAL PRIMARY KEY,
+        message_id TEXT NOT NULL,
+        attempt BIGINT NOT NULL,
+        data TEXT NOT NULL
+    );
+    CREATE INDEX submissions_message ON submissions (message_id, attempt);
+    CREATE TABLE retries (
+        id BIGSERIAL PRIMARY KEY,
+        message_id TEXT NOT NULL,
+        attempt BIGINT NOT NULL,
+        data TEXT NOT NULL
+    );
+    CREATE INDEX retries_message ON retries (message_id, attempt);
+    CREATE TABLE ledger (
+        entry_id TEXT PRIMARY KEY,
+        asset TEXT NOT NULL,
+        source_chain TEXT NOT NULL,
+        target_chain TEXT NOT NULL,
+        timestamp BIGINT NOT NULL,
+        data TEXT NOT NULL
+    );
+    CREATE INDEX ledger_asset ON ledger (asset, timestamp);
+    CREATE TABLE leases (
+        name TEXT PRIMARY KEY,
+        holder TEXT NOT NULL,
+        expires_at BIGINT NOT NULL
+    );"),
+    (2, "CREATE TABLE schemas (
+        message_type TEXT NOT NULL,
+        version BIGINT NOT NULL,
+        data TEXT NOT NULL,
+        PRIMARY KEY (message_type, version)
+    );"),
+    (3, "CREATE TABLE equivocations (
+        evidence_id TEXT PRIMARY KEY,
+        detected_at BIGINT NOT NULL,
+        data TEXT NOT NULL
+    );"),
+    (4, "CREATE TABLE held (
+        message_id TEXT PRIMARY KEY,
+        held_at BIGINT NOT NULL,
+        data TEXT NOT NULL
+    );"),
+    (5, "CREATE TABLE cursors (
+        chain TEXT PRIMARY KEY,
+        data TEXT NOT NULL
+    );"),
+];
+
+/// 删除消息时一并删除的处理历史
+const REMOVE_HISTORY: &[&str] = &[
+    "DELETE FROM sources WHERE message_id = $1",
+    "DELETE FROM attestations WHERE message_id = $1",
+    "DELETE FROM submissions WHERE message_id = $1",
+    "DELETE FROM retries WHERE message_id = $1",
+];
+
+/// 基于 PostgreSQL 的共享消息存储，供多实例部署使用
+///
+/// 租约使用会话级咨询锁实现：持有者在专用连接上持有锁，进程退出或连接断开时
+/// 数据库自动释放，备用实例无需等待租约过期即可接管。`leases` 表只记录当前持有者，便于运维查看。
+pub struct PostgresStore {
+    pool: PgPool,
+    /// 本实例持有咨询锁的专用连接，按租约名称索引
+    locks: Mutex<HashMap<String, PoolConnection<Postgres>>>,
+}
+
+impl PostgresStore {
+    pub async fn connect(config: &StorageConfig) -> Result<Self, Error> {
+        let url = config.url.as_deref()
+            .ok_or_else(|| Error::Config("PostgreSQL storage requires a connection url".to_string()))?;
+        let pool = PgPoolOptions::new()
+            .max_connections(config.max_connections)
+            .acquire_timeout(ACQUIRE_TIMEOUT)
+            .connect(url)
+            .await
+            .map_err(db_error)?;
+        let store = Self {
+            pool,
+            locks: Mutex::new(HashMap::new()),
+        };
+        store.migrate().await?;
+        Ok(store)
+    }
+
+    /// 执行尚未应用的迁移
+    async fn migrate(&self) -> Result<(), Error> {
+        let mut conn = self.pool.acquire().await.map_err(db_error)?;
+        sqlx::query("SELECT pg_advisory_lock(hashtext($1))")
+            .bind(MIGRATION_LOCK)
+            .execute(&mut *conn)
+            .await
+            .map_err(db_error)?;
+
+        let result = async {
+            sqlx::query("CREATE TABLE IF NOT EXISTS schema_migrations (version BIGINT PRIMARY KEY, applied_at TIMESTAMPTZ NOT NULL DEFAULT now())")
+                .execute(&mut *conn)
+                .await
+                .map_err(db_error)?;
+            let applied: Option<i64> = sqlx::query_scalar("SELECT MAX(version) FROM schema_migrations")
+                .fetch_one(&mut *conn)
+                .await
+                .map_err(db_error)?;
+
+            for (version, sql) in MIGRATIONS.iter().filter(|(v, _)| applied.is_none_or(|a| *v > a)) {
+                let mut tx = sqlx::Connection::begin(&mut *conn).await.map_err(db_error)?;
+                sqlx::raw_sql(sql).execute(&mut *tx).await.map_err(db_error)?;
+                sqlx::query("INSERT INTO schema_migrations (version) VALUES ($1)")
+                    .bind(version)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(db_error)?;
+                tx.commit().await.map_err(db_error)?;
+                info!("Applied storage migration {}", version);
+            }
+            Ok(())
+        }
+        .await;
+
+        sqlx::query("SELECT pg_advisory_unlock(hashtext($1))")
+            .bind(MIGRATION_LOCK)
+            .execute(&mut *conn)
+            .await
+            .map_err(db_error)?;
+        result
+    }
+
+    async fn fetch_data<T: DeserializeOwned>(&self, sql: &str, message_id: &str) -> Result<Vec<T>, Error> {
+        let rows = sqlx::query(sql)
+            .bind(message_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_error)?;
+        rows.iter().map(|row| decode(row.get("data"))).collect()
+    }
+}
+
+fn db_error(e: sqlx::Error) -> Error {
+    Error::Config(format!("PostgreSQL store error: {}", e))
+}
+
+fn encode<T: Serialize>(value: &T) -> Result<String, Error> {
+    serde_json::to_string(value).map_err(|e| Error::Serialization(e.to_string()))
+}
+
+fn decode<T: DeserializeOwned>(data: &str) -> Result<T, Error> {
+    serde_json::from_str(data).map_err(|e| Error::Serialization(format!("Corrupted store record: {}", e)))
+}
+
+fn status_value(status: &MessageStatus) -> Result<String, Error> {
+    Ok(encode(status)?.trim_matches('"').to_string())
+}
+
+#[async_trait]
+impl MessageStore for PostgresStore {
+    async fn upsert(&self, record: &MessageRecord) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO messages (message_id, source_chain, target_chain, sender, status, observed_at, data)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)
+             ON CONFLICT (message_id) DO UPDATE SET
+                sender = EXCLUDED.sender, status = EXCLUDED.status,
+                observed_at = EXCLUDED.observed_at, data = EXCLUDED.data",
+        )
+        .bind(&record.message_id)
+        .bind(&record.source_chain)
+        .bind(&record.target_chain)
+        .bind(&record.sender)
+        .bind(status_value(&record.status)?)
+        .bind(record.observed_at as i64)
+        .bind(encode(record)?)
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn get(&self, message_id: &str) -> Result<Option<MessageRecord>, Error> {
+        Ok(self.fetch_data("SELECT data FROM messages WHERE message_id = $1", message_id).await?.pop())
+    }
+
+    async fn query(&self, query: &MessageQuery) -> Result<Vec<MessageRecord>, Error> {
+        let mut sql: QueryBuilder<Postgres> = QueryBuilder::new("SELECT data FROM messages WHERE TRUE");
+        if let Some(status) = &query.status {
+            sql.push(" AND status = ").push_bind(status_value(status)?);
+        }
+        if let Some(chain) = &query.source_chain {
+            sql.push(" AND source_chain = ").push_bind(chain);
+        }
+        if let Some(chain) = &query.target_chain {
+            sql.push(" AND target_chain = ").push_bind(chain);
+        }
+        if let Some(chain) = &query.chain {
+            sql.push(" AND (source_chain = ").push_bind(chain).push(" OR target_chain = ").push_bind(chain).push(")");
+        }
+        if let Some(sender) = &query.sender {
+            sql.push(" AND sender = ").push_bind(sender);
+        }
+        if let Some(recipient) = &query.recipient {
+            sql.push(" AND data::jsonb ->> 'recipient' = ").push_bind(recipient);
+        }
+        if let Some(since) = query.since {
+            sql.push(" AND observed_at >= ").push_bind(since as i64);
+        }
+        if let Some(until) = query.until {
+            sql.push(" AND observed_at < ").push_bind(until as i64);
+        }
+        sql.push(" ORDER BY observed_at");
+        if let Some(limit) = query.limit {
+            sql.push(" LIMIT ").push_bind(limit as i64);
+        }
+
+        let rows = sql.build().fetch_all(&self.pool).await.map_err(db_error)?;
+        rows.iter().map(|row| decode(row.get("data"))).collect()
+    }
+
+    async fn remove(&self, message_ids: &[String]) -> Result<usize, Error> {
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        let mut removed = 0;
+        for message_id in message_ids {
+            for statement in REMOVE_HISTORY {
+                sqlx::query(statement).bind(message_id).execute(&mut *tx).await.map_err(db_error)?;
+            }
+            removed += sqlx::query("DELETE FROM messages WHERE message_id = $1")
+                .bind(message_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_error)?
+                .rows_affected() as usize;
+        }
+        tx.commit().await.map_err(db_error)?;
+        Ok(removed)
+    }
+
+    async fn append_ledger(&self, entries: &[LedgerEntry]) -> Result<(), Error> {
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        for entry in entries {
+            sqlx::query(
+                "INSERT INTO ledger (entry_id, asset, source_chain, target_chain, timestamp, data)
+                 VALUES ($1, $2, $3, $4, $5, $6)
+                 ON CONFLICT (entry_id) DO UPDATE SET timestamp = EXCLUDED.timestamp, data = EXCLUDED.data",
+            )
+            .bind(&entry.entry_id)
+            .bind(&entry.asset)
+            .bind(&entry.source_chain)
+            .bind(&entry.target_chain)
+            .bind(entry.timestamp as i64)
+            .bind(encode(entry)?)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        }
+        tx.commit().await.map_err(db_error)
+    }
+
+    async fn query_ledger(&self, query: &LedgerQuery) -> Result<Vec<LedgerEntry>, Error> {
+        let mut sql: QueryBuilder<Postgres> = QueryBuilder::new("SELECT data FROM ledger WHERE TRUE");
+        if let Some(asset) = &query.asset {
+            sql.push(" AND asset = ").push_bind(asset);
+        }
+        if let Some(chain) = &query.source_chain {
+            sql.push(" AND source_chain = ").push_bind(chain);
+        }
+        if let Some(chain) = &query.target_chain {
+            sql.push(" AND target_chain = ").push_bind(chain);
+        }
+        if let Some(since) = query.since {
+            sql.push(" AND timestamp >= ").push_bind(since as i64);
+        }
+        if let Some(until) = query.until {
+            sql.push(" AND timestamp < ").push_bind(until as i64);
+        }
+        sql.push(" ORDER BY timestamp, entry_id");
+
+        let rows = sql.build().fetch_all(&self.pool).await.map_err(db_error)?;
+        rows.iter().map(|row| decode(row.get("data"))).collect()
+    }
+
+    async fn acquire_lease(&self, name: &str, holder: &str, ttl: u64, now: u64) -> Result<bool, Error> {
+        let mut locks = self.locks.lock().await;
+
+        // 已持有时确认专用连接仍然存活，连接断开意味着锁已被数据库释放
+        if let Some(conn) = locks.get_mut(name) {
+            if sqlx::query("SELECT 1").execute(&mut **conn).await.is_ok() {
+                self.record_holder(name, holder, now + ttl).await;
+                return Ok(true);
+            }
+            warn!("Lost connection holding lease {}", name);
+            locks.remove(name);
+        }
+
+        let mut conn = self.pool.acquire().await.map_err(db_error)?;
+        let acquired: bool = sqlx::query_scalar("SELECT pg_try_advisory_lock(hashtext($1))")
+            .bind(name)
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(db_error)?;
+        if acquired {
+            // 锁随连接保留，连接不再归还连接池
+            locks.insert(name.to_string(), conn);
+            self.record_holder(name, holder, now + ttl).await;
+        }
+        Ok(acquired)
+    }
+
+    async fn release_lease(&self, name: &str, holder: &str) -> Result<(), Error> {
+        if let Some(mut conn) = self.locks.lock().await.remove(name) {
+            sqlx::query("SELECT pg_advisory_unlock(hashtext($1))")
+                .bind(name)
+                .execute(&mut *conn)
+                .await
+                .map_err(db_error)?;
+        }
+        sqlx::query("DELETE FROM leases WHERE name = $1 AND holder = $2")
+            .bind(name)
+            .bind(holder)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    // 移交请求只写入 `leases` 表，由当前持有者续期时读取后主动释放咨询锁
+    async fn request_handoff(&self, name: &str, holder: &str, expires_at: u64) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO leases (name, holder, expires_at) VALUES ($1, $2, $3)
+             ON CONFLICT (name) DO UPDATE SET holder = EXCLUDED.holder, expires_at = EXCLUDED.expires_at",
+        )
+        .bind(handoff_key(name))
+        .bind(holder)
+        .bind(expires_at as i64)
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn handoff(&self, name: &str, now: u64) -> Result<Option<String>, Error> {
+        sqlx::query_scalar("SELECT holder FROM leases WHERE name = $1 AND expires_at > $2")
+            .bind(handoff_key(name))
+            .bind(now as i64)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(db_error)
+    }
+
+    async fn clear_handoff(&self, name: &str, holder: &str) -> Result<(), Error> {
+        sqlx::query("DELETE FROM leases WHERE name = $1 AND holder = $2")
+            .bind(handoff_key(name))
+            .bind(holder)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn register_schema(&self, schema: &SchemaDescriptor) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO schemas (message_type, version, data) VALUES ($1, $2, $3)
+             ON CONFLICT (message_type, version) DO UPDATE SET data = EXCLUDED.data",
+        )
+        .bind(&schema.message_type)
+        .bind(schema.version as i64)
+        .bind(encode(schema)?)
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn schemas(&self, message_type: Option<&str>) -> Result<Vec<SchemaDescriptor>, Error> {
+        let rows = sqlx::query(
+            "SELECT data FROM schemas WHERE $1::TEXT IS NULL OR message_type = $1 ORDER BY message_type, version",
+        )
+        .bind(message_type)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+        rows.iter().map(|row| decode(row.get("data"))).collect()
+    }
+
+    async fn hold(&self, held: &HeldMessage) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO held (message_id, held_at, data) VALUES ($1, $2, $3)
+             ON CONFLICT (message_id) DO UPDATE SET held_at = EXCLUDED.held_at, data = EXCLUDED.data",
+        )
+        .bind(&held.message_id)
+        .bind(held.held_at as i64)
+        .bind(encode(held)?)
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn held(&self) -> Result<Vec<HeldMessage>, Error> {
+        let rows = sqlx::query("SELECT data FROM held ORDER BY held_at, message_id")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_error)?;
+        rows.iter().map(|row| decode(row.get("data"))).collect()
+    }
+
+    async fn remove_held(&self, message_id: &str) -> Result<bool, Error> {
+        let result = sqlx::query("DELETE FROM held WHERE message_id = $1")
+            .bind(message_id)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn cursors(&self, chain: &str) -> Result<HashMap<String, u64>, Error> {
+        Ok(self.fetch_data("SELECT data FROM cursors WHERE chain = $1", chain).await?.pop().unwrap_or_default())
+    }
+
+    async fn save_cursors(&self, chain: &str, cursors: &HashMap<String, u64>) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO cursors (chain, data) VALUES ($1, $2)
+             ON CONFLICT (chain) DO UPDATE SET data = EXCLUDED.data",
+        )
+        .bind(chain)
+        .bind(encode(cursors)?)
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn record_source(&self, source: &SourceRecord) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO sources (message_id, data) VALUES ($1, $2)
+             ON CONFLICT (message_id) DO UPDATE SET data = EXCLUDED.data",
+        )
+        .bind(&source.message_id)
+        .bind(encode(source)?)
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn source(&self, message_id: &str) -> Result<Option<SourceRecord>, Error> {
+        Ok(self.fetch_data("SELECT data FROM sources WHERE message_id = $1", message_id).await?.pop())
+    }
+
+    async fn record_attestation(&self, attestation: &Attestation) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO attestations (message_id, validator, received_at, data) VALUES ($1, $2, $3, $4)
+             ON CONFLICT (message_id, validator) DO UPDATE SET received_at = EXCLUDED.received_at, data = EXCLUDED.data",
+        )
+        .bind(&attestation.message_id)
+        .bind(&attestation.validator)
+        .bind(attestation.timestamp as i64)
+        .bind(encode(attestation)?)
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn record_submission(&self, submission: &SubmissionRecord) -> Result<(), Error> {
+        sqlx::query("INSERT INTO submissions (message_id, attempt, data) VALUES ($1, $2, $3)")
+            .bind(&submission.message_id)
+            .bind(submission.attempt as i64)
+            .bind(encode(submission)?)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn record_retry(&self, retry: &RetryRecord) -> Result<(), Error> {
+        sqlx::query("INSERT INTO retries (message_id, attempt, data) VALUES ($1, $2, $3)")
+            .bind(&retry.message_id)
+            .bind(retry.attempt as i64)
+            .bind(encode(retry)?)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn attestations(&self, message_id: &str) -> Result<Vec<Attestation>, Error> {
+        self.fetch_data("SELECT data FROM attestations WHERE message_id = $1 ORDER BY received_at", message_id)
+            .await
+    }
+
+    async fn submissions(&self, message_id: &str) -> Result<Vec<SubmissionRecord>, Error> {
+        self.fetch_data("SELECT data FROM submissions WHERE message_id = $1 ORDER BY attempt, id", message_id)
+            .await
+    }
+
+    async fn retries(&self, message_id: &str) -> Result<Vec<RetryRecord>, Error> {
+        self.fetch_data("SELECT data FROM retries WHERE message_id = $1 ORDER BY attempt, id", message_id)
+            .await
+    }
+
+    async fn record_equivocation(&self, evidence: &EquivocationEvidence) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO equivocations (evidence_id, detected_at, data) VALUES ($1, $2, $3)
+             ON CONFLICT (evidence_id) DO UPDATE SET detected_at = EXCLUDED.detected_at, data = EXCLUDED.data",
+        )
+        .bind(evidence.evidence_id())
+        .bind(evidence.detected_at as i64)
+        .bind(encode(evidence)?)
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn equivocations(&self) -> Result<Vec<EquivocationEvidence>, Error> {
+        let rows = sqlx::query("SELECT data FROM equivocations ORDER BY detected_at")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_error)?;
+        rows.iter().map(|row| decode(row.get("data"))).collect()
+    }
+}
+
+impl PostgresStore {
+    /// 记录租约持有者，仅用于展示，失败不影响租约本身
+    async fn record_holder(&self, name: &str, holder: &str, expires_at: u64) {
+        let result = sqlx::query(
+            "INSERT INTO leases (name, holder, expires_at) VALUES ($1, $2, $3)
+             ON CONFLICT (name) DO UPDATE SET holder = EXCLUDED.holder, expires_at = EXCLUDED.expires_at",
+        )
+        .bind(name)
+        .bind(holder)
+        .bind(expires_at as i64)
+        .execute(&self.pool)
+        .await;
+        if let Err(e) = result {
+            warn!("Failed to record holder of lease {}: {}", name, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrations_are_ordered() {
+        assert!(MIGRATIONS.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(MIGRATIONS.first().map(|m| m.0), Some(1));
+    }
+}
@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::{debug, info, warn};
+use super::{MessageQuery, MessageRecord, MessageStore};
+use crate::{config::RetentionConfig, types::MessageStatus, Error};
+
+/// 每次删除的消息数
+const REMOVE_BATCH: usize = 500;
+
+/// 默认参与清理的终态；失败的消息通常需要人工处理，默认保留
+pub const DEFAULT_STATUSES: &[MessageStatus] = &[MessageStatus::Processed, MessageStatus::Expired];
+
+/// 清理条件：指定终态中、进入终态早于 `before` 的消息
+#[derive(Debug, Clone, PartialEq)]
+pub struct PruneCriteria {
+    /// 时间上界（秒，不含），未记录终态时间的消息按观察时间计
+    pub before: u64,
+    pub statuses: Vec<MessageStatus>,
+}
+
+impl PruneCriteria {
+    /// 按保留策略计算当前时刻的清理条件
+    pub fn from_retention(retention: &RetentionConfig, now: u64) -> Self {
+        Self { before: now.saturating_sub(retention.max_age), statuses: retention.statuses.clone() }
+    }
+
+    pub fn matches(&self, record: &MessageRecord) -> bool {
+        record.status.is_terminal()
+            && self.statuses.contains(&record.status)
+            && record.completed_at.unwrap_or(record.observed_at) < self.before
+    }
+}
+
+/// 清理结果；试运行时为将要删除的消息
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PruneReport {
+    pub before: u64,
+    pub dry_run: bool,
+    pub messages: usize,
+    /// 按状态统计的消息数
+    pub by_status: BTreeMap<String, usize>,
+    /// 按 `源链 -> 目标链` 统计的消息数
+    pub by_route: BTreeMap<String, usize>,
+    /// 涉及消息中最早与最晚的终态时间
+    pub oldest: Option<u64>,
+    pub newest: Option<u64>,
+}
+
+impl PruneReport {
+    fn add(&mut self, record: &MessageRecord) {
+        let completed_at = record.completed_at.unwrap_or(record.observed_at);
+        self.messages += 1;
+        *self.by_status.entry(format!("{:?}", record.status)).or_default() += 1;
+        *self.by_route.entry(format!("{} -> {}", record.source_chain, record.target_chain)).or_default() += 1;
+        self.oldest = Some(self.oldest.map_or(completed_at, |t| t.min(completed_at)));
+        self.newest = Some(self.newest.map_or(completed_at, |t| t.max(completed_at)));
+    }
+}
+
+/// 删除满足条件的消息及其处理历史，`dry_run` 时只统计不删除
+///
+/// 记账分录与双签证据不受影响。
+pub async fn prune(store: &dyn MessageStore, criteria: &PruneCriteria, dry_run: bool) -> Result<PruneReport, Error> {
+    let mut report = PruneReport { before: criteria.before, dry_run, ..Default::default() };
+    let mut message_ids = Vec::new();
+    for (i, status) in criteria.statuses.iter().enumerate() {
+        if criteria.statuses[..i].contains(status) {
+            continue;
+        }
+        // 终态时间不早于观察时间，先按观察时间缩小范围
+        let query = MessageQuery { status: Some(status.clone()), until: Some(criteria.before), ..Default::default() };
+        for record in store.query(&query).await? {
+            if criteria.matches(&record) {
+                report.add(&record);
+                message_ids.push(record.message_id);
+            }
+        }
+    }
+
+    if !dry_run {
+        for batch in message_ids.chunks(REMOVE_BATCH) {
+            store.remove(batch).await?;
+        }
+    }
+    Ok(report)
+}
+
+/// 按保留策略定期清理消息存储
+pub async fn run(store: Arc<dyn MessageStore>, retention: RetentionConfig) {
+    let mut interval = tokio::time::interval(Duration::from_secs(retention.interval));
+    loop {
+        interval.tick().await;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+        let criteria = PruneCriteria::from_retention(&retention, now);
+        match prune(store.as_ref(), &criteria, false).await {
+            Ok(report) if report.messages > 0 => {
+                info!("Pruned {} messages completed before {}", report.messages, report.before);
+            }
+            Ok(_) => debug!("No messages to prune before {}", criteria.before),
+            Err(e) => warn!("Failed to prune message store: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::file::FileStore;
+
+    fn record(message_id: &str, status: MessageStatus, observed_at: u64, completed_at: Option<u64>) -> MessageRecord {
+        MessageRecord {
+            message_id: message_id.to_string(),
+            source_chain: "sui".to_string(),
+            target_chain: "rooch".to_string(),
+            asset: None,
+            sender: None,
+            recipient: None,
+            nonce: 1,
+            status,
+            observed_at,
+            completed_at,
+            error: None,
+            cause: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_prune_terminal_messages_before_cutoff() {
+        let path = std::env::temp_dir().join(format!("bridge-retention-test-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let store = FileStore::open(&path).unwrap();
+        for record in [
+            record("old", MessageStatus::Processed, 100, Some(200)),
+            record("recent", MessageStatus::Processed, 100, Some(900)),
+            record("failed", MessageStatus::Failed, 100, Some(200)),
+            record("expired", MessageStatus::Expired, 100, None),
+            record("pending", MessageStatus::Pending, 100, None),
+        ] {
+            store.upsert(&record).await.unwrap();
+        }
+        // 同一消息的多次更新在清理时压缩为一行
+        store.upsert(&record("recent", MessageStatus::Processed, 100, Some(1_000))).await.unwrap();
+
+        let criteria = PruneCriteria::from_retention(
+            &RetentionConfig { max_age: 500, statuses: vec![MessageStatus::Processed, MessageStatus::Expired], interval: 60 },
+            1_000,
+        );
+        let report = prune(&store, &criteria, true).await.unwrap();
+        assert_eq!((report.messages, report.oldest, report.newest), (2, Some(100), Some(200)));
+        assert_eq!(report.by_status, BTreeMap::from([("Expired".to_string(), 1), ("Processed".to_string(), 1)]));
+        assert!(store.get("old").await.unwrap().is_some());
+
+        let report = prune(&store, &criteria, false).await.unwrap();
+        assert_eq!(report.messages, 2);
+        let reopened = FileStore::open(&path).unwrap();
+        let mut remaining: Vec<_> = reopened.query(&MessageQuery::default()).await.unwrap().into_iter().map(|r| r.message_id).collect();
+        remaining.sort();
+        assert_eq!(remaining, ["failed", "pending", "recent"]);
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 3);
+
+        for extension in ["", ".lease", ".ledger", ".schemas"] {
+            let _ = std::fs::remove_file(format!("{}{}", path.display(), extension));
+        }
+    }
+}
@@ -0,0 +1,550 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use serde::{de::DeserializeOwned, Serialize};
+use sqlx::{
+    sqlite::{SqliteConnectOptions, SqlitePool, SqlitePoolOptions},
+    QueryBuilder, Row, Sqlite,
+};
+use super::{handoff_key, HeldMessage, MessageQuery, MessageRecord, MessageStore, RetryRecord, SourceRecord, SubmissionRecord};
+use crate::{
+    ledger::{LedgerEntry, LedgerQuery},
+    relayer::equivocation::EquivocationEvidence,
+    schema::SchemaDescriptor,
+    types::{Attestation, MessageStatus},
+    Error,
+};
+
+/// 建表语句
+///
+/// 可查询的字段单独成列并建索引，完整记录以 JSON 保存在 `data` 列，
+/// 记录结构增加字段时无需迁移。
+const SCHEMA: &[&str] = &[
+    "CREATE TABLE IF NOT EXISTS messages (
+        message_id TEXT PRIMARY KEY,
+        source_chain TEXT NOT NULL,
+        target_chain TEXT NOT NULL,
+        sender TEXT,
+        status TEXT NOT NULL,
+        observed_at INTEGER NOT NULL,
+        data TEXT NOT NULL
+    )",
+    "CREATE INDEX IF NOT EXISTS messages_route ON messages (source_chain, target_chain, observed_at)",
+    "CREATE INDEX IF NOT EXISTS messages_status ON messages (status, observed_at)",
+    "CREATE INDEX IF NOT EXISTS messages_sender ON messages (sender, observed_at)",
+    "CREATE TABLE IF NOT EXISTS sources (
+        message_id TEXT PRIMARY KEY,
+        data TEXT NOT NULL
+    )",
+    "CREATE TABLE IF NOT EXISTS attestations (
+        message_id TEXT NOT NULL,
+        validator TEXT NOT NULL,
+        received_at INTEGER NOT NULL,
+        data TEXT NOT NULL,
+        PRIMARY KEY (message_id, validator)
+    )",
+    "CREATE TABLE IF NOT EXISTS submissions (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        message_id TEXT NOT NULL,
+        attempt INTEGER NOT NULL,
+        data TEXT NOT NULL
+    )",
+    "CREATE INDEX IF NOT EXISTS submissions_message ON submissions (message_id, attempt)",
+    "CREATE TABLE IF NOT EXISTS retries (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        message_id TEXT NOT NULL,
+        attempt INTEGER NOT NULL,
+        data TEXT NOT NULL
+    )",
+    "CREATE INDEX IF NOT EXISTS retries_message ON retries (message_id, attempt)",
+    "CREATE TABLE IF NOT EXISTS ledger (
+        entry_id TEXT PRIMARY KEY,
+        asset TEXT NOT NULL,
+        source_chain TEXT NOT NULL,
+        target_chain TEXT NOT NULL,
+        timestamp INTEGER NOT NULL,
+        data TEXT NOT NULL
+    )",
+    "CREATE INDEX IF NOT EXISTS ledger_asset ON ledger (asset, timestamp)",
+    "CREATE TABLE IF NOT EXISTS leases (
+        name TEXT PRIMARY KEY,
+        holder TEXT NOT NULL,
+        expires_at INTEGER NOT NULL
+    )",
+    "CREATE TABLE IF NOT EXISTS schemas (
+        message_type TEXT NOT NULL,
+        version INTEGER NOT NULL,
+        data TEXT NOT NULL,
+        PRIMARY KEY (message_type, version)
+    )",
+    "CREATE TABLE IF NOT EXISTS equivocations (
+        evidence_id TEXT PRIMARY KEY,
+        detected_at INTEGER NOT NULL,
+        data TEXT NOT NULL
+    )",
+    "CREATE TABLE IF NOT EXISTS held (
+        message_id TEXT PRIMARY KEY,
+        held_at INTEGER NOT NULL,
+        data TEXT NOT NULL
+    )",
+    "CREATE TABLE IF NOT EXISTS cursors (
+        chain TEXT PRIMARY KEY,
+        data TEXT NOT NULL
+    )",
+];
+
+/// 删除消息时一并删除的处理历史
+const REMOVE_HISTORY: &[&str] = &[
+    "DELETE FROM sources WHERE message_id = ?",
+    "DELETE FROM attestations WHERE message_id = ?",
+    "DELETE FROM submissions WHERE message_id = ?",
+    "DELETE FROM retries WHERE message_id = ?",
+];
+
+/// 基于 SQLite 的消息存储，保存消息的完整处理历史
+///
+/// 同一数据库文件可被同一主机上的多个实例共享，租约通过条件更新保证互斥。
+pub struct SqliteStore {
+    pool: SqlitePool,
+}
+
+impl SqliteStore {
+    pub async fn open(path: &str) -> Result<Self, Error> {
+        let options = SqliteConnectOptions::new()
+            .filename(path)
+            .create_if_missing(true);
+        let pool = SqlitePoolOptions::new()
+            .max_connections(4)
+            .connect_with(options)
+            .await
+            .map_err(db_error)?;
+        for statement in SCHEMA {
+            sqlx::query(statement).execute(&pool).await.map_err(db_error)?;
+        }
+        Ok(Self { pool })
+    }
+
+    /// 按 `data` 列解码查询结果
+    async fn fetch_data<T: DeserializeOwned>(&self, sql: &str, message_id: &str) -> Result<Vec<T>, Error> {
+        let rows = sqlx::query(sql)
+            .bind(message_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_error)?;
+        rows.iter().map(|row| decode(row.get("data"))).collect()
+    }
+}
+
+fn db_error(e: sqlx::Error) -> Error {
+    Error::Config(format!("SQLite store error: {}", e))
+}
+
+fn encode<T: Serialize>(value: &T) -> Result<String, Error> {
+    serde_json::to_string(value).map_err(|e| Error::Serialization(e.to_string()))
+}
+
+fn decode<T: DeserializeOwned>(data: &str) -> Result<T, Error> {
+    serde_json::from_str(data).map_err(|e| Error::Serialization(format!("Corrupted store record: {}", e)))
+}
+
+/// 消息状态在 `status` 列中的取值，与 JSON 编码一致
+fn status_value(status: &MessageStatus) -> Result<String, Error> {
+    Ok(encode(status)?.trim_matches('"').to_string())
+}
+
+#[async_trait]
+impl MessageStore for SqliteStore {
+    async fn upsert(&self, record: &MessageRecord) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO messages (message_id, source_chain, target_chain, sender, status, observed_at, data)
+             VALUES (?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT (message_id) DO UPDATE SET
+                sender = excluded.sender, status = excluded.status,
+                observed_at = excluded.observed_at, data = excluded.data",
+        )
+        .bind(&record.message_id)
+        .bind(&record.source_chain)
+        .bind(&record.target_chain)
+        .bind(&record.sender)
+        .bind(status_value(&record.status)?)
+        .bind(record.observed_at as i64)
+        .bind(encode(record)?)
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn get(&self, message_id: &str) -> Result<Option<MessageRecord>, Error> {
+        let row = sqlx::query("SELECT data FROM messages WHERE message_id = ?")
+            .bind(message_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(db_error)?;
+        row.map(|row| decode(row.get("data"))).transpose()
+    }
+
+    async fn query(&self, query: &MessageQuery) -> Result<Vec<MessageRecord>, Error> {
+        let mut sql: QueryBuilder<Sqlite> = QueryBuilder::new("SELECT data FROM messages WHERE 1 = 1");
+        if let Some(status) = &query.status {
+            sql.push(" AND status = ").push_bind(status_value(status)?);
+        }
+        if let Some(chain) = &query.source_chain {
+            sql.push(" AND source_chain = ").push_bind(chain);
+        }
+        if let Some(chain) = &query.target_chain {
+            sql.push(" AND target_chain = ").push_bind(chain);
+        }
+        if let Some(chain) = &query.chain {
+            sql.push(" AND (source_chain = ").push_bind(chain).push(" OR target_chain = ").push_bind(chain).push(")");
+        }
+        if let Some(sender) = &query.sender {
+            sql.push(" AND sender = ").push_bind(sender);
+        }
+        if let Some(recipient) = &query.recipient {
+            sql.push(" AND json_extract(data, '$.recipient') = ").push_bind(recipient);
+        }
+        if let Some(since) = query.since {
+            sql.push(" AND observed_at >= ").push_bind(since as i64);
+        }
+        if let Some(until) = query.until {
+            sql.push(" AND observed_at < ").push_bind(until as i64);
+        }
+        sql.push(" ORDER BY observed_at");
+        if let Some(limit) = query.limit {
+            sql.push(" LIMIT ").push_bind(limit as i64);
+        }
+
+        let rows = sql.build().fetch_all(&self.pool).await.map_err(db_error)?;
+        rows.iter().map(|row| decode(row.get("data"))).collect()
+    }
+
+    async fn remove(&self, message_ids: &[String]) -> Result<usize, Error> {
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        let mut removed = 0;
+        for message_id in message_ids {
+            for statement in REMOVE_HISTORY {
+                sqlx::query(statement).bind(message_id).execute(&mut *tx).await.map_err(db_error)?;
+            }
+            removed += sqlx::query("DELETE FROM messages WHERE message_id = ?")
+                .bind(message_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_error)?
+                .rows_affected() as usize;
+        }
+        tx.commit().await.map_err(db_error)?;
+        Ok(removed)
+    }
+
+    async fn append_ledger(&self, entries: &[LedgerEntry]) -> Result<(), Error> {
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        for entry in entries {
+            sqlx::query(
+                "INSERT OR REPLACE INTO ledger (entry_id, asset, source_chain, target_chain, timestamp, data)
+                 VALUES (?, ?, ?, ?, ?, ?)",
+            )
+            .bind(&entry.entry_id)
+            .bind(&entry.asset)
+            .bind(&entry.source_chain)
+            .bind(&entry.target_chain)
+            .bind(entry.timestamp as i64)
+            .bind(encode(entry)?)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        }
+        tx.commit().await.map_err(db_error)
+    }
+
+    async fn query_ledger(&self, query: &LedgerQuery) -> Result<Vec<LedgerEntry>, Error> {
+        let mut sql: QueryBuilder<Sqlite> = QueryBuilder::new("SELECT data FROM ledger WHERE 1 = 1");
+        if let Some(asset) = &query.asset {
+            sql.push(" AND asset = ").push_bind(asset);
+        }
+        if let Some(chain) = &query.source_chain {
+            sql.push(" AND source_chain = ").push_bind(chain);
+        }
+        if let Some(chain) = &query.target_chain {
+            sql.push(" AND target_chain = ").push_bind(chain);
+        }
+        if let Some(since) = query.since {
+            sql.push(" AND timestamp >= ").push_bind(since as i64);
+        }
+        if let Some(until) = query.until {
+            sql.push(" AND timestamp < ").push_bind(until as i64);
+        }
+        sql.push(" ORDER BY timestamp, entry_id");
+
+        let rows = sql.build().fetch_all(&self.pool).await.map_err(db_error)?;
+        rows.iter().map(|row| decode(row.get("data"))).collect()
+    }
+
+    async fn acquire_lease(&self, name: &str, holder: &str, ttl: u64, now: u64) -> Result<bool, Error> {
+        // 只有租约空闲、已过期或由自己持有时更新生效
+        let result = sqlx::query(
+            "INSERT INTO leases (name, holder, expires_at) VALUES (?, ?, ?)
+             ON CONFLICT (name) DO UPDATE SET holder = excluded.holder, expires_at = excluded.expires_at
+             WHERE leases.holder = excluded.holder OR leases.expires_at <= ?",
+        )
+        .bind(name)
+        .bind(holder)
+        .bind((now + ttl) as i64)
+        .bind(now as i64)
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn release_lease(&self, name: &str, holder: &str) -> Result<(), Error> {
+        sqlx::query("DELETE FROM leases WHERE name = ? AND holder = ?")
+            .bind(name)
+            .bind(holder)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn request_handoff(&self, name: &str, holder: &str, expires_at: u64) -> Result<(), Error> {
+        sqlx::query("INSERT OR REPLACE INTO leases (name, holder, expires_at) VALUES (?, ?, ?)")
+            .bind(handoff_key(name))
+            .bind(holder)
+            .bind(expires_at as i64)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn handoff(&self, name: &str, now: u64) -> Result<Option<String>, Error> {
+        let row = sqlx::query("SELECT holder FROM leases WHERE name = ? AND expires_at > ?")
+            .bind(handoff_key(name))
+            .bind(now as i64)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(row.map(|row| row.get("holder")))
+    }
+
+    async fn clear_handoff(&self, name: &str, holder: &str) -> Result<(), Error> {
+        self.release_lease(&handoff_key(name), holder).await
+    }
+
+    async fn register_schema(&self, schema: &SchemaDescriptor) -> Result<(), Error> {
+        sqlx::query("INSERT OR REPLACE INTO schemas (message_type, version, data) VALUES (?, ?, ?)")
+            .bind(&schema.message_type)
+            .bind(schema.version as i64)
+            .bind(encode(schema)?)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn schemas(&self, message_type: Option<&str>) -> Result<Vec<SchemaDescriptor>, Error> {
+        let rows = sqlx::query("SELECT data FROM schemas WHERE ?1 IS NULL OR message_type = ?1 ORDER BY message_type, version")
+            .bind(message_type)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_error)?;
+        rows.iter().map(|row| decode(row.get("data"))).collect()
+    }
+
+    async fn hold(&self, held: &HeldMessage) -> Result<(), Error> {
+        sqlx::query("INSERT OR REPLACE INTO held (message_id, held_at, data) VALUES (?, ?, ?)")
+            .bind(&held.message_id)
+            .bind(held.held_at as i64)
+            .bind(encode(held)?)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn held(&self) -> Result<Vec<HeldMessage>, Error> {
+        let rows = sqlx::query("SELECT data FROM held ORDER BY held_at, message_id")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_error)?;
+        rows.iter().map(|row| decode(row.get("data"))).collect()
+    }
+
+    async fn remove_held(&self, message_id: &str) -> Result<bool, Error> {
+        let result = sqlx::query("DELETE FROM held WHERE message_id = ?")
+            .bind(message_id)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn cursors(&self, chain: &str) -> Result<HashMap<String, u64>, Error> {
+        Ok(self.fetch_data("SELECT data FROM cursors WHERE chain = ?", chain).await?.pop().unwrap_or_default())
+    }
+
+    async fn save_cursors(&self, chain: &str, cursors: &HashMap<String, u64>) -> Result<(), Error> {
+        sqlx::query("INSERT OR REPLACE INTO cursors (chain, data) VALUES (?, ?)")
+            .bind(chain)
+            .bind(encode(cursors)?)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn record_source(&self, source: &SourceRecord) -> Result<(), Error> {
+        sqlx::query("INSERT OR REPLACE INTO sources (message_id, data) VALUES (?, ?)")
+            .bind(&source.message_id)
+            .bind(encode(source)?)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn source(&self, message_id: &str) -> Result<Option<SourceRecord>, Error> {
+        Ok(self.fetch_data("SELECT data FROM sources WHERE message_id = ?", message_id).await?.pop())
+    }
+
+    async fn record_attestation(&self, attestation: &Attestation) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT OR REPLACE INTO attestations (message_id, validator, received_at, data) VALUES (?, ?, ?, ?)",
+        )
+        .bind(&attestation.message_id)
+        .bind(&attestation.validator)
+        .bind(attestation.timestamp as i64)
+        .bind(encode(attestation)?)
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn record_submission(&self, submission: &SubmissionRecord) -> Result<(), Error> {
+        sqlx::query("INSERT INTO submissions (message_id, attempt, data) VALUES (?, ?, ?)")
+            .bind(&submission.message_id)
+            .bind(submission.attempt as i64)
+            .bind(encode(submission)?)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn record_retry(&self, retry: &RetryRecord) -> Result<(), Error> {
+        sqlx::query("INSERT INTO retries (message_id, attempt, data) VALUES (?, ?, ?)")
+            .bind(&retry.message_id)
+            .bind(retry.attempt as i64)
+            .bind(encode(retry)?)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn attestations(&self, message_id: &str) -> Result<Vec<Attestation>, Error> {
+        self.fetch_data("SELECT data FROM attestations WHERE message_id = ? ORDER BY received_at", message_id)
+            .await
+    }
+
+    async fn submissions(&self, message_id: &str) -> Result<Vec<SubmissionRecord>, Error> {
+        self.fetch_data("SELECT data FROM submissions WHERE message_id = ? ORDER BY attempt, id", message_id)
+            .await
+    }
+
+    async fn retries(&self, message_id: &str) -> Result<Vec<RetryRecord>, Error> {
+        self.fetch_data("SELECT data FROM retries WHERE message_id = ? ORDER BY attempt, id", message_id)
+            .await
+    }
+
+    async fn record_equivocation(&self, evidence: &EquivocationEvidence) -> Result<(), Error> {
+        sqlx::query("INSERT OR REPLACE INTO equivocations (evidence_id, detected_at, data) VALUES (?, ?, ?)")
+            .bind(evidence.evidence_id())
+            .bind(evidence.detected_at as i64)
+            .bind(encode(evidence)?)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn equivocations(&self) -> Result<Vec<EquivocationEvidence>, Error> {
+        let rows = sqlx::query("SELECT data FROM equivocations ORDER BY detected_at")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_error)?;
+        rows.iter().map(|row| decode(row.get("data"))).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(message_id: &str, sender: &str, status: MessageStatus, observed_at: u64) -> MessageRecord {
+        MessageRecord {
+            message_id: message_id.to_string(),
+            source_chain: "sui_testnet".to_string(),
+            target_chain: "rooch_testnet".to_string(),
+            asset: None,
+            sender: Some(sender.to_string()),
+            recipient: None,
+            nonce: observed_at,
+            status,
+            observed_at,
+            completed_at: None,
+            error: None,
+            cause: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_history_queries() {
+        let path = std::env::temp_dir().join(format!("bridge-sqlite-test-{}.db", std::process::id()));
+        let store = SqliteStore::open(path.to_str().unwrap()).await.unwrap();
+
+        store.upsert(&record("m1", "0xa", MessageStatus::Pending, 10)).await.unwrap();
+        store.upsert(&record("m1", "0xa", MessageStatus::Processed, 10)).await.unwrap();
+        store.upsert(&record("m2", "0xb", MessageStatus::Failed, 20)).await.unwrap();
+
+        let processed = store.query(&MessageQuery {
+            status: Some(MessageStatus::Processed),
+            ..Default::default()
+        }).await.unwrap();
+        assert_eq!(processed.len(), 1);
+        let by_sender = store.query(&MessageQuery {
+            sender: Some("0xb".to_string()),
+            since: Some(15),
+            ..Default::default()
+        }).await.unwrap();
+        assert_eq!(by_sender[0].message_id, "m2");
+        let by_chain = store.query(&MessageQuery {
+            chain: Some("rooch_testnet".to_string()),
+            ..Default::default()
+        }).await.unwrap();
+        assert_eq!(by_chain.len(), 2);
+
+        for attempt in [1, 2] {
+            store.record_submission(&SubmissionRecord {
+                message_id: "m2".to_string(),
+                chain: "rooch_testnet".to_string(),
+                attempt,
+                tx_hash: None,
+                error: Some("out of gas".to_string()),
+                submitted_at: 20 + attempt as u64,
+            }).await.unwrap();
+        }
+        assert_eq!(store.submissions("m2").await.unwrap().len(), 2);
+        // 删除消息时一并删除其处理历史
+        assert_eq!(store.remove(&["m2".to_string(), "m3".to_string()]).await.unwrap(), 1);
+        assert!(store.get("m2").await.unwrap().is_none());
+        assert!(store.submissions("m2").await.unwrap().is_empty());
+
+        assert!(store.acquire_lease("relayer", "a", 30, 100).await.unwrap());
+        assert!(!store.acquire_lease("relayer", "b", 30, 110).await.unwrap());
+        assert!(store.acquire_lease("relayer", "b", 30, 131).await.unwrap());
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,468 @@
+use std::collections::HashMap;
+use crate::{
+    chain_adapter::mock::{MockChain, MockChainAdapterFactory, MOCK_ADAPTER_TYPE},
+    config::{AssetConfig, ChainConfig, Config, EventFilter, RelayerConfig},
+    relayer::{handler::MESSAGE_EVENT_HANDLER, RelayState, Relayer},
+    types::ChainRegistry,
+    Error,
+};
+
+/// 源链 ID
+pub const SOURCE_CHAIN: &str = "mock_source";
+/// 目标链 ID
+pub const TARGET_CHAIN: &str = "mock_target";
+/// 源链在合约中的数字链 ID
+pub const SOURCE_CHAIN_NUMERIC: u64 = 1;
+/// 目标链在合约中的数字链 ID
+pub const TARGET_CHAIN_NUMERIC: u64 = 2;
+/// 源链原生、映射到目标链的测试资产
+pub const TEST_ASSET: &str = "USDC";
+
+/// 模拟链配置，所有事件交给原样转发的消息处理器
+pub fn mock_chain_config(id: &str) -> ChainConfig {
+    ChainConfig {
+        id: id.into(),
+        adapter_type: MOCK_ADAPTER_TYPE.to_string(),
+        name: id.to_string(),
+        rpc_url: format!("mock://{}", id),
+        bridge_address: "0x0".to_string(),
+        address_format: None,
+        event_filters: vec![EventFilter {
+            name: "MessageEvent".to_string(),
+            handler: MESSAGE_EVENT_HANDLER.to_string(),
+        }],
+        validator_registry: None,
+        processed_nonces: None,
+        executed_messages: None,
+        min_node_version: None,
+        chain_id: None,
+        chain_namespace: None,
+        sender_keystore: None,
+        submission: None,
+        payload_public_key: None,
+        batch: None,
+        ordering: None,
+        relayer: None,
+        rate_limit: None,
+        http: None,
+        rpc_headers: HashMap::new(),
+        auth: None,
+        plugin: None,
+        solana: None,
+        movement: None,
+        cosmos: None,
+        json_rpc: None,
+        sui: None,
+        rooch: None,
+        light_client: None,
+        reimbursement: None,
+    }
+}
+
+/// 两条模拟链的中继配置：不等待重试、不要求证明，`TEST_ASSET` 可从源链转到目标链
+pub fn two_chain_config() -> Config {
+    Config {
+        chains: vec![
+            ChainConfig { chain_id: Some(SOURCE_CHAIN_NUMERIC), ..mock_chain_config(SOURCE_CHAIN) },
+            ChainConfig { chain_id: Some(TARGET_CHAIN_NUMERIC), ..mock_chain_config(TARGET_CHAIN) },
+        ],
+        assets: vec![AssetConfig {
+            name: TEST_ASSET.to_string(),
+            symbol: None,
+            native_chain: SOURCE_CHAIN.to_string(),
+            type_: "0x2::usdc::USDC".to_string(),
+            decimals: 6,
+            mappings: HashMap::from([(TARGET_CHAIN.to_string(), "0x3::usdc::USDC".to_string())]),
+            fee_rate: 0,
+            mapped_decimals: HashMap::new(),
+            relayer_fee: None,
+            timelock: None,
+            paused: false,
+        }],
+        nft_collections: vec![],
+        validators: vec![],
+        relayer: RelayerConfig {
+            poll_interval: 0,
+            max_retries: 3,
+            retry_delay: 0,
+            keystore: None,
+            signer: None,
+            require_attestations: false,
+            attestation_threshold: None,
+            governance_threshold: None,
+            validator_set_epoch: 0,
+            coordination: None,
+            check_recipients: false,
+            validator_set_guard: None,
+            max_message_age: 3_600,
+            queue_capacity: 1_000,
+            confirmations: 0,
+            batch_size: 16,
+            priority: None,
+            script_timeout: 30,
+        },
+        storage: None,
+        api: None,
+        schemas: None,
+        audit: None,
+        screening: None,
+        health: None,
+        p2p: None,
+        watchtower: None,
+        slashing: None,
+        indexer: None,
+        alerts: None,
+        logging: None,
+        reconciliation: None,
+    }
+}
+
+/// `two_chain_config` 的链登记表，测试中用来计算消息 ID
+pub fn test_chains() -> ChainRegistry {
+    two_chain_config().chain_registry().expect("two_chain_config registers both chains")
+}
+
+/// 转账负载：32 字节接收者地址后接小端序金额
+pub fn transfer_payload(recipient: [u8; 32], amount: u64) -> Vec<u8> {
+    let mut payload = recipient.to_vec();
+    payload.extend_from_slice(&amount.to_le_bytes());
+    payload
+}
+
+/// 两条模拟链与连接它们的中继器，由测试逐轮驱动中继循环
+pub struct TwoChainHarness {
+    pub source: MockChain,
+    pub target: MockChain,
+    pub relayer: Relayer,
+    state: RelayState,
+}
+
+impl TwoChainHarness {
+    pub async fn new() -> Result<Self, Error> {
+        Self::with_config(two_chain_config()).await
+    }
+
+    /// 使用自定义配置创建，配置中须包含 `SOURCE_CHAIN` 与 `TARGET_CHAIN` 两条链
+    pub async fn with_config(config: Config) -> Result<Self, Error> {
+        let source = MockChain::new(SOURCE_CHAIN);
+        let target = MockChain::new(TARGET_CHAIN);
+        let factory = MockChainAdapterFactory::new([source.clone(), target.clone()]);
+        let relayer = Relayer::with_factory(config, &factory).await?;
+        let state = relayer.relay_state();
+        Ok(Self { source, target, relayer, state })
+    }
+
+    /// 执行一轮中继
+    pub async fn tick(&mut self) {
+        self.relayer.tick(&mut self.state).await;
+    }
+
+    /// 连续执行多轮中继
+    pub async fn run(&mut self, ticks: usize) {
+        for _ in 0..ticks {
+            self.tick().await;
+        }
+    }
+
+    /// 尚未处理完毕的消息数
+    pub fn backlog(&self) -> usize {
+        self.state.backlog()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain_adapter::mock::MockOperation;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_relays_between_mock_chains() {
+        let mut harness = TwoChainHarness::new().await.unwrap();
+        let sent = harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([1u8; 32], 500));
+        harness.tick().await;
+        assert_eq!(harness.target.submitted()[0].message, sent.message);
+        assert_eq!(harness.backlog(), 0);
+
+        // 提交失败时在同一轮内重试
+        harness.target.fail_next(MockOperation::Submit, Error::Network("connection reset".to_string()));
+        harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([2u8; 32], 700));
+        harness.tick().await;
+        assert_eq!(harness.target.submitted().len(), 2);
+
+        // 读取事件失败时消息留在链上，下一轮再读取
+        harness.source.set_latency(Duration::from_millis(5));
+        harness.source.fail_next(MockOperation::ListenEvents, Error::Network("timeout".to_string()));
+        harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([3u8; 32], 900));
+        harness.tick().await;
+        assert_eq!(harness.source.pending_events(), 1);
+        let report = harness.relayer.health().report(None).await;
+        assert!(!report.chains[SOURCE_CHAIN].connected);
+        harness.tick().await;
+        assert_eq!(harness.target.submitted().len(), 3);
+
+        // 合约判定不合法的消息记为失败，之后各轮不再提交
+        harness.target.fail_next(MockOperation::Submit, Error::InvalidMessage("message::EINVALID_PAYLOAD".to_string()));
+        harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([5u8; 32], 300));
+        harness.run(2).await;
+        assert_eq!(harness.target.submitted().len(), 3);
+        assert_eq!(harness.backlog(), 0);
+
+        // 用尽重试次数的消息同样记为失败，不再在后续各轮重新提交
+        for _ in 0..3 {
+            harness.target.fail_next(MockOperation::Submit, Error::Network("connection reset".to_string()));
+        }
+        harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([6u8; 32], 300));
+        harness.run(2).await;
+        assert_eq!(harness.target.submitted().len(), 3);
+        assert_eq!(harness.backlog(), 0);
+        let metrics = harness.relayer.metrics().export().unwrap();
+        assert!(metrics.contains("bridge_messages_failed_total"));
+
+        // 未配置资产映射的方向不会提交
+        harness.target.send(SOURCE_CHAIN, "transfer", transfer_payload([4u8; 32], 100));
+        harness.run(2).await;
+        assert!(harness.source.submitted().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_skips_messages_processed_by_another_relayer() {
+        let mut harness = TwoChainHarness::new().await.unwrap();
+
+        // 另一个中继器已提交，提交前查询到后不再提交
+        let sent = harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([1u8; 32], 500));
+        harness.target.execute_external(sent);
+        harness.tick().await;
+        assert_eq!(harness.target.submitted().len(), 1);
+        assert_eq!(harness.backlog(), 0);
+
+        // 查询之后才被抢先提交，合约拒绝重复执行也视为已送达
+        harness.target.fail_next(MockOperation::Submit, Error::AlreadyProcessed("message::EALREADY_PROCESSED".to_string()));
+        harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([2u8; 32], 700));
+        harness.tick().await;
+        assert_eq!(harness.target.submitted().len(), 1);
+        assert_eq!(harness.backlog(), 0);
+
+        let metrics = harness.relayer.metrics().export().unwrap();
+        assert!(metrics.contains("bridge_submissions_skipped_total{chain=\"mock_target\"} 2"));
+        assert!(!metrics.contains("bridge_messages_failed_total"));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_recipient_in_wrong_address_format() {
+        let mut config = two_chain_config();
+        config.chains[1].address_format = Some(crate::chain_adapter::address::AddressFormat::Evm);
+        let mut harness = TwoChainHarness::with_config(config).await.unwrap();
+
+        // 32 字节的接收者不是 EVM 地址，拒绝后不再重试
+        harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([1u8; 32], 500));
+        harness.run(2).await;
+        assert!(harness.target.submitted().is_empty());
+        assert_eq!(harness.backlog(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_validator_rotation_syncs_contracts() {
+        use crate::types::{GovernanceAction, ValidatorGovernance, VALIDATOR_GOVERNANCE_MESSAGE_TYPE};
+
+        let mut harness = TwoChainHarness::new().await.unwrap();
+        let governance = ValidatorGovernance {
+            activation_height: 10,
+            action: GovernanceAction::ValidatorAdded {
+                address: "0xnew".to_string(),
+                public_key: vec![7; 32],
+                weight: 1,
+                chains: vec![SOURCE_CHAIN.to_string()],
+            },
+        };
+        harness.source.set_height(Some(5));
+        harness.source.send(TARGET_CHAIN, VALIDATOR_GOVERNANCE_MESSAGE_TYPE, governance.encode().unwrap());
+        harness.tick().await;
+        // 治理消息不向目标链中继，未到生效高度时不推送
+        assert!(harness.target.submitted().is_empty());
+        assert!(harness.target.validator_sets().is_empty());
+
+        harness.source.set_height(Some(10));
+        harness.tick().await;
+        for chain in [&harness.source, &harness.target] {
+            let sets = chain.validator_sets();
+            assert_eq!(sets.len(), 1);
+            assert_eq!(sets[0].validators[0].address, "0xnew");
+        }
+
+        // 推送失败的链下一轮重试
+        harness.target.fail_next(MockOperation::UpdateValidatorSet, Error::Network("timeout".to_string()));
+        let threshold = ValidatorGovernance {
+            activation_height: 10,
+            action: GovernanceAction::ThresholdChanged { threshold: 1 },
+        };
+        harness.source.send(TARGET_CHAIN, VALIDATOR_GOVERNANCE_MESSAGE_TYPE, threshold.encode().unwrap());
+        harness.tick().await;
+        assert_eq!(harness.source.validator_sets().len(), 2);
+        assert_eq!(harness.target.validator_sets().len(), 1);
+        harness.tick().await;
+        assert_eq!(harness.target.validator_sets()[1].threshold, Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_metadata_sync_registers_asset() {
+        use crate::types::{MetadataSync, METADATA_SYNC_MESSAGE_TYPE};
+
+        let mut harness = TwoChainHarness::new().await.unwrap();
+        let config = two_chain_config();
+        let metadata = MetadataSync::from_asset(&config.assets[0], TARGET_CHAIN).unwrap();
+        assert_eq!(metadata.symbol, TEST_ASSET);
+        assert_eq!(metadata.target_type, "0x3::usdc::USDC");
+
+        // 登记失败时下一轮重试
+        harness.target.fail_next(MockOperation::RegisterAsset, Error::Network("timeout".to_string()));
+        harness.source.send(TARGET_CHAIN, METADATA_SYNC_MESSAGE_TYPE, metadata.encode().unwrap());
+        harness.tick().await;
+        assert!(harness.target.assets().is_empty());
+        harness.tick().await;
+        assert_eq!(harness.target.assets(), vec![metadata.clone()]);
+        assert!(harness.target.submitted().is_empty());
+
+        // 与配置不一致的元数据不登记
+        let forged = MetadataSync { decimals: 18, ..metadata };
+        harness.source.send(TARGET_CHAIN, METADATA_SYNC_MESSAGE_TYPE, forged.encode().unwrap());
+        harness.run(2).await;
+        assert_eq!(harness.target.assets().len(), 1);
+        assert_eq!(harness.backlog(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_asset_governance_pauses_single_asset() {
+        use crate::types::{AssetAction, AssetGovernance, ASSET_GOVERNANCE_MESSAGE_TYPE};
+
+        let mut harness = TwoChainHarness::new().await.unwrap();
+        let pause = AssetGovernance {
+            action: AssetAction::Pause { asset: TEST_ASSET.to_string(), reason: "token compromised".to_string() },
+        };
+        harness.source.send(TARGET_CHAIN, ASSET_GOVERNANCE_MESSAGE_TYPE, pause.encode().unwrap());
+        harness.tick().await;
+        assert!(harness.relayer.asset_pauses().is_paused(TEST_ASSET));
+
+        // 暂停资产的转账保留，其它消息照常中继
+        harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([1u8; 32], 500));
+        harness.source.send(TARGET_CHAIN, "ping", vec![]);
+        harness.run(2).await;
+        assert_eq!(harness.target.submitted().len(), 1);
+        assert_eq!(harness.backlog(), 1);
+
+        // 未配置的资产被拒绝
+        let unknown = AssetGovernance { action: AssetAction::Resume { asset: "WETH".to_string() } };
+        harness.source.send(TARGET_CHAIN, ASSET_GOVERNANCE_MESSAGE_TYPE, unknown.encode().unwrap());
+        harness.tick().await;
+        assert_eq!(harness.backlog(), 1);
+
+        let resume = AssetGovernance { action: AssetAction::Resume { asset: TEST_ASSET.to_string() } };
+        harness.source.send(TARGET_CHAIN, ASSET_GOVERNANCE_MESSAGE_TYPE, resume.encode().unwrap());
+        harness.run(2).await;
+        assert_eq!(harness.target.submitted().len(), 2);
+        assert_eq!(harness.backlog(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_claims_refunds_for_own_submissions() {
+        use crate::config::{ReimbursementConfig, StorageConfig};
+        use crate::ledger::{fees, LedgerQuery};
+        use crate::storage;
+
+        let path = std::env::temp_dir().join(format!("bridge-refund-test-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let mut config = two_chain_config();
+        config.storage = Some(StorageConfig {
+            backend: "file".to_string(),
+            path: path.to_string_lossy().into_owned(),
+            url: None,
+            max_connections: 1,
+            retention: None,
+        });
+        config.chains[1].reimbursement = Some(ReimbursementConfig {
+            payout_address: "0xpayout".to_string(),
+            claim_interval: 3_600,
+            min_claim: 0,
+        });
+        let mut harness = TwoChainHarness::with_config(config.clone()).await.unwrap();
+        harness.target.set_refund_per_message(40);
+        harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([1u8; 32], 500));
+        harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([2u8; 32], 700));
+        // 另一个中继器执行的消息不计入本实例的报销
+        let other = harness.source.send(TARGET_CHAIN, "ping", vec![]);
+        harness.target.execute_external(other);
+        harness.tick().await;
+        let claims = harness.target.refund_claims();
+        assert_eq!(claims.len(), 1);
+        assert_eq!((claims[0].1.as_str(), claims[0].2), ("0xpayout", 80));
+
+        // 认领间隔未到时不再认领
+        harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([3u8; 32], 900));
+        harness.tick().await;
+        assert_eq!(harness.target.submitted().len(), 4);
+        assert_eq!(harness.target.refund_claims().len(), 1);
+
+        let store = storage::open(config.storage.as_ref().unwrap()).await.unwrap();
+        let report = fees::fee_report(&store.query_ledger(&LedgerQuery::default()).await.unwrap());
+        let target = report.iter().find(|fees| fees.chain == TARGET_CHAIN).unwrap();
+        assert_eq!(target.gas_refunded, 80);
+        let metrics = harness.relayer.metrics().export().unwrap();
+        assert!(metrics.contains("bridge_gas_refunds_claimed_total{chain=\"mock_target\"} 80"));
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_standby_follows_leader_and_takes_over_on_promotion() {
+        use crate::config::{CoordinationConfig, StorageConfig};
+        use crate::relayer::leader;
+        use crate::storage::{self, MessageRecord};
+        use crate::types::MessageStatus;
+
+        let path = std::env::temp_dir().join(format!("bridge-standby-test-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let mut config = two_chain_config();
+        config.storage = Some(StorageConfig {
+            backend: "file".to_string(),
+            path: path.to_string_lossy().into_owned(),
+            url: None,
+            max_connections: 1,
+            retention: None,
+        });
+        let coordination = CoordinationConfig { instance_id: Some("standby".to_string()), lease_ttl: 30, follow_interval: 5 };
+        config.relayer.coordination = Some(coordination.clone());
+        let mut harness = TwoChainHarness::with_config(config.clone()).await.unwrap();
+
+        // 领导者已处理第一条消息，备用实例跟随后只保留第二条
+        let finished = harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([1u8; 32], 500));
+        let remaining = harness.source.send(TARGET_CHAIN, "transfer", transfer_payload([2u8; 32], 700));
+        let store = storage::open(config.storage.as_ref().unwrap()).await.unwrap();
+        store.upsert(&MessageRecord {
+            message_id: finished.message.message_id(&test_chains()).unwrap(),
+            source_chain: SOURCE_CHAIN.to_string(),
+            target_chain: TARGET_CHAIN.to_string(),
+            asset: Some(TEST_ASSET.to_string()),
+            sender: None,
+            recipient: None,
+            nonce: finished.message.nonce,
+            status: MessageStatus::Processed,
+            observed_at: finished.timestamp,
+            completed_at: Some(finished.timestamp),
+            error: None,
+            cause: None,
+        }).await.unwrap();
+        harness.tick().await;
+        assert!(harness.target.submitted().is_empty());
+        assert_eq!(harness.backlog(), 1);
+
+        leader::request_promotion(store.as_ref(), "standby", &coordination).await.unwrap();
+        assert!(harness.relayer.election().unwrap().refresh().await.unwrap());
+        harness.tick().await;
+        let submitted = harness.target.submitted();
+        assert_eq!(submitted.len(), 1);
+        assert_eq!(submitted[0].message.nonce, remaining.message.nonce);
+        assert_eq!(harness.backlog(), 0);
+        for extension in ["", ".ledger", ".schemas", ".lease"] {
+            let _ = std::fs::remove_file(format!("{}{}", path.display(), extension));
+        }
+    }
+}
@@ -0,0 +1,341 @@
+use serde::Serialize;
+use crate::{
+    config::Config,
+    ledger::{self, LedgerEntry, LedgerQuery, Transfer},
+    relayer::{aggregator::{AttestationAggregator, QuorumProgress}, ChainAdapterFactory, DefaultChainAdapterFactory},
+    storage::{MessageRecord, MessageStore, RetryRecord, SourceRecord, SubmissionRecord},
+    types::{Attestation, MessageStatus, SignedMessage},
+    Error,
+};
+
+/// 解码后的转账负载
+#[derive(Debug, Clone, Serialize)]
+pub struct DecodedTransfer {
+    pub asset: String,
+    pub recipient: String,
+    pub amount: u64,
+    pub fee: u64,
+    pub target_asset_type: Option<String>,
+}
+
+/// 解码后的消息负载
+#[derive(Debug, Clone, Serialize)]
+pub struct DecodedPayload {
+    pub message_type: String,
+    /// 以合约数字链 ID 编码的规范字节（十六进制），其哈希即消息 ID 与验证者签名的内容
+    pub canonical_bytes: String,
+    pub payload: String,
+    pub transfer: Option<DecodedTransfer>,
+}
+
+/// 单条证明的重新校验结果
+#[derive(Debug, Clone, Serialize)]
+pub struct AttestationCheck {
+    pub attestation: Attestation,
+    pub valid: bool,
+    pub error: Option<String>,
+}
+
+/// 对账检查项
+#[derive(Debug, Clone, Serialize)]
+pub struct ReconciliationCheck {
+    pub name: String,
+    pub passed: bool,
+    pub detail: String,
+}
+
+impl ReconciliationCheck {
+    fn new(name: &str, passed: bool, detail: String) -> Self {
+        Self {
+            name: name.to_string(),
+            passed,
+            detail,
+        }
+    }
+}
+
+/// 单条消息的排查报告，汇总存储中与该消息相关的全部记录
+#[derive(Debug, Clone, Serialize)]
+pub struct MessageTrace {
+    pub message_id: String,
+    pub generated_at: u64,
+    pub record: MessageRecord,
+    pub source: Option<SourceRecord>,
+    pub decoded: Option<DecodedPayload>,
+    pub attestations: Vec<AttestationCheck>,
+    pub quorum: Option<QuorumProgress>,
+    pub submissions: Vec<SubmissionRecord>,
+    pub retries: Vec<RetryRecord>,
+    pub ledger: Vec<LedgerEntry>,
+    pub checks: Vec<ReconciliationCheck>,
+}
+
+/// 从存储收集消息的排查报告
+///
+/// `reconcile_on_chain` 为 true 时连接目标链查询消息的链上状态，并与存储状态比对。
+pub async fn collect(
+    config: &Config,
+    store: &dyn MessageStore,
+    message_id: &str,
+    reconcile_on_chain: bool,
+) -> Result<MessageTrace, Error> {
+    let record = store.get(message_id).await?
+        .ok_or_else(|| Error::Config(format!("Message {} not found in storage", message_id)))?;
+    let source = store.source(message_id).await?;
+    // 机密消息的负载未保存，无法解码，也无法重新计算消息 ID 与校验证明签名
+    let redacted = source.as_ref().is_some_and(|s| s.payload_redacted);
+    let decoded = source.as_ref()
+        .filter(|_| !redacted)
+        .map(|s| decode(config, message_id, s))
+        .transpose()?;
+
+    // 用当前验证者集合重新校验已保存的证明
+    let chains = config.chain_registry()?;
+    let aggregator = AttestationAggregator::new(config.validators.clone(), config.relayer.attestation_threshold, chains.clone())
+        .with_governance_threshold(config.relayer.governance_threshold);
+    // 验证者对提交到目标链的消息签名，转账换算金额后其消息 ID 与源链不同，证明按该 ID 保存
+    let mut attested_id = message_id.to_string();
+    if let Some(source) = source.as_ref().filter(|_| !redacted) {
+        let submitted = ledger::submitted_message(config, &source.message)?;
+        attested_id = submitted.message_id(&chains)?;
+        aggregator.observe(message_id, &submitted).await?;
+    }
+    let stored = store.attestations(&attested_id).await?;
+    let attestations = if redacted {
+        stored
+            .into_iter()
+            .map(|attestation| AttestationCheck {
+                attestation,
+                valid: false,
+                error: Some("payload redacted, signature not re-verified".to_string()),
+            })
+            .collect()
+    } else {
+        let results = aggregator.submit_batch(stored.clone()).await;
+        stored
+            .into_iter()
+            .zip(results)
+            .map(|(attestation, result)| AttestationCheck {
+                attestation,
+                valid: result.is_ok(),
+                error: result.err().map(|e| e.to_string()),
+            })
+            .collect()
+    };
+    let quorum = aggregator.progress(message_id).await;
+
+    let ledger: Vec<LedgerEntry> = store
+        .query_ledger(&LedgerQuery {
+            asset: record.asset.clone(),
+            source_chain: Some(record.source_chain.clone()),
+            target_chain: Some(record.target_chain.clone()),
+            ..Default::default()
+        })
+        .await?
+        .into_iter()
+        .filter(|entry| entry.message_id == message_id)
+        .collect();
+
+    let mut trace = MessageTrace {
+        message_id: message_id.to_string(),
+        generated_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default(),
+        record,
+        source,
+        decoded,
+        attestations,
+        quorum,
+        submissions: store.submissions(message_id).await?,
+        retries: store.retries(message_id).await?,
+        ledger,
+        checks: Vec::new(),
+    };
+    trace.checks = offline_checks(config, &trace);
+    if reconcile_on_chain {
+        trace.checks.push(on_chain_check(config, &trace).await);
+    }
+    Ok(trace)
+}
+
+fn decode(config: &Config, message_id: &str, source: &SourceRecord) -> Result<DecodedPayload, Error> {
+    let transfer = Transfer::parse(config, message_id, &source.message).map(|transfer| DecodedTransfer {
+        asset: transfer.asset.name.clone(),
+        recipient: hex::encode(transfer.recipient()),
+        amount: transfer.amount,
+        fee: transfer.fee(),
+        target_asset_type: transfer.target_asset_type().map(|t| t.to_string()),
+    });
+    Ok(DecodedPayload {
+        message_type: source.message.message_type.clone(),
+        canonical_bytes: hex::encode(source.message.canonical_bytes(&config.chain_registry()?)?),
+        payload: hex::encode(&source.message.payload),
+        transfer,
+    })
+}
+
+/// 不依赖链上数据的一致性检查
+fn offline_checks(config: &Config, trace: &MessageTrace) -> Vec<ReconciliationCheck> {
+    let mut checks = Vec::new();
+
+    if trace.source.as_ref().is_some_and(|s| s.payload_redacted) {
+        checks.push(ReconciliationCheck::new(
+            "confidential",
+            true,
+            "payload redacted in storage; message ID and attestation signatures not re-verified".to_string(),
+        ));
+        checks.extend(delivery_checks(trace));
+        return checks;
+    }
+
+    if let Some(source) = &trace.source {
+        let computed = config
+            .chain_registry()
+            .and_then(|chains| source.message.message_id(&chains))
+            .unwrap_or_default();
+        checks.push(ReconciliationCheck::new(
+            "message_id",
+            computed == trace.message_id,
+            format!("canonical hash of the source event is {}", computed),
+        ));
+    }
+
+    let invalid = trace.attestations.iter().filter(|a| !a.valid).count();
+    checks.push(ReconciliationCheck::new(
+        "attestation_signatures",
+        invalid == 0,
+        format!("{} of {} attestation(s) failed verification", invalid, trace.attestations.len()),
+    ));
+    if config.relayer.require_attestations && trace.record.status == MessageStatus::Processed {
+        let reached = trace.quorum.as_ref().is_some_and(|q| q.reached());
+        checks.push(ReconciliationCheck::new(
+            "quorum",
+            reached,
+            match &trace.quorum {
+                Some(q) => format!("attested weight {}/{}", q.weight, q.threshold),
+                None => "no attestations recorded".to_string(),
+            },
+        ));
+    }
+
+    checks.extend(delivery_checks(trace));
+    checks
+}
+
+/// 提交记录与记账分录的一致性检查
+fn delivery_checks(trace: &MessageTrace) -> Vec<ReconciliationCheck> {
+    let mut checks = Vec::new();
+    let delivered = trace.submissions.iter().any(|s| s.tx_hash.is_some() && s.error.is_none());
+    if trace.record.status == MessageStatus::Processed {
+        checks.push(ReconciliationCheck::new(
+            "submission",
+            delivered || trace.submissions.is_empty(),
+            format!("{} submission attempt(s) recorded", trace.submissions.len()),
+        ));
+    }
+
+    if !trace.ledger.is_empty() {
+        let summaries = ledger::summarize(&trace.ledger);
+        let balanced = summaries.iter().all(|s| s.is_balanced());
+        let in_transit: i128 = summaries
+            .iter()
+            .filter_map(|s| s.balances.get(&ledger::LedgerAccount::InTransit))
+            .sum();
+        // 已交付的消息在途余额应为零
+        let settled = trace.record.status != MessageStatus::Processed || in_transit == 0;
+        checks.push(ReconciliationCheck::new(
+            "ledger",
+            balanced && settled,
+            format!("{} entries, balanced: {}, in transit: {}", trace.ledger.len(), balanced, -in_transit),
+        ));
+    }
+    checks
+}
+
+/// 查询目标链上的消息状态并与存储状态比对
+async fn on_chain_check(config: &Config, trace: &MessageTrace) -> ReconciliationCheck {
+    let result = async {
+        let source = trace.source.as_ref()
+            .ok_or_else(|| Error::Config("Source event not recorded".to_string()))?;
+        let target = config.get_chain_config(&trace.record.target_chain)
+            .ok_or_else(|| Error::Config(format!("Chain config not found: {}", trace.record.target_chain)))?;
+        let adapter = DefaultChainAdapterFactory::new(config).create_adapter(target).await?;
+        let message = SignedMessage {
+            message: source.message.clone(),
+            signature: source.signature.clone(),
+            timestamp: source.observed_at,
+            event_name: source.event_name.clone(),
+            sender: source.sender.clone(),
+            provenance: source.provenance.clone(),
+            proof: None,
+            trace_id: None,
+        };
+        let status = adapter.verify_message(&message).await;
+        adapter.close().await?;
+        status
+    }
+    .await;
+
+    match result {
+        Ok(status) => ReconciliationCheck::new(
+            "target_chain_status",
+            status == trace.record.status,
+            format!("on-chain status {:?}, stored status {:?}", status, trace.record.status),
+        ),
+        Err(e) => ReconciliationCheck::new("target_chain_status", false, format!("query failed: {}", e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        storage::FileStore,
+        testing::{test_chains, two_chain_config, SOURCE_CHAIN, TARGET_CHAIN, TEST_ASSET},
+        types::CrossChainMessage,
+    };
+
+    #[tokio::test]
+    async fn test_trace_reconciles_ledger() {
+        let config = two_chain_config();
+        let mut payload = vec![0xaa; 32];
+        payload.extend_from_slice(&500u64.to_le_bytes());
+        let message = CrossChainMessage {
+            nonce: 1,
+            source_chain: SOURCE_CHAIN.into(),
+            target_chain: TARGET_CHAIN.into(),
+            message_type: "transfer".to_string(),
+            payload,
+        };
+        let message_id = message.message_id(&test_chains()).unwrap();
+
+        let path = std::env::temp_dir().join(format!("bridge-trace-test-{}.jsonl", std::process::id()));
+        let store = FileStore::open(&path).unwrap();
+        store.upsert(&MessageRecord {
+            message_id: message_id.clone(),
+            source_chain: message.source_chain.to_string(),
+            target_chain: message.target_chain.to_string(),
+            asset: Some(TEST_ASSET.to_string()),
+            sender: None,
+            recipient: None,
+            nonce: 1,
+            status: MessageStatus::Pending,
+            observed_at: 10,
+            completed_at: None,
+            error: None,
+            cause: None,
+        }).await.unwrap();
+        let transfer = Transfer::parse(&config, &message_id, &message).unwrap();
+        store.append_ledger(&transfer.initiated(10)).await.unwrap();
+
+        let trace = collect(&config, &store, &message_id, false).await.unwrap();
+        assert_eq!(trace.ledger.len(), 2);
+        assert!(trace.checks.iter().all(|c| c.passed));
+
+        let _ = std::fs::remove_file(&path);
+        let mut ledger_path = path.into_os_string();
+        ledger_path.push(".ledger");
+        let _ = std::fs::remove_file(ledger_path);
+    }
+}
@@ -1,19 +1,623 @@
 use serde::{Deserialize, Serialize};
+use blake2::{Blake2b, Digest, digest::consts::U32};
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::ops::Deref;
+use crate::{proof::InclusionProof, Error};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+type Blake2b256 = Blake2b<U32>;
+
+/// 支持的跨链消息格式版本
+pub const SUPPORTED_MESSAGE_VERSIONS: &[u8] = &[1];
+
+/// 链标识，即配置中 `chains[].id` 的取值
+///
+/// 序列化形式与字符串相同，消息的 BCS 编码和存储格式不受影响。
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ChainId(String);
+
+impl ChainId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for ChainId {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for ChainId {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for ChainId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ChainId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for ChainId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+
+impl From<String> for ChainId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+
+impl From<ChainId> for String {
+    fn from(id: ChainId) -> Self {
+        id.0
+    }
+}
+
+impl PartialEq<str> for ChainId {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for ChainId {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<String> for ChainId {
+    fn eq(&self, other: &String) -> bool {
+        &self.0 == other
+    }
+}
+
+impl PartialEq<ChainId> for String {
+    fn eq(&self, other: &ChainId) -> bool {
+        *self == other.0
+    }
+}
+
+impl PartialEq<ChainId> for &str {
+    fn eq(&self, other: &ChainId) -> bool {
+        *self == other.0
+    }
+}
+
+/// 链 ID 到适配器类型与合约数字链 ID 的登记表
+///
+/// 消息中的链 ID 和验证器允许的链都以此为准，未登记的链 ID 一律视为无效。
+/// 合约以 u64 标识链，登记表保证配置链 ID 与数字链 ID 一一对应。
+#[derive(Debug, Clone, Default)]
+pub struct ChainRegistry {
+    chains: BTreeMap<ChainId, String>,
+    numeric: BTreeMap<u64, ChainId>,
+}
+
+impl ChainRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 登记一条链，同一 ID 不能重复登记
+    pub fn register(&mut self, id: ChainId, adapter_type: &str) -> Result<(), Error> {
+        if self.chains.contains_key(&id) {
+            return Err(Error::Config(format!("Duplicate chain ID: {}", id)));
+        }
+        self.chains.insert(id, adapter_type.to_string());
+        Ok(())
+    }
+
+    /// 为已登记的链指定合约数字链 ID，一个数字 ID 只能对应一条链，一条链也只能有一个数字 ID
+    pub fn register_numeric(&mut self, id: &str, numeric: u64) -> Result<(), Error> {
+        let id = self.resolve(id).map_err(|e| Error::Config(e.to_string()))?.clone();
+        if let Some(existing) = self.numeric.get(&numeric) {
+            return Err(Error::Config(format!("Duplicate numeric chain ID {}: used by {} and {}", numeric, existing, id)));
+        }
+        if let Some(existing) = self.numeric_id(&id) {
+            return Err(Error::Config(format!("Chain {} already has numeric chain ID {}", id, existing)));
+        }
+        self.numeric.insert(numeric, id);
+        Ok(())
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.chains.contains_key(id)
+    }
+
+    /// 链使用的适配器类型，未登记时为 None
+    pub fn adapter_type(&self, id: &str) -> Option<&str> {
+        self.chains.get(id).map(String::as_str)
+    }
+
+    /// 解析链 ID，未登记时返回错误
+    pub fn resolve(&self, id: &str) -> Result<&ChainId, Error> {
+        self.chains
+            .get_key_value(id)
+            .map(|(id, _)| id)
+            .ok_or_else(|| Error::Chain(format!("Unknown chain: {}", id)))
+    }
+
+    /// 链的合约数字 ID，未指定时为 None
+    pub fn numeric_id(&self, id: &str) -> Option<u64> {
+        self.numeric.iter().find(|(_, chain)| *chain == id).map(|(numeric, _)| *numeric)
+    }
+
+    /// 由合约数字 ID 解析链 ID，未登记时返回错误
+    pub fn resolve_numeric(&self, numeric: u64) -> Result<&ChainId, Error> {
+        self.numeric
+            .get(&numeric)
+            .ok_or_else(|| Error::Chain(format!("Unknown numeric chain ID: {}", numeric)))
+    }
+
+    /// 全部数字链 ID 到链 ID 的映射，供需要解析链上数字 ID 的适配器使用
+    pub fn numeric_ids(&self) -> HashMap<u64, ChainId> {
+        self.numeric.iter().map(|(numeric, id)| (*numeric, id.clone())).collect()
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = &ChainId> {
+        self.chains.keys()
+    }
+}
+
+/// 配置链 ID 到合约数字链 ID 的查询，消息的规范编码据此换用合约中的数字链 ID
+pub trait NumericChainIds {
+    fn numeric_chain_id(&self, id: &str) -> Option<u64>;
+}
+
+impl NumericChainIds for ChainRegistry {
+    fn numeric_chain_id(&self, id: &str) -> Option<u64> {
+        self.numeric_id(id)
+    }
+}
+
+/// 适配器持有的数字链 ID 到链 ID 的映射
+impl NumericChainIds for HashMap<u64, ChainId> {
+    fn numeric_chain_id(&self, id: &str) -> Option<u64> {
+        self.iter().find(|(_, chain)| *chain == id).map(|(numeric, _)| *numeric)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CrossChainMessage {
     pub nonce: u64,
-    pub source_chain: String,
-    pub target_chain: String,
+    pub source_chain: ChainId,
+    pub target_chain: ChainId,
     pub message_type: String,
     pub payload: Vec<u8>,
 }
 
+impl CrossChainMessage {
+    /// 规范编码：`nonce`、源链与目标链的合约数字链 ID（u64）、消息类型与负载的 BCS 字节
+    ///
+    /// 与 Move `message::canonical_bytes` 一致，合约以 u64 标识链，因此编码中不出现配置链 ID；
+    /// 源链或目标链没有登记数字 ID 时返回错误。
+    pub fn canonical_bytes(&self, chains: &impl NumericChainIds) -> Result<Vec<u8>, Error> {
+        let numeric = |id: &ChainId| {
+            chains
+                .numeric_chain_id(id)
+                .ok_or_else(|| Error::Config(format!("No numeric chain ID registered for {}", id)))
+        };
+        let fields = (self.nonce, numeric(&self.source_chain)?, numeric(&self.target_chain)?, &self.message_type, &self.payload);
+        bcs::to_bytes(&fields).map_err(|e| Error::Serialization(e.to_string()))
+    }
+
+    /// 规范哈希：规范编码的 Blake2b-256 摘要，即 Move 的 `message::get_message_id`，验证者对该哈希签名
+    pub fn canonical_hash(&self, chains: &impl NumericChainIds) -> Result<[u8; 32], Error> {
+        let digest = Blake2b256::digest(self.canonical_bytes(chains)?);
+        Ok(digest.into())
+    }
+
+    /// 消息 ID：规范哈希的十六进制编码
+    pub fn message_id(&self, chains: &impl NumericChainIds) -> Result<String, Error> {
+        Ok(hex::encode(self.canonical_hash(chains)?))
+    }
+}
+
+/// 同质化资产转账消息的类型名，负载为接收者地址后接小端序 u64 金额
+pub const TRANSFER_MESSAGE_TYPE: &str = "transfer";
+
+/// NFT 转移消息的类型名
+pub const NFT_TRANSFER_MESSAGE_TYPE: &str = "nft_transfer";
+
+/// NFT/对象跨链转移负载，以 BCS 编码放入 `nft_transfer` 消息的 payload
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NftTransfer {
+    /// 源链上的集合类型，例如 Sui 对象的 Move 类型
+    pub collection: String,
+    /// 源链上的对象 ID 或 token ID
+    pub token_id: Vec<u8>,
+    /// 元数据的 Blake2b-256 摘要，目标链铸造时用于校验元数据未被篡改
+    pub metadata_hash: Vec<u8>,
+    pub recipient: Vec<u8>,
+}
+
+impl NftTransfer {
+    pub fn decode(payload: &[u8]) -> Result<Self, Error> {
+        let transfer: Self = bcs::from_bytes(payload)
+            .map_err(|e| Error::Serialization(format!("Invalid NFT transfer payload: {}", e)))?;
+        if transfer.token_id.is_empty() {
+            return Err(Error::Serialization("NFT transfer token ID is empty".to_string()));
+        }
+        if transfer.metadata_hash.len() != 32 {
+            return Err(Error::Serialization(format!(
+                "NFT metadata hash must be 32 bytes, got {}",
+                transfer.metadata_hash.len()
+            )));
+        }
+        Ok(transfer)
+    }
+
+    pub fn encode(&self) -> Result<Vec<u8>, Error> {
+        bcs::to_bytes(self).map_err(|e| Error::Serialization(e.to_string()))
+    }
+}
+
+/// 验证者集合更新消息的类型名
+pub const VALIDATOR_SET_UPDATE_MESSAGE_TYPE: &str = "validator_set_update";
+
+/// 验证者集合更新负载，以 BCS 编码放入 `validator_set_update` 消息的 payload
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidatorSetUpdate {
+    /// 新集合生效的纪元
+    pub epoch: u64,
+    /// 更新后的完整验证者集合
+    pub validators: Vec<ValidatorWeight>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidatorWeight {
+    pub address: String,
+    pub weight: u64,
+}
+
+impl ValidatorSetUpdate {
+    pub fn decode(payload: &[u8]) -> Result<Self, Error> {
+        let update: Self = bcs::from_bytes(payload)
+            .map_err(|e| Error::Serialization(format!("Invalid validator set update payload: {}", e)))?;
+        if update.validators.iter().all(|v| v.weight == 0) {
+            return Err(Error::Serialization("Validator set update has no voting weight".to_string()));
+        }
+        Ok(update)
+    }
+
+    pub fn encode(&self) -> Result<Vec<u8>, Error> {
+        bcs::to_bytes(self).map_err(|e| Error::Serialization(e.to_string()))
+    }
+}
+
+/// 桥治理消息的类型名
+pub const GOVERNANCE_MESSAGE_TYPE: &str = "governance";
+
+/// 桥治理操作，由目标链合约执行
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum BridgeAction {
+    /// 暂停桥
+    Pause,
+    /// 恢复桥
+    Unpause,
+    /// 设置资产的跨链手续费率（基点）
+    SetFee { asset: String, fee_rate: u64 },
+    /// 更新资产的单笔与每日转账上限，使用目标链精度
+    UpdateLimits { asset: String, max_amount: u64, daily_limit: u64 },
+    /// 替换目标链合约的验证者集合
+    UpdateValidatorSet(ValidatorSetUpdate),
+}
+
+/// 桥治理负载，以 BCS 编码放入 `governance` 消息的 payload
+///
+/// 治理消息需要比转账更高的证明权重，见 `RelayerConfig.governance_threshold`。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Governance {
+    /// 提案编号，目标链合约据此拒绝重复执行
+    pub proposal_id: u64,
+    pub action: BridgeAction,
+}
+
+impl Governance {
+    pub fn decode(payload: &[u8]) -> Result<Self, Error> {
+        let governance: Self = bcs::from_bytes(payload)
+            .map_err(|e| Error::Serialization(format!("Invalid governance payload: {}", e)))?;
+        match &governance.action {
+            BridgeAction::SetFee { fee_rate, .. } if *fee_rate > 10_000 => {
+                return Err(Error::Serialization(format!("Fee rate {} exceeds 10000 basis points", fee_rate)));
+            }
+            BridgeAction::UpdateLimits { max_amount, daily_limit, .. } if max_amount > daily_limit => {
+                return Err(Error::Serialization("Transfer limit exceeds the daily limit".to_string()));
+            }
+            BridgeAction::UpdateValidatorSet(update) if update.validators.iter().all(|v| v.weight == 0) => {
+                return Err(Error::Serialization("Validator set update has no voting weight".to_string()));
+            }
+            _ => {}
+        }
+        Ok(governance)
+    }
+
+    pub fn encode(&self) -> Result<Vec<u8>, Error> {
+        bcs::to_bytes(self).map_err(|e| Error::Serialization(e.to_string()))
+    }
+}
+
+/// 是否为治理类消息：桥治理、验证者治理、资产治理与验证者集合更新，这些消息使用治理阈值
+pub fn is_governance(message_type: &str) -> bool {
+    [
+        GOVERNANCE_MESSAGE_TYPE,
+        VALIDATOR_GOVERNANCE_MESSAGE_TYPE,
+        ASSET_GOVERNANCE_MESSAGE_TYPE,
+        VALIDATOR_SET_UPDATE_MESSAGE_TYPE,
+    ]
+    .contains(&message_type)
+}
+
+/// 验证者治理消息的类型名
+pub const VALIDATOR_GOVERNANCE_MESSAGE_TYPE: &str = "validator_governance";
+
+/// 对验证者集合的单项治理操作
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum GovernanceAction {
+    ValidatorAdded {
+        address: String,
+        public_key: Vec<u8>,
+        weight: u64,
+        /// 验证者负责证明的源链
+        chains: Vec<String>,
+    },
+    ValidatorRemoved { address: String },
+    ThresholdChanged { threshold: u64 },
+    KeyRotated(KeyRotation),
+}
+
+/// 密钥轮换声明签名的域分隔前缀，以 [`crate::signer::RAW_SIGNING_DOMAIN`] 开头
+const KEY_ROTATION_DOMAIN: &[u8] = b"move-bridge/key-rotation/v1";
+
+/// 验证者密钥轮换声明，由旧密钥签名
+///
+/// 生效后新密钥成为验证者的当前密钥，旧密钥在宽限期内仍可签名，便于验证者平滑切换。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyRotation {
+    pub address: String,
+    pub old_public_key: Vec<u8>,
+    pub new_public_key: Vec<u8>,
+    /// 生效后旧密钥继续有效的秒数
+    pub grace_period: u64,
+    pub issued_at: u64,
+    /// 旧密钥对 [`KeyRotation::signing_bytes`] 的签名
+    pub signature: Vec<u8>,
+}
+
+impl KeyRotation {
+    /// 签名覆盖的内容：域分隔前缀后接除签名外全部字段的 BCS 编码
+    pub fn signing_bytes(&self) -> Result<Vec<u8>, Error> {
+        let fields = (&self.address, &self.old_public_key, &self.new_public_key, self.grace_period, self.issued_at);
+        let mut bytes = KEY_ROTATION_DOMAIN.to_vec();
+        bytes.extend(bcs::to_bytes(&fields).map_err(|e| Error::Serialization(e.to_string()))?);
+        Ok(bytes)
+    }
+
+    /// 校验旧密钥的签名
+    pub fn verify(&self) -> Result<(), Error> {
+        if self.new_public_key.len() != 32 {
+            return Err(Error::Key(format!("New public key must be 32 bytes, got {}", self.new_public_key.len())));
+        }
+        if self.new_public_key == self.old_public_key {
+            return Err(Error::Key("New public key is the same as the old one".to_string()));
+        }
+        crate::keystore::verify_signature(&self.old_public_key, &self.signing_bytes()?, &self.signature)
+    }
+}
+
+/// 验证者治理负载，以 BCS 编码放入 `validator_governance` 消息的 payload
+///
+/// 与 `validator_set_update` 的整体替换不同，治理消息逐项修改集合，并在源链达到生效高度后才生效。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidatorGovernance {
+    /// 源链上的生效高度，单位与该链 `replay_events` 的区间一致
+    pub activation_height: u64,
+    pub action: GovernanceAction,
+}
+
+impl ValidatorGovernance {
+    pub fn decode(payload: &[u8]) -> Result<Self, Error> {
+        bcs::from_bytes(payload)
+            .map_err(|e| Error::Serialization(format!("Invalid validator governance payload: {}", e)))
+    }
+
+    pub fn encode(&self) -> Result<Vec<u8>, Error> {
+        bcs::to_bytes(self).map_err(|e| Error::Serialization(e.to_string()))
+    }
+}
+
+/// 资产治理消息的类型名
+pub const ASSET_GOVERNANCE_MESSAGE_TYPE: &str = "asset_governance";
+
+/// 对单个资产中继开关的治理操作
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AssetAction {
+    /// 暂停资产的转账，其它资产不受影响
+    Pause { asset: String, reason: String },
+    Resume { asset: String },
+}
+
+/// 资产治理负载，以 BCS 编码放入 `asset_governance` 消息的 payload
+///
+/// 资产治理消息不向目标链中继，由中继器收到后立即切换该资产的中继开关。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AssetGovernance {
+    pub action: AssetAction,
+}
+
+impl AssetGovernance {
+    pub fn decode(payload: &[u8]) -> Result<Self, Error> {
+        bcs::from_bytes(payload).map_err(|e| Error::Serialization(format!("Invalid asset governance payload: {}", e)))
+    }
+
+    pub fn encode(&self) -> Result<Vec<u8>, Error> {
+        bcs::to_bytes(self).map_err(|e| Error::Serialization(e.to_string()))
+    }
+}
+
+/// 退款消息的类型名
+pub const REFUND_MESSAGE_TYPE: &str = "refund";
+
+/// 退款消息 nonce 的最高位
+///
+/// 退款消息的 nonce 为原转账的 nonce 置最高位，与源链合约分配的 nonce 分开，
+/// 不会占用反方向真实消息在合约中的重放槽位 `(源链, nonce)`。
+pub const REFUND_NONCE_FLAG: u64 = 1 << 63;
+
+/// 过期转账的退款负载，以 BCS 编码放入 `refund` 消息的 payload
+///
+/// 退款消息发回原转账的源链，由源链合约把锁定或销毁的资产退还给发起账户。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Refund {
+    /// 原转账消息的规范哈希，源链合约据此拒绝重复退款
+    pub original_message_id: Vec<u8>,
+    /// 原转账在源链上的发起账户
+    pub recipient: Vec<u8>,
+    /// 退款金额，使用源链精度
+    pub amount: u64,
+}
+
+impl Refund {
+    pub fn decode(payload: &[u8]) -> Result<Self, Error> {
+        let refund: Self = bcs::from_bytes(payload)
+            .map_err(|e| Error::Serialization(format!("Invalid refund payload: {}", e)))?;
+        if refund.original_message_id.len() != 32 {
+            return Err(Error::Serialization(format!(
+                "Refund original message ID must be 32 bytes, got {}",
+                refund.original_message_id.len()
+            )));
+        }
+        Ok(refund)
+    }
+
+    pub fn encode(&self) -> Result<Vec<u8>, Error> {
+        bcs::to_bytes(self).map_err(|e| Error::Serialization(e.to_string()))
+    }
+}
+
+/// 代币元数据同步消息的类型名
+pub const METADATA_SYNC_MESSAGE_TYPE: &str = "metadata_sync";
+
+/// 代币元数据同步负载，以 BCS 编码放入 `metadata_sync` 消息的 payload
+///
+/// 新资产上线时从原生链发往映射链，目标链合约据此登记包装代币的名称、符号与精度。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetadataSync {
+    /// 资产名称，与配置中的 `AssetConfig.name` 一致
+    pub name: String,
+    pub symbol: String,
+    /// 目标链上的精度
+    pub decimals: u8,
+    /// 原生链上的资产类型
+    pub native_type: String,
+    /// 目标链上的包装代币类型
+    pub target_type: String,
+}
+
+impl MetadataSync {
+    /// 按资产配置生成发往 `target_chain` 的元数据，资产未映射到该链时返回错误
+    pub fn from_asset(asset: &crate::config::AssetConfig, target_chain: &str) -> Result<Self, Error> {
+        if target_chain == asset.native_chain {
+            return Err(Error::Config(format!("Asset {} is native to {}", asset.name, target_chain)));
+        }
+        let target_type = asset
+            .type_on(target_chain)
+            .ok_or_else(|| Error::Config(format!("Asset {} is not mapped to {}", asset.name, target_chain)))?;
+        Ok(Self {
+            name: asset.name.clone(),
+            symbol: asset.symbol().to_string(),
+            decimals: asset.decimals_on(target_chain),
+            native_type: asset.type_.clone(),
+            target_type: target_type.to_string(),
+        })
+    }
+
+    pub fn decode(payload: &[u8]) -> Result<Self, Error> {
+        bcs::from_bytes(payload).map_err(|e| Error::Serialization(format!("Invalid metadata sync payload: {}", e)))
+    }
+
+    pub fn encode(&self) -> Result<Vec<u8>, Error> {
+        bcs::to_bytes(self).map_err(|e| Error::Serialization(e.to_string()))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SignedMessage {
     pub message: CrossChainMessage,
     pub signature: Vec<u8>,
     pub timestamp: u64,
+    /// 产生该消息的链上事件名称，仅用于链下分发处理器，不会被序列化提交
+    #[serde(default, skip_serializing)]
+    pub event_name: Option<String>,
+    /// 源链上发起消息的账户，适配器无法提供时为 None；仅用于链下记录
+    #[serde(default, skip_serializing)]
+    pub sender: Option<String>,
+    /// 事件在源链上的确切位置，适配器无法提供时为 None；仅用于链下记录
+    #[serde(default, skip_serializing)]
+    pub provenance: Option<EventProvenance>,
+    /// 事件在源链上的包含证明，随消息转发给中继器与验证者
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proof: Option<InclusionProof>,
+    /// 源链消息 ID，提交前由中继器设置；换算金额后提交的消息 ID 与源链不同，以此关联回源链消息
+    #[serde(default, skip_serializing)]
+    pub trace_id: Option<String>,
+}
+
+impl SignedMessage {
+    /// 随提交交易写入链上的追踪 ID：源链消息 ID 的 32 字节哈希，未设置时为消息自身的 ID
+    pub fn trace_id(&self, chains: &impl NumericChainIds) -> Result<Vec<u8>, Error> {
+        match &self.trace_id {
+            Some(id) => hex::decode(id).map_err(|e| Error::Serialization(format!("Invalid trace ID {}: {}", id, e))),
+            None => Ok(self.message.canonical_hash(chains)?.to_vec()),
+        }
+    }
+}
+
+/// 链上事件的来源位置
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventProvenance {
+    /// 事件所在的区块高度；Sui 为检查点序号
+    pub block: u64,
+    pub tx_hash: String,
+    /// 事件在交易中的序号
+    pub event_index: u64,
+}
+
+/// 目标链上观察到的一次消息交付，字段取决于链上事件能提供的信息
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Delivery {
+    /// 链上事件的唯一标识，用于对重复读取的事件去重
+    pub event_id: String,
+    pub source_chain: ChainId,
+    pub target_chain: ChainId,
+    /// 事件不包含 nonce 时为 None
+    pub nonce: Option<u64>,
+    pub recipient: Option<Vec<u8>>,
+    /// 实际到账金额（已扣除手续费）
+    pub amount: Option<u64>,
+    pub tx_hash: Option<String>,
+    /// 交付交易携带的消息 ID，适配器无法读取时为 None
+    #[serde(default)]
+    pub message_id: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -21,4 +625,292 @@ pub enum MessageStatus {
     Pending,
     Processed,
     Failed,
-} 
\ No newline at end of file
+    /// 中继前检查未通过（例如接收者无法接收资产），暂缓中继等待人工处理
+    Held,
+    /// 超过最大有效期仍未中继，不再提交；转账消息会向源链发出退款
+    Expired,
+}
+
+impl MessageStatus {
+    /// 消息不会再被中继器处理：已处理、失败或已过期
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, MessageStatus::Processed | MessageStatus::Failed | MessageStatus::Expired)
+    }
+}
+
+impl std::str::FromStr for MessageStatus {
+    type Err = Error;
+
+    /// 解析命令行与查询参数中的状态，不区分大小写
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s.to_ascii_lowercase().as_str() {
+            "pending" => Ok(MessageStatus::Pending),
+            "processed" => Ok(MessageStatus::Processed),
+            "failed" => Ok(MessageStatus::Failed),
+            "held" => Ok(MessageStatus::Held),
+            "expired" => Ok(MessageStatus::Expired),
+            _ => Err(Error::Config(format!("Unknown message status: {}", s))),
+        }
+    }
+}
+
+/// 失败原因分类
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureKind {
+    /// 目标链合约中止执行
+    MoveAbort,
+    /// 消息已在目标链执行
+    AlreadyProcessed,
+    /// 目标链合约拒绝了验证者签名
+    InvalidSignature,
+    /// 目标链上的桥已暂停
+    Paused,
+    /// 节点限制了请求频率
+    RateLimited,
+    /// 中继账户的 gas 不足
+    InsufficientGas,
+    /// 消息内容被目标链合约判定为不合法
+    InvalidMessage,
+    /// 节点返回 JSON-RPC 错误
+    Rpc,
+    /// 无法连接节点
+    Network,
+    /// 中继前检查未通过，例如接收者无法接收资产
+    Rejected,
+    /// 负载类型未注册或负载不符合已注册的结构
+    Schema,
+    /// 变更超出自动放行范围，等待人工批准
+    ApprovalRequired,
+    /// 大额转账处于时间锁延迟期
+    Timelocked,
+    /// 运营方取消了转账
+    Cancelled,
+    /// 发送方或接收方地址未通过筛查
+    Screened,
+    /// 运营方或资产核对暂停了该资产的中继
+    AssetPaused,
+    /// 源链事件的包含证明未通过轻客户端验证
+    InvalidProof,
+    /// 交易已广播但迟迟未确认
+    Stuck,
+    /// 重试或重新提交次数已用完
+    RetriesExhausted,
+    Config,
+    Serialization,
+    Key,
+    IncompatibleNode,
+    Chain,
+}
+
+/// JSON-RPC 错误码分类
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RpcErrorClass {
+    /// 请求格式或参数错误（-32700、-32600、-32602）
+    InvalidRequest,
+    /// 节点不支持该方法（-32601）
+    MethodNotFound,
+    /// 节点内部错误（-32603）
+    Internal,
+    /// 节点自定义的服务端错误（-32000 至 -32099），通常是交易校验或执行失败
+    Server,
+    Other,
+}
+
+impl RpcErrorClass {
+    pub fn from_code(code: i64) -> Self {
+        match code {
+            -32700 | -32600 | -32602 => RpcErrorClass::InvalidRequest,
+            -32601 => RpcErrorClass::MethodNotFound,
+            -32603 => RpcErrorClass::Internal,
+            -32099..=-32000 => RpcErrorClass::Server,
+            _ => RpcErrorClass::Other,
+        }
+    }
+}
+
+/// 结构化的失败原因，随消息记录保存并通过查询接口返回
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FailureCause {
+    pub kind: FailureKind,
+    /// 中止位置（模块），仅 `move_abort`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub abort_code: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rpc_class: Option<RpcErrorClass>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rpc_code: Option<i64>,
+    /// 可展示给最终用户的处理建议
+    pub hint: String,
+}
+
+impl FailureCause {
+    fn new(kind: FailureKind, hint: &str) -> Self {
+        Self {
+            kind,
+            location: None,
+            abort_code: None,
+            rpc_class: None,
+            rpc_code: None,
+            hint: hint.to_string(),
+        }
+    }
+
+    pub fn from_error(error: &Error) -> Self {
+        match error {
+            Error::MoveAbort { location, code } => Self {
+                location: Some(location.clone()),
+                abort_code: Some(*code),
+                ..Self::new(FailureKind::MoveAbort, abort_hint(location, *code))
+            },
+            Error::AlreadyProcessed(_) => Self::new(
+                FailureKind::AlreadyProcessed,
+                "The message was already executed on the target chain; no action is needed.",
+            ),
+            Error::InvalidSignature(_) => Self::new(
+                FailureKind::InvalidSignature,
+                "The target contract rejected the validator signatures; contact the operator with the message ID.",
+            ),
+            Error::Paused(_) => Self::new(
+                FailureKind::Paused,
+                "The bridge is paused on the target chain; the transfer will be delivered once it resumes.",
+            ),
+            Error::RateLimited(_) => Self::new(
+                FailureKind::RateLimited,
+                "The target chain node is rate limiting the relayer; the transfer will be retried automatically.",
+            ),
+            Error::InsufficientGas(_) => Self::new(
+                FailureKind::InsufficientGas,
+                "The relayer account cannot pay for gas on the target chain; the transfer will be retried once the operator tops it up.",
+            ),
+            Error::InvalidMessage(_) => Self::new(
+                FailureKind::InvalidMessage,
+                "The target contract rejected the message as malformed; contact the operator with the message ID.",
+            ),
+            Error::Rpc { code, .. } => {
+                let class = RpcErrorClass::from_code(*code);
+                let hint = match class {
+                    RpcErrorClass::Server => "The target chain node rejected the transaction; it will be retried, contact the operator if it keeps failing.",
+                    RpcErrorClass::Internal => "The target chain node failed internally; the transfer will be retried automatically.",
+                    _ => "The relayer sent a request the node did not accept; the operator needs to check node compatibility.",
+                };
+                Self {
+                    rpc_class: Some(class),
+                    rpc_code: Some(*code),
+                    ..Self::new(FailureKind::Rpc, hint)
+                }
+            }
+            Error::Network(_) => Self::new(
+                FailureKind::Network,
+                "The target chain node was unreachable; the transfer will be retried once it is back.",
+            ),
+            Error::Rejected(_) => Self::new(
+                FailureKind::Rejected,
+                "The recipient cannot receive this asset on the target chain; enable the asset for the recipient account and ask the operator to release the transfer.",
+            ),
+            Error::Schema(_) => Self::new(
+                FailureKind::Schema,
+                "The payload does not match the schema registered for its type; re-encode it or ask the operator to register the schema and release the transfer.",
+            ),
+            Error::ApprovalRequired(_) => Self::new(
+                FailureKind::ApprovalRequired,
+                "The message changes more than the automatically accepted amount and is waiting for operator approval.",
+            ),
+            Error::Timelocked { .. } => Self::new(
+                FailureKind::Timelocked,
+                "Large transfers are delayed before delivery; the transfer will be submitted automatically once the delay ends.",
+            ),
+            Error::Cancelled(_) => Self::new(
+                FailureKind::Cancelled,
+                "The operator cancelled this transfer during its timelock; contact the operator for a refund.",
+            ),
+            Error::Screened(_) => Self::new(
+                FailureKind::Screened,
+                "The sender or recipient address did not pass address screening; contact the operator.",
+            ),
+            Error::AssetPaused { .. } => Self::new(
+                FailureKind::AssetPaused,
+                "Relaying of this asset is paused by the operator; the transfer will be delivered once it resumes.",
+            ),
+            Error::InvalidProof(_) => Self::new(
+                FailureKind::InvalidProof,
+                "The source chain event could not be proven against the trusted chain state; contact the operator with the message ID.",
+            ),
+            Error::Stuck { .. } => Self::new(
+                FailureKind::Stuck,
+                "The delivery transaction was not confirmed in time and has been resubmitted; no action is needed unless it keeps failing.",
+            ),
+            Error::RetriesExhausted { .. } => Self::new(
+                FailureKind::RetriesExhausted,
+                "Delivery kept failing and the relayer stopped retrying; contact the operator with the message ID.",
+            ),
+            Error::Config(_) => Self::new(FailureKind::Config, "The bridge is misconfigured for this route; contact the operator."),
+            Error::Serialization(_) => Self::new(
+                FailureKind::Serialization,
+                "The transfer payload could not be decoded; check the amount and recipient encoding.",
+            ),
+            Error::Key(_) => Self::new(FailureKind::Key, "The relayer could not sign the transaction; contact the operator."),
+            Error::SignerTimeout { .. } => Self::new(
+                FailureKind::Key,
+                "The signing service did not respond in time; the transfer will be retried once it is reachable.",
+            ),
+            Error::SignerFailure { .. } => Self::new(FailureKind::Key, "The signing service refused to sign; contact the operator."),
+            Error::IncompatibleNode { .. } => Self::new(
+                FailureKind::IncompatibleNode,
+                "The target chain node runs an unsupported version; the operator needs to upgrade it.",
+            ),
+            Error::Chain(_) => Self::new(FailureKind::Chain, "The target chain reported an error; contact the operator with the message ID."),
+        }
+    }
+}
+
+/// 桥合约 `message` 模块中止码对应的处理建议
+fn abort_hint(location: &str, code: u64) -> &'static str {
+    if !location.ends_with("::message") {
+        return "The target contract rejected the transaction; contact the operator with the abort code.";
+    }
+    match code {
+        1 => "The message type is not supported by the target contract.",
+        2 => "The source and target chain of the transfer are invalid.",
+        4 => "The transfer payload is empty or malformed.",
+        5 => "The message was already executed on the target chain; no action is needed.",
+        _ => "The target contract rejected the transaction; contact the operator with the abort code.",
+    }
+}
+
+/// 验证者在链上注册表中的登记信息
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidatorRegistration {
+    pub address: String,
+    pub weight: u64,
+    pub active: bool,
+}
+
+/// 验证者对消息规范哈希的签名证明
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attestation {
+    pub message_id: String,
+    pub message: CrossChainMessage,
+    pub validator: String,
+    /// 签名公钥（十六进制）
+    pub public_key: String,
+    pub signature: Vec<u8>,
+    pub timestamp: u64,
+}
+
+impl Attestation {
+    /// 离线校验：消息 ID 等于消息的规范哈希，且签名由 `public_key` 对应的私钥产生
+    pub fn verify(&self, chains: &impl NumericChainIds, public_key: &[u8]) -> Result<(), Error> {
+        let hash = self.message.canonical_hash(chains)?;
+        if self.message_id != hex::encode(hash) {
+            return Err(Error::Key(format!("Message ID {} does not match canonical hash {}", self.message_id, hex::encode(hash))));
+        }
+        if hex::encode(public_key) != self.public_key.trim_start_matches("0x").to_lowercase() {
+            return Err(Error::Key(format!("Attestation was signed by {}, not {}", self.public_key, hex::encode(public_key))));
+        }
+        crate::keystore::verify_signature(public_key, &hash, &self.signature)
+    }
+}
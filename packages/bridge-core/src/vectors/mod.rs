@@ -0,0 +1,309 @@
+use blake2::{Blake2b, Digest, digest::consts::U32};
+use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer as _};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use crate::{
+    config::ValidatorConfig,
+    keystore::verify_signature,
+    types::{ChainId, CrossChainMessage, TRANSFER_MESSAGE_TYPE},
+    verify::bls::{self, AggregatedProof, BlsSigner},
+    Error,
+};
+
+mod move_module;
+
+pub use move_module::render_move_tests;
+
+type Blake2b256 = Blake2b<U32>;
+
+/// 向量文件格式版本，字段含义变化时递增
+pub const VECTOR_FORMAT_VERSION: u32 = 2;
+
+/// Move 合约中的资产消息类型
+const MESSAGE_TYPE_ASSET: u8 = 1;
+
+/// 验证者委员会规模，BLS 聚合证明由其中下标为偶数的验证者签名
+const COMMITTEE_SIZE: usize = 3;
+
+/// 一组签名测试向量，Rust 与 Move 两侧各自生成并互相校验
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VectorFile {
+    pub version: u32,
+    /// 生成向量的实现，`rust` 或 `move`
+    pub source: String,
+    pub vectors: Vec<TestVector>,
+}
+
+/// Move `sui_bridge::message::Message` 的字段，编码顺序与 `serialize_message` 一致
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageFields {
+    pub message_type: u8,
+    pub source_chain: u64,
+    pub target_chain: u64,
+    pub nonce: u64,
+    /// 十六进制负载
+    pub payload: String,
+}
+
+impl MessageFields {
+    /// 按 `serialize_message` 编码：类型、源链、目标链、nonce 的 BCS 字节后接原始负载
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = vec![self.message_type];
+        bytes.extend_from_slice(&self.source_chain.to_le_bytes());
+        bytes.extend_from_slice(&self.target_chain.to_le_bytes());
+        bytes.extend_from_slice(&self.nonce.to_le_bytes());
+        bytes.extend(decode_hex("payload", &self.payload)?);
+        Ok(bytes)
+    }
+
+    /// 按 `CrossChainMessage::canonical_bytes` 编码，即 Move 的 `canonical_bytes`
+    ///
+    /// 向量中的链以数字 ID 给出，这里以数字 ID 的十进制字符串作为链 ID 登记。
+    pub fn canonical_bytes(&self) -> Result<Vec<u8>, Error> {
+        let message_type = match self.message_type {
+            MESSAGE_TYPE_ASSET => TRANSFER_MESSAGE_TYPE,
+            other => return Err(Error::Serialization(format!("Unknown Move message type: {}", other))),
+        };
+        let chains: HashMap<u64, ChainId> = [self.source_chain, self.target_chain]
+            .into_iter()
+            .map(|numeric| (numeric, ChainId::from(numeric.to_string())))
+            .collect();
+        let message = CrossChainMessage {
+            nonce: self.nonce,
+            source_chain: ChainId::from(self.source_chain.to_string()),
+            target_chain: ChainId::from(self.target_chain.to_string()),
+            message_type: message_type.to_string(),
+            payload: decode_hex("payload", &self.payload)?,
+        };
+        message.canonical_bytes(&chains)
+    }
+}
+
+/// 单个验证者对消息哈希的 ed25519 签名
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Ed25519Vector {
+    pub public_key: String,
+    pub signature: String,
+}
+
+/// 委员会对消息哈希的 BLS 聚合证明
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlsVector {
+    /// 委员会全部成员的 BLS 公钥，下标与签名者位图对应
+    pub public_keys: Vec<String>,
+    pub signers: Vec<usize>,
+    pub aggregated_public_key: String,
+    pub signature: String,
+    /// `AggregatedProof` 的 BCS 编码
+    pub proof: String,
+}
+
+/// 一条消息的编码、哈希与签名
+///
+/// Move 侧无法签名，其生成的向量只包含编码与哈希。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestVector {
+    pub name: String,
+    pub message: MessageFields,
+    pub message_bytes: String,
+    /// 规范编码，即 Move 的 `canonical_bytes`
+    pub canonical_bytes: String,
+    /// 规范编码的 Blake2b-256 摘要，即 Move 的 `get_message_id`，签名对象为该摘要
+    pub message_hash: String,
+    #[serde(default)]
+    pub ed25519: Vec<Ed25519Vector>,
+    #[serde(default)]
+    pub bls: Option<BlsVector>,
+}
+
+impl VectorFile {
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let file: Self = serde_json::from_str(json).map_err(|e| Error::Serialization(format!("Invalid vector file: {}", e)))?;
+        if file.version != VECTOR_FORMAT_VERSION {
+            return Err(Error::Serialization(format!("Unsupported vector format version: {}", file.version)));
+        }
+        Ok(file)
+    }
+
+    pub fn to_json(&self) -> Result<String, Error> {
+        serde_json::to_string_pretty(self).map_err(|e| Error::Serialization(e.to_string()))
+    }
+}
+
+/// 使用固定种子生成 Rust 侧的向量，同一版本的输出逐字节不变
+pub fn generate() -> Result<VectorFile, Error> {
+    let ed25519_keys: Vec<Keypair> = (0..COMMITTEE_SIZE)
+        .map(|i| {
+            let secret = SecretKey::from_bytes(&[i as u8 + 1; 32]).map_err(|e| Error::Key(e.to_string()))?;
+            let public = PublicKey::from(&secret);
+            Ok(Keypair { secret, public })
+        })
+        .collect::<Result<_, Error>>()?;
+    let bls_signers: Vec<BlsSigner> = (0..COMMITTEE_SIZE)
+        .map(|i| BlsSigner::from_seed(&[0x10 + i as u8; 32]))
+        .collect::<Result<_, _>>()?;
+    let committee = bls_committee(&bls_signers.iter().map(|s| hex::encode(s.public_key())).collect::<Vec<_>>());
+
+    let mut vectors = Vec::new();
+    for (name, message) in messages() {
+        let bytes = message.to_bytes()?;
+        let canonical = message.canonical_bytes()?;
+        let hash: [u8; 32] = Blake2b256::digest(&canonical).into();
+        let ed25519 = ed25519_keys
+            .iter()
+            .map(|key| Ed25519Vector {
+                public_key: hex::encode(key.public.to_bytes()),
+                signature: hex::encode(key.sign(&hash).to_bytes()),
+            })
+            .collect();
+        let signatures: Vec<(usize, Vec<u8>)> = (0..COMMITTEE_SIZE)
+            .step_by(2)
+            .map(|i| (i, bls_signers[i].sign(&hash)))
+            .collect();
+        let proof = AggregatedProof::build(&committee, &signatures)?;
+        vectors.push(TestVector {
+            name: name.to_string(),
+            message,
+            message_bytes: hex::encode(&bytes),
+            canonical_bytes: hex::encode(&canonical),
+            message_hash: hex::encode(hash),
+            ed25519,
+            bls: Some(BlsVector {
+                public_keys: committee.iter().filter_map(|v| v.bls_public_key.clone()).collect(),
+                signers: proof.signers(),
+                aggregated_public_key: hex::encode(proof.aggregated_public_key(&committee)?),
+                signature: hex::encode(&proof.signature),
+                proof: hex::encode(proof.to_bytes()?),
+            }),
+        });
+    }
+    Ok(VectorFile { version: VECTOR_FORMAT_VERSION, source: "rust".to_string(), vectors })
+}
+
+/// 覆盖转账负载、边界 nonce 与非 ASCII 负载的消息
+fn messages() -> Vec<(&'static str, MessageFields)> {
+    let mut transfer = vec![0xab; 32];
+    transfer.extend_from_slice(&1_000_000u64.to_le_bytes());
+    vec![
+        ("asset_transfer", MessageFields {
+            message_type: MESSAGE_TYPE_ASSET,
+            source_chain: 1,
+            target_chain: 2,
+            nonce: 1,
+            payload: hex::encode(transfer),
+        }),
+        ("max_nonce", MessageFields {
+            message_type: MESSAGE_TYPE_ASSET,
+            source_chain: 2,
+            target_chain: 1,
+            nonce: u64::MAX,
+            payload: hex::encode(b"move-bridge"),
+        }),
+        ("binary_payload", MessageFields {
+            message_type: MESSAGE_TYPE_ASSET,
+            source_chain: 0x0102_0304_0506_0708,
+            target_chain: 3,
+            nonce: 256,
+            payload: hex::encode([0x00, 0xff, 0x80, 0x7f]),
+        }),
+    ]
+}
+
+/// 校验向量文件：重新计算编码与哈希，并验证其中的全部签名与聚合证明
+///
+/// 任一向量不一致时返回带向量名的错误。
+pub fn verify(file: &VectorFile) -> Result<(), Error> {
+    for vector in &file.vectors {
+        verify_vector(vector).map_err(|e| Error::Serialization(format!("Vector {} ({}): {}", vector.name, file.source, e)))?;
+    }
+    Ok(())
+}
+
+fn verify_vector(vector: &TestVector) -> Result<(), Error> {
+    let bytes = vector.message.to_bytes()?;
+    if hex::encode(&bytes) != vector.message_bytes.to_lowercase() {
+        return Err(Error::Serialization(format!("message bytes mismatch, computed {}", hex::encode(&bytes))));
+    }
+    let canonical = vector.message.canonical_bytes()?;
+    if hex::encode(&canonical) != vector.canonical_bytes.to_lowercase() {
+        return Err(Error::Serialization(format!("canonical bytes mismatch, computed {}", hex::encode(&canonical))));
+    }
+    let hash: [u8; 32] = Blake2b256::digest(&canonical).into();
+    if hex::encode(hash) != vector.message_hash.to_lowercase() {
+        return Err(Error::Serialization(format!("message hash mismatch, computed {}", hex::encode(hash))));
+    }
+
+    for signature in &vector.ed25519 {
+        verify_signature(
+            &decode_hex("ed25519 public key", &signature.public_key)?,
+            &hash,
+            &decode_hex("ed25519 signature", &signature.signature)?,
+        )?;
+    }
+
+    if let Some(proof) = &vector.bls {
+        let committee = bls_committee(&proof.public_keys);
+        let decoded = AggregatedProof::from_bytes(&decode_hex("BLS proof", &proof.proof)?)?;
+        let aggregated_public_key = decoded.aggregated_public_key(&committee)?;
+        if decoded.signers() != proof.signers
+            || hex::encode(&aggregated_public_key) != proof.aggregated_public_key.to_lowercase()
+            || hex::encode(&decoded.signature) != proof.signature.to_lowercase()
+        {
+            return Err(Error::Serialization("BLS proof encoding does not match its fields".to_string()));
+        }
+        if !decoded.verify(&committee, &hash)? {
+            return Err(Error::Key("BLS aggregated signature verification failed".to_string()));
+        }
+        if !bls::verify(&aggregated_public_key, &hash, &decoded.signature)? {
+            return Err(Error::Key("BLS signature does not verify against aggregated public key".to_string()));
+        }
+    }
+    Ok(())
+}
+
+/// 以向量中的 BLS 公钥构造等权重的验证者集合
+fn bls_committee(public_keys: &[String]) -> Vec<ValidatorConfig> {
+    public_keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| ValidatorConfig {
+            address: format!("0x{:x}", i + 1),
+            public_key: String::new(),
+            bls_public_key: Some(key.clone()),
+            weight: 1,
+            chains: vec![],
+        })
+        .collect()
+}
+
+fn decode_hex(what: &str, value: &str) -> Result<Vec<u8>, Error> {
+    hex::decode(value.trim_start_matches("0x")).map_err(|e| Error::Serialization(format!("Invalid {} hex: {}", what, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Rust 生成、供 Move 测试使用的向量
+    const RUST_VECTORS: &str = include_str!("../../../sui_bridge/tests/vectors/rust.json");
+    /// `sui_bridge::message_tests::test_print_message_vectors` 输出的向量
+    const MOVE_VECTORS: &str = include_str!("../../../sui_bridge/tests/vectors/move.json");
+    const MOVE_TESTS: &str = include_str!("../../../sui_bridge/tests/vector_tests.move");
+
+    #[test]
+    fn test_shared_vectors() {
+        let generated = generate().unwrap();
+        verify(&generated).unwrap();
+        assert_eq!(VectorFile::from_json(RUST_VECTORS).unwrap(), generated, "run `bridge-cli vectors generate` to refresh fixtures");
+        assert_eq!(render_move_tests(&generated), MOVE_TESTS);
+
+        let move_vectors = VectorFile::from_json(MOVE_VECTORS).unwrap();
+        assert_eq!(move_vectors.source, "move");
+        verify(&move_vectors).unwrap();
+
+        let mut tampered = generated;
+        tampered.vectors[0].message.nonce += 1;
+        let err = verify(&tampered).unwrap_err().to_string();
+        assert!(err.contains("asset_transfer") && err.contains("message bytes mismatch"), "{}", err);
+    }
+}
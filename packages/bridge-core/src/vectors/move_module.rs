@@ -0,0 +1,44 @@
+use std::fmt::Write;
+use super::VectorFile;
+
+/// 生成 `sui_bridge::vector_tests` 测试模块
+///
+/// 每个向量对应一个测试：构造消息后断言 `serialize_message`、`canonical_bytes` 与 `get_message_id`
+/// 的结果与向量一致，并用 Sui 原生函数验证向量中的 ed25519 签名与 BLS 聚合签名。
+pub fn render_move_tests(file: &VectorFile) -> String {
+    let mut out = String::new();
+    out.push_str("/// 跨实现签名向量测试\n");
+    out.push_str("/// 由 `bridge-cli vectors generate` 根据 tests/vectors/rust.json 生成，请勿手工修改\n");
+    out.push_str("#[test_only]\nmodule sui_bridge::vector_tests {\n");
+    out.push_str("    use sui::bls12381;\n    use sui::ed25519;\n    use sui_bridge::message;\n");
+
+    for vector in &file.vectors {
+        let m = &vector.message;
+        let _ = write!(
+            out,
+            "\n    #[test]\n    fun test_{}() {{\n        let msg = message::new_message_for_testing({}, {}, {}, {}, x\"{}\");\n",
+            vector.name, m.message_type, m.source_chain, m.target_chain, m.nonce, m.payload,
+        );
+        let _ = writeln!(out, "        let message_hash = x\"{}\";", vector.message_hash);
+        let _ = writeln!(out, "        assert!(message::serialize_message(&msg) == x\"{}\", 0);", vector.message_bytes);
+        let _ = writeln!(out, "        assert!(message::canonical_bytes(&msg) == x\"{}\", 4);", vector.canonical_bytes);
+        out.push_str("        assert!(message::get_message_id(&msg) == message_hash, 1);\n");
+        for signature in &vector.ed25519 {
+            let _ = writeln!(
+                out,
+                "        assert!(ed25519::ed25519_verify(&x\"{}\", &x\"{}\", &message_hash), 2);",
+                signature.signature, signature.public_key,
+            );
+        }
+        if let Some(bls) = &vector.bls {
+            let _ = writeln!(
+                out,
+                "        assert!(bls12381::bls12381_min_pk_verify(&x\"{}\", &x\"{}\", &message_hash), 3);",
+                bls.signature, bls.aggregated_public_key,
+            );
+        }
+        out.push_str("    }\n");
+    }
+    out.push_str("}\n");
+    out
+}
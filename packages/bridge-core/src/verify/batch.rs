@@ -0,0 +1,116 @@
+use ed25519_dalek::{PublicKey, Signature, Verifier};
+use rayon::prelude::*;
+use crate::Error;
+
+/// 每批验证的签名数，各批在线程池中并行验证
+pub const BATCH_SIZE: usize = 64;
+
+/// 一条待验证的 ed25519 签名
+#[derive(Debug, Clone, Copy)]
+pub struct SignatureCheck<'a> {
+    pub public_key: &'a [u8],
+    pub message: &'a [u8],
+    pub signature: &'a [u8],
+}
+
+struct Parsed<'a> {
+    index: usize,
+    public_key: PublicKey,
+    message: &'a [u8],
+    signature: Signature,
+}
+
+/// 批量验证 ed25519 签名，按输入顺序返回每条签名的结果
+///
+/// 签名按 [`BATCH_SIZE`] 分批并行做批量验证；某批验证失败时，逐条验证该批签名以找出无效的签名。
+/// 公钥或签名格式错误的条目不参与批量验证，直接返回错误。
+pub fn verify(checks: &[SignatureCheck<'_>]) -> Vec<Result<(), Error>> {
+    let mut results: Vec<Result<(), Error>> = Vec::with_capacity(checks.len());
+    let mut parsed = Vec::with_capacity(checks.len());
+    for (index, check) in checks.iter().enumerate() {
+        match parse(index, check) {
+            Ok(item) => {
+                parsed.push(item);
+                results.push(Ok(()));
+            }
+            Err(e) => results.push(Err(e)),
+        }
+    }
+
+    let failures: Vec<(usize, Error)> = parsed
+        .par_chunks(BATCH_SIZE)
+        .map(verify_chunk)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .flatten()
+        .collect();
+    for (index, error) in failures {
+        results[index] = Err(error);
+    }
+    results
+}
+
+fn parse<'a>(index: usize, check: &SignatureCheck<'a>) -> Result<Parsed<'a>, Error> {
+    let public_key = PublicKey::from_bytes(check.public_key)
+        .map_err(|e| Error::Key(format!("Invalid public key: {}", e)))?;
+    let signature = Signature::try_from(check.signature)
+        .map_err(|e| Error::Key(format!("Invalid signature: {}", e)))?;
+    Ok(Parsed { index, public_key, message: check.message, signature })
+}
+
+/// 验证一批签名，返回无效签名的下标与错误
+fn verify_chunk(chunk: &[Parsed<'_>]) -> Vec<(usize, Error)> {
+    if chunk.len() > 1 {
+        let messages: Vec<&[u8]> = chunk.iter().map(|item| item.message).collect();
+        let signatures: Vec<Signature> = chunk.iter().map(|item| item.signature).collect();
+        let public_keys: Vec<PublicKey> = chunk.iter().map(|item| item.public_key).collect();
+        if ed25519_dalek::verify_batch(&messages, &signatures, &public_keys).is_ok() {
+            return Vec::new();
+        }
+    }
+    chunk
+        .par_iter()
+        .filter_map(|item| {
+            item.public_key
+                .verify(item.message, &item.signature)
+                .err()
+                .map(|e| (item.index, Error::Key(format!("Signature verification failed: {}", e))))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Keypair, SecretKey, Signer};
+
+    #[test]
+    fn test_batch_identifies_bad_signature() {
+        let keypairs: Vec<Keypair> = (1..=130u8)
+            .map(|i| {
+                let secret = SecretKey::from_bytes(&[i; 32]).unwrap();
+                Keypair { public: PublicKey::from(&secret), secret }
+            })
+            .collect();
+        let messages: Vec<Vec<u8>> = (0..keypairs.len()).map(|i| format!("message {}", i).into_bytes()).collect();
+        let mut signatures: Vec<Vec<u8>> = keypairs
+            .iter()
+            .zip(&messages)
+            .map(|(keypair, message)| keypair.sign(message).to_bytes().to_vec())
+            .collect();
+        // 第二批中的一条签名换成其它消息的签名，最后一条签名长度错误
+        signatures[70] = signatures[71].clone();
+        signatures[129].truncate(10);
+
+        let public_keys: Vec<[u8; 32]> = keypairs.iter().map(|k| k.public.to_bytes()).collect();
+        let checks: Vec<SignatureCheck> = (0..keypairs.len())
+            .map(|i| SignatureCheck { public_key: &public_keys[i], message: &messages[i], signature: &signatures[i] })
+            .collect();
+        let results = verify(&checks);
+        assert_eq!(results.len(), checks.len());
+        let failed: Vec<usize> = results.iter().enumerate().filter(|(_, r)| r.is_err()).map(|(i, _)| i).collect();
+        assert_eq!(failed, [70, 129]);
+        assert!(results[129].as_ref().unwrap_err().to_string().contains("Invalid signature"));
+        assert!(verify(&[]).is_empty());
+    }
+}
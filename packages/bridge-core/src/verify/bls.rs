@@ -0,0 +1,274 @@
+use blst::min_pk::{AggregatePublicKey, AggregateSignature, PublicKey, SecretKey, Signature};
+use blst::BLST_ERROR;
+use serde::{Deserialize, Serialize};
+use crate::{config::ValidatorConfig, Error};
+
+/// BLS 签名域分隔标签，与 Sui `bls12381_min_pk_verify` 使用的标签一致
+pub const BLS_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
+
+/// BLS 公钥持有证明的域分隔前缀，与合约 `validator::bls_pop_message` 一致
+pub const BLS_POP_DOMAIN: &[u8] = b"MOVE_BRIDGE_BLS_POP";
+
+/// BLS 公钥长度（压缩的 G1 点）
+pub const BLS_PUBLIC_KEY_LENGTH: usize = 48;
+/// BLS 签名长度（压缩的 G2 点）
+pub const BLS_SIGNATURE_LENGTH: usize = 96;
+
+/// BLS12-381 签名者（min_pk 方案：公钥在 G1，签名在 G2）
+pub struct BlsSigner {
+    secret: SecretKey,
+}
+
+impl BlsSigner {
+    /// 从种子派生密钥，种子长度至少 32 字节
+    pub fn from_seed(seed: &[u8]) -> Result<Self, Error> {
+        let secret = SecretKey::key_gen(seed, &[]).map_err(bls_error)?;
+        Ok(Self { secret })
+    }
+
+    /// 从 32 字节私钥创建签名者
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let secret = SecretKey::from_bytes(bytes).map_err(bls_error)?;
+        Ok(Self { secret })
+    }
+
+    /// 获取压缩公钥
+    pub fn public_key(&self) -> Vec<u8> {
+        self.secret.sk_to_pk().to_bytes().to_vec()
+    }
+
+    /// 对消息签名
+    pub fn sign(&self, message: &[u8]) -> Vec<u8> {
+        self.secret.sign(message, BLS_DST, &[]).to_bytes().to_vec()
+    }
+
+    /// 生成公钥持有证明，供验证者调用合约 `register_bls_key` 登记公钥
+    pub fn proof_of_possession(&self, validator_address: &[u8; 32]) -> Vec<u8> {
+        self.sign(&pop_message(&self.public_key(), validator_address))
+    }
+}
+
+/// 公钥持有证明的签名消息：域分隔前缀 || BLS 公钥 || 验证者地址（32 字节）
+pub fn pop_message(public_key: &[u8], validator_address: &[u8; 32]) -> Vec<u8> {
+    [BLS_POP_DOMAIN, public_key, validator_address].concat()
+}
+
+/// 验证单个 BLS 签名
+pub fn verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<bool, Error> {
+    let public_key = PublicKey::key_validate(public_key).map_err(bls_error)?;
+    let signature = Signature::from_bytes(signature).map_err(bls_error)?;
+    Ok(signature.verify(true, message, BLS_DST, &[], &public_key, false) == BLST_ERROR::BLST_SUCCESS)
+}
+
+/// 聚合多个签名
+pub fn aggregate_signatures(signatures: &[Vec<u8>]) -> Result<Vec<u8>, Error> {
+    if signatures.is_empty() {
+        return Err(Error::Key("No signatures to aggregate".to_string()));
+    }
+    let signatures = signatures
+        .iter()
+        .map(|s| Signature::from_bytes(s).map_err(bls_error))
+        .collect::<Result<Vec<_>, _>>()?;
+    let refs: Vec<&Signature> = signatures.iter().collect();
+    let aggregated = AggregateSignature::aggregate(&refs, true).map_err(bls_error)?;
+    Ok(aggregated.to_signature().to_bytes().to_vec())
+}
+
+/// 聚合多个公钥
+pub fn aggregate_public_keys(public_keys: &[Vec<u8>]) -> Result<Vec<u8>, Error> {
+    if public_keys.is_empty() {
+        return Err(Error::Key("No public keys to aggregate".to_string()));
+    }
+    let public_keys = public_keys
+        .iter()
+        .map(|k| PublicKey::key_validate(k).map_err(bls_error))
+        .collect::<Result<Vec<_>, _>>()?;
+    let refs: Vec<&PublicKey> = public_keys.iter().collect();
+    let aggregated = AggregatePublicKey::aggregate(&refs, false).map_err(bls_error)?;
+    Ok(aggregated.to_public_key().to_bytes().to_vec())
+}
+
+/// 验证者委员会的聚合签名证明
+///
+/// 链上合约 `verify_aggregated_signature` 只接收 `signers_bitmap` 与 `signature`：
+/// 按位图从验证者集合中选出签名者，用其经持有证明登记的 BLS 公钥在链上计算聚合公钥，
+/// 要求签名者权重达到阈值后再做一次 `bls12381_min_pk_verify`。
+/// 证明同样只包含这两个字段，聚合公钥由 [`AggregatedProof::aggregated_public_key`] 按验证者集合计算。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AggregatedProof {
+    /// 签名者位图，第 i 位对应验证者集合中的第 i 个验证者，
+    /// 验证者顺序须与链上 `get_active_validators` 的顺序（加入顺序）一致
+    pub signers_bitmap: Vec<u8>,
+    /// 聚合签名（96 字节）
+    pub signature: Vec<u8>,
+}
+
+impl AggregatedProof {
+    /// 根据验证者集合及各验证者的签名（验证者下标, 签名）构建聚合证明
+    pub fn build(validators: &[ValidatorConfig], signatures: &[(usize, Vec<u8>)]) -> Result<Self, Error> {
+        let mut signers_bitmap = vec![0u8; validators.len().div_ceil(8)];
+        let mut raw_signatures = Vec::with_capacity(signatures.len());
+
+        for (index, signature) in signatures {
+            let validator = validators
+                .get(*index)
+                .ok_or_else(|| Error::Key(format!("Validator index out of range: {}", index)))?;
+            if signers_bitmap[index / 8] & (1 << (index % 8)) != 0 {
+                return Err(Error::Key(format!("Duplicate signature from validator: {}", validator.address)));
+            }
+            signers_bitmap[index / 8] |= 1 << (index % 8);
+            bls_public_key(validator)?;
+            raw_signatures.push(signature.clone());
+        }
+
+        Ok(Self {
+            signers_bitmap,
+            signature: aggregate_signatures(&raw_signatures)?,
+        })
+    }
+
+    /// 签名者在验证者集合中的下标
+    pub fn signers(&self) -> Vec<usize> {
+        (0..self.signers_bitmap.len() * 8)
+            .filter(|i| self.signers_bitmap[i / 8] & (1 << (i % 8)) != 0)
+            .collect()
+    }
+
+    /// 计算参与签名的验证者总权重
+    pub fn signed_weight(&self, validators: &[ValidatorConfig]) -> u64 {
+        self.signers()
+            .into_iter()
+            .filter_map(|i| validators.get(i))
+            .map(|v| v.weight)
+            .sum()
+    }
+
+    /// 按位图选出的签名者公钥计算聚合公钥（48 字节），与合约在链上的计算一致
+    pub fn aggregated_public_key(&self, validators: &[ValidatorConfig]) -> Result<Vec<u8>, Error> {
+        let signers = self.signers();
+        if signers.iter().any(|i| *i >= validators.len()) {
+            return Err(Error::Key("Signer bitmap exceeds validator set".to_string()));
+        }
+        let public_keys = signers
+            .iter()
+            .map(|i| bls_public_key(&validators[*i]))
+            .collect::<Result<Vec<_>, _>>()?;
+        aggregate_public_keys(&public_keys)
+    }
+
+    /// 验证聚合证明：按验证者集合计算聚合公钥并验证聚合签名
+    pub fn verify(&self, validators: &[ValidatorConfig], message: &[u8]) -> Result<bool, Error> {
+        verify(&self.aggregated_public_key(validators)?, message, &self.signature)
+    }
+
+    /// 编码为 BCS 字节，依次为 `verify_aggregated_signature` 的 `signers_bitmap` 与 `signature` 参数
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        bcs::to_bytes(self).map_err(|e| Error::Serialization(e.to_string()))
+    }
+
+    /// 从 BCS 字节解码
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        bcs::from_bytes(bytes).map_err(|e| Error::Serialization(e.to_string()))
+    }
+}
+
+fn bls_public_key(validator: &ValidatorConfig) -> Result<Vec<u8>, Error> {
+    let key = validator
+        .bls_public_key
+        .as_ref()
+        .ok_or_else(|| Error::Key(format!("Validator {} has no BLS public key", validator.address)))?;
+    let bytes = hex::decode(key.trim_start_matches("0x"))
+        .map_err(|e| Error::Key(format!("Invalid BLS public key for {}: {}", validator.address, e)))?;
+    if bytes.len() != BLS_PUBLIC_KEY_LENGTH {
+        return Err(Error::Key(format!("Invalid BLS public key length for {}", validator.address)));
+    }
+    Ok(bytes)
+}
+
+fn bls_error(e: BLST_ERROR) -> Error {
+    Error::Key(format!("BLS error: {:?}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn committee(size: usize) -> (Vec<BlsSigner>, Vec<ValidatorConfig>) {
+        let signers: Vec<_> = (0..size)
+            .map(|i| BlsSigner::from_seed(&[i as u8 + 1; 32]).unwrap())
+            .collect();
+        let validators = signers
+            .iter()
+            .enumerate()
+            .map(|(i, signer)| ValidatorConfig {
+                address: format!("0x{}", i),
+                public_key: hex::encode([i as u8; 32]),
+                bls_public_key: Some(hex::encode(signer.public_key())),
+                weight: 1,
+                chains: vec![],
+            })
+            .collect();
+        (signers, validators)
+    }
+
+    #[test]
+    fn test_aggregated_proof_roundtrip() {
+        let (signers, validators) = committee(3);
+        let message = b"cross chain message";
+        let signatures: Vec<_> = [0usize, 2]
+            .iter()
+            .map(|i| (*i, signers[*i].sign(message)))
+            .collect();
+
+        let proof = AggregatedProof::build(&validators, &signatures).unwrap();
+        assert_eq!(proof.signers(), vec![0, 2]);
+        assert_eq!(proof.signed_weight(&validators), 2);
+        assert_eq!(proof.signature.len(), BLS_SIGNATURE_LENGTH);
+
+        let decoded = AggregatedProof::from_bytes(&proof.to_bytes().unwrap()).unwrap();
+        assert!(decoded.verify(&validators, message).unwrap());
+        assert!(!decoded.verify(&validators, b"other message").unwrap());
+    }
+
+    #[test]
+    fn test_proof_encodes_contract_arguments() {
+        let (signers, validators) = committee(9);
+        let message = b"cross chain message";
+        let signatures: Vec<_> = [1usize, 8].iter().map(|i| (*i, signers[*i].sign(message))).collect();
+        let proof = AggregatedProof::build(&validators, &signatures).unwrap();
+
+        // 两个 `vector<u8>` 参数依次编码：ULEB128 长度后接内容，合约要求位图长度为验证者数按 8 位向上取整
+        let mut expected = vec![2u8, 0b0000_0010, 0b0000_0001, BLS_SIGNATURE_LENGTH as u8];
+        expected.extend_from_slice(&proof.signature);
+        let bytes = proof.to_bytes().unwrap();
+        assert_eq!(bytes, expected);
+
+        let decoded = AggregatedProof::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, proof);
+        assert_eq!(decoded.signers(), vec![1, 8]);
+        let public_keys: Vec<_> = [1, 8].iter().map(|i| signers[*i].public_key()).collect();
+        assert_eq!(decoded.aggregated_public_key(&validators).unwrap(), aggregate_public_keys(&public_keys).unwrap());
+        assert!(verify(&decoded.aggregated_public_key(&validators).unwrap(), message, &decoded.signature).unwrap());
+    }
+
+    #[test]
+    fn test_proof_of_possession() {
+        let (signers, _) = committee(2);
+        let address = [7u8; 32];
+        let pop = signers[0].proof_of_possession(&address);
+        let message = pop_message(&signers[0].public_key(), &address);
+        assert!(verify(&signers[0].public_key(), &message, &pop).unwrap());
+        assert!(!verify(&signers[1].public_key(), &pop_message(&signers[1].public_key(), &address), &pop).unwrap());
+    }
+
+    #[test]
+    fn test_tampered_bitmap_fails() {
+        let (signers, validators) = committee(3);
+        let message = b"cross chain message";
+        let signatures = vec![(0, signers[0].sign(message)), (1, signers[1].sign(message))];
+
+        let mut proof = AggregatedProof::build(&validators, &signatures).unwrap();
+        proof.signers_bitmap[0] |= 0b100;
+        assert!(!proof.verify(&validators, message).unwrap());
+    }
+}
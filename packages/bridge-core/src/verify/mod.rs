@@ -1,178 +1,235 @@
-use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signature, Signer, Verifier};
-use rand::rngs::OsRng;
-use blake2::{Blake2b512, Digest};
+use ed25519_dalek::{PublicKey, Signature, Verifier};
+use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 use tracing::{info, warn, error, debug};
 use crate::{
-    types::{CrossChainMessage, SignedMessage, ChainType},
-    Result, BridgeError,
+    chain_adapter::ChainAdapter,
+    signer::{LocalSigner, Signer},
+    types::{ChainId, ChainRegistry, CrossChainMessage, SignedMessage},
+    Error,
 };
 
+pub mod bls;
+pub mod batch;
+
 const MAX_MESSAGE_AGE: u64 = 3600; // 消息最大有效期（1小时）
 const MIN_NONCE: u64 = 1; // 最小nonce值
 
+/// 检查消息的源链与目标链都已在登记表中
+///
+/// 中继器与验证器使用同一登记表校验链 ID，两条校验路径的结论保持一致。
+pub fn check_route(chains: &ChainRegistry, message: &CrossChainMessage) -> Result<(), Error> {
+    if !chains.contains(&message.source_chain) {
+        return Err(Error::InvalidMessage(format!("Invalid source chain: {}", message.source_chain)));
+    }
+    if !chains.contains(&message.target_chain) {
+        return Err(Error::InvalidMessage(format!("Invalid target chain: {}", message.target_chain)));
+    }
+    Ok(())
+}
+
+/// 按 `(源链, 目标链)` 记录已处理的最大 nonce
+///
+/// 本地状态在重启后为空，启动时通过 `reconcile` 从各目标链合约恢复，
+/// 合约已消费的消息不会在重启后被再次签名。
+#[derive(Debug, Clone, Default)]
+pub struct NonceState {
+    last: HashMap<(ChainId, ChainId), u64>,
+}
+
+impl NonceState {
+    /// 链对已处理的最大 nonce
+    pub fn last(&self, source_chain: &ChainId, target_chain: &ChainId) -> Option<u64> {
+        self.last.get(&(source_chain.clone(), target_chain.clone())).copied()
+    }
+
+    /// 消息的 nonce 不大于链对已处理的最大 nonce
+    pub fn is_consumed(&self, message: &CrossChainMessage) -> bool {
+        self.last(&message.source_chain, &message.target_chain)
+            .is_some_and(|last| message.nonce <= last)
+    }
+
+    /// 记录已处理的消息，只会前移
+    pub fn record(&mut self, source_chain: &ChainId, target_chain: &ChainId, nonce: u64) {
+        let last = self.last.entry((source_chain.clone(), target_chain.clone())).or_insert(nonce);
+        *last = (*last).max(nonce);
+    }
+
+    /// 从各目标链合约读取来自其它链的已处理 nonce 并合并到本地状态
+    ///
+    /// 读取失败时返回错误：无法确认链上状态时不应开始签名。
+    pub async fn reconcile(&mut self, adapters: &HashMap<ChainId, Box<dyn ChainAdapter>>) -> Result<(), Error> {
+        for (target_chain, adapter) in adapters {
+            for source_chain in adapters.keys().filter(|id| *id != target_chain) {
+                let Some(nonce) = adapter.last_processed_nonce(source_chain).await.inspect_err(|e| {
+                    error!("Failed to read processed nonce for {} -> {}: {}", source_chain, target_chain, e)
+                })?
+                else {
+                    continue;
+                };
+                if self.last(source_chain, target_chain).is_none_or(|last| last < nonce) {
+                    info!("Reconciled nonce for {} -> {} to on-chain value {}", source_chain, target_chain, nonce);
+                }
+                self.record(source_chain, target_chain, nonce);
+            }
+        }
+        Ok(())
+    }
+}
+
 /// 验证器配置
 #[derive(Debug, Clone)]
 pub struct VerifierConfig {
     pub max_message_age: u64,
-    pub allowed_source_chains: Vec<ChainType>,
-    pub allowed_target_chains: Vec<ChainType>,
+    pub allowed_source_chains: Vec<ChainId>,
+    pub allowed_target_chains: Vec<ChainId>,
+    /// 规范哈希使用的数字链 ID
+    pub chains: ChainRegistry,
 }
 
-impl Default for VerifierConfig {
-    fn default() -> Self {
+impl VerifierConfig {
+    /// 允许登记表中的全部链作为源链和目标链
+    pub fn new(chains: &ChainRegistry) -> Self {
+        let ids: Vec<ChainId> = chains.ids().cloned().collect();
         Self {
             max_message_age: MAX_MESSAGE_AGE,
-            allowed_source_chains: vec![ChainType::Sui, ChainType::Rooch],
-            allowed_target_chains: vec![ChainType::Sui, ChainType::Rooch],
+            allowed_source_chains: ids.clone(),
+            allowed_target_chains: ids,
+            chains: chains.clone(),
         }
     }
 }
 
 /// 验证器结构体
 pub struct MessageVerifier {
-    keypair: Keypair,
+    signer: Arc<dyn Signer>,
     config: VerifierConfig,
-    last_processed_nonce: u64,
+    nonces: NonceState,
 }
 
 impl MessageVerifier {
-    /// 创建新的验证器实例
-    pub fn new() -> Self {
-        info!("Creating new message verifier instance");
-        let mut csprng = OsRng {};
-        let keypair: Keypair = Keypair::generate(&mut csprng);
-        Self { 
-            keypair,
-            config: VerifierConfig::default(),
-            last_processed_nonce: 0,
+    /// 使用任意签名后端创建验证器
+    pub fn new(signer: Arc<dyn Signer>, config: VerifierConfig) -> Self {
+        debug!("Creating verifier with {} signer", signer.backend());
+        Self {
+            signer,
+            config,
+            nonces: NonceState::default(),
         }
     }
 
-    /// 从已有的密钥对创建验证器
-    pub fn from_keypair(secret_key: &[u8], config: Option<VerifierConfig>) -> Result<Self> {
-        debug!("Creating verifier from existing keypair");
-        let secret = SecretKey::from_bytes(secret_key)
-            .map_err(|e| {
-                error!("Failed to create secret key: {}", e);
-                BridgeError::ValidationError(format!("Invalid secret key: {}", e))
-            })?;
-        let public = PublicKey::from(&secret);
-        let keypair = Keypair {
-            secret,
-            public,
-        };
-        Ok(Self { 
-            keypair,
-            config: config.unwrap_or_default(),
-            last_processed_nonce: 0,
-        })
+    /// 从本地私钥创建验证器
+    pub fn from_keypair(secret_key: &[u8], config: VerifierConfig) -> Result<Self, Error> {
+        let signer = LocalSigner::from_bytes(secret_key).inspect_err(|e| error!("Failed to create secret key: {}", e))?;
+        Ok(Self::new(Arc::new(signer), config))
     }
 
     /// 获取公钥
     pub fn public_key(&self) -> [u8; 32] {
-        self.keypair.public.to_bytes()
+        self.signer.public_key()
+    }
+
+    /// 启动时按各目标链合约的已处理 nonce 恢复防重放状态
+    pub async fn reconcile_nonces(&mut self, adapters: &HashMap<ChainId, Box<dyn ChainAdapter>>) -> Result<(), Error> {
+        self.nonces.reconcile(adapters).await
     }
 
     /// 验证消息的基本属性
-    fn validate_message_properties(&self, message: &CrossChainMessage) -> Result<()> {
-        // 验证链类型
+    fn validate_message_properties(&self, message: &CrossChainMessage) -> Result<(), Error> {
+        // 验证链 ID
         if !self.config.allowed_source_chains.contains(&message.source_chain) {
-            warn!("Invalid source chain: {:?}", message.source_chain);
-            return Err(BridgeError::ValidationError("Invalid source chain".to_string()));
+            warn!("Invalid source chain: {}", message.source_chain);
+            return Err(Error::InvalidMessage(format!("Invalid source chain: {}", message.source_chain)));
         }
         if !self.config.allowed_target_chains.contains(&message.target_chain) {
-            warn!("Invalid target chain: {:?}", message.target_chain);
-            return Err(BridgeError::ValidationError("Invalid target chain".to_string()));
+            warn!("Invalid target chain: {}", message.target_chain);
+            return Err(Error::InvalidMessage(format!("Invalid target chain: {}", message.target_chain)));
         }
 
         // 验证nonce
-        if message.nonce <= self.last_processed_nonce || message.nonce < MIN_NONCE {
+        if self.nonces.is_consumed(message) || message.nonce < MIN_NONCE {
             warn!("Invalid nonce: {}", message.nonce);
-            return Err(BridgeError::ValidationError("Invalid nonce".to_string()));
+            return Err(Error::InvalidMessage(format!("Invalid nonce: {}", message.nonce)));
         }
 
         Ok(())
     }
 
     /// 对消息进行签名
-    pub fn sign_message(&self, message: CrossChainMessage) -> Result<SignedMessage> {
+    pub async fn sign_message(&self, message: CrossChainMessage) -> Result<SignedMessage, Error> {
         debug!("Signing message with nonce: {}", message.nonce);
-        
+
         // 验证消息属性
         self.validate_message_properties(&message)?;
-        
-        // 序列化消息
-        let message_bytes = serde_json::to_vec(&message)
-            .map_err(|e| {
-                error!("Failed to serialize message: {}", e);
-                BridgeError::SerializationError(e.to_string())
-            })?;
-        
-        // 计算消息哈希
-        let mut hasher = Blake2b512::new();
-        hasher.update(&message_bytes);
-        let message_hash = hasher.finalize();
-        
-        // 签名消息哈希
-        let signature = self.keypair.sign(&message_hash).to_bytes().to_vec();
-        
-        let signed_message = SignedMessage::new(message, signature);
+
+        // 对规范哈希签名，与验证者证明一致
+        let signature = self.signer.sign_message(&message, &self.config.chains).await?;
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| Error::Chain(format!("Failed to get current time: {}", e)))?
+            .as_secs();
+
+        let signed_message = SignedMessage {
+            message,
+            signature,
+            timestamp,
+            event_name: None,
+            sender: None,
+            provenance: None,
+            proof: None,
+            trace_id: None,
+        };
         info!("Message signed successfully, nonce: {}", signed_message.message.nonce);
-        
+
         Ok(signed_message)
     }
 
     /// 批量签名消息
-    pub fn sign_messages(&self, messages: Vec<CrossChainMessage>) -> Vec<Result<SignedMessage>> {
+    pub async fn sign_messages(&self, messages: Vec<CrossChainMessage>) -> Vec<Result<SignedMessage, Error>> {
         info!("Batch signing {} messages", messages.len());
-        messages.into_iter()
-            .map(|msg| self.sign_message(msg))
-            .collect()
+        let mut results = Vec::with_capacity(messages.len());
+        for message in messages {
+            results.push(self.sign_message(message).await);
+        }
+        results
     }
 
     /// 验证签名消息
-    pub fn verify_message(&mut self, signed_message: &SignedMessage) -> Result<bool> {
+    pub fn verify_message(&mut self, signed_message: &SignedMessage) -> Result<bool, Error> {
         debug!("Verifying message with nonce: {}", signed_message.message.nonce);
-        
+
         // 验证消息时间戳
         let current_time = SystemTime::now()
             .duration_since(UNIX_EPOCH)
-            .unwrap()
+            .map_err(|e| Error::Chain(format!("Failed to get current time: {}", e)))?
             .as_secs();
-            
-        if current_time - signed_message.timestamp > self.config.max_message_age {
+
+        if current_time.saturating_sub(signed_message.timestamp) > self.config.max_message_age {
             warn!("Message expired: timestamp={}", signed_message.timestamp);
             return Ok(false);
         }
 
         // 验证消息属性
         self.validate_message_properties(&signed_message.message)?;
-        
-        // 序列化原始消息
-        let message_bytes = serde_json::to_vec(&signed_message.message)
-            .map_err(|e| {
-                error!("Failed to serialize message: {}", e);
-                BridgeError::SerializationError(e.to_string())
-            })?;
-        
-        // 计算消息哈希
-        let mut hasher = Blake2b512::new();
-        hasher.update(&message_bytes);
-        let message_hash = hasher.finalize();
-        
+
+        let message_hash = signed_message.message.canonical_hash(&self.config.chains)?;
+
         // 解析签名
         let signature = Signature::from_bytes(&signed_message.signature)
             .map_err(|e| {
                 error!("Invalid signature format: {}", e);
-                BridgeError::ValidationError(format!("Invalid signature: {}", e))
+                Error::Key(format!("Invalid signature: {}", e))
             })?;
-        
+
         // 验证签名
-        match self.keypair.public.verify(&message_hash, &signature) {
+        let public_key = PublicKey::from_bytes(&self.signer.public_key())
+            .map_err(|e| Error::Key(format!("Invalid public key: {}", e)))?;
+        match public_key.verify(&message_hash, &signature) {
             Ok(_) => {
                 info!("Message verified successfully, nonce: {}", signed_message.message.nonce);
-                self.last_processed_nonce = signed_message.message.nonce;
+                let message = &signed_message.message;
+                self.nonces.record(&message.source_chain, &message.target_chain, message.nonce);
                 Ok(true)
             }
             Err(e) => {
@@ -183,7 +240,7 @@ impl MessageVerifier {
     }
 
     /// 批量验证消息
-    pub fn verify_messages(&mut self, messages: Vec<SignedMessage>) -> Vec<Result<bool>> {
+    pub fn verify_messages(&mut self, messages: Vec<SignedMessage>) -> Vec<Result<bool, Error>> {
         info!("Batch verifying {} messages", messages.len());
         messages.iter()
             .map(|msg| self.verify_message(msg))
@@ -205,154 +262,136 @@ impl MessageVerifier {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::MessageType;
     use std::time::Duration;
-    use std::thread;
+
+    fn registry() -> ChainRegistry {
+        let mut chains = ChainRegistry::new();
+        chains.register(ChainId::from("sui"), "sui").unwrap();
+        chains.register(ChainId::from("rooch"), "rooch").unwrap();
+        chains.register_numeric("sui", 1).unwrap();
+        chains.register_numeric("rooch", 2).unwrap();
+        chains
+    }
+
+    fn verifier(seed: u8, config: Option<VerifierConfig>) -> MessageVerifier {
+        MessageVerifier::from_keypair(&[seed; 32], config.unwrap_or_else(|| VerifierConfig::new(&registry()))).unwrap()
+    }
+
+    fn message(source: &str, target: &str, nonce: u64) -> CrossChainMessage {
+        CrossChainMessage {
+            nonce,
+            source_chain: source.into(),
+            target_chain: target.into(),
+            message_type: "transfer".to_string(),
+            payload: vec![1, 2, 3],
+        }
+    }
 
     // 基本功能测试
-    #[test]
-    fn test_message_signing_and_verification() {
-        let mut verifier = MessageVerifier::new();
-        let message = CrossChainMessage::new(
-            ChainType::Sui,
-            ChainType::Rooch,
-            MessageType::AssetTransfer,
-            1,
-            verifier.public_key(),
-            vec![1, 2, 3],
-        );
-        
-        let signed_message = verifier.sign_message(message).unwrap();
+    #[tokio::test]
+    async fn test_message_signing_and_verification() {
+        let mut verifier = verifier(1, None);
+        let signed_message = verifier.sign_message(message("sui", "rooch", 1)).await.unwrap();
         assert!(verifier.verify_message(&signed_message).unwrap());
     }
 
     // 无效签名测试
-    #[test]
-    fn test_invalid_signature() {
-        let verifier1 = MessageVerifier::new();
-        let mut verifier2 = MessageVerifier::new();
-        
-        let message = CrossChainMessage::new(
-            ChainType::Sui,
-            ChainType::Rooch,
-            MessageType::AssetTransfer,
-            1,
-            verifier1.public_key(),
-            vec![1, 2, 3],
-        );
-        let signed_message = verifier1.sign_message(message).unwrap();
-        
+    #[tokio::test]
+    async fn test_invalid_signature() {
+        let verifier1 = verifier(1, None);
+        let mut verifier2 = verifier(2, None);
+        let signed_message = verifier1.sign_message(message("sui", "rooch", 1)).await.unwrap();
         assert!(!verifier2.verify_message(&signed_message).unwrap());
     }
 
     // 消息过期测试
-    #[test]
-    fn test_message_expiration() {
-        let mut config = VerifierConfig::default();
+    #[tokio::test]
+    async fn test_message_expiration() {
+        let mut config = VerifierConfig::new(&registry());
         config.max_message_age = 0; // 立即过期
-        let mut verifier = MessageVerifier::from_keypair(
-            &[1u8; 32],
-            Some(config)
-        ).unwrap();
-        
-        let message = CrossChainMessage::new(
-            ChainType::Sui,
-            ChainType::Rooch,
-            MessageType::AssetTransfer,
-            1,
-            verifier.public_key(),
-            vec![1, 2, 3],
-        );
-        
-        let signed_message = verifier.sign_message(message).unwrap();
-        thread::sleep(Duration::from_secs(1));
+        let mut verifier = verifier(1, Some(config));
+        let signed_message = verifier.sign_message(message("sui", "rooch", 1)).await.unwrap();
+        tokio::time::sleep(Duration::from_secs(1)).await;
         assert!(!verifier.verify_message(&signed_message).unwrap());
     }
 
     // 批量操作测试
-    #[test]
-    fn test_batch_operations() {
-        let mut verifier = MessageVerifier::new();
-        let messages: Vec<_> = (1..=3)
-            .map(|i| CrossChainMessage::new(
-                ChainType::Sui,
-                ChainType::Rooch,
-                MessageType::AssetTransfer,
-                i as u64,
-                verifier.public_key(),
-                vec![i as u8],
-            ))
-            .collect();
-        
-        let signed_messages: Vec<_> = verifier.sign_messages(messages)
+    #[tokio::test]
+    async fn test_batch_operations() {
+        let mut verifier = verifier(1, None);
+        let messages: Vec<_> = (1..=3).map(|i| message("sui", "rooch", i)).collect();
+        let signed_messages: Vec<_> = verifier.sign_messages(messages).await
             .into_iter()
             .filter_map(Result::ok)
             .collect();
-            
+
         let verification_results = verifier.verify_messages(signed_messages);
         assert!(verification_results.iter().all(|r| r.as_ref().unwrap_or(&false) == &true));
     }
 
     // 配置测试
-    #[test]
-    fn test_verifier_config() {
-        let mut config = VerifierConfig::default();
-        config.allowed_source_chains = vec![ChainType::Sui];
-        config.allowed_target_chains = vec![ChainType::Rooch];
-        
-        let verifier = MessageVerifier::from_keypair(&[1u8; 32], Some(config.clone())).unwrap();
-        
-        // 测试有效配置
-        let valid_message = CrossChainMessage::new(
-            ChainType::Sui,
-            ChainType::Rooch,
-            MessageType::AssetTransfer,
-            1,
-            verifier.public_key(),
-            vec![1, 2, 3],
-        );
-        assert!(verifier.sign_message(valid_message).is_ok());
-        
-        // 测试无效配置
-        let invalid_message = CrossChainMessage::new(
-            ChainType::Rooch, // 不允许的源链
-            ChainType::Sui,   // 不允许的目标链
-            MessageType::AssetTransfer,
-            2,
-            verifier.public_key(),
-            vec![1, 2, 3],
-        );
-        assert!(verifier.sign_message(invalid_message).is_err());
+    #[tokio::test]
+    async fn test_verifier_config() {
+        let mut config = VerifierConfig::new(&registry());
+        config.allowed_source_chains = vec![ChainId::from("sui")];
+        config.allowed_target_chains = vec![ChainId::from("rooch")];
+        let verifier = verifier(1, Some(config));
+
+        assert!(verifier.sign_message(message("sui", "rooch", 1)).await.is_ok());
+        // 不允许的源链与目标链
+        assert!(verifier.sign_message(message("rooch", "sui", 2)).await.is_err());
+    }
+
+    // 中继器与验证器按同一登记表校验链 ID
+    #[tokio::test]
+    async fn test_route_check_matches_verifier() {
+        let chains = registry();
+        let verifier = verifier(1, None);
+        let unknown = message("sui", "aptos", 1);
+        assert!(check_route(&chains, &unknown).is_err());
+        assert!(verifier.sign_message(unknown).await.is_err());
+        assert!(check_route(&chains, &message("rooch", "sui", 1)).is_ok());
     }
 
     // Nonce测试
-    #[test]
-    fn test_nonce_validation() {
-        let mut verifier = MessageVerifier::new();
-        
+    #[tokio::test]
+    async fn test_nonce_validation() {
+        let mut verifier = verifier(1, None);
+
         // 测试正常nonce递增
         for i in 1..=5 {
-            let message = CrossChainMessage::new(
-                ChainType::Sui,
-                ChainType::Rooch,
-                MessageType::AssetTransfer,
-                i,
-                verifier.public_key(),
-                vec![1, 2, 3],
-            );
-            let signed = verifier.sign_message(message).unwrap();
+            let signed = verifier.sign_message(message("sui", "rooch", i)).await.unwrap();
             assert!(verifier.verify_message(&signed).unwrap());
         }
-        
+
         // 测试重复nonce
-        let message = CrossChainMessage::new(
-            ChainType::Sui,
-            ChainType::Rooch,
-            MessageType::AssetTransfer,
-            3, // 已使用的nonce
-            verifier.public_key(),
-            vec![1, 2, 3],
-        );
-        assert!(verifier.sign_message(message).is_err());
+        assert!(verifier.sign_message(message("sui", "rooch", 3)).await.is_err());
+    }
+
+    // 重启后从目标链合约恢复已处理 nonce
+    #[tokio::test]
+    async fn test_reconcile_nonces_with_chain() {
+        use crate::chain_adapter::mock::{MockChain, MockChainAdapter};
+        use crate::testing::mock_chain_config;
+
+        let sui = MockChain::new("sui");
+        let rooch = MockChain::new("rooch");
+        let mut adapters: HashMap<ChainId, Box<dyn ChainAdapter>> = HashMap::new();
+        for chain in [&sui, &rooch] {
+            let adapter = MockChainAdapter::new(chain.clone(), mock_chain_config(chain.id()));
+            adapter.connect().await.unwrap();
+            adapters.insert(chain.id().into(), Box::new(adapter));
+        }
+
+        let signed = verifier(1, None).sign_message(message("sui", "rooch", 4)).await.unwrap();
+        adapters[&ChainId::from("rooch")].submit_message(signed).await.unwrap();
+
+        let mut restarted = verifier(1, None);
+        assert!(restarted.sign_message(message("sui", "rooch", 4)).await.is_ok());
+        restarted.reconcile_nonces(&adapters).await.unwrap();
+        assert!(restarted.sign_message(message("sui", "rooch", 4)).await.is_err());
+        assert!(restarted.sign_message(message("sui", "rooch", 5)).await.is_ok());
+        // 其它链对不受影响
+        assert!(restarted.sign_message(message("rooch", "sui", 1)).await.is_ok());
     }
-} 
\ No newline at end of file
+}
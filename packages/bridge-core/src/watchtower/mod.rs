@@ -0,0 +1,617 @@
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use tokio::time::sleep;
+use tracing::{debug, error, info, warn};
+use crate::{
+    config::Config,
+    chain_adapter::ChainAdapter,
+    ledger::Transfer,
+    proof::InclusionProof,
+    relayer::{handler::HandlerRegistry, ChainAdapterFactory, DefaultChainAdapterFactory},
+    types::{ChainId, ChainRegistry, CrossChainMessage, Delivery, NumericChainIds, SignedMessage},
+    Error,
+};
+
+/// 偏差类型
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DivergenceKind {
+    /// 源链消息超时仍未在目标链交付
+    Missing,
+    /// 目标链交付找不到对应的源链消息
+    Unexpected,
+    /// 同一消息在目标链被交付多次
+    Duplicate,
+    AmountMismatch { expected: u64, actual: u64 },
+    RecipientMismatch { expected: String, actual: String },
+}
+
+impl DivergenceKind {
+    /// 挑战交易中的理由编码；消息缺失交付不是欺诈，不能挑战
+    pub fn challenge_reason(&self) -> Option<u8> {
+        match self {
+            DivergenceKind::Missing => None,
+            DivergenceKind::Unexpected => Some(1),
+            DivergenceKind::Duplicate => Some(2),
+            DivergenceKind::AmountMismatch { .. } => Some(3),
+            DivergenceKind::RecipientMismatch { .. } => Some(4),
+        }
+    }
+}
+
+/// 一条重新计算结果与链上交付不一致的记录
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Divergence {
+    #[serde(flatten)]
+    pub kind: DivergenceKind,
+    pub source_chain: ChainId,
+    pub target_chain: ChainId,
+    pub nonce: Option<u64>,
+    /// 源链消息 ID，交付无法匹配到源链消息时为 None
+    pub message_id: Option<String>,
+    /// 目标链交付事件 ID，消息未交付时为 None
+    pub delivery: Option<String>,
+    pub detected_at: u64,
+}
+
+/// 对目标链上欺诈性交付的挑战
+///
+/// 乐观模式下交付在挑战期内可被推翻：挑战附带重新计算的应交付消息，由目标链合约裁决；
+/// 源链包含证明随挑战一并输出，供链下核验。
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Challenge {
+    #[serde(flatten)]
+    pub divergence: Divergence,
+    /// 见 [`DivergenceKind::challenge_reason`]
+    pub reason: u8,
+    /// 按中继规则应当交付的消息，交付找不到源链消息时为 None
+    pub expected: Option<CrossChainMessage>,
+    pub proof: Option<InclusionProof>,
+}
+
+impl Challenge {
+    /// 偏差不能挑战时返回 None
+    pub fn new(divergence: Divergence, expected: Option<CrossChainMessage>, proof: Option<InclusionProof>) -> Option<Self> {
+        let reason = divergence.kind.challenge_reason()?;
+        Some(Self { divergence, reason, expected, proof })
+    }
+
+    /// 挑战交易携带的证据：应交付消息的规范编码，没有对应消息时为空
+    pub fn evidence(&self, chains: &impl NumericChainIds) -> Result<Vec<u8>, Error> {
+        Ok(self.expected.as_ref().map(|message| message.canonical_bytes(chains)).transpose()?.unwrap_or_default())
+    }
+}
+
+/// 按中继规则应当交付的消息
+#[derive(Debug, Clone)]
+struct Expected {
+    message_id: String,
+    recipient: Option<Vec<u8>>,
+    /// 扣除手续费后的到账金额
+    amount: Option<u64>,
+    observed_at: u64,
+    delivered: bool,
+    reported: bool,
+}
+
+/// 核对源链消息与目标链交付
+///
+/// 交付交易携带消息 ID 时按消息 ID 匹配；否则事件包含 nonce 时按 `(源链, 目标链, nonce)` 精确匹配，并比对接收者与金额；
+/// 不包含 nonce 时（例如 Rooch 的 `AssetUnlockedEvent`）按接收者与金额匹配一条尚未交付的消息。
+/// 暂时匹配不到的交付保留到超时，以容忍目标链事件先于源链事件被读取。
+pub struct Reconciler {
+    delivery_timeout: u64,
+    expected: HashMap<(ChainId, ChainId, u64), Expected>,
+    seen_deliveries: HashSet<String>,
+    /// 尚未匹配到源链消息的交付及首次观察时间
+    orphans: Vec<(Delivery, u64)>,
+    /// 已匹配到源链消息的交付及其消息 ID，等待调用方取走
+    matched: Vec<(String, Delivery)>,
+}
+
+impl Reconciler {
+    pub fn new(delivery_timeout: u64) -> Self {
+        Self {
+            delivery_timeout,
+            expected: HashMap::new(),
+            seen_deliveries: HashSet::new(),
+            orphans: Vec::new(),
+            matched: Vec::new(),
+        }
+    }
+
+    /// 记录一条应当交付的消息，重复记录被忽略
+    pub fn expect(&mut self, config: &Config, message: &CrossChainMessage, now: u64) -> Result<(), Error> {
+        let key = (message.source_chain.clone(), message.target_chain.clone(), message.nonce);
+        if self.expected.contains_key(&key) {
+            return Ok(());
+        }
+        let message_id = message.message_id(&config.chain_registry()?)?;
+        let transfer = Transfer::parse(config, &message_id, message);
+        let recipient = transfer.as_ref().map(|t| t.recipient().to_vec());
+        // 目标链按换算后的金额扣除手续费
+        let amount = match &transfer {
+            Some(t) => {
+                let scaled = t.target_amount()?.amount;
+                Some(scaled - t.asset.fee_for(scaled))
+            }
+            None => None,
+        };
+        self.expected.insert(key, Expected {
+            message_id,
+            recipient,
+            amount,
+            observed_at: now,
+            delivered: false,
+            reported: false,
+        });
+        Ok(())
+    }
+
+    /// 处理一次目标链交付，返回发现的偏差
+    pub fn observe(&mut self, delivery: Delivery, now: u64) -> Vec<Divergence> {
+        if !self.seen_deliveries.insert(delivery.event_id.clone()) {
+            return Vec::new();
+        }
+        match self.try_match(&delivery, now) {
+            Some(divergences) => divergences,
+            None => {
+                self.orphans.push((delivery, now));
+                Vec::new()
+            }
+        }
+    }
+
+    /// 重新匹配暂存的交付，并报告超时的缺失交付与无法匹配的交付
+    pub fn check(&mut self, now: u64) -> Vec<Divergence> {
+        let mut divergences = Vec::new();
+
+        for (delivery, first_seen) in std::mem::take(&mut self.orphans) {
+            match self.try_match(&delivery, now) {
+                Some(found) => divergences.extend(found),
+                None if now.saturating_sub(first_seen) >= self.delivery_timeout => {
+                    divergences.push(divergence(DivergenceKind::Unexpected, &delivery, None, now));
+                }
+                None => self.orphans.push((delivery, first_seen)),
+            }
+        }
+
+        for ((source_chain, target_chain, nonce), expected) in self.expected.iter_mut() {
+            if expected.delivered || expected.reported || now.saturating_sub(expected.observed_at) < self.delivery_timeout {
+                continue;
+            }
+            expected.reported = true;
+            divergences.push(Divergence {
+                kind: DivergenceKind::Missing,
+                source_chain: source_chain.clone(),
+                target_chain: target_chain.clone(),
+                nonce: Some(*nonce),
+                message_id: Some(expected.message_id.clone()),
+                delivery: None,
+                detected_at: now,
+            });
+        }
+        divergences
+    }
+
+    /// 取出上次调用以来匹配到源链消息的交付，重复交付不在其中
+    pub fn take_matched(&mut self) -> Vec<(String, Delivery)> {
+        std::mem::take(&mut self.matched)
+    }
+
+    fn try_match(&mut self, delivery: &Delivery, now: u64) -> Option<Vec<Divergence>> {
+        let (source, target) = (delivery.source_chain.clone(), delivery.target_chain.clone());
+        let expected = match (&delivery.message_id, delivery.nonce) {
+            // 交付交易携带消息 ID 时直接按 ID 关联
+            (Some(message_id), _) => self.expected
+                .iter_mut()
+                .find(|((s, t, _), e)| *s == source && *t == target && &e.message_id == message_id)
+                .map(|(_, e)| e)?,
+            (None, Some(nonce)) => self.expected.get_mut(&(source, target, nonce))?,
+            (None, None) => {
+                // 选择最早观察到的匹配消息，使同额转账按顺序对应
+                let key = self.expected
+                    .iter()
+                    .filter(|((s, t, _), e)| {
+                        *s == source
+                            && *t == target
+                            && !e.delivered
+                            && delivery.recipient.is_some()
+                            && e.recipient == delivery.recipient
+                            && delivery.amount.is_none_or(|a| e.amount == Some(a))
+                    })
+                    .min_by_key(|((_, _, nonce), e)| (e.observed_at, *nonce))
+                    .map(|(key, _)| key.clone())?;
+                self.expected.get_mut(&key)?
+            }
+        };
+
+        let message_id = Some(expected.message_id.clone());
+        if expected.delivered {
+            return Some(vec![divergence(DivergenceKind::Duplicate, delivery, message_id, now)]);
+        }
+        expected.delivered = true;
+        self.matched.push((expected.message_id.clone(), delivery.clone()));
+
+        let mut divergences = Vec::new();
+        if let (Some(expected_amount), Some(actual)) = (expected.amount, delivery.amount) {
+            if expected_amount != actual {
+                let kind = DivergenceKind::AmountMismatch { expected: expected_amount, actual };
+                divergences.push(divergence(kind, delivery, message_id.clone(), now));
+            }
+        }
+        if let (Some(expected_recipient), Some(actual)) = (&expected.recipient, &delivery.recipient) {
+            if expected_recipient != actual {
+                let kind = DivergenceKind::RecipientMismatch {
+                    expected: hex::encode(expected_recipient),
+                    actual: hex::encode(actual),
+                };
+                divergences.push(divergence(kind, delivery, message_id, now));
+            }
+        }
+        Some(divergences)
+    }
+}
+
+fn divergence(kind: DivergenceKind, delivery: &Delivery, message_id: Option<String>, now: u64) -> Divergence {
+    Divergence {
+        kind,
+        source_chain: delivery.source_chain.clone(),
+        target_chain: delivery.target_chain.clone(),
+        nonce: delivery.nonce,
+        message_id,
+        delivery: Some(delivery.event_id.clone()),
+        detected_at: now,
+    }
+}
+
+/// 观察者服务
+///
+/// 监听两侧链，按与中继器相同的处理器规则重新计算应当中继的消息，
+/// 再与目标链上实际发生的交付比对，供第三方审计桥运营方。
+/// 只有启用 `watchtower.challenge` 时才会提交交易：对欺诈性交付发起挑战。
+pub struct Watchtower {
+    config: Config,
+    /// 规范哈希使用的数字链 ID
+    chains: ChainRegistry,
+    adapters: HashMap<ChainId, Box<dyn ChainAdapter>>,
+    handlers: HandlerRegistry,
+    reconciler: Reconciler,
+    /// 重新计算得到的消息，按消息 ID 索引，作为挑战的证据
+    evidence: HashMap<String, SignedMessage>,
+    http: reqwest::Client,
+}
+
+impl Watchtower {
+    pub async fn new(config: Config) -> Result<Self, Error> {
+        let factory = DefaultChainAdapterFactory::new(&config);
+        Self::with_factory(config, &factory).await
+    }
+
+    /// 使用指定的适配器工厂创建，测试时可替换为模拟链
+    pub async fn with_factory(config: Config, factory: &dyn ChainAdapterFactory) -> Result<Self, Error> {
+        let handlers = HandlerRegistry::with_defaults(&config)?;
+        let mut adapters = HashMap::new();
+        for chain in &config.chains {
+            handlers.check_chain(chain)?;
+            adapters.insert(chain.id.clone(), factory.create_adapter(chain).await?);
+        }
+        let delivery_timeout = config.watchtower.clone().unwrap_or_default().delivery_timeout;
+
+        Ok(Self {
+            chains: config.chain_registry()?,
+            config,
+            adapters,
+            handlers,
+            reconciler: Reconciler::new(delivery_timeout),
+            evidence: HashMap::new(),
+            http: reqwest::Client::new(),
+        })
+    }
+
+    /// 执行一轮核对，返回新发现的偏差
+    pub async fn poll(&mut self) -> Vec<Divergence> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        let mut divergences = Vec::new();
+
+        // 先读源链再读目标链，尽量让交付在读取时已有对应的源链消息
+        for chain in &self.config.chains {
+            let Some(adapter) = self.adapters.get(&chain.id) else { continue };
+            let messages = match adapter.listen_events().await {
+                Ok(messages) => messages,
+                Err(e) => {
+                    error!("Failed to listen events on {}: {}", chain.id, e);
+                    continue;
+                }
+            };
+            for message in messages {
+                let nonce = message.message.nonce;
+                // 处理器拒绝的消息诚实的中继器也不会中继
+                match self.handlers.dispatch(chain, message).await {
+                    Ok(Some(message)) => {
+                        let recorded = self.reconciler
+                            .expect(&self.config, &message.message, now)
+                            .and_then(|_| message.message.message_id(&self.chains));
+                        match recorded {
+                            Ok(message_id) => {
+                                self.evidence.insert(message_id, message);
+                            }
+                            Err(e) => warn!("Failed to record message {} from {}: {}", nonce, chain.id, e),
+                        }
+                    }
+                    Ok(None) => {}
+                    Err(e) => debug!("Message {} from {} would not be relayed: {}", nonce, chain.id, e),
+                }
+            }
+        }
+
+        for chain in &self.config.chains {
+            let Some(adapter) = self.adapters.get(&chain.id) else { continue };
+            match adapter.listen_deliveries().await {
+                Ok(deliveries) => {
+                    for delivery in deliveries {
+                        divergences.extend(self.reconciler.observe(delivery, now));
+                    }
+                }
+                Err(e) => error!("Failed to listen deliveries on {}: {}", chain.id, e),
+            }
+        }
+
+        divergences.extend(self.reconciler.check(now));
+        divergences
+    }
+
+    /// 为偏差构造挑战，偏差不能挑战时返回 None
+    ///
+    /// 证据消息未附带包含证明时向源链适配器获取，获取失败不影响挑战。
+    pub async fn challenge(&self, divergence: &Divergence) -> Option<Challenge> {
+        divergence.kind.challenge_reason()?;
+        let evidence = divergence.message_id.as_ref().and_then(|id| self.evidence.get(id));
+        let mut proof = evidence.and_then(|message| message.proof.clone());
+        if let (None, Some(message), Some(adapter)) = (&proof, evidence, self.adapters.get(&divergence.source_chain)) {
+            proof = adapter.inclusion_proof(message)
+                .await
+                .inspect_err(|e| warn!("Failed to fetch inclusion proof for message {:?}: {}", divergence.message_id, e))
+                .ok()
+                .flatten();
+        }
+        Challenge::new(divergence.clone(), evidence.map(|message| message.message.clone()), proof)
+    }
+
+    /// 通过目标链适配器提交挑战，返回交易哈希
+    pub async fn submit_challenge(&self, challenge: &Challenge) -> Result<String, Error> {
+        let target_chain = &challenge.divergence.target_chain;
+        let adapter = self.adapters
+            .get(target_chain)
+            .ok_or_else(|| Error::Config(format!("Unknown chain: {}", target_chain)))?;
+        adapter.submit_challenge(challenge).await
+    }
+
+    pub async fn run(mut self) -> Result<(), Error> {
+        info!("Starting watchtower for {} chain(s)", self.adapters.len());
+        let challenges_enabled = self.config.watchtower.as_ref().is_some_and(|w| w.challenge);
+        loop {
+            for divergence in self.poll().await {
+                self.alert(&divergence).await;
+                if !challenges_enabled {
+                    continue;
+                }
+                let Some(challenge) = self.challenge(&divergence).await else { continue };
+                match self.submit_challenge(&challenge).await {
+                    Ok(tx_hash) => info!("Challenged delivery {:?} on {} in transaction {}", divergence.delivery, divergence.target_chain, tx_hash),
+                    Err(e) => error!("Failed to challenge delivery {:?} on {}: {}", divergence.delivery, divergence.target_chain, e),
+                }
+            }
+            sleep(Duration::from_secs(self.config.relayer.poll_interval)).await;
+        }
+    }
+
+    /// 记录偏差并推送到告警地址
+    async fn alert(&self, divergence: &Divergence) {
+        error!(
+            "Divergence {:?} on {} -> {} (nonce {:?}, message {:?}, delivery {:?})",
+            divergence.kind,
+            divergence.source_chain,
+            divergence.target_chain,
+            divergence.nonce,
+            divergence.message_id,
+            divergence.delivery
+        );
+        let Some(webhook) = self.config.watchtower.as_ref().and_then(|w| w.alert_webhook.as_ref()) else {
+            return;
+        };
+        let result = self.http.post(webhook).json(divergence).send().await;
+        match result {
+            Ok(response) if response.status().is_success() => {}
+            Ok(response) => warn!("Alert webhook rejected divergence: {}", response.status()),
+            Err(e) => warn!("Failed to send alert: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{AssetConfig, ChainConfig, RelayerConfig};
+    use crate::testing::mock_chain_config;
+
+    fn config() -> Config {
+        Config {
+            chains: vec![
+                ChainConfig { chain_id: Some(1), ..mock_chain_config("sui") },
+                ChainConfig { chain_id: Some(2), ..mock_chain_config("rooch") },
+            ],
+            assets: vec![AssetConfig {
+                name: "USDC".to_string(),
+                symbol: None,
+                native_chain: "sui".to_string(),
+                type_: "0x2::usdc::USDC".to_string(),
+                decimals: 6,
+                mappings: HashMap::from([("rooch".to_string(), "0x3::usdc::USDC".to_string())]),
+                fee_rate: 100,
+                mapped_decimals: HashMap::new(),
+                relayer_fee: None,
+                timelock: None,
+                paused: false,
+            }],
+            nft_collections: vec![],
+            validators: vec![],
+            relayer: RelayerConfig {
+                poll_interval: 1,
+                max_retries: 1,
+                retry_delay: 1,
+                keystore: None,
+                signer: None,
+                require_attestations: false,
+                attestation_threshold: None,
+                governance_threshold: None,
+                validator_set_epoch: 0,
+                coordination: None,
+                check_recipients: false,
+                validator_set_guard: None,
+                max_message_age: 3_600,
+                queue_capacity: 1_000,
+                confirmations: 0,
+                batch_size: 16,
+                priority: None,
+                script_timeout: 30,
+            },
+            storage: None,
+            api: None,
+            schemas: None,
+            audit: None,
+            screening: None,
+            health: None,
+            p2p: None,
+            watchtower: None,
+            slashing: None,
+            indexer: None,
+            alerts: None,
+            logging: None,
+            reconciliation: None,
+        }
+    }
+
+    fn transfer(nonce: u64, amount: u64) -> CrossChainMessage {
+        let mut payload = vec![0xaa; 32];
+        payload.extend_from_slice(&amount.to_le_bytes());
+        CrossChainMessage {
+            nonce,
+            source_chain: "sui".into(),
+            target_chain: "rooch".into(),
+            message_type: "transfer".to_string(),
+            payload,
+        }
+    }
+
+    fn delivery(event_id: &str, nonce: Option<u64>, amount: u64) -> Delivery {
+        Delivery {
+            event_id: event_id.to_string(),
+            source_chain: "sui".into(),
+            target_chain: "rooch".into(),
+            nonce,
+            recipient: Some(vec![0xaa; 32]),
+            amount: Some(amount),
+            tx_hash: None,
+            message_id: None,
+        }
+    }
+
+    #[test]
+    fn test_reconcile_deliveries() {
+        let config = config();
+        let mut reconciler = Reconciler::new(60);
+        for (nonce, amount) in [(1, 10_000), (2, 20_000), (3, 30_000)] {
+            reconciler.expect(&config, &transfer(nonce, amount), 0).unwrap();
+        }
+
+        // 扣除 1% 手续费后金额一致
+        assert!(reconciler.observe(delivery("a", Some(1), 9_900), 5).is_empty());
+        assert!(reconciler.observe(delivery("a", Some(1), 9_900), 5).is_empty());
+        let duplicate = reconciler.observe(delivery("b", Some(1), 9_900), 5);
+        assert_eq!(duplicate[0].kind, DivergenceKind::Duplicate);
+
+        let mismatch = reconciler.observe(delivery("c", Some(2), 20_000), 5);
+        assert_eq!(mismatch[0].kind, DivergenceKind::AmountMismatch { expected: 19_800, actual: 20_000 });
+
+        // 不含 nonce 的交付按接收者与金额匹配；无法匹配的交付超时后告警
+        assert!(reconciler.observe(delivery("d", None, 29_700), 5).is_empty());
+        assert!(reconciler.observe(delivery("e", None, 1), 5).is_empty());
+        assert!(reconciler.check(30).is_empty());
+
+        reconciler.expect(&config, &transfer(4, 40_000), 30).unwrap();
+        let late = reconciler.check(70);
+        assert_eq!(late.len(), 1);
+        assert_eq!(late[0].kind, DivergenceKind::Unexpected);
+
+        let missing = reconciler.check(90);
+        assert_eq!(missing.len(), 1);
+        assert_eq!((missing[0].kind.clone(), missing[0].nonce), (DivergenceKind::Missing, Some(4)));
+        assert!(reconciler.check(200).is_empty());
+    }
+
+    #[test]
+    fn test_match_delivery_by_trace_id() {
+        let config = config();
+        let mut reconciler = Reconciler::new(60);
+        let (first, second) = (transfer(1, 10_000), transfer(2, 10_000));
+        reconciler.expect(&config, &first, 0).unwrap();
+        reconciler.expect(&config, &second, 0).unwrap();
+
+        // 同额转账无法按接收者与金额区分，交易携带的消息 ID 确定对应关系
+        let mut traced = delivery("a", None, 9_900);
+        let message_id = second.message_id(&config.chain_registry().unwrap()).unwrap();
+        traced.message_id = Some(message_id.clone());
+        assert!(reconciler.observe(traced, 5).is_empty());
+        assert_eq!(reconciler.take_matched()[0].0, message_id);
+
+        let mut unknown = delivery("b", None, 9_900);
+        unknown.message_id = Some("00".repeat(32));
+        assert!(reconciler.observe(unknown, 5).is_empty());
+        assert!(reconciler.take_matched().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_challenge_fraudulent_delivery() {
+        use crate::chain_adapter::mock::{MockChain, MockChainAdapterFactory};
+        use crate::testing::{transfer_payload, two_chain_config, SOURCE_CHAIN, TARGET_CHAIN};
+
+        let source = MockChain::new(SOURCE_CHAIN);
+        let target = MockChain::new(TARGET_CHAIN);
+        let factory = MockChainAdapterFactory::new([source.clone(), target.clone()]);
+        let mut watchtower = Watchtower::with_factory(two_chain_config(), &factory).await.unwrap();
+
+        let sent = source.send(TARGET_CHAIN, "transfer", transfer_payload([1; 32], 500));
+        // 运营方把转账交付给了另一个接收者
+        target.deliver(Delivery {
+            event_id: "forged".to_string(),
+            source_chain: SOURCE_CHAIN.into(),
+            target_chain: TARGET_CHAIN.into(),
+            nonce: Some(sent.message.nonce),
+            recipient: Some(vec![9; 32]),
+            amount: Some(500),
+            tx_hash: None,
+            message_id: None,
+        });
+
+        let divergences = watchtower.poll().await;
+        assert_eq!(divergences.len(), 1);
+        let challenge = watchtower.challenge(&divergences[0]).await.unwrap();
+        assert_eq!(challenge.reason, 4);
+        assert_eq!(challenge.expected.as_ref(), Some(&sent.message));
+        let chains = crate::testing::test_chains();
+        assert_eq!(challenge.evidence(&chains).unwrap(), sent.message.canonical_bytes(&chains).unwrap());
+
+        watchtower.submit_challenge(&challenge).await.unwrap();
+        assert_eq!(target.challenges(), vec![challenge]);
+
+        // 缺失交付不是欺诈，不能挑战
+        let mut missing = divergences[0].clone();
+        missing.kind = DivergenceKind::Missing;
+        assert!(watchtower.challenge(&missing).await.is_none());
+    }
+}
@@ -0,0 +1,191 @@
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use std::time::{Duration, Instant};
+use bridge_core::{
+    api::{MessageHistory, MessageParams},
+    health::HealthReport,
+    info::BuildInfo,
+    relayer::aggregator::QuorumProgress,
+    schema::SchemaDescriptor,
+    storage::MessageRecord,
+    types::{Attestation, MessageStatus},
+    Error,
+};
+
+/// 请求的默认超时
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 中继器 HTTP 接口的客户端
+///
+/// 接口返回的错误体 `{"error": ...}` 转换为 [`Error::Rpc`]，其中 `code` 为 HTTP 状态码。
+#[derive(Debug, Clone)]
+pub struct BridgeClient {
+    base_url: String,
+    http: reqwest::Client,
+}
+
+impl BridgeClient {
+    /// `base_url` 为中继器 `api.listen` 对应的地址，例如 `http://127.0.0.1:8080`
+    pub fn new(base_url: &str) -> Result<Self, Error> {
+        Self::with_timeout(base_url, DEFAULT_TIMEOUT)
+    }
+
+    pub fn with_timeout(base_url: &str, timeout: Duration) -> Result<Self, Error> {
+        let http = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .map_err(|e| Error::Network(e.to_string()))?;
+        Ok(Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            http,
+        })
+    }
+
+    pub async fn info(&self) -> Result<BuildInfo, Error> {
+        self.get("/info").await
+    }
+
+    /// 各链同步情况、gas 余额与待处理消息数
+    pub async fn status(&self) -> Result<HealthReport, Error> {
+        self.get("/status").await
+    }
+
+    pub async fn messages(&self, params: &MessageParams) -> Result<Vec<MessageRecord>, Error> {
+        let request = self.http.get(self.url("/messages")).query(params);
+        self.send(request, "/messages").await
+    }
+
+    /// 消息的记录、证明、提交与重试历史，中继器尚未观察到该消息时返回 None
+    pub async fn message(&self, message_id: &str) -> Result<Option<MessageHistory>, Error> {
+        not_found_as_none(self.get(&format!("/messages/{}", message_id)).await)
+    }
+
+    /// 消息的证明收集进度，尚无证明时返回 None
+    pub async fn quorum_progress(&self, message_id: &str) -> Result<Option<QuorumProgress>, Error> {
+        not_found_as_none(self.get(&format!("/attestations/{}", message_id)).await)
+    }
+
+    pub async fn submit_attestation(&self, attestation: &Attestation) -> Result<QuorumProgress, Error> {
+        let request = self.http.post(self.url("/attestations")).json(attestation);
+        self.send(request, "/attestations").await
+    }
+
+    /// 已注册的负载结构，指定消息类型时只返回该类型的各版本
+    pub async fn schemas(&self, message_type: Option<&str>) -> Result<Vec<SchemaDescriptor>, Error> {
+        match message_type {
+            Some(message_type) => self.get(&format!("/schemas/{}", message_type)).await,
+            None => self.get("/schemas").await,
+        }
+    }
+
+    /// 轮询消息状态直到进入终态（已处理、暂缓或过期），超时返回网络错误
+    ///
+    /// 失败状态不是终态：中继器会在后续轮次中重试提交。
+    pub async fn wait_for_message(&self, message_id: &str, interval: Duration, timeout: Duration) -> Result<MessageRecord, Error> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Some(history) = self.message(message_id).await? {
+                if matches!(history.record.status, MessageStatus::Processed | MessageStatus::Held | MessageStatus::Expired) {
+                    return Ok(history.record);
+                }
+            }
+            if Instant::now() + interval > deadline {
+                return Err(Error::Network(format!("Timed out waiting for message {}", message_id)));
+            }
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, Error> {
+        self.send(self.http.get(self.url(path)), path).await
+    }
+
+    async fn send<T: DeserializeOwned>(&self, request: reqwest::RequestBuilder, path: &str) -> Result<T, Error> {
+        let response = request
+            .send()
+            .await
+            .map_err(|e| Error::Network(format!("Request to {} failed: {}", path, e)))?;
+        let status = response.status();
+        if !status.is_success() {
+            let body: serde_json::Value = response.json().await.unwrap_or_default();
+            let message = body["error"].as_str().map(str::to_string).unwrap_or_else(|| status.to_string());
+            return Err(Error::Rpc {
+                method: path.to_string(),
+                code: i64::from(status.as_u16()),
+                message,
+            });
+        }
+        response
+            .json()
+            .await
+            .map_err(|e| Error::Serialization(format!("Invalid response from {}: {}", path, e)))
+    }
+}
+
+fn not_found_as_none<T>(result: Result<T, Error>) -> Result<Option<T>, Error> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(Error::Rpc { code, .. }) if code == i64::from(StatusCode::NOT_FOUND.as_u16()) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(status: &str) -> String {
+        serde_json::json!({
+            "record": {
+                "message_id": "abc",
+                "source_chain": "sui",
+                "target_chain": "rooch",
+                "asset": "USDC",
+                "sender": null,
+                "nonce": 1,
+                "status": status,
+                "observed_at": 10,
+                "completed_at": null,
+                "error": null,
+                "cause": null
+            },
+            "attestations": [],
+            "submissions": [],
+            "retries": []
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_polls_until_terminal_status() {
+        let mut server = mockito::Server::new_async().await;
+        let pending = server.mock("GET", "/messages/abc").with_body(record("Pending")).expect(1).create_async().await;
+        let client = BridgeClient::new(&server.url()).unwrap();
+        assert_eq!(client.message("abc").await.unwrap().unwrap().record.status, MessageStatus::Pending);
+        pending.assert_async().await;
+        pending.remove_async().await;
+
+        server.mock("GET", "/messages/abc").with_body(record("Processed")).create_async().await;
+        let record = client.wait_for_message("abc", Duration::from_millis(10), Duration::from_secs(1)).await.unwrap();
+        assert_eq!(record.status, MessageStatus::Processed);
+
+        server
+            .mock("GET", "/messages/missing")
+            .with_status(404)
+            .with_body(r#"{"error":"Unknown message missing"}"#)
+            .create_async()
+            .await;
+        assert!(client.message("missing").await.unwrap().is_none());
+        server
+            .mock("GET", "/status")
+            .with_status(500)
+            .with_body(r#"{"error":"boom"}"#)
+            .create_async()
+            .await;
+        assert!(matches!(client.status().await, Err(Error::Rpc { code: 500, message, .. }) if message == "boom"));
+    }
+}
@@ -0,0 +1,18 @@
+//! move-bridge 的 dApp 集成 SDK
+//!
+//! - [`BridgeClient`]：中继器 HTTP 接口的类型化客户端
+//! - [`message`]：构造与合约一致的消息负载及其 BCS 编码
+//! - [`transfer`]：发起跨链转账所需的合约调用，以及到账状态轮询
+
+pub mod client;
+pub mod message;
+pub mod transfer;
+
+pub use bridge_core::{
+    api::{MessageHistory, MessageParams},
+    storage::MessageRecord,
+    types::{ChainId, CrossChainMessage, MessageStatus},
+    Error,
+};
+pub use client::BridgeClient;
+pub use transfer::{transfer, TransferCall};
@@ -0,0 +1,79 @@
+use bridge_core::{
+    chain_adapter::check_address_format,
+    types::{ChainId, CrossChainMessage, NftTransfer, NumericChainIds, TRANSFER_MESSAGE_TYPE, NFT_TRANSFER_MESSAGE_TYPE},
+    Error,
+};
+
+/// 转账负载，与合约 `encode_asset_payload` 一致：接收者地址后接 BCS（小端序）编码的金额
+pub fn transfer_payload(recipient: &[u8], amount: u64) -> Result<Vec<u8>, Error> {
+    if let Some(reason) = check_address_format(recipient) {
+        return Err(Error::Rejected(reason));
+    }
+    if amount == 0 {
+        return Err(Error::Rejected("Transfer amount must be greater than 0".to_string()));
+    }
+    let mut payload = recipient.to_vec();
+    payload.extend_from_slice(&amount.to_le_bytes());
+    Ok(payload)
+}
+
+/// 由源链合约分配 nonce 的转账消息
+pub fn transfer_message(
+    nonce: u64,
+    source_chain: &str,
+    target_chain: &str,
+    recipient: &[u8],
+    amount: u64,
+) -> Result<CrossChainMessage, Error> {
+    Ok(CrossChainMessage {
+        nonce,
+        source_chain: ChainId::from(source_chain),
+        target_chain: ChainId::from(target_chain),
+        message_type: TRANSFER_MESSAGE_TYPE.to_string(),
+        payload: transfer_payload(recipient, amount)?,
+    })
+}
+
+/// NFT 转移消息，负载为 BCS 编码的 [`NftTransfer`]
+pub fn nft_transfer_message(
+    nonce: u64,
+    source_chain: &str,
+    target_chain: &str,
+    transfer: &NftTransfer,
+) -> Result<CrossChainMessage, Error> {
+    Ok(CrossChainMessage {
+        nonce,
+        source_chain: ChainId::from(source_chain),
+        target_chain: ChainId::from(target_chain),
+        message_type: NFT_TRANSFER_MESSAGE_TYPE.to_string(),
+        payload: transfer.encode()?,
+    })
+}
+
+/// 消息的 BCS 规范编码，链以合约数字链 ID 表示，其哈希即验证者签名的原文
+pub fn encode(message: &CrossChainMessage, chains: &impl NumericChainIds) -> Result<Vec<u8>, Error> {
+    message.canonical_bytes(chains)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bridge_core::testing::{test_chains, two_chain_config, SOURCE_CHAIN, TARGET_CHAIN};
+    use bridge_core::ledger::Transfer;
+
+    #[test]
+    fn test_transfer_message_matches_relayer_parsing() {
+        let message = transfer_message(7, SOURCE_CHAIN, TARGET_CHAIN, &[3u8; 32], 1_250).unwrap();
+        let config = two_chain_config();
+        let chains = test_chains();
+        let message_id = message.message_id(&chains).unwrap();
+        let transfer = Transfer::parse(&config, &message_id, &message).unwrap();
+        assert_eq!(transfer.amount, 1_250);
+        assert_eq!(transfer.recipient(), [3u8; 32]);
+        assert_eq!(encode(&message, &chains).unwrap(), message.canonical_bytes(&chains).unwrap());
+
+        assert!(matches!(transfer_payload(&[0u8; 32], 1), Err(Error::Rejected(_))));
+        assert!(matches!(transfer_payload(&[3u8; 20], 1), Err(Error::Rejected(_))));
+        assert!(matches!(transfer_payload(&[3u8; 32], 0), Err(Error::Rejected(_))));
+    }
+}
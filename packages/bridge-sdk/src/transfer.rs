@@ -0,0 +1,121 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::time::Duration;
+use bridge_core::{
+    config::Config,
+    ledger,
+    storage::MessageRecord,
+    types::{ChainId, CrossChainMessage},
+    Error,
+};
+use crate::{client::BridgeClient, message};
+
+/// 在源链上发起一笔跨链转账需要调用的合约入口函数
+///
+/// 只包含纯值参数；桥对象、资产金库与待转出的代币对象由钱包按账户情况补全。
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TransferCall {
+    /// 发起交易的源链
+    pub chain: ChainId,
+    pub target_chain: ChainId,
+    /// 入口函数，例如 `0x42::bridge::send_coin`
+    pub function: String,
+    /// 资产在源链上的类型
+    pub type_arguments: Vec<String>,
+    pub recipient: Vec<u8>,
+    pub amount: u64,
+    /// 源链在合约中的数字链 ID
+    pub source_chain_id: u64,
+    /// 目标链在合约中的数字链 ID
+    pub target_chain_id: u64,
+}
+
+/// 构造从 `from_chain` 向 `to_chain` 转出 `amount`（源链精度）`asset` 的合约调用
+///
+/// 资产须为一侧原生、另一侧映射的路由，且中继器按方向确定的资产正是该资产（见 `ledger::transfer_asset`）。
+pub fn transfer(
+    config: &Config,
+    asset: &str,
+    amount: u64,
+    from_chain: &str,
+    to_chain: &str,
+    recipient: &[u8],
+) -> Result<TransferCall, Error> {
+    if from_chain == to_chain {
+        return Err(Error::Config(format!("Source and target chain are both {}", from_chain)));
+    }
+    let source = config.get_chain_config(from_chain)
+        .ok_or_else(|| Error::Config(format!("Chain config not found: {}", from_chain)))?;
+    let target = config.get_chain_config(to_chain)
+        .ok_or_else(|| Error::Config(format!("Chain config not found: {}", to_chain)))?;
+    let asset_config = config.assets
+        .iter()
+        .find(|a| a.name == asset)
+        .ok_or_else(|| Error::Config(format!("Unknown asset: {}", asset)))?;
+    // 转账负载不携带资产标识，中继器按方向确定的资产须正是该资产
+    let routed = ledger::transfer_asset(config, from_chain, to_chain).is_some_and(|a| a.name == asset);
+    let asset_type = asset_config.type_on(from_chain).filter(|_| routed).ok_or_else(|| {
+        Error::Config(format!("Asset {} cannot be transferred from {} to {}", asset, from_chain, to_chain))
+    })?;
+    let source_chain_id = source.contract_chain_id()
+        .ok_or_else(|| Error::Config(format!("Chain {} has no numeric chain_id", from_chain)))?;
+    let target_chain_id = target.contract_chain_id()
+        .ok_or_else(|| Error::Config(format!("Chain {} has no numeric chain_id", to_chain)))?;
+    // 提前拒绝合约会拒绝的接收者与金额
+    message::transfer_payload(recipient, amount)?;
+
+    Ok(TransferCall {
+        chain: source.id.clone(),
+        target_chain: target.id.clone(),
+        function: format!("{}::bridge::send_coin", source.bridge_address),
+        type_arguments: vec![asset_type.to_string()],
+        recipient: recipient.to_vec(),
+        amount,
+        source_chain_id,
+        target_chain_id,
+    })
+}
+
+impl TransferCall {
+    /// 源链合约为该转账分配 `nonce` 后产生的跨链消息
+    pub fn message(&self, nonce: u64) -> Result<CrossChainMessage, Error> {
+        message::transfer_message(nonce, &self.chain, &self.target_chain, &self.recipient, self.amount)
+    }
+
+    /// 中继器中的消息 ID，用于查询处理状态
+    pub fn message_id(&self, nonce: u64) -> Result<String, Error> {
+        let chains = HashMap::from([
+            (self.source_chain_id, self.chain.clone()),
+            (self.target_chain_id, self.target_chain.clone()),
+        ]);
+        self.message(nonce)?.message_id(&chains)
+    }
+
+    /// 源链交易确认后，轮询中继器直到消息进入终态
+    pub async fn track(&self, client: &BridgeClient, nonce: u64, interval: Duration, timeout: Duration) -> Result<MessageRecord, Error> {
+        client.wait_for_message(&self.message_id(nonce)?, interval, timeout).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bridge_core::testing::{test_chains, two_chain_config, SOURCE_CHAIN, TARGET_CHAIN, TEST_ASSET};
+
+    #[test]
+    fn test_transfer_call_for_configured_route() {
+        let mut config = two_chain_config();
+        let call = transfer(&config, TEST_ASSET, 500, SOURCE_CHAIN, TARGET_CHAIN, &[4u8; 32]).unwrap();
+        assert_eq!(call.function, "0x0::bridge::send_coin");
+        assert_eq!(call.type_arguments, vec!["0x2::usdc::USDC".to_string()]);
+        assert_eq!(call.target_chain_id, 2);
+        let expected = message::transfer_message(9, SOURCE_CHAIN, TARGET_CHAIN, &[4u8; 32], 500).unwrap();
+        assert_eq!(call.message_id(9).unwrap(), expected.message_id(&test_chains()).unwrap());
+
+        // 链未配置数字链 ID、未知资产与同链转账均被拒绝
+        config.chains[0].chain_id = None;
+        assert!(transfer(&config, TEST_ASSET, 500, TARGET_CHAIN, SOURCE_CHAIN, &[4u8; 32]).is_err());
+        assert!(transfer(&config, "BTC", 500, SOURCE_CHAIN, TARGET_CHAIN, &[4u8; 32]).is_err());
+        assert!(transfer(&config, TEST_ASSET, 500, SOURCE_CHAIN, SOURCE_CHAIN, &[4u8; 32]).is_err());
+    }
+}
@@ -0,0 +1,31 @@
+use anyhow::Result;
+use bridge_core::signer::remote::{self, SignerServiceConfig};
+use clap::Parser;
+use std::path::PathBuf;
+use tracing::info;
+
+/// 远程签名服务：通过双向 TLS 的 gRPC 接口为中继器与验证者签名，私钥不出本进程或签名后端
+#[derive(Parser)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    /// 服务配置文件路径
+    #[arg(short, long, value_name = "FILE")]
+    config: PathBuf,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let config = SignerServiceConfig::load(&cli.config)?;
+    // 初始化日志
+    bridge_core::logging::init(&config.logging, false)?;
+    println!("允许签名的消息类型: {}", config.allowed_message_types.join(", "));
+    println!("限速: 每秒 {} 次，突发 {} 次", config.rate_limit.per_second, config.rate_limit.burst);
+
+    remote::serve(&config, async {
+        let _ = tokio::signal::ctrl_c().await;
+        info!("收到退出信号，停止签名服务");
+    })
+    .await?;
+    Ok(())
+}
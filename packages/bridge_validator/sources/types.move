@@ -5,6 +5,7 @@
 module bridge_validator::types {
     use sui::object::{Self, ID, UID};
     use sui::table::{Self, Table};
+    use sui::dynamic_field;
     use sui::vec_set::{Self, VecSet};
     use sui::tx_context::{Self, TxContext};
     use sui::transfer::{Self};
@@ -52,6 +53,12 @@ module bridge_validator::types {
         slash_rate: u64
     }
 
+    /// 验证器BLS公钥的动态字段键
+    /// BLS公钥以动态字段形式挂在验证器集合上，由验证器出示持有证明后登记
+    public struct BlsKey has copy, drop, store {
+        validator: address
+    }
+
     /// 质押管理器
     /// 管理验证器的质押、解质押和相关的性能评分
     public struct StakeManager has key {
@@ -338,6 +345,11 @@ module bridge_validator::types {
             if (table::contains(&validator_set.weights, validator)) {
                 table::remove(&mut validator_set.weights, validator);
             };
+            // 移除后重新加入须重新出示BLS公钥持有证明
+            let key = BlsKey { validator };
+            if (dynamic_field::exists_(&validator_set.id, key)) {
+                dynamic_field::remove<BlsKey, vector<u8>>(&mut validator_set.id, key);
+            };
         };
     }
 
@@ -655,4 +667,28 @@ module bridge_validator::types {
         assert!(vec_set::contains(&validator_set.validators, &validator), EINVALID_VALIDATOR);
         *table::borrow(&validator_set.public_keys, validator)
     }
-} 
\ No newline at end of file
+
+    /// 登记或替换验证器的BLS公钥，调用方负责校验持有证明
+    public(package) fun set_validator_bls_key(
+        validator_set: &mut ValidatorSet,
+        validator: address,
+        bls_public_key: vector<u8>
+    ) {
+        assert!(vec_set::contains(&validator_set.validators, &validator), EINVALID_VALIDATOR);
+        let key = BlsKey { validator };
+        if (dynamic_field::exists_(&validator_set.id, key)) {
+            *dynamic_field::borrow_mut<BlsKey, vector<u8>>(&mut validator_set.id, key) = bls_public_key;
+        } else {
+            dynamic_field::add(&mut validator_set.id, key, bls_public_key);
+        }
+    }
+
+    public fun has_validator_bls_key(validator_set: &ValidatorSet, validator: address): bool {
+        dynamic_field::exists_(&validator_set.id, BlsKey { validator })
+    }
+
+    public fun get_validator_bls_key(validator_set: &ValidatorSet, validator: address): vector<u8> {
+        assert!(vec_set::contains(&validator_set.validators, &validator), EINVALID_VALIDATOR);
+        *dynamic_field::borrow<BlsKey, vector<u8>>(&validator_set.id, BlsKey { validator })
+    }
+}
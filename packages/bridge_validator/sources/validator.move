@@ -7,6 +7,8 @@ module bridge_validator::validator {
     use sui::event;
     use bridge_validator::types::{Self, ValidatorSet, StakeManager};
     use sui::table::{Self, Table};
+    use sui::bls12381;
+    use sui::bcs;
     use std::vector;
 
     // === 错误码定义 ===
@@ -20,6 +22,13 @@ module bridge_validator::validator {
     const EINVALID_STAKE: u64 = 4;
     /// 当验证器公钥格式无效时抛出
     const EINVALID_PUBLIC_KEY: u64 = 5;
+    /// 当BLS公钥的持有证明无效时抛出
+    const EINVALID_PROOF_OF_POSSESSION: u64 = 6;
+
+    /// BLS公钥持有证明的域分隔前缀
+    const BLS_POP_DOMAIN: vector<u8> = b"MOVE_BRIDGE_BLS_POP";
+    /// BLS公钥长度（压缩的G1点）
+    const BLS_PUBLIC_KEY_LENGTH: u64 = 48;
 
     // === 初始化函数 ===
     /// 初始化验证器集合
@@ -63,6 +72,38 @@ module bridge_validator::validator {
         types::add_validator_with_weight_and_key(validator_set, validator, weight, public_key);
     }
 
+    /// 登记调用者的BLS公钥，用于聚合签名验证
+    /// 聚合公钥在链上由已登记的公钥计算，持有证明防止以他人公钥构造的恶意公钥抵消聚合结果
+    /// * `validator_set` - 验证器集合
+    /// * `bls_public_key` - 48字节压缩BLS公钥
+    /// * `proof_of_possession` - 用该BLS私钥对 `bls_pop_message` 的签名
+    public fun register_bls_key(
+        validator_set: &mut ValidatorSet,
+        bls_public_key: vector<u8>,
+        proof_of_possession: vector<u8>,
+        ctx: &mut TxContext
+    ) {
+        let validator = tx_context::sender(ctx);
+        assert!(types::is_validator(validator_set, validator), EINVALID_VALIDATOR);
+        assert!(vector::length(&bls_public_key) == BLS_PUBLIC_KEY_LENGTH, EINVALID_PUBLIC_KEY);
+
+        let message = bls_pop_message(validator, &bls_public_key);
+        assert!(
+            bls12381::bls12381_min_pk_verify(&proof_of_possession, &bls_public_key, &message),
+            EINVALID_PROOF_OF_POSSESSION
+        );
+
+        types::set_validator_bls_key(validator_set, validator, bls_public_key);
+    }
+
+    /// 构造BLS公钥持有证明的签名消息：域分隔前缀 || BLS公钥 || BCS编码的验证器地址
+    public fun bls_pop_message(validator: address, bls_public_key: &vector<u8>): vector<u8> {
+        let mut message = BLS_POP_DOMAIN;
+        vector::append(&mut message, *bls_public_key);
+        vector::append(&mut message, bcs::to_bytes(&validator));
+        message
+    }
+
     /// 移除验证器
     /// * `validator_set` - 验证器集合
     /// * `validator` - 要移除的验证器地址
@@ -15,6 +15,8 @@ module bridge_validator::verify {
     use bridge_validator::validator;
     use std::vector;
     use sui::ed25519;
+    use sui::bls12381;
+    use sui::group_ops;
 
     // === 错误码定义 ===
     /// 当消息格式无效或内容不合法时抛出
@@ -119,6 +121,55 @@ module bridge_validator::verify {
         threshold_met
     }
 
+    /// 验证验证者委员会的BLS聚合签名
+    /// 聚合公钥在链上由 `signers_bitmap` 选中的验证者已登记的BLS公钥计算，
+    /// 签名者权重之和须达到阈值，最后只需一次配对验证
+    /// * `validator_set` - 验证器集合，第 i 位对应 `get_active_validators` 中的第 i 个验证者
+    /// * `message` - 被签名的原始消息
+    /// * `signers_bitmap` - 签名者位图，长度为验证者数量按8位向上取整的字节数
+    /// * `signature` - 聚合签名（96字节）
+    /// 返回：位图有效、签名者均已登记BLS公钥、权重达到阈值且聚合签名有效
+    public fun verify_aggregated_signature(
+        validator_set: &ValidatorSet,
+        message: vector<u8>,
+        signers_bitmap: vector<u8>,
+        signature: vector<u8>
+    ): bool {
+        let validators = types::get_active_validators(validator_set);
+        let validator_count = vector::length(&validators);
+        if (vector::length(&signers_bitmap) != (validator_count + 7) / 8) {
+            return false
+        };
+
+        let mut aggregated_public_key = bls12381::g1_identity();
+        let mut signed_weight = 0u64;
+        let mut signer_count = 0u64;
+        let mut i = 0;
+        while (i < vector::length(&signers_bitmap) * 8) {
+            let byte = *vector::borrow(&signers_bitmap, i / 8);
+            if ((byte >> ((i % 8) as u8)) & 1 == 1) {
+                // 位图超出验证者集合
+                if (i >= validator_count) {
+                    return false
+                };
+                let signer = *vector::borrow(&validators, i);
+                if (!types::has_validator_bls_key(validator_set, signer)) {
+                    return false
+                };
+                let public_key = bls12381::g1_from_bytes(&types::get_validator_bls_key(validator_set, signer));
+                aggregated_public_key = bls12381::g1_add(&aggregated_public_key, &public_key);
+                signed_weight = signed_weight + validator::get_validator_weight(validator_set, signer);
+                signer_count = signer_count + 1;
+            };
+            i = i + 1;
+        };
+
+        if (signer_count == 0 || signed_weight < validator::get_threshold(validator_set)) {
+            return false
+        };
+        bls12381::bls12381_min_pk_verify(&signature, group_ops::bytes(&aggregated_public_key), &message)
+    }
+
     /// 检查消息是否已被完全验证
     /// * `verification` - 消息验证记录
     /// 返回：消息是否已被验证通过
@@ -204,4 +204,63 @@ module bridge_validator::verify_tests {
         
         test_scenario::end(scenario);
     }
-} 
\ No newline at end of file
+
+    /// 测试无效的BLS聚合签名
+    /// 签名者未登记BLS公钥、位图长度与验证者集合不符或无人签名时均不通过验证
+    #[test]
+    fun test_invalid_aggregated_signature() {
+        let mut scenario = setup_test();
+        setup_validators(&mut scenario);
+
+        test_scenario::next_tx(&mut scenario, USER);
+        {
+            let validator_set = test_scenario::take_shared<ValidatorSet>(&scenario);
+            let message = b"test message";
+            let signature = b"invalid signature";
+
+            // 两个验证者都已签名，但均未登记BLS公钥
+            assert!(!verify::verify_aggregated_signature(&validator_set, message, vector[3], signature), 0);
+            // 位图长度与验证者集合不符
+            assert!(!verify::verify_aggregated_signature(&validator_set, message, vector[3, 0], signature), 1);
+            // 位图超出验证者集合
+            assert!(!verify::verify_aggregated_signature(&validator_set, message, vector[4], signature), 2);
+            // 无人签名
+            assert!(!verify::verify_aggregated_signature(&validator_set, message, vector[0], signature), 3);
+
+            test_scenario::return_shared(validator_set);
+        };
+
+        test_scenario::end(scenario);
+    }
+
+    /// 测试登记BLS公钥时持有证明无效
+    /// 验证无法证明持有私钥的BLS公钥不能登记
+    #[test]
+    #[expected_failure(abort_code = validator::EINVALID_PROOF_OF_POSSESSION)]
+    fun test_register_bls_key_invalid_proof_of_possession() {
+        let mut scenario = setup_test();
+        setup_validators(&mut scenario);
+
+        test_scenario::next_tx(&mut scenario, VALIDATOR1);
+        {
+            let mut validator_set = test_scenario::take_shared<ValidatorSet>(&scenario);
+            let mut bls_public_key = vector[];
+            let mut i = 0;
+            while (i < 48) {
+                vector::push_back(&mut bls_public_key, 0);
+                i = i + 1;
+            };
+
+            validator::register_bls_key(
+                &mut validator_set,
+                bls_public_key,
+                b"invalid proof",
+                test_scenario::ctx(&mut scenario)
+            );
+
+            test_scenario::return_shared(validator_set);
+        };
+
+        test_scenario::end(scenario);
+    }
+}
@@ -10,6 +10,8 @@ module sui_bridge::message {
 
     // === 常量 ===
     const MESSAGE_TYPE_ASSET: u8 = 1;
+    /// 资产消息在规范编码中的类型名，与链下 `TRANSFER_MESSAGE_TYPE` 一致
+    const MESSAGE_TYPE_ASSET_NAME: vector<u8> = b"transfer";
 
     // === 错误码 ===
     const EINVALID_MESSAGE_TYPE: u64 = 1;
@@ -134,6 +136,22 @@ module sui_bridge::message {
         bytes
     }
 
+    /// 规范编码：nonce、源链、目标链、消息类型名与负载依次 BCS 编码
+    /// 与链下 `CrossChainMessage::canonical_bytes` 一致，验证者对其哈希签名
+    public fun canonical_bytes(message: &Message): vector<u8> {
+        let mut bytes = bcs::to_bytes(&message.nonce);
+        vector::append(&mut bytes, bcs::to_bytes(&message.source_chain));
+        vector::append(&mut bytes, bcs::to_bytes(&message.target_chain));
+        vector::append(&mut bytes, bcs::to_bytes(&message_type_name(message.message_type)));
+        vector::append(&mut bytes, bcs::to_bytes(&message.payload));
+        bytes
+    }
+
+    fun message_type_name(message_type: u8): vector<u8> {
+        assert!(message_type == MESSAGE_TYPE_ASSET, EINVALID_MESSAGE_TYPE);
+        MESSAGE_TYPE_ASSET_NAME
+    }
+
     public fun deserialize_message(bytes: vector<u8>): Message {
         let mut bcs_parser = bcs::new(bytes);
         let message_type = bcs::peel_u8(&mut bcs_parser);
@@ -158,8 +176,9 @@ module sui_bridge::message {
         table::contains(&config.executed_messages, *message_id)
     }
 
+    /// 消息 ID：规范编码的 Blake2b-256 摘要
     public fun get_message_id(message: &Message): vector<u8> {
-        let bytes = serialize_message(message);
+        let bytes = canonical_bytes(message);
         hash::blake2b256(&bytes)
     }
 
@@ -201,4 +220,22 @@ module sui_bridge::message {
         };
         serialize_message(&message)
     }
+
+    #[test_only]
+    /// 构造指定 nonce 的消息，供跨实现签名向量测试使用
+    public fun new_message_for_testing(
+        message_type: u8,
+        source_chain: u64,
+        target_chain: u64,
+        nonce: u64,
+        payload: vector<u8>
+    ): Message {
+        Message {
+            message_type,
+            source_chain,
+            target_chain,
+            nonce,
+            payload,
+        }
+    }
 } 
\ No newline at end of file
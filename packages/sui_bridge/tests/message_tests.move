@@ -3,6 +3,7 @@ module sui_bridge::message_tests {
     use sui::test_scenario::{Self as test, Scenario, next_tx, ctx};
     use sui_bridge::message::{Self, MessageConfig, Message};
     use std::vector;
+    use std::debug;
     use sui::transfer;
 
     const ADMIN: address = @0xA;
@@ -97,4 +98,43 @@ module sui_bridge::message_tests {
         };
         test::end(scenario);
     }
-} 
\ No newline at end of file
+
+    /// 打印经 create_message 创建的消息的编码与 ID
+    /// 输出记录在 tests/vectors/move.json 中，由 bridge-core 的 Rust 测试校验两侧编码一致
+    #[test]
+    fun test_print_message_vectors() {
+        let mut scenario = test_scenario();
+
+        next_tx(&mut scenario, ADMIN);
+        {
+            let mut config = message::initialize(ctx(&mut scenario));
+            let first = message::create_message(
+                &mut config,
+                MESSAGE_TYPE_ASSET,
+                SOURCE_CHAIN,
+                TARGET_CHAIN,
+                b"test payload",
+                ctx(&mut scenario)
+            );
+            let second = message::create_message(
+                &mut config,
+                MESSAGE_TYPE_ASSET,
+                TARGET_CHAIN,
+                SOURCE_CHAIN,
+                x"00ff",
+                ctx(&mut scenario)
+            );
+            assert!(message::get_nonce(&second) == 1, 0);
+
+            debug::print(&message::serialize_message(&first));
+            debug::print(&message::canonical_bytes(&first));
+            debug::print(&message::get_message_id(&first));
+            debug::print(&message::serialize_message(&second));
+            debug::print(&message::canonical_bytes(&second));
+            debug::print(&message::get_message_id(&second));
+
+            transfer::public_transfer(config, USER);
+        };
+        test::end(scenario);
+    }
+}
@@ -0,0 +1,47 @@
+/// 跨实现签名向量测试
+/// 由 `bridge-cli vectors generate` 根据 tests/vectors/rust.json 生成，请勿手工修改
+#[test_only]
+module sui_bridge::vector_tests {
+    use sui::bls12381;
+    use sui::ed25519;
+    use sui_bridge::message;
+
+    #[test]
+    fun test_asset_transfer() {
+        let msg = message::new_message_for_testing(1, 1, 2, 1, x"abababababababababababababababababababababababababababababababab40420f0000000000");
+        let message_hash = x"6180f70acdd352af76c2eab92f07fbf80d0c426ab762ebb347bb8edc5a3bd0f1";
+        assert!(message::serialize_message(&msg) == x"01010000000000000002000000000000000100000000000000abababababababababababababababababababababababababababababababab40420f0000000000", 0);
+        assert!(message::canonical_bytes(&msg) == x"010000000000000001000000000000000200000000000000087472616e7366657228abababababababababababababababababababababababababababababababab40420f0000000000", 4);
+        assert!(message::get_message_id(&msg) == message_hash, 1);
+        assert!(ed25519::ed25519_verify(&x"4405f9f7bd1448fa02a6f5a296a5e3a1428c1e668f67aaed62135963bb28472524f76178bdd2bce6b3b296c90d2a1443877ae46a0da8fcb0da8dfcc7140f010b", &x"8a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c", &message_hash), 2);
+        assert!(ed25519::ed25519_verify(&x"fd768f5d6f873da7c6188eeabcbf0727f7ff29f2c9cafcf4a1965594a56c4158e2914b3de87cb16532d1e9d2c41d7dcb3527f21b693f6678bf06526a6ce6620f", &x"8139770ea87d175f56a35466c34c7ecccb8d8a91b4ee37a25df60f5b8fc9b394", &message_hash), 2);
+        assert!(ed25519::ed25519_verify(&x"4223ee85adbf5b814cd9fb42e3db870a53f2e2ac95d8574d0a718c89701c36d6e07e313154880eb3cbfa10c96c2a52bece6f348734b4b3f6256ba598b5da620a", &x"ed4928c628d1c2c6eae90338905995612959273a5c63f93636c14614ac8737d1", &message_hash), 2);
+        assert!(bls12381::bls12381_min_pk_verify(&x"8919e5a7a0ea25159ca2d7f4e463126a91711fa9de72f2fb04811d15c54287ef94cda3fb8a0bb7308a84d609037197320d647fed3524337d384a0c68c1e27f1f114372ff5f3ece44607ef65e01a407a26fe46b82f0b878f15c7cd0fdd21a3cd6", &x"91e58f222aa4f43f390d7c3967ef0c86a65b92c82eb087f347d87c0e865a215eb822425bcf0bb35ec093277f14a34751", &message_hash), 3);
+    }
+
+    #[test]
+    fun test_max_nonce() {
+        let msg = message::new_message_for_testing(1, 2, 1, 18446744073709551615, x"6d6f76652d627269646765");
+        let message_hash = x"f680b7912ceae0a928f8da88c81a7e33061fbcd4ed08a91b1e1210fb327c28a1";
+        assert!(message::serialize_message(&msg) == x"0102000000000000000100000000000000ffffffffffffffff6d6f76652d627269646765", 0);
+        assert!(message::canonical_bytes(&msg) == x"ffffffffffffffff02000000000000000100000000000000087472616e736665720b6d6f76652d627269646765", 4);
+        assert!(message::get_message_id(&msg) == message_hash, 1);
+        assert!(ed25519::ed25519_verify(&x"7299d9bfd40e069b686f05a6302d09e7b51c8725eb1a01fadd27f21614a423d9016a9d152b8143550c7146064f4e75684816ad66432f57139ce6258615c08406", &x"8a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c", &message_hash), 2);
+        assert!(ed25519::ed25519_verify(&x"2093cdaed0d986ecc005659bd943868edc79220dc96a8249c7b885e8be0ae2c10af590716b667711772d2e2c23906a4a5334c041fa3590539c0c3f0b29c40606", &x"8139770ea87d175f56a35466c34c7ecccb8d8a91b4ee37a25df60f5b8fc9b394", &message_hash), 2);
+        assert!(ed25519::ed25519_verify(&x"62b1243ca72390810d9938eb8952f14d0140e2e162db0c7f9f07129b340f249137e23b9a592f30cfc481aff3f800b48c37b620a1d6928655c43f0bfeb931750f", &x"ed4928c628d1c2c6eae90338905995612959273a5c63f93636c14614ac8737d1", &message_hash), 2);
+        assert!(bls12381::bls12381_min_pk_verify(&x"a366a8f57e5c9d6084097b7f33ee29a76b0c083b3b92491989007589a213996ee608c521cb4f3fce4d59842d679c18df11f92dc2dbecca16b1ef61ef8e16a34c3ff9b54db3180f599f34d98ee1cf1a3e2fd2a812ed3f785292497ef7103f3d88", &x"91e58f222aa4f43f390d7c3967ef0c86a65b92c82eb087f347d87c0e865a215eb822425bcf0bb35ec093277f14a34751", &message_hash), 3);
+    }
+
+    #[test]
+    fun test_binary_payload() {
+        let msg = message::new_message_for_testing(1, 72623859790382856, 3, 256, x"00ff807f");
+        let message_hash = x"60dd89470bfc4a3b5a980a8d8a42bc705f42998a833bc68f2de54bddcea11c1a";
+        assert!(message::serialize_message(&msg) == x"0108070605040302010300000000000000000100000000000000ff807f", 0);
+        assert!(message::canonical_bytes(&msg) == x"000100000000000008070605040302010300000000000000087472616e736665720400ff807f", 4);
+        assert!(message::get_message_id(&msg) == message_hash, 1);
+        assert!(ed25519::ed25519_verify(&x"01fe7e3f3a62d4a0a27f5df5ad78d1dc255bd0bb2a45e1de6a296d39b033f142ee9379a155bd295663db73a6de731d812450b35cd751aafd31513000f5606e03", &x"8a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c", &message_hash), 2);
+        assert!(ed25519::ed25519_verify(&x"78bfeb71a3651b819027addb17399b92f7fc9f3b8d1959b2a569065146926235a49af1a0feec51d5fa33e77a466a62a32d1f07afa4daff75dd30f0c76cda140f", &x"8139770ea87d175f56a35466c34c7ecccb8d8a91b4ee37a25df60f5b8fc9b394", &message_hash), 2);
+        assert!(ed25519::ed25519_verify(&x"427cf73e87367d4cf3f6be9200c1ebd7c4b1085185ed72687beb0c0a95de8fa57e001947bfeaec0d798832ecbffdc6537eb87eec65b05c54f244067022f81203", &x"ed4928c628d1c2c6eae90338905995612959273a5c63f93636c14614ac8737d1", &message_hash), 2);
+        assert!(bls12381::bls12381_min_pk_verify(&x"b9124d364e450cf8fa904a1f358fbd53086f49c22becd65fed304100239d1f107a23fd7e532428758029a278ec1fc3a11137abc854b3e1d52ea7eee97d60866c2f36097d40057d22cddcc907f1098a096ba58cfefb436f75159707bfb2a9d099", &x"91e58f222aa4f43f390d7c3967ef0c86a65b92c82eb087f347d87c0e865a215eb822425bcf0bb35ec093277f14a34751", &message_hash), 3);
+    }
+}